@@ -7,6 +7,8 @@ use criterion::{ Criterion, black_box, Throughput, BenchmarkId, BatchSize };
 
 use webassembly_marker_clusterer::*;
 use config::Config;
+use greedy::{ cluster_markers, recluster_markers };
+use structs::cluster_index::ClusterIndex;
 
 use utilities::{DEFAULT_BOUNDS, get_unique_sample_markers};
 
@@ -15,15 +17,27 @@ fn clustering_benchmark(c: &mut Criterion) {
   let sample_markers = get_unique_sample_markers();
   let config = Config::default();
 
-  for (zoom, clusters) in &[(7,1), (8,6), (9,16), (10,51), (11,162)] {
+  for (zoom, clusters) in &[(7,1), (8,6), (9,16), (10,51), (11,162), (12,464), (13,1201), (14,2724)] {
     clustering.throughput(Throughput::Elements((sample_markers.len() / clusters) as u64));
     clustering.bench_with_input(BenchmarkId::from_parameter(zoom), &zoom, |b, &zoom_cur| b.iter_batched(
       || sample_markers.to_vec(),
-      |mut markers| cluster_markers(black_box(&mut Vec::new()), black_box(&mut markers), &DEFAULT_BOUNDS, *zoom_cur, black_box(&config)),
+      |mut markers| cluster_markers(black_box(&mut Vec::new()), black_box(&mut ClusterIndex::default()), black_box(&mut markers), &DEFAULT_BOUNDS, *zoom_cur, black_box(&config)),
       BatchSize::SmallInput
     ));
   }
   clustering.finish();
+
+  let mut reclustering = c.benchmark_group("recluster-10000");
+  let added_markers = sample_markers.iter().cloned().map(|mut marker| { marker.is_added = true; marker }).collect::<Vec<_>>();
+  for (zoom, clusters) in &[(7,1), (8,6), (9,16), (10,51), (12,464), (14,2724)] {
+    reclustering.throughput(Throughput::Elements((added_markers.len() / clusters) as u64));
+    reclustering.bench_with_input(BenchmarkId::from_parameter(zoom), &zoom, |b, &zoom_cur| b.iter_batched(
+      || added_markers.to_vec(),
+      |mut markers| recluster_markers(black_box(&mut Vec::new()), black_box(&mut ClusterIndex::default()), black_box(&mut markers), *zoom_cur, black_box(&config)),
+      BatchSize::SmallInput
+    ));
+  }
+  reclustering.finish();
 }
 
 criterion_group!{
@@ -1,5 +1,5 @@
 import { wrap } from "comlink";
-import { IConfig, IMarker, IBounds, ICluster } from "./interfaces";
+import { IConfig, IMarker, IBounds, ICluster, IClusterColumns, IClusterDiff } from "./interfaces";
 export * from "./interfaces";
 
 const camelToSnake = (str: string): string => str.replace(
@@ -7,22 +7,63 @@ const camelToSnake = (str: string): string => str.replace(
   (group) => `${group.toLowerCase()}_`
 ).slice(0, -1);
 
-const configProps: (keyof IConfig)[] = ["gridSize", "averageCenter", "logTime", "onlyReturnModifiedClusters"];
+const configProps: (keyof IConfig)[] = ["gridSize", "averageCenter", "logTime", "onlyReturnModifiedClusters", "hierarchical", "maxZoom", "includeProperties", "aggregations", "distanceInPixels", "algorithm", "projection", "deterministic", "output"];
+
+/** The options of an algorithm like `{ dbscan: { minPoints: 4 } }` are camelCase too. */
+const mapAlgorithmNames = (algorithm: IConfig["algorithm"]) => {
+  if (!algorithm || typeof algorithm !== "object") return algorithm;
+  let wasmAlgorithm: any = {};
+  Object.keys(algorithm).forEach((name) => {
+    let options = (algorithm as any)[name] || {};
+    wasmAlgorithm[name] = {};
+    Object.keys(options).forEach((option) => wasmAlgorithm[name][camelToSnake(option)] = options[option]);
+  });
+  return wasmAlgorithm;
+}
 
 const mapConfigNames = (config: IConfig) => {
   let wasmConfig: any = {};
   configProps.forEach((property) => {
     if (config[property] !== null || config[property] !== undefined) {
-      wasmConfig[camelToSnake(property)] = config[property];
+      wasmConfig[camelToSnake(property)] = property === "algorithm" ? mapAlgorithmNames(config.algorithm) : config[property];
     }
   });
   return wasmConfig;
 }
 
+/** The Wasm module returns maps (and objects nested in marker properties) as `Map`s, so convert them back to plain objects. */
+const mapsToObjects = (value: any): any => {
+  if (value instanceof Map) {
+    let object: any = {};
+    value.forEach((entry, key) => object[key] = mapsToObjects(entry));
+    return object;
+  }
+  return Array.isArray(value) ? value.map(mapsToObjects) : value;
+}
+
+const restoreMaps = (clusters: ICluster[]): ICluster[] => {
+  clusters.forEach(cluster => {
+    if (cluster.aggregates !== undefined) {
+      cluster.aggregates = mapsToObjects(cluster.aggregates);
+    }
+    if (cluster.markers !== undefined) {
+      cluster.markers.forEach(marker => {
+        if (marker.properties !== undefined) {
+          marker.properties = mapsToObjects(marker.properties);
+        }
+      });
+    }
+  });
+  return clusters;
+}
+
+/** Clusters with a size of 0 had all of their markers removed, so are dropped. */
 const mergeModifiedClusters = (prevClusters: ICluster[], modifiedClusters: ICluster[]): ICluster[] => {
   modifiedClusters.forEach(modifiedCluster => {
     let index = prevClusters.findIndex(prevCluster => prevCluster.uuid === modifiedCluster.uuid);
-    if (index === -1) {
+    if (modifiedCluster.size === 0) {
+      if (index !== -1) prevClusters.splice(index, 1);
+    } else if (index === -1) {
       prevClusters.push(modifiedCluster);
     } else {
       prevClusters[index] = modifiedCluster;
@@ -31,6 +72,20 @@ const mergeModifiedClusters = (prevClusters: ICluster[], modifiedClusters: IClus
   return prevClusters;
 }
 
+const applyClusterDiff = (prevClusters: ICluster[], diff: IClusterDiff): ICluster[] => {
+  let removed = new Set(diff.removed);
+  return mergeModifiedClusters(
+    prevClusters.filter(cluster => !removed.has(cluster.uuid)),
+    diff.updated.concat(diff.added)
+  );
+}
+
+/** The uuid of the cluster at `index` of the columns, as returned by the other methods. */
+export const clusterIdAt = (columns: IClusterColumns, index: number): string => {
+  let hex = Array.from(columns.uuids.subarray(index * 16, index * 16 + 16), byte => ("0" + byte.toString(16)).slice(-2)).join("");
+  return `${hex.slice(0, 8)}-${hex.slice(8, 12)}-${hex.slice(12, 16)}-${hex.slice(16, 20)}-${hex.slice(20)}`;
+}
+
 export class WasmMarkerClusterer {
   private config?: IConfig = { onlyReturnModifiedClusters: true };
   private previousZoom = -1;
@@ -55,11 +110,17 @@ export class WasmMarkerClusterer {
   /**
    * Merges any passed config parameters into existing config. 
    * 
-   * Clears cached clusters if `averageCenter` or `gridSize` is modified.
+   * Clears cached clusters if `averageCenter`, `gridSize`, `aggregations`, `algorithm`, `projection` or `deterministic` is modified.
    */
   configure = async (config: IConfig): Promise<void> => {
     await this.wasmReady;
-    if (this.config.averageCenter != config.averageCenter || this.config.gridSize !== config.gridSize) {
+    let aggregationsChanged = config.aggregations !== undefined
+        && JSON.stringify(this.config.aggregations) !== JSON.stringify(config.aggregations);
+    let projectionChanged = config.projection !== undefined
+        && JSON.stringify(this.config.projection) !== JSON.stringify(config.projection);
+    if (this.config.averageCenter != config.averageCenter || this.config.gridSize !== config.gridSize || aggregationsChanged || projectionChanged
+        || (config.algorithm !== undefined && JSON.stringify(this.config.algorithm) !== JSON.stringify(config.algorithm))
+        || (config.deterministic !== undefined && this.config.deterministic !== config.deterministic)) {
       this.clearClusters();
     }
     this.config = Object.assign(this.config, config);
@@ -77,15 +138,82 @@ export class WasmMarkerClusterer {
     this.previousZoom = zoom;
 
     if (this.config.logTime) console.time("wasm");
-    let wasmClusters = await this.clusterer.clusterMarkersInBounds(bounds, zoom);
+    let wasmClusters = restoreMaps(await this.clusterer.clusterMarkersInBounds(bounds, zoom));
     if (this.config.logTime) console.timeEnd("wasm");
 
-    this.previousClusters = !this.config.onlyReturnModifiedClusters || zoomChanged 
+    // Hierarchical clusters are all returned each time, and get new ids when markers change
+    this.previousClusters = !this.config.onlyReturnModifiedClusters || this.config.hierarchical || zoomChanged 
         ? wasmClusters 
         : mergeModifiedClusters(this.previousClusters, wasmClusters);
     return this.previousClusters;
   }
 
+  /**
+   * Calculates clusters for the markers within the given bounds, and returns what changed since the last call.
+   * Don't mix calls to this with `clusterMarkersInBounds`, since each only returns what the other hasn't.
+   *
+   * @returns The new and changed clusters, and the uuids of clusters that no longer exist
+   */
+  clusterDiffInBounds = async (bounds: IBounds, zoom: number): Promise<IClusterDiff> => {
+    await this.wasmReady;
+    this.previousZoom = zoom;
+
+    if (this.config.logTime) console.time("wasm");
+    let diff: IClusterDiff = await this.clusterer.clusterDiffInBounds(bounds, zoom);
+    if (this.config.logTime) console.timeEnd("wasm");
+
+    restoreMaps(diff.added);
+    restoreMaps(diff.updated);
+    this.previousClusters = applyClusterDiff(this.previousClusters, diff);
+    return diff;
+  }
+
+  /**
+   * Calculates clusters for the markers within the given bounds, and returns every current cluster as typed arrays.
+   * Don't mix calls to this with `clusterMarkersInBounds`, since removed clusters are only reported by one of them.
+   */
+  clusterColumnsInBounds = async (bounds: IBounds, zoom: number): Promise<IClusterColumns> => {
+    await this.wasmReady;
+    this.previousZoom = zoom;
+    return this.clusterer.clusterColumnsInBounds(bounds, zoom);
+  }
+
+  /**
+   * The lowest zoom at which the markers of a cluster from the last call split into more than one cluster,
+   * e.g. to zoom to when it's clicked. Doesn't change the calculated clusters.
+   *
+   * @returns `undefined` if the markers are still one cluster at `maxZoom`
+   */
+  getClusterExpansionZoom = async (clusterId: string): Promise<number | undefined> => {
+    await this.wasmReady;
+    return this.clusterer.getClusterExpansionZoom(clusterId);
+  }
+
+  /**
+   * Up to `limit` of the markers in a cluster from the last call, skipping the first `offset`,
+   * so the markers of a large cluster can be paged through. With `hierarchical`, ids from before markers were added,
+   * updated or removed are no longer found, so cluster the bounds again to get the new ids.
+   */
+  getClusterLeaves = async (clusterId: string, limit = 10, offset = 0): Promise<IMarker[]> => {
+    await this.wasmReady;
+    let leaves: IMarker[] = await this.clusterer.getClusterLeaves(clusterId, limit, offset);
+    leaves.forEach(marker => {
+      if (marker.properties !== undefined) {
+        marker.properties = mapsToObjects(marker.properties);
+      }
+    });
+    return leaves;
+  }
+
+  /**
+   * The clusters that the markers of a cluster from the last call are in at the next zoom.
+   * Doesn't change the calculated clusters.
+   */
+  getClusterChildren = async (clusterId: string): Promise<ICluster[]> => {
+    await this.wasmReady;
+    return restoreMaps(await this.clusterer.getClusterChildren(clusterId));
+  }
+
   /**
    * Add an array of lat/lng markers so that they can be clustered.
    */
@@ -93,6 +221,31 @@ export class WasmMarkerClusterer {
     await this.wasmReady;
     return this.clusterer.addMarkers(markers);
   }
+  /**
+   * Add markers from lat/lng pairs one after another in `coords` (`[lat0, lng0, lat1, lng1, ...]`), with the ids in
+   * `ids` if given. Much faster than `addMarkers` for large numbers of markers, since no objects are read.
+   */
+  addMarkersFromBuffer = async (coords: Float64Array, ids?: Uint32Array): Promise<void> => {
+    await this.wasmReady;
+    return this.clusterer.addMarkersFromBuffer(coords, ids);
+  }
+  /**
+   * Removes the markers with these ids, and removes them from their clusters.
+   * The changed clusters are updated on the next call to `clusterMarkersInBounds`.
+   */
+  removeMarkers = async (ids: (string | number)[]): Promise<void> => {
+    await this.wasmReady;
+    return this.clusterer.removeMarkers(ids);
+  }
+  /**
+   * Moves the markers with the same ids as these to their new positions, also replacing their
+   * `properties` if given. Other clusters are kept as they are.
+   * The changed clusters are updated on the next call to `clusterMarkersInBounds`.
+   */
+  updateMarkers = async (markers: IMarker[]): Promise<void> => {
+    await this.wasmReady;
+    return this.clusterer.updateMarkers(markers);
+  }
   /**
    * Clears all added markers and calculated clusters.
    */
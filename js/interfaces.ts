@@ -1,3 +1,7 @@
+/**
+ * Option names are camelCase, and mapped to the Wasm module's names. Option values that pick one of several choices,
+ * like `"web_mercator"` or `"count_distinct"`, are passed through as they are, so they stay snake_case.
+ */
 export interface IConfig {
   /** 
    * Size of the square (in pixels) that each cluster accumulates markers from.
@@ -21,11 +25,118 @@ export interface IConfig {
    * Default: true
    */
   onlyReturnModifiedClusters?: boolean;
+  /**
+   * Whether to precompute clusters for every zoom level when markers are added, so that changing zoom
+   * doesn't require reclustering. Slower to add markers, but much faster for maps that zoom often.
+   * Adding, updating or removing markers rebuilds every cluster with a new id, so cluster ids from before then
+   * aren't found by `getClusterLeaves`, `getClusterChildren` or `getClusterExpansionZoom`.
+   * Default: false
+   */
+  hierarchical?: boolean;
+  /**
+   * Highest zoom level that `hierarchical` clusters are computed for. Markers are unclustered above it.
+   * Default: 16
+   */
+  maxZoom?: number;
+  /**
+   * Whether markers' `properties` are returned in the markers of each cluster.
+   * Setting to false will save time spent serializing data between Wasm/JS
+   * Default: true
+   */
+  includeProperties?: boolean;
+  /**
+   * Which fields of each cluster are returned. Leaving out markers saves time spent serializing data between Wasm/JS,
+   * and the markers of a cluster can still be fetched with `getClusterLeaves`.
+   * - `markers`: `"all"`, `"ids"` for only the ids of the markers that have one (in `ICluster.ids`),
+   *   `{ sample: n }` for at most `n` markers, or `"none"`. Default: "all"
+   * - `bounds`: whether `ICluster.bounds` is returned. Default: true
+   */
+  output?: { markers?: "all" | "ids" | "none" | { sample: number }; bounds?: boolean };
+  /**
+   * Values to calculate for every cluster from its markers' `properties`, returned in `ICluster.aggregates`.
+   * Default: []
+   */
+  aggregations?: IAggregation[];
+  /**
+   * How markers are grouped into clusters. Not used when `hierarchical` is true.
+   * - `greedy`: MarkerClustererPlus' algorithm, where each marker joins the closest cluster if it's within that cluster's bounds.
+   * - `grid`: each marker joins the cluster of the `gridSize` pixel square of the world that it's in. Doesn't depend on
+   *   the order markers were added in, and clusters keep their ids when the map is panned.
+   * - `dbscan`: clusters are the dense regions found by DBSCAN, with bounds around their markers. Markers outside of
+   *   them are returned in clusters of their own. Clusters are recalculated when new markers come into bounds.
+   * - `kmeans`: the markers within the bounds passed to `clusterMarkersInBounds` are split into `k` clusters, whatever
+   *   the zoom, with bounds around their markers. Clusters are recalculated whenever the bounds change.
+   * Default: "greedy"
+   */
+  algorithm?: "greedy" | "grid" | { dbscan: IDbscan } | { kmeans: IKMeans };
+  /**
+   * Whether a marker joins the cluster closest to it on screen at the current zoom, instead of the closest in kilometres.
+   * These differ at high latitudes, where Web Mercator stretches the map.
+   * Default: true
+   */
+  distanceInPixels?: boolean;
+  /**
+   * Projection of the map, which `gridSize` pixels are measured in. Use `equirectangular` for maps in EPSG:4326,
+   * like Leaflet's `CRS.EPSG4326`.
+   *
+   * Use `planar` for maps with plain x/y coordinates, like Leaflet's `CRS.Simple`, with `lng` as x and `lat` as y.
+   * `scale` is the number of pixels per unit at zoom 0, doubling with each zoom (default 1).
+   * Any finite coordinate is allowed, and clusters' bounds are simply extended by `gridSize`.
+   * Default: "web_mercator"
+   */
+  projection?: "web_mercator" | "equirectangular" | { planar: { scale?: number } };
+  /**
+   * Whether the `greedy` algorithm clusters every marker at once, in an order that only depends on where the markers are
+   * (along a Hilbert curve), so everyone sees the same clusters at a zoom, whatever order markers were added in and
+   * wherever the map was panned. Only clusters with markers in the bounds are returned, but every marker in the world
+   * is clustered again on every zoom change and every time markers are added, updated or removed.
+   * Default: false
+   */
+  deterministic?: boolean;
+}
+
+export interface IDbscan {
+  /** Distance within which markers are neighbours. Default: 40 */
+  eps?: number;
+  /** Whether `eps` is in pixels on screen, or metres on the ground (coordinate units with a `planar` projection). Default: "pixels" */
+  unit?: "pixels" | "metres";
+  /** Neighbours, including itself, that a marker needs to start or grow a cluster. Default: 4 */
+  minPoints?: number;
+}
+
+export interface IKMeans {
+  /** Number of clusters. Fewer are returned if there are fewer markers in bounds. Default: 10 */
+  k?: number;
+  /** Seed for choosing the first centers (by k-means++), so the same markers always give the same clusters. Default: 0 */
+  seed?: number;
+  /** Most rounds of moving the centers, if they haven't settled before. Default: 100 */
+  maxIterations?: number;
+}
+
+export interface IAggregation {
+  /** Key of the result in `ICluster.aggregates` */
+  name: string;
+  /** Key of the value in each marker's `properties` */
+  property: string;
+  /**
+   * `count_distinct` counts the distinct values of any type. The other operations ignore markers
+   * that don't have a numeric value for the property.
+   */
+  operation: "sum" | "min" | "max" | "mean" | "count_distinct";
 }
 
 export interface IMarker {
   lat: number;
   lng: number;
+  /**
+   * Optional id of the marker, which is returned with it in the markers of its cluster.
+   * Ids must be unique: adding a marker with the id of another marker is an error.
+   */
+  id?: string | number;
+  /**
+   * Optional data to keep with the marker, which is returned with it in the markers of its cluster.
+   */
+  properties?: any;
 }
 
 export interface ICluster {
@@ -33,9 +144,42 @@ export interface ICluster {
   size: number;
   center: IMarker;
   bounds?: IBounds;
-  markers: IMarker[];
+  /** Omitted unless `IConfig.output.markers` is `"all"` (the default) or a sample. */
+  markers?: IMarker[];
+  /** Ids of the markers that have one, when `IConfig.output.markers` is `"ids"`. */
+  ids?: (string | number)[];
+  /** Results of `IConfig.aggregations`. Omitted when no marker had a value for an aggregation. */
+  aggregates?: { [name: string]: number };
+}
+
+export interface IClusterDiff {
+  /** Clusters that haven't been returned before */
+  added: ICluster[];
+  /** Clusters that have been returned before, and may have changed since */
+  updated: ICluster[];
+  /** Uuids of returned clusters that no longer exist */
+  removed: string[];
+}
+
+/**
+ * Clusters as columns, with the values for the cluster at an index at that index of each array,
+ * for drawing with WebGL or on a canvas without reading an object per cluster.
+ */
+export interface IClusterColumns {
+  /** Number of clusters */
+  length: number;
+  /** Latitudes of the clusters' centers */
+  lat: Float64Array;
+  /** Longitudes of the clusters' centers */
+  lng: Float64Array;
+  size: Uint32Array;
+  /** The 16 bytes of each cluster's uuid. Use `clusterIdAt` to get one as a string. */
+  uuids: Uint8Array;
+  /** North, east, south and west of each cluster's bounds. Omitted when `IConfig.output.bounds` is false. */
+  bounds?: Float64Array;
 }
 
+/** When `west` is greater than `east`, the bounds cross the antimeridian. */
 export interface IBounds {
   north: number;
   east: number;
@@ -0,0 +1,119 @@
+use std::collections::{ HashMap, HashSet };
+use std::fmt::Debug;
+use uuid::Uuid;
+use structs::{ bounds::Bounds, cluster::Cluster, unique_marker::UniqueMarker };
+use config::Config;
+use error::ClusterError;
+
+/// A way of grouping markers into clusters, used by a `Clusterer` to keep its clusters for the current zoom.
+///
+/// The built in algorithms are chosen with `Config::algorithm`. Others can be set from Rust with
+/// `Clusterer::set_algorithm`. Markers that are in a cluster have `is_added` set.
+pub trait ClusterAlgorithm: CloneAlgorithm + Debug + Send {
+    /// Adds `markers[marker]` to the clusters, and returns the ids of the clusters that changed.
+    fn add(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], marker: usize, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError>;
+
+    /// Clusters every marker with `is_added` set from scratch, replacing `clusters`, and returns the ids of the
+    /// clusters that changed.
+    fn rebuild(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        self.reset();
+        clusters.clear();
+        let mut clusters_modified = HashSet::new();
+        for i in 0..markers.len() {
+            if markers[i].is_added {
+                clusters_modified.extend(self.add(clusters, markers, i, zoom, config)?);
+            }
+        }
+        clusters_modified.extend(clusters.iter().map(|cluster| cluster.uuid));
+        Ok(clusters_modified)
+    }
+
+    /// Adds the markers within `bounds` that aren't in a cluster yet, and returns the ids of the clusters that changed.
+    /// Clusters left with a `size` of 0 are dropped by the caller, keeping the order of the rest.
+    fn cluster_in_bounds(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], bounds: &Bounds, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        let mut clusters_modified = HashSet::new();
+        for i in 0..markers.len() {
+            if !markers[i].is_added && bounds.contains(&markers[i], config.projection.projection()) {
+                markers[i].is_added = true;
+                clusters_modified.extend(self.add(clusters, markers, i, zoom, config)?);
+            }
+        }
+        Ok(clusters_modified)
+    }
+
+    /// Whether `cluster_in_bounds` is passed the bounds extended by `grid_size`, so that clusters at the edges of
+    /// the map include markers just out of view. Otherwise it's passed the bounds as they are.
+    fn extends_bounds(&self) -> bool {
+        true
+    }
+
+    /// Forgets anything kept about the clusters, because they were cleared or changed outside of the algorithm.
+    fn reset(&mut self) {}
+}
+
+/// Lets a boxed `ClusterAlgorithm` be cloned, so it can cluster on the side without changing its state.
+/// Implemented for every algorithm that's `Clone`.
+pub trait CloneAlgorithm {
+    fn clone_box(&self) -> Box<dyn ClusterAlgorithm>;
+}
+
+impl<T: ClusterAlgorithm + Clone + 'static> CloneAlgorithm for T {
+    fn clone_box(&self) -> Box<dyn ClusterAlgorithm> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn ClusterAlgorithm> {
+    fn clone(&self) -> Box<dyn ClusterAlgorithm> {
+        self.clone_box()
+    }
+}
+
+/// Replaces the clusters with `new_clusters`, and returns the ids of the clusters that changed. Where a new cluster
+/// has the same markers as an existing one, it takes the existing cluster's id, and is only returned as changed if its
+/// center or bounds moved. Clusters that no longer exist are left with a `size` of 0 and returned as changed,
+/// to be dropped by the caller.
+pub fn keep_unchanged_clusters(existing_clusters: &mut Vec<Cluster>, new_clusters: Vec<Cluster>) -> HashSet<Uuid> {
+    let key = |cluster: &Cluster| {
+        let mut uuids = cluster.markers.iter().map(UniqueMarker::uuid).collect::<Vec<_>>();
+        uuids.sort();
+        uuids
+    };
+    let mut previous = existing_clusters.drain(..)
+        .map(|cluster| (key(&cluster), cluster))
+        .collect::<HashMap<_, _>>();
+    let mut clusters_modified = HashSet::new();
+    for mut cluster in new_clusters {
+        match previous.remove(&key(&cluster)) {
+            Some(same_markers) => {
+                cluster.uuid = same_markers.uuid;
+                let moved = (cluster.center.lat, cluster.center.lng) != (same_markers.center.lat, same_markers.center.lng);
+                if moved || cluster.bounds != same_markers.bounds {
+                    clusters_modified.insert(cluster.uuid);
+                }
+            },
+            None => {
+                clusters_modified.insert(cluster.uuid);
+            },
+        }
+        existing_clusters.push(cluster);
+    }
+    for (_, mut cluster) in previous {
+        cluster.size = 0;
+        cluster.markers.clear();
+        clusters_modified.insert(cluster.uuid);
+        existing_clusters.push(cluster);
+    }
+    clusters_modified
+}
+
+/// Replaces the clusters with a cluster for each group of indices into `markers`, like `keep_unchanged_clusters`.
+pub fn replace_clusters(existing_clusters: &mut Vec<Cluster>, groups: Vec<Vec<usize>>, markers: &[UniqueMarker], average_center: bool, config: &Config) -> HashSet<Uuid> {
+    let new_clusters = groups.into_iter()
+        .filter_map(|group| {
+            let group_markers = group.iter().map(|&i| markers[i].clone()).collect();
+            Cluster::from_markers(Uuid::new_v4(), group_markers, average_center, config.projection.projection(), &config.aggregations)
+        })
+        .collect();
+    keep_unchanged_clusters(existing_clusters, new_clusters)
+}
@@ -0,0 +1,557 @@
+use std::collections::{ HashMap, HashSet };
+use uuid::Uuid;
+use wasm_bindgen::prelude::*;
+use web_sys::console;
+
+use structs::{ bounds::Bounds, cluster::{ Cluster, ClusterOutput }, cluster_columns::ClusterColumns, cluster_diff::ClusterDiff, marker::Marker, marker_id::MarkerId, unique_marker::UniqueMarker };
+use config::{ Algorithm, Config, OptionalConfig };
+use algorithm::ClusterAlgorithm;
+use hierarchy::ClusterHierarchy;
+use projection::ProjectionKind;
+use utils::bounds::calculate_extended_bounds;
+use error::{ ClusterError, validate_coordinate };
+
+/// A set of markers, the clusters calculated from them, and the config used to cluster them.
+///
+/// Each instance is independent, so a page can cluster several maps at once.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct Clusterer {
+    all_points: Vec<UniqueMarker>,
+    clusters: Vec<Cluster>,
+    algorithm: Box<dyn ClusterAlgorithm>,
+    hierarchy: Option<ClusterHierarchy>,
+    zoom: usize,
+    config: Config,
+    /// Clusters changed by `remove_markers` since the last `clusters_in_bounds`.
+    modified_since_clustering: HashSet<Uuid>,
+    /// Clusters emptied by `remove_markers` since the last `clusters_in_bounds`.
+    removed_since_clustering: Vec<Cluster>,
+    /// Clusters returned by `cluster_diff_in_bounds` that haven't been reported as removed since.
+    returned_uuids: HashSet<Uuid>,
+}
+
+impl Default for Clusterer {
+    fn default() -> Clusterer {
+        let config = Config::default();
+        Clusterer {
+            all_points: Vec::new(),
+            clusters: Vec::new(),
+            algorithm: config.algorithm.create(),
+            hierarchy: None,
+            zoom: 0,
+            config,
+            modified_since_clustering: HashSet::new(),
+            removed_since_clustering: Vec::new(),
+            returned_uuids: HashSet::new(),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Clusterer {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Clusterer {
+        Clusterer::default()
+    }
+
+    pub fn configure(&mut self, config: JsValue) -> Result<(), JsValue> {
+        let new_config: OptionalConfig = serde_wasm_bindgen::from_value(config).map_err(ClusterError::from)?;
+        Ok(self.apply_config(new_config)?)
+    }
+
+    #[wasm_bindgen(js_name = addMarkers)]
+    pub fn add_markers(&mut self, markers_val: JsValue) -> Result<(), JsValue> {
+        let markers: Vec<Marker> = serde_wasm_bindgen::from_value(markers_val).map_err(ClusterError::from)?;
+        Ok(self.push_markers(&markers)?)
+    }
+
+    #[wasm_bindgen(js_name = addMarkersFromBuffer)]
+    pub fn add_markers_from_buffer(&mut self, coords: &[f64], ids: Option<Vec<u32>>) -> Result<(), JsValue> {
+        Ok(self.push_coordinates(coords, ids.as_deref())?)
+    }
+
+    #[wasm_bindgen(js_name = clusterMarkersInBounds)]
+    pub fn cluster_markers_in_bounds(&mut self, bounds_val: JsValue, zoom: usize) -> Result<JsValue, JsValue> {
+        let bounds: Bounds = serde_wasm_bindgen::from_value(bounds_val).map_err(ClusterError::from)?;
+        match self.recluster_in_bounds(&bounds, zoom)? {
+            InBounds::Changed(clusters) => Ok(serde_wasm_bindgen::to_value(&self.output(&clusters))?),
+            InBounds::InView(map_bounds) => Ok(serde_wasm_bindgen::to_value(&self.output(self.in_view(&map_bounds)))?),
+        }
+    }
+
+    #[wasm_bindgen(js_name = clusterDiffInBounds)]
+    pub fn cluster_diff_in_bounds_js(&mut self, bounds_val: JsValue, zoom: usize) -> Result<JsValue, JsValue> {
+        let bounds: Bounds = serde_wasm_bindgen::from_value(bounds_val).map_err(ClusterError::from)?;
+        let diff = self.cluster_diff_in_bounds(&bounds, zoom)?;
+        Ok(serde_wasm_bindgen::to_value(&diff.output(&self.config.output, self.config.include_properties))?)
+    }
+
+    #[wasm_bindgen(js_name = clusterColumnsInBounds)]
+    pub fn cluster_columns_in_bounds_js(&mut self, bounds_val: JsValue, zoom: usize) -> Result<JsValue, JsValue> {
+        let bounds: Bounds = serde_wasm_bindgen::from_value(bounds_val).map_err(ClusterError::from)?;
+        self.cluster_columns_in_bounds(&bounds, zoom)?.to_js()
+    }
+
+    #[wasm_bindgen(js_name = removeMarkers)]
+    pub fn remove_markers_by_id(&mut self, ids_val: JsValue) -> Result<(), JsValue> {
+        let ids: Vec<MarkerId> = serde_wasm_bindgen::from_value(ids_val).map_err(ClusterError::from)?;
+        Ok(self.remove_markers(&ids)?)
+    }
+
+    #[wasm_bindgen(js_name = updateMarkers)]
+    pub fn update_markers_by_id(&mut self, markers_val: JsValue) -> Result<(), JsValue> {
+        let markers: Vec<Marker> = serde_wasm_bindgen::from_value(markers_val).map_err(ClusterError::from)?;
+        Ok(self.update_markers(&markers)?)
+    }
+
+    #[wasm_bindgen(js_name = getClusterExpansionZoom)]
+    pub fn get_cluster_expansion_zoom(&self, cluster_id: &str) -> Result<Option<usize>, JsValue> {
+        Ok(self.cluster_expansion_zoom(parse_cluster_id(cluster_id)?)?)
+    }
+
+    #[wasm_bindgen(js_name = getClusterLeaves)]
+    pub fn get_cluster_leaves(&self, cluster_id: &str, limit: usize, offset: usize) -> Result<JsValue, JsValue> {
+        let leaves = self.cluster_leaves(parse_cluster_id(cluster_id)?, limit, offset)?;
+        Ok(serde_wasm_bindgen::to_value(&leaves)?)
+    }
+
+    #[wasm_bindgen(js_name = getClusterChildren)]
+    pub fn get_cluster_children(&self, cluster_id: &str) -> Result<JsValue, JsValue> {
+        let children = self.cluster_children(parse_cluster_id(cluster_id)?)?;
+        Ok(serde_wasm_bindgen::to_value(&self.output(&children))?)
+    }
+
+    /// Clears all added markers and calculated clusters.
+    pub fn clear(&mut self) {
+        self.hierarchy = None;
+        self.all_points.clear();
+        self.clear_cached_clusters();
+    }
+
+    /// Clears only calculated clusters.
+    #[wasm_bindgen(js_name = clearClusters)]
+    pub fn clear_clusters(&mut self) {
+        self.clear_cached_clusters();
+        self.uncluster_markers();
+    }
+}
+
+impl Clusterer {
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Merges any set options into the existing config. None of them are applied if any is invalid.
+    /// Changing the projection, algorithm, `deterministic`, `grid_size`, `average_center` or aggregations clears calculated
+    /// clusters, since they were made with the old one.
+    pub fn apply_config(&mut self, options: OptionalConfig) -> Result<(), ClusterError> {
+        if let Some(Algorithm::KMeans(kmeans)) = &options.algorithm {
+            kmeans.validate()?;
+        }
+        if let Some(ProjectionKind::Planar(planar)) = &options.projection {
+            planar.validate()?;
+        }
+        let projection_changed = options.projection.as_ref().is_some_and(|projection| *projection != self.config.projection);
+        let algorithm_changed = options.algorithm.is_some_and(|algorithm| algorithm != self.config.algorithm);
+        let order_changed = options.deterministic.is_some_and(|deterministic| deterministic != self.config.deterministic);
+        let aggregations_changed = options.aggregations.as_ref().is_some_and(|aggregations| *aggregations != self.config.aggregations);
+        let grid_changed = options.grid_size.is_some_and(|grid_size| grid_size != self.config.grid_size) ||
+            options.average_center.is_some_and(|average_center| average_center != self.config.average_center);
+        // The hierarchy is only built from these, and isn't kept up to date while it's not used
+        let hierarchy_changed = projection_changed || aggregations_changed || grid_changed ||
+            options.max_zoom.is_some_and(|max_zoom| max_zoom != self.config.max_zoom) ||
+            options.hierarchical.is_some_and(|hierarchical| hierarchical != self.config.hierarchical);
+        self.config.apply_options(options);
+        if hierarchy_changed {
+            self.hierarchy = None;
+        }
+        if algorithm_changed {
+            self.algorithm = self.config.algorithm.create();
+        }
+        if projection_changed || algorithm_changed || order_changed || aggregations_changed || grid_changed {
+            self.clear_clusters();
+        }
+        if projection_changed {
+            for marker in self.all_points.iter_mut() {
+                marker.clear_pixel();
+            }
+        }
+        Ok(())
+    }
+
+    /// Clusters with an algorithm implemented outside of this crate, instead of `Config::algorithm`, until the
+    /// algorithm is next configured. Calculated clusters are cleared.
+    pub fn set_algorithm(&mut self, algorithm: Box<dyn ClusterAlgorithm>) {
+        self.algorithm = algorithm;
+        self.hierarchy = None;
+        self.clear_clusters();
+    }
+
+    /// Adds markers to be clustered. None of them are added if any has an invalid coordinate, or an id that another
+    /// marker has.
+    pub fn push_markers(&mut self, markers: &[Marker]) -> Result<(), ClusterError> {
+        for marker in markers {
+            validate_coordinate(marker.lat, marker.lng, self.config.projection.projection())?;
+        }
+        self.append_markers(markers.iter().map(UniqueMarker::from).collect())
+    }
+
+    /// Adds markers from lat/lng pairs one after another in `coords`, like the contents of a `Float64Array`,
+    /// with the ids in `ids` if given. None of them are added if any has an invalid coordinate, or an id that another
+    /// marker has.
+    pub fn push_coordinates(&mut self, coords: &[f64], ids: Option<&[u32]>) -> Result<(), ClusterError> {
+        if !coords.len().is_multiple_of(2) {
+            return Err(ClusterError::InvalidInput(format!("{} coordinates aren't lat/lng pairs", coords.len())));
+        }
+        if let Some(ids) = ids {
+            if ids.len() != coords.len() / 2 {
+                return Err(ClusterError::InvalidInput(format!("{} ids for {} markers", ids.len(), coords.len() / 2)));
+            }
+        }
+        let projection = self.config.projection.projection();
+        let markers = coords.chunks_exact(2).enumerate().map(|(i, pair)| {
+            validate_coordinate(pair[0], pair[1], projection)?;
+            let id = ids.map(|ids| MarkerId::Number(f64::from(ids[i])));
+            Ok(UniqueMarker::from(&Marker { lat: pair[0], lng: pair[1], id, properties: None }))
+        }).collect::<Result<Vec<_>, ClusterError>>()?;
+        self.append_markers(markers)
+    }
+
+    fn append_markers(&mut self, mut markers: Vec<UniqueMarker>) -> Result<(), ClusterError> {
+        if markers.iter().any(|marker| marker.id.is_some()) {
+            let mut ids = self.all_points.iter().filter_map(|marker| marker.id.as_ref()).collect::<HashSet<_>>();
+            for id in markers.iter().filter_map(|marker| marker.id.as_ref()) {
+                if !ids.insert(id) {
+                    return Err(ClusterError::DuplicateMarkerId(id.clone()));
+                }
+            }
+        }
+        // TODO see if .extend() is faster/better than .append() ?
+        self.all_points.append(&mut markers);
+        // Built again when next used, so adding markers in batches doesn't build it for each batch
+        self.hierarchy = None;
+        Ok(())
+    }
+
+    /// Removes the markers with any of these ids, and removes them from their clusters.
+    /// Clusters left without markers are dropped.
+    ///
+    /// With `only_return_modified_clusters`, the next call to `clusters_in_bounds` returns the changed clusters,
+    /// and the dropped clusters with a `size` of 0.
+    pub fn remove_markers(&mut self, ids: &[MarkerId]) -> Result<(), ClusterError> {
+        let ids = ids.iter().cloned().collect::<HashSet<_>>();
+        self.all_points.retain(|marker| !marker.has_id_in(&ids));
+        self.detach_from_clusters(&ids)
+    }
+
+    /// Moves the markers with the same ids as these to their new positions, also replacing their
+    /// `properties` if these have any. Markers without an id are ignored.
+    ///
+    /// Moved markers are taken out of their clusters, and are added to the closest cluster to their new position
+    /// the next time it's in the bounds passed to `clusters_in_bounds`. Other clusters are kept as they are.
+    pub fn update_markers(&mut self, updates: &[Marker]) -> Result<(), ClusterError> {
+        for marker in updates {
+            validate_coordinate(marker.lat, marker.lng, self.config.projection.projection())?;
+        }
+        let updates = updates.iter()
+            .filter_map(|marker| marker.id.clone().map(|id| (id, marker)))
+            .collect::<HashMap<_, _>>();
+
+        for marker in self.all_points.iter_mut() {
+            if let Some(update) = marker.id.as_ref().and_then(|id| updates.get(id)) {
+                marker.move_to(update.lat, update.lng);
+                if update.properties.is_some() {
+                    marker.properties = update.properties.clone();
+                }
+                marker.is_added = false;
+            }
+        }
+        self.detach_from_clusters(&updates.keys().cloned().collect())
+    }
+
+    /// Takes the markers with any of these ids out of their clusters, dropping any clusters left without markers.
+    fn detach_from_clusters(&mut self, ids: &HashSet<MarkerId>) -> Result<(), ClusterError> {
+        for cluster in self.clusters.iter_mut() {
+            if cluster.remove_markers(ids, self.zoom, self.config.average_center, self.config.grid_size, self.config.projection.projection())? {
+                self.modified_since_clustering.insert(cluster.uuid);
+            }
+        }
+        self.drop_empty_clusters();
+        // Centers may have moved and clusters shifted position, so the index is rebuilt when next used
+        self.algorithm.reset();
+
+        if self.config.hierarchical {
+            self.hierarchy = None;
+        }
+        Ok(())
+    }
+
+    /// Moves clusters left without markers to `removed_since_clustering`.
+    fn drop_empty_clusters(&mut self) {
+        if self.clusters.iter().any(|cluster| cluster.size == 0) {
+            let (removed, kept) = self.clusters.drain(..).partition(|cluster| cluster.size == 0);
+            self.clusters = kept;
+            self.removed_since_clustering.extend::<Vec<_>>(removed);
+        }
+    }
+
+    /// The lowest zoom above the current one at which the markers of the cluster with this id split into more than
+    /// one cluster under the current config, or `None` if they're still one cluster at `max_zoom`. In `hierarchical`
+    /// mode, markers are unclustered above `max_zoom`, so only a single marker gives `None`.
+    ///
+    /// The markers are clustered on their own with a copy of the algorithm, so the clusters kept for the current
+    /// zoom aren't changed.
+    pub fn cluster_expansion_zoom(&self, uuid: Uuid) -> Result<Option<usize>, ClusterError> {
+        if self.config.hierarchical {
+            return self.hierarchy.as_ref()
+                .and_then(|hierarchy| hierarchy.expansion_zoom(uuid))
+                .ok_or(ClusterError::ClusterNotFound(uuid));
+        }
+        let mut algorithm = self.algorithm.clone();
+        let mut markers = self.cached_cluster(uuid)?.markers.clone();
+        for zoom in self.zoom + 1..=self.config.max_zoom {
+            if recluster_on_the_side(algorithm.as_mut(), &mut markers, zoom, &self.config)?.len() > 1 {
+                return Ok(Some(zoom));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Up to `limit` of the markers in the cluster with this id at the current zoom, skipping the first `offset`,
+    /// so the markers of a large cluster can be paged through.
+    pub fn cluster_leaves(&self, uuid: Uuid, limit: usize, offset: usize) -> Result<Vec<UniqueMarker>, ClusterError> {
+        let mut leaves = if self.config.hierarchical {
+            let indices = self.hierarchy.as_ref()
+                .and_then(|hierarchy| hierarchy.leaves(uuid, self.zoom))
+                .ok_or(ClusterError::ClusterNotFound(uuid))?;
+            indices.into_iter().skip(offset).take(limit).map(|i| self.all_points[i].clone()).collect::<Vec<_>>()
+        } else {
+            self.cached_cluster(uuid)?.markers.iter().skip(offset).take(limit).cloned().collect()
+        };
+        if !self.config.include_properties {
+            for marker in leaves.iter_mut() {
+                marker.properties = None;
+            }
+        }
+        Ok(leaves)
+    }
+
+    /// The clusters that the markers of the cluster with this id at the current zoom are in at the next zoom.
+    /// Like `cluster_expansion_zoom`, this doesn't change the clusters kept for the current zoom.
+    pub fn cluster_children(&self, uuid: Uuid) -> Result<Vec<Cluster>, ClusterError> {
+        let children = if self.config.hierarchical {
+            match &self.hierarchy {
+                Some(hierarchy) => hierarchy.children(&self.all_points, uuid, self.zoom, self.config.output.markers, self.config.include_properties, self.config.projection.projection())?,
+                None => None,
+            }.ok_or(ClusterError::ClusterNotFound(uuid))?
+        } else {
+            let mut markers = self.cached_cluster(uuid)?.markers.clone();
+            recluster_on_the_side(self.algorithm.clone().as_mut(), &mut markers, self.zoom + 1, &self.config)?
+        };
+        Ok(self.prepare_output(children))
+    }
+
+    fn cached_cluster(&self, uuid: Uuid) -> Result<&Cluster, ClusterError> {
+        self.clusters.iter().find(|cluster| cluster.uuid == uuid).ok_or(ClusterError::ClusterNotFound(uuid))
+    }
+
+    /// Calculates clusters for the markers within the given bounds, reusing any clusters already
+    /// calculated at this zoom. With `only_return_modified_clusters`, only returns the new or changed clusters.
+    pub fn clusters_in_bounds(&mut self, bounds: &Bounds, zoom: usize) -> Result<Vec<Cluster>, ClusterError> {
+        let vec = match self.recluster_in_bounds(bounds, zoom)? {
+            InBounds::Changed(clusters) => clusters,
+            InBounds::InView(map_bounds) => self.in_view(&map_bounds).cloned().collect(),
+        };
+        Ok(self.prepare_output(vec))
+    }
+
+    /// Reclusters like `clusters_in_bounds`, leaving the clusters kept for this zoom to be borrowed if they're all
+    /// returned, so they're only copied as far as the caller needs.
+    fn recluster_in_bounds(&mut self, bounds: &Bounds, zoom: usize) -> Result<InBounds, ClusterError> {
+        let changed = self.recluster(bounds, zoom)?;
+        let removed = self.removed_since_clustering.drain(..).collect::<Vec<_>>();
+
+        if self.config.hierarchical || self.config.only_return_modified_clusters {
+            Ok(InBounds::Changed(changed.into_iter().chain(removed).collect()))
+        } else {
+            Ok(InBounds::InView(self.clustering_bounds(bounds, zoom)?))
+        }
+    }
+
+    /// The clusters kept for this zoom that are in view of the bounds they were clustered in.
+    fn in_view<'a>(&'a self, map_bounds: &'a Bounds) -> impl Iterator<Item = &'a Cluster> + 'a {
+        self.clusters.iter().filter(move |cluster| self.is_in_view(cluster, map_bounds))
+    }
+
+    /// Calculates clusters for the markers within the given bounds like `clusters_in_bounds`, but returns what
+    /// changed since the last call: the new and changed clusters, and the ids of the clusters that no longer exist
+    /// because of a zoom change, `clear_clusters` or removed markers.
+    ///
+    /// In `hierarchical` mode, only the clusters in bounds are kept, so any others are reported as removed.
+    pub fn cluster_diff_in_bounds(&mut self, bounds: &Bounds, zoom: usize) -> Result<ClusterDiff, ClusterError> {
+        let changed = self.recluster(bounds, zoom)?;
+        self.removed_since_clustering.clear();
+
+        let current = if self.config.hierarchical { &changed } else { &self.clusters }
+            .iter()
+            .map(|cluster| cluster.uuid)
+            .collect::<HashSet<_>>();
+        let removed = self.returned_uuids.iter()
+            .filter(|uuid| !current.contains(uuid))
+            .cloned()
+            .collect::<Vec<_>>();
+        let (updated, added): (Vec<_>, Vec<_>) = self.prepare_output(changed)
+            .into_iter()
+            .partition(|cluster| self.returned_uuids.contains(&cluster.uuid));
+
+        for uuid in &removed {
+            self.returned_uuids.remove(uuid);
+        }
+        self.returned_uuids.extend(added.iter().map(|cluster| cluster.uuid));
+        Ok(ClusterDiff { added, updated, removed })
+    }
+
+    /// Calculates clusters for the markers within the given bounds like `clusters_in_bounds`, and returns every
+    /// current cluster (in `hierarchical` mode, every cluster in the bounds) as columns, read straight from the
+    /// clusters kept for this zoom. Bounds are included if `Config::output` includes them.
+    pub fn cluster_columns_in_bounds(&mut self, bounds: &Bounds, zoom: usize) -> Result<ClusterColumns, ClusterError> {
+        let changed = self.recluster(bounds, zoom)?;
+        self.removed_since_clustering.clear();
+
+        let map_bounds = self.clustering_bounds(bounds, zoom)?;
+        let clusters = if self.config.hierarchical {
+            changed.iter().collect::<Vec<_>>()
+        } else {
+            self.in_view(&map_bounds).collect()
+        };
+        Ok(ClusterColumns::new(&clusters, self.config.output.bounds))
+    }
+
+    /// Clusters the markers within the bounds that aren't yet clustered, and returns the clusters created or changed
+    /// since the last call. In `hierarchical` mode, that's every cluster in the bounds.
+    fn recluster(&mut self, bounds: &Bounds, zoom: usize) -> Result<Vec<Cluster>, ClusterError> {
+        validate_coordinate(bounds.north, bounds.east, self.config.projection.projection())?;
+        validate_coordinate(bounds.south, bounds.west, self.config.projection.projection())?;
+        let map_bounds = self.clustering_bounds(bounds, zoom)?;
+        if self.config.log_time {
+            console::time_with_label("clustering");
+        }
+        let vec = if self.config.hierarchical {
+            self.cluster_hierarchy_in_bounds(&map_bounds, zoom)?
+        } else {
+            self.cluster_cached_in_bounds(&map_bounds, zoom)?
+        };
+        if self.config.log_time {
+            console::time_end_with_label("clustering");
+        }
+        Ok(vec)
+    }
+
+    /// The bounds that markers are clustered in for the map's bounds: extended by `grid_size`, unless the algorithm
+    /// only clusters the markers actually in view.
+    fn clustering_bounds(&self, bounds: &Bounds, zoom: usize) -> Result<Bounds, ClusterError> {
+        if self.config.hierarchical || self.algorithm.extends_bounds() {
+            calculate_extended_bounds(bounds, zoom, self.config.grid_size, self.config.projection.projection())
+        } else {
+            Ok(bounds.clone())
+        }
+    }
+
+    /// Whether the cluster is returned for these clustering bounds. With `deterministic` greedy clustering, every
+    /// marker is clustered, so only clusters with markers in the bounds are.
+    fn is_in_view(&self, cluster: &Cluster, map_bounds: &Bounds) -> bool {
+        !self.config.deterministic || self.config.algorithm != Algorithm::Greedy ||
+            cluster.markers.iter().any(|marker| map_bounds.contains(marker, self.config.projection.projection()))
+    }
+
+    /// Adds the markers within the bounds that aren't yet clustered to the clusters kept for this zoom.
+    /// Changed clusters that aren't in view are returned once they are.
+    fn cluster_cached_in_bounds(&mut self, map_bounds: &Bounds, zoom: usize) -> Result<Vec<Cluster>, ClusterError> {
+        self.set_zoom(zoom);
+        let mut uuids_modified = self.algorithm.cluster_in_bounds(&mut self.clusters, &mut self.all_points, map_bounds, zoom, &self.config)?;
+        self.drop_empty_clusters();
+        uuids_modified.extend(self.modified_since_clustering.drain());
+
+        let (in_view, out_of_view): (Vec<_>, Vec<_>) = self.clusters.iter()
+            .filter(|c| uuids_modified.contains(&c.uuid))
+            .partition(|c| self.is_in_view(c, map_bounds));
+        self.modified_since_clustering.extend(out_of_view.iter().map(|c| c.uuid));
+        Ok(in_view.into_iter().cloned().collect())
+    }
+
+    /// Removes anything from the clusters that the config says not to return.
+    fn prepare_output(&self, mut clusters: Vec<Cluster>) -> Vec<Cluster> {
+        if !self.config.include_properties {
+            for marker in clusters.iter_mut().flat_map(|cluster| cluster.markers.iter_mut()) {
+                marker.properties = None;
+            }
+        }
+        clusters
+    }
+
+    /// The fields of the clusters that `Config::output` says to send to JS.
+    fn output<'a>(&self, clusters: impl IntoIterator<Item = &'a Cluster>) -> Vec<ClusterOutput<'a>> {
+        clusters.into_iter().map(|cluster| cluster.output(&self.config.output, self.config.include_properties)).collect()
+    }
+
+    fn clear_cached_clusters(&mut self) {
+        self.clusters.clear();
+        self.algorithm.reset();
+        self.modified_since_clustering.clear();
+        self.removed_since_clustering.clear();
+    }
+
+    /// Looks up the precomputed clusters, building the hierarchy first if markers or config changed.
+    /// Every cluster in bounds is returned, since their ids are stable while the hierarchy is unchanged.
+    fn cluster_hierarchy_in_bounds(&mut self, map_bounds: &Bounds, zoom: usize) -> Result<Vec<Cluster>, ClusterError> {
+        self.set_zoom(zoom);
+        if self.hierarchy.is_none() {
+            self.hierarchy = Some(ClusterHierarchy::new(&self.all_points, self.config.max_zoom, self.config.grid_size, self.config.average_center, &self.config.aggregations, self.config.projection.projection())?);
+        }
+        match &self.hierarchy {
+            Some(hierarchy) => hierarchy.clusters(&self.all_points, map_bounds, zoom, self.config.output.markers, self.config.include_properties, self.config.projection.projection()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Clears the clusters kept for the previous zoom if the zoom changed.
+    fn set_zoom(&mut self, zoom: usize) {
+        if self.zoom != zoom {
+            self.zoom = zoom;
+            self.clear_cached_clusters();
+            self.uncluster_markers();
+        }
+    }
+
+    fn uncluster_markers(&mut self) {
+        for marker in self.all_points.iter_mut() {
+            marker.is_added = false;
+        }
+    }
+}
+
+/// The clusters that `clusters_in_bounds` returns.
+enum InBounds {
+    /// The changed and removed clusters, with `only_return_modified_clusters` or in `hierarchical` mode.
+    Changed(Vec<Cluster>),
+    /// Every cluster kept for this zoom that's in view of these bounds.
+    InView(Bounds),
+}
+
+/// Clusters the markers on their own at `zoom` with the algorithm, which should be a copy of the one in use,
+/// and returns the clusters.
+fn recluster_on_the_side(algorithm: &mut dyn ClusterAlgorithm, markers: &mut [UniqueMarker], zoom: usize, config: &Config) -> Result<Vec<Cluster>, ClusterError> {
+    for marker in markers.iter_mut() {
+        marker.is_added = true;
+    }
+    let mut clusters = Vec::new();
+    algorithm.reset();
+    algorithm.rebuild(&mut clusters, markers, zoom, config)?;
+    clusters.retain(|cluster| cluster.size > 0);
+    Ok(clusters)
+}
+
+fn parse_cluster_id(cluster_id: &str) -> Result<Uuid, ClusterError> {
+    Uuid::parse_str(cluster_id).map_err(|error| ClusterError::InvalidInput(format!("{} is not a cluster id: {}", cluster_id, error)))
+}
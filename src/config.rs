@@ -1,3 +1,69 @@
+use structs::aggregation::Aggregation;
+use projection::ProjectionKind;
+use dbscan::{ Dbscan, DbscanAlgorithm };
+use kmeans::{ KMeans, KMeansAlgorithm };
+use algorithm::ClusterAlgorithm;
+use greedy::Greedy;
+use grid::Grid;
+
+/// How markers are grouped into clusters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Algorithm {
+  /// MarkerClustererPlus' algorithm, where each marker joins the closest cluster if it's within that cluster's bounds.
+  #[default]
+  Greedy,
+  /// Each marker joins the cluster of the `grid_size` pixel cell of the world that it's in.
+  Grid,
+  /// Clusters are the dense regions of markers found by DBSCAN, and the other markers are on their own.
+  Dbscan(Dbscan),
+  /// The markers within the bounds are split into `k` clusters by k-means.
+  #[serde(rename = "kmeans")]
+  KMeans(KMeans),
+}
+
+impl Algorithm {
+  /// A new instance of the algorithm, without any state from previous clustering.
+  pub fn create(&self) -> Box<dyn ClusterAlgorithm> {
+    match self {
+      Algorithm::Greedy => Box::new(Greedy::default()),
+      Algorithm::Grid => Box::new(Grid::default()),
+      Algorithm::Dbscan(dbscan) => Box::new(DbscanAlgorithm::new(*dbscan)),
+      Algorithm::KMeans(kmeans) => Box::new(KMeansAlgorithm::new(*kmeans)),
+    }
+  }
+}
+
+/// Which markers of a cluster are returned with it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkerOutput {
+  #[default]
+  All,
+  /// Only the ids of the markers that have one, as `ids`.
+  Ids,
+  /// At most this many of the markers.
+  Sample(usize),
+  /// No markers, only the `size`.
+  None,
+}
+
+/// Which fields of the clusters are sent to JS. The clusters kept in Rust always have all of them, so the rest
+/// can be fetched later, e.g. with `Clusterer::cluster_leaves`. In `hierarchical` mode, clusters are built for each
+/// query with only the markers that are sent.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Output {
+  pub markers: MarkerOutput,
+  pub bounds: bool,
+}
+
+impl Default for Output {
+  fn default() -> Output {
+    Output { markers: MarkerOutput::All, bounds: true }
+  }
+}
+
 #[derive(Debug, OptionalStruct)]
 #[optional_derive(Deserialize)]
 pub struct Config {
@@ -5,6 +71,31 @@ pub struct Config {
   pub average_center: bool,
   pub log_time: bool,
   pub only_return_modified_clusters: bool,
+  /// Precompute clusters for every zoom up to `max_zoom` when markers are added,
+  /// instead of clustering on demand for the requested zoom and bounds. Any change to the markers rebuilds every
+  /// cluster with a new id, so ids from before it are no longer found.
+  pub hierarchical: bool,
+  /// Highest zoom that `hierarchical` clusters are built for, and that cluster expansion zooms are looked for up to.
+  /// `hierarchical` markers are unclustered above it.
+  pub max_zoom: usize,
+  /// Whether markers' `properties` are included in the returned clusters.
+  pub include_properties: bool,
+  /// Values to calculate for every cluster from its markers' properties.
+  pub aggregations: Vec<Aggregation>,
+  /// Whether the closest cluster to a marker is the closest on screen at the current zoom, instead of
+  /// the closest in the projection's own distance (kilometres on a globe).
+  pub distance_in_pixels: bool,
+  /// How markers are grouped into clusters. Not used by `hierarchical` clustering.
+  pub algorithm: Algorithm,
+  /// How lat/lngs are converted to the pixels that `grid_size` is measured in.
+  pub projection: ProjectionKind,
+  /// Whether the greedy algorithm clusters every marker at once, in an order that only depends on where they are,
+  /// so the clusters at a zoom are the same whatever order markers were added in and wherever the map was panned.
+  /// Only clusters with markers in the bounds are returned, but every marker in the world is clustered again on
+  /// every zoom change and every time markers are added, updated or removed. Not used by the other algorithms.
+  pub deterministic: bool,
+  /// Which fields of the clusters are returned to JS.
+  pub output: Output,
 }
 
 impl Default for Config {
@@ -14,6 +105,15 @@ impl Default for Config {
       average_center: false,
       log_time: false,
       only_return_modified_clusters: true,
+      hierarchical: false,
+      max_zoom: 16,
+      include_properties: true,
+      aggregations: Vec::new(),
+      distance_in_pixels: true,
+      algorithm: Algorithm::default(),
+      projection: ProjectionKind::default(),
+      deterministic: false,
+      output: Output::default(),
     }
   }
 }
\ No newline at end of file
@@ -0,0 +1,198 @@
+use std::collections::{ HashSet, VecDeque };
+use std::f64::consts::PI;
+use uuid::Uuid;
+use structs::{ bounds::Bounds, cluster::Cluster, kd_tree::KdTree, marker::Marker, unique_marker::UniqueMarker };
+use config::Config;
+use algorithm::{ ClusterAlgorithm, replace_clusters };
+use projection::{ distance_between_markers, pixel_distance };
+use error::ClusterError;
+
+/// Kilometres per degree of latitude.
+const KM_PER_DEGREE: f64 = 6371.0 * PI / 180.0;
+
+/// What the `eps` of `Dbscan` is measured in.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DistanceUnit {
+    /// Pixels on screen at the zoom being clustered.
+    Pixels,
+    /// Metres on the ground, or coordinate units in a planar projection.
+    Metres,
+}
+
+/// Options of `Algorithm::Dbscan`.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Dbscan {
+    /// Distance within which markers are neighbours.
+    pub eps: f64,
+    pub unit: DistanceUnit,
+    /// Neighbours, including itself, that a marker needs to start or grow a cluster.
+    pub min_points: usize,
+}
+
+impl Default for Dbscan {
+    fn default() -> Dbscan {
+        Dbscan { eps: 40.0, unit: DistanceUnit::Pixels, min_points: 4 }
+    }
+}
+
+/// Clusters the markers in `map_bounds`, and any clustered before at this zoom, into the dense regions found by
+/// DBSCAN. Markers not in a dense region (noise) get a cluster of their own. Clusters are centered like
+/// `Cluster::from_markers`, with bounds around their markers.
+///
+/// Every cluster is recalculated when new markers come into bounds, since they can join or bridge clusters,
+/// and the new clusters replace the existing ones like `replace_clusters`.
+pub fn cluster_markers_dbscan(existing_clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], map_bounds: &Bounds, zoom: usize, config: &Config, dbscan: &Dbscan) -> Result<HashSet<Uuid>, ClusterError> {
+    if !add_markers_in_bounds(markers, map_bounds, config) {
+        return Ok(HashSet::new());
+    }
+
+    cluster_added_markers(existing_clusters, markers, zoom, config, dbscan)
+}
+
+/// Sets `is_added` on the markers in `map_bounds` that aren't in a cluster yet, and returns whether there were any.
+fn add_markers_in_bounds(markers: &mut [UniqueMarker], map_bounds: &Bounds, config: &Config) -> bool {
+    let mut has_new_markers = false;
+    for point in markers.iter_mut() {
+        if !point.is_added && map_bounds.contains(point, config.projection.projection()) {
+            point.is_added = true;
+            has_new_markers = true;
+        }
+    }
+    has_new_markers
+}
+
+/// Clusters every marker with `is_added` set, replacing the existing clusters.
+fn cluster_added_markers(existing_clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], zoom: usize, config: &Config, dbscan: &Dbscan) -> Result<HashSet<Uuid>, ClusterError> {
+    let added = markers.iter().enumerate().filter(|(_, m)| m.is_added).map(|(i, _)| i).collect::<Vec<_>>();
+    let groups = dbscan_groups(markers, &added, zoom, config, dbscan)?;
+    Ok(replace_clusters(existing_clusters, groups, markers, config.average_center, config))
+}
+
+/// Clusters with `Dbscan` options like `cluster_markers_dbscan`, and also reclusters after the clusters were
+/// changed outside of it, since removing a marker can split a cluster.
+#[derive(Debug, Clone, Copy)]
+pub struct DbscanAlgorithm {
+    pub options: Dbscan,
+    stale: bool,
+}
+
+impl DbscanAlgorithm {
+    pub fn new(options: Dbscan) -> DbscanAlgorithm {
+        DbscanAlgorithm { options, stale: false }
+    }
+}
+
+impl ClusterAlgorithm for DbscanAlgorithm {
+    /// Reclusters every marker, since the new one can join or bridge clusters.
+    fn add(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], marker: usize, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        markers[marker].is_added = true;
+        self.rebuild(clusters, markers, zoom, config)
+    }
+
+    fn rebuild(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        self.stale = false;
+        cluster_added_markers(clusters, markers, zoom, config, &self.options)
+    }
+
+    fn cluster_in_bounds(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], bounds: &Bounds, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        if !add_markers_in_bounds(markers, bounds, config) && !self.stale {
+            return Ok(HashSet::new());
+        }
+        self.rebuild(clusters, markers, zoom, config)
+    }
+
+    fn reset(&mut self) {
+        self.stale = true;
+    }
+}
+
+/// Groups of indices into `markers` for each dense region, then for each noise marker on its own, out of the markers
+/// at the `candidates` indices. Neighbours are found with a KD-tree, so this takes O(n log n) for well spread out markers.
+fn dbscan_groups(markers: &mut [UniqueMarker], candidates: &[usize], zoom: usize, config: &Config, dbscan: &Dbscan) -> Result<Vec<Vec<usize>>, ClusterError> {
+    let projection = config.projection.projection();
+    let geographic = projection.is_geographic();
+    let in_pixels = dbscan.unit == DistanceUnit::Pixels;
+
+    let points = candidates.iter().map(|&i| {
+        if in_pixels {
+            markers[i].pixel(zoom, projection)
+        } else if geographic {
+            Ok((markers[i].lng + 180.0, markers[i].lat))
+        } else {
+            Ok((markers[i].lng, markers[i].lat))
+        }
+    }).collect::<Result<Vec<_>, ClusterError>>()?;
+    let tree = KdTree::new(&points);
+    let world_width = match (geographic, in_pixels) {
+        (false, _) => None,
+        (true, true) => Some(projection.world_size(zoom).0),
+        (true, false) => Some(360.0),
+    };
+
+    let neighbours = |p: usize| -> Vec<usize> {
+        let (x, y) = points[p];
+        let (rx, ry) = if geographic && !in_pixels {
+            // Degrees of longitude shrink towards the poles, so the box is widened for the latitude closest to one
+            let ry = dbscan.eps / 1000.0 / KM_PER_DEGREE;
+            let cos = (y.abs() + ry).min(90.0).to_radians().cos();
+            (if cos > 0.0 { (ry / cos).min(360.0) } else { 360.0 }, ry)
+        } else {
+            (dbscan.eps, dbscan.eps)
+        };
+        let from = Marker { lat: markers[candidates[p]].lat, lng: markers[candidates[p]].lng, id: None, properties: None };
+        tree.wrapped_range(x - rx, y - ry, x + rx, y + ry, world_width).into_iter().filter(|&q| {
+            let distance = if in_pixels {
+                pixel_distance(points[p], points[q], zoom, projection)
+            } else if geographic {
+                distance_between_markers(&from, &markers[candidates[q]]) * 1000.0
+            } else {
+                projection.distance(&from, &markers[candidates[q]])
+            };
+            distance <= dbscan.eps
+        }).collect()
+    };
+
+    // `None` until visited, then the group the point is in, if any
+    let mut labels: Vec<Option<Option<usize>>> = vec![None; candidates.len()];
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for p in 0..candidates.len() {
+        if labels[p].is_some() {
+            continue;
+        }
+        let found = neighbours(p);
+        if found.len() < dbscan.min_points {
+            labels[p] = Some(None);
+            continue;
+        }
+
+        let group = groups.len();
+        groups.push(Vec::new());
+        labels[p] = Some(Some(group));
+        let mut queue = found.into_iter().collect::<VecDeque<_>>();
+        while let Some(q) = queue.pop_front() {
+            match labels[q] {
+                Some(Some(_)) => continue,
+                // Noise within reach of a core point is on the border of its group, but doesn't extend it
+                Some(None) => {
+                    labels[q] = Some(Some(group));
+                    continue;
+                },
+                None => labels[q] = Some(Some(group)),
+            }
+            let found = neighbours(q);
+            if found.len() >= dbscan.min_points {
+                queue.extend(found);
+            }
+        }
+    }
+
+    for (p, label) in labels.into_iter().enumerate() {
+        match label {
+            Some(Some(group)) => groups[group].push(candidates[p]),
+            _ => groups.push(vec![candidates[p]]),
+        }
+    }
+    Ok(groups)
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::sync::PoisonError;
+use uuid::Uuid;
+use wasm_bindgen::JsValue;
+use projection::Projection;
+use structs::marker_id::MarkerId;
+
+/// Errors returned to JS instead of panicking, which would abort the whole Wasm module.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClusterError {
+    /// A JS value didn't have the shape of a marker, bounds or config object.
+    Deserialization(String),
+    /// Input that doesn't have the expected shape, like a coordinate buffer that isn't made of lat/lng pairs.
+    InvalidInput(String),
+    /// A lat/lng that is not a number, or is outside of ±90°/±180° in a geographic projection.
+    InvalidCoordinate { lat: f64, lng: f64 },
+    /// The projection can't convert coordinates at this zoom.
+    ProjectionOutOfRange { zoom: usize },
+    /// A marker was added with the id of a marker already added, or of another marker in the same batch.
+    DuplicateMarkerId(MarkerId),
+    /// No cluster has this id at the current zoom.
+    ClusterNotFound(Uuid),
+    /// A previous call panicked while it held the clusterer's state. The state has been reset.
+    PoisonedState,
+}
+
+impl fmt::Display for ClusterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClusterError::Deserialization(message) => write!(f, "Could not deserialize value: {}", message),
+            ClusterError::InvalidInput(message) => write!(f, "Invalid input: {}", message),
+            ClusterError::InvalidCoordinate { lat, lng } => write!(f, "Invalid coordinate: lat {}, lng {}", lat, lng),
+            ClusterError::ProjectionOutOfRange { zoom } => write!(f, "Zoom {} is outside the range of the projection", zoom),
+            ClusterError::DuplicateMarkerId(id) => write!(f, "A marker with id {} was already added", id),
+            ClusterError::ClusterNotFound(uuid) => write!(f, "No cluster with id {} at the current zoom", uuid),
+            ClusterError::PoisonedState => write!(f, "Clusterer state was poisoned by an earlier panic and has been reset"),
+        }
+    }
+}
+
+impl std::error::Error for ClusterError {}
+
+impl From<serde_wasm_bindgen::Error> for ClusterError {
+    fn from(error: serde_wasm_bindgen::Error) -> Self {
+        ClusterError::Deserialization(error.to_string())
+    }
+}
+
+impl<T> From<PoisonError<T>> for ClusterError {
+    fn from(_: PoisonError<T>) -> Self {
+        ClusterError::PoisonedState
+    }
+}
+
+impl From<ClusterError> for JsValue {
+    fn from(error: ClusterError) -> Self {
+        js_sys::Error::new(&error.to_string()).into()
+    }
+}
+
+/// Checks that a lat/lng is a real coordinate in the projection before it's stored or projected.
+pub fn validate_coordinate(lat: f64, lng: f64, projection: &dyn Projection) -> Result<(), ClusterError> {
+    let in_range = !projection.is_geographic() || (lat.abs() <= 90.0 && lng.abs() <= 180.0);
+    if lat.is_finite() && lng.is_finite() && in_range {
+        Ok(())
+    } else {
+        Err(ClusterError::InvalidCoordinate { lat, lng })
+    }
+}
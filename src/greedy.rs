@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+use structs::{ bounds::Bounds, cluster::Cluster, cluster_index::ClusterIndex, marker::Marker, unique_marker::UniqueMarker };
+use config::Config;
+use algorithm::{ ClusterAlgorithm, keep_unchanged_clusters };
+use projection::pixel_distance;
+use error::ClusterError;
+use utils::spatial_order::hilbert_order;
+
+/// MarkerClustererPlus' algorithm, where each marker joins the closest cluster if it's within that cluster's bounds.
+///
+/// With `Config::deterministic`, every marker is clustered at once in a canonical order, and again whenever
+/// markers are added, moved or removed.
+#[derive(Debug, Default, Clone)]
+pub struct Greedy {
+    index: ClusterIndex,
+    /// Number of markers when they were last all clustered in a canonical order.
+    clustered: usize,
+}
+
+impl ClusterAlgorithm for Greedy {
+    fn add(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], marker: usize, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        let uuid = add_to_closest_cluster(clusters, &mut self.index, &mut markers[marker], zoom, config)?;
+        Ok(Some(uuid).into_iter().collect())
+    }
+
+    fn cluster_in_bounds(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], bounds: &Bounds, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        if !config.deterministic {
+            return cluster_markers(clusters, &mut self.index, markers, bounds, zoom, config);
+        }
+        if markers.len() == self.clustered && markers.iter().all(|marker| marker.is_added) {
+            return Ok(HashSet::new());
+        }
+        for marker in markers.iter_mut() {
+            marker.is_added = true;
+        }
+        self.rebuild(clusters, markers, zoom, config)
+    }
+
+    /// Keeps any clusters that end up with the same markers as before.
+    fn rebuild(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        self.clustered = markers.len();
+        recluster_markers(clusters, &mut self.index, markers, zoom, config)
+    }
+
+    fn reset(&mut self) {
+        self.index.clear();
+    }
+}
+
+/// Adds the markers in `map_bounds` that aren't yet clustered like `add_to_closest_cluster`, which syncs `index`
+/// only once there are enough clusters to use it.
+pub fn cluster_markers(existing_clusters: &mut Vec<Cluster>, index: &mut ClusterIndex, markers: &mut [UniqueMarker], map_bounds: &Bounds, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+    let mut clusters_modified = HashSet::new();
+    for point in markers.iter_mut() {
+        if !point.is_added && map_bounds.contains(point, config.projection.projection()) {
+            point.is_added = true;
+            let closest_cluster = add_to_closest_cluster(existing_clusters, index, point, zoom, config)?;
+            clusters_modified.insert(closest_cluster);
+        }
+    }
+    Ok(clusters_modified)
+}
+
+/// Clusters every marker with `is_added` set from scratch, in the order they were added, or with `config.deterministic`
+/// in the order they're visited by a Hilbert curve over their pixels at `zoom`, so which markers share a cluster doesn't
+/// depend on the order they were added in, or on which bounds were clustered. The new clusters replace the existing
+/// ones like `keep_unchanged_clusters`.
+pub fn recluster_markers(existing_clusters: &mut Vec<Cluster>, index: &mut ClusterIndex, markers: &mut [UniqueMarker], zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+    let added = markers.iter().enumerate().filter(|(_, m)| m.is_added).map(|(i, _)| i).collect::<Vec<_>>();
+    let order = if config.deterministic {
+        let projection = config.projection.projection();
+        let pixels = added.iter().map(|&i| markers[i].pixel(zoom, projection)).collect::<Result<Vec<_>, ClusterError>>()?;
+        let (width, height) = projection.world_size(zoom);
+        let (min, max) = if width.is_finite() && height.is_finite() {
+            ((0.0, 0.0), (width, height))
+        } else {
+            pixels.iter().fold(((f64::INFINITY, f64::INFINITY), (f64::NEG_INFINITY, f64::NEG_INFINITY)), |(min, max), &(x, y)| {
+                ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+            })
+        };
+        hilbert_order(&pixels, min, max).into_iter().map(|p| added[p]).collect()
+    } else {
+        added
+    };
+
+    let mut clusters = Vec::new();
+    index.clear();
+    for i in order {
+        add_to_closest_cluster(&mut clusters, index, &mut markers[i], zoom, config)?;
+    }
+    Ok(keep_unchanged_clusters(existing_clusters, clusters))
+}
+
+/// Below this many clusters, every cluster is compared instead of looking them up in the `ClusterIndex`.
+pub const LINEAR_SCAN_CLUSTERS: usize = 32;
+
+/// Adds the marker to the closest cluster if the cluster's bounds contain it, or else to a new cluster.
+///
+/// Once there are `LINEAR_SCAN_CLUSTERS` clusters, only the clusters that `index` places near the marker are compared,
+/// instead of every cluster. A cluster further out can't contain the marker, but is still looked for when it could be
+/// closer than one that does, so the marker starts a new cluster as it would if every cluster was compared.
+pub fn add_to_closest_cluster(clusters: &mut Vec<Cluster>, index: &mut ClusterIndex, new_point: &mut UniqueMarker, zoom: usize, config: &Config) -> Result<Uuid, ClusterError> {
+    let projection = config.projection.projection();
+    let pixel = new_point.pixel(zoom, projection)?;
+    let indexed = clusters.len() >= LINEAR_SCAN_CLUSTERS;
+
+    let closest = if indexed {
+        index.sync(clusters, zoom, config.grid_size, projection)?;
+        let mut closest = closest_cluster(clusters, index.nearby(pixel), new_point, pixel, zoom, config)?;
+        if let Some((i, distance)) = closest {
+            // Clusters two cells away are over a cell's width from the marker
+            if clusters[i].contains(new_point, projection) && pixel_distance(clusters[i].center_pixel(zoom, projection)?, pixel, zoom, projection) > index.cell_size() {
+                if let Some((j, further_distance)) = closest_cluster(clusters, index.ring(pixel, 2), new_point, pixel, zoom, config)? {
+                    if further_distance < distance {
+                        closest = Some((j, further_distance));
+                    }
+                }
+            }
+        }
+        closest
+    } else {
+        // The index isn't kept up to date while it's unused, so it's rebuilt once there are enough clusters
+        if !index.is_empty() {
+            index.clear();
+        }
+        let count = clusters.len();
+        closest_cluster(clusters, 0..count, new_point, pixel, zoom, config)?
+    };
+    let cluster_index_to_add_to = closest.map(|(i, _)| i).filter(|&i| clusters[i].contains(new_point, projection));
+
+    match cluster_index_to_add_to {
+        Some(i) => {
+            let old_center = clusters[i].center.clone();
+            clusters[i].add_marker(new_point, zoom, config.average_center, config.grid_size, projection)?;
+            if indexed && config.average_center {
+                index.remove(i, &old_center, projection)?;
+                index.insert(i, &clusters[i].center, projection)?;
+            }
+            Ok(clusters[i].uuid)
+        },
+        None => {
+            let bounds = Bounds::from_point(new_point.lat, new_point.lng, zoom, config.grid_size, projection)?;
+            let uuid = Uuid::new_v4();
+            let center = Marker{
+                lat: new_point.lat,
+                lng: new_point.lng,
+                id: None,
+                properties: None,
+            };
+            clusters.push(Cluster::new(uuid, center, vec![new_point.clone()], bounds, &config.aggregations));
+            if indexed {
+                index.insert(clusters.len() - 1, &clusters[clusters.len() - 1].center, projection)?;
+            }
+            Ok(uuid)
+        }
+    }
+}
+
+/// Index of the closest of the `candidates` clusters to the marker, and its distance.
+fn closest_cluster(clusters: &mut [Cluster], candidates: impl Iterator<Item = usize>, new_point: &UniqueMarker, pixel: (f64, f64), zoom: usize, config: &Config) -> Result<Option<(usize, f64)>, ClusterError> {
+    let projection = config.projection.projection();
+    let mut current_distance: f64;
+    let mut least_distance = f64::INFINITY;
+    let mut closest: Option<(usize, f64)> = None;
+    for i in candidates {
+        current_distance = if config.distance_in_pixels {
+            pixel_distance(clusters[i].center_pixel(zoom, projection)?, pixel, zoom, projection)
+        } else {
+            projection.distance(&clusters[i].center, new_point)
+        };
+        if current_distance < least_distance {
+            least_distance = current_distance;
+            closest = Some((i, current_distance));
+        }
+    }
+    Ok(closest)
+}
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+use structs::{ bounds::Bounds, cluster::Cluster, cluster_index::ClusterIndex, marker::Marker, unique_marker::UniqueMarker };
+use config::Config;
+use algorithm::ClusterAlgorithm;
+use error::ClusterError;
+
+/// Clusters the markers in `map_bounds` that aren't yet clustered by the `grid_size` pixel cell of the world
+/// that they fall in at `zoom`, so each marker is only projected and looked up once. In geographic projections,
+/// cells are widened slightly so a whole number of them fit around the world.
+///
+/// Which markers share a cluster doesn't depend on their order or on the bounds, and a cluster's id and bounds come
+/// from its cell, so the same cluster keeps its id when the map is panned or clustered again.
+pub fn cluster_markers_in_grid(existing_clusters: &mut Vec<Cluster>, index: &mut ClusterIndex, markers: &mut [UniqueMarker], map_bounds: &Bounds, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+    let mut clusters_modified = HashSet::new();
+    index.sync(existing_clusters, zoom, config.grid_size, config.projection.projection())?;
+    for point in markers.iter_mut() {
+        if !point.is_added && map_bounds.contains(point, config.projection.projection()) {
+            point.is_added = true;
+            clusters_modified.insert(add_to_grid_cell(existing_clusters, index, point, zoom, config)?);
+        }
+    }
+    Ok(clusters_modified)
+}
+
+/// Adds the marker to the cluster of its cell, or to a new cluster for the cell. The cluster's bounds are the cell,
+/// and it's centered on the middle of the cell, or with `average_center`, on its markers.
+pub fn add_to_grid_cell(clusters: &mut Vec<Cluster>, index: &mut ClusterIndex, point: &mut UniqueMarker, zoom: usize, config: &Config) -> Result<Uuid, ClusterError> {
+    let projection = config.projection.projection();
+    index.sync(clusters, zoom, config.grid_size, projection)?;
+    let cell = index.pixel_cell(point.pixel(zoom, projection)?);
+
+    match index.in_cell(cell).first() {
+        Some(&i) => {
+            let old_center = clusters[i].center.clone();
+            clusters[i].add_marker(point, zoom, config.average_center, config.grid_size, projection)?;
+            if config.average_center {
+                index.remove(i, &old_center, projection)?;
+                index.insert(i, &clusters[i].center, projection)?;
+            }
+            Ok(clusters[i].uuid)
+        },
+        None => {
+            let uuid = cell_uuid(zoom, cell);
+            let center = if config.average_center {
+                Marker { lat: point.lat, lng: point.lng, id: None, properties: None }
+            } else {
+                index.cell_center(cell, projection)?
+            };
+            let bounds = index.cell_bounds(cell, projection)?;
+            clusters.push(Cluster::in_cell(uuid, center, vec![point.clone()], bounds, &config.aggregations));
+            index.insert(clusters.len() - 1, &clusters[clusters.len() - 1].center, projection)?;
+            Ok(uuid)
+        }
+    }
+}
+
+/// Each marker joins the cluster of the `grid_size` pixel cell of the world that it's in, like `cluster_markers_in_grid`.
+#[derive(Debug, Default, Clone)]
+pub struct Grid {
+    index: ClusterIndex,
+}
+
+impl ClusterAlgorithm for Grid {
+    fn add(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], marker: usize, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        let uuid = add_to_grid_cell(clusters, &mut self.index, &mut markers[marker], zoom, config)?;
+        Ok(Some(uuid).into_iter().collect())
+    }
+
+    fn cluster_in_bounds(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], bounds: &Bounds, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        cluster_markers_in_grid(clusters, &mut self.index, markers, bounds, zoom, config)
+    }
+
+    fn reset(&mut self) {
+        self.index.clear();
+    }
+}
+
+/// Namespace of the ids of grid cells.
+const CELL_NAMESPACE: Uuid = Uuid::from_u128(0xe814c9f5aeee454bbf92c39bab8b7354);
+
+/// A version 5 UUID of the zoom and cell, so it's the same every time the cell is clustered.
+fn cell_uuid(zoom: usize, (x, y): (i64, i64)) -> Uuid {
+    let mut name = [0; 24];
+    name[..8].copy_from_slice(&(zoom as u64).to_be_bytes());
+    name[8..16].copy_from_slice(&x.to_be_bytes());
+    name[16..].copy_from_slice(&y.to_be_bytes());
+    Uuid::new_v5(&CELL_NAMESPACE, &name)
+}
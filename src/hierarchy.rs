@@ -0,0 +1,219 @@
+use uuid::Uuid;
+use structs::{ aggregation::Aggregation, bounds::Bounds, cluster::Cluster, kd_tree::KdTree, marker::Marker, unique_marker::UniqueMarker };
+use projection::Projection;
+use error::ClusterError;
+use config::MarkerOutput;
+
+/// A cluster (or single marker) at one zoom level of a `ClusterHierarchy`.
+/// Positions are world pixels at zoom 0, so they're valid at every level.
+#[derive(Debug, Clone)]
+struct Node {
+    uuid: Uuid,
+    x: f64,
+    y: f64,
+    size: u32,
+    lat: f64,
+    lng: f64,
+    /// The `grid_size` around the center at the zoom of the node's level.
+    bounds: Bounds,
+    /// Index into the clustered markers, only set on the bottom level.
+    marker: Option<usize>,
+    /// Indices of the nodes on the level below that were merged into this one.
+    children: Vec<usize>,
+    parent: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Level {
+    nodes: Vec<Node>,
+    tree: KdTree,
+}
+
+impl Level {
+    fn new(nodes: Vec<Node>) -> Level {
+        let tree = KdTree::new(&nodes.iter().map(|n| (n.x, n.y)).collect::<Vec<_>>());
+        Level { nodes, tree }
+    }
+}
+
+/// Clusters for every zoom from 0 to `max_zoom`, built once from the markers, so that
+/// a query for any zoom and bounds is only a range lookup in that zoom's KD-tree.
+///
+/// Each level is built by merging the clusters of the level below that fall within
+/// `grid_size` pixels of each other, in the same way MarkerClustererPlus grows a cluster.
+#[derive(Debug, Clone)]
+pub struct ClusterHierarchy {
+    max_zoom: usize,
+    grid_size: f64,
+    /// Width of the world in pixels at zoom 0, which node positions are in, or `None` if it doesn't wrap around.
+    world_width: Option<f64>,
+    aggregations: Vec<Aggregation>,
+    /// `levels[zoom]`, with one extra level past `max_zoom` holding the unclustered markers.
+    levels: Vec<Level>,
+}
+
+impl ClusterHierarchy {
+    pub fn new(markers: &[UniqueMarker], max_zoom: usize, grid_size: f64, average_center: bool, aggregations: &[Aggregation], projection: &dyn Projection) -> Result<ClusterHierarchy, ClusterError> {
+        let world_width = if projection.is_geographic() { Some(projection.world_size(0).0) } else { None };
+        let leaves = markers.iter().enumerate().map(|(i, marker)| {
+            let (x, y) = projection.project(marker.lat, marker.lng, 0)?;
+            let bounds = Bounds::from_point(marker.lat, marker.lng, max_zoom + 1, grid_size, projection)?;
+            Ok(Node { uuid: Uuid::new_v4(), x, y, size: 1, lat: marker.lat, lng: marker.lng, bounds, marker: Some(i), children: Vec::new(), parent: None })
+        }).collect::<Result<_, ClusterError>>()?;
+
+        let mut levels = vec![Level::new(leaves)];
+        for zoom in (0..=max_zoom).rev() {
+            let next = merge_level(levels.last_mut().unwrap(), zoom, grid_size, average_center, world_width, projection)?;
+            levels.push(next);
+        }
+        levels.reverse();
+
+        Ok(ClusterHierarchy { max_zoom, grid_size, world_width, aggregations: aggregations.to_vec(), levels })
+    }
+
+    /// The clusters at `zoom` whose centers are within `bounds`, with only the markers that `marker_output` returns,
+    /// without their properties unless `include_properties`. The markers of a cluster are only looked up if they're
+    /// returned or aggregated, so a query only takes as long as the clusters in bounds.
+    pub fn clusters(&self, markers: &[UniqueMarker], bounds: &Bounds, zoom: usize, marker_output: MarkerOutput, include_properties: bool, projection: &dyn Projection) -> Result<Vec<Cluster>, ClusterError> {
+        let level_zoom = zoom.min(self.max_zoom + 1);
+        let level = &self.levels[level_zoom];
+        let (min_x, min_y) = projection.project(bounds.north, bounds.west, 0)?;
+        let (max_x, max_y) = projection.project(bounds.south, bounds.east, 0)?;
+
+        level.tree.wrapped_range(min_x, min_y, max_x, max_y, self.world_width).into_iter()
+            .map(|i| self.cluster(markers, zoom, i, marker_output, include_properties, projection))
+            .collect()
+    }
+
+    /// Indices into the clustered markers of the markers in the cluster with this id at `zoom`,
+    /// or `None` if there's no such cluster.
+    pub fn leaves(&self, uuid: Uuid, zoom: usize) -> Option<Vec<usize>> {
+        let (level_zoom, index) = self.find(uuid, zoom)?;
+        let mut leaves = Vec::new();
+        self.collect_leaves(level_zoom, index, &mut leaves);
+        Some(leaves)
+    }
+
+    /// The clusters at `zoom + 1` that the cluster with this id at `zoom` is made of. A cluster that's one marker
+    /// has none past `max_zoom`.
+    pub fn children(&self, markers: &[UniqueMarker], uuid: Uuid, zoom: usize, marker_output: MarkerOutput, include_properties: bool, projection: &dyn Projection) -> Result<Option<Vec<Cluster>>, ClusterError> {
+        let (level_zoom, index) = match self.find(uuid, zoom) {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        self.levels[level_zoom].nodes[index].children.iter()
+            .map(|&child| self.cluster(markers, zoom + 1, child, marker_output, include_properties, projection))
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+
+    /// The lowest zoom at which the cluster with this id is split into more than one cluster, or `None` if it's
+    /// a single marker. The id is looked up on the deepest level it's on, where the cluster was formed.
+    pub fn expansion_zoom(&self, uuid: Uuid) -> Option<Option<usize>> {
+        self.levels.iter().enumerate().rev().find_map(|(zoom, level)| {
+            let node = level.nodes.iter().find(|node| node.uuid == uuid)?;
+            Some(if node.marker.is_some() { None } else { Some(zoom + 1) })
+        })
+    }
+
+    /// The level and index of the node with this id at `zoom`.
+    fn find(&self, uuid: Uuid, zoom: usize) -> Option<(usize, usize)> {
+        let level_zoom = zoom.min(self.max_zoom + 1);
+        self.levels[level_zoom].nodes.iter().position(|node| node.uuid == uuid).map(|index| (level_zoom, index))
+    }
+
+    /// The cluster for the node at `index` on the level for `zoom`, like `clusters`.
+    fn cluster(&self, markers: &[UniqueMarker], zoom: usize, index: usize, marker_output: MarkerOutput, include_properties: bool, projection: &dyn Projection) -> Result<Cluster, ClusterError> {
+        let level_zoom = zoom.min(self.max_zoom + 1);
+        let node = &self.levels[level_zoom].nodes[index];
+        let bounds = if zoom == level_zoom {
+            node.bounds.clone()
+        } else {
+            Bounds::from_point(node.lat, node.lng, zoom, self.grid_size, projection)?
+        };
+        let mut leaves = Vec::new();
+        if marker_output != MarkerOutput::None || !self.aggregations.is_empty() {
+            leaves.reserve(node.size as usize);
+            self.collect_leaves(level_zoom, index, &mut leaves);
+        }
+        let returned = match marker_output {
+            MarkerOutput::All | MarkerOutput::Ids => leaves.len(),
+            MarkerOutput::Sample(count) => count.min(leaves.len()),
+            MarkerOutput::None => 0,
+        };
+        let copy = |marker: &UniqueMarker| if include_properties && marker_output != MarkerOutput::Ids { marker.clone() } else { marker.without_properties() };
+        Ok(Cluster::summary(
+            node.uuid,
+            Marker { lat: node.lat, lng: node.lng, id: None, properties: None },
+            node.size,
+            leaves[..returned].iter().map(|&m| copy(&markers[m])).collect(),
+            bounds,
+            &self.aggregations,
+            leaves.iter().map(|&m| &markers[m]),
+        ))
+    }
+
+    fn collect_leaves(&self, zoom: usize, index: usize, leaves: &mut Vec<usize>) {
+        let node = &self.levels[zoom].nodes[index];
+        if let Some(marker) = node.marker {
+            leaves.push(marker);
+        }
+        for &child in &node.children {
+            self.collect_leaves(zoom + 1, child, leaves);
+        }
+    }
+}
+
+/// The x moved by the width of the world if needed to be within half of it from `reference`.
+fn unwrap_x(x: f64, reference: f64, world_width: Option<f64>) -> f64 {
+    let world_width = match world_width {
+        Some(world_width) => world_width,
+        None => return x,
+    };
+    if x - reference > world_width / 2.0 {
+        x - world_width
+    } else if reference - x > world_width / 2.0 {
+        x + world_width
+    } else {
+        x
+    }
+}
+
+fn merge_level(below: &mut Level, zoom: usize, grid_size: f64, average_center: bool, world_width: Option<f64>, projection: &dyn Projection) -> Result<Level, ClusterError> {
+    let radius = grid_size / 2.0_f64.powi(zoom as i32);
+    let mut nodes = Vec::new();
+
+    for i in 0..below.nodes.len() {
+        if below.nodes[i].parent.is_some() {
+            continue;
+        }
+        let (seed_x, seed_y) = (below.nodes[i].x, below.nodes[i].y);
+        let children = below.tree.wrapped_range(seed_x - radius, seed_y - radius, seed_x + radius, seed_y + radius, world_width)
+            .into_iter()
+            .filter(|&n| below.nodes[n].parent.is_none())
+            .collect::<Vec<_>>();
+
+        let parent = nodes.len();
+        let (mut size, mut weighted_x, mut weighted_y) = (0, 0.0, 0.0);
+        for &child in &children {
+            let node = &mut below.nodes[child];
+            node.parent = Some(parent);
+            size += node.size;
+            weighted_x += unwrap_x(node.x, seed_x, world_width) * f64::from(node.size);
+            weighted_y += node.y * f64::from(node.size);
+        }
+        let (x, y) = if average_center {
+            let x = weighted_x / f64::from(size);
+            (world_width.map_or(x, |world_width| x.rem_euclid(world_width)), weighted_y / f64::from(size))
+        } else {
+            (seed_x, seed_y)
+        };
+        // A marker that didn't merge with anything keeps its id, so it's the same cluster at every zoom
+        let uuid = if children.len() == 1 { below.nodes[i].uuid } else { Uuid::new_v4() };
+
+        let (lat, lng) = projection.unproject(x, y, 0)?;
+        let bounds = Bounds::from_point(lat, lng, zoom, grid_size, projection)?;
+        nodes.push(Node { uuid, x, y, size, lat, lng, bounds, marker: None, children, parent: None });
+    }
+    Ok(Level::new(nodes))
+}
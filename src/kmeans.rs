@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+use structs::{ bounds::Bounds, cluster::Cluster, unique_marker::UniqueMarker };
+use config::Config;
+use algorithm::{ ClusterAlgorithm, replace_clusters };
+use error::ClusterError;
+
+/// Options of `Algorithm::KMeans`.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct KMeans {
+    /// Number of clusters. Fewer are returned if there are fewer markers in bounds.
+    pub k: usize,
+    /// Seed for choosing the first centers, so the same markers always give the same clusters.
+    pub seed: u64,
+    /// Most rounds of moving the centers, if they haven't settled before.
+    pub max_iterations: usize,
+}
+
+impl Default for KMeans {
+    fn default() -> KMeans {
+        KMeans { k: 10, seed: 0, max_iterations: 100 }
+    }
+}
+
+impl KMeans {
+    /// Checks that the options can cluster markers, since with a `k` of 0 markers in bounds would be in no cluster.
+    pub fn validate(&self) -> Result<(), ClusterError> {
+        if self.k == 0 {
+            return Err(ClusterError::InvalidInput("k-means needs a k of at least 1".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Clusters the markers within `bounds` into `k` clusters by k-means over their pixel positions, seeded by k-means++.
+/// Markers outside of the bounds are taken out of the clusters, so the clusters only ever cover the last bounds.
+///
+/// Clusters are centered on their markers' average position, and replace the existing clusters like `replace_clusters`.
+pub fn cluster_markers_kmeans(existing_clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], bounds: &Bounds, zoom: usize, config: &Config, kmeans: &KMeans) -> Result<HashSet<Uuid>, ClusterError> {
+    for marker in markers.iter_mut() {
+        marker.is_added = bounds.contains(marker, config.projection.projection());
+    }
+    cluster_added_markers(existing_clusters, markers, Some(bounds), zoom, config, kmeans)
+}
+
+/// Clusters the markers with `is_added` set. With `bounds`, positions west of their west edge are moved a world over,
+/// so bounds crossing the antimeridian are one continuous area.
+fn cluster_added_markers(existing_clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], bounds: Option<&Bounds>, zoom: usize, config: &Config, kmeans: &KMeans) -> Result<HashSet<Uuid>, ClusterError> {
+    let projection = config.projection.projection();
+    let west_x = match bounds {
+        Some(bounds) => Some(projection.project(bounds.north, bounds.west, zoom)?.0),
+        None => None,
+    };
+    let added = markers.iter().enumerate().filter(|(_, m)| m.is_added).map(|(i, _)| i).collect::<Vec<_>>();
+    let world_width = projection.world_size(zoom).0;
+    let points = added.iter().map(|&i| {
+        let (x, y) = markers[i].pixel(zoom, projection)?;
+        Ok(match west_x {
+            Some(west_x) if projection.is_geographic() && x < west_x => (x + world_width, y),
+            _ => (x, y),
+        })
+    }).collect::<Result<Vec<_>, ClusterError>>()?;
+
+    let mut groups = vec![Vec::new(); kmeans.k.min(points.len())];
+    for (p, group) in assign(&points, kmeans).into_iter().enumerate() {
+        groups[group].push(added[p]);
+    }
+
+    Ok(replace_clusters(existing_clusters, groups, markers, true, config))
+}
+
+/// Clusters with `KMeans` options like `cluster_markers_kmeans`, and keeps the last bounds clustered in, so that
+/// reclustering after markers are moved or removed unwraps positions around the same west edge.
+#[derive(Debug, Clone)]
+pub struct KMeansAlgorithm {
+    pub options: KMeans,
+    bounds: Option<Bounds>,
+}
+
+impl KMeansAlgorithm {
+    pub fn new(options: KMeans) -> KMeansAlgorithm {
+        KMeansAlgorithm { options, bounds: None }
+    }
+}
+
+impl ClusterAlgorithm for KMeansAlgorithm {
+    /// Reclusters every marker, since the new one moves the centers.
+    fn add(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], marker: usize, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        markers[marker].is_added = true;
+        self.rebuild(clusters, markers, zoom, config)
+    }
+
+    fn rebuild(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        cluster_added_markers(clusters, markers, self.bounds.as_ref(), zoom, config, &self.options)
+    }
+
+    fn cluster_in_bounds(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], bounds: &Bounds, zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+        self.bounds = Some(bounds.clone());
+        cluster_markers_kmeans(clusters, markers, bounds, zoom, config, &self.options)
+    }
+
+    /// Only the markers actually in view are split into the `k` clusters.
+    fn extends_bounds(&self) -> bool {
+        false
+    }
+}
+
+/// Index of the group of each point, after Lloyd's algorithm from k-means++ seeds.
+fn assign(points: &[(f64, f64)], kmeans: &KMeans) -> Vec<usize> {
+    let k = kmeans.k.min(points.len());
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut centers = seed_centers(points, k, kmeans.seed);
+    let mut assignments = vec![0; points.len()];
+
+    for _ in 0..kmeans.max_iterations.max(1) {
+        let mut changed = false;
+        for (p, point) in points.iter().enumerate() {
+            let closest = closest_center(point, &centers);
+            if closest != assignments[p] {
+                assignments[p] = closest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![(0.0, 0.0, 0); k];
+        for (point, &group) in points.iter().zip(&assignments) {
+            sums[group].0 += point.0;
+            sums[group].1 += point.1;
+            sums[group].2 += 1;
+        }
+        // A center left without points stays where it is
+        for (center, &(x, y, count)) in centers.iter_mut().zip(&sums) {
+            if count > 0 {
+                *center = (x / count as f64, y / count as f64);
+            }
+        }
+    }
+    assignments
+}
+
+/// k-means++: the first center is a random point, and each next one is a point picked with a probability
+/// proportional to its squared distance from the closest center so far.
+fn seed_centers(points: &[(f64, f64)], k: usize, seed: u64) -> Vec<(f64, f64)> {
+    let mut random = SplitMix64(seed);
+    let mut centers = vec![points[(random.next_f64() * points.len() as f64) as usize % points.len()]];
+    let mut distances = points.iter().map(|point| squared_distance(point, &centers[0])).collect::<Vec<_>>();
+
+    while centers.len() < k {
+        let total = distances.iter().sum::<f64>();
+        let next = if total > 0.0 {
+            let mut target = random.next_f64() * total;
+            distances.iter().position(|&d| {
+                target -= d;
+                target < 0.0 && d > 0.0
+            }).unwrap_or_else(|| distances.iter().rposition(|&d| d > 0.0).unwrap_or(0))
+        } else {
+            // Every point is on a center already
+            centers.len() % points.len()
+        };
+        centers.push(points[next]);
+        for (distance, point) in distances.iter_mut().zip(points) {
+            *distance = distance.min(squared_distance(point, &points[next]));
+        }
+    }
+    centers
+}
+
+fn closest_center(point: &(f64, f64), centers: &[(f64, f64)]) -> usize {
+    centers.iter()
+        .map(|center| squared_distance(point, center))
+        .enumerate()
+        .fold((0, f64::INFINITY), |closest, (i, d)| if d < closest.1 { (i, d) } else { closest })
+        .0
+}
+
+fn squared_distance(a: &(f64, f64), b: &(f64, f64)) -> f64 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+/// A small seeded random number generator, so results are the same on every platform.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to, but not including, 1.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
@@ -1,7 +1,9 @@
 extern crate wasm_bindgen;
 extern crate web_sys;
+extern crate js_sys;
 extern crate uuid;
 extern crate serde_wasm_bindgen;
+extern crate serde_json;
 
 #[macro_use]
 extern crate serde_derive;
@@ -14,24 +16,34 @@ pub mod structs;
 use structs::{ bounds::Bounds, cluster::Cluster, marker::Marker, unique_marker::UniqueMarker };
 
 pub mod config;
-use config::{ Config, OptionalConfig };
+
+pub mod hierarchy;
+
+pub mod algorithm;
+
+pub mod greedy;
+
+pub mod grid;
+
+pub mod dbscan;
+
+pub mod kmeans;
+
+pub mod clusterer;
+use clusterer::Clusterer;
+
+pub mod projection;
+
+pub mod error;
+use error::ClusterError;
 
 mod utils;
-use utils::bounds::calculate_extended_bounds;
 
-use std::sync::Mutex;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::collections::HashSet;
-use uuid::Uuid;
+use std::sync::{ Mutex, MutexGuard };
 use wasm_bindgen::prelude::*;
-use web_sys::console;
-use std::f64;
 
 lazy_static! {
-    static ref ALL_POINTS: Mutex<Vec<UniqueMarker>> = Mutex::new(Vec::new());
-    static ref CLUSTERS: Mutex<Vec<Cluster>> = Mutex::new(Vec::new());
-    static ref ZOOM: AtomicUsize = AtomicUsize::new(0);
-    static ref CONFIG: Mutex<Config> = Mutex::new(Config::default());
+    static ref DEFAULT_CLUSTERER: Mutex<Clusterer> = Mutex::new(Clusterer::default());
 }
 
 #[wasm_bindgen(start)]
@@ -42,130 +54,98 @@ pub fn main_js() -> Result<(), JsValue> {
     Ok(())
 }
 
+/// Locks the default clusterer. If an earlier call panicked while holding it, its state may be
+/// half-updated, so it's reset and an error is returned instead.
+fn default_clusterer() -> Result<MutexGuard<'static, Clusterer>, ClusterError> {
+    DEFAULT_CLUSTERER.lock().map_err(|poisoned| {
+        *poisoned.into_inner() = Clusterer::default();
+        DEFAULT_CLUSTERER.clear_poison();
+        ClusterError::PoisonedState
+    })
+}
+
 #[wasm_bindgen]
-pub fn configure(config: JsValue) {
-    let new_config: OptionalConfig = serde_wasm_bindgen::from_value(config).unwrap();
-    CONFIG.lock().unwrap().apply_options(new_config);
+pub fn configure(config: JsValue) -> Result<(), JsValue> {
+    default_clusterer()?.configure(config)
 }
 
 #[wasm_bindgen(js_name = addMarkers)]
-pub fn add_markers(markers_val: JsValue) {
-    let markers: &mut Vec<Marker> = &mut serde_wasm_bindgen::from_value(markers_val).unwrap();
-    // TODO see if .extend() is faster/better than .append() ?
-    ALL_POINTS.lock().unwrap().append(&mut markers.iter().map(UniqueMarker::from).collect::<Vec<_>>());
+pub fn add_markers(markers_val: JsValue) -> Result<(), JsValue> {
+    default_clusterer()?.add_markers(markers_val)
+}
+
+#[wasm_bindgen(js_name = addMarkersFromBuffer)]
+pub fn add_markers_from_buffer(coords: &[f64], ids: Option<Vec<u32>>) -> Result<(), JsValue> {
+    default_clusterer()?.add_markers_from_buffer(coords, ids)
 }
 
 #[wasm_bindgen(js_name = clusterMarkersInBounds)]
-pub fn cluster_markers_in_bounds(bounds_val: JsValue, zoom: usize) -> JsValue {
-    let config = CONFIG.lock().unwrap();
-
-    let map_bounds: Bounds = calculate_extended_bounds(&serde_wasm_bindgen::from_value(bounds_val).unwrap(), zoom, config.grid_size);
-    if config.log_time {
-        console::time_with_label("clustering");
-    }
-    let clusters = &mut CLUSTERS.lock().unwrap();
-    if ZOOM.swap(zoom, Ordering::Relaxed) != zoom {
-        clusters.clear();
-        uncluster_markers();
-    }
-    let uuids_modified = cluster_markers(clusters, &mut ALL_POINTS.lock().unwrap(), &map_bounds, zoom, &config);
-    if config.log_time {
-        console::time_end_with_label("clustering");
-    }
-
-    let vec = if !config.only_return_modified_clusters {
-        clusters.to_vec()
-    } else {
-        clusters.iter()
-                .filter(|c| uuids_modified.contains(&c.uuid))
-                .cloned()
-                .collect::<Vec<_>>()
-    };
-    serde_wasm_bindgen::to_value(&vec).unwrap()
+pub fn cluster_markers_in_bounds(bounds_val: JsValue, zoom: usize) -> Result<JsValue, JsValue> {
+    default_clusterer()?.cluster_markers_in_bounds(bounds_val, zoom)
 }
 
-#[wasm_bindgen]
-pub fn clear() {
-    ALL_POINTS.lock().unwrap().clear();
-    CLUSTERS.lock().unwrap().clear();
+#[wasm_bindgen(js_name = clusterDiffInBounds)]
+pub fn cluster_diff_in_bounds(bounds_val: JsValue, zoom: usize) -> Result<JsValue, JsValue> {
+    default_clusterer()?.cluster_diff_in_bounds_js(bounds_val, zoom)
 }
 
-#[wasm_bindgen(js_name = clearClusters)]
-pub fn clear_clusters() {
-    CLUSTERS.lock().unwrap().clear();
-    uncluster_markers();
+#[wasm_bindgen(js_name = clusterColumnsInBounds)]
+pub fn cluster_columns_in_bounds(bounds_val: JsValue, zoom: usize) -> Result<JsValue, JsValue> {
+    default_clusterer()?.cluster_columns_in_bounds_js(bounds_val, zoom)
 }
 
-fn uncluster_markers() {
-    for marker in ALL_POINTS.lock().unwrap().iter_mut() {
-        marker.is_added = false;
-    }
+#[wasm_bindgen(js_name = removeMarkers)]
+pub fn remove_markers(ids_val: JsValue) -> Result<(), JsValue> {
+    default_clusterer()?.remove_markers_by_id(ids_val)
 }
 
-pub fn cluster_markers(existing_clusters: &mut Vec<Cluster>, markers: &mut Vec<UniqueMarker>, map_bounds: &Bounds, zoom: usize, config: &Config) -> HashSet<Uuid> {
-    let mut clusters_modified = HashSet::new();
-    for point in markers.iter_mut() {
-        if !point.is_added && map_bounds.contains(point) {
-            point.is_added = true;
-            let closest_cluster = add_to_closest_cluster(existing_clusters, point, zoom, config.average_center, config.grid_size);
-            if config.only_return_modified_clusters {
-                clusters_modified.insert(closest_cluster);
-            }
-        }
-    }
-    clusters_modified
-}
-
-pub fn add_to_closest_cluster(clusters: &mut Vec<Cluster>, new_point: &UniqueMarker, zoom: usize, average_center: bool, grid_size: f64) -> Uuid {
-    let mut current_distance: f64;
-    let mut least_distance = 40000.0; // Some large number
-    let mut cluster_index_to_add_to: Option<usize> = None;
-    for (i, cluster) in clusters.iter().enumerate() {
-        current_distance = distance_between_markers(&cluster.center, new_point);
-        if current_distance < least_distance {
-            least_distance = current_distance;
-            cluster_index_to_add_to = Some(i);
-        }
-    }
-
-    if cluster_index_to_add_to.is_some() && clusters[cluster_index_to_add_to.unwrap()].bounds.contains(&new_point) {
-        let index = cluster_index_to_add_to.unwrap();
-        clusters[index].add_marker(new_point, zoom, average_center, grid_size);
-        clusters[index].uuid
-    } else {
-        let uuid = Uuid::new_v4();
-        clusters.push(Cluster {
-            uuid,
-            size: 1,
-            center: Marker{
-                lat: new_point.lat,
-                lng: new_point.lng
-            },
-            markers: vec![new_point.clone()],
-            bounds: Bounds::from_point(new_point.lat, new_point.lng, zoom, grid_size)
-        });
-        uuid
-    }
+#[wasm_bindgen(js_name = updateMarkers)]
+pub fn update_markers(markers_val: JsValue) -> Result<(), JsValue> {
+    default_clusterer()?.update_markers_by_id(markers_val)
 }
 
-pub fn distance_between_markers(p1: &Marker, p2: &UniqueMarker) -> f64 {
-    let earth_radius_kilometer = 6371.0_f64;
+#[wasm_bindgen(js_name = getClusterExpansionZoom)]
+pub fn get_cluster_expansion_zoom(cluster_id: &str) -> Result<Option<usize>, JsValue> {
+    default_clusterer()?.get_cluster_expansion_zoom(cluster_id)
+}
 
-    let delta_latitude = (p1.lat - p2.lat).to_radians();
-    let delta_longitude = (p1.lng - p2.lng).to_radians();
+#[wasm_bindgen(js_name = getClusterLeaves)]
+pub fn get_cluster_leaves(cluster_id: &str, limit: usize, offset: usize) -> Result<JsValue, JsValue> {
+    default_clusterer()?.get_cluster_leaves(cluster_id, limit, offset)
+}
 
-    let central_angle_inner = (delta_latitude / 2.0).sin().powi(2)
-        + p1.lat.to_radians().cos() * p2.lat.to_radians().cos() * (delta_longitude / 2.0).sin().powi(2);
-    let central_angle = 2.0 * central_angle_inner.sqrt().asin();
+#[wasm_bindgen(js_name = getClusterChildren)]
+pub fn get_cluster_children(cluster_id: &str) -> Result<JsValue, JsValue> {
+    default_clusterer()?.get_cluster_children(cluster_id)
+}
+
+#[wasm_bindgen]
+pub fn clear() -> Result<(), JsValue> {
+    default_clusterer()?.clear();
+    Ok(())
+}
 
-    earth_radius_kilometer * central_angle
+#[wasm_bindgen(js_name = clearClusters)]
+pub fn clear_clusters() -> Result<(), JsValue> {
+    default_clusterer()?.clear_clusters();
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
+    use uuid::Uuid;
+    use structs::cluster_index::ClusterIndex;
+    use config::{ Config, MarkerOutput };
+    use hierarchy::ClusterHierarchy;
+    use greedy::{ LINEAR_SCAN_CLUSTERS, add_to_closest_cluster, cluster_markers };
+    use utils::bounds::calculate_extended_bounds;
+    use projection::{ Equirectangular, Planar, Projection, ProjectionKind, WebMercator };
+    use structs::aggregation::Aggregation;
+    use structs::marker_id::MarkerId;
 
-    static SAMPLE_POINT: Marker = Marker { lat: 43.0, lng: -79.0 };
+    static SAMPLE_POINT: Marker = Marker { lat: 43.0, lng: -79.0, id: None, properties: None };
     static DEFAULT_ZOOM: usize = 8;
     static DEFAULT_BOUNDS: Bounds = Bounds {
         north: 45.0,
@@ -174,30 +154,93 @@ mod tests {
         west: -81.0,
     };
 
+    fn marker(lat: f64, lng: f64, id: Option<f64>) -> Marker {
+        Marker { lat, lng, id: id.map(MarkerId::Number), properties: None }
+    }
+
+    fn clusterer_with(config: config::OptionalConfig) -> Clusterer {
+        let mut clusterer = Clusterer::new();
+        clusterer.apply_config(config).unwrap();
+        clusterer
+    }
+
+    /// 200 markers in a grid, with their index as id.
+    fn grid_of_markers() -> Vec<Marker> {
+        (0..200).map(|i| marker(42.0 + f64::from(i % 20) * 0.05, -80.0 + f64::from(i / 20) * 0.2, Some(f64::from(i)))).collect()
+    }
+
+    /// Checks that the clusters of `grid_of_markers` under the config are the same whatever order the markers are
+    /// added in, and whichever bounds were clustered before, and returns them as the sorted ids of their markers.
+    fn assert_clusters_dont_depend_on_order_or_panning(config: impl Fn() -> config::OptionalConfig) -> Vec<Vec<String>> {
+        let markers = grid_of_markers();
+        let clusters = |markers: &[Marker], bounds: &[&Bounds]| {
+            let mut clusterer = clusterer_with(config::OptionalConfig { only_return_modified_clusters: Some(false), ..config() });
+            clusterer.push_markers(markers).unwrap();
+            for bounds in bounds {
+                clusterer.clusters_in_bounds(bounds, DEFAULT_ZOOM).unwrap();
+            }
+            let mut clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().into_iter().map(|cluster| {
+                assert!(cluster.markers.iter().all(|m| cluster.contains(m, &WebMercator)));
+                let mut ids = cluster.markers.iter().map(|m| format!("{:?}", m.id)).collect::<Vec<_>>();
+                ids.sort();
+                (ids, format!("{:?} {:?}", cluster.center, cluster.bounds))
+            }).collect::<Vec<_>>();
+            clusters.sort();
+            clusters
+        };
+
+        let in_order = clusters(&markers, &[]);
+        assert!(in_order.len() > 1 && in_order.len() < 200);
+        assert_eq!(in_order.iter().map(|(ids, _)| ids.len()).sum::<usize>(), 200);
+
+        let mut reversed = markers.clone();
+        reversed.reverse();
+        let western_half = Bounds { east: -79.2, ..DEFAULT_BOUNDS.clone() };
+        assert_eq!(clusters(&reversed, &[]), in_order);
+        assert_eq!(clusters(&markers, &[ &western_half ]), in_order);
+        assert_eq!(clusters(&reversed, &[ &western_half ]), in_order);
+        in_order.into_iter().map(|(ids, _)| ids).collect()
+    }
+
     #[test]
     fn clusters_include_all_markers() {
-        let mut sample_markers = vec![ Marker { lat: 43.0, lng: -79.0 }; 5 ].iter().map(UniqueMarker::from).collect::<Vec<_>>();
+        let mut sample_markers = vec![ marker(43.0, -79.0, None); 5 ].iter().map(UniqueMarker::from).collect::<Vec<_>>();
 
         let clustered = &mut Vec::new();
-        cluster_markers(clustered, &mut sample_markers, &DEFAULT_BOUNDS, DEFAULT_ZOOM, &Config::default());
-        let cluster_point_count = clustered.iter().fold(0, |sum, ref x| sum + x.size );
+        cluster_markers(clustered, &mut ClusterIndex::default(), &mut sample_markers, &DEFAULT_BOUNDS, DEFAULT_ZOOM, &Config::default()).unwrap();
+        let cluster_point_count = clustered.iter().fold(0, |sum, x| sum + x.size );
         assert_eq!(sample_markers.len() as u32, cluster_point_count);
     }
 
+    #[test]
+    fn index_is_only_built_once_there_are_enough_clusters() {
+        let mut clusters = Vec::new();
+        let mut index = ClusterIndex::default();
+        let mut nearby = vec![ marker(43.0, -79.0, None); 5 ].iter().map(UniqueMarker::from).collect::<Vec<_>>();
+        cluster_markers(&mut clusters, &mut index, &mut nearby, &DEFAULT_BOUNDS, DEFAULT_ZOOM, &Config::default()).unwrap();
+        assert!(index.is_empty());
+
+        let mut spread_out = (0..48).map(|i| UniqueMarker::from(&marker(40.2 + f64::from(i / 6) * 0.6, -80.8 + f64::from(i % 6) * 0.9, None))).collect::<Vec<_>>();
+        cluster_markers(&mut clusters, &mut index, &mut spread_out, &DEFAULT_BOUNDS, DEFAULT_ZOOM, &Config::default()).unwrap();
+        assert!(clusters.len() >= LINEAR_SCAN_CLUSTERS);
+        assert!(!index.is_empty());
+    }
+
     #[test]
     fn add_some_markers_to_a_cluster() {
         let mut sample_clusters: Vec<Cluster> = Vec::new();
-        let p1 = UniqueMarker::from(&SAMPLE_POINT);
-        let p2 = UniqueMarker::from(&SAMPLE_POINT);
+        let mut index = ClusterIndex::default();
+        let mut p1 = UniqueMarker::from(&SAMPLE_POINT);
+        let mut p2 = UniqueMarker::from(&SAMPLE_POINT);
 
-        add_to_closest_cluster(&mut sample_clusters, &p1, DEFAULT_ZOOM, false, 60.0);
+        add_to_closest_cluster(&mut sample_clusters, &mut index, &mut p1, DEFAULT_ZOOM, &Config::default()).unwrap();
 
         assert_eq!(sample_clusters.len(), 1);
         assert_eq!(sample_clusters[0].size, 1);
         assert!((sample_clusters[0].center.lat - SAMPLE_POINT.lat).abs() < f64::EPSILON);
         assert!((sample_clusters[0].center.lng - SAMPLE_POINT.lng).abs() < f64::EPSILON);
 
-        add_to_closest_cluster(&mut sample_clusters, &p2, DEFAULT_ZOOM, false, 60.0);
+        add_to_closest_cluster(&mut sample_clusters, &mut index, &mut p2, DEFAULT_ZOOM, &Config::default()).unwrap();
 
         assert_eq!(sample_clusters.len(), 1);
         assert_eq!(sample_clusters[0].size, 2);
@@ -205,14 +248,357 @@ mod tests {
         assert!((sample_clusters[0].center.lng - SAMPLE_POINT.lng).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn distant_markers_get_their_own_clusters() {
+        let mut sample_clusters: Vec<Cluster> = Vec::new();
+        let mut index = ClusterIndex::default();
+        let mut near = UniqueMarker::from(&SAMPLE_POINT);
+        let mut far = UniqueMarker::from(&marker(44.5, -76.0, None));
+
+        add_to_closest_cluster(&mut sample_clusters, &mut index, &mut near, DEFAULT_ZOOM, &Config::default()).unwrap();
+        add_to_closest_cluster(&mut sample_clusters, &mut index, &mut far, DEFAULT_ZOOM, &Config::default()).unwrap();
+        add_to_closest_cluster(&mut sample_clusters, &mut index, &mut far.clone(), DEFAULT_ZOOM, &Config::default()).unwrap();
+
+        assert_eq!(sample_clusters.len(), 2);
+        assert_eq!(sample_clusters[0].size, 1);
+        assert_eq!(sample_clusters[1].size, 2);
+    }
+
+    #[test]
+    fn markers_only_join_the_closest_cluster_if_it_contains_them() {
+        let config = Config::default();
+        let projection = config.projection.projection();
+        let mut index = ClusterIndex::default();
+        index.sync(&[], DEFAULT_ZOOM, config.grid_size, projection).unwrap();
+        // Pixels from the top left corner of a cell
+        let (x, y) = projection.project(SAMPLE_POINT.lat, SAMPLE_POINT.lng, DEFAULT_ZOOM).unwrap();
+        let (x, y) = ((x / index.cell_size()).floor() * index.cell_size(), (y / index.cell_size()).floor() * index.cell_size());
+        let at = |dx: f64, dy: f64| {
+            let (lat, lng) = projection.unproject(x + dx, y + dy, DEFAULT_ZOOM).unwrap();
+            UniqueMarker::from(&marker(lat, lng, None))
+        };
+        // Clustered after enough distant markers that clusters are looked up in the index, and again without them
+        let cluster_sizes = |markers: Vec<UniqueMarker>| {
+            let sizes = [ LINEAR_SCAN_CLUSTERS, 0 ].iter().map(|&distant| {
+                let mut clusters = Vec::new();
+                let mut index = ClusterIndex::default();
+                let distant_markers = (0..distant).map(|i| at(0.0, 1000.0 + 200.0 * i as f64));
+                for mut point in distant_markers.chain(markers.iter().cloned()) {
+                    add_to_closest_cluster(&mut clusters, &mut index, &mut point, DEFAULT_ZOOM, &config).unwrap();
+                }
+                clusters.iter().skip(distant).map(|c| c.size).collect::<Vec<_>>()
+            }).collect::<Vec<_>>();
+            assert_eq!(sizes[0], sizes[1]);
+            sizes[0].clone()
+        };
+
+        // The third marker is closest to the first, but outside its bounds, and inside the bounds of the second
+        assert_eq!(cluster_sizes(vec![ at(0.0, 0.0), at(115.0, 50.0), at(65.0, 0.0) ]), vec![1, 1, 1]);
+        // The third marker is inside the bounds of the first, but closer to the second, two cells away
+        assert_eq!(cluster_sizes(vec![ at(55.5, 55.5), at(-60.5, 0.5), at(0.5, 0.5) ]), vec![1, 1, 1]);
+        assert_eq!(cluster_sizes(vec![ at(55.5, 55.5), at(0.5, 0.5) ]), vec![2]);
+    }
+
+    #[test]
+    fn marker_ids_are_kept_in_clusters() {
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&[
+            marker(43.0, -79.0, Some(7.0)),
+            Marker { id: Some(MarkerId::Text("truck-12".to_string())), ..marker(43.0, -79.0, None) },
+        ]).unwrap();
+
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        let ids = clusters[0].markers.iter().map(|m| m.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids, vec![ Some(MarkerId::Number(7.0)), Some(MarkerId::Text("truck-12".to_string())) ]);
+
+        let same_id = UniqueMarker::from(&marker(10.0, 10.0, Some(7.0)));
+        assert_eq!(clusters[0].markers[0], same_id);
+        assert_ne!(clusters[0].markers[1], same_id);
+    }
+
+    #[test]
+    fn duplicate_marker_ids_are_rejected() {
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&[ marker(43.0, -79.0, Some(7.0)), marker(43.0, -79.0, None) ]).unwrap();
+
+        let already_added = clusterer.push_markers(&[ marker(44.0, -79.0, Some(8.0)), marker(44.0, -79.0, Some(7.0)) ]);
+        assert_eq!(already_added, Err(ClusterError::DuplicateMarkerId(MarkerId::Number(7.0))));
+        let in_batch = clusterer.push_coordinates(&[ 44.0, -79.0, 44.0, -79.0 ], Some(&[ 9, 9 ]));
+        assert_eq!(in_batch, Err(ClusterError::DuplicateMarkerId(MarkerId::Number(9.0))));
+
+        // Neither batch was added, and markers without ids can still share a position
+        clusterer.push_markers(&[ marker(44.0, -79.0, Some(8.0)), marker(43.0, -79.0, None) ]).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters.iter().map(|c| c.size).sum::<u32>(), 4);
+    }
+
+    #[test]
+    fn marker_properties_are_returned_unless_disabled() {
+        let properties = serde_json::json!({ "name": "Depot", "vehicles": [3, 4] });
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&[ Marker { properties: Some(properties.clone()), ..marker(43.0, -79.0, None) } ]).unwrap();
+
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters[0].markers[0].properties, Some(properties));
+        let output = serde_json::to_value(clusters[0].output(&config::Output::default(), false)).unwrap();
+        assert!(output["markers"][0].get("properties").is_none());
+
+        clusterer.apply_config(config::OptionalConfig { include_properties: Some(false), ..config::OptionalConfig::empty() }).unwrap();
+        clusterer.clear_clusters();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters[0].markers[0].properties, None);
+    }
+
+    #[test]
+    fn clusters_aggregate_marker_properties() {
+        use structs::aggregation::AggregateOperation;
+        let aggregation = |name: &str, property: &str, operation| Aggregation { name: name.to_string(), property: property.to_string(), operation };
+        let mut clusterer = clusterer_with(config::OptionalConfig {
+            aggregations: Some(vec![
+                aggregation("revenue", "revenue", AggregateOperation::Sum),
+                aggregation("worst", "severity", AggregateOperation::Max),
+                aggregation("mean_revenue", "revenue", AggregateOperation::Mean),
+                aggregation("regions", "region", AggregateOperation::CountDistinct),
+                aggregation("missing", "missing", AggregateOperation::Min),
+            ]),
+            ..config::OptionalConfig::empty()
+        });
+        let with_properties = |properties| Marker { properties: Some(properties), ..marker(43.0, -79.0, None) };
+        clusterer.push_markers(&[
+            with_properties(serde_json::json!({ "revenue": 10.0, "severity": 2, "region": "east" })),
+            with_properties(serde_json::json!({ "revenue": 5.5, "severity": 4, "region": "west" })),
+            with_properties(serde_json::json!({ "severity": 1, "region": "east" })),
+        ]).unwrap();
+
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        let aggregates = &clusters[0].aggregates;
+        assert_eq!(aggregates.get("revenue"), Some(&15.5));
+        assert_eq!(aggregates.get("worst"), Some(&4.0));
+        assert_eq!(aggregates.get("mean_revenue"), Some(&7.75));
+        assert_eq!(aggregates.get("regions"), Some(&2.0));
+        assert_eq!(aggregates.get("missing"), None);
+
+        // Clusters are calculated again with the new aggregations
+        clusterer.apply_config(config::OptionalConfig { aggregations: Some(vec![ aggregation("worst", "severity", AggregateOperation::Max) ]), ..config::OptionalConfig::empty() }).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters[0].aggregates.keys().collect::<Vec<_>>(), vec!["worst"]);
+    }
+
+    #[test]
+    fn removed_markers_update_their_clusters() {
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&[ marker(43.0, -79.0, Some(1.0)), marker(43.01, -79.01, Some(2.0)), marker(44.5, -76.0, Some(3.0)) ]).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters.len(), 2);
+
+        clusterer.remove_markers(&[ MarkerId::Number(1.0), MarkerId::Number(3.0) ]).unwrap();
+        let modified = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+
+        assert_eq!(modified.len(), 2);
+        assert_eq!(modified[0].uuid, clusters[0].uuid);
+        assert_eq!(modified[0].size, 1);
+        assert!((modified[0].center.lat - 43.01).abs() < f64::EPSILON);
+        assert_eq!(modified[1].uuid, clusters[1].uuid);
+        assert_eq!(modified[1].size, 0);
+        assert!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().is_empty());
+    }
+
+    #[test]
+    fn removing_markers_centers_clusters_like_adding_them() {
+        let markers = [ marker(43.0, -79.0, Some(1.0)), marker(43.05, -79.05, Some(2.0)), marker(43.06, -79.06, Some(3.0)), marker(43.1, -79.1, Some(4.0)) ];
+        let average_center = || config::OptionalConfig { average_center: Some(true), ..config::OptionalConfig::empty() };
+        let mut clusterer = clusterer_with(average_center());
+        clusterer.push_markers(&markers).unwrap();
+        clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        clusterer.remove_markers(&[ MarkerId::Number(4.0) ]).unwrap();
+        let removed = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+
+        let mut fresh = clusterer_with(average_center());
+        fresh.push_markers(&markers[..3]).unwrap();
+        let added = fresh.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+
+        assert_eq!((removed[0].center.lat, removed[0].center.lng), (added[0].center.lat, added[0].center.lng));
+        assert!((added[0].center.lat - 43.0366666).abs() < 1e-6);
+    }
+
+    #[test]
+    fn updated_markers_move_between_clusters() {
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&[ marker(43.0, -79.0, Some(1.0)), marker(43.01, -79.01, Some(2.0)), marker(44.5, -76.0, Some(3.0)) ]).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+
+        clusterer.update_markers(&[ marker(44.51, -76.01, Some(2.0)) ]).unwrap();
+        let modified = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+
+        assert_eq!(modified.len(), 2);
+        for cluster in &modified {
+            let ids = cluster.markers.iter().map(|m| m.id.clone().unwrap()).collect::<Vec<_>>();
+            if cluster.uuid == clusters[0].uuid {
+                assert_eq!(ids, vec![ MarkerId::Number(1.0) ]);
+            } else {
+                assert_eq!(cluster.uuid, clusters[1].uuid);
+                assert_eq!(ids, vec![ MarkerId::Number(3.0), MarkerId::Number(2.0) ]);
+            }
+        }
+    }
+
+    #[test]
+    fn cluster_diff_reports_added_updated_and_removed_clusters() {
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&[ marker(43.0, -79.0, Some(1.0)), marker(43.01, -79.01, Some(2.0)), marker(44.5, -76.0, Some(3.0)) ]).unwrap();
+
+        let diff = clusterer.cluster_diff_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!((diff.added.len(), diff.updated.len(), diff.removed.len()), (2, 0, 0));
+        let (first, second) = (diff.added[0].uuid, diff.added[1].uuid);
+
+        clusterer.remove_markers(&[ MarkerId::Number(1.0), MarkerId::Number(3.0) ]).unwrap();
+        let diff = clusterer.cluster_diff_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert!(diff.added.is_empty());
+        assert_eq!(diff.updated.len(), 1);
+        assert_eq!(diff.updated[0].uuid, first);
+        assert_eq!(diff.removed, vec![ second ]);
+
+        clusterer.clear_clusters();
+        let diff = clusterer.cluster_diff_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!((diff.added.len(), diff.updated.len()), (1, 0));
+        assert_eq!(diff.removed, vec![ first ]);
+
+        let replaced = diff.added[0].uuid;
+        let diff = clusterer.cluster_diff_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM + 1).unwrap();
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.removed, vec![ replaced ]);
+    }
+
     #[test]
     fn test_1000_markers() {
-        let mut sample_markers = vec![ Marker { lat: 43.0, lng: -79.0 }; 1000 ].iter().map(UniqueMarker::from).collect::<Vec<_>>();
+        let mut sample_markers = vec![ marker(43.0, -79.0, None); 1000 ].iter().map(UniqueMarker::from).collect::<Vec<_>>();
         
         let clustered = &mut Vec::new();
-        cluster_markers(clustered, &mut sample_markers, &DEFAULT_BOUNDS, DEFAULT_ZOOM, &Config::default());
+        cluster_markers(clustered, &mut ClusterIndex::default(), &mut sample_markers, &DEFAULT_BOUNDS, DEFAULT_ZOOM, &Config::default()).unwrap();
         assert_eq!(clustered.len(), 1);
-        assert_eq!(clustered.get(0).unwrap().size, 1000);
+        assert_eq!(clustered[0].size, 1000);
+    }
+
+    #[test]
+    fn hierarchy_includes_all_markers_at_every_zoom() {
+        let sample_markers = (0..500)
+            .map(|i| marker(40.5 + f64::from(i % 25) * 0.15, -80.5 + f64::from(i / 25) * 0.25, None))
+            .collect::<Vec<_>>()
+            .iter()
+            .map(UniqueMarker::from)
+            .collect::<Vec<_>>();
+
+        let hierarchy = ClusterHierarchy::new(&sample_markers, 16, 60.0, false, &[], &WebMercator).unwrap();
+        let mut previous_count = 0;
+        for zoom in 0..=17 {
+            let clusters = hierarchy.clusters(&sample_markers, &calculate_extended_bounds(&DEFAULT_BOUNDS, zoom, 60.0, &WebMercator).unwrap(), zoom, MarkerOutput::All, true, &WebMercator).unwrap();
+            let cluster_point_count = clusters.iter().fold(0, |sum, x| sum + x.markers.len() as u32);
+            assert_eq!(sample_markers.len() as u32, cluster_point_count);
+            assert!(clusters.len() >= previous_count);
+            previous_count = clusters.len();
+        }
+        assert_eq!(previous_count, sample_markers.len());
+    }
+
+    #[test]
+    fn hierarchy_is_kept_until_its_options_change() {
+        let mut clusterer = clusterer_with(config::OptionalConfig { hierarchical: Some(true), ..config::OptionalConfig::empty() });
+        clusterer.push_markers(&[ SAMPLE_POINT.clone(), marker(44.5, -76.0, None) ]).unwrap();
+        let uuids = |clusterer: &mut Clusterer| {
+            let mut uuids = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().iter().map(|c| c.uuid).collect::<Vec<_>>();
+            uuids.sort();
+            uuids
+        };
+        let before = uuids(&mut clusterer);
+
+        clusterer.apply_config(config::OptionalConfig { include_properties: Some(false), grid_size: Some(60.0), ..config::OptionalConfig::empty() }).unwrap();
+        assert_eq!(uuids(&mut clusterer), before);
+
+        clusterer.apply_config(config::OptionalConfig { grid_size: Some(80.0), ..config::OptionalConfig::empty() }).unwrap();
+        assert!(uuids(&mut clusterer).iter().all(|uuid| !before.contains(uuid)));
+    }
+
+    #[test]
+    fn hierarchy_clusters_only_copy_the_markers_that_are_returned() {
+        use structs::aggregation::AggregateOperation;
+        let markers = (0..10)
+            .map(|i| Marker { properties: Some(serde_json::json!({ "revenue": i })), ..marker(43.0 + f64::from(i) * 0.01, -79.0, Some(f64::from(i))) })
+            .map(|m| UniqueMarker::from(&m))
+            .collect::<Vec<_>>();
+        let revenue = Aggregation { name: "revenue".to_string(), property: "revenue".to_string(), operation: AggregateOperation::Sum };
+        let hierarchy = ClusterHierarchy::new(&markers, 16, 60.0, false, &[ revenue ], &WebMercator).unwrap();
+        let clusters = |marker_output| hierarchy.clusters(&markers, &DEFAULT_BOUNDS, 0, marker_output, true, &WebMercator).unwrap();
+
+        let none = clusters(MarkerOutput::None);
+        assert_eq!(none.len(), 1);
+        assert_eq!(none[0].size, 10);
+        assert!(none[0].markers.is_empty());
+        assert_eq!(none[0].aggregates.get("revenue"), Some(&45.0));
+        assert_eq!(clusters(MarkerOutput::Sample(3))[0].markers.len(), 3);
+        let ids = clusters(MarkerOutput::Ids);
+        assert_eq!(ids[0].markers.len(), 10);
+        assert!(ids[0].markers.iter().all(|m| m.id.is_some() && m.properties.is_none()));
+        assert_eq!(clusters(MarkerOutput::All)[0].markers[0].properties, markers[0].properties);
+    }
+
+    #[test]
+    fn kd_tree_range_matches_linear_scan() {
+        let points = (0..1000).map(|i| (f64::from(i * 37 % 101), f64::from(i * 53 % 97))).collect::<Vec<_>>();
+        let tree = structs::kd_tree::KdTree::new(&points);
+
+        let mut found = tree.range(20.0, 30.0, 60.0, 45.0);
+        found.sort();
+        let expected = points.iter().enumerate()
+            .filter(|(_, &(x, y))| (20.0..=60.0).contains(&x) && (30.0..=45.0).contains(&y))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn clusterers_are_independent() {
+        let mut first = Clusterer::new();
+        let mut second = Clusterer::new();
+        first.push_markers(&[ SAMPLE_POINT.clone(), SAMPLE_POINT.clone() ]).unwrap();
+        second.push_markers(&[ marker(44.0, -77.0, None) ]).unwrap();
+
+        let first_clusters = first.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        let second_clusters = second.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+
+        assert_eq!(first_clusters.len(), 1);
+        assert_eq!(first_clusters[0].size, 2);
+        assert_eq!(second_clusters.len(), 1);
+        assert_eq!(second_clusters[0].size, 1);
+    }
+
+    #[test]
+    fn grid_changes_recluster_the_markers() {
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&[ marker(43.0, -79.0, None), marker(43.0, -78.5, None) ]).unwrap();
+        assert_eq!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().iter().map(|c| c.size).collect::<Vec<_>>(), vec![1, 1]);
+
+        clusterer.apply_config(config::OptionalConfig { grid_size: Some(400.0), ..config::OptionalConfig::empty() }).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters.iter().filter(|c| c.size > 0).map(|c| c.size).collect::<Vec<_>>(), vec![2]);
+
+        clusterer.apply_config(config::OptionalConfig { average_center: Some(true), ..config::OptionalConfig::empty() }).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters.iter().filter(|c| c.size > 0).map(|c| c.size).collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn bad_input_returns_errors_and_leaves_clusterer_usable() {
+        let mut clusterer = Clusterer::new();
+        assert_eq!(
+            clusterer.push_markers(&[ SAMPLE_POINT.clone(), marker(91.0, 0.0, None) ]),
+            Err(ClusterError::InvalidCoordinate { lat: 91.0, lng: 0.0 })
+        );
+        assert_eq!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, 30).unwrap_err(), ClusterError::ProjectionOutOfRange { zoom: 30 });
+
+        clusterer.push_markers(&[ marker(43.5, -79.5, None) ]).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].size, 1);
     }
 
     #[test]
@@ -224,11 +610,607 @@ mod tests {
             west: -79.3832,
         };
 
-        let extended_bounds = calculate_extended_bounds(&bounds, DEFAULT_ZOOM, 60.0);
+        let extended_bounds = calculate_extended_bounds(&bounds, DEFAULT_ZOOM, 60.0, &WebMercator).unwrap();
 
         assert!(bounds.north < extended_bounds.north);
         assert!(bounds.east < extended_bounds.east);
         assert!(bounds.south > extended_bounds.south);
         assert!(bounds.west > extended_bounds.west);
     }
+
+    #[test]
+    fn markers_across_the_antimeridian_share_a_cluster() {
+        let pacific = Bounds { north: 10.0, east: -170.0, south: -10.0, west: 170.0 };
+        let mut markers = vec![
+            UniqueMarker::from(&marker(0.0, 179.99, None)),
+            UniqueMarker::from(&marker(0.0, -179.99, None)),
+            UniqueMarker::from(&marker(0.0, 0.0, None)),
+        ];
+
+        let extended_bounds = calculate_extended_bounds(&pacific, DEFAULT_ZOOM, 60.0, &WebMercator).unwrap();
+        assert!(extended_bounds.west > extended_bounds.east);
+        assert!(extended_bounds.contains(&markers[0], &WebMercator) && extended_bounds.contains(&markers[1], &WebMercator));
+        assert!(!extended_bounds.contains(&markers[2], &WebMercator));
+
+        let mut clusters = Vec::new();
+        cluster_markers(&mut clusters, &mut ClusterIndex::default(), &mut markers, &pacific, DEFAULT_ZOOM, &Config::default()).unwrap();
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].size, 2);
+
+        let hierarchy = ClusterHierarchy::new(&markers, 16, 60.0, true, &[], &WebMercator).unwrap();
+        let clusters = hierarchy.clusters(&markers, &pacific, DEFAULT_ZOOM, MarkerOutput::All, true, &WebMercator).unwrap();
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].size, 2);
+        assert!(clusters[0].center.lng.abs() > 179.0);
+
+        // Markers on the antimeridian itself, when the whole world is in view
+        let world = Bounds { north: 85.0, east: 179.0, south: -85.0, west: -179.0 };
+        for hierarchical in [ false, true ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig { hierarchical: Some(hierarchical), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&[ marker(0.0, 180.0, None), marker(0.0, -180.0, None) ]).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&world, 2).unwrap();
+            assert_eq!(clusters.iter().map(|c| c.size).sum::<u32>(), 2);
+        }
+    }
+
+    #[test]
+    fn polar_markers_are_clustered() {
+        let arctic = Bounds { north: 90.0, east: 30.0, south: 80.0, west: -30.0 };
+        let mut markers = vec![
+            UniqueMarker::from(&marker(89.5, 0.0, None)),
+            UniqueMarker::from(&marker(89.4, 0.1, None)),
+            UniqueMarker::from(&marker(86.0, 0.0, None)),
+        ];
+
+        let extended_bounds = calculate_extended_bounds(&arctic, DEFAULT_ZOOM, 60.0, &WebMercator).unwrap();
+        assert_eq!(extended_bounds.north, 90.0);
+        assert!(extended_bounds.south < arctic.south);
+
+        let mut clusters = Vec::new();
+        cluster_markers(&mut clusters, &mut ClusterIndex::default(), &mut markers, &arctic, DEFAULT_ZOOM, &Config::default()).unwrap();
+        assert_eq!(clusters.iter().map(|c| c.size).sum::<u32>(), 3);
+        assert!(clusters.iter().any(|c| c.size >= 2));
+
+        let hierarchy = ClusterHierarchy::new(&markers, 16, 60.0, false, &[], &WebMercator).unwrap();
+        let clusters = hierarchy.clusters(&markers, &arctic, DEFAULT_ZOOM, MarkerOutput::All, true, &WebMercator).unwrap();
+        assert_eq!(clusters.iter().map(|c| c.size).sum::<u32>(), 3);
+
+        // Markers on the poles themselves, like the South Pole station
+        let world = Bounds { north: 85.0, east: 179.0, south: -85.0, west: -179.0 };
+        for hierarchical in [ false, true ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig { hierarchical: Some(hierarchical), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&[ marker(90.0, 0.0, None), marker(-90.0, 0.0, None) ]).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&world, 2).unwrap();
+            assert_eq!(clusters.iter().map(|c| c.size).sum::<u32>(), 2);
+        }
+    }
+
+    #[test]
+    fn projections_convert_both_ways_and_can_be_replaced() {
+        for projection in [ &WebMercator as &dyn Projection, &Equirectangular ] {
+            let (x, y) = projection.project(SAMPLE_POINT.lat, SAMPLE_POINT.lng, DEFAULT_ZOOM).unwrap();
+            let (lat, lng) = projection.unproject(x, y, DEFAULT_ZOOM).unwrap();
+            assert!((lat - SAMPLE_POINT.lat).abs() < 1e-9 && (lng - SAMPLE_POINT.lng).abs() < 1e-9);
+        }
+
+        // Web Mercator one zoom in, so clustering with it at a zoom matches Web Mercator at the next
+        #[derive(Debug)]
+        struct ZoomedIn;
+        impl Projection for ZoomedIn {
+            fn project(&self, lat: f64, lng: f64, zoom: usize) -> Result<(f64, f64), ClusterError> {
+                WebMercator.project(lat, lng, zoom + 1)
+            }
+            fn unproject(&self, x: f64, y: f64, zoom: usize) -> Result<(f64, f64), ClusterError> {
+                WebMercator.unproject(x, y, zoom + 1)
+            }
+            fn world_size(&self, zoom: usize) -> (f64, f64) {
+                WebMercator.world_size(zoom + 1)
+            }
+        }
+
+        let markers = (0..100).map(|i| marker(40.0 + f64::from(i % 10) * 0.4, -81.0 + f64::from(i / 10) * 0.5, None)).collect::<Vec<_>>();
+        let cluster_count = |projection: ProjectionKind, zoom: usize| {
+            let mut clusterer = clusterer_with(config::OptionalConfig { projection: Some(projection), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&markers).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, zoom).unwrap();
+            assert_eq!(clusters.iter().map(|c| c.size).sum::<u32>(), 100);
+            clusters.len()
+        };
+        assert_eq!(cluster_count(ProjectionKind::Custom(std::sync::Arc::new(ZoomedIn)), DEFAULT_ZOOM), cluster_count(ProjectionKind::WebMercator, DEFAULT_ZOOM + 1));
+        assert!(cluster_count(ProjectionKind::Equirectangular, DEFAULT_ZOOM) > 0);
+    }
+
+    #[test]
+    fn planar_coordinates_are_clustered_by_euclidean_distance() {
+        let plan = Bounds { north: 1000.0, east: 3000.0, south: -1000.0, west: -1000.0 };
+        let markers = [ (0.0, 0.0), (10.0, 10.0), (500.0, 500.0), (-300.0, 2000.0), (-290.0, 2010.0) ].iter()
+            .map(|&(lat, lng)| marker(lat, lng, None))
+            .collect::<Vec<_>>();
+        let planar = ProjectionKind::Planar(Planar { scale: 1.0 });
+
+        assert!(calculate_extended_bounds(&plan, 0, 60.0, planar.projection()).unwrap().east - plan.east == 60.0);
+        assert_eq!(planar.projection().distance(&markers[0], &UniqueMarker::from(&markers[1])), 200.0_f64.sqrt());
+
+        for hierarchical in [ false, true ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig { projection: Some(planar.clone()), hierarchical: Some(hierarchical), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&markers).unwrap();
+            let mut sizes = clusterer.clusters_in_bounds(&plan, 0).unwrap().iter().map(|c| c.size).collect::<Vec<_>>();
+            sizes.sort();
+            assert_eq!(sizes, vec![1, 2, 2]);
+        }
+    }
+
+    #[test]
+    fn planar_scale_must_be_a_positive_number() {
+        let planar = |scale| config::OptionalConfig { projection: Some(ProjectionKind::Planar(Planar { scale })), ..config::OptionalConfig::empty() };
+        let mut clusterer = Clusterer::new();
+        for scale in [ 0.0, -1.0, f64::NAN, f64::INFINITY ] {
+            assert!(matches!(clusterer.apply_config(planar(scale)), Err(ClusterError::InvalidInput(_))), "{}", scale);
+        }
+        assert_eq!(clusterer.config().projection, ProjectionKind::WebMercator);
+        clusterer.apply_config(planar(0.5)).unwrap();
+    }
+
+    #[test]
+    fn planar_bounds_dont_wrap_or_stop_at_poles() {
+        let planar = ProjectionKind::Planar(Planar { scale: 1.0 });
+        let edges = Bounds { north: 90.0, east: 180.0, south: -90.0, west: -180.0 };
+        let on_edges = [ marker(90.0, 0.0, None), marker(-90.0, 0.0, None), marker(0.0, 180.0, None), marker(0.0, -180.0, None) ];
+        for marker in on_edges.iter().map(UniqueMarker::from) {
+            assert!(edges.contains(&marker, &WebMercator));
+            assert!(!edges.contains(&marker, planar.projection()));
+        }
+
+        // With `west` greater than `east`, a box on a plane is empty instead of crossing the antimeridian
+        let backwards = Bounds { north: 1000.0, east: 200.0, south: -1000.0, west: 400.0 };
+        let markers = [ marker(0.0, 100.0, None), marker(0.0, 300.0, None), marker(0.0, 500.0, None) ];
+        assert!(markers.iter().all(|m| !backwards.contains(&UniqueMarker::from(m), planar.projection())));
+        let mut clusterer = clusterer_with(config::OptionalConfig { projection: Some(planar), ..config::OptionalConfig::empty() });
+        clusterer.push_markers(&markers).unwrap();
+        assert!(clusterer.clusters_in_bounds(&backwards, 0).unwrap().is_empty());
+        assert_eq!(clusterer.clusters_in_bounds(&Bounds { north: 1000.0, east: 600.0, south: -1000.0, west: 200.0 }, 0).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn extreme_planar_coordinates_are_clustered() {
+        let plan = Bounds { north: 1e23, east: 1e23, south: -1e23, west: -1e23 };
+        let markers = [ marker(0.0, 1e22, None), marker(0.0, -1e22, None), marker(1e22, 0.0, None), marker(0.0, 0.0, None) ];
+        for algorithm in [ config::Algorithm::Greedy, config::Algorithm::Grid ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig {
+                projection: Some(ProjectionKind::Planar(Planar { scale: 1.0 })),
+                algorithm: Some(algorithm),
+                ..config::OptionalConfig::empty()
+            });
+            clusterer.push_markers(&markers).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&plan, 0).unwrap();
+            assert_eq!(clusters.iter().map(|c| c.size).sum::<u32>(), 4);
+        }
+    }
+
+    #[test]
+    fn cells_left_of_the_antimeridian_wrap_to_the_last_column() {
+        let config = Config::default();
+        let projection = config.projection.projection();
+        let mut index = ClusterIndex::default();
+        index.sync(&[], DEFAULT_ZOOM, config.grid_size, projection).unwrap();
+        let world_width = projection.world_size(DEFAULT_ZOOM).0;
+        assert_eq!(index.pixel_cell((-1.0, 100.0)), index.pixel_cell((world_width - 1.0, 100.0)));
+    }
+
+    #[test]
+    fn closest_cluster_is_closest_on_screen_unless_disabled() {
+        // Web Mercator stretches latitudes further from the equator, so a marker a little closer to 75° than 65°
+        // in kilometres is closer to 65° on screen
+        let closest_center = |distance_in_pixels: bool| {
+            let config = Config { grid_size: 100.0, distance_in_pixels, ..Config::default() };
+            let mut clusters = Vec::new();
+            let mut index = ClusterIndex::default();
+            for lat in [ 75.0, 65.0, 70.5 ] {
+                let mut point = UniqueMarker::from(&marker(lat, 0.0, None));
+                add_to_closest_cluster(&mut clusters, &mut index, &mut point, 3, &config).unwrap();
+            }
+            assert_eq!(clusters.len(), 2);
+            clusters.into_iter().find(|c| c.size == 2).unwrap().center.lat
+        };
+        assert_eq!(closest_center(true), 65.0);
+        assert_eq!(closest_center(false), 75.0);
+    }
+
+    #[test]
+    fn grid_clusters_dont_depend_on_order_or_panning() {
+        let grid = || config::OptionalConfig { algorithm: Some(config::Algorithm::Grid), ..config::OptionalConfig::empty() };
+        assert_clusters_dont_depend_on_order_or_panning(grid);
+
+        // A cluster's id comes from its cell, so it's the same after panning
+        let uuids = |bounds: &[&Bounds]| {
+            let mut clusterer = clusterer_with(config::OptionalConfig { only_return_modified_clusters: Some(false), ..grid() });
+            clusterer.push_markers(&grid_of_markers()).unwrap();
+            for bounds in bounds {
+                clusterer.clusters_in_bounds(bounds, DEFAULT_ZOOM).unwrap();
+            }
+            clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().iter().map(|c| c.uuid).collect::<HashSet<_>>()
+        };
+        let western_half = Bounds { east: -79.2, ..DEFAULT_BOUNDS.clone() };
+        assert_eq!(uuids(&[ &western_half ]), uuids(&[]));
+        // and it's a valid UUID
+        assert!(uuids(&[]).iter().all(|uuid| uuid.get_version() == Some(uuid::Version::Sha1) && uuid.get_variant() == Some(uuid::Variant::RFC4122)));
+    }
+
+    #[test]
+    fn dbscan_clusters_dense_regions_and_leaves_noise_on_its_own() {
+        use dbscan::{ Dbscan, DistanceUnit };
+
+        // ~1 km apart in a 10 and a 5 marker region, with two markers far from anything
+        let mut markers = (0..10).map(|i| marker(43.0 + f64::from(i) * 0.009, -79.0, None)).collect::<Vec<_>>();
+        markers.extend((0..5).map(|i| marker(42.0, -80.0 + f64::from(i) * 0.012, None)));
+        markers.push(marker(44.0, -77.0, None));
+        markers.push(marker(41.0, -76.5, None));
+
+        for dbscan in [ Dbscan { eps: 20.0, unit: DistanceUnit::Pixels, min_points: 4 }, Dbscan { eps: 2500.0, unit: DistanceUnit::Metres, min_points: 4 } ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig { algorithm: Some(config::Algorithm::Dbscan(dbscan)), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&markers).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+            let mut sizes = clusters.iter().map(|c| c.size).collect::<Vec<_>>();
+            sizes.sort();
+            assert_eq!(sizes, vec![1, 1, 5, 10], "{:?}", dbscan);
+            assert!(clusters.iter().all(|c| c.markers.iter().all(|m| m.lat >= c.bounds.south && m.lat <= c.bounds.north)));
+
+            // Nothing new in bounds, so nothing is reclustered
+            assert!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().is_empty());
+
+            // A marker next to the smaller region joins it, replacing its cluster
+            clusterer.push_markers(&[ marker(42.0, -79.94, None) ]).unwrap();
+            let changed = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+            assert_eq!(changed.iter().filter(|c| c.size > 0).map(|c| c.size).collect::<Vec<_>>(), vec![6]);
+            assert_eq!(changed.iter().filter(|c| c.size == 0).count(), 1);
+        }
+    }
+
+    #[test]
+    fn dbscan_reclusters_after_markers_are_removed() {
+        use dbscan::{ Dbscan, DistanceUnit };
+
+        // A chain of markers ~18 pixels apart, each within `eps` of the next only
+        let markers = (0..7).map(|i| marker(43.0, -79.0 + f64::from(i) * 0.1, Some(f64::from(i)))).collect::<Vec<_>>();
+        let mut clusterer = clusterer_with(config::OptionalConfig {
+            algorithm: Some(config::Algorithm::Dbscan(Dbscan { eps: 20.0, unit: DistanceUnit::Pixels, min_points: 3 })),
+            only_return_modified_clusters: Some(false),
+            ..config::OptionalConfig::empty()
+        });
+        clusterer.push_markers(&markers).unwrap();
+        let bounds = Bounds { north: 44.0, east: -78.0, south: 42.0, west: -80.0 };
+        assert_eq!(clusterer.clusters_in_bounds(&bounds, 8).unwrap().iter().map(|c| c.size).collect::<Vec<_>>(), vec![7]);
+
+        // Without the middle of the chain, what's left on either side is too small to be dense
+        clusterer.remove_markers(&(2..5).map(|i| MarkerId::Number(f64::from(i))).collect::<Vec<_>>()).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&bounds, 8).unwrap();
+        assert_eq!(clusters.iter().map(|c| c.size).collect::<Vec<_>>(), vec![1, 1, 1, 1]);
+        for cluster in clusters {
+            let marker = &cluster.markers[0];
+            assert_eq!((cluster.bounds.north, cluster.bounds.east, cluster.bounds.south, cluster.bounds.west), (marker.lat, marker.lng, marker.lat, marker.lng));
+        }
+    }
+
+    #[test]
+    fn extent_bounds_contain_the_markers_on_their_edges() {
+        use dbscan::{ Dbscan, DistanceUnit };
+
+        let markers = [ marker(43.0, -79.0, None), marker(43.01, -79.01, None), marker(43.02, -78.99, None), marker(44.0, -77.0, None) ];
+        for projection in [ ProjectionKind::WebMercator, ProjectionKind::Planar(Planar { scale: 1.0 }) ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig {
+                algorithm: Some(config::Algorithm::Dbscan(Dbscan { eps: 20.0, unit: DistanceUnit::Pixels, min_points: 3 })),
+                projection: Some(projection.clone()),
+                ..config::OptionalConfig::empty()
+            });
+            clusterer.push_markers(&markers).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+            assert_eq!(clusters.len(), 2);
+            for cluster in clusters {
+                assert!(cluster.markers.iter().all(|m| cluster.contains(m, projection.projection())), "{:?}", cluster);
+                assert!(cluster.markers.iter().all(|m| !cluster.bounds.contains(m, projection.projection())), "{:?}", cluster);
+            }
+        }
+    }
+
+    #[test]
+    fn kmeans_splits_markers_in_bounds_into_k_clusters() {
+        use kmeans::KMeans;
+
+        let mut markers = Vec::new();
+        for (i, &(lat, lng, count)) in [ (41.0, -80.0, 6), (44.0, -80.0, 4), (42.5, -76.5, 5) ].iter().enumerate() {
+            markers.extend((0..count).map(|j| marker(lat + f64::from(j) * 0.02, lng - f64::from(i as u32) * 0.01, None)));
+        }
+        markers.push(marker(10.0, 10.0, None));
+
+        let clusters = |seed: u64| {
+            let mut clusterer = clusterer_with(config::OptionalConfig { algorithm: Some(config::Algorithm::KMeans(KMeans { k: 3, seed, max_iterations: 100 })), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&markers).unwrap();
+            let mut clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+            clusters.sort_by(|a, b| a.center.lat.total_cmp(&b.center.lat));
+            clusters
+        };
+
+        let first = clusters(7);
+        assert_eq!(first.iter().map(|c| c.size).collect::<Vec<_>>(), vec![6, 5, 4]);
+        for cluster in &first {
+            assert!(cluster.markers.iter().all(|m| m.lat >= cluster.bounds.south && m.lat <= cluster.bounds.north && m.lng >= cluster.bounds.west && m.lng <= cluster.bounds.east));
+        }
+        let again = clusters(7);
+        assert_eq!(
+            first.iter().map(|c| (c.center.lat, c.center.lng)).collect::<Vec<_>>(),
+            again.iter().map(|c| (c.center.lat, c.center.lng)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn kmeans_needs_at_least_one_cluster() {
+        use kmeans::KMeans;
+
+        let mut clusterer = Clusterer::new();
+        let kmeans = |k| config::OptionalConfig { algorithm: Some(config::Algorithm::KMeans(KMeans { k, ..KMeans::default() })), ..config::OptionalConfig::empty() };
+        assert!(matches!(clusterer.apply_config(kmeans(0)), Err(ClusterError::InvalidInput(_))));
+        assert_eq!(clusterer.config().algorithm, config::Algorithm::Greedy);
+
+        clusterer.apply_config(kmeans(1)).unwrap();
+        clusterer.push_markers(&[ marker(43.0, -79.0, None), marker(44.0, -77.0, None) ]).unwrap();
+        assert_eq!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().iter().map(|c| c.size).collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn kmeans_rebuilds_across_the_antimeridian_like_it_clusters_in_bounds() {
+        use algorithm::ClusterAlgorithm;
+        use kmeans::{ KMeans, KMeansAlgorithm };
+
+        let pacific = Bounds { north: 20.0, east: -170.0, south: -10.0, west: 170.0 };
+        let mut markers = [ (0.0, 179.9), (0.0, -179.9), (8.0, 179.9) ].iter()
+            .flat_map(|&(lat, lng)| (0..3).map(move |j| marker(lat + f64::from(j) * 0.01, lng, None)))
+            .map(|m| UniqueMarker::from(&m))
+            .collect::<Vec<_>>();
+        let config = Config::default();
+        let mut kmeans = KMeansAlgorithm::new(KMeans { k: 2, ..KMeans::default() });
+        let groups = |clusters: &[Cluster]| {
+            let mut groups = clusters.iter().map(|c| {
+                let mut lats = c.markers.iter().map(|m| (m.lat * 100.0).round() as i64).collect::<Vec<_>>();
+                lats.sort();
+                lats
+            }).collect::<Vec<_>>();
+            groups.sort();
+            groups
+        };
+
+        let mut clusters = Vec::new();
+        kmeans.cluster_in_bounds(&mut clusters, &mut markers, &pacific, DEFAULT_ZOOM, &config).unwrap();
+        // The markers either side of the antimeridian are together, apart from the markers to the north
+        assert_eq!(groups(&clusters), vec![ vec![0, 0, 1, 1, 2, 2], vec![800, 801, 802] ]);
+
+        let mut rebuilt = Vec::new();
+        kmeans.rebuild(&mut rebuilt, &mut markers, DEFAULT_ZOOM, &config).unwrap();
+        assert_eq!(groups(&rebuilt), groups(&clusters));
+    }
+
+    #[test]
+    fn algorithms_can_be_implemented_downstream() {
+        use algorithm::ClusterAlgorithm;
+
+        // Every marker in a cluster of its own
+        #[derive(Debug, Clone)]
+        struct Singletons;
+        impl ClusterAlgorithm for Singletons {
+            fn add(&mut self, clusters: &mut Vec<Cluster>, markers: &mut [UniqueMarker], marker: usize, _zoom: usize, config: &Config) -> Result<HashSet<Uuid>, ClusterError> {
+                let cluster = Cluster::from_markers(markers[marker].uuid(), vec![ markers[marker].clone() ], true, config.projection.projection(), &config.aggregations).unwrap();
+                clusters.push(cluster);
+                Ok(Some(markers[marker].uuid()).into_iter().collect())
+            }
+        }
+
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&vec![ SAMPLE_POINT.clone(); 5 ]).unwrap();
+        assert_eq!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().len(), 1);
+
+        clusterer.set_algorithm(Box::new(Singletons));
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters.len(), 5);
+        assert!(clusters.iter().all(|c| c.size == 1));
+
+        let mut clusters = Vec::new();
+        let mut markers = vec![ SAMPLE_POINT.clone(); 5 ].iter().map(UniqueMarker::from).collect::<Vec<_>>();
+        markers[0].is_added = true;
+        markers[3].is_added = true;
+        Singletons.rebuild(&mut clusters, &mut markers, DEFAULT_ZOOM, &Config::default()).unwrap();
+        assert_eq!(clusters.len(), 2);
+
+        clusterer.apply_config(config::OptionalConfig { algorithm: Some(config::Algorithm::Grid), ..config::OptionalConfig::empty() }).unwrap();
+        assert_eq!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn deterministic_clusters_dont_depend_on_order_or_panning() {
+        let markers = grid_of_markers();
+        let in_order = assert_clusters_dont_depend_on_order_or_panning(|| config::OptionalConfig { deterministic: Some(true), ..config::OptionalConfig::empty() });
+
+        // Nothing changes until the markers do, and unchanged clusters keep their ids when they do
+        let mut clusterer = clusterer_with(config::OptionalConfig { deterministic: Some(true), ..config::OptionalConfig::empty() });
+        clusterer.push_markers(&markers).unwrap();
+        assert_eq!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().len(), in_order.len());
+        assert!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap().is_empty());
+        clusterer.remove_markers(&[ MarkerId::Number(199.0) ]).unwrap();
+        let changed = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert!(!changed.is_empty() && changed.len() < in_order.len());
+
+        // Clusters out of the bounds are left until they come into them
+        for only_return_modified_clusters in [ true, false ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig { deterministic: Some(true), only_return_modified_clusters: Some(only_return_modified_clusters), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&markers).unwrap();
+            clusterer.push_markers(&[ marker(10.0, 10.0, None) ]).unwrap();
+            let in_bounds = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+            assert_eq!(in_bounds.len(), in_order.len());
+            let elsewhere = Bounds { north: 11.0, east: 11.0, south: 9.0, west: 9.0 };
+            let clusters = clusterer.clusters_in_bounds(&elsewhere, DEFAULT_ZOOM).unwrap();
+            assert_eq!(clusters.iter().map(|c| (c.center.lat, c.center.lng)).collect::<Vec<_>>(), vec![ (10.0, 10.0) ]);
+        }
+
+        // Clusters that keep their markers are still centered on them like newly built ones
+        let markers = [ marker(43.0, -79.0, Some(1.0)), marker(43.05, -79.05, Some(2.0)), marker(43.06, -79.06, Some(3.0)), marker(43.1, -79.1, Some(4.0)) ];
+        let average_center = || config::OptionalConfig { deterministic: Some(true), average_center: Some(true), ..config::OptionalConfig::empty() };
+        let mut clusterer = clusterer_with(average_center());
+        clusterer.push_markers(&markers).unwrap();
+        clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        clusterer.remove_markers(&[ MarkerId::Number(4.0) ]).unwrap();
+        let removed = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        let mut fresh = clusterer_with(average_center());
+        fresh.push_markers(&markers[..3]).unwrap();
+        let added = fresh.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!((removed[0].center.lat, removed[0].center.lng), (added[0].center.lat, added[0].center.lng));
+    }
+
+    #[test]
+    fn expansion_zoom_is_where_a_cluster_splits() {
+        let markers = [ (43.0, -79.0), (43.01, -79.01), (43.3, -79.3), (43.31, -79.3) ].iter()
+            .map(|&(lat, lng)| marker(lat, lng, None))
+            .collect::<Vec<_>>();
+        for hierarchical in [ false, true ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig { hierarchical: Some(hierarchical), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&markers).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, 5).unwrap();
+            assert_eq!(clusters.len(), 1);
+
+            let expansion_zoom = clusterer.cluster_expansion_zoom(clusters[0].uuid).unwrap().unwrap();
+            let count_at = |zoom: usize| {
+                let mut other = clusterer_with(config::OptionalConfig { hierarchical: Some(hierarchical), ..config::OptionalConfig::empty() });
+                other.push_markers(&markers).unwrap();
+                other.clusters_in_bounds(&DEFAULT_BOUNDS, zoom).unwrap().len()
+            };
+            assert_eq!(count_at(expansion_zoom - 1), 1);
+            assert!(count_at(expansion_zoom) > 1);
+
+            // The clusters at the current zoom are left alone
+            if !hierarchical {
+                assert!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, 5).unwrap().is_empty());
+            }
+            let unknown = Uuid::new_v4();
+            assert_eq!(clusterer.cluster_expansion_zoom(unknown), Err(ClusterError::ClusterNotFound(unknown)));
+        }
+
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&vec![ SAMPLE_POINT.clone(); 3 ]).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusterer.cluster_expansion_zoom(clusters[0].uuid), Ok(None));
+    }
+
+    #[test]
+    fn cluster_leaves_are_paged_and_children_are_at_the_next_zoom() {
+        let markers = (0..25).map(|i| marker(43.0 + f64::from(i % 5) * 0.06, -79.0 + f64::from(i / 5) * 0.06, Some(f64::from(i)))).collect::<Vec<_>>();
+        for hierarchical in [ false, true ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig { hierarchical: Some(hierarchical), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&markers).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, 6).unwrap();
+            assert_eq!(clusters.len(), 1);
+            let uuid = clusters[0].uuid;
+
+            let mut ids = HashSet::new();
+            for page in 0..3 {
+                let leaves = clusterer.cluster_leaves(uuid, 10, page * 10).unwrap();
+                assert_eq!(leaves.len(), if page < 2 { 10 } else { 5 });
+                ids.extend(leaves.iter().map(|m| format!("{:?}", m.id)));
+            }
+            assert_eq!(ids.len(), 25);
+            assert!(clusterer.cluster_leaves(uuid, 10, 30).unwrap().is_empty());
+
+            let children = clusterer.cluster_children(uuid).unwrap();
+            assert_eq!(children.iter().map(|c| c.size).sum::<u32>(), 25);
+            let mut next_zoom = clusterer_with(config::OptionalConfig { hierarchical: Some(hierarchical), ..config::OptionalConfig::empty() });
+            next_zoom.push_markers(&markers).unwrap();
+            assert_eq!(children.len(), next_zoom.clusters_in_bounds(&DEFAULT_BOUNDS, 7).unwrap().len());
+
+            let unknown = Uuid::new_v4();
+            assert_eq!(clusterer.cluster_leaves(unknown, 10, 0), Err(ClusterError::ClusterNotFound(unknown)));
+            assert_eq!(clusterer.cluster_children(unknown).err(), Some(ClusterError::ClusterNotFound(unknown)));
+        }
+    }
+
+    #[test]
+    fn hierarchical_cluster_ids_go_stale_after_markers_change() {
+        let markers = (0..50).map(|i| marker(43.0 + f64::from(i % 5) * 0.01, -79.0 + f64::from(i / 5) * 0.01, Some(f64::from(i)))).collect::<Vec<_>>();
+        for hierarchical in [ false, true ] {
+            let mut clusterer = clusterer_with(config::OptionalConfig { hierarchical: Some(hierarchical), ..config::OptionalConfig::empty() });
+            clusterer.push_markers(&markers).unwrap();
+            let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, 6).unwrap();
+            assert_eq!(clusters.len(), 1);
+            let uuid = clusters[0].uuid;
+
+            clusterer.remove_markers(&[ MarkerId::Number(0.0) ]).unwrap();
+            if hierarchical {
+                // Every cluster is rebuilt with a new id, so only ids from clustering again are found
+                assert_eq!(clusterer.cluster_leaves(uuid, 50, 0), Err(ClusterError::ClusterNotFound(uuid)));
+                assert_eq!(clusterer.cluster_children(uuid).err(), Some(ClusterError::ClusterNotFound(uuid)));
+                assert_eq!(clusterer.cluster_expansion_zoom(uuid), Err(ClusterError::ClusterNotFound(uuid)));
+                let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, 6).unwrap();
+                assert_ne!(clusters[0].uuid, uuid);
+                assert_eq!(clusterer.cluster_leaves(clusters[0].uuid, 50, 0).unwrap().len(), 49);
+            } else {
+                assert_eq!(clusterer.cluster_leaves(uuid, 50, 0).unwrap().len(), 49);
+            }
+        }
+    }
+
+    #[test]
+    fn output_only_includes_the_configured_cluster_fields() {
+        use config::Output;
+        let markers = (0..5).map(|i| marker(43.0, -79.0, if i < 3 { Some(f64::from(i)) } else { None })).collect::<Vec<_>>();
+        let mut clusterer = Clusterer::new();
+        clusterer.push_markers(&markers).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        let output = |markers: MarkerOutput, bounds: bool| serde_json::to_value(clusters[0].output(&Output { markers, bounds }, true)).unwrap();
+
+        assert_eq!(output(MarkerOutput::All, true), serde_json::to_value(&clusters[0]).unwrap());
+        let ids = output(MarkerOutput::Ids, false);
+        assert_eq!(ids["ids"], serde_json::json!([ 0.0, 1.0, 2.0 ]));
+        assert!(ids.get("markers").is_none() && ids.get("bounds").is_none());
+        assert_eq!(output(MarkerOutput::Sample(2), true)["markers"].as_array().unwrap().len(), 2);
+        let none = output(MarkerOutput::None, true);
+        assert_eq!(none["size"], 5);
+        assert!(none.get("markers").is_none() && none.get("ids").is_none() && none.get("bounds").is_some());
+
+        // The clusters kept in Rust still have every marker
+        assert_eq!(clusterer.cluster_leaves(clusters[0].uuid, 10, 0).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn markers_are_added_from_coordinate_buffers() {
+        let mut clusterer = Clusterer::new();
+        clusterer.push_coordinates(&[ 43.0, -79.0, 43.01, -79.01, 10.0, 10.0 ], Some(&[ 7, 8, 9 ])).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].markers.iter().map(|m| m.id.clone()).collect::<Vec<_>>(), vec![ Some(MarkerId::Number(7.0)), Some(MarkerId::Number(8.0)) ]);
+
+        assert!(matches!(clusterer.push_coordinates(&[ 43.0, -79.0, 43.0 ], None), Err(ClusterError::InvalidInput(_))));
+        assert!(matches!(clusterer.push_coordinates(&[ 43.0, -79.0 ], Some(&[ 1, 2 ])), Err(ClusterError::InvalidInput(_))));
+        assert_eq!(clusterer.push_coordinates(&[ 43.0, -79.0, 95.0, 0.0 ], None), Err(ClusterError::InvalidCoordinate { lat: 95.0, lng: 0.0 }));
+        clusterer.push_coordinates(&[ 43.0, -79.0 ], None).unwrap();
+        assert_eq!(clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap()[0].size, 3);
+    }
+
+    #[test]
+    fn cluster_columns_match_the_clusters() {
+        let markers = [ (43.0, -79.0), (43.01, -79.01), (41.0, -76.0) ].iter()
+            .map(|&(lat, lng)| marker(lat, lng, None))
+            .collect::<Vec<_>>();
+        let mut clusterer = clusterer_with(config::OptionalConfig { only_return_modified_clusters: Some(false), ..config::OptionalConfig::empty() });
+        clusterer.push_markers(&markers).unwrap();
+        let columns = clusterer.cluster_columns_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        let clusters = clusterer.clusters_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns.size, clusters.iter().map(|c| c.size).collect::<Vec<_>>());
+        assert_eq!(columns.lat, clusters.iter().map(|c| c.center.lat).collect::<Vec<_>>());
+        assert_eq!(columns.lng, clusters.iter().map(|c| c.center.lng).collect::<Vec<_>>());
+        assert_eq!(Uuid::from_slice(&columns.uuids[16..32]).unwrap(), clusters[1].uuid);
+        assert_eq!(columns.bounds.unwrap()[4..8], [ clusters[1].bounds.north, clusters[1].bounds.east, clusters[1].bounds.south, clusters[1].bounds.west ]);
+
+        // Every cluster is included, not only the changed ones, and bounds can be left out
+        clusterer.apply_config(config::OptionalConfig { output: Some(config::Output { bounds: false, ..config::Output::default() }), ..config::OptionalConfig::empty() }).unwrap();
+        let again = clusterer.cluster_columns_in_bounds(&DEFAULT_BOUNDS, DEFAULT_ZOOM).unwrap();
+        assert_eq!(again.len(), 2);
+        assert!(again.bounds.is_none());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,194 @@
+extern crate googleprojection;
+use self::googleprojection::{ from_ll_to_subpixel, from_pixel_to_ll };
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use structs::{ marker::Marker, unique_marker::UniqueMarker };
+use error::ClusterError;
+
+/// Converts between lat/lng and world pixels at a zoom, the way the map being clustered for renders them.
+///
+/// Implement this to cluster for a map in a CRS that isn't built in, and set it with `ProjectionKind::Custom`.
+pub trait Projection: Debug + Send + Sync {
+    /// World pixel coordinates `(x, y)` of a lat/lng at the given zoom, with y increasing southwards.
+    fn project(&self, lat: f64, lng: f64, zoom: usize) -> Result<(f64, f64), ClusterError>;
+
+    /// Lat/lng `(lat, lng)` of world pixel coordinates at the given zoom.
+    fn unproject(&self, x: f64, y: f64, zoom: usize) -> Result<(f64, f64), ClusterError>;
+
+    /// Width and height of the world in pixels at the given zoom.
+    fn world_size(&self, zoom: usize) -> (f64, f64);
+
+    /// Whether coordinates are lat/lngs on a globe, so the world wraps around at the antimeridian and
+    /// ends at the poles. Otherwise the plane goes on in every direction and any finite coordinate is valid.
+    fn is_geographic(&self) -> bool {
+        true
+    }
+
+    /// Distance between two points, used to pick the closest of the clusters that could contain a marker.
+    fn distance(&self, p1: &Marker, p2: &UniqueMarker) -> f64 {
+        distance_between_markers(p1, p2)
+    }
+}
+
+/// Latitude at which Web Mercator reaches the top (and its negative the bottom) of the world.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// The projection used by Google Maps, and by default in Leaflet and OpenLayers (EPSG:3857).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebMercator;
+
+impl Projection for WebMercator {
+    /// Latitudes past `MAX_LATITUDE` are projected onto the edge of the world.
+    fn project(&self, lat: f64, lng: f64, zoom: usize) -> Result<(f64, f64), ClusterError> {
+        let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+        from_ll_to_subpixel(&(lng, lat), zoom).ok_or(ClusterError::ProjectionOutOfRange { zoom })
+    }
+
+    /// A `y` above or below the world is clamped to its edge.
+    fn unproject(&self, x: f64, y: f64, zoom: usize) -> Result<(f64, f64), ClusterError> {
+        let y = y.clamp(0.0, self.world_size(zoom).1);
+        let (lng, lat) = from_pixel_to_ll(&(x, y), zoom).ok_or(ClusterError::ProjectionOutOfRange { zoom })?;
+        Ok((lat, lng))
+    }
+
+    fn world_size(&self, zoom: usize) -> (f64, f64) {
+        let size = 256.0 * 2.0_f64.powi(zoom as i32);
+        (size, size)
+    }
+}
+
+/// Plate carrée (EPSG:4326), where lat/lng map linearly to pixels. As in Leaflet's `CRS.EPSG4326`,
+/// the world is two 256 pixel tiles wide and one tall at zoom 0.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Equirectangular;
+
+impl Projection for Equirectangular {
+    fn project(&self, lat: f64, lng: f64, zoom: usize) -> Result<(f64, f64), ClusterError> {
+        let (width, height) = self.world_size(zoom);
+        Ok(((lng + 180.0) / 360.0 * width, (90.0 - lat.clamp(-90.0, 90.0)) / 180.0 * height))
+    }
+
+    fn unproject(&self, x: f64, y: f64, zoom: usize) -> Result<(f64, f64), ClusterError> {
+        let (width, height) = self.world_size(zoom);
+        Ok((90.0 - y.clamp(0.0, height) / height * 180.0, x / width * 360.0 - 180.0))
+    }
+
+    fn world_size(&self, zoom: usize) -> (f64, f64) {
+        let size = 256.0 * 2.0_f64.powi(zoom as i32);
+        (size * 2.0, size)
+    }
+}
+
+/// Plain x/y coordinates, as on floor plans and game maps (Leaflet's `CRS.Simple`), with `lng` as x and
+/// `lat` as y increasing northwards. A unit is `scale` pixels at zoom 0, doubling with each zoom.
+///
+/// Distances are Euclidean, in coordinate units.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Planar {
+    pub scale: f64,
+}
+
+impl Planar {
+    /// Checks that `scale` is a positive number, since any other scale collapses or flips distances.
+    pub fn validate(&self) -> Result<(), ClusterError> {
+        if !(self.scale.is_finite() && self.scale > 0.0) {
+            return Err(ClusterError::InvalidInput(format!("a planar scale of {} isn't a positive number", self.scale)));
+        }
+        Ok(())
+    }
+
+    fn pixels_per_unit(&self, zoom: usize) -> f64 {
+        self.scale * 2.0_f64.powi(zoom as i32)
+    }
+}
+
+impl Default for Planar {
+    fn default() -> Planar {
+        Planar { scale: 1.0 }
+    }
+}
+
+impl Projection for Planar {
+    fn project(&self, lat: f64, lng: f64, zoom: usize) -> Result<(f64, f64), ClusterError> {
+        Ok((lng * self.pixels_per_unit(zoom), -lat * self.pixels_per_unit(zoom)))
+    }
+
+    fn unproject(&self, x: f64, y: f64, zoom: usize) -> Result<(f64, f64), ClusterError> {
+        Ok((-y / self.pixels_per_unit(zoom), x / self.pixels_per_unit(zoom)))
+    }
+
+    /// The plane has no edges.
+    fn world_size(&self, _zoom: usize) -> (f64, f64) {
+        (f64::INFINITY, f64::INFINITY)
+    }
+
+    fn is_geographic(&self) -> bool {
+        false
+    }
+
+    fn distance(&self, p1: &Marker, p2: &UniqueMarker) -> f64 {
+        (p1.lat - p2.lat).hypot(p1.lng - p2.lng)
+    }
+}
+
+/// Which `Projection` to cluster with, set by `Config::projection`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectionKind {
+    #[default]
+    WebMercator,
+    Equirectangular,
+    Planar(Planar),
+    /// A projection implemented outside of this crate. Only settable from Rust.
+    #[serde(skip)]
+    Custom(Arc<dyn Projection>),
+}
+
+impl ProjectionKind {
+    pub fn projection(&self) -> &dyn Projection {
+        match self {
+            ProjectionKind::WebMercator => &WebMercator,
+            ProjectionKind::Equirectangular => &Equirectangular,
+            ProjectionKind::Planar(planar) => planar,
+            ProjectionKind::Custom(projection) => projection.as_ref(),
+        }
+    }
+}
+
+impl PartialEq for ProjectionKind {
+    /// Custom projections are only equal if they're the same instance.
+    fn eq(&self, other: &ProjectionKind) -> bool {
+        match (self, other) {
+            (ProjectionKind::WebMercator, ProjectionKind::WebMercator) => true,
+            (ProjectionKind::Equirectangular, ProjectionKind::Equirectangular) => true,
+            (ProjectionKind::Planar(a), ProjectionKind::Planar(b)) => a == b,
+            (ProjectionKind::Custom(a), ProjectionKind::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Distance between world pixel coordinates, going around the world if that's shorter in a geographic projection.
+pub fn pixel_distance(p1: (f64, f64), p2: (f64, f64), zoom: usize, projection: &dyn Projection) -> f64 {
+    let mut dx = (p1.0 - p2.0).abs();
+    if projection.is_geographic() {
+        dx = dx.min(projection.world_size(zoom).0 - dx);
+    }
+    dx.hypot(p1.1 - p2.1)
+}
+
+/// Great-circle distance in kilometres, which is also correct for markers on opposite sides of the antimeridian.
+pub fn distance_between_markers(p1: &Marker, p2: &UniqueMarker) -> f64 {
+    let earth_radius_kilometer = 6371.0_f64;
+
+    let delta_latitude = (p1.lat - p2.lat).to_radians();
+    let delta_longitude = (p1.lng - p2.lng).to_radians();
+
+    let central_angle_inner = (delta_latitude / 2.0).sin().powi(2)
+        + p1.lat.to_radians().cos() * p2.lat.to_radians().cos() * (delta_longitude / 2.0).sin().powi(2);
+    let central_angle = 2.0 * central_angle_inner.sqrt().asin();
+
+    earth_radius_kilometer * central_angle
+}
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateOperation {
+  Sum,
+  Min,
+  Max,
+  Mean,
+  CountDistinct,
+}
+
+/// A value to calculate for every cluster from one of its markers' properties.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Aggregation {
+  /// Key of the result in `Cluster.aggregates`.
+  pub name: String,
+  /// Key of the value in each marker's `properties`.
+  pub property: String,
+  pub operation: AggregateOperation,
+}
+
+/// Running state of an `Aggregation` over the markers added to a cluster so far.
+/// Markers without a numeric value for the property are ignored, except by `CountDistinct`
+/// which counts any value.
+#[derive(Clone, Debug)]
+pub struct Aggregator {
+  pub aggregation: Aggregation,
+  count: u32,
+  sum: f64,
+  min: f64,
+  max: f64,
+  distinct: HashSet<String>,
+}
+
+impl Aggregator {
+  pub fn new(aggregation: &Aggregation) -> Aggregator {
+    Aggregator {
+      aggregation: aggregation.clone(),
+      count: 0,
+      sum: 0.0,
+      min: f64::INFINITY,
+      max: f64::NEG_INFINITY,
+      distinct: HashSet::new(),
+    }
+  }
+
+  pub fn add(&mut self, properties: Option<&Value>) {
+    let value = match properties.and_then(|p| p.get(&self.aggregation.property)) {
+      Some(value) if !value.is_null() => value,
+      _ => return,
+    };
+    if self.aggregation.operation == AggregateOperation::CountDistinct {
+      self.distinct.insert(value.to_string());
+    } else if let Some(number) = value.as_f64() {
+      self.count += 1;
+      self.sum += number;
+      self.min = self.min.min(number);
+      self.max = self.max.max(number);
+    }
+  }
+
+  /// The result so far, or `None` if no marker had a value to aggregate.
+  pub fn value(&self) -> Option<f64> {
+    match self.aggregation.operation {
+      AggregateOperation::CountDistinct => Some(self.distinct.len() as f64),
+      _ if self.count == 0 => None,
+      AggregateOperation::Sum => Some(self.sum),
+      AggregateOperation::Min => Some(self.min),
+      AggregateOperation::Max => Some(self.max),
+      AggregateOperation::Mean => Some(self.sum / f64::from(self.count)),
+    }
+  }
+}
\ No newline at end of file
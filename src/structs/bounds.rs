@@ -1,7 +1,10 @@
 use UniqueMarker;
 use utils::bounds::calculate_extended_bounds;
+use projection::Projection;
+use error::ClusterError;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// A lat/lng box. In a geographic projection, when `west` is greater than `east`, the box crosses the antimeridian.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Bounds {
   pub north: f64,
   pub east: f64,
@@ -10,19 +13,77 @@ pub struct Bounds {
 }
 
 impl Bounds {
-  pub fn contains(&self, point: &UniqueMarker) -> bool {
-    self.north > point.lat &&
-    self.east > point.lng &&
-    self.south < point.lat &&
-    self.west < point.lng
+  /// Whether the marker is inside the box. In a geographic projection, edges at a pole or the antimeridian include
+  /// markers on them, since `calculate_extended_bounds` stops there, and a `west` greater than `east` crosses the
+  /// antimeridian. On a plane, the box is only ever the area between its edges.
+  pub fn contains(&self, point: &UniqueMarker, projection: &dyn Projection) -> bool {
+    self.within(point, projection, false)
   }
 
-  pub fn from_point(lat: f64, lng: f64, zoom: usize, grid_size: f64) -> Bounds {
+  /// Whether the marker is inside the box or on any of its edges, like a marker at the extent of the markers
+  /// that `around` is the box of.
+  pub fn contains_edges(&self, point: &UniqueMarker, projection: &dyn Projection) -> bool {
+    self.within(point, projection, true)
+  }
+
+  fn within(&self, point: &UniqueMarker, projection: &dyn Projection, edges: bool) -> bool {
+    let before = |a: f64, b: f64| a < b || (edges && a == b);
+    if !projection.is_geographic() {
+      return before(self.west, point.lng) && before(point.lng, self.east) && before(self.south, point.lat) && before(point.lat, self.north);
+    }
+    let before_east = before(point.lng, self.east) || (self.east == 180.0 && point.lng == 180.0);
+    let after_west = before(self.west, point.lng) || (self.west == -180.0 && point.lng == -180.0);
+    let within_lng = if self.west <= self.east {
+      before_east && after_west
+    } else {
+      before_east || after_west
+    };
+    (before(point.lat, self.north) || (self.north == 90.0 && point.lat == 90.0)) &&
+    (before(self.south, point.lat) || (self.south == -90.0 && point.lat == -90.0)) &&
+    within_lng
+  }
+
+  /// The smallest box containing every marker, or `None` without any. In a geographic projection,
+  /// the box crosses the antimeridian if that makes it narrower. The outermost markers are on its edges,
+  /// so use `contains_edges` to check for them.
+  pub fn around(markers: &[UniqueMarker], projection: &dyn Projection) -> Option<Bounds> {
+    let first = markers.first()?;
+    let mut bounds = Bounds { north: first.lat, east: first.lng, south: first.lat, west: first.lng };
+    for marker in markers {
+      bounds.north = bounds.north.max(marker.lat);
+      bounds.south = bounds.south.min(marker.lat);
+    }
+    if !projection.is_geographic() {
+      for marker in markers {
+        bounds.east = bounds.east.max(marker.lng);
+        bounds.west = bounds.west.min(marker.lng);
+      }
+      return Some(bounds);
+    }
+
+    // The narrowest box leaves out the widest gap between neighbouring longitudes, going around the world
+    let mut lngs = markers.iter().map(|m| m.lng).collect::<Vec<_>>();
+    lngs.sort_by(|a, b| a.total_cmp(b));
+    let last = lngs[lngs.len() - 1];
+    let (mut west, mut east, mut widest_gap) = (lngs[0], last, lngs[0] + 360.0 - last);
+    for pair in lngs.windows(2) {
+      if pair[1] - pair[0] > widest_gap {
+        widest_gap = pair[1] - pair[0];
+        west = pair[1];
+        east = pair[0];
+      }
+    }
+    bounds.west = west;
+    bounds.east = east;
+    Some(bounds)
+  }
+
+  pub fn from_point(lat: f64, lng: f64, zoom: usize, grid_size: f64, projection: &dyn Projection) -> Result<Bounds, ClusterError> {
     calculate_extended_bounds(&Bounds {
       north: lat,
       east: lng,
       south: lat,
       west: lng
-    }, zoom, grid_size)
+    }, zoom, grid_size, projection)
   }
-}
+}
\ No newline at end of file
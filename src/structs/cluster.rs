@@ -1,7 +1,15 @@
+use std::borrow::Cow;
+use std::collections::{ BTreeMap, HashSet };
 use uuid::Uuid;
 use Marker;
 use UniqueMarker;
 use Bounds;
+use projection::Projection;
+use error::ClusterError;
+use utils::bounds::{ unwrap_lng, wrap_lng };
+use structs::aggregation::{ Aggregation, Aggregator };
+use structs::marker_id::MarkerId;
+use config::{ MarkerOutput, Output };
 
 #[derive(Debug, Serialize, Clone)]
 pub struct Cluster {
@@ -10,16 +18,252 @@ pub struct Cluster {
     pub center: Marker,
     pub markers: Vec<UniqueMarker>,
     pub bounds: Bounds,
+    /// Results of the configured aggregations over the markers' properties, by aggregation name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub aggregates: BTreeMap<String, f64>,
+    #[serde(skip)]
+    aggregators: Vec<Aggregator>,
+    /// World pixel coordinates of the center at a zoom, until the center moves.
+    #[serde(skip)]
+    center_pixel: Option<(usize, f64, f64)>,
+    #[serde(skip)]
+    bounds_kind: BoundsKind,
+    /// Sums that the average center is taken from, like `center_of`, kept while markers are only added.
+    #[serde(skip)]
+    sums: Option<(f64, f64)>,
+}
+
+/// What a cluster's bounds are kept around as its markers change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BoundsKind {
+    /// The `grid_size` around the center.
+    Center,
+    /// The markers' extent, as from `Cluster::from_markers`.
+    Extent,
+    /// A fixed area, as from `Cluster::in_cell`.
+    Fixed,
 }
 
 impl Cluster {
-    pub fn add_marker(&mut self, new_point: &UniqueMarker, zoom: usize, average_center: bool, grid_size: f64) {
+    pub fn new(uuid: Uuid, center: Marker, markers: Vec<UniqueMarker>, bounds: Bounds, aggregations: &[Aggregation]) -> Cluster {
+        let mut cluster = Cluster {
+            uuid,
+            size: markers.len() as u32,
+            center,
+            markers,
+            bounds,
+            aggregates: BTreeMap::new(),
+            aggregators: aggregations.iter().map(Aggregator::new).collect(),
+            center_pixel: None,
+            bounds_kind: BoundsKind::Center,
+            sums: None,
+        };
+        cluster.recalculate_aggregates();
+        cluster
+    }
+
+    pub fn add_marker(&mut self, new_point: &UniqueMarker, zoom: usize, average_center: bool, grid_size: f64, projection: &dyn Projection) -> Result<(), ClusterError> {
         self.size += 1;
         self.markers.push(new_point.clone());
+        if !self.aggregators.is_empty() {
+            for aggregator in self.aggregators.iter_mut() {
+                aggregator.add(new_point.properties.as_ref());
+            }
+            self.update_aggregates();
+        }
         if average_center {
-            self.center.lat = ((self.center.lat * f64::from(self.size)) + new_point.lat) / f64::from(self.size + 1);
-            self.center.lng = ((self.center.lng * f64::from(self.size)) + new_point.lng) / f64::from(self.size + 1);
-            self.bounds = Bounds::from_point(self.center.lat, self.center.lng, zoom, grid_size);
+            // The same sums as `center_of` the markers, without going over every marker again
+            let added = self.markers.len() - 1;
+            let (lat_sum, lng_sum) = self.sums.unwrap_or_else(|| sums_of(&self.markers[..added], self.markers[0].lng, projection));
+            let sums = (lat_sum + new_point.lat, lng_sum + lng_from(new_point, self.markers[0].lng, projection));
+            let (lat, lng) = average(sums, self.markers.len(), projection);
+            self.sums = Some(sums);
+            self.center.lat = lat;
+            self.center.lng = lng;
+            self.center_pixel = None;
+        }
+        match self.bounds_kind {
+            BoundsKind::Center if average_center => self.bounds = Bounds::from_point(self.center.lat, self.center.lng, zoom, grid_size, projection)?,
+            BoundsKind::Extent => self.bounds = Bounds::around(&self.markers, projection).unwrap_or_else(|| self.bounds.clone()),
+            _ => {},
+        }
+        Ok(())
+    }
+
+    /// Removes the markers with any of these ids, and recalculates the cluster from the markers left.
+    /// Without `average_center`, the center moves to the oldest marker left, except in a cluster from `in_cell`.
+    /// Bounds from `from_markers` stay around the markers left, and bounds from `in_cell` don't change.
+    ///
+    /// Returns whether any markers were removed. A cluster left with no markers has a `size` of 0.
+    pub fn remove_markers(&mut self, ids: &HashSet<MarkerId>, zoom: usize, average_center: bool, grid_size: f64, projection: &dyn Projection) -> Result<bool, ClusterError> {
+        let previous_size = self.markers.len();
+        self.markers.retain(|marker| !marker.has_id_in(ids));
+        if self.markers.len() == previous_size {
+            return Ok(false);
+        }
+
+        self.size = self.markers.len() as u32;
+        self.sums = None;
+        if let Some((lat, lng)) = center_of(&self.markers, average_center, projection) {
+            if average_center || self.bounds_kind != BoundsKind::Fixed {
+                self.center.lat = lat;
+                self.center.lng = lng;
+                self.center_pixel = None;
+            }
+            match self.bounds_kind {
+                BoundsKind::Center => self.bounds = Bounds::from_point(lat, lng, zoom, grid_size, projection)?,
+                BoundsKind::Extent => self.bounds = Bounds::around(&self.markers, projection).unwrap_or_else(|| self.bounds.clone()),
+                BoundsKind::Fixed => {},
+            }
+        }
+        self.recalculate_aggregates();
+        Ok(true)
+    }
+
+    /// Whether the cluster's bounds contain the marker. Bounds around the markers' extent also contain markers on
+    /// their edges, where the outermost markers of the cluster are.
+    pub fn contains(&self, marker: &UniqueMarker, projection: &dyn Projection) -> bool {
+        match self.bounds_kind {
+            BoundsKind::Extent => self.bounds.contains_edges(marker, projection),
+            _ => self.bounds.contains(marker, projection),
+        }
+    }
+
+    /// A cluster of these markers, centered like `remove_markers` centers the markers left,
+    /// and with bounds that are the markers' extent instead of the `grid_size` around the center.
+    pub fn from_markers(uuid: Uuid, markers: Vec<UniqueMarker>, average_center: bool, projection: &dyn Projection, aggregations: &[Aggregation]) -> Option<Cluster> {
+        let (lat, lng) = center_of(&markers, average_center, projection)?;
+        let bounds = Bounds::around(&markers, projection)?;
+        let mut cluster = Cluster::new(uuid, Marker { lat, lng, id: None, properties: None }, markers, bounds, aggregations);
+        cluster.bounds_kind = BoundsKind::Extent;
+        Some(cluster)
+    }
+
+    /// A cluster of a fixed area, like a grid cell, whose bounds don't change as markers are added or removed,
+    /// and whose center only moves with `average_center`.
+    pub fn in_cell(uuid: Uuid, center: Marker, markers: Vec<UniqueMarker>, bounds: Bounds, aggregations: &[Aggregation]) -> Cluster {
+        let mut cluster = Cluster::new(uuid, center, markers, bounds, aggregations);
+        cluster.bounds_kind = BoundsKind::Fixed;
+        cluster
+    }
+
+    /// A cluster of `size` markers that only keeps `markers` of them, with aggregates over all of `members`, for
+    /// clusters that are built for each query and only need the markers that are returned.
+    pub fn summary<'a>(uuid: Uuid, center: Marker, size: u32, markers: Vec<UniqueMarker>, bounds: Bounds, aggregations: &[Aggregation], members: impl Iterator<Item = &'a UniqueMarker>) -> Cluster {
+        let mut cluster = Cluster::new(uuid, center, markers, bounds, &[]);
+        cluster.size = size;
+        if !aggregations.is_empty() {
+            cluster.aggregators = aggregations.iter().map(Aggregator::new).collect();
+            for member in members {
+                for aggregator in cluster.aggregators.iter_mut() {
+                    aggregator.add(member.properties.as_ref());
+                }
+            }
+            cluster.update_aggregates();
+        }
+        cluster
+    }
+
+    /// World pixel coordinates of the center at the given zoom, projected only when the center has moved.
+    pub fn center_pixel(&mut self, zoom: usize, projection: &dyn Projection) -> Result<(f64, f64), ClusterError> {
+        match self.center_pixel {
+            Some((pixel_zoom, x, y)) if pixel_zoom == zoom => Ok((x, y)),
+            _ => {
+                let (x, y) = projection.project(self.center.lat, self.center.lng, zoom)?;
+                self.center_pixel = Some((zoom, x, y));
+                Ok((x, y))
+            }
+        }
+    }
+
+    /// The fields of the cluster that `output` says to send to JS, without markers' properties unless `include_properties`.
+    pub fn output(&self, output: &Output, include_properties: bool) -> ClusterOutput<'_> {
+        let (markers, ids) = match output.markers {
+            MarkerOutput::All => (Some(markers_output(&self.markers, include_properties)), None),
+            MarkerOutput::Ids => (None, Some(self.markers.iter().filter_map(|marker| marker.id.as_ref()).collect())),
+            MarkerOutput::Sample(count) => (Some(markers_output(&self.markers[..count.min(self.markers.len())], include_properties)), None),
+            MarkerOutput::None => (None, None),
+        };
+        ClusterOutput {
+            uuid: self.uuid,
+            size: self.size,
+            center: &self.center,
+            markers,
+            ids,
+            bounds: if output.bounds { Some(&self.bounds) } else { None },
+            aggregates: &self.aggregates,
         }
     }
+
+    fn recalculate_aggregates(&mut self) {
+        self.aggregates.clear();
+        for aggregator in self.aggregators.iter_mut() {
+            *aggregator = Aggregator::new(&aggregator.aggregation);
+            for marker in &self.markers {
+                aggregator.add(marker.properties.as_ref());
+            }
+        }
+        self.update_aggregates();
+    }
+
+    fn update_aggregates(&mut self) {
+        for aggregator in &self.aggregators {
+            match aggregator.value() {
+                Some(value) => self.aggregates.insert(aggregator.aggregation.name.clone(), value),
+                None => self.aggregates.remove(&aggregator.aggregation.name),
+            };
+        }
+    }
+}
+
+/// The fields of a `Cluster` that `Output` says to send to JS, borrowed from the cluster.
+#[derive(Debug, Serialize)]
+pub struct ClusterOutput<'a> {
+    pub uuid: Uuid,
+    pub size: u32,
+    pub center: &'a Marker,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markers: Option<Cow<'a, [UniqueMarker]>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a MarkerId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<&'a Bounds>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub aggregates: &'a BTreeMap<String, f64>,
+}
+
+/// The markers as they're sent to JS, copied only if their properties need to be left out.
+fn markers_output(markers: &[UniqueMarker], include_properties: bool) -> Cow<'_, [UniqueMarker]> {
+    if include_properties || markers.iter().all(|marker| marker.properties.is_none()) {
+        Cow::Borrowed(markers)
+    } else {
+        Cow::Owned(markers.iter().map(UniqueMarker::without_properties).collect())
+    }
+}
+
+/// The average position of the markers, or the first marker's without `average_center`.
+fn center_of(markers: &[UniqueMarker], average_center: bool, projection: &dyn Projection) -> Option<(f64, f64)> {
+    let first = markers.first()?;
+    if !average_center {
+        return Some((first.lat, first.lng));
+    }
+    Some(average(sums_of(markers, first.lng, projection), markers.len(), projection))
+}
+
+/// Sums of the markers' lats and lngs, with lngs taken around `first_lng` in geographic projections.
+fn sums_of(markers: &[UniqueMarker], first_lng: f64, projection: &dyn Projection) -> (f64, f64) {
+    markers.iter().fold((0.0, 0.0), |(lat_sum, lng_sum), marker| (lat_sum + marker.lat, lng_sum + lng_from(marker, first_lng, projection)))
+}
+
+fn lng_from(marker: &UniqueMarker, first_lng: f64, projection: &dyn Projection) -> f64 {
+    if projection.is_geographic() { unwrap_lng(marker.lng, first_lng) } else { marker.lng }
+}
+
+fn average((lat_sum, lng_sum): (f64, f64), count: usize, projection: &dyn Projection) -> (f64, f64) {
+    let count = count as f64;
+    if projection.is_geographic() {
+        (lat_sum / count, wrap_lng(lng_sum / count))
+    } else {
+        (lat_sum / count, lng_sum / count)
+    }
 }
\ No newline at end of file
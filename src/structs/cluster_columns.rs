@@ -0,0 +1,60 @@
+use js_sys::{ Float64Array, Object, Reflect, Uint32Array, Uint8Array };
+use wasm_bindgen::JsValue;
+use Cluster;
+
+/// Clusters as columns of numbers, with the values for the cluster at an index at that index of each column,
+/// so they can be drawn with WebGL or on a canvas without reading an object per cluster.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ClusterColumns {
+    pub lat: Vec<f64>,
+    pub lng: Vec<f64>,
+    pub size: Vec<u32>,
+    /// The 16 bytes of each cluster's id.
+    pub uuids: Vec<u8>,
+    /// North, east, south and west of each cluster's bounds, if included.
+    pub bounds: Option<Vec<f64>>,
+}
+
+impl ClusterColumns {
+    pub fn new(clusters: &[&Cluster], include_bounds: bool) -> ClusterColumns {
+        let mut columns = ClusterColumns {
+            lat: Vec::with_capacity(clusters.len()),
+            lng: Vec::with_capacity(clusters.len()),
+            size: Vec::with_capacity(clusters.len()),
+            uuids: Vec::with_capacity(clusters.len() * 16),
+            bounds: if include_bounds { Some(Vec::with_capacity(clusters.len() * 4)) } else { None },
+        };
+        for cluster in clusters {
+            columns.lat.push(cluster.center.lat);
+            columns.lng.push(cluster.center.lng);
+            columns.size.push(cluster.size);
+            columns.uuids.extend_from_slice(cluster.uuid.as_bytes());
+            if let Some(bounds) = columns.bounds.as_mut() {
+                bounds.extend_from_slice(&[ cluster.bounds.north, cluster.bounds.east, cluster.bounds.south, cluster.bounds.west ]);
+            }
+        }
+        columns
+    }
+
+    pub fn len(&self) -> usize {
+        self.size.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size.is_empty()
+    }
+
+    /// A plain JS object with a typed array for each column, which can be posted from a worker as it is.
+    pub fn to_js(&self) -> Result<JsValue, JsValue> {
+        let object = Object::new();
+        Reflect::set(&object, &"length".into(), &JsValue::from(self.len() as u32))?;
+        Reflect::set(&object, &"lat".into(), &Float64Array::from(&self.lat[..]))?;
+        Reflect::set(&object, &"lng".into(), &Float64Array::from(&self.lng[..]))?;
+        Reflect::set(&object, &"size".into(), &Uint32Array::from(&self.size[..]))?;
+        Reflect::set(&object, &"uuids".into(), &Uint8Array::from(&self.uuids[..]))?;
+        if let Some(bounds) = &self.bounds {
+            Reflect::set(&object, &"bounds".into(), &Float64Array::from(&bounds[..]))?;
+        }
+        Ok(object.into())
+    }
+}
@@ -0,0 +1,26 @@
+use uuid::Uuid;
+use Cluster;
+use config::Output;
+use structs::cluster::ClusterOutput;
+
+/// Changes to the clusters since they were last returned, so that map overlays can be updated precisely.
+#[derive(Debug, Serialize, Clone)]
+pub struct ClusterDiff<C = Cluster> {
+    /// Clusters that haven't been returned before.
+    pub added: Vec<C>,
+    /// Clusters that have been returned before, and may have changed since.
+    pub updated: Vec<C>,
+    /// Ids of returned clusters that no longer exist.
+    pub removed: Vec<Uuid>,
+}
+
+impl ClusterDiff {
+    /// The diff with only the fields of the clusters that `output` says to send to JS.
+    pub fn output(&self, output: &Output, include_properties: bool) -> ClusterDiff<ClusterOutput<'_>> {
+        ClusterDiff {
+            added: self.added.iter().map(|cluster| cluster.output(output, include_properties)).collect(),
+            updated: self.updated.iter().map(|cluster| cluster.output(output, include_properties)).collect(),
+            removed: self.removed.clone(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use Cluster;
+use Marker;
+use Bounds;
+use projection::Projection;
+use error::ClusterError;
+
+/// Buckets clusters by the `grid_size` pixel cell that their center falls in at a given zoom,
+/// so that finding the clusters near a marker doesn't require a scan of every cluster.
+///
+/// A cluster's bounds extend `grid_size` pixels around its center, so any cluster that could
+/// contain a marker has its center in the marker's cell or one of the 8 cells around it.
+/// In geographic projections, cells are widened slightly so a whole number of them fit around the world,
+/// and the columns at either side of the antimeridian are neighbours.
+#[derive(Debug, Default, Clone)]
+pub struct ClusterIndex {
+    zoom: usize,
+    grid_size: f64,
+    cell_size: f64,
+    /// Number of columns around the world, or `None` if the world doesn't wrap around.
+    columns: Option<i64>,
+    indexed: usize,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl ClusterIndex {
+    pub fn clear(&mut self) {
+        self.indexed = 0;
+        self.cells.clear();
+    }
+
+    /// Brings the index up to date with `clusters`, rebuilding it if the zoom or grid size changed
+    /// or if clusters were removed since it was last synced.
+    pub fn sync(&mut self, clusters: &[Cluster], zoom: usize, grid_size: f64, projection: &dyn Projection) -> Result<(), ClusterError> {
+        if self.zoom != zoom || (self.grid_size - grid_size).abs() > f64::EPSILON || self.cell_size == 0.0 || self.indexed > clusters.len() {
+            self.zoom = zoom;
+            self.grid_size = grid_size;
+            if projection.is_geographic() {
+                let world_width = projection.world_size(zoom).0;
+                let columns = ((world_width / grid_size).floor() as i64).max(1);
+                self.columns = Some(columns);
+                self.cell_size = world_width / columns as f64;
+            } else {
+                self.columns = None;
+                self.cell_size = grid_size;
+            }
+            self.clear();
+        }
+        for (i, cluster) in clusters.iter().enumerate().skip(self.indexed) {
+            self.insert(i, &cluster.center, projection)?;
+        }
+        Ok(())
+    }
+
+    pub fn insert(&mut self, cluster_index: usize, center: &Marker, projection: &dyn Projection) -> Result<(), ClusterError> {
+        let cell = self.cell(center.lat, center.lng, projection)?;
+        self.cells.entry(cell).or_default().push(cluster_index);
+        self.indexed = self.indexed.max(cluster_index + 1);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, cluster_index: usize, center: &Marker, projection: &dyn Projection) -> Result<(), ClusterError> {
+        let cell = self.cell(center.lat, center.lng, projection)?;
+        if let Some(indices) = self.cells.get_mut(&cell) {
+            indices.retain(|&i| i != cluster_index);
+            if indices.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+        Ok(())
+    }
+
+    /// Indices of the clusters whose bounds could contain a marker at these world pixel coordinates.
+    pub fn nearby(&self, pixel: (f64, f64)) -> impl Iterator<Item = usize> + '_ {
+        self.within(pixel, 1)
+    }
+
+    /// Indices of the clusters whose center is at most `cells` cells away from the cell of these world pixel coordinates.
+    pub fn within(&self, pixel: (f64, f64), cells: i64) -> impl Iterator<Item = usize> + '_ {
+        self.around(pixel, cells, false)
+    }
+
+    /// Indices of the clusters whose center is exactly `cells` cells away from the cell of these world pixel coordinates,
+    /// so that a lookup can be widened past `within(pixel, cells - 1)` without comparing those clusters again.
+    pub fn ring(&self, pixel: (f64, f64), cells: i64) -> impl Iterator<Item = usize> + '_ {
+        self.around(pixel, cells, true)
+    }
+
+    /// Whether no clusters are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.indexed == 0
+    }
+
+    /// Visits each column once, wrapped around the world if it wraps, even if the world has fewer columns than the lookup is wide.
+    fn around(&self, pixel: (f64, f64), cells: i64, ring: bool) -> impl Iterator<Item = usize> + '_ {
+        let (x, y) = self.pixel_cell(pixel);
+        let width = self.columns.map_or(2 * cells + 1, |columns| columns.min(2 * cells + 1));
+        (0..width)
+            .flat_map(move |offset| {
+                let dx = offset - cells;
+                let column = x.saturating_add(dx);
+                let column = self.columns.map_or(column, |columns| column.rem_euclid(columns));
+                (-cells..=cells)
+                    .filter(move |dy| !ring || dx.abs() == cells || dy.abs() == cells)
+                    .map(move |dy| (column, y.saturating_add(dy)))
+            })
+            .filter_map(move |cell| self.cells.get(&cell))
+            .flat_map(|indices| indices.iter().cloned())
+    }
+
+    /// Width of a cell in pixels at the synced zoom.
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    fn cell(&self, lat: f64, lng: f64, projection: &dyn Projection) -> Result<(i64, i64), ClusterError> {
+        Ok(self.pixel_cell(projection.project(lat, lng, self.zoom)?))
+    }
+
+    /// Indices of the clusters whose center is in the cell.
+    pub fn in_cell(&self, cell: (i64, i64)) -> &[usize] {
+        self.cells.get(&cell).map_or(&[], |indices| indices.as_slice())
+    }
+
+    /// Lat/lng bounds of the cell at the synced zoom. In geographic projections, cells at the top or bottom of the
+    /// world extend to the pole.
+    pub fn cell_bounds(&self, (x, y): (i64, i64), projection: &dyn Projection) -> Result<Bounds, ClusterError> {
+        let (north, west) = projection.unproject(x as f64 * self.cell_size, y as f64 * self.cell_size, self.zoom)?;
+        let (south, east) = projection.unproject(x.saturating_add(1) as f64 * self.cell_size, y.saturating_add(1) as f64 * self.cell_size, self.zoom)?;
+        if !projection.is_geographic() {
+            return Ok(Bounds { north, east, south, west });
+        }
+        let world_height = projection.world_size(self.zoom).1;
+        Ok(Bounds {
+            north: if y <= 0 { 90.0 } else { north },
+            east,
+            south: if y.saturating_add(1) as f64 * self.cell_size >= world_height { -90.0 } else { south },
+            west,
+        })
+    }
+
+    /// The middle of the cell at the synced zoom.
+    pub fn cell_center(&self, (x, y): (i64, i64), projection: &dyn Projection) -> Result<Marker, ClusterError> {
+        let (lat, lng) = projection.unproject((x as f64 + 0.5) * self.cell_size, (y as f64 + 0.5) * self.cell_size, self.zoom)?;
+        Ok(Marker { lat, lng, id: None, properties: None })
+    }
+
+    /// The cell that world pixel coordinates at the synced zoom fall in. Coordinates too far out for an `i64` cell
+    /// saturate at the last cell, so neighbouring cells are found with saturating arithmetic.
+    pub fn pixel_cell(&self, (x, y): (f64, f64)) -> (i64, i64) {
+        let column = (x / self.cell_size).floor() as i64;
+        (self.columns.map_or(column, |columns| column.rem_euclid(columns)), (y / self.cell_size).floor() as i64)
+    }
+}
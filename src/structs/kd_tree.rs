@@ -0,0 +1,92 @@
+use std::cmp::Ordering;
+
+const NODE_SIZE: usize = 64;
+
+/// A static 2D KD-tree over `(x, y)` points, answering box range queries.
+///
+/// Points are sorted in place when the tree is built, so it can't be added to afterwards.
+#[derive(Debug, Default, Clone)]
+pub struct KdTree {
+    points: Vec<(usize, f64, f64)>,
+}
+
+impl KdTree {
+    /// Builds a tree where each point is identified by its index in `points`.
+    pub fn new(points: &[(f64, f64)]) -> KdTree {
+        let mut tree = KdTree {
+            points: points.iter().enumerate().map(|(i, &(x, y))| (i, x, y)).collect(),
+        };
+        sort_kd(&mut tree.points, 0);
+        tree
+    }
+
+    /// Ids of the points within the box, inclusive of its edges.
+    pub fn range(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut stack = vec![(0, self.points.len(), 0)];
+
+        while let Some((left, right, axis)) = stack.pop() {
+            if right - left <= NODE_SIZE {
+                for &(id, x, y) in &self.points[left..right] {
+                    if x >= min_x && x <= max_x && y >= min_y && y <= max_y {
+                        result.push(id);
+                    }
+                }
+                continue;
+            }
+
+            let m = left + (right - left) / 2;
+            let (id, x, y) = self.points[m];
+            if x >= min_x && x <= max_x && y >= min_y && y <= max_y {
+                result.push(id);
+            }
+
+            let (value, min, max) = if axis == 0 { (x, min_x, max_x) } else { (y, min_y, max_y) };
+            if min <= value {
+                stack.push((left, m, 1 - axis));
+            }
+            if max >= value {
+                stack.push((m + 1, right, 1 - axis));
+            }
+        }
+        result
+    }
+
+    /// Like `range`, but for points on a world `world_width` wide that wraps around, so a box that goes past
+    /// either side continues from the other. A `min_x` greater than `max_x` is a box crossing the edge.
+    /// With no `world_width`, this is the same as `range`.
+    pub fn wrapped_range(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64, world_width: Option<f64>) -> Vec<usize> {
+        let world_width = match world_width {
+            Some(world_width) => world_width,
+            None => return self.range(min_x, min_y, max_x, max_y),
+        };
+        if min_x > max_x {
+            return self.wrapped_range(min_x, min_y, max_x + world_width, max_y, Some(world_width));
+        }
+        if max_x - min_x >= world_width {
+            return self.range(f64::NEG_INFINITY, min_y, f64::INFINITY, max_y);
+        }
+        let mut ids = self.range(min_x, min_y, max_x, max_y);
+        if min_x < 0.0 {
+            ids.extend(self.range(min_x + world_width, min_y, world_width, max_y));
+        }
+        if max_x > world_width {
+            ids.extend(self.range(0.0, min_y, max_x - world_width, max_y));
+        }
+        ids
+    }
+}
+
+fn sort_kd(points: &mut [(usize, f64, f64)], axis: usize) {
+    if points.len() <= NODE_SIZE {
+        return;
+    }
+    let m = points.len() / 2;
+    points.select_nth_unstable_by(m, |a, b| {
+        let (a, b) = if axis == 0 { (a.1, b.1) } else { (a.2, b.2) };
+        a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+    });
+    let (left, right) = points.split_at_mut(m);
+    sort_kd(left, 1 - axis);
+    sort_kd(&mut right[1..], 1 - axis);
+}
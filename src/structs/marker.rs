@@ -1,5 +1,13 @@
+use serde_json::Value;
+use structs::marker_id::MarkerId;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Marker {
     pub lat: f64,
     pub lng: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<MarkerId>,
+    /// Any data to keep with the marker, returned with it in the markers of its cluster.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Value>,
 }
@@ -0,0 +1,39 @@
+use std::fmt;
+use std::hash::{ Hash, Hasher };
+
+/// An id supplied with a marker by the user, so clustered markers can be matched back to their own records.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MarkerId {
+  Number(f64),
+  Text(String),
+}
+
+impl PartialEq for MarkerId {
+  fn eq(&self, other: &MarkerId) -> bool {
+    match (self, other) {
+      (MarkerId::Number(a), MarkerId::Number(b)) => a.to_bits() == b.to_bits(),
+      (MarkerId::Text(a), MarkerId::Text(b)) => a == b,
+      _ => false,
+    }
+  }
+}
+
+impl Eq for MarkerId {}
+
+impl Hash for MarkerId {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    match self {
+      MarkerId::Number(n) => n.to_bits().hash(state),
+      MarkerId::Text(s) => s.hash(state),
+    }
+  }
+}
+impl fmt::Display for MarkerId {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      MarkerId::Number(n) => write!(f, "{}", n),
+      MarkerId::Text(s) => write!(f, "{:?}", s),
+    }
+  }
+}
@@ -1,4 +1,10 @@
+pub mod aggregation;
 pub mod cluster;
+pub mod cluster_columns;
+pub mod cluster_diff;
+pub mod cluster_index;
+pub mod kd_tree;
 pub mod marker;
+pub mod marker_id;
 pub mod bounds;
 pub mod unique_marker;
\ No newline at end of file
@@ -1,16 +1,31 @@
+use std::collections::HashSet;
+use serde_json::Value;
 use uuid::Uuid;
 use Marker;
+use structs::marker_id::MarkerId;
+use projection::Projection;
+use error::ClusterError;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct UniqueMarker {
   pub lat: f64,
   pub lng: f64,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<MarkerId>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub properties: Option<Value>,
+
   #[serde(skip)]
   uuid: Uuid,
 
   #[serde(skip)]
   pub is_added: bool,
+
+  /// World pixel coordinates at a zoom, kept so the marker isn't projected again while clustering at that zoom.
+  #[serde(skip)]
+  pixel: Option<(usize, f64, f64)>,
 }
 
 impl From<&Marker> for UniqueMarker {
@@ -18,14 +33,68 @@ impl From<&Marker> for UniqueMarker {
     UniqueMarker {
       lat: point.lat,
       lng: point.lng,
+      id: point.id.clone(),
+      properties: point.properties.clone(),
       uuid: Uuid::new_v4(),
       is_added: false,
+      pixel: None,
+    }
+  }
+}
+
+impl UniqueMarker {
+  pub fn has_id_in(&self, ids: &HashSet<MarkerId>) -> bool {
+    self.id.as_ref().is_some_and(|id| ids.contains(id))
+  }
+
+  /// World pixel coordinates of the marker at the given zoom, projected only the first time they're needed.
+  /// `clear_pixel` must be called if the projection changes.
+  pub fn pixel(&mut self, zoom: usize, projection: &dyn Projection) -> Result<(f64, f64), ClusterError> {
+    match self.pixel {
+      Some((pixel_zoom, x, y)) if pixel_zoom == zoom => Ok((x, y)),
+      _ => {
+        let (x, y) = projection.project(self.lat, self.lng, zoom)?;
+        self.pixel = Some((zoom, x, y));
+        Ok((x, y))
+      }
+    }
+  }
+
+  /// Id that's unique to this marker, even if it has no `id` of its own.
+  pub fn uuid(&self) -> Uuid {
+    self.uuid
+  }
+
+  /// A copy of the marker without its properties, which aren't cloned.
+  pub fn without_properties(&self) -> UniqueMarker {
+    UniqueMarker {
+      lat: self.lat,
+      lng: self.lng,
+      id: self.id.clone(),
+      properties: None,
+      uuid: self.uuid,
+      is_added: self.is_added,
+      pixel: self.pixel,
     }
   }
+
+  pub fn clear_pixel(&mut self) {
+    self.pixel = None;
+  }
+
+  pub fn move_to(&mut self, lat: f64, lng: f64) {
+    self.lat = lat;
+    self.lng = lng;
+    self.clear_pixel();
+  }
 }
 
+/// Markers with a user-supplied id are equal if their ids are, otherwise only a marker is equal to itself.
 impl PartialEq for UniqueMarker {
   fn eq(&self, other: &UniqueMarker) -> bool {
-    self.uuid == other.uuid
+    match (&self.id, &other.id) {
+      (Some(id), Some(other_id)) => id == other_id,
+      _ => self.uuid == other.uuid,
+    }
   }
-}
+}
\ No newline at end of file
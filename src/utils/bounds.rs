@@ -1,11 +1,20 @@
-extern crate googleprojection;
-use self::googleprojection::{from_ll_to_subpixel, from_pixel_to_ll};
-
 use structs::bounds::Bounds;
+use projection::Projection;
+use error::ClusterError;
+
+/// The bounds extended by `grid_size` pixels on every side.
+pub fn calculate_extended_bounds(bounds: &Bounds, zoom: usize, grid_size: f64, projection: &dyn Projection) -> Result<Bounds, ClusterError> {
+    if !projection.is_geographic() {
+        return calculate_extended_box(bounds, zoom, grid_size, projection);
+    }
+    let (world_width, world_height) = projection.world_size(zoom);
+    let mut north_east_pix = projection.project(bounds.north, bounds.east, zoom)?;
+    let mut south_west_pix = projection.project(bounds.south, bounds.west, zoom)?;
 
-pub fn calculate_extended_bounds(bounds: &Bounds, zoom: usize, grid_size: f64) -> Bounds {
-    let mut north_east_pix = from_ll_to_subpixel(&(bounds.east, bounds.north), zoom).unwrap();
-    let mut south_west_pix = from_ll_to_subpixel(&(bounds.west, bounds.south), zoom).unwrap();
+    // Bounds crossing the antimeridian have their east edge at the start of the world, so move it past the west edge
+    if bounds.west > bounds.east {
+        north_east_pix.0 += world_width;
+    }
 
     north_east_pix.0 += grid_size;
     north_east_pix.1 -= grid_size;
@@ -14,13 +23,53 @@ pub fn calculate_extended_bounds(bounds: &Bounds, zoom: usize, grid_size: f64) -
     south_west_pix.1 += grid_size;
     
     // println!("ne0 {}, ne1 {}, sw0 {}, sw1 {}", north_east_pix.0, north_east_pix.1, south_west_pix.0, south_west_pix.1);
-    let north_east_latlng = from_pixel_to_ll(&(north_east_pix.0, north_east_pix.1), zoom).unwrap();
-    let south_west_latlng = from_pixel_to_ll(&(south_west_pix.0, south_west_pix.1), zoom).unwrap();
-
-    Bounds {
-        north: north_east_latlng.1,
-        east: north_east_latlng.0,
-        south: south_west_latlng.1,
-        west: south_west_latlng.0,
+    let north_east_latlng = projection.unproject(north_east_pix.0, north_east_pix.1, zoom)?;
+    let south_west_latlng = projection.unproject(south_west_pix.0, south_west_pix.1, zoom)?;
+
+    // Past the top or bottom of the world there's nothing left to project, so include everything up to the pole
+    let north = if north_east_pix.1 <= 0.0 { 90.0 } else { north_east_latlng.0 };
+    let south = if south_west_pix.1 >= world_height { -90.0 } else { south_west_latlng.0 };
+
+    let (west, east) = if north_east_pix.0 - south_west_pix.0 >= world_width {
+        (-180.0, 180.0)
+    } else {
+        (wrap_lng(south_west_latlng.1), wrap_lng(north_east_latlng.1))
+    };
+
+    Ok(Bounds {
+        north,
+        east,
+        south,
+        west,
+    })
+}
+
+/// A box on a plane extended by `grid_size` pixels, without the wrapping or poles of a globe.
+fn calculate_extended_box(bounds: &Bounds, zoom: usize, grid_size: f64, projection: &dyn Projection) -> Result<Bounds, ClusterError> {
+    let (east, north) = projection.project(bounds.north, bounds.east, zoom)?;
+    let (west, south) = projection.project(bounds.south, bounds.west, zoom)?;
+    let (north, east) = projection.unproject(east + grid_size, north - grid_size, zoom)?;
+    let (south, west) = projection.unproject(west - grid_size, south + grid_size, zoom)?;
+    Ok(Bounds { north, east, south, west })
+}
+
+/// The same longitude within -180° to 180°, where 180° itself stays 180°.
+pub fn wrap_lng(lng: f64) -> f64 {
+    if (-180.0..=180.0).contains(&lng) {
+        lng
+    } else {
+        (lng + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+/// The same longitude moved by 360° if needed to be within 180° of `reference`,
+/// so that longitudes on either side of the antimeridian can be averaged.
+pub fn unwrap_lng(lng: f64, reference: f64) -> f64 {
+    if lng - reference > 180.0 {
+        lng - 360.0
+    } else if reference - lng > 180.0 {
+        lng + 360.0
+    } else {
+        lng
     }
 }
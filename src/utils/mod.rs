@@ -1 +1,2 @@
-pub mod bounds;
\ No newline at end of file
+pub mod bounds;
+pub mod spatial_order;
\ No newline at end of file
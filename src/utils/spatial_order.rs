@@ -0,0 +1,42 @@
+/// Cells along each side of the square that points are placed in before being ordered.
+const SIDE: u64 = 1 << 32;
+
+/// Indices of the points in the order a Hilbert curve over `(min_x, min_y)` to `(max_x, max_y)` visits them,
+/// so points close together on the curve are close together in space. Points in the same cell are ordered by
+/// their coordinates, so the order only depends on where the points are, and not on the order they were in.
+pub fn hilbert_order(points: &[(f64, f64)], (min_x, min_y): (f64, f64), (max_x, max_y): (f64, f64)) -> Vec<usize> {
+    let cell = |value: f64, min: f64, max: f64| -> u64 {
+        let ratio = if max > min { (value - min) / (max - min) } else { 0.0 };
+        (ratio.clamp(0.0, 1.0) * (SIDE - 1) as f64) as u64
+    };
+    let keys = points.iter()
+        .map(|&(x, y)| hilbert_index(cell(x, min_x, max_x), cell(y, min_y, max_y)))
+        .collect::<Vec<_>>();
+
+    let mut order = (0..points.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| keys[a].cmp(&keys[b])
+        .then(points[a].0.total_cmp(&points[b].0))
+        .then(points[a].1.total_cmp(&points[b].1)));
+    order
+}
+
+/// Distance along the Hilbert curve filling a `SIDE` by `SIDE` square to the cell at `(x, y)`.
+fn hilbert_index(mut x: u64, mut y: u64) -> u64 {
+    let mut index = 0;
+    let mut s = SIDE / 2;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        index += s * s * ((3 * rx) ^ ry);
+        // Rotate the quadrant so the curve inside it starts and ends next to its neighbours
+        if ry == 0 {
+            if rx == 1 {
+                x = SIDE - 1 - x;
+                y = SIDE - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    index
+}
@@ -1,8 +1,12 @@
+// `JsValue::from_serde` is deprecated, but is kept here to benchmark it against serde-wasm-bindgen
+#![allow(deprecated)]
+
 extern crate wasm_bindgen;
 extern crate wasm_bindgen_test;
 extern crate utilities;
 extern crate webassembly_marker_clusterer;
 extern crate web_sys;
+extern crate js_sys;
 extern crate easybench_wasm;
 
 use wasm_bindgen_test::*;
@@ -10,11 +14,14 @@ use wasm_bindgen::prelude::JsValue;
 use utilities::{ get_sample_markers, get_unique_sample_markers, DEFAULT_BOUNDS };
 use webassembly_marker_clusterer::{
   add_markers,
+  add_markers_from_buffer,
   cluster_markers_in_bounds,
+  clear,
   clear_clusters,
   structs::marker::Marker
 };
 use web_sys::console;
+use js_sys::Float64Array;
 
 // This runs a unit test in the browser, so it can use browser APIs.
 wasm_bindgen_test_configure!(run_in_browser);
@@ -46,21 +53,46 @@ fn serialize_deserialize() {
   console::log_1(&format!("deserialize/serde-wasm-bindgen: {}", deserialize_serde_wasm_bindgen).into());
 }
 
+#[wasm_bindgen_test]
+fn add_markers_from_objects_or_buffer() {
+  let sample_markers = get_sample_markers();
+  let coords = sample_markers.iter().flat_map(|marker| vec![marker.lat, marker.lng]).collect::<Vec<_>>();
+  let run_time = 10.0;
+
+  let add_objects = easybench_wasm::bench_env_limit(run_time,
+    JsValue::from_serde(&sample_markers).unwrap(),
+    |js_value| {
+      add_markers(js_value.clone()).unwrap();
+      clear().unwrap();
+    });
+
+  // Copying out of the typed array is included, as wasm-bindgen does it when JS passes one in
+  let add_buffer = easybench_wasm::bench_env_limit(run_time,
+    Float64Array::from(&coords[..]),
+    |buffer| {
+      add_markers_from_buffer(&buffer.to_vec(), None).unwrap();
+      clear().unwrap();
+    });
+
+  console::log_1(&format!("add/objects:                    {}", add_objects).into());
+  console::log_1(&format!("add/float64array:               {}", add_buffer).into());
+}
+
 #[wasm_bindgen_test]
 fn cluster() {
   let sample_markers = get_sample_markers();
   let js = JsValue::from_serde(&sample_markers).unwrap();
   let run_time = 6.0;
 
-  add_markers(js);
+  add_markers(js).unwrap();
 
   console::log_1(&"zm,    nanos,    R², itrs, smpl".into());
   for zoom in 7..12 {
     let res = easybench_wasm::bench_limit(run_time,
       || {
         let bounds = JsValue::from_serde(&DEFAULT_BOUNDS).unwrap();
-        cluster_markers_in_bounds(bounds, zoom);
-        clear_clusters();
+        cluster_markers_in_bounds(bounds, zoom).unwrap();
+        clear_clusters().unwrap();
       });
 
     console::log_1(&format!("{:2}, {:8.0}, {:.3}, {:4}, {:4}", zoom, res.ns_per_iter, res.goodness_of_fit, res.iterations, res.samples).into());
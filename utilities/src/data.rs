@@ -1,10005 +1,10004 @@
-use webassembly_marker_clusterer::structs::marker::Marker;
-
+/// Lat/lng pairs of the sample markers.
 #[allow(clippy::unreadable_literal)]
-pub static MARKERS: &[Marker] = &[
-Marker { lat: 43.6358644, lng: -79.4673894 },
-Marker { lat: 43.893691, lng: -78.9528484 },
-Marker { lat: 43.7003735, lng: -79.82465 },
-Marker { lat: 43.8090152, lng: -79.5977762 },
-Marker { lat: 43.5464983, lng: -79.7196848 },
-Marker { lat: 43.6479257, lng: -79.4843808 },
-Marker { lat: 43.6479257, lng: -79.4843808 },
-Marker { lat: 43.58174, lng: -79.7629768 },
-Marker { lat: 43.6381724, lng: -79.489059 },
-Marker { lat: 43.7398872, lng: -79.4860122 },
-Marker { lat: 43.9662864, lng: -79.2731931 },
-Marker { lat: 43.6383588, lng: -79.558157 },
-Marker { lat: 43.6787095, lng: -79.7298062 },
-Marker { lat: 43.5357441, lng: -79.620029 },
-Marker { lat: 43.6411375, lng: -79.4007447 },
-Marker { lat: 43.5671142, lng: -79.5720556 },
-Marker { lat: 43.6362766, lng: -79.7110688 },
-Marker { lat: 43.5966175, lng: -79.508407 },
-Marker { lat: 43.7642731, lng: -79.289817 },
-Marker { lat: 43.6737835, lng: -79.3289639 },
-Marker { lat: 43.5104756, lng: -79.6378863 },
-Marker { lat: 43.6824739, lng: -79.7923111 },
-Marker { lat: 43.7249117, lng: -79.5587716 },
-Marker { lat: 43.7038398, lng: -79.3972256 },
-Marker { lat: 43.8515565, lng: -79.4326387 },
-Marker { lat: 43.7857976, lng: -79.4202892 },
-Marker { lat: 43.5588904, lng: -79.5748638 },
-Marker { lat: 43.7894687, lng: -79.6225507 },
-Marker { lat: 43.8716243, lng: -79.4138112 },
-Marker { lat: 43.7400255, lng: -79.7567148 },
-Marker { lat: 43.858393, lng: -79.046034 },
-Marker { lat: 43.70462, lng: -79.397498 },
-Marker { lat: 43.7034304, lng: -79.5257417 },
-Marker { lat: 43.7034304, lng: -79.5257417 },
-Marker { lat: 43.7329696, lng: -79.3698925 },
-Marker { lat: 43.76784, lng: -79.647201 },
-Marker { lat: 43.4379865, lng: -79.7793937 },
-Marker { lat: 43.7258079, lng: -79.4806067 },
-Marker { lat: 43.5085523, lng: -79.8447597 },
-Marker { lat: 43.8028771, lng: -79.6067427 },
-Marker { lat: 43.7761934, lng: -79.7060079 },
-Marker { lat: 43.8484721, lng: -79.2516394 },
-Marker { lat: 43.708728, lng: -79.393175 },
-Marker { lat: 43.593633, lng: -79.534104 },
-Marker { lat: 43.5451746, lng: -79.7392907 },
-Marker { lat: 43.5850975, lng: -79.6461541 },
-Marker { lat: 43.8611036, lng: -79.3058536 },
-Marker { lat: 43.6700845, lng: -79.3864005 },
-Marker { lat: 43.6717424, lng: -79.3878028 },
-Marker { lat: 43.7893747, lng: -79.5306859 },
-Marker { lat: 43.7889194, lng: -79.1710875 },
-Marker { lat: 43.7398872, lng: -79.4860122 },
-Marker { lat: 43.661967, lng: -79.383964 },
-Marker { lat: 43.5891322, lng: -79.5665102 },
-Marker { lat: 43.5397534, lng: -79.6752927 },
-Marker { lat: 43.4529826, lng: -79.6835772 },
-Marker { lat: 43.9126435, lng: -79.5224295 },
-Marker { lat: 43.5875112, lng: -79.5396698 },
-Marker { lat: 43.817627, lng: -79.526387 },
-Marker { lat: 43.661641, lng: -79.551529 },
-Marker { lat: 43.781194, lng: -79.699799 },
-Marker { lat: 43.8827846, lng: -78.9176518 },
-Marker { lat: 43.4866305, lng: -79.7203828 },
-Marker { lat: 43.7307386, lng: -79.4025863 },
-Marker { lat: 43.770218, lng: -79.752934 },
-Marker { lat: 43.7404261, lng: -79.767597 },
-Marker { lat: 43.6467185, lng: -79.5254493 },
-Marker { lat: 43.7376226, lng: -79.5526474 },
-Marker { lat: 43.4426282, lng: -79.6723596 },
-Marker { lat: 43.7780028, lng: -79.5927857 },
-Marker { lat: 43.4034532, lng: -79.6962786 },
-Marker { lat: 43.4769, lng: -79.6517 },
-Marker { lat: 43.450706, lng: -79.6637369 },
-Marker { lat: 43.6708124, lng: -79.5684146 },
-Marker { lat: 43.7484654, lng: -79.4288477 },
-Marker { lat: 43.7424282, lng: -79.3895303 },
-Marker { lat: 43.9296357, lng: -79.4080208 },
-Marker { lat: 43.4456204, lng: -79.6707202 },
-Marker { lat: 43.4456204, lng: -79.6707202 },
-Marker { lat: 43.7677191, lng: -79.5025131 },
-Marker { lat: 43.7247312, lng: -79.3794753 },
-Marker { lat: 43.7488304, lng: -79.3801307 },
-Marker { lat: 43.644864, lng: -79.39906 },
-Marker { lat: 43.7466012, lng: -79.3873379 },
-Marker { lat: 43.603957, lng: -79.6496967 },
-Marker { lat: 43.3798154, lng: -79.8135987 },
-Marker { lat: 43.8602656, lng: -79.3345049 },
-Marker { lat: 43.778779, lng: -79.234277 },
-Marker { lat: 43.7035233, lng: -79.4964812 },
-Marker { lat: 43.566313, lng: -79.701798 },
-Marker { lat: 43.7657237, lng: -79.3834602 },
-Marker { lat: 43.9291685, lng: -79.5206991 },
-Marker { lat: 43.6466477, lng: -79.5222686 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.8902602, lng: -79.4175139 },
-Marker { lat: 43.724328, lng: -79.457733 },
-Marker { lat: 43.724328, lng: -79.457733 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.7853217, lng: -79.481963 },
-Marker { lat: 43.8823821, lng: -79.3582469 },
-Marker { lat: 43.4295918, lng: -79.6901089 },
-Marker { lat: 43.8376, lng: -79.3405 },
-Marker { lat: 43.8673932, lng: -79.4292426 },
-Marker { lat: 43.4922466, lng: -79.7213623 },
-Marker { lat: 43.866389, lng: -79.419764 },
-Marker { lat: 43.693909, lng: -79.50581 },
-Marker { lat: 43.6406527, lng: -79.3957762 },
-Marker { lat: 43.79922, lng: -79.119018 },
-Marker { lat: 43.712554, lng: -79.406076 },
-Marker { lat: 43.964052, lng: -79.451905 },
-Marker { lat: 43.750501, lng: -79.463972 },
-Marker { lat: 43.7954103, lng: -79.5852631 },
-Marker { lat: 43.759517, lng: -79.4473645 },
-Marker { lat: 43.876246, lng: -79.084793 },
-Marker { lat: 43.979162, lng: -79.605173 },
-Marker { lat: 43.943104, lng: -79.473689 },
-Marker { lat: 43.789, lng: -79.5743 },
-Marker { lat: 43.7100841, lng: -79.7518485 },
-Marker { lat: 43.9547713, lng: -78.9605599 },
-Marker { lat: 43.7364608, lng: -79.3795479 },
-Marker { lat: 43.7031905, lng: -79.4515166 },
-Marker { lat: 43.3685055, lng: -79.8213968 },
-Marker { lat: 43.4590762, lng: -79.6629057 },
-Marker { lat: 43.6928268, lng: -79.3041993 },
-Marker { lat: 43.7153856, lng: -79.7978277 },
-Marker { lat: 43.648953, lng: -79.385768 },
-Marker { lat: 43.965084, lng: -79.469436 },
-Marker { lat: 43.3462945, lng: -79.879245 },
-Marker { lat: 43.434491, lng: -79.775259 },
-Marker { lat: 43.9237996, lng: -79.5189553 },
-Marker { lat: 43.71814, lng: -79.2352263 },
-Marker { lat: 43.5128184, lng: -79.8783264 },
-Marker { lat: 43.8146255, lng: -79.4670506 },
-Marker { lat: 43.6043786, lng: -79.4938077 },
-Marker { lat: 43.7550283, lng: -79.3996323 },
-Marker { lat: 43.8793, lng: -79.497 },
-Marker { lat: 43.9799805, lng: -79.4441398 },
-Marker { lat: 43.628177, lng: -79.478717 },
-Marker { lat: 43.8668718, lng: -79.2754123 },
-Marker { lat: 43.6083711, lng: -79.5321219 },
-Marker { lat: 43.892331, lng: -79.041405 },
-Marker { lat: 43.9226845, lng: -79.4694341 },
-Marker { lat: 43.606496, lng: -79.655651 },
-Marker { lat: 43.632067, lng: -79.796738 },
-Marker { lat: 43.6301998, lng: -79.7788924 },
-Marker { lat: 43.7392709, lng: -79.3914954 },
-Marker { lat: 43.637822, lng: -79.392997 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.588944, lng: -79.6619422 },
-Marker { lat: 43.5217755, lng: -79.6451295 },
-Marker { lat: 43.6418261, lng: -79.7263528 },
-Marker { lat: 43.7600373, lng: -79.5245304 },
-Marker { lat: 43.761461, lng: -79.409802 },
-Marker { lat: 43.88, lng: -79.43 },
-Marker { lat: 43.9241031, lng: -79.5338182 },
-Marker { lat: 43.474768, lng: -79.746258 },
-Marker { lat: 43.645505, lng: -79.392457 },
-Marker { lat: 43.8573143, lng: -79.5642557 },
-Marker { lat: 43.7366961, lng: -79.8296295 },
-Marker { lat: 43.5221261, lng: -79.6077384 },
-Marker { lat: 43.7191625, lng: -79.4923959 },
-Marker { lat: 43.7439664, lng: -79.49275 },
-Marker { lat: 43.7810966, lng: -79.4128321 },
-Marker { lat: 43.850509, lng: -79.0746 },
-Marker { lat: 43.8442794, lng: -79.5551795 },
-Marker { lat: 43.712554, lng: -79.406076 },
-Marker { lat: 43.6404162, lng: -79.5856861 },
-Marker { lat: 43.7230949, lng: -79.3897365 },
-Marker { lat: 43.43, lng: -79.7225 },
-Marker { lat: 43.6385781, lng: -79.3993661 },
-Marker { lat: 43.665202, lng: -79.55153 },
-Marker { lat: 43.8696148, lng: -79.2197984 },
-Marker { lat: 43.857807, lng: -79.081645 },
-Marker { lat: 43.817274, lng: -79.124216 },
-Marker { lat: 43.868881, lng: -79.504132 },
-Marker { lat: 43.675308, lng: -79.280562 },
-Marker { lat: 43.7990565, lng: -79.2382248 },
-Marker { lat: 43.834474, lng: -79.581217 },
-Marker { lat: 43.6438226, lng: -79.5589299 },
-Marker { lat: 43.6840931, lng: -79.5167347 },
-Marker { lat: 43.9066239, lng: -79.6447435 },
-Marker { lat: 43.8220748, lng: -79.1453528 },
-Marker { lat: 43.6527787, lng: -79.8078842 },
-Marker { lat: 43.661725, lng: -79.384128 },
-Marker { lat: 43.7369211, lng: -79.4419226 },
-Marker { lat: 43.848855, lng: -79.572754 },
-Marker { lat: 43.7977757, lng: -79.4064701 },
-Marker { lat: 43.7762122, lng: -79.6061479 },
-Marker { lat: 43.760304, lng: -79.41162 },
-Marker { lat: 43.6849299, lng: -79.48394 },
-Marker { lat: 43.8200813, lng: -79.5661949 },
-Marker { lat: 43.863327, lng: -79.904064 },
-Marker { lat: 43.874256, lng: -79.000566 },
-Marker { lat: 43.9551619, lng: -78.96217 },
-Marker { lat: 43.7028172, lng: -79.4101737 },
-Marker { lat: 43.941575, lng: -78.886407 },
-Marker { lat: 43.7954103, lng: -79.5852631 },
-Marker { lat: 43.8901316, lng: -79.2713581 },
-Marker { lat: 43.887557, lng: -78.9132341 },
-Marker { lat: 43.65429, lng: -79.463097 },
-Marker { lat: 43.6452883, lng: -79.7318251 },
-Marker { lat: 43.7530801, lng: -79.5111433 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.768151, lng: -79.390791 },
-Marker { lat: 43.6982465, lng: -79.5144527 },
-Marker { lat: 43.7449277, lng: -79.401526 },
-Marker { lat: 43.882892, lng: -79.012662 },
-Marker { lat: 43.83, lng: -79.2096 },
-Marker { lat: 43.8562518, lng: -79.5438141 },
-Marker { lat: 43.7657237, lng: -79.3834602 },
-Marker { lat: 43.7717364, lng: -79.2613815 },
-Marker { lat: 43.8221254, lng: -79.558882 },
-Marker { lat: 43.7112057, lng: -79.2879966 },
-Marker { lat: 43.7080205, lng: -79.4786935 },
-Marker { lat: 43.8932507, lng: -79.256921 },
-Marker { lat: 43.5857073, lng: -79.6464202 },
-Marker { lat: 43.7541274, lng: -79.5013529 },
-Marker { lat: 43.708965, lng: -79.392985 },
-Marker { lat: 43.5442626, lng: -79.6680551 },
-Marker { lat: 43.647699, lng: -79.566622 },
-Marker { lat: 43.6593521, lng: -79.3856503 },
-Marker { lat: 43.6616166, lng: -79.3846797 },
-Marker { lat: 43.6595018, lng: -79.3847849 },
-Marker { lat: 43.87765, lng: -79.439331 },
-Marker { lat: 43.4273464, lng: -79.801659 },
-Marker { lat: 43.6768964, lng: -79.5165707 },
-Marker { lat: 43.788215, lng: -79.392636 },
-Marker { lat: 43.567403, lng: -79.590972 },
-Marker { lat: 43.3911173, lng: -79.896943 },
-Marker { lat: 43.6976288, lng: -79.5120986 },
-Marker { lat: 43.697717, lng: -79.51213 },
-Marker { lat: 43.623821, lng: -79.478728 },
-Marker { lat: 43.452521, lng: -79.657899 },
-Marker { lat: 43.6446591, lng: -79.4847665 },
-Marker { lat: 43.850021, lng: -79.067152 },
-Marker { lat: 43.84902, lng: -79.066748 },
-Marker { lat: 43.849856, lng: -79.067957 },
-Marker { lat: 43.850079, lng: -79.068071 },
-Marker { lat: 43.849211, lng: -79.066866 },
-Marker { lat: 43.850277, lng: -79.067298 },
-Marker { lat: 43.883973, lng: -79.339361 },
-Marker { lat: 43.837757, lng: -79.689359 },
-Marker { lat: 43.833614, lng: -79.644529 },
-Marker { lat: 43.956064, lng: -79.460794 },
-Marker { lat: 43.7942627, lng: -79.4421239 },
-Marker { lat: 43.7907823, lng: -79.5571765 },
-Marker { lat: 43.7325595, lng: -79.8279358 },
-Marker { lat: 43.9082719, lng: -78.9366993 },
-Marker { lat: 43.6659675, lng: -79.3871076 },
-Marker { lat: 43.8772637, lng: -79.0537471 },
-Marker { lat: 43.7233558, lng: -79.3797211 },
-Marker { lat: 43.4318359, lng: -79.9302112 },
-Marker { lat: 43.4712989, lng: -79.6423452 },
-Marker { lat: 43.5779331, lng: -79.5884547 },
-Marker { lat: 43.7191965, lng: -79.9329562 },
-Marker { lat: 43.8762759, lng: -79.2697201 },
-Marker { lat: 43.4210884, lng: -79.7095968 },
-Marker { lat: 43.944026, lng: -79.778394 },
-Marker { lat: 43.6338194, lng: -79.7737747 },
-Marker { lat: 43.5497304, lng: -79.6706397 },
-Marker { lat: 43.7515172, lng: -79.8259083 },
-Marker { lat: 43.8829341, lng: -79.3975317 },
-Marker { lat: 43.5015311, lng: -79.6941039 },
-Marker { lat: 43.858247, lng: -79.438127 },
-Marker { lat: 43.7938753, lng: -79.4157965 },
-Marker { lat: 43.910163, lng: -79.445996 },
-Marker { lat: 43.8205905, lng: -79.4237985 },
-Marker { lat: 43.80208, lng: -79.714026 },
-Marker { lat: 43.6554446, lng: -79.4260804 },
-Marker { lat: 43.7953138, lng: -79.3545517 },
-Marker { lat: 43.640345, lng: -79.563119 },
-Marker { lat: 43.7931515, lng: -79.6813391 },
-Marker { lat: 43.925739, lng: -79.5164663 },
-Marker { lat: 43.662775, lng: -79.411813 },
-Marker { lat: 43.645107, lng: -79.391052 },
-Marker { lat: 43.800219, lng: -79.4089761 },
-Marker { lat: 43.65341, lng: -79.923246 },
-Marker { lat: 43.874695, lng: -79.440523 },
-Marker { lat: 43.632425, lng: -79.870069 },
-Marker { lat: 43.621199, lng: -79.910656 },
-Marker { lat: 43.7258482, lng: -79.3643509 },
-Marker { lat: 43.833554, lng: -79.487112 },
-Marker { lat: 43.6383599, lng: -79.398657 },
-Marker { lat: 43.625807, lng: -79.477924 },
-Marker { lat: 43.8446161, lng: -79.5090854 },
-Marker { lat: 43.983376, lng: -79.380147 },
-Marker { lat: 43.3512621, lng: -79.8519404 },
-Marker { lat: 43.867274, lng: -79.4319528 },
-Marker { lat: 43.7161359, lng: -79.4341751 },
-Marker { lat: 43.7989466, lng: -79.4149676 },
-Marker { lat: 43.9095919, lng: -78.9507187 },
-Marker { lat: 43.816392, lng: -79.103311 },
-Marker { lat: 43.8619851, lng: -79.4374648 },
-Marker { lat: 43.8974112, lng: -79.4575046 },
-Marker { lat: 43.420095, lng: -79.687314 },
-Marker { lat: 43.8369445, lng: -79.3081149 },
-Marker { lat: 43.7473316, lng: -79.5539102 },
-Marker { lat: 43.717992, lng: -79.415278 },
-Marker { lat: 43.731919, lng: -79.577359 },
-Marker { lat: 43.43852, lng: -79.753911 },
-Marker { lat: 43.8753838, lng: -79.407612 },
-Marker { lat: 43.7560373, lng: -79.5110753 },
-Marker { lat: 43.7002792, lng: -79.4278103 },
-Marker { lat: 43.644883, lng: -79.363868 },
-Marker { lat: 43.851993, lng: -79.43178 },
-Marker { lat: 43.6307453, lng: -79.7965763 },
-Marker { lat: 43.4560511, lng: -79.6644791 },
-Marker { lat: 43.704519, lng: -79.433254 },
-Marker { lat: 43.667953, lng: -79.293598 },
-Marker { lat: 43.6506887, lng: -79.5674133 },
-Marker { lat: 43.8890652, lng: -79.3239364 },
-Marker { lat: 43.7941547, lng: -79.5143493 },
-Marker { lat: 43.500402, lng: -79.878087 },
-Marker { lat: 43.4617205, lng: -79.6455308 },
-Marker { lat: 43.8228271, lng: -79.3989259 },
-Marker { lat: 43.7727699, lng: -79.3938121 },
-Marker { lat: 43.942295, lng: -79.4504192 },
-Marker { lat: 43.7795678, lng: -79.3542799 },
-Marker { lat: 43.6436191, lng: -79.8003496 },
-Marker { lat: 43.4655941, lng: -79.6471301 },
-Marker { lat: 43.975456, lng: -78.99449 },
-Marker { lat: 43.8695348, lng: -79.3172869 },
-Marker { lat: 43.9450774, lng: -79.6560971 },
-Marker { lat: 43.9107362, lng: -79.2552344 },
-Marker { lat: 43.6676849, lng: -79.3890196 },
-Marker { lat: 43.7602923, lng: -79.4154558 },
-Marker { lat: 43.8447451, lng: -79.4432559 },
-Marker { lat: 43.8063683, lng: -79.4182548 },
-Marker { lat: 43.352015, lng: -79.944409 },
-Marker { lat: 43.95103, lng: -79.4768495 },
-Marker { lat: 43.7286088, lng: -79.3261749 },
-Marker { lat: 43.64078, lng: -79.396295 },
-Marker { lat: 43.9285482, lng: -79.417096 },
-Marker { lat: 43.6676849, lng: -79.3890196 },
-Marker { lat: 43.6616609, lng: -79.3536888 },
-Marker { lat: 43.7844008, lng: -79.288555 },
-Marker { lat: 43.8246179, lng: -79.4327452 },
-Marker { lat: 43.843866, lng: -79.431036 },
-Marker { lat: 43.7740292, lng: -79.3984688 },
-Marker { lat: 43.4549918, lng: -79.6628853 },
-Marker { lat: 43.6054405, lng: -79.5401977 },
-Marker { lat: 43.665786, lng: -79.3855809 },
-Marker { lat: 43.6414946, lng: -79.8052683 },
-Marker { lat: 43.819975, lng: -79.380895 },
-Marker { lat: 43.939768, lng: -79.466367 },
-Marker { lat: 43.9734, lng: -79.802179 },
-Marker { lat: 43.7247033, lng: -79.307678 },
-Marker { lat: 43.766028, lng: -79.1979422 },
-Marker { lat: 43.8425836, lng: -79.0913144 },
-Marker { lat: 43.6774655, lng: -79.8339673 },
-Marker { lat: 43.4196107, lng: -79.7061283 },
-Marker { lat: 43.8552424, lng: -79.4116306 },
-Marker { lat: 43.672821, lng: -79.2823433 },
-Marker { lat: 43.4366336, lng: -79.6783318 },
-Marker { lat: 43.8627984, lng: -79.4651037 },
-Marker { lat: 43.8140888, lng: -79.4372378 },
-Marker { lat: 43.6549162, lng: -79.379287 },
-Marker { lat: 43.8075577, lng: -79.379943 },
-Marker { lat: 43.6490838, lng: -79.392082 },
-Marker { lat: 43.696135, lng: -79.403471 },
-Marker { lat: 43.7524996, lng: -79.3325441 },
-Marker { lat: 43.8713481, lng: -79.4377905 },
-Marker { lat: 43.7887037, lng: -79.4370217 },
-Marker { lat: 43.62526, lng: -79.477903 },
-Marker { lat: 43.789718, lng: -79.274999 },
-Marker { lat: 43.778008, lng: -79.4527173 },
-Marker { lat: 43.8159152, lng: -79.3984355 },
-Marker { lat: 43.7475418, lng: -79.4356373 },
-Marker { lat: 43.5901567, lng: -79.5377688 },
-Marker { lat: 43.63499, lng: -79.4937258 },
-Marker { lat: 43.8135782, lng: -79.6214215 },
-Marker { lat: 43.8760975, lng: -79.4796529 },
-Marker { lat: 43.8571799, lng: -79.3261757 },
-Marker { lat: 43.6041158, lng: -79.6183248 },
-Marker { lat: 43.7761811, lng: -79.3621877 },
-Marker { lat: 43.8555461, lng: -79.4626471 },
-Marker { lat: 43.7976995, lng: -79.4004139 },
-Marker { lat: 43.6024451, lng: -79.4953553 },
-Marker { lat: 43.6397347, lng: -79.3920867 },
-Marker { lat: 43.5292779, lng: -79.8657555 },
-Marker { lat: 43.652331, lng: -79.356014 },
-Marker { lat: 43.7940884, lng: -79.6683269 },
-Marker { lat: 43.6985318, lng: -79.7919938 },
-Marker { lat: 43.7690784, lng: -79.3694836 },
-Marker { lat: 43.487834, lng: -79.7316009 },
-Marker { lat: 43.651872, lng: -79.883 },
-Marker { lat: 43.4486419, lng: -79.6674981 },
-Marker { lat: 43.448688, lng: -79.667467 },
-Marker { lat: 43.885839, lng: -79.3145671 },
-Marker { lat: 43.7814069, lng: -79.5763573 },
-Marker { lat: 43.908177, lng: -79.375176 },
-Marker { lat: 43.5584535, lng: -79.6126917 },
-Marker { lat: 43.939828, lng: -79.466651 },
-Marker { lat: 43.9702083, lng: -79.4898684 },
-Marker { lat: 43.632905, lng: -79.928671 },
-Marker { lat: 43.6926243, lng: -79.4563179 },
-Marker { lat: 43.7769633, lng: -79.3711861 },
-Marker { lat: 43.7897445, lng: -79.151341 },
-Marker { lat: 43.8742771, lng: -79.0385196 },
-Marker { lat: 43.868772, lng: -79.393233 },
-Marker { lat: 43.8000938, lng: -79.474316 },
-Marker { lat: 43.8512896, lng: -79.5119295 },
-Marker { lat: 43.853766, lng: -79.5633173 },
-Marker { lat: 43.7287555, lng: -79.2492934 },
-Marker { lat: 43.5638496, lng: -79.576553 },
-Marker { lat: 43.9707739, lng: -79.4412916 },
-Marker { lat: 43.809992, lng: -79.561354 },
-Marker { lat: 43.835263, lng: -79.6256733 },
-Marker { lat: 43.7202729, lng: -79.7142161 },
-Marker { lat: 43.7951102, lng: -79.2391742 },
-Marker { lat: 43.892394, lng: -79.213701 },
-Marker { lat: 43.843866, lng: -79.431036 },
-Marker { lat: 43.7850657, lng: -79.7206633 },
-Marker { lat: 43.8068655, lng: -79.7143324 },
-Marker { lat: 43.8946325, lng: -79.377817 },
-Marker { lat: 43.7763249, lng: -79.4140326 },
-Marker { lat: 43.8083698, lng: -79.4116091 },
-Marker { lat: 43.4410466, lng: -79.7259838 },
-Marker { lat: 43.9218516, lng: -79.5248497 },
-Marker { lat: 43.903741, lng: -78.956544 },
-Marker { lat: 43.589184, lng: -79.6543144 },
-Marker { lat: 43.7949531, lng: -79.393571 },
-Marker { lat: 43.9622131, lng: -79.4416564 },
-Marker { lat: 43.983629, lng: -79.468687 },
-Marker { lat: 43.801904, lng: -79.3009306 },
-Marker { lat: 43.6026495, lng: -79.5925375 },
-Marker { lat: 43.606173, lng: -79.655629 },
-Marker { lat: 43.755457, lng: -79.454193 },
-Marker { lat: 43.583364, lng: -79.71222 },
-Marker { lat: 43.7937989, lng: -79.280506 },
-Marker { lat: 43.9528351, lng: -79.3857072 },
-Marker { lat: 43.7368681, lng: -79.3491592 },
-Marker { lat: 43.8104341, lng: -79.3808711 },
-Marker { lat: 43.665724, lng: -79.447415 },
-Marker { lat: 43.881403, lng: -79.219835 },
-Marker { lat: 43.6807098, lng: -79.3894335 },
-Marker { lat: 43.8694674, lng: -79.3272055 },
-Marker { lat: 43.8743171, lng: -79.3303704 },
-Marker { lat: 43.6968333, lng: -79.3939934 },
-Marker { lat: 43.7031907, lng: -79.5136749 },
-Marker { lat: 43.659661, lng: -79.780012 },
-Marker { lat: 43.5990981, lng: -79.6743268 },
-Marker { lat: 43.6707526, lng: -79.4615102 },
-Marker { lat: 43.863707, lng: -79.514399 },
-Marker { lat: 43.4889496, lng: -79.8503825 },
-Marker { lat: 43.64182, lng: -79.381734 },
-Marker { lat: 43.434214, lng: -79.776184 },
-Marker { lat: 43.862518, lng: -79.016156 },
-Marker { lat: 43.9010978, lng: -78.9756096 },
-Marker { lat: 43.84354, lng: -79.466315 },
-Marker { lat: 43.4381875, lng: -79.9412275 },
-Marker { lat: 43.7137, lng: -79.7913883 },
-Marker { lat: 43.7787489, lng: -79.4171422 },
-Marker { lat: 43.780228, lng: -79.418123 },
-Marker { lat: 43.718287, lng: -79.830654 },
-Marker { lat: 43.4792273, lng: -79.6545708 },
-Marker { lat: 43.7843486, lng: -79.3971592 },
-Marker { lat: 43.4271323, lng: -79.6964378 },
-Marker { lat: 43.496354, lng: -79.719763 },
-Marker { lat: 43.569919, lng: -79.5937118 },
-Marker { lat: 43.4833156, lng: -79.7298005 },
-Marker { lat: 43.765581, lng: -79.412587 },
-Marker { lat: 43.3563593, lng: -79.7638927 },
-Marker { lat: 43.4787969, lng: -79.75987 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.8565997, lng: -79.4836714 },
-Marker { lat: 43.656165, lng: -79.383893 },
-Marker { lat: 43.7400831, lng: -79.344693 },
-Marker { lat: 43.7171038, lng: -79.2800195 },
-Marker { lat: 43.960441, lng: -78.959294 },
-Marker { lat: 43.412108, lng: -79.801877 },
-Marker { lat: 43.903929, lng: -79.401161 },
-Marker { lat: 43.4418243, lng: -79.7607598 },
-Marker { lat: 43.641596, lng: -79.423443 },
-Marker { lat: 43.641966, lng: -79.38174 },
-Marker { lat: 43.7575473, lng: -79.4067503 },
-Marker { lat: 43.731548, lng: -79.762418 },
-Marker { lat: 43.944339, lng: -79.455992 },
-Marker { lat: 43.852115, lng: -79.10122 },
-Marker { lat: 43.961678, lng: -79.4372405 },
-Marker { lat: 43.961678, lng: -79.4372405 },
-Marker { lat: 43.649067, lng: -79.37693 },
-Marker { lat: 43.5304905, lng: -79.8695173 },
-Marker { lat: 43.660137, lng: -79.385869 },
-Marker { lat: 43.6200221, lng: -79.5967888 },
-Marker { lat: 43.638608, lng: -79.413352 },
-Marker { lat: 43.523063, lng: -79.835957 },
-Marker { lat: 43.3830024, lng: -79.8027324 },
-Marker { lat: 43.4678256, lng: -79.6471677 },
-Marker { lat: 43.4790773, lng: -79.653967 },
-Marker { lat: 43.6853077, lng: -79.552673 },
-Marker { lat: 43.594365, lng: -79.6682848 },
-Marker { lat: 43.8729042, lng: -79.4275302 },
-Marker { lat: 43.668718, lng: -79.383996 },
-Marker { lat: 43.663592, lng: -79.38683 },
-Marker { lat: 43.8534942, lng: -79.4653801 },
-Marker { lat: 43.3726009, lng: -79.7529051 },
-Marker { lat: 43.6664221, lng: -79.5560937 },
-Marker { lat: 43.377245, lng: -79.8359689 },
-Marker { lat: 43.873693, lng: -79.062149 },
-Marker { lat: 43.3939474, lng: -79.8279672 },
-Marker { lat: 43.760559, lng: -79.4080879 },
-Marker { lat: 43.692288, lng: -79.860371 },
-Marker { lat: 43.64534, lng: -79.402185 },
-Marker { lat: 43.518299, lng: -79.877404 },
-Marker { lat: 43.758585, lng: -79.406534 },
-Marker { lat: 43.4293, lng: -79.782108 },
-Marker { lat: 43.8865437, lng: -79.4589935 },
-Marker { lat: 43.659964, lng: -79.451591 },
-Marker { lat: 43.4552599, lng: -79.6680814 },
-Marker { lat: 43.588525, lng: -79.67628 },
-Marker { lat: 43.4014816, lng: -79.7051778 },
-Marker { lat: 43.447443, lng: -79.686948 },
-Marker { lat: 43.6374676, lng: -79.4184499 },
-Marker { lat: 43.381104, lng: -79.731903 },
-Marker { lat: 43.624595, lng: -79.480119 },
-Marker { lat: 43.4366336, lng: -79.6783318 },
-Marker { lat: 43.624595, lng: -79.480119 },
-Marker { lat: 43.641087, lng: -79.381405 },
-Marker { lat: 43.664042, lng: -79.387166 },
-Marker { lat: 43.7058847, lng: -79.2744219 },
-Marker { lat: 43.647651, lng: -79.401518 },
-Marker { lat: 43.7774165, lng: -79.2812115 },
-Marker { lat: 43.655305, lng: -79.3537502 },
-Marker { lat: 43.6652675, lng: -79.3410336 },
-Marker { lat: 43.6503521, lng: -79.3609928 },
-Marker { lat: 43.6294481, lng: -79.7978252 },
-Marker { lat: 43.8518098, lng: -79.4302547 },
-Marker { lat: 43.4973072, lng: -79.691856 },
-Marker { lat: 43.862336, lng: -79.435736 },
-Marker { lat: 43.8294967, lng: -79.2362866 },
-Marker { lat: 43.7758867, lng: -79.599052 },
-Marker { lat: 43.6018706, lng: -79.6571194 },
-Marker { lat: 43.4033178, lng: -79.6945961 },
-Marker { lat: 43.8541173, lng: -79.3409476 },
-Marker { lat: 43.977482, lng: -79.232725 },
-Marker { lat: 43.780228, lng: -79.418123 },
-Marker { lat: 43.7300903, lng: -79.3322682 },
-Marker { lat: 43.7307812, lng: -79.3353886 },
-Marker { lat: 43.66295, lng: -79.3863069 },
-Marker { lat: 43.6480159, lng: -79.3968889 },
-Marker { lat: 43.8035, lng: -79.3543771 },
-Marker { lat: 43.7578524, lng: -79.4130365 },
-Marker { lat: 43.7734697, lng: -79.2536323 },
-Marker { lat: 43.8564417, lng: -79.3251868 },
-Marker { lat: 43.6784907, lng: -79.2988979 },
-Marker { lat: 43.6559939, lng: -79.4669626 },
-Marker { lat: 43.783315, lng: -79.578869 },
-Marker { lat: 43.7094565, lng: -79.4335822 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.7242482, lng: -79.6162362 },
-Marker { lat: 43.678607, lng: -79.8011632 },
-Marker { lat: 43.8529504, lng: -79.0561635 },
-Marker { lat: 43.792606, lng: -79.415067 },
-Marker { lat: 43.8874316, lng: -79.3074869 },
-Marker { lat: 43.792314, lng: -79.449052 },
-Marker { lat: 43.6853301, lng: -79.4349605 },
-Marker { lat: 43.491504, lng: -79.7202492 },
-Marker { lat: 43.3581324, lng: -79.8350093 },
-Marker { lat: 43.5805107, lng: -79.6244991 },
-Marker { lat: 43.7739436, lng: -79.3452463 },
-Marker { lat: 43.7069544, lng: -79.395016 },
-Marker { lat: 43.6459385, lng: -79.7340171 },
-Marker { lat: 43.590285, lng: -79.652689 },
-Marker { lat: 43.6369656, lng: -79.7550544 },
-Marker { lat: 43.622729, lng: -79.48319 },
-Marker { lat: 43.7180254, lng: -79.4013513 },
-Marker { lat: 43.7698862, lng: -79.4135354 },
-Marker { lat: 43.7793201, lng: -79.2814437 },
-Marker { lat: 43.6930009, lng: -79.8032856 },
-Marker { lat: 43.549821, lng: -79.614806 },
-Marker { lat: 43.4837182, lng: -79.7328219 },
-Marker { lat: 43.6185387, lng: -79.4850993 },
-Marker { lat: 43.8029581, lng: -79.2718367 },
-Marker { lat: 43.9538565, lng: -79.4613649 },
-Marker { lat: 43.809115, lng: -79.423348 },
-Marker { lat: 43.863769, lng: -79.4308691 },
-Marker { lat: 43.9193502, lng: -79.5246262 },
-Marker { lat: 43.637727, lng: -79.582466 },
-Marker { lat: 43.721178, lng: -79.741324 },
-Marker { lat: 43.6881587, lng: -79.7858857 },
-Marker { lat: 43.639593, lng: -79.563672 },
-Marker { lat: 43.5516727, lng: -79.7135208 },
-Marker { lat: 43.4256549, lng: -79.774697 },
-Marker { lat: 43.4230246, lng: -79.7598708 },
-Marker { lat: 43.506948, lng: -79.813279 },
-Marker { lat: 43.90919, lng: -79.636193 },
-Marker { lat: 43.8346732, lng: -79.4391956 },
-Marker { lat: 43.5921211, lng: -79.6841365 },
-Marker { lat: 43.888228, lng: -79.465994 },
-Marker { lat: 43.7400855, lng: -79.3848074 },
-Marker { lat: 43.871856, lng: -79.113531 },
-Marker { lat: 43.957065, lng: -78.971797 },
-Marker { lat: 43.3718156, lng: -79.7639519 },
-Marker { lat: 43.5963161, lng: -79.7667906 },
-Marker { lat: 43.7024449, lng: -79.8563285 },
-Marker { lat: 43.5286325, lng: -79.6569117 },
-Marker { lat: 43.7311487, lng: -79.7872672 },
-Marker { lat: 43.9387658, lng: -79.4337945 },
-Marker { lat: 43.7803254, lng: -79.2897587 },
-Marker { lat: 43.78323, lng: -79.2988415 },
-Marker { lat: 43.8335678, lng: -79.5750104 },
-Marker { lat: 43.858601, lng: -79.504814 },
-Marker { lat: 43.8588994, lng: -79.2863437 },
-Marker { lat: 43.979692, lng: -79.281561 },
-Marker { lat: 43.9693647, lng: -79.2671987 },
-Marker { lat: 43.7308993, lng: -79.4180256 },
-Marker { lat: 43.4958948, lng: -79.6634856 },
-Marker { lat: 43.6312825, lng: -79.8179249 },
-Marker { lat: 43.96976, lng: -79.5130729 },
-Marker { lat: 43.7676486, lng: -79.3821279 },
-Marker { lat: 43.8609613, lng: -79.4683922 },
-Marker { lat: 43.664773, lng: -79.386987 },
-Marker { lat: 43.6397205, lng: -79.384172 },
-Marker { lat: 43.5311699, lng: -79.6812284 },
-Marker { lat: 43.644672, lng: -79.5294079 },
-Marker { lat: 43.8738949, lng: -79.4096392 },
-Marker { lat: 43.663008, lng: -79.376777 },
-Marker { lat: 43.8911679, lng: -79.915979 },
-Marker { lat: 43.861812, lng: -79.438149 },
-Marker { lat: 43.639803, lng: -79.414753 },
-Marker { lat: 43.7536761, lng: -79.1908242 },
-Marker { lat: 43.762714, lng: -79.346424 },
-Marker { lat: 43.9241418, lng: -79.5175135 },
-Marker { lat: 43.7393249, lng: -79.3948333 },
-Marker { lat: 43.647513, lng: -79.392702 },
-Marker { lat: 43.688974, lng: -79.388742 },
-Marker { lat: 43.9613393, lng: -78.9622963 },
-Marker { lat: 43.9252792, lng: -78.881418 },
-Marker { lat: 43.8608398, lng: -79.4602586 },
-Marker { lat: 43.8518581, lng: -79.5018269 },
-Marker { lat: 43.9067919, lng: -79.6514425 },
-Marker { lat: 43.6770656, lng: -79.4412766 },
-Marker { lat: 43.759234, lng: -79.5851521 },
-Marker { lat: 43.883078, lng: -79.814237 },
-Marker { lat: 43.625383, lng: -79.4969316 },
-Marker { lat: 43.8958312, lng: -79.2160162 },
-Marker { lat: 43.7610136, lng: -79.7184557 },
-Marker { lat: 43.6227432, lng: -79.4819344 },
-Marker { lat: 43.5646723, lng: -79.7366116 },
-Marker { lat: 43.5620702, lng: -79.7202245 },
-Marker { lat: 43.6320192, lng: -79.8732153 },
-Marker { lat: 43.728742, lng: -79.482576 },
-Marker { lat: 43.6805, lng: -79.4625767 },
-Marker { lat: 43.586289, lng: -79.647364 },
-Marker { lat: 43.75429, lng: -79.492249 },
-Marker { lat: 43.4513865, lng: -79.7368857 },
-Marker { lat: 43.6883032, lng: -79.4768786 },
-Marker { lat: 43.701781, lng: -79.418053 },
-Marker { lat: 43.910163, lng: -79.445996 },
-Marker { lat: 43.6998957, lng: -79.8501093 },
-Marker { lat: 43.6830187, lng: -79.4180049 },
-Marker { lat: 43.5559395, lng: -79.6495358 },
-Marker { lat: 43.7026519, lng: -79.351603 },
-Marker { lat: 43.535943, lng: -79.858296 },
-Marker { lat: 43.852333, lng: -79.3391785 },
-Marker { lat: 43.4791688, lng: -79.7261692 },
-Marker { lat: 43.6533142, lng: -79.3683904 },
-Marker { lat: 43.7713931, lng: -79.4126675 },
-Marker { lat: 43.7786129, lng: -79.4518042 },
-Marker { lat: 43.64787, lng: -79.391138 },
-Marker { lat: 43.7789023, lng: -79.2834915 },
-Marker { lat: 43.8180112, lng: -79.3297792 },
-Marker { lat: 43.704646, lng: -79.251748 },
-Marker { lat: 43.601008, lng: -79.503545 },
-Marker { lat: 43.3589266, lng: -79.8427286 },
-Marker { lat: 43.376575, lng: -79.7491803 },
-Marker { lat: 43.643012, lng: -79.406201 },
-Marker { lat: 43.6948889, lng: -79.3182261 },
-Marker { lat: 43.718009, lng: -79.325996 },
-Marker { lat: 43.6800705, lng: -79.7319362 },
-Marker { lat: 43.649783, lng: -79.38044 },
-Marker { lat: 43.88453, lng: -78.880411 },
-Marker { lat: 43.887132, lng: -79.420343 },
-Marker { lat: 43.6460836, lng: -79.3650117 },
-Marker { lat: 43.8301675, lng: -79.5708923 },
-Marker { lat: 43.826744, lng: -79.091001 },
-Marker { lat: 43.813422, lng: -79.10561 },
-Marker { lat: 43.8444911, lng: -79.4258074 },
-Marker { lat: 43.890795, lng: -78.915014 },
-Marker { lat: 43.737966, lng: -79.762589 },
-Marker { lat: 43.718033, lng: -79.7838365 },
-Marker { lat: 43.5110649, lng: -79.851121 },
-Marker { lat: 43.4095538, lng: -79.7045407 },
-Marker { lat: 43.80821, lng: -79.357177 },
-Marker { lat: 43.838982, lng: -79.523495 },
-Marker { lat: 43.6871413, lng: -79.3327398 },
-Marker { lat: 43.6911065, lng: -79.7917003 },
-Marker { lat: 43.7282818, lng: -79.4332806 },
-Marker { lat: 43.659421, lng: -79.361025 },
-Marker { lat: 43.3567142, lng: -79.8363353 },
-Marker { lat: 43.7989173, lng: -79.2097151 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.6679848, lng: -79.3755762 },
-Marker { lat: 43.7534418, lng: -79.3046931 },
-Marker { lat: 43.8635634, lng: -79.4650042 },
-Marker { lat: 43.6243335, lng: -79.485323 },
-Marker { lat: 43.7267828, lng: -79.8000023 },
-Marker { lat: 43.7204811, lng: -79.7132316 },
-Marker { lat: 43.6434207, lng: -79.7251993 },
-Marker { lat: 43.833122, lng: -79.531325 },
-Marker { lat: 43.4517224, lng: -79.735387 },
-Marker { lat: 43.660142, lng: -79.4404141 },
-Marker { lat: 43.631119, lng: -79.6006862 },
-Marker { lat: 43.9579666, lng: -79.2433791 },
-Marker { lat: 43.656981, lng: -79.919535 },
-Marker { lat: 43.412112, lng: -79.800498 },
-Marker { lat: 43.6368129, lng: -79.3975163 },
-Marker { lat: 43.7517603, lng: -79.3975819 },
-Marker { lat: 43.85593, lng: -79.3433541 },
-Marker { lat: 43.6654565, lng: -79.7730581 },
-Marker { lat: 43.74509, lng: -79.776653 },
-Marker { lat: 43.4709615, lng: -79.6921393 },
-Marker { lat: 43.6642255, lng: -79.7804607 },
-Marker { lat: 43.590124, lng: -79.632916 },
-Marker { lat: 43.6211459, lng: -79.5215694 },
-Marker { lat: 43.8527753, lng: -79.3560834 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.7964306, lng: -79.294996 },
-Marker { lat: 43.8263784, lng: -79.2750135 },
-Marker { lat: 43.7751647, lng: -79.429325 },
-Marker { lat: 43.863141, lng: -79.326994 },
-Marker { lat: 43.7786606, lng: -79.4179499 },
-Marker { lat: 43.7242249, lng: -79.6165728 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.6271301, lng: -79.7420208 },
-Marker { lat: 43.8535994, lng: -79.464877 },
-Marker { lat: 43.875926, lng: -79.400308 },
-Marker { lat: 43.6778138, lng: -79.5233338 },
-Marker { lat: 43.4531835, lng: -79.7479672 },
-Marker { lat: 43.4650951, lng: -79.6487322 },
-Marker { lat: 43.3589266, lng: -79.8427286 },
-Marker { lat: 43.5814507, lng: -79.6036067 },
-Marker { lat: 43.9617408, lng: -79.2473084 },
-Marker { lat: 43.793542, lng: -79.664573 },
-Marker { lat: 43.7859191, lng: -79.3189248 },
-Marker { lat: 43.5416825, lng: -79.6917468 },
-Marker { lat: 43.572029, lng: -79.757581 },
-Marker { lat: 43.7070855, lng: -79.3977452 },
-Marker { lat: 43.6700845, lng: -79.3864005 },
-Marker { lat: 43.4821135, lng: -79.8491058 },
-Marker { lat: 43.7070855, lng: -79.3977452 },
-Marker { lat: 43.7258079, lng: -79.4806067 },
-Marker { lat: 43.77807, lng: -79.4158254 },
-Marker { lat: 43.9131528, lng: -78.9082985 },
-Marker { lat: 43.58504, lng: -79.646032 },
-Marker { lat: 43.8633646, lng: -79.4300888 },
-Marker { lat: 43.7525703, lng: -79.7378973 },
-Marker { lat: 43.646811, lng: -79.56668 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.9677686, lng: -79.2325097 },
-Marker { lat: 43.6869213, lng: -79.7452191 },
-Marker { lat: 43.464726, lng: -79.723928 },
-Marker { lat: 43.479721, lng: -79.727978 },
-Marker { lat: 43.648817, lng: -79.388006 },
-Marker { lat: 43.676052, lng: -79.810895 },
-Marker { lat: 43.5049101, lng: -79.8453117 },
-Marker { lat: 43.7177605, lng: -79.2357029 },
-Marker { lat: 43.66719, lng: -79.4130511 },
-Marker { lat: 43.816504, lng: -79.287461 },
-Marker { lat: 43.4889538, lng: -79.8699167 },
-Marker { lat: 43.5943476, lng: -79.664237 },
-Marker { lat: 43.6670409, lng: -79.3866462 },
-Marker { lat: 43.779746, lng: -79.4181433 },
-Marker { lat: 43.7066441, lng: -79.4271318 },
-Marker { lat: 43.660905, lng: -79.378806 },
-Marker { lat: 43.856447, lng: -79.3282132 },
-Marker { lat: 43.641596, lng: -79.423443 },
-Marker { lat: 43.6784555, lng: -79.4628991 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.807228, lng: -79.608513 },
-Marker { lat: 43.7694183, lng: -79.3686931 },
-Marker { lat: 43.665388, lng: -79.3880211 },
-Marker { lat: 43.6648039, lng: -79.3869669 },
-Marker { lat: 43.6648039, lng: -79.3869669 },
-Marker { lat: 43.6695417, lng: -79.3837883 },
-Marker { lat: 43.8085914, lng: -79.7335603 },
-Marker { lat: 43.7027883, lng: -79.8415704 },
-Marker { lat: 43.6772701, lng: -79.5068667 },
-Marker { lat: 43.8969807, lng: -79.4643117 },
-Marker { lat: 43.649261, lng: -79.375726 },
-Marker { lat: 43.6963597, lng: -79.7951628 },
-Marker { lat: 43.8154357, lng: -79.0817429 },
-Marker { lat: 43.7844008, lng: -79.288555 },
-Marker { lat: 43.6739425, lng: -79.7896573 },
-Marker { lat: 43.803687, lng: -79.730903 },
-Marker { lat: 43.7801939, lng: -79.6020845 },
-Marker { lat: 43.585748, lng: -79.648916 },
-Marker { lat: 43.624447, lng: -79.4899623 },
-Marker { lat: 43.539427, lng: -79.671352 },
-Marker { lat: 43.5864149, lng: -79.6479746 },
-Marker { lat: 43.6384158, lng: -79.582077 },
-Marker { lat: 43.8036515, lng: -79.4505507 },
-Marker { lat: 43.961709, lng: -78.910855 },
-Marker { lat: 43.8835053, lng: -79.3721782 },
-Marker { lat: 43.6334372, lng: -79.7815062 },
-Marker { lat: 43.8853343, lng: -79.0228945 },
-Marker { lat: 43.6851958, lng: -79.406631 },
-Marker { lat: 43.5889647, lng: -79.5346886 },
-Marker { lat: 43.8664986, lng: -79.3138921 },
-Marker { lat: 43.938691, lng: -79.4714774 },
-Marker { lat: 43.85334, lng: -79.468411 },
-Marker { lat: 43.3828159, lng: -79.7275546 },
-Marker { lat: 43.403482, lng: -79.798526 },
-Marker { lat: 43.733567, lng: -79.416081 },
-Marker { lat: 43.615549, lng: -79.645627 },
-Marker { lat: 43.539184, lng: -79.674289 },
-Marker { lat: 43.8807305, lng: -79.8586862 },
-Marker { lat: 43.899808, lng: -79.010902 },
-Marker { lat: 43.8163328, lng: -79.2223067 },
-Marker { lat: 43.8989026, lng: -79.2407699 },
-Marker { lat: 43.8959512, lng: -79.3014164 },
-Marker { lat: 43.963183, lng: -78.945472 },
-Marker { lat: 43.527228, lng: -79.854865 },
-Marker { lat: 43.9411203, lng: -79.4387293 },
-Marker { lat: 43.802027, lng: -79.4475386 },
-Marker { lat: 43.867291, lng: -79.04633 },
-Marker { lat: 43.4469446, lng: -79.7627738 },
-Marker { lat: 43.3492884, lng: -79.766177 },
-Marker { lat: 43.881051, lng: -79.0280918 },
-Marker { lat: 43.6022361, lng: -79.6498521 },
-Marker { lat: 43.5442093, lng: -79.7218194 },
-Marker { lat: 43.7810628, lng: -79.2485568 },
-Marker { lat: 43.4910572, lng: -79.8720089 },
-Marker { lat: 43.816463, lng: -79.3283933 },
-Marker { lat: 43.8306057, lng: -79.4789238 },
-Marker { lat: 43.5850975, lng: -79.6461541 },
-Marker { lat: 43.6611022, lng: -79.4361113 },
-Marker { lat: 43.5598955, lng: -79.7417334 },
-Marker { lat: 43.7420013, lng: -79.3615893 },
-Marker { lat: 43.8121752, lng: -79.3173672 },
-Marker { lat: 43.7539761, lng: -79.4448412 },
-Marker { lat: 43.6433727, lng: -79.4041107 },
-Marker { lat: 43.730701, lng: -79.465717 },
-Marker { lat: 43.640416, lng: -79.5630875 },
-Marker { lat: 43.622466, lng: -79.4824018 },
-Marker { lat: 43.879058, lng: -79.3936241 },
-Marker { lat: 43.708965, lng: -79.392985 },
-Marker { lat: 43.5569564, lng: -79.7620473 },
-Marker { lat: 43.7985883, lng: -79.6734913 },
-Marker { lat: 43.4919501, lng: -79.8485289 },
-Marker { lat: 43.5689451, lng: -79.5647828 },
-Marker { lat: 43.790843, lng: -79.5627052 },
-Marker { lat: 43.7273082, lng: -79.3635963 },
-Marker { lat: 43.684239, lng: -79.468408 },
-Marker { lat: 43.7427021, lng: -79.7626853 },
-Marker { lat: 43.899088, lng: -79.266191 },
-Marker { lat: 43.7157936, lng: -79.8406367 },
-Marker { lat: 43.7484647, lng: -79.3773326 },
-Marker { lat: 43.689247, lng: -79.843054 },
-Marker { lat: 43.8983826, lng: -79.2385614 },
-Marker { lat: 43.759198, lng: -79.28341 },
-Marker { lat: 43.8883417, lng: -79.4613768 },
-Marker { lat: 43.9516826, lng: -79.4396413 },
-Marker { lat: 43.4905363, lng: -79.7137149 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.8376, lng: -79.3405 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.5748, lng: -79.7214 },
-Marker { lat: 43.6040446, lng: -79.6523817 },
-Marker { lat: 43.7927944, lng: -79.5999348 },
-Marker { lat: 43.7574, lng: -79.8126 },
-Marker { lat: 43.6826194, lng: -79.391523 },
-Marker { lat: 43.6628495, lng: -79.3762687 },
-Marker { lat: 43.655028, lng: -79.418075 },
-Marker { lat: 43.7411752, lng: -79.3517979 },
-Marker { lat: 43.7925345, lng: -79.6002808 },
-Marker { lat: 43.463402, lng: -79.71856 },
-Marker { lat: 43.9280415, lng: -79.5206777 },
-Marker { lat: 43.8497349, lng: -79.5598207 },
-Marker { lat: 43.6400887, lng: -79.3939574 },
-Marker { lat: 43.6839995, lng: -79.4119636 },
-Marker { lat: 43.859698, lng: -79.0351219 },
-Marker { lat: 43.6302332, lng: -79.4762201 },
-Marker { lat: 43.581455, lng: -79.648078 },
-Marker { lat: 43.7121041, lng: -79.4604911 },
-Marker { lat: 43.6720296, lng: -79.3798098 },
-Marker { lat: 43.7410742, lng: -79.5373929 },
-Marker { lat: 43.92302, lng: -78.9513617 },
-Marker { lat: 43.71418, lng: -79.7416356 },
-Marker { lat: 43.5718108, lng: -79.7354552 },
-Marker { lat: 43.6989956, lng: -79.4472598 },
-Marker { lat: 43.8810864, lng: -79.052594 },
-Marker { lat: 43.7795461, lng: -79.2715982 },
-Marker { lat: 43.899075, lng: -78.935432 },
-Marker { lat: 43.8218614, lng: -79.0132992 },
-Marker { lat: 43.842348, lng: -79.5657199 },
-Marker { lat: 43.8936052, lng: -79.4563559 },
-Marker { lat: 43.84485, lng: -79.509822 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.912438, lng: -79.462263 },
-Marker { lat: 43.873693, lng: -79.062149 },
-Marker { lat: 43.5837216, lng: -79.5751113 },
-Marker { lat: 43.4854, lng: -79.6988 },
-Marker { lat: 43.3606606, lng: -79.7532716 },
-Marker { lat: 43.873623, lng: -79.4521193 },
-Marker { lat: 43.7448413, lng: -79.2146236 },
-Marker { lat: 43.8901743, lng: -79.2711734 },
-Marker { lat: 43.8846415, lng: -79.2795246 },
-Marker { lat: 43.6317833, lng: -79.4946972 },
-Marker { lat: 43.66572, lng: -79.386054 },
-Marker { lat: 43.8547742, lng: -79.3405972 },
-Marker { lat: 43.802145, lng: -79.4206681 },
-Marker { lat: 43.8152085, lng: -79.4470479 },
-Marker { lat: 43.8509173, lng: -79.5720767 },
-Marker { lat: 43.879944, lng: -79.4681185 },
-Marker { lat: 43.6740453, lng: -79.7614893 },
-Marker { lat: 43.7594123, lng: -79.6893057 },
-Marker { lat: 43.6932811, lng: -79.3273475 },
-Marker { lat: 43.7608609, lng: -79.6957299 },
-Marker { lat: 43.488937, lng: -79.864588 },
-Marker { lat: 43.717564, lng: -79.7264808 },
-Marker { lat: 43.649381, lng: -79.374532 },
-Marker { lat: 43.756947, lng: -79.4064994 },
-Marker { lat: 43.7460379, lng: -79.3991282 },
-Marker { lat: 43.6396262, lng: -79.4157955 },
-Marker { lat: 43.64787, lng: -79.391138 },
-Marker { lat: 43.67032, lng: -79.394433 },
-Marker { lat: 43.7506401, lng: -79.3837449 },
-Marker { lat: 43.6740324, lng: -79.3904727 },
-Marker { lat: 43.8499194, lng: -79.5649858 },
-Marker { lat: 43.640315, lng: -79.562295 },
-Marker { lat: 43.7396482, lng: -79.7733183 },
-Marker { lat: 43.7024135, lng: -79.8133843 },
-Marker { lat: 43.6508493, lng: -79.8021868 },
-Marker { lat: 43.680462, lng: -79.7593612 },
-Marker { lat: 43.7391234, lng: -79.7243367 },
-Marker { lat: 43.5379379, lng: -79.6468683 },
-Marker { lat: 43.756555, lng: -79.837396 },
-Marker { lat: 43.7292971, lng: -79.4905773 },
-Marker { lat: 43.9457853, lng: -79.4668869 },
-Marker { lat: 43.8907716, lng: -79.4428898 },
-Marker { lat: 43.8719194, lng: -79.3066319 },
-Marker { lat: 43.8393905, lng: -79.4577525 },
-Marker { lat: 43.8548328, lng: -79.4840717 },
-Marker { lat: 43.7175965, lng: -79.4494736 },
-Marker { lat: 43.9633683, lng: -79.2404162 },
-Marker { lat: 43.7951402, lng: -79.5841771 },
-Marker { lat: 43.5751766, lng: -79.5790979 },
-Marker { lat: 43.4802665, lng: -79.7261506 },
-Marker { lat: 43.6514205, lng: -79.8114896 },
-Marker { lat: 43.3757944, lng: -79.798879 },
-Marker { lat: 43.4254103, lng: -79.7355246 },
-Marker { lat: 43.8522079, lng: -79.427938 },
-Marker { lat: 43.7684033, lng: -79.1820615 },
-Marker { lat: 43.7839969, lng: -79.4160404 },
-Marker { lat: 43.8329287, lng: -79.2884565 },
-Marker { lat: 43.6006042, lng: -79.6388674 },
-Marker { lat: 43.7681761, lng: -79.4117756 },
-Marker { lat: 43.7485556, lng: -79.7525642 },
-Marker { lat: 43.8771346, lng: -79.3645673 },
-Marker { lat: 43.7949078, lng: -79.3139676 },
-Marker { lat: 43.665412, lng: -79.3871187 },
-Marker { lat: 43.7029299, lng: -79.2503152 },
-Marker { lat: 43.6986141, lng: -79.2731601 },
-Marker { lat: 43.714343, lng: -79.411659 },
-Marker { lat: 43.665388, lng: -79.3880211 },
-Marker { lat: 43.664188, lng: -79.380543 },
-Marker { lat: 43.6429141, lng: -79.3806469 },
-Marker { lat: 43.646944, lng: -79.392397 },
-Marker { lat: 43.7518004, lng: -79.1931398 },
-Marker { lat: 43.8275331, lng: -79.4254001 },
-Marker { lat: 43.8953052, lng: -79.4535677 },
-Marker { lat: 43.95062, lng: -79.455785 },
-Marker { lat: 43.8412959, lng: -79.3938341 },
-Marker { lat: 43.8523801, lng: -79.4147703 },
-Marker { lat: 43.802582, lng: -79.420565 },
-Marker { lat: 43.8794858, lng: -78.9035611 },
-Marker { lat: 43.76988, lng: -79.419991 },
-Marker { lat: 43.769846, lng: -79.420097 },
-Marker { lat: 43.6536011, lng: -79.3688254 },
-Marker { lat: 43.7092776, lng: -79.8217697 },
-Marker { lat: 43.7306147, lng: -79.4060587 },
-Marker { lat: 43.869188, lng: -79.053185 },
-Marker { lat: 43.7266293, lng: -79.8218776 },
-Marker { lat: 43.8270588, lng: -79.656495 },
-Marker { lat: 43.72866, lng: -79.523063 },
-Marker { lat: 43.624376, lng: -79.48554 },
-Marker { lat: 43.6852244, lng: -79.4601451 },
-Marker { lat: 43.7419021, lng: -79.2723504 },
-Marker { lat: 43.576824, lng: -79.7024934 },
-Marker { lat: 43.8494035, lng: -79.4337956 },
-Marker { lat: 43.788764, lng: -79.673036 },
-Marker { lat: 43.6923725, lng: -79.8205623 },
-Marker { lat: 43.5312137, lng: -79.7113219 },
-Marker { lat: 43.6612322, lng: -79.5555583 },
-Marker { lat: 43.7466256, lng: -79.7627146 },
-Marker { lat: 43.5421846, lng: -79.6269109 },
-Marker { lat: 43.7566544, lng: -79.4204548 },
-Marker { lat: 43.7947528, lng: -79.3748898 },
-Marker { lat: 43.7255458, lng: -79.2517641 },
-Marker { lat: 43.6891263, lng: -79.2745072 },
-Marker { lat: 43.7677262, lng: -79.3706232 },
-Marker { lat: 43.624083, lng: -79.4917823 },
-Marker { lat: 43.391031, lng: -79.783862 },
-Marker { lat: 43.3822161, lng: -79.740034 },
-Marker { lat: 43.7195729, lng: -79.2955413 },
-Marker { lat: 43.4990914, lng: -79.7066482 },
-Marker { lat: 43.6954626, lng: -79.5651811 },
-Marker { lat: 43.5728324, lng: -79.5654932 },
-Marker { lat: 43.8911637, lng: -79.2740915 },
-Marker { lat: 43.7839969, lng: -79.4160404 },
-Marker { lat: 43.7913353, lng: -79.3866546 },
-Marker { lat: 43.7519341, lng: -79.4389849 },
-Marker { lat: 43.7305312, lng: -79.251337 },
-Marker { lat: 43.7621296, lng: -79.3993738 },
-Marker { lat: 43.8395254, lng: -79.3920429 },
-Marker { lat: 43.9715083, lng: -79.4775213 },
-Marker { lat: 43.8502471, lng: -79.417431 },
-Marker { lat: 43.6239195, lng: -79.7257475 },
-Marker { lat: 43.7276063, lng: -79.5221164 },
-Marker { lat: 43.7654478, lng: -79.4141909 },
-Marker { lat: 43.6404932, lng: -79.3949157 },
-Marker { lat: 43.8042371, lng: -79.4497063 },
-Marker { lat: 43.8436901, lng: -79.4514242 },
-Marker { lat: 43.9585028, lng: -79.433902 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.893692, lng: -79.322627 },
-Marker { lat: 43.9403548, lng: -78.8882409 },
-Marker { lat: 43.75514, lng: -79.833201 },
-Marker { lat: 43.8492988, lng: -79.396558 },
-Marker { lat: 43.5400177, lng: -79.6006715 },
-Marker { lat: 43.7957434, lng: -79.7520742 },
-Marker { lat: 43.79014, lng: -79.423905 },
-Marker { lat: 43.8556947, lng: -79.300041 },
-Marker { lat: 43.983375, lng: -78.908867 },
-Marker { lat: 43.9074397, lng: -78.8826113 },
-Marker { lat: 43.7307782, lng: -79.4902255 },
-Marker { lat: 43.7163324, lng: -79.4418676 },
-Marker { lat: 43.4751482, lng: -79.7129872 },
-Marker { lat: 43.977555, lng: -79.462377 },
-Marker { lat: 43.5019894, lng: -79.8526964 },
-Marker { lat: 43.9175913, lng: -79.4695632 },
-Marker { lat: 43.5850975, lng: -79.6461541 },
-Marker { lat: 43.721887, lng: -79.334639 },
-Marker { lat: 43.3834033, lng: -79.7299149 },
-Marker { lat: 43.6474301, lng: -79.7567794 },
-Marker { lat: 43.6867713, lng: -79.4530624 },
-Marker { lat: 43.7794018, lng: -79.2205213 },
-Marker { lat: 43.767464, lng: -79.370763 },
-Marker { lat: 43.642313, lng: -79.424366 },
-Marker { lat: 43.795489, lng: -79.933931 },
-Marker { lat: 43.8940108, lng: -79.2848021 },
-Marker { lat: 43.666981, lng: -79.388889 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.9607742, lng: -78.9136596 },
-Marker { lat: 43.6537223, lng: -79.7943158 },
-Marker { lat: 43.5344205, lng: -79.8600686 },
-Marker { lat: 43.7084377, lng: -79.2641399 },
-Marker { lat: 43.675944, lng: -79.459984 },
-Marker { lat: 43.6671242, lng: -79.5162796 },
-Marker { lat: 43.6642374, lng: -79.3755383 },
-Marker { lat: 43.9505981, lng: -79.4230827 },
-Marker { lat: 43.8963738, lng: -79.0362081 },
-Marker { lat: 43.65517, lng: -79.915208 },
-Marker { lat: 43.641806, lng: -79.378981 },
-Marker { lat: 43.5832384, lng: -79.7274283 },
-Marker { lat: 43.647524, lng: -79.387335 },
-Marker { lat: 43.473702, lng: -79.742289 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.7676405, lng: -79.2828561 },
-Marker { lat: 43.7029246, lng: -79.7437753 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.8403126, lng: -79.3915204 },
-Marker { lat: 43.6460836, lng: -79.3650117 },
-Marker { lat: 43.7686168, lng: -79.3880662 },
-Marker { lat: 43.8823929, lng: -79.2232651 },
-Marker { lat: 43.770449, lng: -79.2115916 },
-Marker { lat: 43.5363749, lng: -79.8598455 },
-Marker { lat: 43.837415, lng: -79.391117 },
-Marker { lat: 43.3938878, lng: -79.8280365 },
-Marker { lat: 43.6398757, lng: -79.5525158 },
-Marker { lat: 43.7680556, lng: -79.4995224 },
-Marker { lat: 43.5905171, lng: -79.628811 },
-Marker { lat: 43.4671888, lng: -79.7125127 },
-Marker { lat: 43.625807, lng: -79.477924 },
-Marker { lat: 43.749978, lng: -79.840983 },
-Marker { lat: 43.8044039, lng: -79.5921063 },
-Marker { lat: 43.7960194, lng: -79.2911588 },
-Marker { lat: 43.6440617, lng: -79.3894386 },
-Marker { lat: 43.6952107, lng: -79.5508744 },
-Marker { lat: 43.778071, lng: -79.7140865 },
-Marker { lat: 43.7931914, lng: -79.3500044 },
-Marker { lat: 43.862017, lng: -79.4014194 },
-Marker { lat: 43.8124718, lng: -79.7337541 },
-Marker { lat: 43.6102912, lng: -79.7046361 },
-Marker { lat: 43.862662, lng: -79.644374 },
-Marker { lat: 43.7763249, lng: -79.4140326 },
-Marker { lat: 43.7217287, lng: -79.2560904 },
-Marker { lat: 43.675203, lng: -79.399574 },
-Marker { lat: 43.7316454, lng: -79.430181 },
-Marker { lat: 43.7117457, lng: -79.3892836 },
-Marker { lat: 43.812585, lng: -79.453084 },
-Marker { lat: 43.6720081, lng: -79.3879319 },
-Marker { lat: 43.887489, lng: -79.4987307 },
-Marker { lat: 43.6424403, lng: -79.3721255 },
-Marker { lat: 43.9034699, lng: -78.9612489 },
-Marker { lat: 43.7909895, lng: -79.4499094 },
-Marker { lat: 43.73757, lng: -79.4254754 },
-Marker { lat: 43.811936, lng: -79.455842 },
-Marker { lat: 43.4475761, lng: -79.7434318 },
-Marker { lat: 43.5920325, lng: -79.7070699 },
-Marker { lat: 43.9158745, lng: -79.4730575 },
-Marker { lat: 43.7073201, lng: -79.8239393 },
-Marker { lat: 43.6600471, lng: -79.3754477 },
-Marker { lat: 43.7124424, lng: -79.4265613 },
-Marker { lat: 43.6865805, lng: -79.3975526 },
-Marker { lat: 43.877593, lng: -79.233618 },
-Marker { lat: 43.8209675, lng: -79.3221572 },
-Marker { lat: 43.7236817, lng: -79.3854368 },
-Marker { lat: 43.8241469, lng: -79.4214265 },
-Marker { lat: 43.7485573, lng: -79.7844606 },
-Marker { lat: 43.686627, lng: -79.841684 },
-Marker { lat: 43.756556, lng: -79.408625 },
-Marker { lat: 43.621078, lng: -79.521523 },
-Marker { lat: 43.4326767, lng: -79.6927803 },
-Marker { lat: 43.5883573, lng: -79.6395201 },
-Marker { lat: 43.697149, lng: -79.4482492 },
-Marker { lat: 43.8935129, lng: -79.3761163 },
-Marker { lat: 43.581151, lng: -79.645693 },
-Marker { lat: 43.6702658, lng: -79.3943539 },
-Marker { lat: 43.8113804, lng: -79.2260421 },
-Marker { lat: 43.665753, lng: -79.385579 },
-Marker { lat: 43.6982743, lng: -79.8076261 },
-Marker { lat: 43.7653441, lng: -79.4126151 },
-Marker { lat: 43.6319202, lng: -79.4913421 },
-Marker { lat: 43.5081843, lng: -79.8553512 },
-Marker { lat: 43.7575885, lng: -79.7504381 },
-Marker { lat: 43.7858307, lng: -79.6663713 },
-Marker { lat: 43.7578384, lng: -79.581581 },
-Marker { lat: 43.4369552, lng: -79.6901138 },
-Marker { lat: 43.7781623, lng: -79.7194514 },
-Marker { lat: 43.7734808, lng: -79.2544987 },
-Marker { lat: 43.5949331, lng: -79.6351503 },
-Marker { lat: 43.8636105, lng: -79.4388462 },
-Marker { lat: 43.9641069, lng: -78.97017 },
-Marker { lat: 43.7136212, lng: -79.4079573 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.8592966, lng: -79.3554939 },
-Marker { lat: 43.850653, lng: -79.049782 },
-Marker { lat: 43.8902513, lng: -79.3326019 },
-Marker { lat: 43.7606271, lng: -79.7145895 },
-Marker { lat: 43.8573138, lng: -79.3296381 },
-Marker { lat: 43.8091676, lng: -79.455206 },
-Marker { lat: 43.685286, lng: -79.761492 },
-Marker { lat: 43.7544241, lng: -79.5101932 },
-Marker { lat: 43.8505281, lng: -79.0538837 },
-Marker { lat: 43.652757, lng: -79.366511 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.7652017, lng: -79.3832771 },
-Marker { lat: 43.638513, lng: -79.400897 },
-Marker { lat: 43.6406527, lng: -79.3957762 },
-Marker { lat: 43.829999, lng: -79.109403 },
-Marker { lat: 43.6700845, lng: -79.3864005 },
-Marker { lat: 43.6479143, lng: -79.4305512 },
-Marker { lat: 43.6238862, lng: -79.4792234 },
-Marker { lat: 43.7676405, lng: -79.2828561 },
-Marker { lat: 43.765639, lng: -79.4127414 },
-Marker { lat: 43.833122, lng: -79.531325 },
-Marker { lat: 43.5887481, lng: -79.6517696 },
-Marker { lat: 43.8582227, lng: -79.4336464 },
-Marker { lat: 43.656092, lng: -79.519717 },
-Marker { lat: 43.7680083, lng: -79.206352 },
-Marker { lat: 43.8287829, lng: -79.1228298 },
-Marker { lat: 43.8830681, lng: -79.2723628 },
-Marker { lat: 43.496674, lng: -79.707317 },
-Marker { lat: 43.7575874, lng: -79.326688 },
-Marker { lat: 43.8505281, lng: -79.0538837 },
-Marker { lat: 43.8318469, lng: -79.532176 },
-Marker { lat: 43.6647482, lng: -79.3324607 },
-Marker { lat: 43.7424087, lng: -79.3012429 },
-Marker { lat: 43.9034357, lng: -79.4643012 },
-Marker { lat: 43.4091464, lng: -79.7110254 },
-Marker { lat: 43.67032, lng: -79.394433 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.4544123, lng: -79.6550123 },
-Marker { lat: 43.7110634, lng: -79.2889948 },
-Marker { lat: 43.3969643, lng: -79.8114381 },
-Marker { lat: 43.7947424, lng: -79.6201098 },
-Marker { lat: 43.5558201, lng: -79.5780703 },
-Marker { lat: 43.7490152, lng: -79.406243 },
-Marker { lat: 43.7639534, lng: -79.4069857 },
-Marker { lat: 43.8567338, lng: -79.5038745 },
-Marker { lat: 43.7036269, lng: -79.2923982 },
-Marker { lat: 43.9313023, lng: -79.5300336 },
-Marker { lat: 43.8833711, lng: -79.461697 },
-Marker { lat: 43.7785806, lng: -79.2811718 },
-Marker { lat: 43.8829799, lng: -79.2779997 },
-Marker { lat: 43.784842, lng: -79.5938836 },
-Marker { lat: 43.7368051, lng: -79.4308942 },
-Marker { lat: 43.8574527, lng: -79.8506497 },
-Marker { lat: 43.86231, lng: -79.084741 },
-Marker { lat: 43.595443, lng: -79.7128167 },
-Marker { lat: 43.67862, lng: -79.390345 },
-Marker { lat: 43.9704741, lng: -79.5643181 },
-Marker { lat: 43.8959272, lng: -79.2371549 },
-Marker { lat: 43.6756198, lng: -79.806306 },
-Marker { lat: 43.711559, lng: -79.8224172 },
-Marker { lat: 43.8574348, lng: -79.3248166 },
-Marker { lat: 43.8234203, lng: -79.6107975 },
-Marker { lat: 43.7101719, lng: -79.4560217 },
-Marker { lat: 43.521972, lng: -79.838921 },
-Marker { lat: 43.8417326, lng: -79.0908077 },
-Marker { lat: 43.9747394, lng: -79.2355651 },
-Marker { lat: 43.5967736, lng: -79.7241716 },
-Marker { lat: 43.692773, lng: -79.4201127 },
-Marker { lat: 43.669301, lng: -79.384171 },
-Marker { lat: 43.661781, lng: -79.384218 },
-Marker { lat: 43.6383599, lng: -79.398657 },
-Marker { lat: 43.778476, lng: -79.450359 },
-Marker { lat: 43.6624074, lng: -79.3858983 },
-Marker { lat: 43.6695417, lng: -79.3837883 },
-Marker { lat: 43.90763, lng: -78.973962 },
-Marker { lat: 43.797121, lng: -79.14246 },
-Marker { lat: 43.7428085, lng: -79.3495661 },
-Marker { lat: 43.768501, lng: -79.381285 },
-Marker { lat: 43.853813, lng: -79.1084381 },
-Marker { lat: 43.6637914, lng: -79.3830261 },
-Marker { lat: 43.5342555, lng: -79.6291675 },
-Marker { lat: 43.689939, lng: -79.790151 },
-Marker { lat: 43.9605301, lng: -79.2518691 },
-Marker { lat: 43.6547114, lng: -79.3792073 },
-Marker { lat: 43.9707943, lng: -79.2722247 },
-Marker { lat: 43.7040059, lng: -79.3998499 },
-Marker { lat: 43.6958987, lng: -79.2785755 },
-Marker { lat: 43.62372, lng: -79.483703 },
-Marker { lat: 43.5787037, lng: -79.5705129 },
-Marker { lat: 43.8976755, lng: -78.8803451 },
-Marker { lat: 43.8923949, lng: -79.4963122 },
-Marker { lat: 43.6616166, lng: -79.3846797 },
-Marker { lat: 43.8517028, lng: -79.5654388 },
-Marker { lat: 43.7344419, lng: -79.4764623 },
-Marker { lat: 43.8890019, lng: -79.5142354 },
-Marker { lat: 43.8358024, lng: -79.4887048 },
-Marker { lat: 43.8366415, lng: -79.4765307 },
-Marker { lat: 43.848113, lng: -79.478862 },
-Marker { lat: 43.7692453, lng: -79.9216405 },
-Marker { lat: 43.8567204, lng: -79.5433182 },
-Marker { lat: 43.8109022, lng: -79.3161035 },
-Marker { lat: 43.8516438, lng: -79.5482603 },
-Marker { lat: 43.8118964, lng: -79.456238 },
-Marker { lat: 43.8305857, lng: -79.4790669 },
-Marker { lat: 43.8042371, lng: -79.4497063 },
-Marker { lat: 43.963094, lng: -78.905184 },
-Marker { lat: 43.656092, lng: -79.519717 },
-Marker { lat: 43.9265987, lng: -79.5078287 },
-Marker { lat: 43.760762, lng: -79.821738 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.793032, lng: -79.599426 },
-Marker { lat: 43.943448, lng: -78.890674 },
-Marker { lat: 43.8081274, lng: -79.3149766 },
-Marker { lat: 43.7603442, lng: -79.8220617 },
-Marker { lat: 43.8216745, lng: -79.3788001 },
-Marker { lat: 43.6899454, lng: -79.4033746 },
-Marker { lat: 43.9404802, lng: -79.4282711 },
-Marker { lat: 43.8907373, lng: -79.4591732 },
-Marker { lat: 43.792045, lng: -79.4640508 },
-Marker { lat: 43.7664283, lng: -79.4289788 },
-Marker { lat: 43.628152, lng: -79.7611206 },
-Marker { lat: 43.6667012, lng: -79.4120351 },
-Marker { lat: 43.7653441, lng: -79.4126151 },
-Marker { lat: 43.872007, lng: -79.736076 },
-Marker { lat: 43.6417386, lng: -79.4233157 },
-Marker { lat: 43.6298595, lng: -79.7108006 },
-Marker { lat: 43.9022349, lng: -79.4558445 },
-Marker { lat: 43.8086392, lng: -79.4125703 },
-Marker { lat: 43.7422943, lng: -79.4438507 },
-Marker { lat: 43.8922591, lng: -79.0241404 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.9004866, lng: -79.288139 },
-Marker { lat: 43.641806, lng: -79.378981 },
-Marker { lat: 43.665753, lng: -79.385579 },
-Marker { lat: 43.7042621, lng: -79.8534208 },
-Marker { lat: 43.7786878, lng: -79.430321 },
-Marker { lat: 43.6547114, lng: -79.3792073 },
-Marker { lat: 43.673817, lng: -79.7575811 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.5849599, lng: -79.6749579 },
-Marker { lat: 43.7181463, lng: -79.72599 },
-Marker { lat: 43.601736, lng: -79.6094174 },
-Marker { lat: 43.5990147, lng: -79.6255636 },
-Marker { lat: 43.7691576, lng: -79.3688621 },
-Marker { lat: 43.7265437, lng: -79.4706079 },
-Marker { lat: 43.8633835, lng: -79.4332961 },
-Marker { lat: 43.8636931, lng: -79.6179175 },
-Marker { lat: 43.478579, lng: -79.70921 },
-Marker { lat: 43.9609, lng: -78.9009 },
-Marker { lat: 43.949801, lng: -79.462762 },
-Marker { lat: 43.7932277, lng: -79.4127048 },
-Marker { lat: 43.9533804, lng: -78.9929012 },
-Marker { lat: 43.6940509, lng: -79.8347245 },
-Marker { lat: 43.6839, lng: -79.4824 },
-Marker { lat: 43.9240652, lng: -79.4153371 },
-Marker { lat: 43.3945503, lng: -79.8296494 },
-Marker { lat: 43.3933686, lng: -79.8281313 },
-Marker { lat: 43.3499142, lng: -79.8168743 },
-Marker { lat: 43.8651044, lng: -79.6212948 },
-Marker { lat: 43.9422165, lng: -79.4789287 },
-Marker { lat: 43.9269989, lng: -78.8804339 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.5274646, lng: -79.6708009 },
-Marker { lat: 43.6902386, lng: -79.822147 },
-Marker { lat: 43.4803368, lng: -79.7199784 },
-Marker { lat: 43.8428587, lng: -79.4411012 },
-Marker { lat: 43.883097, lng: -79.466912 },
-Marker { lat: 43.586139, lng: -79.6238631 },
-Marker { lat: 43.7506494, lng: -79.464186 },
-Marker { lat: 43.980412, lng: -79.446872 },
-Marker { lat: 43.4739326, lng: -79.6834478 },
-Marker { lat: 43.8524695, lng: -79.0787038 },
-Marker { lat: 43.7035497, lng: -79.2912606 },
-Marker { lat: 43.716387, lng: -79.3641303 },
-Marker { lat: 43.785532, lng: -79.212796 },
-Marker { lat: 43.670587, lng: -79.380609 },
-Marker { lat: 43.749112, lng: -79.2710394 },
-Marker { lat: 43.8081639, lng: -79.5553916 },
-Marker { lat: 43.8643379, lng: -79.5195378 },
-Marker { lat: 43.7750632, lng: -79.3457849 },
-Marker { lat: 43.9649979, lng: -79.3705946 },
-Marker { lat: 43.664579, lng: -79.410692 },
-Marker { lat: 43.434214, lng: -79.776184 },
-Marker { lat: 43.7330964, lng: -79.2264877 },
-Marker { lat: 43.7118324, lng: -79.2914046 },
-Marker { lat: 43.6850847, lng: -79.3341608 },
-Marker { lat: 43.6397347, lng: -79.3920867 },
-Marker { lat: 43.5925004, lng: -79.5267079 },
-Marker { lat: 43.7676173, lng: -79.5010688 },
-Marker { lat: 43.6363712, lng: -79.4027602 },
-Marker { lat: 43.7612374, lng: -79.408937 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.6400907, lng: -79.3786023 },
-Marker { lat: 43.6926459, lng: -79.4462154 },
-Marker { lat: 43.6489903, lng: -79.3870081 },
-Marker { lat: 43.4392296, lng: -79.755996 },
-Marker { lat: 43.8615497, lng: -79.4611572 },
-Marker { lat: 43.544741, lng: -79.927523 },
-Marker { lat: 43.7306775, lng: -79.4657154 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.6667966, lng: -79.3886683 },
-Marker { lat: 43.9351943, lng: -79.5504595 },
-Marker { lat: 43.7669808, lng: -79.4309076 },
-Marker { lat: 43.8886582, lng: -79.1592889 },
-Marker { lat: 43.6675198, lng: -79.4011128 },
-Marker { lat: 43.9641241, lng: -79.2305602 },
-Marker { lat: 43.8534631, lng: -79.5717405 },
-Marker { lat: 43.607292, lng: -79.895302 },
-Marker { lat: 43.661294, lng: -79.358138 },
-Marker { lat: 43.786191, lng: -79.21174 },
-Marker { lat: 43.8745191, lng: -79.4212141 },
-Marker { lat: 43.7923021, lng: -79.4490826 },
-Marker { lat: 43.7103699, lng: -79.7537018 },
-Marker { lat: 43.6922159, lng: -79.7578757 },
-Marker { lat: 43.8883291, lng: -79.2367508 },
-Marker { lat: 43.9439803, lng: -79.4620682 },
-Marker { lat: 43.968714, lng: -79.280113 },
-Marker { lat: 43.4974207, lng: -79.7424764 },
-Marker { lat: 43.8179183, lng: -79.319368 },
-Marker { lat: 43.8573, lng: -79.0125 },
-Marker { lat: 43.7566627, lng: -79.4547997 },
-Marker { lat: 43.8457702, lng: -79.3994987 },
-Marker { lat: 43.8606926, lng: -79.3965395 },
-Marker { lat: 43.8270616, lng: -79.3953945 },
-Marker { lat: 43.6720476, lng: -79.2999357 },
-Marker { lat: 43.9531958, lng: -79.4633433 },
-Marker { lat: 43.4873911, lng: -79.7059627 },
-Marker { lat: 43.8727776, lng: -79.2752252 },
-Marker { lat: 43.960306, lng: -78.9362303 },
-Marker { lat: 43.8985203, lng: -78.8847343 },
-Marker { lat: 43.887092, lng: -79.293886 },
-Marker { lat: 43.760341, lng: -79.325519 },
-Marker { lat: 43.8007128, lng: -79.3170366 },
-Marker { lat: 43.8719951, lng: -79.5267141 },
-Marker { lat: 43.8221323, lng: -79.6093906 },
-Marker { lat: 43.8559126, lng: -79.5414046 },
-Marker { lat: 43.5679451, lng: -79.5646593 },
-Marker { lat: 43.4835307, lng: -79.844804 },
-Marker { lat: 43.7784327, lng: -79.413539 },
-Marker { lat: 43.8764498, lng: -79.422309 },
-Marker { lat: 43.8046762, lng: -79.4230377 },
-Marker { lat: 43.8336461, lng: -79.3042692 },
-Marker { lat: 43.6423906, lng: -79.4247385 },
-Marker { lat: 43.768151, lng: -79.390791 },
-Marker { lat: 43.5816498, lng: -79.6480951 },
-Marker { lat: 43.85062, lng: -79.427416 },
-Marker { lat: 43.8874029, lng: -79.3346351 },
-Marker { lat: 43.6485613, lng: -79.4241096 },
-Marker { lat: 43.7778811, lng: -79.7036415 },
-Marker { lat: 43.6527316, lng: -79.4305034 },
-Marker { lat: 43.62372, lng: -79.483703 },
-Marker { lat: 43.791298, lng: -79.407038 },
-Marker { lat: 43.648678, lng: -79.397235 },
-Marker { lat: 43.6445412, lng: -79.3764773 },
-Marker { lat: 43.661725, lng: -79.384128 },
-Marker { lat: 43.763643, lng: -79.395882 },
-Marker { lat: 43.661214, lng: -79.382681 },
-Marker { lat: 43.6884863, lng: -79.3136348 },
-Marker { lat: 43.814514, lng: -79.125992 },
-Marker { lat: 43.8610302, lng: -79.5085464 },
-Marker { lat: 43.8318469, lng: -79.532176 },
-Marker { lat: 43.766764, lng: -79.385993 },
-Marker { lat: 43.818577, lng: -79.08624 },
-Marker { lat: 43.8099381, lng: -79.358997 },
-Marker { lat: 43.9255085, lng: -78.9267848 },
-Marker { lat: 43.7939829, lng: -79.4434454 },
-Marker { lat: 43.858252, lng: -79.433621 },
-Marker { lat: 43.884765, lng: -79.4210353 },
-Marker { lat: 43.5613699, lng: -79.7131936 },
-Marker { lat: 43.6539727, lng: -79.7791162 },
-Marker { lat: 43.8869239, lng: -79.242555 },
-Marker { lat: 43.4387945, lng: -79.6732763 },
-Marker { lat: 43.4993373, lng: -79.8831371 },
-Marker { lat: 43.686315, lng: -79.514191 },
-Marker { lat: 43.624305, lng: -79.488423 },
-Marker { lat: 43.6753924, lng: -79.4693025 },
-Marker { lat: 43.6774473, lng: -79.8136478 },
-Marker { lat: 43.6098317, lng: -79.659562 },
-Marker { lat: 43.5733634, lng: -79.6907616 },
-Marker { lat: 43.8630267, lng: -79.4323167 },
-Marker { lat: 43.7498015, lng: -79.3924442 },
-Marker { lat: 43.6893403, lng: -79.8489851 },
-Marker { lat: 43.7679629, lng: -79.3964689 },
-Marker { lat: 43.7817373, lng: -79.4143115 },
-Marker { lat: 43.768597, lng: -79.3896 },
-Marker { lat: 43.6649335, lng: -79.4174207 },
-Marker { lat: 43.6629934, lng: -79.3404389 },
-Marker { lat: 43.7751229, lng: -79.4138309 },
-Marker { lat: 43.7125376, lng: -79.3734249 },
-Marker { lat: 43.7563401, lng: -79.4056932 },
-Marker { lat: 43.9244074, lng: -79.467687 },
-Marker { lat: 43.688905, lng: -79.8508543 },
-Marker { lat: 43.780039, lng: -79.344324 },
-Marker { lat: 43.593602, lng: -79.6312311 },
-Marker { lat: 43.8603434, lng: -79.4324218 },
-Marker { lat: 43.8503461, lng: -79.4300813 },
-Marker { lat: 43.662961, lng: -79.349123 },
-Marker { lat: 43.7645339, lng: -79.3703253 },
-Marker { lat: 43.6429451, lng: -79.3805034 },
-Marker { lat: 43.8108853, lng: -79.221617 },
-Marker { lat: 43.8496836, lng: -79.3765109 },
-Marker { lat: 43.6914665, lng: -79.4005801 },
-Marker { lat: 43.897399, lng: -79.3123844 },
-Marker { lat: 43.6659675, lng: -79.3871076 },
-Marker { lat: 43.749969, lng: -79.406135 },
-Marker { lat: 43.7737399, lng: -79.2555526 },
-Marker { lat: 43.775123, lng: -79.345456 },
-Marker { lat: 43.625807, lng: -79.477924 },
-Marker { lat: 43.9002054, lng: -79.4403178 },
-Marker { lat: 43.773688, lng: -79.255422 },
-Marker { lat: 43.7512277, lng: -79.4641134 },
-Marker { lat: 43.7226665, lng: -79.2709227 },
-Marker { lat: 43.8617175, lng: -79.4355742 },
-Marker { lat: 43.8574348, lng: -79.3248166 },
-Marker { lat: 43.6460155, lng: -79.3940644 },
-Marker { lat: 43.5927159, lng: -79.6041477 },
-Marker { lat: 43.6351152, lng: -79.717727 },
-Marker { lat: 43.6417386, lng: -79.4233157 },
-Marker { lat: 43.960787, lng: -79.442412 },
-Marker { lat: 43.4189435, lng: -79.7742067 },
-Marker { lat: 43.7709677, lng: -79.3730181 },
-Marker { lat: 43.7754066, lng: -79.4295265 },
-Marker { lat: 43.8075508, lng: -79.1235335 },
-Marker { lat: 43.878289, lng: -79.2583967 },
-Marker { lat: 43.850094, lng: -79.323495 },
-Marker { lat: 43.621968, lng: -79.52092 },
-Marker { lat: 43.613229, lng: -79.659943 },
-Marker { lat: 43.779164, lng: -79.414515 },
-Marker { lat: 43.520078, lng: -79.620473 },
-Marker { lat: 43.7504178, lng: -79.5817375 },
-Marker { lat: 43.4030283, lng: -79.7170106 },
-Marker { lat: 43.5384146, lng: -79.6249765 },
-Marker { lat: 43.730455, lng: -79.729121 },
-Marker { lat: 43.584967, lng: -79.587183 },
-Marker { lat: 43.8966534, lng: -79.2703268 },
-Marker { lat: 43.675835, lng: -79.827952 },
-Marker { lat: 43.725403, lng: -79.698982 },
-Marker { lat: 43.7885851, lng: -79.4265789 },
-Marker { lat: 43.6527869, lng: -79.4688202 },
-Marker { lat: 43.891758, lng: -79.5191663 },
-Marker { lat: 43.862523, lng: -79.292638 },
-Marker { lat: 43.6424403, lng: -79.3721255 },
-Marker { lat: 43.618717, lng: -79.4855402 },
-Marker { lat: 43.480595, lng: -79.731421 },
-Marker { lat: 43.5319544, lng: -79.6269204 },
-Marker { lat: 43.640349, lng: -79.914759 },
-Marker { lat: 43.814732, lng: -79.4605717 },
-Marker { lat: 43.8218854, lng: -79.4243456 },
-Marker { lat: 43.7611969, lng: -79.383773 },
-Marker { lat: 43.8759276, lng: -78.9580626 },
-Marker { lat: 43.9568234, lng: -78.8969736 },
-Marker { lat: 43.6746538, lng: -79.3890069 },
-Marker { lat: 43.5743956, lng: -79.6876426 },
-Marker { lat: 43.8911125, lng: -79.2656609 },
-Marker { lat: 43.7686, lng: -79.412075 },
-Marker { lat: 43.8611973, lng: -79.3969425 },
-Marker { lat: 43.7086423, lng: -79.7582664 },
-Marker { lat: 43.7902978, lng: -79.4276874 },
-Marker { lat: 43.486386, lng: -79.733027 },
-Marker { lat: 43.8481941, lng: -79.2746771 },
-Marker { lat: 43.5587686, lng: -79.5820891 },
-Marker { lat: 43.851756, lng: -79.432591 },
-Marker { lat: 43.5211882, lng: -79.6201163 },
-Marker { lat: 43.8753846, lng: -79.3600939 },
-Marker { lat: 43.6980638, lng: -79.7876559 },
-Marker { lat: 43.8154729, lng: -79.4580946 },
-Marker { lat: 43.473702, lng: -79.742289 },
-Marker { lat: 43.7412272, lng: -79.5374166 },
-Marker { lat: 43.7087705, lng: -79.2924247 },
-Marker { lat: 43.7208042, lng: -79.7839006 },
-Marker { lat: 43.8528736, lng: -79.0571995 },
-Marker { lat: 43.958331, lng: -78.953064 },
-Marker { lat: 43.7572108, lng: -79.4221169 },
-Marker { lat: 43.5712691, lng: -79.5694847 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.6480403, lng: -79.4186404 },
-Marker { lat: 43.638987, lng: -79.531391 },
-Marker { lat: 43.7551247, lng: -79.8301219 },
-Marker { lat: 43.623406, lng: -79.481472 },
-Marker { lat: 43.5291142, lng: -79.6636794 },
-Marker { lat: 43.6665874, lng: -79.4460776 },
-Marker { lat: 43.7752161, lng: -79.4133461 },
-Marker { lat: 43.7307234, lng: -79.3276511 },
-Marker { lat: 43.6101691, lng: -79.4986065 },
-Marker { lat: 43.8100647, lng: -79.4487117 },
-Marker { lat: 43.9009639, lng: -79.461736 },
-Marker { lat: 43.625159, lng: -79.47872 },
-Marker { lat: 43.8415411, lng: -79.3928268 },
-Marker { lat: 43.7496021, lng: -79.2668943 },
-Marker { lat: 43.794242, lng: -79.524434 },
-Marker { lat: 43.694531, lng: -79.74986 },
-Marker { lat: 43.7842089, lng: -79.3337756 },
-Marker { lat: 43.886548, lng: -79.037424 },
-Marker { lat: 43.5395893, lng: -79.6297652 },
-Marker { lat: 43.937246, lng: -79.5202033 },
-Marker { lat: 43.698496, lng: -79.548124 },
-Marker { lat: 43.782451, lng: -79.175908 },
-Marker { lat: 43.8909873, lng: -79.2258973 },
-Marker { lat: 43.6398182, lng: -79.3836155 },
-Marker { lat: 43.7532386, lng: -79.4909284 },
-Marker { lat: 43.7624387, lng: -79.5670189 },
-Marker { lat: 43.6784181, lng: -79.7757596 },
-Marker { lat: 43.8436904, lng: -79.4428336 },
-Marker { lat: 43.9075604, lng: -78.9792908 },
-Marker { lat: 43.9114073, lng: -78.9225918 },
-Marker { lat: 43.7030009, lng: -79.2926536 },
-Marker { lat: 43.8747929, lng: -79.4133547 },
-Marker { lat: 43.8705017, lng: -79.324673 },
-Marker { lat: 43.73372, lng: -79.446151 },
-Marker { lat: 43.9645732, lng: -79.5875673 },
-Marker { lat: 43.753813, lng: -79.445853 },
-Marker { lat: 43.7812179, lng: -79.1735087 },
-Marker { lat: 43.8887296, lng: -79.4282765 },
-Marker { lat: 43.768151, lng: -79.390791 },
-Marker { lat: 43.8376638, lng: -79.3903703 },
-Marker { lat: 43.64787, lng: -79.391138 },
-Marker { lat: 43.7524468, lng: -79.7438979 },
-Marker { lat: 43.647627, lng: -79.390408 },
-Marker { lat: 43.6892811, lng: -79.4508791 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.779869, lng: -79.417504 },
-Marker { lat: 43.8517581, lng: -79.337025 },
-Marker { lat: 43.645404, lng: -79.401414 },
-Marker { lat: 43.766836, lng: -79.198163 },
-Marker { lat: 43.7751229, lng: -79.4138309 },
-Marker { lat: 43.791887, lng: -79.3535054 },
-Marker { lat: 43.6460155, lng: -79.3940644 },
-Marker { lat: 43.8027272, lng: -79.4110347 },
-Marker { lat: 43.852413, lng: -79.336585 },
-Marker { lat: 43.3682997, lng: -79.8592575 },
-Marker { lat: 43.9595382, lng: -78.8915145 },
-Marker { lat: 43.8064685, lng: -79.3738234 },
-Marker { lat: 43.8738137, lng: -79.4026916 },
-Marker { lat: 43.5976032, lng: -79.6210436 },
-Marker { lat: 43.8234886, lng: -79.2760294 },
-Marker { lat: 43.8978767, lng: -79.4426111 },
-Marker { lat: 43.6597031, lng: -79.4039817 },
-Marker { lat: 43.8486492, lng: -79.0688134 },
-Marker { lat: 43.6363831, lng: -79.7352478 },
-Marker { lat: 43.7728109, lng: -79.398128 },
-Marker { lat: 43.7820048, lng: -79.3220891 },
-Marker { lat: 43.7227214, lng: -79.7135484 },
-Marker { lat: 43.9349353, lng: -79.3797962 },
-Marker { lat: 43.6637546, lng: -79.5012643 },
-Marker { lat: 43.5627815, lng: -79.7060868 },
-Marker { lat: 43.6905799, lng: -79.5297734 },
-Marker { lat: 43.5097176, lng: -79.884918 },
-Marker { lat: 43.494968, lng: -79.701773 },
-Marker { lat: 43.7949916, lng: -79.1553338 },
-Marker { lat: 43.7496773, lng: -79.5661533 },
-Marker { lat: 43.419861, lng: -79.762377 },
-Marker { lat: 43.6733936, lng: -79.7267631 },
-Marker { lat: 43.6401441, lng: -79.4239168 },
-Marker { lat: 43.6620882, lng: -79.3856802 },
-Marker { lat: 43.906049, lng: -79.031418 },
-Marker { lat: 43.9736865, lng: -79.2354151 },
-Marker { lat: 43.9702672, lng: -79.2637063 },
-Marker { lat: 43.979601, lng: -79.360172 },
-Marker { lat: 43.4693018, lng: -79.7508604 },
-Marker { lat: 43.8474824, lng: -79.4304978 },
-Marker { lat: 43.8240601, lng: -79.2769069 },
-Marker { lat: 43.511045, lng: -79.8427812 },
-Marker { lat: 43.4706311, lng: -79.6476176 },
-Marker { lat: 43.8699539, lng: -78.9410646 },
-Marker { lat: 43.4506024, lng: -79.7338018 },
-Marker { lat: 43.6011244, lng: -79.5949481 },
-Marker { lat: 43.576244, lng: -79.877987 },
-Marker { lat: 43.8889393, lng: -79.4252324 },
-Marker { lat: 43.6050137, lng: -79.6708941 },
-Marker { lat: 43.433141, lng: -79.770423 },
-Marker { lat: 43.7158593, lng: -79.7617054 },
-Marker { lat: 43.8228271, lng: -79.3989259 },
-Marker { lat: 43.923974, lng: -78.9211729 },
-Marker { lat: 43.787379, lng: -79.4614381 },
-Marker { lat: 43.643103, lng: -79.403831 },
-Marker { lat: 43.638788, lng: -79.557133 },
-Marker { lat: 43.967435, lng: -78.9464482 },
-Marker { lat: 43.669616, lng: -79.362936 },
-Marker { lat: 43.7188144, lng: -79.758636 },
-Marker { lat: 43.5803772, lng: -79.5819982 },
-Marker { lat: 43.943115, lng: -79.425708 },
-Marker { lat: 43.9704216, lng: -79.2237158 },
-Marker { lat: 43.8647331, lng: -79.592525 },
-Marker { lat: 43.6720468, lng: -79.3900486 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.9759711, lng: -79.2587575 },
-Marker { lat: 43.6699665, lng: -79.3053023 },
-Marker { lat: 43.6468455, lng: -79.4028294 },
-Marker { lat: 43.761198, lng: -79.408952 },
-Marker { lat: 43.8376638, lng: -79.3903703 },
-Marker { lat: 43.3720526, lng: -79.7550548 },
-Marker { lat: 43.762714, lng: -79.346479 },
-Marker { lat: 43.558267, lng: -79.6122732 },
-Marker { lat: 43.554909, lng: -79.5807682 },
-Marker { lat: 43.7831107, lng: -79.4151692 },
-Marker { lat: 43.641806, lng: -79.378981 },
-Marker { lat: 43.7167448, lng: -79.7271985 },
-Marker { lat: 43.9014414, lng: -79.6551231 },
-Marker { lat: 43.760304, lng: -79.41162 },
-Marker { lat: 43.7388732, lng: -79.3443723 },
-Marker { lat: 43.778486, lng: -79.41333 },
-Marker { lat: 43.59554, lng: -79.633839 },
-Marker { lat: 43.6808474, lng: -79.3285478 },
-Marker { lat: 43.6556821, lng: -79.5168468 },
-Marker { lat: 43.6956176, lng: -79.5538552 },
-Marker { lat: 43.378349, lng: -79.796061 },
-Marker { lat: 43.8851913, lng: -79.2858471 },
-Marker { lat: 43.894628, lng: -78.895206 },
-Marker { lat: 43.7750632, lng: -79.3457849 },
-Marker { lat: 43.7897579, lng: -79.4698761 },
-Marker { lat: 43.3949227, lng: -79.8213628 },
-Marker { lat: 43.7733926, lng: -79.4130418 },
-Marker { lat: 43.66703, lng: -79.376386 },
-Marker { lat: 43.7395482, lng: -79.3011461 },
-Marker { lat: 43.7439728, lng: -79.559961 },
-Marker { lat: 43.707396, lng: -79.368413 },
-Marker { lat: 43.6834057, lng: -79.4051256 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.6469441, lng: -79.3923974 },
-Marker { lat: 43.7138387, lng: -79.2636181 },
-Marker { lat: 43.5944469, lng: -79.6351125 },
-Marker { lat: 43.791887, lng: -79.3535054 },
-Marker { lat: 43.4253516, lng: -79.7843774 },
-Marker { lat: 43.753507, lng: -79.421812 },
-Marker { lat: 43.7490751, lng: -79.4023737 },
-Marker { lat: 43.6787181, lng: -79.4142359 },
-Marker { lat: 43.8533239, lng: -79.4127103 },
-Marker { lat: 43.642311, lng: -79.38123 },
-Marker { lat: 43.6377596, lng: -79.3951472 },
-Marker { lat: 43.7933386, lng: -79.3262139 },
-Marker { lat: 43.657893, lng: -79.405207 },
-Marker { lat: 43.6342318, lng: -79.540934 },
-Marker { lat: 43.6712188, lng: -79.354131 },
-Marker { lat: 43.5595722, lng: -79.5747312 },
-Marker { lat: 43.5645699, lng: -79.574195 },
-Marker { lat: 43.7239329, lng: -79.5609709 },
-Marker { lat: 43.660137, lng: -79.385869 },
-Marker { lat: 43.6406292, lng: -79.4484244 },
-Marker { lat: 43.8728022, lng: -78.9414628 },
-Marker { lat: 43.8664336, lng: -79.4355835 },
-Marker { lat: 43.8519036, lng: -79.4956062 },
-Marker { lat: 43.8500482, lng: -79.4189922 },
-Marker { lat: 43.763024, lng: -79.420463 },
-Marker { lat: 43.6460991, lng: -79.3651362 },
-Marker { lat: 43.8664336, lng: -79.4355835 },
-Marker { lat: 43.7065639, lng: -79.7193434 },
-Marker { lat: 43.7788623, lng: -79.7363767 },
-Marker { lat: 43.858252, lng: -79.433621 },
-Marker { lat: 43.6250411, lng: -79.7618933 },
-Marker { lat: 43.5994595, lng: -79.6376254 },
-Marker { lat: 43.837415, lng: -79.391117 },
-Marker { lat: 43.851965, lng: -79.438503 },
-Marker { lat: 43.7587651, lng: -79.4084528 },
-Marker { lat: 43.9082416, lng: -78.9215725 },
-Marker { lat: 43.7711875, lng: -79.2871116 },
-Marker { lat: 43.6454146, lng: -79.387361 },
-Marker { lat: 43.6765411, lng: -79.4056079 },
-Marker { lat: 43.6401441, lng: -79.4239168 },
-Marker { lat: 43.6754371, lng: -79.400335 },
-Marker { lat: 43.7253596, lng: -79.7301871 },
-Marker { lat: 43.945014, lng: -78.917078 },
-Marker { lat: 43.6113238, lng: -79.6487546 },
-Marker { lat: 43.6402683, lng: -79.3960141 },
-Marker { lat: 43.622515, lng: -79.482441 },
-Marker { lat: 43.4138418, lng: -79.6965336 },
-Marker { lat: 43.7277024, lng: -79.5675984 },
-Marker { lat: 43.7663216, lng: -79.5033594 },
-Marker { lat: 43.665786, lng: -79.3855809 },
-Marker { lat: 43.7444002, lng: -79.4000937 },
-Marker { lat: 43.7731661, lng: -79.4154428 },
-Marker { lat: 43.492401, lng: -79.70929 },
-Marker { lat: 43.4832052, lng: -79.7317394 },
-Marker { lat: 43.9409238, lng: -78.8886319 },
-Marker { lat: 43.5230051, lng: -79.8905313 },
-Marker { lat: 43.8499537, lng: -79.5760725 },
-Marker { lat: 43.4168331, lng: -79.7081456 },
-Marker { lat: 43.8815358, lng: -79.2435834 },
-Marker { lat: 43.6227388, lng: -79.7140587 },
-Marker { lat: 43.5696562, lng: -79.6306328 },
-Marker { lat: 43.4085337, lng: -79.8198733 },
-Marker { lat: 43.82918, lng: -79.653006 },
-Marker { lat: 43.9585636, lng: -78.8988281 },
-Marker { lat: 43.820001, lng: -79.087098 },
-Marker { lat: 43.707083, lng: -79.397771 },
-Marker { lat: 43.6720081, lng: -79.3879319 },
-Marker { lat: 43.764029, lng: -79.407042 },
-Marker { lat: 43.672907, lng: -79.411122 },
-Marker { lat: 43.7199816, lng: -79.6277139 },
-Marker { lat: 43.7461933, lng: -79.7266562 },
-Marker { lat: 43.748215, lng: -79.781536 },
-Marker { lat: 43.6650324, lng: -79.447587 },
-Marker { lat: 43.747335, lng: -79.552426 },
-Marker { lat: 43.7181463, lng: -79.72599 },
-Marker { lat: 43.674976, lng: -79.5137411 },
-Marker { lat: 43.7446264, lng: -79.5733191 },
-Marker { lat: 43.7248102, lng: -79.4209473 },
-Marker { lat: 43.6478816, lng: -79.4224246 },
-Marker { lat: 43.772832, lng: -79.412978 },
-Marker { lat: 43.761894, lng: -79.571274 },
-Marker { lat: 43.751463, lng: -79.740283 },
-Marker { lat: 43.7123572, lng: -79.6939768 },
-Marker { lat: 43.3584533, lng: -79.8136703 },
-Marker { lat: 43.643291, lng: -79.404726 },
-Marker { lat: 43.606496, lng: -79.655651 },
-Marker { lat: 43.8031442, lng: -79.4133405 },
-Marker { lat: 43.6542637, lng: -79.4078083 },
-Marker { lat: 43.7917035, lng: -79.4093977 },
-Marker { lat: 43.7870959, lng: -79.4314198 },
-Marker { lat: 43.5650248, lng: -79.5815754 },
-Marker { lat: 43.812554, lng: -79.4544922 },
-Marker { lat: 43.8649249, lng: -79.3126365 },
-Marker { lat: 43.671731, lng: -79.389691 },
-Marker { lat: 43.6874164, lng: -79.3739842 },
-Marker { lat: 43.7569815, lng: -79.4044001 },
-Marker { lat: 43.6640993, lng: -79.3745788 },
-Marker { lat: 43.869972, lng: -79.3233936 },
-Marker { lat: 43.8344493, lng: -79.4726017 },
-Marker { lat: 43.9011218, lng: -79.2518848 },
-Marker { lat: 43.7909007, lng: -79.4131942 },
-Marker { lat: 43.7636916, lng: -79.5154799 },
-Marker { lat: 43.7868866, lng: -79.7488714 },
-Marker { lat: 43.6791456, lng: -79.4733623 },
-Marker { lat: 43.6595449, lng: -79.7370602 },
-Marker { lat: 43.6695417, lng: -79.3837883 },
-Marker { lat: 43.6406527, lng: -79.3957762 },
-Marker { lat: 43.631577, lng: -79.897849 },
-Marker { lat: 43.661725, lng: -79.384128 },
-Marker { lat: 43.6427513, lng: -79.3769186 },
-Marker { lat: 43.7727963, lng: -79.3453943 },
-Marker { lat: 43.674612, lng: -79.36692 },
-Marker { lat: 43.70378, lng: -79.409702 },
-Marker { lat: 43.7686951, lng: -79.3891863 },
-Marker { lat: 43.6942883, lng: -79.3170607 },
-Marker { lat: 43.7070826, lng: -79.3977712 },
-Marker { lat: 43.497263, lng: -79.613658 },
-Marker { lat: 43.5409851, lng: -79.6171006 },
-Marker { lat: 43.515867, lng: -79.754293 },
-Marker { lat: 43.798491, lng: -79.117095 },
-Marker { lat: 43.491852, lng: -79.718708 },
-Marker { lat: 43.4244716, lng: -79.6908325 },
-Marker { lat: 43.8675652, lng: -79.3196797 },
-Marker { lat: 43.6470829, lng: -79.5109309 },
-Marker { lat: 43.6517188, lng: -79.7824615 },
-Marker { lat: 43.7195087, lng: -79.3377222 },
-Marker { lat: 43.8869248, lng: -79.3573508 },
-Marker { lat: 43.7532386, lng: -79.4909284 },
-Marker { lat: 43.871436, lng: -79.017332 },
-Marker { lat: 43.642524, lng: -79.797178 },
-Marker { lat: 43.954921, lng: -79.4344552 },
-Marker { lat: 43.8497821, lng: -79.3288246 },
-Marker { lat: 43.935288, lng: -78.877872 },
-Marker { lat: 43.865158, lng: -79.220024 },
-Marker { lat: 43.6302332, lng: -79.4762201 },
-Marker { lat: 43.7124535, lng: -79.3785713 },
-Marker { lat: 43.654525, lng: -79.915981 },
-Marker { lat: 43.397218, lng: -79.8228728 },
-Marker { lat: 43.8042371, lng: -79.4497063 },
-Marker { lat: 43.862336, lng: -79.435736 },
-Marker { lat: 43.549497, lng: -79.716276 },
-Marker { lat: 43.877256, lng: -79.450193 },
-Marker { lat: 43.8204485, lng: -79.3764056 },
-Marker { lat: 43.8930603, lng: -79.4483962 },
-Marker { lat: 43.650945, lng: -79.398185 },
-Marker { lat: 43.6725539, lng: -79.389571 },
-Marker { lat: 43.6717424, lng: -79.3878028 },
-Marker { lat: 43.647226, lng: -79.3911 },
-Marker { lat: 43.555075, lng: -79.583315 },
-Marker { lat: 43.7854735, lng: -79.2058909 },
-Marker { lat: 43.736234, lng: -79.539159 },
-Marker { lat: 43.5529343, lng: -79.6487737 },
-Marker { lat: 43.8884824, lng: -79.3103491 },
-Marker { lat: 43.680889, lng: -79.328525 },
-Marker { lat: 43.6888757, lng: -79.7635882 },
-Marker { lat: 43.680633, lng: -79.3022343 },
-Marker { lat: 43.6807098, lng: -79.3894335 },
-Marker { lat: 43.5696324, lng: -79.5786668 },
-Marker { lat: 43.6761117, lng: -79.8245371 },
-Marker { lat: 43.8527461, lng: -79.3386798 },
-Marker { lat: 43.6573448, lng: -79.3599297 },
-Marker { lat: 43.77807, lng: -79.4158254 },
-Marker { lat: 43.707949, lng: -79.400632 },
-Marker { lat: 43.8850547, lng: -79.2802087 },
-Marker { lat: 43.656008, lng: -79.40872 },
-Marker { lat: 43.874974, lng: -79.733004 },
-Marker { lat: 43.6806972, lng: -79.5689852 },
-Marker { lat: 43.789653, lng: -79.253554 },
-Marker { lat: 43.67386, lng: -79.394042 },
-Marker { lat: 43.8800433, lng: -78.8958752 },
-Marker { lat: 43.9507499, lng: -78.8960947 },
-Marker { lat: 43.8992665, lng: -79.441747 },
-Marker { lat: 43.8889441, lng: -79.4432869 },
-Marker { lat: 43.4290194, lng: -79.6971669 },
-Marker { lat: 43.746564, lng: -79.404908 },
-Marker { lat: 43.4804055, lng: -79.6350468 },
-Marker { lat: 43.9582084, lng: -79.4648115 },
-Marker { lat: 43.7920378, lng: -79.4689109 },
-Marker { lat: 43.7667499, lng: -79.3928005 },
-Marker { lat: 43.4700365, lng: -79.6927295 },
-Marker { lat: 43.6104241, lng: -79.6604224 },
-Marker { lat: 43.6313736, lng: -79.4933266 },
-Marker { lat: 43.8948774, lng: -79.2140377 },
-Marker { lat: 43.7073389, lng: -79.394278 },
-Marker { lat: 43.434491, lng: -79.775259 },
-Marker { lat: 43.7606972, lng: -79.494205 },
-Marker { lat: 43.8517791, lng: -79.4368481 },
-Marker { lat: 43.9680514, lng: -79.2570698 },
-Marker { lat: 43.635423, lng: -79.871509 },
-Marker { lat: 43.6642383, lng: -79.7963174 },
-Marker { lat: 43.825265, lng: -79.4323354 },
-Marker { lat: 43.9503713, lng: -78.9468456 },
-Marker { lat: 43.5658494, lng: -79.5660168 },
-Marker { lat: 43.7446316, lng: -79.3446506 },
-Marker { lat: 43.708008, lng: -79.400785 },
-Marker { lat: 43.6686799, lng: -79.297004 },
-Marker { lat: 43.965793, lng: -79.327849 },
-Marker { lat: 43.7787489, lng: -79.4171422 },
-Marker { lat: 43.665588, lng: -79.383028 },
-Marker { lat: 43.7359262, lng: -79.5454763 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.5850905, lng: -79.6168483 },
-Marker { lat: 43.7585319, lng: -79.4122556 },
-Marker { lat: 43.971199, lng: -79.2698608 },
-Marker { lat: 43.7049603, lng: -79.2726095 },
-Marker { lat: 43.891514, lng: -79.027517 },
-Marker { lat: 43.6699083, lng: -79.3815245 },
-Marker { lat: 43.6712941, lng: -79.384925 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.6460836, lng: -79.3650117 },
-Marker { lat: 43.5680367, lng: -79.7425758 },
-Marker { lat: 43.7510438, lng: -79.2058019 },
-Marker { lat: 43.8658769, lng: -79.4875481 },
-Marker { lat: 43.8497541, lng: -79.4466197 },
-Marker { lat: 43.9169063, lng: -79.4711918 },
-Marker { lat: 43.8385031, lng: -79.2879329 },
-Marker { lat: 43.7698862, lng: -79.4135354 },
-Marker { lat: 43.9134672, lng: -79.4530437 },
-Marker { lat: 43.6676702, lng: -79.3864205 },
-Marker { lat: 43.7050736, lng: -79.3189432 },
-Marker { lat: 43.4598083, lng: -79.6727511 },
-Marker { lat: 43.6812513, lng: -79.4647683 },
-Marker { lat: 43.8921312, lng: -79.4528938 },
-Marker { lat: 43.8496775, lng: -79.6103766 },
-Marker { lat: 43.7531706, lng: -79.5109026 },
-Marker { lat: 43.8557947, lng: -79.4575266 },
-Marker { lat: 43.622625, lng: -79.5910922 },
-Marker { lat: 43.6377807, lng: -79.4379497 },
-Marker { lat: 43.8278556, lng: -79.3948726 },
-Marker { lat: 43.8234688, lng: -79.4504487 },
-Marker { lat: 43.6679848, lng: -79.3755762 },
-Marker { lat: 43.4107238, lng: -79.6953428 },
-Marker { lat: 43.7070578, lng: -79.3250801 },
-Marker { lat: 43.739248, lng: -79.7674484 },
-Marker { lat: 43.5278373, lng: -79.669754 },
-Marker { lat: 43.8611858, lng: -79.4408931 },
-Marker { lat: 43.8444917, lng: -79.4405206 },
-Marker { lat: 43.7952076, lng: -79.4020077 },
-Marker { lat: 43.8113804, lng: -79.2260421 },
-Marker { lat: 43.834401, lng: -79.003871 },
-Marker { lat: 43.9120609, lng: -79.2513548 },
-Marker { lat: 43.7364399, lng: -79.4266762 },
-Marker { lat: 43.7434662, lng: -79.4022897 },
-Marker { lat: 43.6387756, lng: -79.4163147 },
-Marker { lat: 43.6387756, lng: -79.4163147 },
-Marker { lat: 43.7581379, lng: -79.4095627 },
-Marker { lat: 43.7073389, lng: -79.394278 },
-Marker { lat: 43.957, lng: -78.9682239 },
-Marker { lat: 43.6451676, lng: -79.753333 },
-Marker { lat: 43.8202234, lng: -79.3235999 },
-Marker { lat: 43.952675, lng: -79.432564 },
-Marker { lat: 43.898864, lng: -79.2258017 },
-Marker { lat: 43.6068994, lng: -79.820796 },
-Marker { lat: 43.9055193, lng: -79.6491683 },
-Marker { lat: 43.7682673, lng: -79.2077877 },
-Marker { lat: 43.8754331, lng: -79.2447458 },
-Marker { lat: 43.7289081, lng: -79.4024371 },
-Marker { lat: 43.64589, lng: -79.73255 },
-Marker { lat: 43.7088581, lng: -79.7159762 },
-Marker { lat: 43.6063679, lng: -79.6554019 },
-Marker { lat: 43.6371667, lng: -79.404733 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.6368129, lng: -79.3975163 },
-Marker { lat: 43.7534621, lng: -79.416317 },
-Marker { lat: 43.3823386, lng: -79.7239662 },
-Marker { lat: 43.685286, lng: -79.761492 },
-Marker { lat: 43.687956, lng: -79.848052 },
-Marker { lat: 43.6140853, lng: -79.5749679 },
-Marker { lat: 43.4896526, lng: -79.7110609 },
-Marker { lat: 43.6818067, lng: -79.2825843 },
-Marker { lat: 43.5897092, lng: -79.625799 },
-Marker { lat: 43.6823901, lng: -79.2796015 },
-Marker { lat: 43.447443, lng: -79.686948 },
-Marker { lat: 43.8542665, lng: -79.2372676 },
-Marker { lat: 43.8834989, lng: -79.4523119 },
-Marker { lat: 43.8834312, lng: -79.4212689 },
-Marker { lat: 43.797244, lng: -79.3652338 },
-Marker { lat: 43.6342318, lng: -79.540934 },
-Marker { lat: 43.9435059, lng: -79.4629398 },
-Marker { lat: 43.640623, lng: -79.4378519 },
-Marker { lat: 43.6342271, lng: -79.6013926 },
-Marker { lat: 43.6152776, lng: -79.7370708 },
-Marker { lat: 43.623426, lng: -79.4789788 },
-Marker { lat: 43.4122719, lng: -79.7132397 },
-Marker { lat: 43.9300438, lng: -78.9285222 },
-Marker { lat: 43.63092, lng: -79.884987 },
-Marker { lat: 43.7691713, lng: -79.3433872 },
-Marker { lat: 43.7255161, lng: -79.3865601 },
-Marker { lat: 43.6388732, lng: -79.3986617 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.6412251, lng: -79.3809582 },
-Marker { lat: 43.848575, lng: -79.43254 },
-Marker { lat: 43.7604383, lng: -79.6912717 },
-Marker { lat: 43.6980142, lng: -79.5136834 },
-Marker { lat: 43.4992679, lng: -79.8659486 },
-Marker { lat: 43.7576978, lng: -79.7645338 },
-Marker { lat: 43.870621, lng: -79.757606 },
-Marker { lat: 43.7814276, lng: -79.7460724 },
-Marker { lat: 43.6842483, lng: -79.8091713 },
-Marker { lat: 43.9591047, lng: -79.440706 },
-Marker { lat: 43.9474715, lng: -79.4778024 },
-Marker { lat: 43.7986752, lng: -79.4048859 },
-Marker { lat: 43.8822922, lng: -79.087325 },
-Marker { lat: 43.6107139, lng: -79.6591896 },
-Marker { lat: 43.6852491, lng: -79.4247549 },
-Marker { lat: 43.6404932, lng: -79.3949157 },
-Marker { lat: 43.6852146, lng: -79.7618393 },
-Marker { lat: 43.646564, lng: -79.943122 },
-Marker { lat: 43.8885601, lng: -79.3265574 },
-Marker { lat: 43.4898858, lng: -79.8738655 },
-Marker { lat: 43.7739867, lng: -79.3595046 },
-Marker { lat: 43.6967419, lng: -79.4422624 },
-Marker { lat: 43.8026996, lng: -79.4084069 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.5991017, lng: -79.6820735 },
-Marker { lat: 43.6045411, lng: -79.666338 },
-Marker { lat: 43.8870397, lng: -79.4222753 },
-Marker { lat: 43.7686168, lng: -79.3880662 },
-Marker { lat: 43.3455205, lng: -79.8898836 },
-Marker { lat: 43.7339816, lng: -79.5623587 },
-Marker { lat: 43.811745, lng: -79.5993135 },
-Marker { lat: 43.9651459, lng: -78.8877804 },
-Marker { lat: 43.842195, lng: -79.394728 },
-Marker { lat: 43.665909, lng: -79.385172 },
-Marker { lat: 43.785895, lng: -79.29186 },
-Marker { lat: 43.6854748, lng: -79.8132336 },
-Marker { lat: 43.7657785, lng: -79.4100647 },
-Marker { lat: 43.8466723, lng: -79.4293837 },
-Marker { lat: 43.5410066, lng: -79.7277574 },
-Marker { lat: 43.8750281, lng: -79.4481817 },
-Marker { lat: 43.7919231, lng: -79.2388853 },
-Marker { lat: 43.7700944, lng: -79.7191734 },
-Marker { lat: 43.5571607, lng: -79.7071213 },
-Marker { lat: 43.783315, lng: -79.578869 },
-Marker { lat: 43.6713468, lng: -79.7305986 },
-Marker { lat: 43.711111, lng: -79.8136307 },
-Marker { lat: 43.9763642, lng: -79.4872393 },
-Marker { lat: 43.5436603, lng: -79.6462686 },
-Marker { lat: 43.5866025, lng: -79.6422661 },
-Marker { lat: 43.716298, lng: -79.832908 },
-Marker { lat: 43.6054342, lng: -79.5312891 },
-Marker { lat: 43.9175, lng: -79.799265 },
-Marker { lat: 43.8565168, lng: -79.541477 },
-Marker { lat: 43.958219, lng: -78.8955236 },
-Marker { lat: 43.5175, lng: -79.6423 },
-Marker { lat: 43.5796362, lng: -79.6053443 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9219045, lng: -79.5349221 },
-Marker { lat: 43.9435223, lng: -79.5426558 },
-Marker { lat: 43.624101, lng: -79.783075 },
-Marker { lat: 43.3672, lng: -79.9339 },
-Marker { lat: 43.7386, lng: -79.8437 },
-Marker { lat: 43.6692, lng: -79.53 },
-Marker { lat: 43.893036, lng: -79.6618456 },
-Marker { lat: 43.8117, lng: -79.6306 },
-Marker { lat: 43.865886, lng: -79.6190214 },
-Marker { lat: 43.6360952, lng: -79.9043551 },
-Marker { lat: 43.9246166, lng: -79.5472112 },
-Marker { lat: 43.5885932, lng: -79.6229642 },
-Marker { lat: 43.8376, lng: -79.3405 },
-Marker { lat: 43.661967, lng: -79.383964 },
-Marker { lat: 43.898658, lng: -79.2251235 },
-Marker { lat: 43.3943872, lng: -79.8292067 },
-Marker { lat: 43.814876, lng: -79.4223816 },
-Marker { lat: 43.9470849, lng: -79.4629504 },
-Marker { lat: 43.7087517, lng: -79.2462679 },
-Marker { lat: 43.7283791, lng: -79.2490187 },
-Marker { lat: 43.509869, lng: -79.857259 },
-Marker { lat: 43.780275, lng: -79.3145667 },
-Marker { lat: 43.6302332, lng: -79.4762201 },
-Marker { lat: 43.7566931, lng: -79.5713408 },
-Marker { lat: 43.562506, lng: -79.576562 },
-Marker { lat: 43.7886777, lng: -79.2217572 },
-Marker { lat: 43.708965, lng: -79.392985 },
-Marker { lat: 43.7165398, lng: -79.2845142 },
-Marker { lat: 43.795356, lng: -79.181504 },
-Marker { lat: 43.6426507, lng: -79.3773286 },
-Marker { lat: 43.9159535, lng: -78.9757182 },
-Marker { lat: 43.3939206, lng: -79.8296628 },
-Marker { lat: 43.6647, lng: -79.4352 },
-Marker { lat: 43.9748537, lng: -79.2721053 },
-Marker { lat: 43.8581, lng: -78.9318 },
-Marker { lat: 43.8927225, lng: -79.6611964 },
-Marker { lat: 43.4720976, lng: -79.7380337 },
-Marker { lat: 43.8117, lng: -79.6306 },
-Marker { lat: 43.658239, lng: -79.404774 },
-Marker { lat: 43.9609, lng: -78.9009 },
-Marker { lat: 43.8954, lng: -79.3222 },
-Marker { lat: 43.8573, lng: -79.0125 },
-Marker { lat: 43.9590676, lng: -78.903133 },
-Marker { lat: 43.7294, lng: -79.3373 },
-Marker { lat: 43.8117, lng: -79.6306 },
-Marker { lat: 43.3633984, lng: -79.7752536 },
-Marker { lat: 43.7740321, lng: -79.2580098 },
-Marker { lat: 43.3912476, lng: -79.8278123 },
-Marker { lat: 43.5748, lng: -79.7214 },
-Marker { lat: 43.6428502, lng: -79.3917462 },
-Marker { lat: 43.9000932, lng: -79.8832675 },
-Marker { lat: 43.6704872, lng: -79.3845738 },
-Marker { lat: 43.771429, lng: -79.365577 },
-Marker { lat: 43.7799579, lng: -79.3446599 },
-Marker { lat: 43.6637914, lng: -79.3830261 },
-Marker { lat: 43.6446545, lng: -79.4156518 },
-Marker { lat: 43.708965, lng: -79.392985 },
-Marker { lat: 43.6429141, lng: -79.3806469 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.8340372, lng: -79.4620169 },
-Marker { lat: 43.8844281, lng: -79.4442288 },
-Marker { lat: 43.8616497, lng: -79.5087576 },
-Marker { lat: 43.8700132, lng: -79.4758881 },
-Marker { lat: 43.7376729, lng: -79.2190043 },
-Marker { lat: 43.831892, lng: -79.644562 },
-Marker { lat: 43.62902, lng: -79.477811 },
-Marker { lat: 43.3788314, lng: -79.8094539 },
-Marker { lat: 43.9201416, lng: -79.4750348 },
-Marker { lat: 43.774532, lng: -79.688773 },
-Marker { lat: 43.77341, lng: -79.747018 },
-Marker { lat: 43.8829682, lng: -79.4674762 },
-Marker { lat: 43.3530986, lng: -79.7856423 },
-Marker { lat: 43.8284068, lng: -79.4075625 },
-Marker { lat: 43.9005837, lng: -79.2469012 },
-Marker { lat: 43.6393941, lng: -79.3997862 },
-Marker { lat: 43.7659803, lng: -79.4014463 },
-Marker { lat: 43.7689071, lng: -79.345767 },
-Marker { lat: 43.6594036, lng: -79.3611785 },
-Marker { lat: 43.5942303, lng: -79.6859991 },
-Marker { lat: 43.6547114, lng: -79.3792073 },
-Marker { lat: 43.7255458, lng: -79.2517641 },
-Marker { lat: 43.656806, lng: -79.375092 },
-Marker { lat: 43.6462325, lng: -79.3835846 },
-Marker { lat: 43.8051356, lng: -79.257493 },
-Marker { lat: 43.6702658, lng: -79.3943539 },
-Marker { lat: 43.863259, lng: -79.3161433 },
-Marker { lat: 43.7867552, lng: -79.217231 },
-Marker { lat: 43.867771, lng: -79.054617 },
-Marker { lat: 43.6704388, lng: -79.4220686 },
-Marker { lat: 43.6083247, lng: -79.6600429 },
-Marker { lat: 43.6630622, lng: -79.7811829 },
-Marker { lat: 43.7399096, lng: -79.7213105 },
-Marker { lat: 43.92663, lng: -79.5096404 },
-Marker { lat: 43.897049, lng: -79.3030439 },
-Marker { lat: 43.940519, lng: -78.884837 },
-Marker { lat: 43.7074786, lng: -79.8163889 },
-Marker { lat: 43.6830051, lng: -79.525957 },
-Marker { lat: 43.9112476, lng: -79.6526676 },
-Marker { lat: 43.656359, lng: -79.357277 },
-Marker { lat: 43.846104, lng: -79.013541 },
-Marker { lat: 43.816197, lng: -79.145249 },
-Marker { lat: 43.8875222, lng: -79.4567741 },
-Marker { lat: 43.6566436, lng: -79.3547917 },
-Marker { lat: 43.7312599, lng: -79.3731208 },
-Marker { lat: 43.8051997, lng: -79.3970691 },
-Marker { lat: 43.6469073, lng: -79.3709274 },
-Marker { lat: 43.842075, lng: -79.424278 },
-Marker { lat: 43.6417034, lng: -79.592157 },
-Marker { lat: 43.7270016, lng: -79.8218279 },
-Marker { lat: 43.6249874, lng: -79.6073628 },
-Marker { lat: 43.8401337, lng: -79.468557 },
-Marker { lat: 43.685286, lng: -79.761492 },
-Marker { lat: 43.9763923, lng: -79.2577411 },
-Marker { lat: 43.8508663, lng: -79.4283197 },
-Marker { lat: 43.8977687, lng: -79.2566536 },
-Marker { lat: 43.6095153, lng: -79.6102789 },
-Marker { lat: 43.4903974, lng: -79.6872711 },
-Marker { lat: 43.637279, lng: -79.402978 },
-Marker { lat: 43.9003576, lng: -79.2572755 },
-Marker { lat: 43.3756181, lng: -79.7377875 },
-Marker { lat: 43.8955768, lng: -79.4536817 },
-Marker { lat: 43.9559477, lng: -79.4708513 },
-Marker { lat: 43.645939, lng: -79.902034 },
-Marker { lat: 43.8889579, lng: -79.3102321 },
-Marker { lat: 43.779746, lng: -79.4181433 },
-Marker { lat: 43.5229548, lng: -79.6530311 },
-Marker { lat: 43.943448, lng: -78.890674 },
-Marker { lat: 43.8153218, lng: -79.2630549 },
-Marker { lat: 43.881761, lng: -79.730803 },
-Marker { lat: 43.6036032, lng: -79.5331303 },
-Marker { lat: 43.694531, lng: -79.74986 },
-Marker { lat: 43.8688, lng: -79.0336 },
-Marker { lat: 43.5896218, lng: -79.6302402 },
-Marker { lat: 43.8784439, lng: -79.3468439 },
-Marker { lat: 43.877888, lng: -79.2362167 },
-Marker { lat: 43.761461, lng: -79.409802 },
-Marker { lat: 43.7698862, lng: -79.4135354 },
-Marker { lat: 43.7489421, lng: -79.600491 },
-Marker { lat: 43.7637774, lng: -79.8307099 },
-Marker { lat: 43.8272251, lng: -79.6415633 },
-Marker { lat: 43.8986691, lng: -79.4249799 },
-Marker { lat: 43.77416, lng: -79.189538 },
-Marker { lat: 43.713225, lng: -79.4383228 },
-Marker { lat: 43.686709, lng: -79.399258 },
-Marker { lat: 43.9323017, lng: -79.4791329 },
-Marker { lat: 43.795094, lng: -79.681998 },
-Marker { lat: 43.774818, lng: -79.3356934 },
-Marker { lat: 43.7211631, lng: -79.8001308 },
-Marker { lat: 43.7914648, lng: -79.4247652 },
-Marker { lat: 43.7395147, lng: -79.4349468 },
-Marker { lat: 43.8860927, lng: -79.4476214 },
-Marker { lat: 43.8470596, lng: -79.457073 },
-Marker { lat: 43.7956305, lng: -79.4392177 },
-Marker { lat: 43.9446789, lng: -79.4743824 },
-Marker { lat: 43.7872283, lng: -79.1946802 },
-Marker { lat: 43.4047181, lng: -79.8242696 },
-Marker { lat: 43.6201698, lng: -79.4833611 },
-Marker { lat: 43.7703396, lng: -79.703017 },
-Marker { lat: 43.684467, lng: -79.7679405 },
-Marker { lat: 43.8146689, lng: -79.5734157 },
-Marker { lat: 43.5062199, lng: -79.6427766 },
-Marker { lat: 43.5350934, lng: -79.7055681 },
-Marker { lat: 43.9760691, lng: -79.2589597 },
-Marker { lat: 43.5967029, lng: -79.5344793 },
-Marker { lat: 43.891482, lng: -79.457138 },
-Marker { lat: 43.6411298, lng: -79.5284464 },
-Marker { lat: 43.7504482, lng: -79.770012 },
-Marker { lat: 43.9645387, lng: -79.2488277 },
-Marker { lat: 43.628159, lng: -79.898497 },
-Marker { lat: 43.7844981, lng: -79.2964658 },
-Marker { lat: 43.7036226, lng: -79.7685535 },
-Marker { lat: 43.8206416, lng: -79.5581339 },
-Marker { lat: 43.8997958, lng: -79.4419535 },
-Marker { lat: 43.7718089, lng: -79.373956 },
-Marker { lat: 43.7191005, lng: -79.3022627 },
-Marker { lat: 43.683431, lng: -79.348767 },
-Marker { lat: 43.7043, lng: -79.9029 },
-Marker { lat: 43.7318575, lng: -79.7740451 },
-Marker { lat: 43.65221, lng: -79.370799 },
-Marker { lat: 43.6421222, lng: -79.4001609 },
-Marker { lat: 43.7681094, lng: -79.4405388 },
-Marker { lat: 43.782532, lng: -79.455287 },
-Marker { lat: 43.679292, lng: -79.4461077 },
-Marker { lat: 43.8121488, lng: -79.4569092 },
-Marker { lat: 43.7487288, lng: -79.4329663 },
-Marker { lat: 43.6695417, lng: -79.3837883 },
-Marker { lat: 43.8732842, lng: -79.4530934 },
-Marker { lat: 43.647226, lng: -79.3911 },
-Marker { lat: 43.921988, lng: -79.519422 },
-Marker { lat: 43.7672502, lng: -79.4384451 },
-Marker { lat: 43.559866, lng: -79.7009666 },
-Marker { lat: 43.478579, lng: -79.70921 },
-Marker { lat: 43.7448893, lng: -79.3980004 },
-Marker { lat: 43.7937977, lng: -79.2350364 },
-Marker { lat: 43.9647212, lng: -79.4451956 },
-Marker { lat: 43.802582, lng: -79.420565 },
-Marker { lat: 43.659603, lng: -79.384784 },
-Marker { lat: 43.6993951, lng: -79.7548379 },
-Marker { lat: 43.909998, lng: -79.4331786 },
-Marker { lat: 43.8667026, lng: -79.2203482 },
-Marker { lat: 43.6653017, lng: -79.7963055 },
-Marker { lat: 43.7014439, lng: -79.289252 },
-Marker { lat: 43.908555, lng: -78.979307 },
-Marker { lat: 43.7747204, lng: -79.4343092 },
-Marker { lat: 43.3435608, lng: -79.7918215 },
-Marker { lat: 43.875089, lng: -79.718707 },
-Marker { lat: 43.7750632, lng: -79.3457849 },
-Marker { lat: 43.753829, lng: -79.7364243 },
-Marker { lat: 43.8817107, lng: -79.4161718 },
-Marker { lat: 43.6390951, lng: -79.5351457 },
-Marker { lat: 43.640285, lng: -79.559817 },
-Marker { lat: 43.8396291, lng: -79.3910537 },
-Marker { lat: 43.647075, lng: -79.50782 },
-Marker { lat: 43.536776, lng: -79.601921 },
-Marker { lat: 43.5442651, lng: -79.5994485 },
-Marker { lat: 43.846555, lng: -79.5250477 },
-Marker { lat: 43.511767, lng: -79.844183 },
-Marker { lat: 43.6676702, lng: -79.3864205 },
-Marker { lat: 43.6191999, lng: -79.4830575 },
-Marker { lat: 43.6796195, lng: -79.7802695 },
-Marker { lat: 43.758071, lng: -79.403165 },
-Marker { lat: 43.8263134, lng: -79.6470566 },
-Marker { lat: 43.7302883, lng: -79.6035826 },
-Marker { lat: 43.8431887, lng: -79.0869897 },
-Marker { lat: 43.6611627, lng: -79.4529506 },
-Marker { lat: 43.5077126, lng: -79.8804733 },
-Marker { lat: 43.80564, lng: -79.712861 },
-Marker { lat: 43.668542, lng: -79.397174 },
-Marker { lat: 43.659069, lng: -79.367188 },
-Marker { lat: 43.8151101, lng: -79.1037059 },
-Marker { lat: 43.5960512, lng: -79.5343077 },
-Marker { lat: 43.6673161, lng: -79.3154417 },
-Marker { lat: 43.5805008, lng: -79.6548227 },
-Marker { lat: 43.546571, lng: -79.7186691 },
-Marker { lat: 43.49771, lng: -79.887476 },
-Marker { lat: 43.6222485, lng: -79.592394 },
-Marker { lat: 43.6223725, lng: -79.6539939 },
-Marker { lat: 43.6498372, lng: -79.4445731 },
-Marker { lat: 43.950761, lng: -79.874882 },
-Marker { lat: 43.6456519, lng: -79.5139868 },
-Marker { lat: 43.834078, lng: -79.144601 },
-Marker { lat: 43.4003059, lng: -79.8206956 },
-Marker { lat: 43.6374676, lng: -79.4184499 },
-Marker { lat: 43.652588, lng: -79.3676913 },
-Marker { lat: 43.7856596, lng: -79.4202513 },
-Marker { lat: 43.6525884, lng: -79.3637912 },
-Marker { lat: 43.6490923, lng: -79.3885042 },
-Marker { lat: 43.62678, lng: -79.650311 },
-Marker { lat: 43.6710574, lng: -79.8112399 },
-Marker { lat: 43.656574, lng: -79.3683276 },
-Marker { lat: 43.6632069, lng: -79.5841018 },
-Marker { lat: 43.5709343, lng: -79.75942 },
-Marker { lat: 43.4277485, lng: -79.7647268 },
-Marker { lat: 43.8067321, lng: -79.7267111 },
-Marker { lat: 43.6120826, lng: -79.5418484 },
-Marker { lat: 43.8007128, lng: -79.3170366 },
-Marker { lat: 43.9188358, lng: -78.9223224 },
-Marker { lat: 43.8984529, lng: -79.0223244 },
-Marker { lat: 43.7146015, lng: -79.354391 },
-Marker { lat: 43.729322, lng: -79.2529134 },
-Marker { lat: 43.9090624, lng: -78.9131181 },
-Marker { lat: 43.447167, lng: -79.687053 },
-Marker { lat: 43.6765756, lng: -79.8095196 },
-Marker { lat: 43.3923239, lng: -79.8297819 },
-Marker { lat: 43.8659, lng: -79.4342 },
-Marker { lat: 43.6501, lng: -79.9175 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.7901984, lng: -79.6009603 },
-Marker { lat: 43.8117, lng: -79.6306 },
-Marker { lat: 43.88, lng: -79.43 },
-Marker { lat: 43.9318, lng: -79.411 },
-Marker { lat: 43.3942593, lng: -79.8296393 },
-Marker { lat: 43.8575, lng: -79.0534 },
-Marker { lat: 43.6334086, lng: -79.7970504 },
-Marker { lat: 43.8793, lng: -79.497 },
-Marker { lat: 43.8141951, lng: -79.7242259 },
-Marker { lat: 43.6514942, lng: -79.7696292 },
-Marker { lat: 43.676273, lng: -79.338363 },
-Marker { lat: 43.6989613, lng: -79.4244115 },
-Marker { lat: 43.4022325, lng: -79.788762 },
-Marker { lat: 43.5563609, lng: -79.700108 },
-Marker { lat: 43.8559576, lng: -79.0818026 },
-Marker { lat: 43.6839, lng: -79.4824 },
-Marker { lat: 43.714455, lng: -79.454411 },
-Marker { lat: 43.6789739, lng: -79.729878 },
-Marker { lat: 43.571114, lng: -79.566798 },
-Marker { lat: 43.4937942, lng: -79.700813 },
-Marker { lat: 43.5627912, lng: -79.7256475 },
-Marker { lat: 43.5246987, lng: -79.6987852 },
-Marker { lat: 43.9694108, lng: -79.4875074 },
-Marker { lat: 43.8518786, lng: -79.3273662 },
-Marker { lat: 43.6497827, lng: -79.3804402 },
-Marker { lat: 43.7994134, lng: -79.5522788 },
-Marker { lat: 43.8645025, lng: -79.5679757 },
-Marker { lat: 43.7776863, lng: -79.4270995 },
-Marker { lat: 43.8205795, lng: -79.287934 },
-Marker { lat: 43.779725, lng: -79.414445 },
-Marker { lat: 43.4767612, lng: -79.6338277 },
-Marker { lat: 43.782553, lng: -79.6033834 },
-Marker { lat: 43.7533079, lng: -79.7389241 },
-Marker { lat: 43.7739867, lng: -79.3595046 },
-Marker { lat: 43.8176, lng: -79.4921 },
-Marker { lat: 43.6451081, lng: -79.757374 },
-Marker { lat: 43.6238516, lng: -79.878298 },
-Marker { lat: 43.7610496, lng: -79.8319554 },
-Marker { lat: 43.8355352, lng: -79.6526618 },
-Marker { lat: 43.8812, lng: -79.1253 },
-Marker { lat: 43.9139788, lng: -78.9759081 },
-Marker { lat: 43.4922064, lng: -79.8464476 },
-Marker { lat: 43.665588, lng: -79.383028 },
-Marker { lat: 43.8117, lng: -79.6306 },
-Marker { lat: 43.3918628, lng: -79.8298439 },
-Marker { lat: 43.836224, lng: -79.2782681 },
-Marker { lat: 43.6241341, lng: -79.4858146 },
-Marker { lat: 43.7474, lng: -79.5903 },
-Marker { lat: 43.7622868, lng: -79.7840826 },
-Marker { lat: 43.6904837, lng: -79.8342389 },
-Marker { lat: 43.8482725, lng: -79.4498615 },
-Marker { lat: 43.803238, lng: -79.4153963 },
-Marker { lat: 43.7064611, lng: -79.7536934 },
-Marker { lat: 43.8589797, lng: -79.5040618 },
-Marker { lat: 43.5816498, lng: -79.6480951 },
-Marker { lat: 43.3995859, lng: -79.7038426 },
-Marker { lat: 43.7676128, lng: -79.7546178 },
-Marker { lat: 43.5336328, lng: -79.6256087 },
-Marker { lat: 43.6016906, lng: -79.6473268 },
-Marker { lat: 43.599726, lng: -79.638381 },
-Marker { lat: 43.7604326, lng: -79.6902569 },
-Marker { lat: 43.8896735, lng: -79.4630795 },
-Marker { lat: 43.7675016, lng: -79.2877702 },
-Marker { lat: 43.5656934, lng: -79.7505529 },
-Marker { lat: 43.8521806, lng: -79.3373192 },
-Marker { lat: 43.7881889, lng: -79.7486126 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.9089031, lng: -79.3786047 },
-Marker { lat: 43.9318, lng: -79.411 },
-Marker { lat: 43.4333511, lng: -79.6995725 },
-Marker { lat: 43.4555564, lng: -79.6772657 },
-Marker { lat: 43.7083045, lng: -79.4525829 },
-Marker { lat: 43.606015, lng: -79.654859 },
-Marker { lat: 43.5592261, lng: -79.753395 },
-Marker { lat: 43.3563341, lng: -79.7648958 },
-Marker { lat: 43.5583988, lng: -79.6001052 },
-Marker { lat: 43.691772, lng: -79.5270004 },
-Marker { lat: 43.8915156, lng: -79.8985097 },
-Marker { lat: 43.678247, lng: -79.729876 },
-Marker { lat: 43.6035205, lng: -79.7229745 },
-Marker { lat: 43.6600002, lng: -79.4517988 },
-Marker { lat: 43.811668, lng: -79.726536 },
-Marker { lat: 43.6719565, lng: -79.7873183 },
-Marker { lat: 43.7070826, lng: -79.3977712 },
-Marker { lat: 43.780234, lng: -79.419673 },
-Marker { lat: 43.6468455, lng: -79.4028294 },
-Marker { lat: 43.594797, lng: -79.534494 },
-Marker { lat: 43.643012, lng: -79.406201 },
-Marker { lat: 43.6496742, lng: -79.3981713 },
-Marker { lat: 43.7323343, lng: -79.420748 },
-Marker { lat: 43.6620369, lng: -79.3865221 },
-Marker { lat: 43.866964, lng: -79.063429 },
-Marker { lat: 43.8187589, lng: -79.1300632 },
-Marker { lat: 43.8997402, lng: -79.2931977 },
-Marker { lat: 43.6637514, lng: -79.7802816 },
-Marker { lat: 43.8007128, lng: -79.3170366 },
-Marker { lat: 43.650779, lng: -79.383519 },
-Marker { lat: 43.7444159, lng: -79.3910667 },
-Marker { lat: 43.6795665, lng: -79.3995161 },
-Marker { lat: 43.3776739, lng: -79.8042364 },
-Marker { lat: 43.8617175, lng: -79.4355742 },
-Marker { lat: 43.6318518, lng: -79.6036581 },
-Marker { lat: 43.6598992, lng: -79.4817372 },
-Marker { lat: 43.5700533, lng: -79.6309451 },
-Marker { lat: 43.9387168, lng: -79.4680269 },
-Marker { lat: 43.3938431, lng: -79.7117345 },
-Marker { lat: 43.79622, lng: -79.142447 },
-Marker { lat: 43.5896117, lng: -79.6523764 },
-Marker { lat: 43.650405, lng: -79.392168 },
-Marker { lat: 43.5821801, lng: -79.7711336 },
-Marker { lat: 43.8819503, lng: -79.0495909 },
-Marker { lat: 43.7564719, lng: -79.7347764 },
-Marker { lat: 43.695303, lng: -79.447018 },
-Marker { lat: 43.9339901, lng: -79.4715628 },
-Marker { lat: 43.7782517, lng: -79.4347568 },
-Marker { lat: 43.8898713, lng: -79.406379 },
-Marker { lat: 43.7694392, lng: -79.4160861 },
-Marker { lat: 43.658493, lng: -79.384854 },
-Marker { lat: 43.7922949, lng: -79.7504551 },
-Marker { lat: 43.895128, lng: -79.4050381 },
-Marker { lat: 43.9533809, lng: -78.9444964 },
-Marker { lat: 43.9532411, lng: -79.4438399 },
-Marker { lat: 43.7239547, lng: -79.7148731 },
-Marker { lat: 43.908969, lng: -79.4390763 },
-Marker { lat: 43.7024834, lng: -79.8183845 },
-Marker { lat: 43.6752989, lng: -79.8276732 },
-Marker { lat: 43.6107497, lng: -79.6569604 },
-Marker { lat: 43.9124557, lng: -78.8967887 },
-Marker { lat: 43.829145, lng: -78.990392 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.7190541, lng: -79.2383063 },
-Marker { lat: 43.5896117, lng: -79.6523764 },
-Marker { lat: 43.7975582, lng: -79.414986 },
-Marker { lat: 43.6595018, lng: -79.3847849 },
-Marker { lat: 43.376648, lng: -79.80065 },
-Marker { lat: 43.7582885, lng: -79.586393 },
-Marker { lat: 43.428238, lng: -79.7492063 },
-Marker { lat: 43.6936538, lng: -79.8403649 },
-Marker { lat: 43.970267, lng: -79.263706 },
-Marker { lat: 43.8117632, lng: -79.4388514 },
-Marker { lat: 43.7827154, lng: -79.429505 },
-Marker { lat: 43.9022238, lng: -79.2520534 },
-Marker { lat: 43.6031028, lng: -79.5919361 },
-Marker { lat: 43.7297244, lng: -79.4419558 },
-Marker { lat: 43.5689488, lng: -79.5654176 },
-Marker { lat: 43.7804885, lng: -79.4470239 },
-Marker { lat: 43.6497455, lng: -79.4105005 },
-Marker { lat: 43.7593645, lng: -79.4058308 },
-Marker { lat: 43.6800965, lng: -79.3921327 },
-Marker { lat: 43.7301549, lng: -79.7941791 },
-Marker { lat: 43.740625, lng: -79.3990231 },
-Marker { lat: 43.8724755, lng: -79.4418904 },
-Marker { lat: 43.7654037, lng: -79.2879246 },
-Marker { lat: 43.70693, lng: -79.499761 },
-Marker { lat: 43.7823554, lng: -79.4311434 },
-Marker { lat: 43.6571106, lng: -79.7148876 },
-Marker { lat: 43.75371, lng: -79.8763784 },
-Marker { lat: 43.8935595, lng: -79.375744 },
-Marker { lat: 43.859254, lng: -79.119665 },
-Marker { lat: 43.6667966, lng: -79.3886683 },
-Marker { lat: 43.8504522, lng: -79.4696633 },
-Marker { lat: 43.6546284, lng: -79.4208488 },
-Marker { lat: 43.7465433, lng: -79.43541 },
-Marker { lat: 43.7862635, lng: -79.3695798 },
-Marker { lat: 43.768597, lng: -79.3896 },
-Marker { lat: 43.7875307, lng: -79.417827 },
-Marker { lat: 43.6620882, lng: -79.3856802 },
-Marker { lat: 43.758761, lng: -79.576257 },
-Marker { lat: 43.9186243, lng: -78.9108494 },
-Marker { lat: 43.6548441, lng: -79.3846797 },
-Marker { lat: 43.648321, lng: -79.3928867 },
-Marker { lat: 43.637453, lng: -79.418387 },
-Marker { lat: 43.7624163, lng: -79.4128224 },
-Marker { lat: 43.7982779, lng: -79.3430673 },
-Marker { lat: 43.8005838, lng: -79.4053655 },
-Marker { lat: 43.8208244, lng: -79.0848127 },
-Marker { lat: 43.7945882, lng: -79.4380612 },
-Marker { lat: 43.792665, lng: -79.237219 },
-Marker { lat: 43.7793669, lng: -79.4064017 },
-Marker { lat: 43.8348422, lng: -79.4909889 },
-Marker { lat: 43.8338415, lng: -79.4903767 },
-Marker { lat: 43.9680766, lng: -79.276052 },
-Marker { lat: 43.9000365, lng: -79.2537898 },
-Marker { lat: 43.843778, lng: -79.2709682 },
-Marker { lat: 43.56222, lng: -79.575467 },
-Marker { lat: 43.6070809, lng: -79.5433997 },
-Marker { lat: 43.7939829, lng: -79.4434454 },
-Marker { lat: 43.79615, lng: -79.3539499 },
-Marker { lat: 43.6539796, lng: -79.3885197 },
-Marker { lat: 43.703816, lng: -79.399822 },
-Marker { lat: 43.795742, lng: -79.4173293 },
-Marker { lat: 43.9229615, lng: -79.454053 },
-Marker { lat: 43.6545521, lng: -79.5215743 },
-Marker { lat: 43.6184563, lng: -79.7434628 },
-Marker { lat: 43.6547114, lng: -79.3792073 },
-Marker { lat: 43.8934211, lng: -79.2196975 },
-Marker { lat: 43.8037782, lng: -79.3503596 },
-Marker { lat: 43.6683716, lng: -79.5426154 },
-Marker { lat: 43.8139532, lng: -79.6049461 },
-Marker { lat: 43.6337605, lng: -79.4899705 },
-Marker { lat: 43.6832877, lng: -79.3024054 },
-Marker { lat: 43.6406816, lng: -79.376999 },
-Marker { lat: 43.6758498, lng: -79.7336825 },
-Marker { lat: 43.6488273, lng: -79.3780964 },
-Marker { lat: 43.8026456, lng: -79.3228686 },
-Marker { lat: 43.869143, lng: -79.473773 },
-Marker { lat: 43.9449423, lng: -79.4322093 },
-Marker { lat: 43.766836, lng: -79.198163 },
-Marker { lat: 43.3465148, lng: -79.8463698 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.8057263, lng: -79.4241206 },
-Marker { lat: 43.6363058, lng: -79.7143093 },
-Marker { lat: 43.5359841, lng: -79.6495258 },
-Marker { lat: 43.773248, lng: -79.345838 },
-Marker { lat: 43.8226089, lng: -79.4247113 },
-Marker { lat: 43.3783814, lng: -79.7988286 },
-Marker { lat: 43.7162449, lng: -79.423762 },
-Marker { lat: 43.76972, lng: -79.366921 },
-Marker { lat: 43.704848, lng: -79.2907636 },
-Marker { lat: 43.7560372, lng: -79.1828855 },
-Marker { lat: 43.7651793, lng: -79.1819983 },
-Marker { lat: 43.7006393, lng: -79.2760185 },
-Marker { lat: 43.8170814, lng: -79.4165541 },
-Marker { lat: 43.8112083, lng: -79.4616662 },
-Marker { lat: 43.7041341, lng: -79.853586 },
-Marker { lat: 43.5285219, lng: -79.8444923 },
-Marker { lat: 43.9419648, lng: -78.8933791 },
-Marker { lat: 43.6708286, lng: -79.4562831 },
-Marker { lat: 43.7378891, lng: -79.7230589 },
-Marker { lat: 43.6209169, lng: -79.5068931 },
-Marker { lat: 43.7209346, lng: -79.411508 },
-Marker { lat: 43.661123, lng: -79.4058778 },
-Marker { lat: 43.7505689, lng: -79.2425288 },
-Marker { lat: 43.5623402, lng: -79.6186935 },
-Marker { lat: 43.522968, lng: -79.836003 },
-Marker { lat: 43.9687097, lng: -79.2515382 },
-Marker { lat: 43.5875756, lng: -79.6750138 },
-Marker { lat: 43.7405091, lng: -79.7283684 },
-Marker { lat: 43.8524091, lng: -79.5112967 },
-Marker { lat: 43.3513459, lng: -79.8160013 },
-Marker { lat: 43.646434, lng: -79.397806 },
-Marker { lat: 43.7424033, lng: -79.4021006 },
-Marker { lat: 43.6962745, lng: -79.2927586 },
-Marker { lat: 43.672166, lng: -79.377049 },
-Marker { lat: 43.6698863, lng: -79.3909824 },
-Marker { lat: 43.6679848, lng: -79.3755762 },
-Marker { lat: 43.8242246, lng: -79.4747088 },
-Marker { lat: 43.8399987, lng: -79.0994387 },
-Marker { lat: 43.9221659, lng: -79.4591391 },
-Marker { lat: 43.9658148, lng: -78.9397802 },
-Marker { lat: 43.7214936, lng: -79.3956384 },
-Marker { lat: 43.534297, lng: -79.600505 },
-Marker { lat: 43.7859764, lng: -79.1975731 },
-Marker { lat: 43.7483422, lng: -79.5495289 },
-Marker { lat: 43.621078, lng: -79.521523 },
-Marker { lat: 43.9106144, lng: -78.9038774 },
-Marker { lat: 43.793183, lng: -79.453798 },
-Marker { lat: 43.6731833, lng: -79.4611267 },
-Marker { lat: 43.6957356, lng: -79.4540034 },
-Marker { lat: 43.666647, lng: -79.448206 },
-Marker { lat: 43.564132, lng: -79.860855 },
-Marker { lat: 43.6818873, lng: -79.4110167 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.756947, lng: -79.4064994 },
-Marker { lat: 43.7787489, lng: -79.4171422 },
-Marker { lat: 43.786346, lng: -79.419292 },
-Marker { lat: 43.649074, lng: -79.404884 },
-Marker { lat: 43.7066889, lng: -79.3804467 },
-Marker { lat: 43.7639218, lng: -79.3668643 },
-Marker { lat: 43.708287, lng: -79.391964 },
-Marker { lat: 43.8107918, lng: -79.3590482 },
-Marker { lat: 43.9732247, lng: -79.2396598 },
-Marker { lat: 43.8571799, lng: -79.3261757 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.8318668, lng: -79.2337799 },
-Marker { lat: 43.811118, lng: -79.559299 },
-Marker { lat: 43.7496021, lng: -79.2668943 },
-Marker { lat: 43.7802816, lng: -79.4455163 },
-Marker { lat: 43.5944866, lng: -79.632353 },
-Marker { lat: 43.5847029, lng: -79.6415724 },
-Marker { lat: 43.3539883, lng: -79.8387967 },
-Marker { lat: 43.609209, lng: -79.5583318 },
-Marker { lat: 43.9744979, lng: -79.4473933 },
-Marker { lat: 43.8939062, lng: -79.290452 },
-Marker { lat: 43.8205905, lng: -79.4237985 },
-Marker { lat: 43.594797, lng: -79.534494 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.448163, lng: -79.740535 },
-Marker { lat: 43.6012373, lng: -79.5249438 },
-Marker { lat: 43.349837, lng: -79.8851514 },
-Marker { lat: 43.851994, lng: -78.9413657 },
-Marker { lat: 43.654085, lng: -79.354172 },
-Marker { lat: 43.643132, lng: -79.376486 },
-Marker { lat: 43.866321, lng: -79.435621 },
-Marker { lat: 43.977234, lng: -79.261816 },
-Marker { lat: 43.6283161, lng: -79.7547458 },
-Marker { lat: 43.7320681, lng: -79.4303849 },
-Marker { lat: 43.8225024, lng: -79.607995 },
-Marker { lat: 43.9648544, lng: -79.24389 },
-Marker { lat: 43.7724923, lng: -79.1837248 },
-Marker { lat: 43.793896, lng: -79.417397 },
-Marker { lat: 43.7922564, lng: -79.3153309 },
-Marker { lat: 43.9021112, lng: -78.943731 },
-Marker { lat: 43.9028585, lng: -78.9768567 },
-Marker { lat: 43.7226789, lng: -79.3801588 },
-Marker { lat: 43.8175253, lng: -79.3181227 },
-Marker { lat: 43.680806, lng: -79.3389588 },
-Marker { lat: 43.8590201, lng: -79.5088869 },
-Marker { lat: 43.7810628, lng: -79.2485568 },
-Marker { lat: 43.8511, lng: -79.105366 },
-Marker { lat: 43.7318577, lng: -79.576954 },
-Marker { lat: 43.8083789, lng: -79.4461995 },
-Marker { lat: 43.66955, lng: -79.383776 },
-Marker { lat: 43.506779, lng: -79.8776673 },
-Marker { lat: 43.6399438, lng: -79.4735875 },
-Marker { lat: 43.5615679, lng: -79.7311595 },
-Marker { lat: 43.6888631, lng: -79.7940534 },
-Marker { lat: 43.5698797, lng: -79.576748 },
-Marker { lat: 43.7942854, lng: -79.2873764 },
-Marker { lat: 43.700445, lng: -79.397789 },
-Marker { lat: 43.7252229, lng: -79.6287903 },
-Marker { lat: 43.6870922, lng: -79.4355427 },
-Marker { lat: 43.6952107, lng: -79.5508744 },
-Marker { lat: 43.707083, lng: -79.397771 },
-Marker { lat: 43.7334078, lng: -79.4356985 },
-Marker { lat: 43.6968333, lng: -79.3939934 },
-Marker { lat: 43.766764, lng: -79.385993 },
-Marker { lat: 43.7726667, lng: -79.7417898 },
-Marker { lat: 43.9069438, lng: -79.3786245 },
-Marker { lat: 43.8012387, lng: -79.3985109 },
-Marker { lat: 43.8488033, lng: -79.4295915 },
-Marker { lat: 43.7614554, lng: -79.7365311 },
-Marker { lat: 43.9649367, lng: -79.2361445 },
-Marker { lat: 43.8020778, lng: -79.3315811 },
-Marker { lat: 43.5852249, lng: -79.7428385 },
-Marker { lat: 43.7507925, lng: -79.7675869 },
-Marker { lat: 43.7834295, lng: -79.5949341 },
-Marker { lat: 43.8247242, lng: -79.4704958 },
-Marker { lat: 43.590497, lng: -79.63578 },
-Marker { lat: 43.7431246, lng: -79.8103573 },
-Marker { lat: 43.60596, lng: -79.655123 },
-Marker { lat: 43.682424, lng: -79.279432 },
-Marker { lat: 43.7295846, lng: -79.7296348 },
-Marker { lat: 43.94966, lng: -78.918945 },
-Marker { lat: 43.6568586, lng: -79.3836642 },
-Marker { lat: 43.669593, lng: -79.38058 },
-Marker { lat: 43.6706377, lng: -79.3901448 },
-Marker { lat: 43.8551602, lng: -79.4207989 },
-Marker { lat: 43.7066497, lng: -79.2982284 },
-Marker { lat: 43.5857073, lng: -79.6464202 },
-Marker { lat: 43.66983, lng: -79.381818 },
-Marker { lat: 43.529907, lng: -79.846203 },
-Marker { lat: 43.7985239, lng: -79.3153793 },
-Marker { lat: 43.6154368, lng: -79.6252354 },
-Marker { lat: 43.6390951, lng: -79.5351457 },
-Marker { lat: 43.643939, lng: -79.401477 },
-Marker { lat: 43.7158923, lng: -79.2377266 },
-Marker { lat: 43.6016906, lng: -79.6473268 },
-Marker { lat: 43.9082505, lng: -79.4322974 },
-Marker { lat: 43.8956651, lng: -78.9568582 },
-Marker { lat: 43.4956301, lng: -79.7173717 },
-Marker { lat: 43.8711747, lng: -79.465073 },
-Marker { lat: 43.7164916, lng: -79.8294463 },
-Marker { lat: 43.8870591, lng: -79.2625378 },
-Marker { lat: 43.8710914, lng: -79.082758 },
-Marker { lat: 43.8677, lng: -78.9743 },
-Marker { lat: 43.8581, lng: -78.9318 },
-Marker { lat: 43.8793, lng: -79.497 },
-Marker { lat: 43.872229, lng: -79.468727 },
-Marker { lat: 43.4803396, lng: -79.7360838 },
-Marker { lat: 43.8915239, lng: -78.9178202 },
-Marker { lat: 43.650996, lng: -79.393888 },
-Marker { lat: 43.764933, lng: -79.410561 },
-Marker { lat: 43.8007402, lng: -79.3834263 },
-Marker { lat: 43.8738212, lng: -79.3523399 },
-Marker { lat: 43.6991784, lng: -79.4483194 },
-Marker { lat: 43.6565113, lng: -79.4453793 },
-Marker { lat: 43.6585306, lng: -79.346833 },
-Marker { lat: 43.6686162, lng: -79.3843472 },
-Marker { lat: 43.868958, lng: -79.026466 },
-Marker { lat: 43.7918858, lng: -79.4571177 },
-Marker { lat: 43.758194, lng: -79.5885159 },
-Marker { lat: 43.689739, lng: -79.4480973 },
-Marker { lat: 43.561639, lng: -79.712551 },
-Marker { lat: 43.8830002, lng: -79.8520869 },
-Marker { lat: 43.8410112, lng: -79.4651145 },
-Marker { lat: 43.6184125, lng: -79.6387417 },
-Marker { lat: 43.734331, lng: -79.534458 },
-Marker { lat: 43.8864737, lng: -79.2692408 },
-Marker { lat: 43.8445495, lng: -79.089615 },
-Marker { lat: 43.70529, lng: -79.397827 },
-Marker { lat: 43.7617153, lng: -79.3817303 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.622729, lng: -79.48319 },
-Marker { lat: 43.5850975, lng: -79.6461541 },
-Marker { lat: 43.6717727, lng: -79.3947803 },
-Marker { lat: 43.6904137, lng: -79.8160384 },
-Marker { lat: 43.7047318, lng: -79.8139049 },
-Marker { lat: 43.874469, lng: -79.432662 },
-Marker { lat: 43.883934, lng: -79.047635 },
-Marker { lat: 43.779746, lng: -79.4181433 },
-Marker { lat: 43.6201607, lng: -79.508481 },
-Marker { lat: 43.650718, lng: -79.49575 },
-Marker { lat: 43.7138263, lng: -79.8102237 },
-Marker { lat: 43.7260607, lng: -79.4968256 },
-Marker { lat: 43.8077831, lng: -79.3917533 },
-Marker { lat: 43.6897126, lng: -79.2989718 },
-Marker { lat: 43.9211371, lng: -79.470702 },
-Marker { lat: 43.41821, lng: -79.7051989 },
-Marker { lat: 43.624595, lng: -79.480119 },
-Marker { lat: 43.7021752, lng: -79.763478 },
-Marker { lat: 43.4443931, lng: -79.7655091 },
-Marker { lat: 43.9555627, lng: -78.9112313 },
-Marker { lat: 43.8152932, lng: -79.7261025 },
-Marker { lat: 43.9381379, lng: -79.4459982 },
-Marker { lat: 43.8693746, lng: -79.317758 },
-Marker { lat: 43.8054856, lng: -79.4495125 },
-Marker { lat: 43.8426931, lng: -79.5169233 },
-Marker { lat: 43.8332745, lng: -79.4854625 },
-Marker { lat: 43.9407421, lng: -79.4443385 },
-Marker { lat: 43.9788372, lng: -79.4919871 },
-Marker { lat: 43.6226636, lng: -79.5872963 },
-Marker { lat: 43.4785433, lng: -79.7386257 },
-Marker { lat: 43.4043282, lng: -79.821991 },
-Marker { lat: 43.6004413, lng: -79.5697589 },
-Marker { lat: 43.683985, lng: -79.564277 },
-Marker { lat: 43.5971374, lng: -79.6652055 },
-Marker { lat: 43.6710573, lng: -79.8040616 },
-Marker { lat: 43.5890579, lng: -79.6309412 },
-Marker { lat: 43.6650324, lng: -79.447587 },
-Marker { lat: 43.593015, lng: -79.708597 },
-Marker { lat: 43.6454885, lng: -79.5303865 },
-Marker { lat: 43.6306779, lng: -79.4758539 },
-Marker { lat: 43.862636, lng: -79.041391 },
-Marker { lat: 43.813653, lng: -79.32323 },
-Marker { lat: 43.8485336, lng: -79.4327552 },
-Marker { lat: 43.701585, lng: -79.771813 },
-Marker { lat: 43.7458891, lng: -79.5158122 },
-Marker { lat: 43.7939829, lng: -79.4434454 },
-Marker { lat: 43.9182238, lng: -79.4662114 },
-Marker { lat: 43.8440441, lng: -79.4878336 },
-Marker { lat: 43.8005417, lng: -79.4191588 },
-Marker { lat: 43.8211037, lng: -79.4399479 },
-Marker { lat: 43.9093328, lng: -79.3777612 },
-Marker { lat: 43.3824119, lng: -79.7402414 },
-Marker { lat: 43.7001923, lng: -79.756787 },
-Marker { lat: 43.6803099, lng: -79.8084479 },
-Marker { lat: 43.717448, lng: -79.831553 },
-Marker { lat: 43.497059, lng: -79.6998944 },
-Marker { lat: 43.5960512, lng: -79.5343077 },
-Marker { lat: 43.4875328, lng: -79.7270423 },
-Marker { lat: 43.5103475, lng: -79.6271995 },
-Marker { lat: 43.7416363, lng: -79.7414763 },
-Marker { lat: 43.6871794, lng: -79.5048436 },
-Marker { lat: 43.6243335, lng: -79.485323 },
-Marker { lat: 43.759027, lng: -79.757243 },
-Marker { lat: 43.6172, lng: -79.492424 },
-Marker { lat: 43.6828878, lng: -79.5273754 },
-Marker { lat: 43.6822721, lng: -79.7422132 },
-Marker { lat: 43.5735519, lng: -79.6204246 },
-Marker { lat: 43.94391, lng: -79.775605 },
-Marker { lat: 43.5998052, lng: -79.5466281 },
-Marker { lat: 43.721347, lng: -79.733575 },
-Marker { lat: 43.6709686, lng: -79.7433219 },
-Marker { lat: 43.7914635, lng: -79.9283422 },
-Marker { lat: 43.7348274, lng: -79.7220433 },
-Marker { lat: 43.7446909, lng: -79.3498725 },
-Marker { lat: 43.661933, lng: -79.3782001 },
-Marker { lat: 43.6520368, lng: -79.3733358 },
-Marker { lat: 43.7181463, lng: -79.72599 },
-Marker { lat: 43.7213367, lng: -79.7081443 },
-Marker { lat: 43.7325022, lng: -79.2481332 },
-Marker { lat: 43.7033, lng: -79.828427 },
-Marker { lat: 43.7369733, lng: -79.4299885 },
-Marker { lat: 43.7073389, lng: -79.394278 },
-Marker { lat: 43.652508, lng: -79.3885848 },
-Marker { lat: 43.6853614, lng: -79.3950269 },
-Marker { lat: 43.6423906, lng: -79.4247385 },
-Marker { lat: 43.8970671, lng: -78.8890692 },
-Marker { lat: 43.7759878, lng: -79.3987954 },
-Marker { lat: 43.6648469, lng: -79.3861096 },
-Marker { lat: 43.6733388, lng: -79.3968295 },
-Marker { lat: 43.8505548, lng: -78.9465347 },
-Marker { lat: 43.6667966, lng: -79.3886683 },
-Marker { lat: 43.700767, lng: -79.407987 },
-Marker { lat: 43.8737578, lng: -79.1225325 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.6593521, lng: -79.3856503 },
-Marker { lat: 43.7767049, lng: -79.4110639 },
-Marker { lat: 43.6404948, lng: -79.3808155 },
-Marker { lat: 43.7312911, lng: -79.3934479 },
-Marker { lat: 43.6500154, lng: -79.3873578 },
-Marker { lat: 43.641521, lng: -79.415204 },
-Marker { lat: 43.6768701, lng: -79.4335111 },
-Marker { lat: 43.920333, lng: -78.9174978 },
-Marker { lat: 43.7961895, lng: -79.3119872 },
-Marker { lat: 43.6239417, lng: -79.4884467 },
-Marker { lat: 43.6882357, lng: -79.3100233 },
-Marker { lat: 43.7560349, lng: -79.4108793 },
-Marker { lat: 43.6387756, lng: -79.4163147 },
-Marker { lat: 43.8136201, lng: -79.3298006 },
-Marker { lat: 43.7803426, lng: -79.388221 },
-Marker { lat: 43.6673247, lng: -79.3727384 },
-Marker { lat: 43.866309, lng: -79.029406 },
-Marker { lat: 43.849678, lng: -78.9469136 },
-Marker { lat: 43.873693, lng: -79.062149 },
-Marker { lat: 43.940519, lng: -78.884837 },
-Marker { lat: 43.887633, lng: -79.046162 },
-Marker { lat: 43.854714, lng: -79.021384 },
-Marker { lat: 43.8766856, lng: -79.4339105 },
-Marker { lat: 43.8240409, lng: -79.392354 },
-Marker { lat: 43.7752161, lng: -79.4133461 },
-Marker { lat: 43.8565448, lng: -79.3244227 },
-Marker { lat: 43.656537, lng: -79.354497 },
-Marker { lat: 43.8611036, lng: -79.3058536 },
-Marker { lat: 43.9140954, lng: -79.4353744 },
-Marker { lat: 43.7879818, lng: -79.3554579 },
-Marker { lat: 43.5842175, lng: -79.6164494 },
-Marker { lat: 43.8375018, lng: -79.6190981 },
-Marker { lat: 43.589678, lng: -79.630472 },
-Marker { lat: 43.5334514, lng: -79.8420464 },
-Marker { lat: 43.6871794, lng: -79.5048436 },
-Marker { lat: 43.721268, lng: -79.732148 },
-Marker { lat: 43.564446, lng: -79.7271362 },
-Marker { lat: 43.72643, lng: -79.7143814 },
-Marker { lat: 43.4925287, lng: -79.7174344 },
-Marker { lat: 43.7333727, lng: -79.5906723 },
-Marker { lat: 43.621078, lng: -79.521523 },
-Marker { lat: 43.63932, lng: -79.385723 },
-Marker { lat: 43.8551057, lng: -79.3453338 },
-Marker { lat: 43.3703773, lng: -79.7288226 },
-Marker { lat: 43.760402, lng: -79.40901 },
-Marker { lat: 43.3486176, lng: -79.8067668 },
-Marker { lat: 43.8091676, lng: -79.455206 },
-Marker { lat: 43.4392429, lng: -79.737204 },
-Marker { lat: 43.7902389, lng: -79.600766 },
-Marker { lat: 43.6638679, lng: -79.7766695 },
-Marker { lat: 43.8147713, lng: -79.4432081 },
-Marker { lat: 43.6222561, lng: -79.4825565 },
-Marker { lat: 43.6022361, lng: -79.6498521 },
-Marker { lat: 43.6448465, lng: -79.4022849 },
-Marker { lat: 43.4973119, lng: -79.7042877 },
-Marker { lat: 43.645621, lng: -79.389497 },
-Marker { lat: 43.6327973, lng: -79.7686575 },
-Marker { lat: 43.9390813, lng: -79.4747451 },
-Marker { lat: 43.6149072, lng: -79.7444803 },
-Marker { lat: 43.6647797, lng: -79.3409446 },
-Marker { lat: 43.5262629, lng: -79.65372 },
-Marker { lat: 43.7736002, lng: -79.2069556 },
-Marker { lat: 43.8100647, lng: -79.4487117 },
-Marker { lat: 43.5330392, lng: -79.8541709 },
-Marker { lat: 43.7294, lng: -79.3373 },
-Marker { lat: 43.7963988, lng: -79.1671812 },
-Marker { lat: 43.8429639, lng: -79.5485961 },
-Marker { lat: 43.7506, lng: -79.4435 },
-Marker { lat: 43.9318, lng: -79.411 },
-Marker { lat: 43.854404, lng: -79.7004542 },
-Marker { lat: 43.5952639, lng: -79.7083251 },
-Marker { lat: 43.8117, lng: -79.6306 },
-Marker { lat: 43.8560345, lng: -79.3280239 },
-Marker { lat: 43.8066203, lng: -79.7285117 },
-Marker { lat: 43.4785311, lng: -79.7459985 },
-Marker { lat: 43.7617716, lng: -79.8258369 },
-Marker { lat: 43.8659, lng: -79.4342 },
-Marker { lat: 43.7694183, lng: -79.3686931 },
-Marker { lat: 43.886862, lng: -79.048412 },
-Marker { lat: 43.5661014, lng: -79.7466393 },
-Marker { lat: 43.6432587, lng: -79.3764035 },
-Marker { lat: 43.5816498, lng: -79.6480951 },
-Marker { lat: 43.915045, lng: -79.645189 },
-Marker { lat: 43.851427, lng: -78.940724 },
-Marker { lat: 43.7795711, lng: -79.6983228 },
-Marker { lat: 43.6375103, lng: -79.4005804 },
-Marker { lat: 43.6891866, lng: -79.8574258 },
-Marker { lat: 43.6372054, lng: -79.4035754 },
-Marker { lat: 43.9391999, lng: -79.4691406 },
-Marker { lat: 43.6676702, lng: -79.3864205 },
-Marker { lat: 43.3798023, lng: -79.7432543 },
-Marker { lat: 43.3733294, lng: -79.8365383 },
-Marker { lat: 43.526368, lng: -79.846905 },
-Marker { lat: 43.5551832, lng: -79.7390383 },
-Marker { lat: 43.781465, lng: -79.726097 },
-Marker { lat: 43.3867476, lng: -79.7221636 },
-Marker { lat: 43.4358343, lng: -79.6946972 },
-Marker { lat: 43.3978356, lng: -79.8159123 },
-Marker { lat: 43.391031, lng: -79.783862 },
-Marker { lat: 43.812554, lng: -79.4544922 },
-Marker { lat: 43.6936358, lng: -79.8023319 },
-Marker { lat: 43.7122855, lng: -79.6985085 },
-Marker { lat: 43.8493964, lng: -79.2736596 },
-Marker { lat: 43.6692155, lng: -79.4530051 },
-Marker { lat: 43.8684802, lng: -79.5180359 },
-Marker { lat: 43.9713671, lng: -78.9444532 },
-Marker { lat: 43.5096367, lng: -79.8521782 },
-Marker { lat: 43.837511, lng: -78.984112 },
-Marker { lat: 43.6860744, lng: -79.8305874 },
-Marker { lat: 43.488954, lng: -79.869917 },
-Marker { lat: 43.3477753, lng: -79.8062143 },
-Marker { lat: 43.493466, lng: -79.840259 },
-Marker { lat: 43.820405, lng: -79.6258802 },
-Marker { lat: 43.5656802, lng: -79.6120757 },
-Marker { lat: 43.799707, lng: -79.4181582 },
-Marker { lat: 43.8356224, lng: -79.4394752 },
-Marker { lat: 43.7615186, lng: -79.4640085 },
-Marker { lat: 43.6108333, lng: -79.6496434 },
-Marker { lat: 43.7647371, lng: -79.4935259 },
-Marker { lat: 43.869017, lng: -79.026254 },
-Marker { lat: 43.548701, lng: -79.7248802 },
-Marker { lat: 43.6459582, lng: -79.3898731 },
-Marker { lat: 43.446344, lng: -79.725153 },
-Marker { lat: 43.6393446, lng: -79.3929716 },
-Marker { lat: 43.4476595, lng: -79.7083698 },
-Marker { lat: 43.6164915, lng: -79.7458242 },
-Marker { lat: 43.8268733, lng: -79.3251829 },
-Marker { lat: 43.7366089, lng: -79.7480521 },
-Marker { lat: 43.818925, lng: -79.021441 },
-Marker { lat: 43.3467657, lng: -79.850725 },
-Marker { lat: 43.6357104, lng: -79.3988424 },
-Marker { lat: 43.8050452, lng: -79.7287954 },
-Marker { lat: 43.854705, lng: -79.031997 },
-Marker { lat: 43.638563, lng: -79.412811 },
-Marker { lat: 43.982395, lng: -79.633002 },
-Marker { lat: 43.8501943, lng: -79.4064839 },
-Marker { lat: 43.4016533, lng: -79.9292351 },
-Marker { lat: 43.79615, lng: -79.3539499 },
-Marker { lat: 43.8443265, lng: -79.4021773 },
-Marker { lat: 43.685137, lng: -79.2809765 },
-Marker { lat: 43.732864, lng: -79.7510983 },
-Marker { lat: 43.7927851, lng: -79.1583503 },
-Marker { lat: 43.6748037, lng: -79.2875099 },
-Marker { lat: 43.3922129, lng: -79.7826151 },
-Marker { lat: 43.642311, lng: -79.38123 },
-Marker { lat: 43.6400907, lng: -79.3786023 },
-Marker { lat: 43.83998, lng: -79.4881873 },
-Marker { lat: 43.7492059, lng: -79.3608746 },
-Marker { lat: 43.6823901, lng: -79.2796015 },
-Marker { lat: 43.6598587, lng: -79.4884017 },
-Marker { lat: 43.711535, lng: -79.4353189 },
-Marker { lat: 43.5883573, lng: -79.6395201 },
-Marker { lat: 43.609209, lng: -79.5583318 },
-Marker { lat: 43.58813, lng: -79.640126 },
-Marker { lat: 43.7595056, lng: -79.8234441 },
-Marker { lat: 43.7796544, lng: -79.2829265 },
-Marker { lat: 43.622729, lng: -79.48319 },
-Marker { lat: 43.8190204, lng: -79.6092996 },
-Marker { lat: 43.8772017, lng: -79.422479 },
-Marker { lat: 43.7778723, lng: -79.2807506 },
-Marker { lat: 43.6605208, lng: -79.3785421 },
-Marker { lat: 43.7657237, lng: -79.3834602 },
-Marker { lat: 43.653687, lng: -79.4187412 },
-Marker { lat: 43.809346, lng: -79.41344 },
-Marker { lat: 43.754143, lng: -79.4486376 },
-Marker { lat: 43.6371667, lng: -79.404733 },
-Marker { lat: 43.512309, lng: -79.65286 },
-Marker { lat: 43.7489254, lng: -79.5917971 },
-Marker { lat: 43.9040143, lng: -79.4005292 },
-Marker { lat: 43.7348494, lng: -79.7685711 },
-Marker { lat: 43.5410701, lng: -79.6018536 },
-Marker { lat: 43.7935688, lng: -79.4213652 },
-Marker { lat: 43.9225233, lng: -78.9534705 },
-Marker { lat: 43.8741377, lng: -79.2448158 },
-Marker { lat: 43.9534235, lng: -78.9711935 },
-Marker { lat: 43.9037673, lng: -79.6574998 },
-Marker { lat: 43.648317, lng: -79.393065 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.6363712, lng: -79.4027602 },
-Marker { lat: 43.641087, lng: -79.381405 },
-Marker { lat: 43.769718, lng: -79.41383 },
-Marker { lat: 43.7672196, lng: -79.3908157 },
-Marker { lat: 43.78291, lng: -79.417394 },
-Marker { lat: 43.6595018, lng: -79.3847849 },
-Marker { lat: 43.6423906, lng: -79.4247385 },
-Marker { lat: 43.7599764, lng: -79.2586042 },
-Marker { lat: 43.6667966, lng: -79.3886683 },
-Marker { lat: 43.6810916, lng: -79.2802931 },
-Marker { lat: 43.8159627, lng: -79.3310626 },
-Marker { lat: 43.905756, lng: -78.969102 },
-Marker { lat: 43.781795, lng: -79.246575 },
-Marker { lat: 43.8742465, lng: -79.3357115 },
-Marker { lat: 43.93946, lng: -79.466757 },
-Marker { lat: 43.6238862, lng: -79.4792234 },
-Marker { lat: 43.9623693, lng: -79.4369718 },
-Marker { lat: 43.631322, lng: -79.592586 },
-Marker { lat: 43.6657347, lng: -79.447861 },
-Marker { lat: 43.661781, lng: -79.384218 },
-Marker { lat: 43.7681782, lng: -79.3906547 },
-Marker { lat: 43.372041, lng: -79.8041742 },
-Marker { lat: 43.7657785, lng: -79.4100647 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.665753, lng: -79.385579 },
-Marker { lat: 43.7872283, lng: -79.1946802 },
-Marker { lat: 43.802705, lng: -79.4192904 },
-Marker { lat: 43.7577566, lng: -79.3814139 },
-Marker { lat: 43.939683, lng: -79.655925 },
-Marker { lat: 43.7028015, lng: -79.4513882 },
-Marker { lat: 43.3506558, lng: -79.8128351 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.747413, lng: -79.224651 },
-Marker { lat: 43.6601105, lng: -79.3605898 },
-Marker { lat: 43.914941, lng: -79.4412465 },
-Marker { lat: 43.8014785, lng: -79.1539126 },
-Marker { lat: 43.6651112, lng: -79.4886038 },
-Marker { lat: 43.6401213, lng: -79.3812266 },
-Marker { lat: 43.7047403, lng: -79.7673576 },
-Marker { lat: 43.6398049, lng: -79.3979687 },
-Marker { lat: 43.7102879, lng: -79.303001 },
-Marker { lat: 43.779735, lng: -79.366223 },
-Marker { lat: 43.7844276, lng: -79.4161322 },
-Marker { lat: 43.737385, lng: -79.2907484 },
-Marker { lat: 43.7405594, lng: -79.4245956 },
-Marker { lat: 43.569813, lng: -79.6304482 },
-Marker { lat: 43.8737636, lng: -79.086527 },
-Marker { lat: 43.8805211, lng: -79.2872262 },
-Marker { lat: 43.7703, lng: -79.413645 },
-Marker { lat: 43.619564, lng: -79.5103162 },
-Marker { lat: 43.6449599, lng: -79.3643305 },
-Marker { lat: 43.7692297, lng: -79.411935 },
-Marker { lat: 43.897327, lng: -79.4473026 },
-Marker { lat: 43.7430899, lng: -79.211373 },
-Marker { lat: 43.6539796, lng: -79.3885197 },
-Marker { lat: 43.91521, lng: -79.642209 },
-Marker { lat: 43.5550514, lng: -79.5833327 },
-Marker { lat: 43.5130706, lng: -79.6255887 },
-Marker { lat: 43.6830187, lng: -79.4180049 },
-Marker { lat: 43.4016688, lng: -79.7882484 },
-Marker { lat: 43.7255754, lng: -79.5230345 },
-Marker { lat: 43.7010661, lng: -79.8254968 },
-Marker { lat: 43.8396633, lng: -79.5679172 },
-Marker { lat: 43.9157575, lng: -79.538157 },
-Marker { lat: 43.7858274, lng: -79.2915268 },
-Marker { lat: 43.6489, lng: -79.392205 },
-Marker { lat: 43.7752003, lng: -79.412361 },
-Marker { lat: 43.641579, lng: -79.415902 },
-Marker { lat: 43.7831752, lng: -79.3202693 },
-Marker { lat: 43.437345, lng: -79.716022 },
-Marker { lat: 43.5874623, lng: -79.6414072 },
-Marker { lat: 43.6676702, lng: -79.3864205 },
-Marker { lat: 43.975297, lng: -79.246042 },
-Marker { lat: 43.9040841, lng: -79.2563332 },
-Marker { lat: 43.649696, lng: -79.398202 },
-Marker { lat: 43.855302, lng: -79.040422 },
-Marker { lat: 43.645375, lng: -79.877219 },
-Marker { lat: 43.7940191, lng: -79.1242582 },
-Marker { lat: 43.7440854, lng: -79.4010926 },
-Marker { lat: 43.6726511, lng: -79.388732 },
-Marker { lat: 43.6866509, lng: -79.3995111 },
-Marker { lat: 43.651936, lng: -79.394743 },
-Marker { lat: 43.6490534, lng: -79.3861546 },
-Marker { lat: 43.9318, lng: -79.411 },
-Marker { lat: 43.8227273, lng: -79.3877869 },
-Marker { lat: 43.8298031, lng: -79.379595 },
-Marker { lat: 43.9562448, lng: -79.4535255 },
-Marker { lat: 43.899422, lng: -79.2232002 },
-Marker { lat: 43.8228271, lng: -79.3989259 },
-Marker { lat: 43.6758169, lng: -79.5475719 },
-Marker { lat: 43.7065673, lng: -79.5124902 },
-Marker { lat: 43.7012318, lng: -79.4486233 },
-Marker { lat: 43.512022, lng: -79.840375 },
-Marker { lat: 43.5589545, lng: -79.6438869 },
-Marker { lat: 43.5392365, lng: -79.6001881 },
-Marker { lat: 43.5980183, lng: -79.6213526 },
-Marker { lat: 43.840289, lng: -79.392027 },
-Marker { lat: 43.8999, lng: -78.914515 },
-Marker { lat: 43.6998074, lng: -79.4189457 },
-Marker { lat: 43.630306, lng: -79.93046 },
-Marker { lat: 43.6477053, lng: -79.5860438 },
-Marker { lat: 43.7847637, lng: -79.6135763 },
-Marker { lat: 43.8719421, lng: -79.4268026 },
-Marker { lat: 43.650161, lng: -79.930178 },
-Marker { lat: 43.6909738, lng: -79.3505854 },
-Marker { lat: 43.9580123, lng: -79.4394975 },
-Marker { lat: 43.4864684, lng: -79.8762441 },
-Marker { lat: 43.654085, lng: -79.354172 },
-Marker { lat: 43.9390957, lng: -79.4766175 },
-Marker { lat: 43.6694723, lng: -79.3874877 },
-Marker { lat: 43.640783, lng: -79.40121 },
-Marker { lat: 43.57592, lng: -79.7740978 },
-Marker { lat: 43.9167218, lng: -78.9186822 },
-Marker { lat: 43.646099, lng: -79.365136 },
-Marker { lat: 43.8890923, lng: -79.2664697 },
-Marker { lat: 43.7484859, lng: -79.4952603 },
-Marker { lat: 43.8236775, lng: -79.4491149 },
-Marker { lat: 43.5524026, lng: -79.7503458 },
-Marker { lat: 43.8140985, lng: -79.4514793 },
-Marker { lat: 43.6454468, lng: -79.7334741 },
-Marker { lat: 43.3937868, lng: -79.711106 },
-Marker { lat: 43.7549969, lng: -79.5722286 },
-Marker { lat: 43.8332895, lng: -79.4951178 },
-Marker { lat: 43.8631591, lng: -79.3380648 },
-Marker { lat: 43.6591662, lng: -79.9088837 },
-Marker { lat: 43.642422, lng: -79.3755829 },
-Marker { lat: 43.9617028, lng: -78.969589 },
-Marker { lat: 43.7218509, lng: -79.4262468 },
-Marker { lat: 43.725109, lng: -79.7002685 },
-Marker { lat: 43.8428417, lng: -79.4262012 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.908679, lng: -79.2469741 },
-Marker { lat: 43.9034828, lng: -79.4403346 },
-Marker { lat: 43.6977219, lng: -79.440005 },
-Marker { lat: 43.725655, lng: -79.398456 },
-Marker { lat: 43.3708943, lng: -79.8343871 },
-Marker { lat: 43.3706106, lng: -79.8343855 },
-Marker { lat: 43.6557184, lng: -79.9313693 },
-Marker { lat: 43.5187137, lng: -79.8507639 },
-Marker { lat: 43.362748, lng: -79.7481797 },
-Marker { lat: 43.605548, lng: -79.675623 },
-Marker { lat: 43.476725, lng: -79.688622 },
-Marker { lat: 43.626013, lng: -79.894091 },
-Marker { lat: 43.7547, lng: -79.372485 },
-Marker { lat: 43.5967029, lng: -79.5344793 },
-Marker { lat: 43.9716303, lng: -79.2219425 },
-Marker { lat: 43.7683764, lng: -79.2124157 },
-Marker { lat: 43.8937987, lng: -79.2546305 },
-Marker { lat: 43.6943623, lng: -79.3093245 },
-Marker { lat: 43.661725, lng: -79.384128 },
-Marker { lat: 43.7703, lng: -79.413645 },
-Marker { lat: 43.7185483, lng: -79.8208202 },
-Marker { lat: 43.6429451, lng: -79.3805034 },
-Marker { lat: 43.78291, lng: -79.417394 },
-Marker { lat: 43.7379409, lng: -79.4255601 },
-Marker { lat: 43.718243, lng: -79.37642 },
-Marker { lat: 43.8073965, lng: -79.2671295 },
-Marker { lat: 43.6951374, lng: -79.3208556 },
-Marker { lat: 43.8591058, lng: -79.4372759 },
-Marker { lat: 43.6384158, lng: -79.582077 },
-Marker { lat: 43.4219945, lng: -79.7723611 },
-Marker { lat: 43.804606, lng: -79.212387 },
-Marker { lat: 43.8378043, lng: -79.2764902 },
-Marker { lat: 43.3434654, lng: -79.7904648 },
-Marker { lat: 43.3413947, lng: -79.7956072 },
-Marker { lat: 43.717218, lng: -79.9358897 },
-Marker { lat: 43.878334, lng: -79.352188 },
-Marker { lat: 43.5269974, lng: -79.6541593 },
-Marker { lat: 43.434214, lng: -79.776184 },
-Marker { lat: 43.6791701, lng: -79.5180055 },
-Marker { lat: 43.4149174, lng: -79.7102973 },
-Marker { lat: 43.717865, lng: -79.828857 },
-Marker { lat: 43.639783, lng: -79.397947 },
-Marker { lat: 43.641087, lng: -79.381405 },
-Marker { lat: 43.851993, lng: -79.43178 },
-Marker { lat: 43.7700855, lng: -79.4402409 },
-Marker { lat: 43.843083, lng: -79.425925 },
-Marker { lat: 43.7437176, lng: -79.2456657 },
-Marker { lat: 43.9675306, lng: -78.9497941 },
-Marker { lat: 43.6468329, lng: -79.3752164 },
-Marker { lat: 43.9774085, lng: -79.4747086 },
-Marker { lat: 43.846632, lng: -79.43836 },
-Marker { lat: 43.7752161, lng: -79.4133461 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.494925, lng: -79.8717301 },
-Marker { lat: 43.637225, lng: -79.4037 },
-Marker { lat: 43.7409219, lng: -79.2911107 },
-Marker { lat: 43.6620882, lng: -79.3856802 },
-Marker { lat: 43.7639657, lng: -79.8304003 },
-Marker { lat: 43.6460155, lng: -79.3940644 },
-Marker { lat: 43.7810628, lng: -79.2485568 },
-Marker { lat: 43.770256, lng: -79.2871781 },
-Marker { lat: 43.7831209, lng: -79.3987281 },
-Marker { lat: 43.685364, lng: -79.4366604 },
-Marker { lat: 43.624595, lng: -79.480119 },
-Marker { lat: 43.7740321, lng: -79.2580098 },
-Marker { lat: 43.6899231, lng: -79.2976766 },
-Marker { lat: 43.6403228, lng: -79.3917567 },
-Marker { lat: 43.9825441, lng: -79.4827891 },
-Marker { lat: 43.6916185, lng: -79.4627873 },
-Marker { lat: 43.8452981, lng: -79.4589418 },
-Marker { lat: 43.8637063, lng: -79.440589 },
-Marker { lat: 43.5554368, lng: -79.7482852 },
-Marker { lat: 43.4698162, lng: -79.7471018 },
-Marker { lat: 43.872531, lng: -79.022982 },
-Marker { lat: 43.904543, lng: -79.4355601 },
-Marker { lat: 43.384717, lng: -79.817382 },
-Marker { lat: 43.6656028, lng: -79.4137405 },
-Marker { lat: 43.6436277, lng: -79.5043039 },
-Marker { lat: 43.8410854, lng: -79.2855675 },
-Marker { lat: 43.9391561, lng: -79.4455705 },
-Marker { lat: 43.6440617, lng: -79.3894386 },
-Marker { lat: 43.717364, lng: -79.829605 },
-Marker { lat: 43.4300041, lng: -79.7672994 },
-Marker { lat: 43.7459265, lng: -79.7788376 },
-Marker { lat: 43.712675, lng: -79.840813 },
-Marker { lat: 43.638299, lng: -79.582237 },
-Marker { lat: 43.9266911, lng: -79.5122681 },
-Marker { lat: 43.71166, lng: -79.324539 },
-Marker { lat: 43.5445398, lng: -79.7009559 },
-Marker { lat: 43.7005632, lng: -79.3105504 },
-Marker { lat: 43.6568058, lng: -79.3750924 },
-Marker { lat: 43.785204, lng: -79.409031 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.6399005, lng: -79.3905103 },
-Marker { lat: 43.8779688, lng: -79.4492459 },
-Marker { lat: 43.960864, lng: -78.9135827 },
-Marker { lat: 43.840667, lng: -79.393288 },
-Marker { lat: 43.6898448, lng: -79.3127857 },
-Marker { lat: 43.584381, lng: -79.6519398 },
-Marker { lat: 43.4273448, lng: -79.7313502 },
-Marker { lat: 43.647524, lng: -79.387335 },
-Marker { lat: 43.8401874, lng: -79.2867746 },
-Marker { lat: 43.7225005, lng: -79.8079905 },
-Marker { lat: 43.6684578, lng: -79.772701 },
-Marker { lat: 43.7377481, lng: -79.5113152 },
-Marker { lat: 43.7855326, lng: -79.3682668 },
-Marker { lat: 43.823302, lng: -79.129185 },
-Marker { lat: 43.878251, lng: -79.051014 },
-Marker { lat: 43.4039079, lng: -79.7100979 },
-Marker { lat: 43.6842512, lng: -79.8079003 },
-Marker { lat: 43.5646574, lng: -79.6657993 },
-Marker { lat: 43.8080689, lng: -79.6109004 },
-Marker { lat: 43.8036515, lng: -79.4505507 },
-Marker { lat: 43.685286, lng: -79.761492 },
-Marker { lat: 43.445879, lng: -79.7082266 },
-Marker { lat: 43.6806683, lng: -79.7918873 },
-Marker { lat: 43.6559105, lng: -79.5159025 },
-Marker { lat: 43.827088, lng: -79.09158 },
-Marker { lat: 43.7925486, lng: -79.3037522 },
-Marker { lat: 43.641785, lng: -79.379006 },
-Marker { lat: 43.835247, lng: -79.141997 },
-Marker { lat: 43.7268093, lng: -79.4020116 },
-Marker { lat: 43.6632625, lng: -79.34844 },
-Marker { lat: 43.9131067, lng: -78.96192 },
-Marker { lat: 43.6411777, lng: -79.3937202 },
-Marker { lat: 43.9121058, lng: -78.8878215 },
-Marker { lat: 43.847647, lng: -79.25228 },
-Marker { lat: 43.676041, lng: -79.558165 },
-Marker { lat: 43.8629479, lng: -79.2411178 },
-Marker { lat: 43.7724448, lng: -79.393289 },
-Marker { lat: 43.860066, lng: -78.938789 },
-Marker { lat: 43.739924, lng: -79.434866 },
-Marker { lat: 43.6822551, lng: -79.5481405 },
-Marker { lat: 43.6371018, lng: -79.5008655 },
-Marker { lat: 43.7813679, lng: -79.5957636 },
-Marker { lat: 43.8465981, lng: -79.471566 },
-Marker { lat: 43.6611781, lng: -79.4409001 },
-Marker { lat: 43.7173297, lng: -79.5326653 },
-Marker { lat: 43.6438543, lng: -79.5291731 },
-Marker { lat: 43.657455, lng: -79.5859143 },
-Marker { lat: 43.677065, lng: -79.322465 },
-Marker { lat: 43.7739867, lng: -79.3595046 },
-Marker { lat: 43.656806, lng: -79.375092 },
-Marker { lat: 43.766764, lng: -79.385993 },
-Marker { lat: 43.6896134, lng: -79.4325777 },
-Marker { lat: 43.6482422, lng: -79.3949808 },
-Marker { lat: 43.6533584, lng: -79.390087 },
-Marker { lat: 43.6374676, lng: -79.4184499 },
-Marker { lat: 43.7187973, lng: -79.7385051 },
-Marker { lat: 43.6480233, lng: -79.7343203 },
-Marker { lat: 43.5521282, lng: -79.7554441 },
-Marker { lat: 43.6429592, lng: -79.4306846 },
-Marker { lat: 43.7590528, lng: -79.7374842 },
-Marker { lat: 43.553766, lng: -79.581959 },
-Marker { lat: 43.553858, lng: -79.711239 },
-Marker { lat: 43.718367, lng: -79.830287 },
-Marker { lat: 43.6457578, lng: -79.3946255 },
-Marker { lat: 43.5094415, lng: -79.8445691 },
-Marker { lat: 43.5512965, lng: -79.5943358 },
-Marker { lat: 43.8425055, lng: -79.2721067 },
-Marker { lat: 43.6771165, lng: -79.3275484 },
-Marker { lat: 43.670547, lng: -79.380581 },
-Marker { lat: 43.3450041, lng: -79.8897972 },
-Marker { lat: 43.4074609, lng: -79.8150468 },
-Marker { lat: 43.7667084, lng: -79.3098093 },
-Marker { lat: 43.5253141, lng: -79.8806397 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.8966101, lng: -79.2669407 },
-Marker { lat: 43.8947344, lng: -78.9546165 },
-Marker { lat: 43.8910403, lng: -79.4552748 },
-Marker { lat: 43.8036515, lng: -79.4505507 },
-Marker { lat: 43.6697789, lng: -79.4478683 },
-Marker { lat: 43.447443, lng: -79.686948 },
-Marker { lat: 43.66199, lng: -79.434017 },
-Marker { lat: 43.403434, lng: -79.804417 },
-Marker { lat: 43.6390951, lng: -79.5351457 },
-Marker { lat: 43.8757916, lng: -79.0920436 },
-Marker { lat: 43.7251734, lng: -79.4276214 },
-Marker { lat: 43.7439705, lng: -79.2635931 },
-Marker { lat: 43.7728732, lng: -79.4554129 },
-Marker { lat: 43.774928, lng: -79.33551 },
-Marker { lat: 43.774482, lng: -79.3592026 },
-Marker { lat: 43.820418, lng: -79.130745 },
-Marker { lat: 43.8496064, lng: -79.5648463 },
-Marker { lat: 43.9001512, lng: -79.2660727 },
-Marker { lat: 43.7524543, lng: -79.2658188 },
-Marker { lat: 43.6366903, lng: -79.5262624 },
-Marker { lat: 43.846483, lng: -79.566664 },
-Marker { lat: 43.8729042, lng: -79.4275302 },
-Marker { lat: 43.8070505, lng: -79.4551611 },
-Marker { lat: 43.615229, lng: -79.489016 },
-Marker { lat: 43.7640884, lng: -79.3753644 },
-Marker { lat: 43.6882845, lng: -79.4086639 },
-Marker { lat: 43.4252974, lng: -79.7844288 },
-Marker { lat: 43.7213754, lng: -79.5659705 },
-Marker { lat: 43.5592325, lng: -79.5818658 },
-Marker { lat: 43.5665838, lng: -79.6949328 },
-Marker { lat: 43.6313954, lng: -79.5492978 },
-Marker { lat: 43.6596291, lng: -79.7152671 },
-Marker { lat: 43.613136, lng: -79.6607762 },
-Marker { lat: 43.6333489, lng: -79.39983 },
-Marker { lat: 43.6126343, lng: -79.6539773 },
-Marker { lat: 43.7265925, lng: -79.6233842 },
-Marker { lat: 43.759752, lng: -79.461853 },
-Marker { lat: 43.7114796, lng: -79.709072 },
-Marker { lat: 43.8503808, lng: -79.0099622 },
-Marker { lat: 43.55642, lng: -79.740125 },
-Marker { lat: 43.7568656, lng: -79.7346371 },
-Marker { lat: 43.7202729, lng: -79.7142161 },
-Marker { lat: 43.5455436, lng: -79.6853328 },
-Marker { lat: 43.5798519, lng: -79.6285491 },
-Marker { lat: 43.757354, lng: -79.409358 },
-Marker { lat: 43.560127, lng: -79.737142 },
-Marker { lat: 43.6825697, lng: -79.8473303 },
-Marker { lat: 43.5277101, lng: -79.6539767 },
-Marker { lat: 43.4285, lng: -79.7445 },
-Marker { lat: 43.5409056, lng: -79.7278654 },
-Marker { lat: 43.6046367, lng: -79.5534502 },
-Marker { lat: 43.5959983, lng: -79.5477901 },
-Marker { lat: 43.6724665, lng: -79.2862241 },
-Marker { lat: 43.672578, lng: -79.28627 },
-Marker { lat: 43.623762, lng: -79.604951 },
-Marker { lat: 43.8794877, lng: -79.4225585 },
-Marker { lat: 43.6211556, lng: -79.5215447 },
-Marker { lat: 43.7400561, lng: -79.2728098 },
-Marker { lat: 43.9739552, lng: -78.9418519 },
-Marker { lat: 43.6959683, lng: -79.3957747 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.7707505, lng: -79.6637375 },
-Marker { lat: 43.652433, lng: -79.38886 },
-Marker { lat: 43.6363712, lng: -79.4027602 },
-Marker { lat: 43.7449, lng: -79.828559 },
-Marker { lat: 43.7645058, lng: -79.288476 },
-Marker { lat: 43.7474115, lng: -79.5599092 },
-Marker { lat: 43.6823354, lng: -79.4856319 },
-Marker { lat: 43.789371, lng: -79.664708 },
-Marker { lat: 43.7936532, lng: -79.5499233 },
-Marker { lat: 43.6828608, lng: -79.5300597 },
-Marker { lat: 43.7474, lng: -79.5903 },
-Marker { lat: 43.5062118, lng: -79.6443859 },
-Marker { lat: 43.3415378, lng: -79.7989424 },
-Marker { lat: 43.3565262, lng: -79.8162623 },
-Marker { lat: 43.4011826, lng: -79.7139237 },
-Marker { lat: 43.7993897, lng: -79.4490838 },
-Marker { lat: 43.5816498, lng: -79.6480951 },
-Marker { lat: 43.71694, lng: -79.260884 },
-Marker { lat: 43.8219046, lng: -79.3821684 },
-Marker { lat: 43.9218512, lng: -79.5358425 },
-Marker { lat: 43.6740094, lng: -79.4124294 },
-Marker { lat: 43.665432, lng: -79.40831 },
-Marker { lat: 43.802033, lng: -79.275585 },
-Marker { lat: 43.691162, lng: -79.840879 },
-Marker { lat: 43.7882208, lng: -79.4494936 },
-Marker { lat: 43.6364331, lng: -79.5575207 },
-Marker { lat: 43.8608861, lng: -79.506176 },
-Marker { lat: 43.8886163, lng: -79.3130133 },
-Marker { lat: 43.8288549, lng: -79.4111336 },
-Marker { lat: 43.4836123, lng: -79.8534907 },
-Marker { lat: 43.654776, lng: -79.425277 },
-Marker { lat: 43.838892, lng: -79.084382 },
-Marker { lat: 43.8006531, lng: -79.3051929 },
-Marker { lat: 43.7582903, lng: -79.4070459 },
-Marker { lat: 43.5592774, lng: -79.6394023 },
-Marker { lat: 43.8575094, lng: -79.3186998 },
-Marker { lat: 43.762598, lng: -79.3938 },
-Marker { lat: 43.8243568, lng: -79.425407 },
-Marker { lat: 43.7247945, lng: -79.2353277 },
-Marker { lat: 43.848113, lng: -79.478862 },
-Marker { lat: 43.9520637, lng: -78.9472426 },
-Marker { lat: 43.7778723, lng: -79.2807506 },
-Marker { lat: 43.7350429, lng: -79.4208381 },
-Marker { lat: 43.7800627, lng: -79.3930053 },
-Marker { lat: 43.6982888, lng: -79.5480021 },
-Marker { lat: 43.8391533, lng: -79.3859029 },
-Marker { lat: 43.68596, lng: -79.853966 },
-Marker { lat: 43.4848211, lng: -79.7302319 },
-Marker { lat: 43.6986033, lng: -79.2715347 },
-Marker { lat: 43.7438205, lng: -79.6017924 },
-Marker { lat: 43.8565979, lng: -79.4960695 },
-Marker { lat: 43.7783322, lng: -79.2825717 },
-Marker { lat: 43.752406, lng: -79.454611 },
-Marker { lat: 43.5944489, lng: -79.6346157 },
-Marker { lat: 43.7377965, lng: -79.3055942 },
-Marker { lat: 43.7476968, lng: -79.2138714 },
-Marker { lat: 43.4537817, lng: -79.7523708 },
-Marker { lat: 43.7803283, lng: -79.4481677 },
-Marker { lat: 43.5706038, lng: -79.7428689 },
-Marker { lat: 43.6882394, lng: -79.4383422 },
-Marker { lat: 43.5856516, lng: -79.7876062 },
-Marker { lat: 43.6295841, lng: -79.4908855 },
-Marker { lat: 43.7149634, lng: -79.5430499 },
-Marker { lat: 43.682004, lng: -79.843868 },
-Marker { lat: 43.801543, lng: -79.73444 },
-Marker { lat: 43.5213571, lng: -79.8416424 },
-Marker { lat: 43.555983, lng: -79.582205 },
-Marker { lat: 43.9784137, lng: -79.2365 },
-Marker { lat: 43.4089957, lng: -79.699805 },
-Marker { lat: 43.530807, lng: -79.845634 },
-Marker { lat: 43.6629571, lng: -79.4610823 },
-Marker { lat: 43.641806, lng: -79.403004 },
-Marker { lat: 43.6848879, lng: -79.7911692 },
-Marker { lat: 43.586832, lng: -79.791553 },
-Marker { lat: 43.779746, lng: -79.4181433 },
-Marker { lat: 43.624995, lng: -79.479045 },
-Marker { lat: 43.8406893, lng: -79.4670326 },
-Marker { lat: 43.6466606, lng: -79.438819 },
-Marker { lat: 43.7710571, lng: -79.7541126 },
-Marker { lat: 43.858252, lng: -79.433621 },
-Marker { lat: 43.7648322, lng: -79.4339059 },
-Marker { lat: 43.6951904, lng: -79.4095862 },
-Marker { lat: 43.594882, lng: -79.634317 },
-Marker { lat: 43.843516, lng: -79.426394 },
-Marker { lat: 43.7985645, lng: -79.5835534 },
-Marker { lat: 43.3575904, lng: -79.8133894 },
-Marker { lat: 43.6710104, lng: -79.4496401 },
-Marker { lat: 43.6240514, lng: -79.5277116 },
-Marker { lat: 43.9070205, lng: -79.6561078 },
-Marker { lat: 43.5890786, lng: -79.7516318 },
-Marker { lat: 43.876851, lng: -79.032551 },
-Marker { lat: 43.8532467, lng: -79.5252063 },
-Marker { lat: 43.9687697, lng: -79.2665561 },
-Marker { lat: 43.8701389, lng: -79.3014085 },
-Marker { lat: 43.9457675, lng: -79.4825387 },
-Marker { lat: 43.5605185, lng: -79.7243702 },
-Marker { lat: 43.833032, lng: -79.565129 },
-Marker { lat: 43.8247986, lng: -79.5789158 },
-Marker { lat: 43.5814259, lng: -79.7228 },
-Marker { lat: 43.4080561, lng: -79.7975075 },
-Marker { lat: 43.6884856, lng: -79.2668986 },
-Marker { lat: 43.885547, lng: -79.012727 },
-Marker { lat: 43.8043953, lng: -79.405691 },
-Marker { lat: 43.4093686, lng: -79.8170909 },
-Marker { lat: 43.641579, lng: -79.415903 },
-Marker { lat: 43.3776672, lng: -79.7932763 },
-Marker { lat: 43.6037091, lng: -79.7316222 },
-Marker { lat: 43.648687, lng: -79.9159 },
-Marker { lat: 43.669301, lng: -79.384171 },
-Marker { lat: 43.6445412, lng: -79.3764773 },
-Marker { lat: 43.7751229, lng: -79.4138309 },
-Marker { lat: 43.7627348, lng: -79.3464967 },
-Marker { lat: 43.7300903, lng: -79.3322682 },
-Marker { lat: 43.6846135, lng: -79.3569988 },
-Marker { lat: 43.6866108, lng: -79.2872095 },
-Marker { lat: 43.6599507, lng: -79.3849463 },
-Marker { lat: 43.6440617, lng: -79.3894386 },
-Marker { lat: 43.643012, lng: -79.406201 },
-Marker { lat: 43.648783, lng: -79.388042 },
-Marker { lat: 43.6522442, lng: -79.3776386 },
-Marker { lat: 43.752094, lng: -79.297182 },
-Marker { lat: 43.852094, lng: -79.432423 },
-Marker { lat: 43.581187, lng: -79.647617 },
-Marker { lat: 43.5491973, lng: -79.7200968 },
-Marker { lat: 43.479968, lng: -79.712859 },
-Marker { lat: 43.7506974, lng: -79.4650506 },
-Marker { lat: 43.8666211, lng: -79.2943904 },
-Marker { lat: 43.6548441, lng: -79.3846797 },
-Marker { lat: 43.660927, lng: -79.5345097 },
-Marker { lat: 43.81121, lng: -79.102616 },
-Marker { lat: 43.8128057, lng: -79.4519464 },
-Marker { lat: 43.6394695, lng: -79.3850744 },
-Marker { lat: 43.814434, lng: -79.104712 },
-Marker { lat: 43.6539796, lng: -79.3885197 },
-Marker { lat: 43.7070855, lng: -79.3977452 },
-Marker { lat: 43.3832987, lng: -79.8039491 },
-Marker { lat: 43.8529663, lng: -79.5721764 },
-Marker { lat: 43.649256, lng: -79.375765 },
-Marker { lat: 43.772018, lng: -79.4208548 },
-Marker { lat: 43.6514779, lng: -79.7817386 },
-Marker { lat: 43.73493, lng: -79.463639 },
-Marker { lat: 43.685494, lng: -79.414432 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.9196978, lng: -78.9142853 },
-Marker { lat: 43.7245989, lng: -79.5518205 },
-Marker { lat: 43.79326, lng: -79.137192 },
-Marker { lat: 43.7454584, lng: -79.3670288 },
-Marker { lat: 43.8985155, lng: -79.4516208 },
-Marker { lat: 43.762714, lng: -79.346424 },
-Marker { lat: 43.3961572, lng: -79.8043383 },
-Marker { lat: 43.4399016, lng: -79.739501 },
-Marker { lat: 43.7588282, lng: -79.4464761 },
-Marker { lat: 43.6180336, lng: -79.6049525 },
-Marker { lat: 43.70155, lng: -79.858744 },
-Marker { lat: 43.9533, lng: -79.4518135 },
-Marker { lat: 43.749148, lng: -79.2263315 },
-Marker { lat: 43.774865, lng: -79.313528 },
-Marker { lat: 43.700394, lng: -79.845346 },
-Marker { lat: 43.6976794, lng: -79.3097607 },
-Marker { lat: 43.8002725, lng: -79.3737175 },
-Marker { lat: 43.804698, lng: -79.2997868 },
-Marker { lat: 43.881959, lng: -79.030372 },
-Marker { lat: 43.9573388, lng: -79.4677698 },
-Marker { lat: 43.8564417, lng: -79.3251868 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.766751, lng: -79.410332 },
-Marker { lat: 43.7949078, lng: -79.3139676 },
-Marker { lat: 43.6881075, lng: -79.3979987 },
-Marker { lat: 43.7211935, lng: -79.2480273 },
-Marker { lat: 43.70465, lng: -79.7064213 },
-Marker { lat: 43.6393446, lng: -79.3929716 },
-Marker { lat: 43.792559, lng: -79.449828 },
-Marker { lat: 43.7817207, lng: -79.2473686 },
-Marker { lat: 43.7502504, lng: -79.7529767 },
-Marker { lat: 43.929264, lng: -78.887778 },
-Marker { lat: 43.6833566, lng: -79.4186353 },
-Marker { lat: 43.4857301, lng: -79.7097265 },
-Marker { lat: 43.7244264, lng: -79.6259024 },
-Marker { lat: 43.7931132, lng: -79.3485898 },
-Marker { lat: 43.9114898, lng: -79.6629989 },
-Marker { lat: 43.7916822, lng: -79.3847338 },
-Marker { lat: 43.8983359, lng: -79.4470063 },
-Marker { lat: 43.640078, lng: -79.928558 },
-Marker { lat: 43.479958, lng: -79.726695 },
-Marker { lat: 43.8422158, lng: -79.7812865 },
-Marker { lat: 43.7052154, lng: -79.5268021 },
-Marker { lat: 43.3939455, lng: -79.7132383 },
-Marker { lat: 43.6896326, lng: -79.2683731 },
-Marker { lat: 43.7753462, lng: -79.4018025 },
-Marker { lat: 43.5626228, lng: -79.7348677 },
-Marker { lat: 43.8930246, lng: -79.4338708 },
-Marker { lat: 43.6695135, lng: -79.4224707 },
-Marker { lat: 43.7033227, lng: -79.3998916 },
-Marker { lat: 43.6896304, lng: -79.3706227 },
-Marker { lat: 43.646434, lng: -79.397806 },
-Marker { lat: 43.645077, lng: -79.364342 },
-Marker { lat: 43.6575162, lng: -79.3767709 },
-Marker { lat: 43.4717578, lng: -79.6896302 },
-Marker { lat: 43.8586114, lng: -79.3240221 },
-Marker { lat: 43.746555, lng: -79.5629067 },
-Marker { lat: 43.8231751, lng: -79.3717502 },
-Marker { lat: 43.9823032, lng: -79.2368413 },
-Marker { lat: 43.6332403, lng: -79.5982495 },
-Marker { lat: 43.9628951, lng: -78.9098074 },
-Marker { lat: 43.887104, lng: -78.920092 },
-Marker { lat: 43.4116145, lng: -79.7114053 },
-Marker { lat: 43.6062627, lng: -79.6806568 },
-Marker { lat: 43.6702252, lng: -79.3817578 },
-Marker { lat: 43.7872283, lng: -79.1946802 },
-Marker { lat: 43.5488926, lng: -79.7455016 },
-Marker { lat: 43.8554741, lng: -79.5645114 },
-Marker { lat: 43.7905089, lng: -79.7821717 },
-Marker { lat: 43.8965, lng: -78.8965 },
-Marker { lat: 43.5947307, lng: -79.6352183 },
-Marker { lat: 43.6628495, lng: -79.3762687 },
-Marker { lat: 43.6654978, lng: -79.3672247 },
-Marker { lat: 43.8004023, lng: -79.3219642 },
-Marker { lat: 43.9085206, lng: -78.9072092 },
-Marker { lat: 43.970586, lng: -79.244284 },
-Marker { lat: 43.7219643, lng: -79.8254379 },
-Marker { lat: 43.8498245, lng: -79.4259626 },
-Marker { lat: 43.654587, lng: -79.386656 },
-Marker { lat: 43.7842304, lng: -79.1713827 },
-Marker { lat: 43.7134604, lng: -79.4199252 },
-Marker { lat: 43.6736696, lng: -79.8166034 },
-Marker { lat: 43.9081825, lng: -79.4316069 },
-Marker { lat: 43.8476604, lng: -79.5121181 },
-Marker { lat: 43.9219727, lng: -78.9244036 },
-Marker { lat: 43.9017429, lng: -79.2312897 },
-Marker { lat: 43.6902468, lng: -79.788643 },
-Marker { lat: 43.7667355, lng: -79.4234375 },
-Marker { lat: 43.7035255, lng: -79.2940227 },
-Marker { lat: 43.4571396, lng: -79.6576673 },
-Marker { lat: 43.7373096, lng: -79.3066342 },
-Marker { lat: 43.6801314, lng: -79.7590658 },
-Marker { lat: 43.8930769, lng: -79.3757918 },
-Marker { lat: 43.5682369, lng: -79.6213603 },
-Marker { lat: 43.7443073, lng: -79.4334042 },
-Marker { lat: 43.3523156, lng: -79.7831415 },
-Marker { lat: 43.8416693, lng: -79.4767782 },
-Marker { lat: 43.8859854, lng: -79.2284435 },
-Marker { lat: 43.9405808, lng: -79.4267268 },
-Marker { lat: 43.8465879, lng: -79.0958771 },
-Marker { lat: 43.516763, lng: -79.865347 },
-Marker { lat: 43.648369, lng: -79.376601 },
-Marker { lat: 43.5763205, lng: -79.676517 },
-Marker { lat: 43.5126327, lng: -79.6309339 },
-Marker { lat: 43.4334059, lng: -79.7289973 },
-Marker { lat: 43.8028037, lng: -79.4016187 },
-Marker { lat: 43.8669352, lng: -79.2159245 },
-Marker { lat: 43.4074138, lng: -79.8227631 },
-Marker { lat: 43.6134376, lng: -79.6391991 },
-Marker { lat: 43.67025, lng: -79.385924 },
-Marker { lat: 43.76115, lng: -79.788257 },
-Marker { lat: 43.841845, lng: -79.032281 },
-Marker { lat: 43.779588, lng: -79.748509 },
-Marker { lat: 43.4189833, lng: -79.7053 },
-Marker { lat: 43.367985, lng: -79.8198147 },
-Marker { lat: 43.3589266, lng: -79.8427286 },
-Marker { lat: 43.661401, lng: -79.38091 },
-Marker { lat: 43.775801, lng: -79.182197 },
-Marker { lat: 43.4711211, lng: -79.7485849 },
-Marker { lat: 43.6429141, lng: -79.3806469 },
-Marker { lat: 43.666751, lng: -79.376235 },
-Marker { lat: 43.5550963, lng: -79.7317985 },
-Marker { lat: 43.7413445, lng: -79.4158105 },
-Marker { lat: 43.7589, lng: -79.688582 },
-Marker { lat: 43.652008, lng: -79.4495376 },
-Marker { lat: 43.9392041, lng: -79.4567374 },
-Marker { lat: 43.8410876, lng: -79.405166 },
-Marker { lat: 43.863295, lng: -79.447799 },
-Marker { lat: 43.9488503, lng: -79.461149 },
-Marker { lat: 43.4381983, lng: -79.7469338 },
-Marker { lat: 43.374053, lng: -79.721288 },
-Marker { lat: 43.7028015, lng: -79.4513882 },
-Marker { lat: 43.9164543, lng: -78.9093675 },
-Marker { lat: 43.6440617, lng: -79.3894386 },
-Marker { lat: 43.5956797, lng: -79.6347654 },
-Marker { lat: 43.474318, lng: -79.64438 },
-Marker { lat: 43.4969291, lng: -79.704724 },
-Marker { lat: 43.4949853, lng: -79.6886521 },
-Marker { lat: 43.403161, lng: -79.820086 },
-Marker { lat: 43.727577, lng: -79.751225 },
-Marker { lat: 43.6243335, lng: -79.485323 },
-Marker { lat: 43.633803, lng: -79.87001 },
-Marker { lat: 43.6451235, lng: -79.402811 },
-Marker { lat: 43.7467948, lng: -79.3762964 },
-Marker { lat: 43.696892, lng: -79.4243228 },
-Marker { lat: 43.8280667, lng: -79.4362287 },
-Marker { lat: 43.8635065, lng: -79.2515481 },
-Marker { lat: 43.6279274, lng: -79.4781622 },
-Marker { lat: 43.3992143, lng: -79.7077282 },
-Marker { lat: 43.8954886, lng: -79.3731473 },
-Marker { lat: 43.4410008, lng: -79.6787715 },
-Marker { lat: 43.8968262, lng: -79.4246916 },
-Marker { lat: 43.7341056, lng: -79.6378743 },
-Marker { lat: 43.9451324, lng: -79.4648839 },
-Marker { lat: 43.5897545, lng: -79.6480938 },
-Marker { lat: 43.7013763, lng: -79.2648 },
-Marker { lat: 43.7031829, lng: -79.4509291 },
-Marker { lat: 43.6986823, lng: -79.5537027 },
-Marker { lat: 43.6592713, lng: -79.3849163 },
-Marker { lat: 43.8729042, lng: -79.4275302 },
-Marker { lat: 43.7642862, lng: -79.3441063 },
-Marker { lat: 43.6560406, lng: -79.3631568 },
-Marker { lat: 43.7767974, lng: -79.1530293 },
-Marker { lat: 43.8081133, lng: -79.2317736 },
-Marker { lat: 43.863524, lng: -79.029654 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.8007128, lng: -79.3170366 },
-Marker { lat: 43.9111604, lng: -79.4388427 },
-Marker { lat: 43.8406631, lng: -79.4667506 },
-Marker { lat: 43.6460836, lng: -79.3650117 },
-Marker { lat: 43.7260965, lng: -79.4796815 },
-Marker { lat: 43.6028557, lng: -79.517091 },
-Marker { lat: 43.644864, lng: -79.39906 },
-Marker { lat: 43.78189, lng: -79.595788 },
-Marker { lat: 43.4460732, lng: -79.7637167 },
-Marker { lat: 43.6398031, lng: -79.4139432 },
-Marker { lat: 43.826744, lng: -79.091001 },
-Marker { lat: 43.6475103, lng: -79.3927254 },
-Marker { lat: 43.7357289, lng: -79.7552608 },
-Marker { lat: 43.5843282, lng: -79.5969674 },
-Marker { lat: 43.8652838, lng: -79.0424179 },
-Marker { lat: 43.7403616, lng: -79.238065 },
-Marker { lat: 43.6085, lng: -79.5066 },
-Marker { lat: 43.8776809, lng: -79.2339602 },
-Marker { lat: 43.736284, lng: -79.8215483 },
-Marker { lat: 43.779321, lng: -79.413712 },
-Marker { lat: 43.6399004, lng: -79.7417617 },
-Marker { lat: 43.866093, lng: -79.439199 },
-Marker { lat: 43.4190769, lng: -79.6858089 },
-Marker { lat: 43.6352427, lng: -79.7117053 },
-Marker { lat: 43.6400887, lng: -79.3939574 },
-Marker { lat: 43.778765, lng: -79.417903 },
-Marker { lat: 43.7692297, lng: -79.411935 },
-Marker { lat: 43.669461, lng: -79.295065 },
-Marker { lat: 43.638513, lng: -79.400897 },
-Marker { lat: 43.75225, lng: -79.45571 },
-Marker { lat: 43.594913, lng: -79.635736 },
-Marker { lat: 43.532856, lng: -79.6215529 },
-Marker { lat: 43.5874623, lng: -79.6414072 },
-Marker { lat: 43.9571256, lng: -79.4819402 },
-Marker { lat: 43.8375818, lng: -79.1295289 },
-Marker { lat: 43.767123, lng: -79.383185 },
-Marker { lat: 43.622729, lng: -79.48319 },
-Marker { lat: 43.9061381, lng: -79.4497531 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.4062308, lng: -79.7113983 },
-Marker { lat: 43.7173319, lng: -79.3595986 },
-Marker { lat: 43.7076183, lng: -79.811398 },
-Marker { lat: 43.730849, lng: -79.714086 },
-Marker { lat: 43.79493, lng: -79.313943 },
-Marker { lat: 43.683176, lng: -79.4861647 },
-Marker { lat: 43.7389387, lng: -79.5827134 },
-Marker { lat: 43.8739101, lng: -79.4490594 },
-Marker { lat: 43.3881226, lng: -79.8894165 },
-Marker { lat: 43.6387203, lng: -79.5115546 },
-Marker { lat: 43.9042886, lng: -79.6418098 },
-Marker { lat: 43.708008, lng: -79.400785 },
-Marker { lat: 43.7893778, lng: -79.4275055 },
-Marker { lat: 43.835544, lng: -79.108044 },
-Marker { lat: 43.7723462, lng: -79.4063005 },
-Marker { lat: 43.4512793, lng: -79.7362915 },
-Marker { lat: 43.8467022, lng: -79.5155581 },
-Marker { lat: 43.8870243, lng: -79.3372246 },
-Marker { lat: 43.3931614, lng: -79.7884102 },
-Marker { lat: 43.977105, lng: -79.2450586 },
-Marker { lat: 43.8860043, lng: -78.9699265 },
-Marker { lat: 43.8476757, lng: -79.3802375 },
-Marker { lat: 43.9332452, lng: -79.5521789 },
-Marker { lat: 43.782769, lng: -79.431291 },
-Marker { lat: 43.794242, lng: -79.524434 },
-Marker { lat: 43.8089068, lng: -79.2284012 },
-Marker { lat: 43.7657266, lng: -79.4415531 },
-Marker { lat: 43.524102, lng: -79.841486 },
-Marker { lat: 43.9090089, lng: -78.9140607 },
-Marker { lat: 43.7940724, lng: -79.4407676 },
-Marker { lat: 43.8710654, lng: -79.0695096 },
-Marker { lat: 43.7396054, lng: -79.7526261 },
-Marker { lat: 43.706291, lng: -79.392573 },
-Marker { lat: 43.4747702, lng: -79.6606415 },
-Marker { lat: 43.4740407, lng: -79.737348 },
-Marker { lat: 43.399397, lng: -79.7083418 },
-Marker { lat: 43.9609619, lng: -79.258437 },
-Marker { lat: 43.8593994, lng: -79.2938863 },
-Marker { lat: 43.7247863, lng: -79.2556789 },
-Marker { lat: 43.649759, lng: -79.928177 },
-Marker { lat: 43.636057, lng: -79.470781 },
-Marker { lat: 43.5952453, lng: -79.5342625 },
-Marker { lat: 43.7698441, lng: -79.409455 },
-Marker { lat: 43.4426282, lng: -79.6723596 },
-Marker { lat: 43.931493, lng: -79.5313864 },
-Marker { lat: 43.6400548, lng: -79.4938009 },
-Marker { lat: 43.758071, lng: -79.403165 },
-Marker { lat: 43.5188156, lng: -79.9291684 },
-Marker { lat: 43.833059, lng: -79.532424 },
-Marker { lat: 43.6042625, lng: -79.5360879 },
-Marker { lat: 43.460707, lng: -79.704644 },
-Marker { lat: 43.7754722, lng: -79.2195397 },
-Marker { lat: 43.5060521, lng: -79.642427 },
-Marker { lat: 43.652508, lng: -79.3885848 },
-Marker { lat: 43.8954886, lng: -79.3731473 },
-Marker { lat: 43.5225199, lng: -79.6040191 },
-Marker { lat: 43.877212, lng: -79.05649 },
-Marker { lat: 43.5678026, lng: -79.7235325 },
-Marker { lat: 43.7739867, lng: -79.3595046 },
-Marker { lat: 43.5470587, lng: -79.5986904 },
-Marker { lat: 43.5948677, lng: -79.6049147 },
-Marker { lat: 43.656806, lng: -79.375092 },
-Marker { lat: 43.7016605, lng: -79.4852617 },
-Marker { lat: 43.8583822, lng: -78.9470359 },
-Marker { lat: 43.7813959, lng: -79.4135743 },
-Marker { lat: 43.8894617, lng: -79.3944839 },
-Marker { lat: 43.6656459, lng: -79.3859491 },
-Marker { lat: 43.8745759, lng: -79.4474349 },
-Marker { lat: 43.6029586, lng: -79.6911568 },
-Marker { lat: 43.668718, lng: -79.383996 },
-Marker { lat: 43.7800398, lng: -79.4126795 },
-Marker { lat: 43.801665, lng: -79.674593 },
-Marker { lat: 43.6966073, lng: -79.2942117 },
-Marker { lat: 43.669879, lng: -79.385009 },
-Marker { lat: 43.757501, lng: -79.409225 },
-Marker { lat: 43.669301, lng: -79.384171 },
-Marker { lat: 43.6400003, lng: -79.3788904 },
-Marker { lat: 43.970267, lng: -79.263706 },
-Marker { lat: 43.5861922, lng: -79.6429407 },
-Marker { lat: 43.6595987, lng: -79.7640774 },
-Marker { lat: 43.6289027, lng: -79.7896781 },
-Marker { lat: 43.6385781, lng: -79.3993661 },
-Marker { lat: 43.5748, lng: -79.7214 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.6251221, lng: -79.478823 },
-Marker { lat: 43.9768744, lng: -79.258244 },
-Marker { lat: 43.7272339, lng: -79.8009218 },
-Marker { lat: 43.950513, lng: -79.423624 },
-Marker { lat: 43.9239455, lng: -78.9296541 },
-Marker { lat: 43.90256, lng: -78.947763 },
-Marker { lat: 43.768151, lng: -79.390791 },
-Marker { lat: 43.626884, lng: -79.7045448 },
-Marker { lat: 43.4867886, lng: -79.8670315 },
-Marker { lat: 43.5945672, lng: -79.6887741 },
-Marker { lat: 43.6404948, lng: -79.3808155 },
-Marker { lat: 43.7504348, lng: -79.3736576 },
-Marker { lat: 43.6625248, lng: -79.3400404 },
-Marker { lat: 43.8563585, lng: -78.946274 },
-Marker { lat: 43.828794, lng: -79.002218 },
-Marker { lat: 43.7081002, lng: -79.8180854 },
-Marker { lat: 43.5345352, lng: -79.8599404 },
-Marker { lat: 43.586211, lng: -79.64293 },
-Marker { lat: 43.422984, lng: -79.938387 },
-Marker { lat: 43.865893, lng: -79.235518 },
-Marker { lat: 43.5941, lng: -79.7437 },
-Marker { lat: 43.8091676, lng: -79.455206 },
-Marker { lat: 43.8155617, lng: -79.0825462 },
-Marker { lat: 43.8650534, lng: -79.2216923 },
-Marker { lat: 43.802845, lng: -79.585896 },
-Marker { lat: 43.8999734, lng: -78.8940108 },
-Marker { lat: 43.7341315, lng: -79.268091 },
-Marker { lat: 43.8800746, lng: -79.2859721 },
-Marker { lat: 43.9299495, lng: -79.4781005 },
-Marker { lat: 43.854717, lng: -79.539701 },
-Marker { lat: 43.7429376, lng: -79.8193144 },
-Marker { lat: 43.5907831, lng: -79.5613018 },
-Marker { lat: 43.561803, lng: -79.7126716 },
-Marker { lat: 43.4337966, lng: -79.6921229 },
-Marker { lat: 43.6078198, lng: -79.4912655 },
-Marker { lat: 43.6941679, lng: -79.4691066 },
-Marker { lat: 43.6867268, lng: -79.7454334 },
-Marker { lat: 43.6956176, lng: -79.5538552 },
-Marker { lat: 43.640285, lng: -79.559817 },
-Marker { lat: 43.6493542, lng: -79.4795714 },
-Marker { lat: 43.6815369, lng: -79.4899844 },
-Marker { lat: 43.483614, lng: -79.839783 },
-Marker { lat: 43.751781, lng: -79.843774 },
-Marker { lat: 43.6402022, lng: -79.5593652 },
-Marker { lat: 43.7015228, lng: -79.8375858 },
-Marker { lat: 43.5079692, lng: -79.6215126 },
-Marker { lat: 43.6322677, lng: -79.7678705 },
-Marker { lat: 43.6819191, lng: -79.7966068 },
-Marker { lat: 43.623289, lng: -79.483695 },
-Marker { lat: 43.6103928, lng: -79.6614215 },
-Marker { lat: 43.5876077, lng: -79.7873495 },
-Marker { lat: 43.7179076, lng: -79.6255816 },
-Marker { lat: 43.7428168, lng: -79.7639405 },
-Marker { lat: 43.8995658, lng: -79.219362 },
-Marker { lat: 43.6971531, lng: -79.8393511 },
-Marker { lat: 43.4899618, lng: -79.8525978 },
-Marker { lat: 43.6771024, lng: -79.3766691 },
-Marker { lat: 43.6782715, lng: -79.3443713 },
-Marker { lat: 43.8458404, lng: -78.9989636 },
-Marker { lat: 43.680733, lng: -79.28772 },
-Marker { lat: 43.590391, lng: -79.628548 },
-Marker { lat: 43.66703, lng: -79.376386 },
-Marker { lat: 43.7044614, lng: -79.3699101 },
-Marker { lat: 43.6834734, lng: -79.4633101 },
-Marker { lat: 43.875512, lng: -79.120966 },
-Marker { lat: 43.8541241, lng: -79.4268381 },
-Marker { lat: 43.7536416, lng: -79.7538928 },
-Marker { lat: 43.6241314, lng: -79.4880676 },
-Marker { lat: 43.6752013, lng: -79.7812877 },
-Marker { lat: 43.450892, lng: -79.705464 },
-Marker { lat: 43.7238651, lng: -79.7819717 },
-Marker { lat: 43.7896333, lng: -79.7380051 },
-Marker { lat: 43.3730238, lng: -79.8388131 },
-Marker { lat: 43.3819062, lng: -79.7358635 },
-Marker { lat: 43.7485427, lng: -79.7723143 },
-Marker { lat: 43.8469842, lng: -79.5666433 },
-Marker { lat: 43.674717, lng: -79.430682 },
-Marker { lat: 43.6410594, lng: -79.4461529 },
-Marker { lat: 43.4279897, lng: -79.7499778 },
-Marker { lat: 43.6383872, lng: -79.4010639 },
-Marker { lat: 43.427839, lng: -79.752518 },
-Marker { lat: 43.580457, lng: -79.747192 },
-Marker { lat: 43.4837779, lng: -79.8398583 },
-Marker { lat: 43.6517024, lng: -79.3747876 },
-Marker { lat: 43.774071, lng: -79.329324 },
-Marker { lat: 43.684626, lng: -79.398302 },
-Marker { lat: 43.777401, lng: -79.412802 },
-Marker { lat: 43.6859084, lng: -79.3941337 },
-Marker { lat: 43.903929, lng: -79.401161 },
-Marker { lat: 43.7783428, lng: -79.4159581 },
-Marker { lat: 43.5890411, lng: -79.6495976 },
-Marker { lat: 43.5166262, lng: -79.8628156 },
-Marker { lat: 43.468073, lng: -79.7450595 },
-Marker { lat: 43.58838, lng: -79.639953 },
-Marker { lat: 43.561339, lng: -79.6027745 },
-Marker { lat: 43.83071, lng: -79.4194337 },
-Marker { lat: 43.7070826, lng: -79.3977712 },
-Marker { lat: 43.8659617, lng: -79.4490198 },
-Marker { lat: 43.626966, lng: -79.478488 },
-Marker { lat: 43.8665769, lng: -79.3372021 },
-Marker { lat: 43.650405, lng: -79.392168 },
-Marker { lat: 43.6924039, lng: -79.3132605 },
-Marker { lat: 43.970816, lng: -79.249128 },
-Marker { lat: 43.643939, lng: -79.401477 },
-Marker { lat: 43.7295847, lng: -79.4244916 },
-Marker { lat: 43.707098, lng: -79.39508 },
-Marker { lat: 43.827363, lng: -79.6505997 },
-Marker { lat: 43.6388334, lng: -79.3988388 },
-Marker { lat: 43.848616, lng: -79.048902 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.9694907, lng: -79.4772885 },
-Marker { lat: 43.4206713, lng: -79.7049524 },
-Marker { lat: 43.5905171, lng: -79.628811 },
-Marker { lat: 43.661725, lng: -79.384128 },
-Marker { lat: 43.9769805, lng: -79.4073853 },
-Marker { lat: 43.667162, lng: -79.3735007 },
-Marker { lat: 43.5187, lng: -79.8761 },
-Marker { lat: 43.525403, lng: -79.860402 },
-Marker { lat: 43.637524, lng: -79.400877 },
-Marker { lat: 43.6591293, lng: -79.4098165 },
-Marker { lat: 43.488196, lng: -79.876802 },
-Marker { lat: 43.6197166, lng: -79.8841008 },
-Marker { lat: 43.7707253, lng: -79.4332638 },
-Marker { lat: 43.9038519, lng: -78.9706114 },
-Marker { lat: 43.4051468, lng: -79.7260997 },
-Marker { lat: 43.6570646, lng: -79.7583659 },
-Marker { lat: 43.6964648, lng: -79.8604467 },
-Marker { lat: 43.7065117, lng: -79.4132715 },
-Marker { lat: 43.754427, lng: -79.444778 },
-Marker { lat: 43.7311713, lng: -79.5685464 },
-Marker { lat: 43.4968504, lng: -79.6929681 },
-Marker { lat: 43.7790064, lng: -79.4336058 },
-Marker { lat: 43.6720296, lng: -79.3798098 },
-Marker { lat: 43.896918, lng: -78.886429 },
-Marker { lat: 43.9259744, lng: -79.5278258 },
-Marker { lat: 43.8701828, lng: -79.4645518 },
-Marker { lat: 43.8800614, lng: -78.9692371 },
-Marker { lat: 43.9072933, lng: -78.9279286 },
-Marker { lat: 43.8031782, lng: -79.3541414 },
-Marker { lat: 43.6740094, lng: -79.4124294 },
-Marker { lat: 43.6871115, lng: -79.4241354 },
-Marker { lat: 43.7832366, lng: -79.3702475 },
-Marker { lat: 43.6367062, lng: -79.4000807 },
-Marker { lat: 43.641087, lng: -79.381405 },
-Marker { lat: 43.9060379, lng: -78.9037684 },
-Marker { lat: 43.7780331, lng: -79.1588032 },
-Marker { lat: 43.7642862, lng: -79.3441063 },
-Marker { lat: 43.7117927, lng: -79.2562122 },
-Marker { lat: 43.7158967, lng: -79.2791716 },
-Marker { lat: 43.83938, lng: -79.088197 },
-Marker { lat: 43.8941806, lng: -78.9184509 },
-Marker { lat: 43.911477, lng: -78.9184026 },
-Marker { lat: 43.6575906, lng: -79.3389784 },
-Marker { lat: 43.7805353, lng: -79.2510063 },
-Marker { lat: 43.8127933, lng: -79.4240875 },
-Marker { lat: 43.9713613, lng: -79.259949 },
-Marker { lat: 43.6575992, lng: -79.3498961 },
-Marker { lat: 43.665299, lng: -79.341231 },
-Marker { lat: 43.848844, lng: -79.4743494 },
-Marker { lat: 43.3682117, lng: -79.8464461 },
-Marker { lat: 43.646006, lng: -79.389908 },
-Marker { lat: 43.760304, lng: -79.41162 },
-Marker { lat: 43.730304, lng: -79.4411185 },
-Marker { lat: 43.6425128, lng: -79.3813182 },
-Marker { lat: 43.648879, lng: -79.3880443 },
-Marker { lat: 43.7844008, lng: -79.288555 },
-Marker { lat: 43.7254686, lng: -79.3917967 },
-Marker { lat: 43.4194334, lng: -79.701612 },
-Marker { lat: 43.433141, lng: -79.770423 },
-Marker { lat: 43.758421, lng: -79.422073 },
-Marker { lat: 43.938036, lng: -79.4321152 },
-Marker { lat: 43.681516, lng: -79.7455618 },
-Marker { lat: 43.9687748, lng: -79.4818464 },
-Marker { lat: 43.649292, lng: -79.93197 },
-Marker { lat: 43.8131214, lng: -79.4545306 },
-Marker { lat: 43.623124, lng: -79.886156 },
-Marker { lat: 43.6960795, lng: -79.7782895 },
-Marker { lat: 43.447189, lng: -79.669002 },
-Marker { lat: 43.646885, lng: -79.925432 },
-Marker { lat: 43.5255217, lng: -79.8604228 },
-Marker { lat: 43.3931614, lng: -79.7884102 },
-Marker { lat: 43.4211812, lng: -79.7617046 },
-Marker { lat: 43.5105079, lng: -79.9057925 },
-Marker { lat: 43.7018936, lng: -79.8383179 },
-Marker { lat: 43.4635594, lng: -79.6475377 },
-Marker { lat: 43.6600002, lng: -79.4517988 },
-Marker { lat: 43.4927789, lng: -79.6587051 },
-Marker { lat: 43.406445, lng: -79.8214555 },
-Marker { lat: 43.6383693, lng: -79.3986981 },
-Marker { lat: 43.6707607, lng: -79.3773018 },
-Marker { lat: 43.5495979, lng: -79.7196551 },
-Marker { lat: 43.8704713, lng: -79.4134306 },
-Marker { lat: 43.6620882, lng: -79.3856802 },
-Marker { lat: 43.4358151, lng: -79.7184366 },
-Marker { lat: 43.781809, lng: -79.3820298 },
-Marker { lat: 43.5480257, lng: -79.6225518 },
-Marker { lat: 43.705218, lng: -79.361653 },
-Marker { lat: 43.852094, lng: -79.432423 },
-Marker { lat: 43.7338186, lng: -79.346789 },
-Marker { lat: 43.610767, lng: -79.65335 },
-Marker { lat: 43.7972588, lng: -79.4010257 },
-Marker { lat: 43.9620694, lng: -79.4422967 },
-Marker { lat: 43.8407403, lng: -79.6486134 },
-Marker { lat: 43.5606547, lng: -79.6059811 },
-Marker { lat: 43.4793503, lng: -79.6385056 },
-Marker { lat: 43.70529, lng: -79.397827 },
-Marker { lat: 43.413464, lng: -79.800437 },
-Marker { lat: 43.6239412, lng: -79.5243608 },
-Marker { lat: 43.6659594, lng: -79.7717436 },
-Marker { lat: 43.5053997, lng: -79.8547261 },
-Marker { lat: 43.846748, lng: -79.476694 },
-Marker { lat: 43.7958074, lng: -79.3251799 },
-Marker { lat: 43.779718, lng: -79.342657 },
-Marker { lat: 43.623289, lng: -79.483695 },
-Marker { lat: 43.63952, lng: -79.890359 },
-Marker { lat: 43.653068, lng: -79.760674 },
-Marker { lat: 43.9536999, lng: -78.8970862 },
-Marker { lat: 43.763873, lng: -79.405766 },
-Marker { lat: 43.623289, lng: -79.483695 },
-Marker { lat: 43.851506, lng: -79.067217 },
-Marker { lat: 43.810531, lng: -79.3706978 },
-Marker { lat: 43.7805216, lng: -79.4007203 },
-Marker { lat: 43.8939431, lng: -79.5006582 },
-Marker { lat: 43.8992778, lng: -79.2850316 },
-Marker { lat: 43.4799969, lng: -79.7266097 },
-Marker { lat: 43.7490152, lng: -79.406243 },
-Marker { lat: 43.585748, lng: -79.648916 },
-Marker { lat: 43.8972899, lng: -78.9257416 },
-Marker { lat: 43.6882719, lng: -79.4278246 },
-Marker { lat: 43.8768815, lng: -79.0840065 },
-Marker { lat: 43.799608, lng: -79.2733021 },
-Marker { lat: 43.7569815, lng: -79.4044001 },
-Marker { lat: 43.635851, lng: -79.780203 },
-Marker { lat: 43.6677808, lng: -79.3259031 },
-Marker { lat: 43.6926302, lng: -79.508629 },
-Marker { lat: 43.6606139, lng: -79.3782378 },
-Marker { lat: 43.7447369, lng: -79.2027201 },
-Marker { lat: 43.3905964, lng: -79.7796355 },
-Marker { lat: 43.669054, lng: -79.365898 },
-Marker { lat: 43.8451296, lng: -79.4924157 },
-Marker { lat: 43.6426507, lng: -79.3773286 },
-Marker { lat: 43.78085, lng: -79.7304292 },
-Marker { lat: 43.4978683, lng: -79.6917625 },
-Marker { lat: 43.625834, lng: -79.7473606 },
-Marker { lat: 43.8167584, lng: -79.4656411 },
-Marker { lat: 43.8462814, lng: -79.4246254 },
-Marker { lat: 43.4890068, lng: -79.8659412 },
-Marker { lat: 43.4927104, lng: -79.8573787 },
-Marker { lat: 43.6915273, lng: -79.5354938 },
-Marker { lat: 43.6851041, lng: -79.3133756 },
-Marker { lat: 43.7795648, lng: -79.4024739 },
-Marker { lat: 43.9189501, lng: -79.4694683 },
-Marker { lat: 43.8080466, lng: -79.4173394 },
-Marker { lat: 43.486269, lng: -79.865843 },
-Marker { lat: 43.773249, lng: -79.255273 },
-Marker { lat: 43.715865, lng: -79.84172 },
-Marker { lat: 43.6823082, lng: -79.3579525 },
-Marker { lat: 43.63694, lng: -79.402947 },
-Marker { lat: 43.8760774, lng: -79.3588037 },
-Marker { lat: 43.67408, lng: -79.374474 },
-Marker { lat: 43.751745, lng: -79.266388 },
-Marker { lat: 43.6403228, lng: -79.3917567 },
-Marker { lat: 43.8814559, lng: -79.4687383 },
-Marker { lat: 43.8148654, lng: -79.466816 },
-Marker { lat: 43.75116, lng: -79.7789955 },
-Marker { lat: 43.95453, lng: -79.501623 },
-Marker { lat: 43.6679848, lng: -79.3755762 },
-Marker { lat: 43.7765738, lng: -79.3087084 },
-Marker { lat: 43.6915364, lng: -79.3424811 },
-Marker { lat: 43.7140871, lng: -79.6447392 },
-Marker { lat: 43.5759635, lng: -79.5700505 },
-Marker { lat: 43.7261365, lng: -79.2643908 },
-Marker { lat: 43.776533, lng: -79.670361 },
-Marker { lat: 43.78638, lng: -79.291535 },
-Marker { lat: 43.6882901, lng: -79.4383244 },
-Marker { lat: 43.8273217, lng: -79.5700868 },
-Marker { lat: 43.7510466, lng: -79.1883601 },
-Marker { lat: 43.6807387, lng: -79.2883524 },
-Marker { lat: 43.875512, lng: -79.120966 },
-Marker { lat: 43.6768065, lng: -79.3741415 },
-Marker { lat: 43.6892416, lng: -79.3952485 },
-Marker { lat: 43.7063718, lng: -79.2571002 },
-Marker { lat: 43.632572, lng: -79.6018504 },
-Marker { lat: 43.6617603, lng: -79.5722083 },
-Marker { lat: 43.52916, lng: -79.843133 },
-Marker { lat: 43.8647346, lng: -79.4301639 },
-Marker { lat: 43.7732692, lng: -79.3450544 },
-Marker { lat: 43.6907955, lng: -79.2931823 },
-Marker { lat: 43.3927652, lng: -79.7824767 },
-Marker { lat: 43.675347, lng: -79.43592 },
-Marker { lat: 43.7053255, lng: -79.4342263 },
-Marker { lat: 43.6820728, lng: -79.4497509 },
-Marker { lat: 43.6529119, lng: -79.3703524 },
-Marker { lat: 43.7545016, lng: -79.3938097 },
-Marker { lat: 43.6922058, lng: -79.3087827 },
-Marker { lat: 43.7344068, lng: -79.4109092 },
-Marker { lat: 43.6691837, lng: -79.3873558 },
-Marker { lat: 43.795696, lng: -79.520318 },
-Marker { lat: 43.8680274, lng: -79.4279956 },
-Marker { lat: 43.812916, lng: -79.4599396 },
-Marker { lat: 43.7037028, lng: -79.4335729 },
-Marker { lat: 43.661725, lng: -79.384128 },
-Marker { lat: 43.6429892, lng: -79.5039369 },
-Marker { lat: 43.8787944, lng: -79.0954067 },
-Marker { lat: 43.9341146, lng: -79.4776855 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.5820394, lng: -79.7641533 },
-Marker { lat: 43.6174639, lng: -79.6650514 },
-Marker { lat: 43.847647, lng: -79.25228 },
-Marker { lat: 43.6922018, lng: -79.3252941 },
-Marker { lat: 43.7914091, lng: -79.2366785 },
-Marker { lat: 43.6438059, lng: -79.3916717 },
-Marker { lat: 43.7079594, lng: -79.4105444 },
-Marker { lat: 43.7639534, lng: -79.4069857 },
-Marker { lat: 43.856886, lng: -79.03765 },
-Marker { lat: 43.806869, lng: -79.2653042 },
-Marker { lat: 43.8565448, lng: -79.3244227 },
-Marker { lat: 43.8097923, lng: -79.2556847 },
-Marker { lat: 43.882437, lng: -79.002606 },
-Marker { lat: 43.8719421, lng: -79.4268026 },
-Marker { lat: 43.7298398, lng: -79.2195215 },
-Marker { lat: 43.3715952, lng: -79.8440052 },
-Marker { lat: 43.53262, lng: -79.846869 },
-Marker { lat: 43.58765, lng: -79.790914 },
-Marker { lat: 43.8993513, lng: -79.0232277 },
-Marker { lat: 43.3525655, lng: -79.7652892 },
-Marker { lat: 43.7180212, lng: -79.3768816 },
-Marker { lat: 43.6782788, lng: -79.3956553 },
-Marker { lat: 43.7786129, lng: -79.4518042 },
-Marker { lat: 43.6577566, lng: -79.3678178 },
-Marker { lat: 43.649516, lng: -79.387954 },
-Marker { lat: 43.6610187, lng: -79.3635907 },
-Marker { lat: 43.6594036, lng: -79.3611785 },
-Marker { lat: 43.7946144, lng: -79.410105 },
-Marker { lat: 43.8734982, lng: -78.9592202 },
-Marker { lat: 43.951241, lng: -78.950943 },
-Marker { lat: 43.6744052, lng: -79.2806529 },
-Marker { lat: 43.830255, lng: -79.0067 },
-Marker { lat: 43.858771, lng: -79.029209 },
-Marker { lat: 43.8805979, lng: -78.9688178 },
-Marker { lat: 43.6685941, lng: -79.3076191 },
-Marker { lat: 43.814792, lng: -79.3938029 },
-Marker { lat: 43.858252, lng: -79.433621 },
-Marker { lat: 43.8294833, lng: -79.2867074 },
-Marker { lat: 43.851911, lng: -79.4313933 },
-Marker { lat: 43.8596195, lng: -79.2214946 },
-Marker { lat: 43.8766566, lng: -79.2612263 },
-Marker { lat: 43.8128057, lng: -79.4519464 },
-Marker { lat: 43.756902, lng: -79.51127 },
-Marker { lat: 43.6418186, lng: -79.440346 },
-Marker { lat: 43.6804583, lng: -79.7880439 },
-Marker { lat: 43.5197129, lng: -79.6724485 },
-Marker { lat: 43.7594734, lng: -79.7557956 },
-Marker { lat: 43.666647, lng: -79.448206 },
-Marker { lat: 43.7536264, lng: -79.5604059 },
-Marker { lat: 43.6243991, lng: -79.4785079 },
-Marker { lat: 43.518299, lng: -79.877404 },
-Marker { lat: 43.5410615, lng: -79.6004473 },
-Marker { lat: 43.5998135, lng: -79.5255865 },
-Marker { lat: 43.636608, lng: -79.4048673 },
-Marker { lat: 43.760402, lng: -79.40901 },
-Marker { lat: 43.6377596, lng: -79.3951472 },
-Marker { lat: 43.84754, lng: -78.992652 },
-Marker { lat: 43.7577327, lng: -79.403149 },
-Marker { lat: 43.7486095, lng: -79.3004445 },
-Marker { lat: 43.7905781, lng: -79.2158875 },
-Marker { lat: 43.6364747, lng: -79.4020594 },
-Marker { lat: 43.8130739, lng: -79.2173426 },
-Marker { lat: 43.828551, lng: -79.10462 },
-Marker { lat: 43.8270741, lng: -79.3884452 },
-Marker { lat: 43.7639679, lng: -79.2032065 },
-Marker { lat: 43.6960564, lng: -79.3085967 },
-Marker { lat: 43.686315, lng: -79.514191 },
-Marker { lat: 43.6254697, lng: -79.7589431 },
-Marker { lat: 43.693185, lng: -79.842792 },
-Marker { lat: 43.580921, lng: -79.6258565 },
-Marker { lat: 43.5330697, lng: -79.8559583 },
-Marker { lat: 43.7423514, lng: -79.5386744 },
-Marker { lat: 43.6373709, lng: -79.7722032 },
-Marker { lat: 43.7241914, lng: -79.2310809 },
-Marker { lat: 43.819849, lng: -79.026476 },
-Marker { lat: 43.8753112, lng: -79.4127466 },
-Marker { lat: 43.8139504, lng: -79.4401187 },
-Marker { lat: 43.7615883, lng: -79.2889197 },
-Marker { lat: 43.886044, lng: -79.228971 },
-Marker { lat: 43.6719848, lng: -79.4402226 },
-Marker { lat: 43.9040284, lng: -79.23145 },
-Marker { lat: 43.9608784, lng: -79.2501138 },
-Marker { lat: 43.7779246, lng: -79.3761996 },
-Marker { lat: 43.6375103, lng: -79.4005804 },
-Marker { lat: 43.7302432, lng: -79.4697143 },
-Marker { lat: 43.8779873, lng: -79.2753025 },
-Marker { lat: 43.6548441, lng: -79.3846797 },
-Marker { lat: 43.7114372, lng: -79.2560505 },
-Marker { lat: 43.7806579, lng: -79.3763973 },
-Marker { lat: 43.82621, lng: -79.117198 },
-Marker { lat: 43.720895, lng: -79.4745273 },
-Marker { lat: 43.7469491, lng: -79.4348698 },
-Marker { lat: 43.6735793, lng: -79.3361402 },
-Marker { lat: 43.6971244, lng: -79.28271 },
-Marker { lat: 43.858084, lng: -79.028059 },
-Marker { lat: 43.875122, lng: -79.313541 },
-Marker { lat: 43.8860665, lng: -79.2471054 },
-Marker { lat: 43.685843, lng: -79.274132 },
-Marker { lat: 43.430246, lng: -79.755139 },
-Marker { lat: 43.7337826, lng: -79.5644371 },
-Marker { lat: 43.6971856, lng: -79.2827337 },
-Marker { lat: 43.7339219, lng: -79.2099336 },
-Marker { lat: 43.8319048, lng: -79.4776071 },
-Marker { lat: 43.9550411, lng: -78.9483969 },
-Marker { lat: 43.6695417, lng: -79.3837883 },
-Marker { lat: 43.6707892, lng: -79.8023597 },
-Marker { lat: 43.8528309, lng: -79.4446191 },
-Marker { lat: 43.706123, lng: -79.442929 },
-Marker { lat: 43.801291, lng: -79.149438 },
-Marker { lat: 43.668535, lng: -79.321173 },
-Marker { lat: 43.8234526, lng: -79.4760661 },
-Marker { lat: 43.8848814, lng: -78.9080943 },
-Marker { lat: 43.6735244, lng: -79.3080522 },
-Marker { lat: 43.8501286, lng: -79.565712 },
-Marker { lat: 43.9639375, lng: -78.9748211 },
-Marker { lat: 43.5589348, lng: -79.5823834 },
-Marker { lat: 43.6211657, lng: -79.8836245 },
-Marker { lat: 43.641523, lng: -79.4240871 },
-Marker { lat: 43.7782466, lng: -79.5975781 },
-Marker { lat: 43.48461, lng: -79.846911 },
-Marker { lat: 43.640285, lng: -79.559817 },
-Marker { lat: 43.7968255, lng: -79.2998342 },
-Marker { lat: 43.7167448, lng: -79.7271985 },
-Marker { lat: 43.550227, lng: -79.669046 },
-Marker { lat: 43.7189326, lng: -79.6234501 },
-Marker { lat: 43.6970302, lng: -79.2826787 },
-Marker { lat: 43.6206069, lng: -79.4865296 },
-Marker { lat: 43.909609, lng: -78.905197 },
-Marker { lat: 43.674231, lng: -79.307142 },
-Marker { lat: 43.645261, lng: -79.554412 },
-Marker { lat: 43.838279, lng: -79.092443 },
-Marker { lat: 43.9785703, lng: -79.4462784 },
-Marker { lat: 43.6553702, lng: -79.4561244 },
-Marker { lat: 43.698221, lng: -79.445264 },
-Marker { lat: 43.964227, lng: -79.559455 },
-Marker { lat: 43.4193928, lng: -79.7039195 },
-Marker { lat: 43.8649408, lng: -79.410603 },
-Marker { lat: 43.623821, lng: -79.478728 },
-Marker { lat: 43.6464971, lng: -79.4211364 },
-Marker { lat: 43.623821, lng: -79.478728 },
-Marker { lat: 43.6959554, lng: -79.3957179 },
-Marker { lat: 43.944729, lng: -79.085851 },
-Marker { lat: 43.639955, lng: -79.382025 },
-Marker { lat: 43.6847598, lng: -79.3417523 },
-Marker { lat: 43.7762122, lng: -79.6061479 },
-Marker { lat: 43.838262, lng: -79.488991 },
-Marker { lat: 43.8659, lng: -79.4342 },
-Marker { lat: 43.6076, lng: -79.6719 },
-Marker { lat: 43.8562513, lng: -79.6186978 },
-Marker { lat: 43.7830984, lng: -79.3255486 },
-Marker { lat: 43.6398765, lng: -79.4167372 },
-Marker { lat: 43.9435276, lng: -79.4498888 },
-Marker { lat: 43.769094, lng: -79.368687 },
-Marker { lat: 43.395758, lng: -79.791088 },
-Marker { lat: 43.624595, lng: -79.480119 },
-Marker { lat: 43.7785351, lng: -79.3898979 },
-Marker { lat: 43.774764, lng: -79.336918 },
-Marker { lat: 43.778008, lng: -79.4527173 },
-Marker { lat: 43.8752668, lng: -78.934097 },
-Marker { lat: 43.5460424, lng: -79.7415352 },
-Marker { lat: 43.750599, lng: -79.465027 },
-Marker { lat: 43.9594812, lng: -78.906286 },
-Marker { lat: 43.819402, lng: -79.145208 },
-Marker { lat: 43.8977124, lng: -79.4343074 },
-Marker { lat: 43.662393, lng: -79.467027 },
-Marker { lat: 43.8794466, lng: -79.4700178 },
-Marker { lat: 43.8979737, lng: -78.9258371 },
-Marker { lat: 43.62372, lng: -79.483703 },
-Marker { lat: 43.7779113, lng: -79.3299942 },
-Marker { lat: 43.8562959, lng: -79.5130013 },
-Marker { lat: 43.8164461, lng: -79.6186428 },
-Marker { lat: 43.6042027, lng: -79.7012312 },
-Marker { lat: 43.6654142, lng: -79.7464395 },
-Marker { lat: 43.75667, lng: -79.408623 },
-Marker { lat: 43.7947234, lng: -79.4035924 },
-Marker { lat: 43.8797936, lng: -79.4373026 },
-Marker { lat: 43.6017336, lng: -79.5251608 },
-Marker { lat: 43.7320107, lng: -79.4261705 },
-Marker { lat: 43.6897569, lng: -79.8518111 },
-Marker { lat: 43.661725, lng: -79.384128 },
-Marker { lat: 43.8683356, lng: -79.3084298 },
-Marker { lat: 43.684897, lng: -79.4704611 },
-Marker { lat: 43.7918439, lng: -79.4061522 },
-Marker { lat: 43.6413253, lng: -79.4365534 },
-Marker { lat: 43.8395422, lng: -79.2483829 },
-Marker { lat: 43.6787938, lng: -79.2866696 },
-Marker { lat: 43.5654152, lng: -79.7358586 },
-Marker { lat: 43.8225321, lng: -79.2243412 },
-Marker { lat: 43.6822081, lng: -79.5182218 },
-Marker { lat: 43.7096197, lng: -79.8252008 },
-Marker { lat: 43.661126, lng: -79.3532776 },
-Marker { lat: 43.6396649, lng: -79.4146468 },
-Marker { lat: 43.6871766, lng: -79.3465648 },
-Marker { lat: 43.9629602, lng: -78.9391588 },
-Marker { lat: 43.916996, lng: -79.441757 },
-Marker { lat: 43.7780952, lng: -79.3385344 },
-Marker { lat: 43.6342488, lng: -79.5144012 },
-Marker { lat: 43.749969, lng: -79.406135 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.660405, lng: -79.4070029 },
-Marker { lat: 43.790292, lng: -79.411578 },
-Marker { lat: 43.520078, lng: -79.620473 },
-Marker { lat: 43.9703547, lng: -79.2335199 },
-Marker { lat: 43.645077, lng: -79.364342 },
-Marker { lat: 43.6591852, lng: -79.434787 },
-Marker { lat: 43.8671615, lng: -79.5249901 },
-Marker { lat: 43.7333204, lng: -79.2223925 },
-Marker { lat: 43.7362117, lng: -79.2654009 },
-Marker { lat: 43.7033917, lng: -79.7652463 },
-Marker { lat: 43.6620243, lng: -79.3865463 },
-Marker { lat: 43.6712941, lng: -79.384925 },
-Marker { lat: 43.665368, lng: -79.387318 },
-Marker { lat: 43.7708581, lng: -79.4120073 },
-Marker { lat: 43.7600504, lng: -79.4074672 },
-Marker { lat: 43.4854315, lng: -79.8546992 },
-Marker { lat: 43.5846327, lng: -79.7820268 },
-Marker { lat: 43.7538386, lng: -79.8420693 },
-Marker { lat: 43.6217109, lng: -79.5901086 },
-Marker { lat: 43.475321, lng: -79.7361903 },
-Marker { lat: 43.5890411, lng: -79.6495976 },
-Marker { lat: 43.6690845, lng: -79.3213525 },
-Marker { lat: 43.853925, lng: -79.025874 },
-Marker { lat: 43.7905493, lng: -79.1838591 },
-Marker { lat: 43.8077984, lng: -79.2028867 },
-Marker { lat: 43.7805353, lng: -79.2510063 },
-Marker { lat: 43.8774726, lng: -78.8994916 },
-Marker { lat: 43.7991402, lng: -79.1232996 },
-Marker { lat: 43.777026, lng: -79.413773 },
-Marker { lat: 43.8376638, lng: -79.3903703 },
-Marker { lat: 43.6966383, lng: -79.7731308 },
-Marker { lat: 43.8516093, lng: -79.3278228 },
-Marker { lat: 43.8584949, lng: -79.2542774 },
-Marker { lat: 43.512024, lng: -79.844703 },
-Marker { lat: 43.6685967, lng: -79.4526653 },
-Marker { lat: 43.7646482, lng: -79.7650034 },
-Marker { lat: 43.5758424, lng: -79.7563621 },
-Marker { lat: 43.5244604, lng: -79.8863931 },
-Marker { lat: 43.6297235, lng: -79.8803554 },
-Marker { lat: 43.8214575, lng: -79.727793 },
-Marker { lat: 43.5816834, lng: -79.7286125 },
-Marker { lat: 43.651394, lng: -79.8017765 },
-Marker { lat: 43.5420788, lng: -79.6912269 },
-Marker { lat: 43.3504578, lng: -79.7773855 },
-Marker { lat: 43.4268892, lng: -79.7842184 },
-Marker { lat: 43.3689398, lng: -79.8064052 },
-Marker { lat: 43.916141, lng: -78.907386 },
-Marker { lat: 43.638764, lng: -79.416242 },
-Marker { lat: 43.7853727, lng: -79.3424792 },
-Marker { lat: 43.7399512, lng: -79.4141134 },
-Marker { lat: 43.658009, lng: -79.362484 },
-Marker { lat: 43.859112, lng: -79.032895 },
-Marker { lat: 43.7802816, lng: -79.4455163 },
-Marker { lat: 43.8139326, lng: -79.451459 },
-Marker { lat: 43.7998802, lng: -79.4455936 },
-Marker { lat: 43.8583448, lng: -79.5225776 },
-Marker { lat: 43.6969342, lng: -79.5629805 },
-Marker { lat: 43.7434783, lng: -79.7562183 },
-Marker { lat: 43.6701574, lng: -79.4498051 },
-Marker { lat: 43.5631586, lng: -79.732453 },
-Marker { lat: 43.4932777, lng: -79.8710321 },
-Marker { lat: 43.6708111, lng: -79.4978575 },
-Marker { lat: 43.6859944, lng: -79.8036553 },
-Marker { lat: 43.8251872, lng: -79.7335427 },
-Marker { lat: 43.6448263, lng: -79.7227034 },
-Marker { lat: 43.6363836, lng: -79.4857012 },
-Marker { lat: 43.622658, lng: -79.6533335 },
-Marker { lat: 43.6427532, lng: -79.4274654 },
-Marker { lat: 43.7703, lng: -79.413645 },
-Marker { lat: 43.8240601, lng: -79.2769069 },
-Marker { lat: 43.769906, lng: -79.411747 },
-Marker { lat: 43.724239, lng: -79.40255 },
-Marker { lat: 43.7932131, lng: -79.4230093 },
-Marker { lat: 43.6384324, lng: -79.4135407 },
-Marker { lat: 43.6745572, lng: -79.7287736 },
-Marker { lat: 43.4831913, lng: -79.8701418 },
-Marker { lat: 43.6993368, lng: -79.7494078 },
-Marker { lat: 43.8139375, lng: -79.3571315 },
-Marker { lat: 43.491259, lng: -79.7041605 },
-Marker { lat: 43.7030998, lng: -79.8151052 },
-Marker { lat: 43.8374915, lng: -79.4397024 },
-Marker { lat: 43.9762458, lng: -79.2277496 },
-Marker { lat: 43.7630078, lng: -79.7668224 },
-Marker { lat: 43.811911, lng: -79.2317174 },
-Marker { lat: 43.807136, lng: -79.101569 },
-Marker { lat: 43.839495, lng: -79.448749 },
-Marker { lat: 43.6842723, lng: -79.339358 },
-Marker { lat: 43.9530798, lng: -79.2647477 },
-Marker { lat: 43.7041839, lng: -79.7432245 },
-Marker { lat: 43.6488706, lng: -79.50833 },
-Marker { lat: 43.737143, lng: -79.800691 },
-Marker { lat: 43.7266141, lng: -79.8297065 },
-Marker { lat: 43.6724473, lng: -79.7404743 },
-Marker { lat: 43.607785, lng: -79.665932 },
-Marker { lat: 43.490987, lng: -79.845326 },
-Marker { lat: 43.4984046, lng: -79.8794327 },
-Marker { lat: 43.641633, lng: -79.382053 },
-Marker { lat: 43.8796811, lng: -79.3688456 },
-Marker { lat: 43.749878, lng: -79.531076 },
-Marker { lat: 43.818816, lng: -79.077231 },
-Marker { lat: 43.7066318, lng: -79.3656577 },
-Marker { lat: 43.6349494, lng: -79.5556028 },
-Marker { lat: 43.7006188, lng: -79.2613348 },
-Marker { lat: 43.7987509, lng: -79.3799786 },
-Marker { lat: 43.7063986, lng: -79.4137966 },
-Marker { lat: 43.680512, lng: -79.3144592 },
-Marker { lat: 43.7635981, lng: -79.2368484 },
-Marker { lat: 43.9071093, lng: -78.8993617 },
-Marker { lat: 43.724239, lng: -79.40255 },
-Marker { lat: 43.8769594, lng: -79.3363685 },
-Marker { lat: 43.6609733, lng: -79.5423059 },
-Marker { lat: 43.6628915, lng: -79.3677193 },
-Marker { lat: 43.6746538, lng: -79.3890069 },
-Marker { lat: 43.6408931, lng: -79.5107879 },
-Marker { lat: 43.640345, lng: -79.563119 },
-Marker { lat: 43.5874559, lng: -79.5522414 },
-Marker { lat: 43.630221, lng: -79.51004 },
-Marker { lat: 43.5814591, lng: -79.7649635 },
-Marker { lat: 43.77414, lng: -79.178771 },
-Marker { lat: 43.6687948, lng: -79.5222739 },
-Marker { lat: 43.6779435, lng: -79.5268329 },
-Marker { lat: 43.7958397, lng: -79.7439736 },
-Marker { lat: 43.6645238, lng: -79.3083842 },
-Marker { lat: 43.665642, lng: -79.777419 },
-Marker { lat: 43.7391782, lng: -79.3456388 },
-Marker { lat: 43.7927261, lng: -79.6136447 },
-Marker { lat: 43.7547018, lng: -79.2363154 },
-Marker { lat: 43.7657287, lng: -79.6767878 },
-Marker { lat: 43.8776809, lng: -79.2339602 },
-Marker { lat: 43.9198152, lng: -79.473357 },
-Marker { lat: 43.9674318, lng: -79.4841077 },
-Marker { lat: 43.427728, lng: -79.733357 },
-Marker { lat: 43.7160694, lng: -79.2760357 },
-Marker { lat: 43.6713415, lng: -79.3012185 },
-Marker { lat: 43.7008874, lng: -79.3830882 },
-Marker { lat: 43.7205104, lng: -79.5676787 },
-Marker { lat: 43.5532522, lng: -79.6729278 },
-Marker { lat: 43.7542372, lng: -79.8182109 },
-Marker { lat: 43.7415284, lng: -79.5421902 },
-Marker { lat: 43.7612064, lng: -79.8262915 },
-Marker { lat: 43.9019382, lng: -79.4610424 },
-Marker { lat: 43.7156837, lng: -79.8320623 },
-Marker { lat: 43.3784963, lng: -79.8088371 },
-Marker { lat: 43.6856494, lng: -79.3178527 },
-Marker { lat: 43.3576496, lng: -79.8453949 },
-Marker { lat: 43.641071, lng: -79.400252 },
-Marker { lat: 43.664424, lng: -79.4833853 },
-Marker { lat: 43.598537, lng: -79.6822215 },
-Marker { lat: 43.4385703, lng: -79.6740542 },
-Marker { lat: 43.4259146, lng: -79.7054593 },
-Marker { lat: 43.7851358, lng: -79.5951728 },
-Marker { lat: 43.890966, lng: -79.02842 },
-Marker { lat: 43.6644192, lng: -79.3528382 },
-Marker { lat: 43.5413513, lng: -79.6055772 },
-Marker { lat: 43.5603226, lng: -79.6022085 },
-Marker { lat: 43.6369794, lng: -79.4060787 },
-Marker { lat: 43.6342318, lng: -79.540934 },
-Marker { lat: 43.411811, lng: -79.801472 },
-Marker { lat: 43.8080559, lng: -79.4553335 },
-Marker { lat: 43.7945494, lng: -79.166009 },
-Marker { lat: 43.9091686, lng: -78.9457338 },
-Marker { lat: 43.6570971, lng: -79.3690995 },
-Marker { lat: 43.7871878, lng: -79.4310826 },
-Marker { lat: 43.5809236, lng: -79.6238027 },
-Marker { lat: 43.62825, lng: -79.759417 },
-Marker { lat: 43.6541577, lng: -79.7747873 },
-Marker { lat: 43.862228, lng: -79.5401151 },
-Marker { lat: 43.8265229, lng: -79.5805217 },
-Marker { lat: 43.649844, lng: -79.444627 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.8633835, lng: -79.4332961 },
-Marker { lat: 43.6429451, lng: -79.3805034 },
-Marker { lat: 43.6499, lng: -79.7339 },
-Marker { lat: 43.9218906, lng: -79.4678832 },
-Marker { lat: 43.635457, lng: -79.870386 },
-Marker { lat: 43.4854, lng: -79.6988 },
-Marker { lat: 43.4566156, lng: -79.6902655 },
-Marker { lat: 43.641291, lng: -79.5049174 },
-Marker { lat: 43.9116381, lng: -78.9309734 },
-Marker { lat: 43.58504, lng: -79.646032 },
-Marker { lat: 43.8296128, lng: -79.6522442 },
-Marker { lat: 43.8842351, lng: -79.3513438 },
-Marker { lat: 43.6811548, lng: -79.4647502 },
-Marker { lat: 43.745448, lng: -79.392705 },
-Marker { lat: 43.7531706, lng: -79.5109026 },
-Marker { lat: 43.707083, lng: -79.397771 },
-Marker { lat: 43.822714, lng: -79.398741 },
-Marker { lat: 43.6898782, lng: -79.4013358 },
-Marker { lat: 43.7149433, lng: -79.2641055 },
-Marker { lat: 43.6358951, lng: -79.5775681 },
-Marker { lat: 43.651639, lng: -79.410785 },
-Marker { lat: 43.9059046, lng: -78.9700541 },
-Marker { lat: 43.9090949, lng: -78.9300448 },
-Marker { lat: 43.740237, lng: -79.747101 },
-Marker { lat: 43.5191078, lng: -79.8246582 },
-Marker { lat: 43.7081088, lng: -79.7494836 },
-Marker { lat: 43.665388, lng: -79.3880211 },
-Marker { lat: 43.8006531, lng: -79.3051929 },
-Marker { lat: 43.405875, lng: -79.725807 },
-Marker { lat: 43.89928, lng: -79.735429 },
-Marker { lat: 43.983445, lng: -79.238875 },
-Marker { lat: 43.5256777, lng: -79.6884089 },
-Marker { lat: 43.7303276, lng: -79.2309128 },
-Marker { lat: 43.8806588, lng: -78.9098709 },
-Marker { lat: 43.5844461, lng: -79.7183504 },
-Marker { lat: 43.544666, lng: -79.594681 },
-Marker { lat: 43.6790581, lng: -79.3093626 },
-Marker { lat: 43.834747, lng: -79.007799 },
-Marker { lat: 43.6918342, lng: -79.3261898 },
-Marker { lat: 43.9538324, lng: -78.9708882 },
-Marker { lat: 43.7704344, lng: -79.1635782 },
-Marker { lat: 43.5317195, lng: -79.8789523 },
-Marker { lat: 43.66273, lng: -79.379025 },
-Marker { lat: 43.644629, lng: -79.392933 },
-Marker { lat: 43.621285, lng: -79.4968961 },
-Marker { lat: 43.572976, lng: -79.680012 },
-Marker { lat: 43.715384, lng: -79.454548 },
-Marker { lat: 43.8745836, lng: -79.3487094 },
-Marker { lat: 43.87686, lng: -79.2714764 },
-Marker { lat: 43.8949733, lng: -79.2948504 },
-Marker { lat: 43.8917222, lng: -79.3061116 },
-Marker { lat: 43.8196803, lng: -79.3146699 },
-Marker { lat: 43.7817373, lng: -79.4143115 },
-Marker { lat: 43.7880545, lng: -79.1523278 },
-Marker { lat: 43.96478, lng: -79.371892 },
-Marker { lat: 43.6491763, lng: -79.3770958 },
-Marker { lat: 43.667222, lng: -79.3839697 },
-Marker { lat: 43.779718, lng: -79.342657 },
-Marker { lat: 43.638784, lng: -79.398863 },
-Marker { lat: 43.6214031, lng: -79.4837838 },
-Marker { lat: 43.775055, lng: -79.6647377 },
-Marker { lat: 43.8457525, lng: -79.4719348 },
-Marker { lat: 43.7695626, lng: -79.4134466 },
-Marker { lat: 43.6968373, lng: -79.293353 },
-Marker { lat: 43.683047, lng: -79.822826 },
-Marker { lat: 43.672822, lng: -79.414098 },
-Marker { lat: 43.826744, lng: -79.091001 },
-Marker { lat: 43.7914344, lng: -79.3333916 },
-Marker { lat: 43.8614279, lng: -79.4364729 },
-Marker { lat: 43.798005, lng: -79.2154367 },
-Marker { lat: 43.8381722, lng: -79.1307769 },
-Marker { lat: 43.7816473, lng: -79.2449273 },
-Marker { lat: 43.4854, lng: -79.6988 },
-Marker { lat: 43.5483701, lng: -79.7147011 },
-Marker { lat: 43.595841, lng: -79.681723 },
-Marker { lat: 43.660905, lng: -79.378806 },
-Marker { lat: 43.8403126, lng: -79.3915204 },
-Marker { lat: 43.9643519, lng: -79.2418543 },
-Marker { lat: 43.8450756, lng: -79.0887944 },
-Marker { lat: 43.6590857, lng: -79.3756802 },
-Marker { lat: 43.659137, lng: -79.3578443 },
-Marker { lat: 43.7550359, lng: -79.3610977 },
-Marker { lat: 43.7463137, lng: -79.2086907 },
-Marker { lat: 43.7994443, lng: -79.5659722 },
-Marker { lat: 43.7566685, lng: -79.8172817 },
-Marker { lat: 43.7109922, lng: -79.5292263 },
-Marker { lat: 43.6341013, lng: -79.5134468 },
-Marker { lat: 43.6259053, lng: -79.5936465 },
-Marker { lat: 43.6598918, lng: -79.4482483 },
-Marker { lat: 43.6966498, lng: -79.814265 },
-Marker { lat: 43.8642237, lng: -79.2160149 },
-Marker { lat: 43.5614759, lng: -79.743117 },
-Marker { lat: 43.8281418, lng: -79.2323095 },
-Marker { lat: 43.7472669, lng: -79.507223 },
-Marker { lat: 43.7023034, lng: -79.6504321 },
-Marker { lat: 43.5967884, lng: -79.7158424 },
-Marker { lat: 43.5316414, lng: -79.6632147 },
-Marker { lat: 43.381034, lng: -79.798334 },
-Marker { lat: 43.4675312, lng: -79.7462961 },
-Marker { lat: 43.6548176, lng: -79.8003252 },
-Marker { lat: 43.5383733, lng: -79.6997667 },
-Marker { lat: 43.6614316, lng: -79.7671763 },
-Marker { lat: 43.8752956, lng: -79.3227974 },
-Marker { lat: 43.6700784, lng: -79.7338812 },
-Marker { lat: 43.8200179, lng: -79.396951 },
-Marker { lat: 43.6476394, lng: -79.7426066 },
-Marker { lat: 43.7762122, lng: -79.6061479 },
-Marker { lat: 43.843516, lng: -79.426394 },
-Marker { lat: 43.5397866, lng: -79.6752457 },
-Marker { lat: 43.8419509, lng: -79.5346191 },
-Marker { lat: 43.6411433, lng: -79.5209149 },
-Marker { lat: 43.76228, lng: -79.7286 },
-Marker { lat: 43.377128, lng: -79.8002583 },
-Marker { lat: 43.586871, lng: -79.572192 },
-Marker { lat: 43.9693694, lng: -79.1339026 },
-Marker { lat: 43.7874191, lng: -79.3669901 },
-Marker { lat: 43.824578, lng: -79.015081 },
-Marker { lat: 43.8522694, lng: -79.431868 },
-Marker { lat: 43.8999421, lng: -79.4373678 },
-Marker { lat: 43.9501155, lng: -78.8791714 },
-Marker { lat: 43.7388898, lng: -79.2728878 },
-Marker { lat: 43.9173966, lng: -78.9182827 },
-Marker { lat: 43.7879505, lng: -79.2142114 },
-Marker { lat: 43.7433627, lng: -79.3431633 },
-Marker { lat: 43.720889, lng: -79.716783 },
-Marker { lat: 43.6216546, lng: -79.483188 },
-Marker { lat: 43.848575, lng: -79.43254 },
-Marker { lat: 43.985927, lng: -79.530876 },
-Marker { lat: 43.8501686, lng: -79.3264608 },
-Marker { lat: 43.7938805, lng: -79.4818504 },
-Marker { lat: 43.9058657, lng: -79.4297581 },
-Marker { lat: 43.700445, lng: -79.397789 },
-Marker { lat: 43.6818471, lng: -79.3249838 },
-Marker { lat: 43.8384935, lng: -79.1117271 },
-Marker { lat: 43.8228173, lng: -79.475183 },
-Marker { lat: 43.7615378, lng: -79.2482897 },
-Marker { lat: 43.5209202, lng: -79.8471596 },
-Marker { lat: 43.5530533, lng: -79.7119838 },
-Marker { lat: 43.6764633, lng: -79.5647825 },
-Marker { lat: 43.5595182, lng: -79.7415424 },
-Marker { lat: 43.6802687, lng: -79.4602327 },
-Marker { lat: 43.8694955, lng: -78.9400803 },
-Marker { lat: 43.7031717, lng: -79.3835804 },
-Marker { lat: 43.6978398, lng: -79.3871947 },
-Marker { lat: 43.9714626, lng: -79.4788217 },
-Marker { lat: 43.8826112, lng: -79.4658394 },
-Marker { lat: 43.8824717, lng: -79.2576784 },
-Marker { lat: 43.9489878, lng: -79.4732376 },
-Marker { lat: 43.930668, lng: -79.029476 },
-Marker { lat: 43.791361, lng: -79.3314669 },
-Marker { lat: 43.859563, lng: -79.4603847 },
-Marker { lat: 43.6505967, lng: -79.8035915 },
-Marker { lat: 43.8411271, lng: -79.2619593 },
-Marker { lat: 43.4255418, lng: -79.7796351 },
-Marker { lat: 43.7341144, lng: -79.3090117 },
-Marker { lat: 43.8384839, lng: -79.3905913 },
-Marker { lat: 43.4566156, lng: -79.6902655 },
-Marker { lat: 43.9791419, lng: -79.4708658 },
-Marker { lat: 43.9330962, lng: -79.410058 },
-Marker { lat: 43.908459, lng: -78.915276 },
-Marker { lat: 43.7373063, lng: -79.4175152 },
-Marker { lat: 43.8710718, lng: -79.2580406 },
-Marker { lat: 43.4398427, lng: -79.6819776 },
-Marker { lat: 43.929346, lng: -79.5200806 },
-Marker { lat: 43.730772, lng: -79.643457 },
-Marker { lat: 43.7704511, lng: -79.4318068 },
-Marker { lat: 43.8842389, lng: -79.4572355 },
-Marker { lat: 43.700843, lng: -79.76937 },
-Marker { lat: 43.6909516, lng: -79.8608188 },
-Marker { lat: 43.4849245, lng: -79.7327975 },
-Marker { lat: 43.7570235, lng: -79.4587707 },
-Marker { lat: 43.5944866, lng: -79.632353 },
-Marker { lat: 43.697, lng: -79.437094 },
-Marker { lat: 43.6916733, lng: -79.4440573 },
-Marker { lat: 43.6451278, lng: -79.4265917 },
-Marker { lat: 43.6779678, lng: -79.4488036 },
-Marker { lat: 43.8746097, lng: -79.064976 },
-Marker { lat: 43.393691, lng: -79.8214354 },
-Marker { lat: 43.8373498, lng: -79.1274666 },
-Marker { lat: 43.8081004, lng: -79.4488949 },
-Marker { lat: 43.4657601, lng: -79.6700891 },
-Marker { lat: 43.9440791, lng: -78.997466 },
-Marker { lat: 43.623335, lng: -79.5222847 },
-Marker { lat: 43.7322447, lng: -79.2116127 },
-Marker { lat: 43.5892132, lng: -79.5483734 },
-Marker { lat: 43.7154127, lng: -79.5012784 },
-Marker { lat: 43.777401, lng: -79.412802 },
-Marker { lat: 43.5122856, lng: -79.8583754 },
-Marker { lat: 43.644768, lng: -79.4026 },
-Marker { lat: 43.6277473, lng: -79.5108889 },
-Marker { lat: 43.4708785, lng: -79.702139 },
-Marker { lat: 43.7017573, lng: -79.7617035 },
-Marker { lat: 43.6871437, lng: -79.5064656 },
-Marker { lat: 43.7652017, lng: -79.3832771 },
-Marker { lat: 43.3487291, lng: -79.885335 },
-Marker { lat: 43.4932777, lng: -79.8710321 },
-Marker { lat: 43.7310859, lng: -79.7283975 },
-Marker { lat: 43.899716, lng: -79.2527241 },
-Marker { lat: 43.3926025, lng: -79.7228336 },
-Marker { lat: 43.626917, lng: -79.478378 },
-Marker { lat: 43.622515, lng: -79.482441 },
-Marker { lat: 43.6908649, lng: -79.8413983 },
-Marker { lat: 43.657547, lng: -79.401464 },
-Marker { lat: 43.7149056, lng: -79.8133143 },
-Marker { lat: 43.7917673, lng: -79.8785604 },
-Marker { lat: 43.8833921, lng: -79.2469364 },
-Marker { lat: 43.5753621, lng: -79.6175603 },
-Marker { lat: 43.6599471, lng: -79.4165785 },
-Marker { lat: 43.880717, lng: -79.2956574 },
-Marker { lat: 43.8841749, lng: -79.2820172 },
-Marker { lat: 43.3634928, lng: -79.7405651 },
-Marker { lat: 43.7055814, lng: -79.7776636 },
-Marker { lat: 43.6660249, lng: -79.320463 },
-Marker { lat: 43.6895541, lng: -79.3158738 },
-Marker { lat: 43.5597966, lng: -79.5977394 },
-Marker { lat: 43.7804522, lng: -79.4275805 },
-Marker { lat: 43.3538863, lng: -79.8161092 },
-Marker { lat: 43.7769288, lng: -79.1500251 },
-Marker { lat: 43.4325856, lng: -79.6983128 },
-Marker { lat: 43.4803135, lng: -79.8678431 },
-Marker { lat: 43.8607037, lng: -79.4533321 },
-Marker { lat: 43.7677952, lng: -79.4377575 },
-Marker { lat: 43.6813854, lng: -79.4928427 },
-Marker { lat: 43.909627, lng: -79.44314 },
-Marker { lat: 43.7820784, lng: -79.3921358 },
-Marker { lat: 43.8558603, lng: -79.4836722 },
-Marker { lat: 43.7755525, lng: -79.3876432 },
-Marker { lat: 43.9697256, lng: -79.4451098 },
-Marker { lat: 43.8167459, lng: -79.5617563 },
-Marker { lat: 43.6747765, lng: -79.2861823 },
-Marker { lat: 43.403215, lng: -79.700388 },
-Marker { lat: 43.728777, lng: -79.522653 },
-Marker { lat: 43.5863025, lng: -79.602461 },
-Marker { lat: 43.5630943, lng: -79.601452 },
-Marker { lat: 43.7244955, lng: -79.2517684 },
-Marker { lat: 43.454157, lng: -79.670543 },
-Marker { lat: 43.545153, lng: -79.733174 },
-Marker { lat: 43.53699, lng: -79.7035537 },
-Marker { lat: 43.4276412, lng: -79.6991059 },
-Marker { lat: 43.4311554, lng: -79.6842184 },
-Marker { lat: 43.7872812, lng: -79.4282906 },
-Marker { lat: 43.9669001, lng: -79.13366 },
-Marker { lat: 43.9425584, lng: -79.4762835 },
-Marker { lat: 43.4770024, lng: -79.6565169 },
-Marker { lat: 43.9762223, lng: -79.4880841 },
-Marker { lat: 43.9446796, lng: -79.4652727 },
-Marker { lat: 43.8894085, lng: -79.2488304 },
-Marker { lat: 43.8440376, lng: -79.5033458 },
-Marker { lat: 43.8728915, lng: -79.2463381 },
-Marker { lat: 43.7735355, lng: -79.3798505 },
-Marker { lat: 43.683599, lng: -79.9466291 },
-Marker { lat: 43.5883235, lng: -79.5865722 },
-Marker { lat: 43.663293, lng: -79.323712 },
-Marker { lat: 43.7409711, lng: -79.4426921 },
-Marker { lat: 43.7835817, lng: -79.4014933 },
-Marker { lat: 43.8800967, lng: -78.9083916 },
-Marker { lat: 43.8844103, lng: -79.4029686 },
-Marker { lat: 43.7959305, lng: -79.3991497 },
-Marker { lat: 43.8546577, lng: -79.4931617 },
-Marker { lat: 43.6252037, lng: -79.8793658 },
-Marker { lat: 43.4056825, lng: -79.7017371 },
-Marker { lat: 43.6446545, lng: -79.4156518 },
-Marker { lat: 43.9762649, lng: -79.4879015 },
-Marker { lat: 43.4204154, lng: -79.6871974 },
-Marker { lat: 43.640634, lng: -79.552153 },
-Marker { lat: 43.6842595, lng: -79.8524018 },
-Marker { lat: 43.744298, lng: -79.209542 },
-Marker { lat: 43.6854398, lng: -79.2828686 },
-Marker { lat: 43.7736783, lng: -79.3770054 },
-Marker { lat: 43.6088415, lng: -79.5386035 },
-Marker { lat: 43.9300305, lng: -78.9301457 },
-Marker { lat: 43.8762988, lng: -79.2422027 },
-Marker { lat: 43.808893, lng: -79.366376 },
-Marker { lat: 43.4536802, lng: -79.6711111 },
-Marker { lat: 43.526331, lng: -79.846265 },
-Marker { lat: 43.443316, lng: -79.670015 },
-Marker { lat: 43.507186, lng: -79.863059 },
-Marker { lat: 43.8904837, lng: -78.9230386 },
-Marker { lat: 43.823668, lng: -79.147326 },
-Marker { lat: 43.751745, lng: -79.1890611 },
-Marker { lat: 43.6411375, lng: -79.4007447 },
-Marker { lat: 43.7782404, lng: -79.4177748 },
-Marker { lat: 43.493007, lng: -79.8656167 },
-Marker { lat: 43.5857073, lng: -79.6464202 },
-Marker { lat: 43.5745729, lng: -79.7337269 },
-Marker { lat: 43.759252, lng: -79.2575702 },
-Marker { lat: 43.8560491, lng: -79.5436316 },
-Marker { lat: 43.881963, lng: -78.9548268 },
-Marker { lat: 43.6620243, lng: -79.3865463 },
-Marker { lat: 43.6871794, lng: -79.5048436 },
-Marker { lat: 43.7563401, lng: -79.4056932 },
-Marker { lat: 43.6650085, lng: -79.5631207 },
-Marker { lat: 43.7625, lng: -79.1981 },
-Marker { lat: 43.9024294, lng: -79.6589596 },
-Marker { lat: 43.5218696, lng: -79.6761877 },
-Marker { lat: 43.5975715, lng: -79.510258 },
-Marker { lat: 43.833889, lng: -78.986646 },
-Marker { lat: 43.8251084, lng: -79.4682552 },
-Marker { lat: 43.9648649, lng: -78.9592638 },
-Marker { lat: 43.639214, lng: -79.5903991 },
-Marker { lat: 43.7892336, lng: -79.4138558 },
-Marker { lat: 43.7548425, lng: -79.3372804 },
-Marker { lat: 43.7544434, lng: -79.3745661 },
-Marker { lat: 43.6893339, lng: -79.7464586 },
-Marker { lat: 43.6576836, lng: -79.54376 },
-Marker { lat: 43.7049147, lng: -79.2608658 },
-Marker { lat: 43.647624, lng: -79.396861 },
-Marker { lat: 43.7353274, lng: -79.7626674 },
-Marker { lat: 43.4831913, lng: -79.8701418 },
-Marker { lat: 43.6107139, lng: -79.6591896 },
-Marker { lat: 43.8991794, lng: -79.0112008 },
-Marker { lat: 43.8892899, lng: -78.9659786 },
-Marker { lat: 43.9370665, lng: -78.8923068 },
-Marker { lat: 43.9064116, lng: -78.9787368 },
-Marker { lat: 43.9128215, lng: -78.922252 },
-Marker { lat: 43.7827681, lng: -79.3657391 },
-Marker { lat: 43.6401213, lng: -79.3812266 },
-Marker { lat: 43.829591, lng: -79.066753 },
-Marker { lat: 43.7517802, lng: -79.8215371 },
-Marker { lat: 43.8363536, lng: -79.5539074 },
-Marker { lat: 43.640845, lng: -79.414319 },
-Marker { lat: 43.873962, lng: -79.718549 },
-Marker { lat: 43.7106444, lng: -79.6445193 },
-Marker { lat: 43.643323, lng: -79.559239 },
-Marker { lat: 43.6191999, lng: -79.4830575 },
-Marker { lat: 43.588409, lng: -79.789988 },
-Marker { lat: 43.5904264, lng: -79.5707812 },
-Marker { lat: 43.5548552, lng: -79.5800457 },
-Marker { lat: 43.6693707, lng: -79.544687 },
-Marker { lat: 43.3478, lng: -79.7806 },
-Marker { lat: 43.8861136, lng: -78.945867 },
-Marker { lat: 43.857262, lng: -79.017354 },
-Marker { lat: 43.5927159, lng: -79.6041477 },
-Marker { lat: 43.7022417, lng: -79.8428082 },
-Marker { lat: 43.5867765, lng: -79.7719822 },
-Marker { lat: 43.8887209, lng: -79.4129958 },
-Marker { lat: 43.5630488, lng: -79.5759477 },
-Marker { lat: 43.7073194, lng: -79.4198986 },
-Marker { lat: 43.7751229, lng: -79.4138309 },
-Marker { lat: 43.6230869, lng: -79.8838443 },
-Marker { lat: 43.778926, lng: -79.417112 },
-Marker { lat: 43.8368067, lng: -79.6226433 },
-Marker { lat: 43.657102, lng: -79.374093 },
-Marker { lat: 43.6983542, lng: -79.3647852 },
-Marker { lat: 43.7156819, lng: -79.7677409 },
-Marker { lat: 43.7748082, lng: -79.4045609 },
-Marker { lat: 43.537821, lng: -79.85965 },
-Marker { lat: 43.745043, lng: -79.765243 },
-Marker { lat: 43.843628, lng: -79.128603 },
-Marker { lat: 43.5474659, lng: -79.7431876 },
-Marker { lat: 43.7089383, lng: -79.7823182 },
-Marker { lat: 43.669301, lng: -79.384171 },
-Marker { lat: 43.5805107, lng: -79.6244991 },
-Marker { lat: 43.664723, lng: -79.52401 },
-Marker { lat: 43.6572744, lng: -79.537638 },
-Marker { lat: 43.6706377, lng: -79.3901448 },
-Marker { lat: 43.739727, lng: -79.5746516 },
-Marker { lat: 43.87989, lng: -79.735228 },
-Marker { lat: 43.8410876, lng: -79.405166 },
-Marker { lat: 43.5917166, lng: -79.7771307 },
-Marker { lat: 43.6667359, lng: -79.4506731 },
-Marker { lat: 43.708353, lng: -79.264185 },
-Marker { lat: 43.652331, lng: -79.356014 },
-Marker { lat: 43.9781869, lng: -79.4643373 },
-Marker { lat: 43.8371904, lng: -79.0784106 },
-Marker { lat: 43.471603, lng: -79.7504152 },
-Marker { lat: 43.756206, lng: -79.602491 },
-Marker { lat: 43.5571607, lng: -79.7071213 },
-Marker { lat: 43.859275, lng: -79.028471 },
-Marker { lat: 43.6489903, lng: -79.3870081 },
-Marker { lat: 43.810086, lng: -79.36409 },
-Marker { lat: 43.644679, lng: -79.900498 },
-Marker { lat: 43.3962395, lng: -79.7127951 },
-Marker { lat: 43.640359, lng: -79.884764 },
-Marker { lat: 43.7075746, lng: -79.3101847 },
-Marker { lat: 43.74543, lng: -79.3555308 },
-Marker { lat: 43.7402509, lng: -79.2609564 },
-Marker { lat: 43.5927336, lng: -79.706565 },
-Marker { lat: 43.4904103, lng: -79.8774736 },
-Marker { lat: 43.4425968, lng: -79.712181 },
-Marker { lat: 43.96066, lng: -78.946192 },
-Marker { lat: 43.6782856, lng: -79.8280876 },
-Marker { lat: 43.9111894, lng: -78.9206406 },
-Marker { lat: 43.852115, lng: -79.10122 },
-Marker { lat: 43.7531571, lng: -79.5136899 },
-Marker { lat: 43.866009, lng: -79.861997 },
-Marker { lat: 43.7733017, lng: -79.2553721 },
-Marker { lat: 43.834701, lng: -79.070892 },
-Marker { lat: 43.8996692, lng: -79.2931826 },
-Marker { lat: 43.8429834, lng: -79.2754337 },
-Marker { lat: 43.861574, lng: -79.084851 },
-Marker { lat: 43.8121488, lng: -79.4569092 },
-Marker { lat: 43.6765829, lng: -79.4139144 },
-Marker { lat: 43.7255635, lng: -79.8197747 },
-Marker { lat: 43.9214991, lng: -78.9167473 },
-Marker { lat: 43.6725851, lng: -79.3172907 },
-Marker { lat: 43.8107918, lng: -79.3590482 },
-Marker { lat: 43.7083764, lng: -79.7725137 },
-Marker { lat: 43.4314539, lng: -79.7791373 },
-Marker { lat: 43.5178359, lng: -79.8481703 },
-Marker { lat: 43.8790625, lng: -79.0851659 },
-Marker { lat: 43.872169, lng: -79.4667374 },
-Marker { lat: 43.7164916, lng: -79.8294463 },
-Marker { lat: 43.3550653, lng: -79.7496457 },
-Marker { lat: 43.9119329, lng: -79.2555124 },
-Marker { lat: 43.8873812, lng: -79.0290219 },
-Marker { lat: 43.6957708, lng: -79.7845929 },
-Marker { lat: 43.8888522, lng: -78.8886065 },
-Marker { lat: 43.668332, lng: -79.8230233 },
-Marker { lat: 43.900546, lng: -78.976088 },
-Marker { lat: 43.842433, lng: -79.122962 },
-Marker { lat: 43.7563794, lng: -79.7334914 },
-Marker { lat: 43.707669, lng: -79.392307 },
-Marker { lat: 43.6865523, lng: -79.4421867 },
-Marker { lat: 43.6430829, lng: -79.4018327 },
-Marker { lat: 43.6427017, lng: -79.3933634 },
-Marker { lat: 43.7658523, lng: -79.3827681 },
-Marker { lat: 43.671156, lng: -79.394936 },
-Marker { lat: 43.7731661, lng: -79.4154428 },
-Marker { lat: 43.6613867, lng: -79.3804382 },
-Marker { lat: 43.8706367, lng: -78.9512665 },
-Marker { lat: 43.8855176, lng: -79.4069896 },
-Marker { lat: 43.915026, lng: -78.8971915 },
-Marker { lat: 43.3525786, lng: -79.8494377 },
-Marker { lat: 43.5955489, lng: -79.6337354 },
-Marker { lat: 43.877466, lng: -79.231754 },
-Marker { lat: 43.4935347, lng: -79.8743128 },
-Marker { lat: 43.84704, lng: -79.249586 },
-Marker { lat: 43.6441339, lng: -79.5889237 },
-Marker { lat: 43.878775, lng: -79.715406 },
-Marker { lat: 43.8046762, lng: -79.4230377 },
-Marker { lat: 43.669593, lng: -79.38058 },
-Marker { lat: 43.6888299, lng: -79.3531033 },
-Marker { lat: 43.6947332, lng: -79.2733524 },
-Marker { lat: 43.637305, lng: -79.735244 },
-Marker { lat: 43.3772464, lng: -79.7948168 },
-Marker { lat: 43.6600719, lng: -79.5164138 },
-Marker { lat: 43.4282526, lng: -79.7546148 },
-Marker { lat: 43.7494133, lng: -79.7730888 },
-Marker { lat: 43.6457103, lng: -79.5145589 },
-Marker { lat: 43.601038, lng: -79.709741 },
-Marker { lat: 43.802145, lng: -79.4206681 },
-Marker { lat: 43.6036254, lng: -79.6939638 },
-Marker { lat: 43.6876996, lng: -79.4674183 },
-Marker { lat: 43.766314, lng: -79.74784 },
-Marker { lat: 43.717564, lng: -79.7264808 },
-Marker { lat: 43.8090153, lng: -79.7322489 },
-Marker { lat: 43.7107688, lng: -79.7238786 },
-Marker { lat: 43.5350747, lng: -79.8605302 },
-Marker { lat: 43.428668, lng: -79.7031432 },
-Marker { lat: 43.411811, lng: -79.801472 },
-Marker { lat: 43.5619689, lng: -79.7374941 },
-Marker { lat: 43.6947043, lng: -79.5576801 },
-Marker { lat: 43.3434469, lng: -79.7963879 },
-Marker { lat: 43.8961675, lng: -79.4329359 },
-Marker { lat: 43.9036446, lng: -79.6505264 },
-Marker { lat: 43.7242852, lng: -79.4233642 },
-Marker { lat: 43.7695626, lng: -79.4134466 },
-Marker { lat: 43.780853, lng: -79.4144717 },
-Marker { lat: 43.780853, lng: -79.412934 },
-Marker { lat: 43.7141279, lng: -79.3920441 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.6679848, lng: -79.3755762 },
-Marker { lat: 43.7002792, lng: -79.4278103 },
-Marker { lat: 43.6695584, lng: -79.3846569 },
-Marker { lat: 43.772832, lng: -79.412978 },
-Marker { lat: 43.651327, lng: -79.3583833 },
-Marker { lat: 43.9057871, lng: -78.9743136 },
-Marker { lat: 43.9568259, lng: -78.9634517 },
-Marker { lat: 43.8667088, lng: -79.438789 },
-Marker { lat: 43.796519, lng: -79.518865 },
-Marker { lat: 43.5737365, lng: -79.5788452 },
-Marker { lat: 43.8735933, lng: -79.3295128 },
-Marker { lat: 43.7494363, lng: -79.2092391 },
-Marker { lat: 43.4735747, lng: -79.6365465 },
-Marker { lat: 43.8495444, lng: -79.0654087 },
-Marker { lat: 43.558921, lng: -79.643939 },
-Marker { lat: 43.4449377, lng: -79.6823965 },
-Marker { lat: 43.6875507, lng: -79.800025 },
-Marker { lat: 43.705845, lng: -79.466962 },
-Marker { lat: 43.8745824, lng: -79.2726648 },
-Marker { lat: 43.7271344, lng: -79.5654462 },
-Marker { lat: 43.7654079, lng: -79.7243942 },
-Marker { lat: 43.5845766, lng: -79.723839 },
-Marker { lat: 43.9771081, lng: -79.4781684 },
-Marker { lat: 43.642643, lng: -79.527247 },
-Marker { lat: 43.4273331, lng: -79.7455358 },
-Marker { lat: 43.7057634, lng: -79.466791 },
-Marker { lat: 43.77535, lng: -79.760604 },
-Marker { lat: 43.8612785, lng: -79.3488571 },
-Marker { lat: 43.7447117, lng: -79.2228141 },
-Marker { lat: 43.8426315, lng: -79.4669955 },
-Marker { lat: 43.5863243, lng: -79.6413876 },
-Marker { lat: 43.8497821, lng: -79.3288246 },
-Marker { lat: 43.5653261, lng: -79.7404961 },
-Marker { lat: 43.6664277, lng: -79.8143448 },
-Marker { lat: 43.7024886, lng: -79.8431824 },
-Marker { lat: 43.4498303, lng: -79.7271343 },
-Marker { lat: 43.5964444, lng: -79.5443432 },
-Marker { lat: 43.8983, lng: -79.4064 },
-Marker { lat: 43.7625782, lng: -79.3659614 },
-Marker { lat: 43.6414554, lng: -79.3912936 },
-Marker { lat: 43.7690784, lng: -79.3694836 },
-Marker { lat: 43.955584, lng: -79.572696 },
-Marker { lat: 43.594913, lng: -79.635736 },
-Marker { lat: 43.7084468, lng: -79.3976005 },
-Marker { lat: 43.6429141, lng: -79.3806469 },
-Marker { lat: 43.641806, lng: -79.378981 },
-Marker { lat: 43.7056504, lng: -79.3973799 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.6427513, lng: -79.3769186 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.5809236, lng: -79.6238027 },
-Marker { lat: 43.7785806, lng: -79.2811718 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.6426507, lng: -79.3773286 },
-Marker { lat: 43.6462722, lng: -79.3945349 },
-Marker { lat: 43.672986, lng: -79.387029 },
-Marker { lat: 43.664773, lng: -79.386987 },
-Marker { lat: 43.7602436, lng: -79.4083424 },
-Marker { lat: 43.630681, lng: -79.882218 },
-Marker { lat: 43.690637, lng: -79.341822 },
-Marker { lat: 43.802145, lng: -79.4206681 },
-Marker { lat: 43.664844, lng: -79.38593 },
-Marker { lat: 43.7648208, lng: -79.410443 },
-Marker { lat: 43.8146069, lng: -79.2334758 },
-Marker { lat: 43.981979, lng: -79.4832516 },
-Marker { lat: 43.623821, lng: -79.478728 },
-Marker { lat: 43.641785, lng: -79.379006 },
-Marker { lat: 43.7642352, lng: -79.4127406 },
-Marker { lat: 43.731744, lng: -79.577292 },
-Marker { lat: 43.447443, lng: -79.686948 },
-Marker { lat: 43.4087632, lng: -79.819142 },
-Marker { lat: 43.765261, lng: -79.4143597 },
-Marker { lat: 43.970816, lng: -79.249128 },
-Marker { lat: 43.642548, lng: -79.425899 },
-Marker { lat: 43.818981, lng: -79.079008 },
-Marker { lat: 43.495476, lng: -79.718644 },
-Marker { lat: 43.8825679, lng: -79.493175 },
-Marker { lat: 43.4400475, lng: -79.7378899 },
-Marker { lat: 43.953941, lng: -78.895383 },
-Marker { lat: 43.4008212, lng: -79.8241167 },
-Marker { lat: 43.6534384, lng: -79.8101954 },
-Marker { lat: 43.5546111, lng: -79.7106761 },
-Marker { lat: 43.919627, lng: -79.799857 },
-Marker { lat: 43.827056, lng: -79.113665 },
-Marker { lat: 43.7877417, lng: -79.7494199 },
-Marker { lat: 43.8509016, lng: -79.4306409 },
-Marker { lat: 43.7084948, lng: -79.7495266 },
-Marker { lat: 43.6316681, lng: -79.548399 },
-Marker { lat: 43.641806, lng: -79.378981 },
-Marker { lat: 43.6466902, lng: -79.4218755 },
-Marker { lat: 43.9133161, lng: -78.9550351 },
-Marker { lat: 43.6381724, lng: -79.489059 },
-Marker { lat: 43.7398872, lng: -79.4860122 },
-Marker { lat: 43.7398872, lng: -79.4860122 },
-Marker { lat: 43.6358644, lng: -79.4673894 },
-Marker { lat: 43.9627558, lng: -78.9055234 },
-Marker { lat: 43.7727963, lng: -79.3453943 },
-Marker { lat: 43.7843151, lng: -79.3717668 },
-Marker { lat: 43.9785703, lng: -79.4462784 },
-Marker { lat: 43.4818934, lng: -79.7267202 },
-Marker { lat: 43.6467185, lng: -79.5254493 },
-Marker { lat: 43.6706912, lng: -79.4783546 },
-Marker { lat: 43.6673325, lng: -79.8030392 },
-Marker { lat: 43.5671142, lng: -79.5720556 },
-Marker { lat: 43.5671142, lng: -79.5720556 },
-Marker { lat: 43.6626979, lng: -79.3794096 },
-Marker { lat: 43.7842158, lng: -79.3967163 },
-Marker { lat: 43.6510293, lng: -79.7515727 },
-Marker { lat: 43.6513368, lng: -79.7510423 },
-Marker { lat: 43.6997614, lng: -79.7595255 },
-Marker { lat: 43.829103, lng: -79.10092 },
-Marker { lat: 43.6807221, lng: -79.7832496 },
-Marker { lat: 43.6464451, lng: -79.5257461 },
-Marker { lat: 43.7930527, lng: -79.5812928 },
-Marker { lat: 43.913658, lng: -79.6485229 },
-Marker { lat: 43.7034304, lng: -79.5257417 },
-Marker { lat: 43.5671142, lng: -79.5720556 },
-Marker { lat: 43.7406387, lng: -79.7429786 },
-Marker { lat: 43.671238, lng: -79.823197 },
-Marker { lat: 43.6411407, lng: -79.4036466 },
-Marker { lat: 43.7907553, lng: -79.758992 },
-Marker { lat: 43.6617616, lng: -79.3836302 },
-Marker { lat: 43.5935564, lng: -79.5336308 },
-Marker { lat: 43.5841003, lng: -79.6446072 },
-Marker { lat: 43.5935564, lng: -79.5336308 },
-Marker { lat: 43.8316837, lng: -79.1328101 },
-Marker { lat: 43.8940298, lng: -79.0418276 },
-Marker { lat: 43.871239, lng: -79.710657 },
-Marker { lat: 43.661967, lng: -79.383964 },
-Marker { lat: 43.8873431, lng: -79.4073095 },
-Marker { lat: 43.6617616, lng: -79.3836302 },
-Marker { lat: 43.4529826, lng: -79.6835772 },
-Marker { lat: 43.4529826, lng: -79.6835772 },
-Marker { lat: 43.819654, lng: -79.022235 },
-Marker { lat: 43.7650996, lng: -79.7270478 },
-Marker { lat: 43.7034304, lng: -79.5257417 },
-Marker { lat: 43.8419241, lng: -79.0725646 },
-Marker { lat: 43.67369, lng: -79.3417634 },
-Marker { lat: 43.45176, lng: -79.65631 },
-Marker { lat: 43.7827, lng: -79.4673 },
-Marker { lat: 43.8430677, lng: -79.4265211 },
-Marker { lat: 43.4930027, lng: -79.8706212 },
-Marker { lat: 43.6862934, lng: -79.3074313 },
-Marker { lat: 43.865177, lng: -79.027217 },
-Marker { lat: 43.7704603, lng: -79.4322739 },
-Marker { lat: 43.4479112, lng: -79.9295999 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.7630506, lng: -79.2828027 },
-Marker { lat: 43.7893747, lng: -79.5306859 },
-Marker { lat: 43.671819, lng: -79.3724032 },
-Marker { lat: 43.8003304, lng: -79.4787099 },
-Marker { lat: 43.9553569, lng: -79.2640615 },
-Marker { lat: 43.5355336, lng: -79.6607718 },
-Marker { lat: 43.4529826, lng: -79.6835772 },
-Marker { lat: 43.4555421, lng: -79.6679018 },
-Marker { lat: 43.850146, lng: -79.6096926 },
-Marker { lat: 43.8606975, lng: -78.9383595 },
-Marker { lat: 43.6368129, lng: -79.3975163 },
-Marker { lat: 43.5671142, lng: -79.5720556 },
-Marker { lat: 43.7590053, lng: -79.5885886 },
-Marker { lat: 43.6718462, lng: -79.3726355 },
-Marker { lat: 43.6785321, lng: -79.8279415 },
-Marker { lat: 43.5656739, lng: -79.7024956 },
-Marker { lat: 43.6520694, lng: -79.8044671 },
-Marker { lat: 43.721861, lng: -79.448516 },
-Marker { lat: 43.778141, lng: -79.406668 },
-Marker { lat: 43.6387257, lng: -79.5356352 },
-Marker { lat: 43.4121113, lng: -79.6986941 },
-Marker { lat: 43.8132447, lng: -79.412254 },
-Marker { lat: 43.3414059, lng: -79.7928312 },
-Marker { lat: 43.7430414, lng: -79.3659229 },
-Marker { lat: 43.6388334, lng: -79.3988388 },
-Marker { lat: 43.7585111, lng: -79.3765865 },
-Marker { lat: 43.983192, lng: -79.771893 },
-Marker { lat: 43.7990117, lng: -79.5910862 },
-Marker { lat: 43.3775777, lng: -79.8066593 },
-Marker { lat: 43.9461791, lng: -79.7787746 },
-Marker { lat: 43.433141, lng: -79.770423 },
-Marker { lat: 43.486598, lng: -79.720422 },
-Marker { lat: 43.6427756, lng: -79.4984381 },
-Marker { lat: 43.6359244, lng: -79.7131849 },
-Marker { lat: 43.3621489, lng: -79.7391312 },
-Marker { lat: 43.7246556, lng: -79.5300975 },
-Marker { lat: 43.6605241, lng: -79.4492841 },
-Marker { lat: 43.3590482, lng: -79.9410353 },
-Marker { lat: 43.564334, lng: -79.570084 },
-Marker { lat: 43.75618, lng: -79.398791 },
-Marker { lat: 43.398877, lng: -79.7152548 },
-Marker { lat: 43.3954773, lng: -79.8180629 },
-Marker { lat: 43.899892, lng: -79.010573 },
-Marker { lat: 43.6397347, lng: -79.3920867 },
-Marker { lat: 43.831699, lng: -79.06477 },
-Marker { lat: 43.8399429, lng: -79.4700121 },
-Marker { lat: 43.358787, lng: -79.8471878 },
-Marker { lat: 43.3940052, lng: -79.7087494 },
-Marker { lat: 43.7286405, lng: -79.4636886 },
-Marker { lat: 43.5413969, lng: -79.5958942 },
-Marker { lat: 43.4539938, lng: -79.6683522 },
-Marker { lat: 43.7434438, lng: -79.3986633 },
-Marker { lat: 43.5805107, lng: -79.6244991 },
-Marker { lat: 43.9204073, lng: -79.5224306 },
-Marker { lat: 43.6263259, lng: -79.477234 },
-Marker { lat: 43.7957722, lng: -79.2305512 },
-Marker { lat: 43.3554902, lng: -79.7502084 },
-Marker { lat: 43.7366766, lng: -79.7212827 },
-Marker { lat: 43.8543625, lng: -79.6377077 },
-Marker { lat: 43.731351, lng: -79.48585 },
-Marker { lat: 43.780324, lng: -79.418647 },
-Marker { lat: 43.4285, lng: -79.7445 },
-Marker { lat: 43.8326, lng: -79.6325 },
-Marker { lat: 43.9398619, lng: -79.4735329 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.880364, lng: -79.00241 },
-Marker { lat: 43.4769, lng: -79.6517 },
-Marker { lat: 43.8125538, lng: -79.4529616 },
-Marker { lat: 43.543937, lng: -79.892214 },
-Marker { lat: 43.8793, lng: -79.497 },
-Marker { lat: 43.7842507, lng: -79.5962857 },
-Marker { lat: 43.603957, lng: -79.6496967 },
-Marker { lat: 43.795759, lng: -79.520472 },
-Marker { lat: 43.871448, lng: -79.774777 },
-Marker { lat: 43.7472676, lng: -79.3999829 },
-Marker { lat: 43.5524496, lng: -79.6109675 },
-Marker { lat: 43.7070826, lng: -79.3977712 },
-Marker { lat: 43.7953769, lng: -79.7359535 },
-Marker { lat: 43.8988459, lng: -79.4257251 },
-Marker { lat: 43.6704, lng: -79.7528 },
-Marker { lat: 43.707083, lng: -79.397771 },
-Marker { lat: 43.753877, lng: -79.385386 },
-Marker { lat: 43.6417992, lng: -79.5445367 },
-Marker { lat: 43.7688896, lng: -79.4180457 },
-Marker { lat: 43.7438137, lng: -79.3999152 },
-Marker { lat: 43.625807, lng: -79.477924 },
-Marker { lat: 43.6056375, lng: -79.4984726 },
-Marker { lat: 43.6395122, lng: -79.5828363 },
-Marker { lat: 43.4360764, lng: -79.7763802 },
-Marker { lat: 43.8882009, lng: -79.2350784 },
-Marker { lat: 43.8705234, lng: -78.932931 },
-Marker { lat: 43.60371, lng: -79.545679 },
-Marker { lat: 43.8681264, lng: -79.2130916 },
-Marker { lat: 43.749821, lng: -79.384514 },
-Marker { lat: 43.4650271, lng: -79.6584269 },
-Marker { lat: 43.8629236, lng: -79.2234014 },
-Marker { lat: 43.7853926, lng: -79.5994358 },
-Marker { lat: 43.88701, lng: -79.3936526 },
-Marker { lat: 43.863707, lng: -79.514399 },
-Marker { lat: 43.8427373, lng: -79.9223509 },
-Marker { lat: 43.6584381, lng: -79.4705413 },
-Marker { lat: 43.659454, lng: -79.382587 },
-Marker { lat: 43.7487107, lng: -79.4326312 },
-Marker { lat: 43.7884904, lng: -79.452403 },
-Marker { lat: 43.7023628, lng: -79.4479735 },
-Marker { lat: 43.3767332, lng: -79.7203957 },
-Marker { lat: 43.7386, lng: -79.8437 },
-Marker { lat: 43.650405, lng: -79.392168 },
-Marker { lat: 43.4816061, lng: -79.8741102 },
-Marker { lat: 43.7491906, lng: -79.4040226 },
-Marker { lat: 43.658924, lng: -79.3840439 },
-Marker { lat: 43.8533449, lng: -79.5532775 },
-Marker { lat: 43.7855946, lng: -79.3645242 },
-Marker { lat: 43.8441286, lng: -79.5528643 },
-Marker { lat: 43.652572, lng: -79.363722 },
-Marker { lat: 43.7703, lng: -79.413645 },
-Marker { lat: 43.7544787, lng: -79.3941315 },
-Marker { lat: 43.9394042, lng: -79.0295658 },
-Marker { lat: 43.4670676, lng: -79.657395 },
-Marker { lat: 43.9865649, lng: -79.4814569 },
-Marker { lat: 43.485136, lng: -79.849159 },
-Marker { lat: 43.8044089, lng: -79.7452375 },
-Marker { lat: 43.456768, lng: -79.706792 },
-Marker { lat: 43.618993, lng: -79.483137 },
-Marker { lat: 43.7418, lng: -79.4709 },
-Marker { lat: 43.9010081, lng: -79.4292539 },
-Marker { lat: 43.7691232, lng: -79.3699769 },
-Marker { lat: 43.7938043, lng: -79.1566145 },
-Marker { lat: 43.7800398, lng: -79.4126795 },
-Marker { lat: 43.961019, lng: -78.892052 },
-Marker { lat: 43.827954, lng: -79.122653 },
-Marker { lat: 43.8480882, lng: -79.5714003 },
-Marker { lat: 43.8198174, lng: -79.6182177 },
-Marker { lat: 43.8169954, lng: -79.7232336 },
-Marker { lat: 43.680643, lng: -79.8395583 },
-Marker { lat: 43.768673, lng: -79.177074 },
-Marker { lat: 43.778613, lng: -79.4510561 },
-Marker { lat: 43.8821374, lng: -78.9333207 },
-Marker { lat: 43.6915081, lng: -79.7754452 },
-Marker { lat: 43.7262279, lng: -79.7337354 },
-Marker { lat: 43.693802, lng: -79.326994 },
-Marker { lat: 43.6688114, lng: -79.3068879 },
-Marker { lat: 43.8669461, lng: -79.4405641 },
-Marker { lat: 43.5917708, lng: -79.6157969 },
-Marker { lat: 43.8970108, lng: -79.4687358 },
-Marker { lat: 43.9040847, lng: -79.2324901 },
-Marker { lat: 43.882782, lng: -79.34572 },
-Marker { lat: 43.6740872, lng: -79.2904412 },
-Marker { lat: 43.694369, lng: -79.278434 },
-Marker { lat: 43.8140985, lng: -79.4514793 },
-Marker { lat: 43.6489, lng: -79.392205 },
-Marker { lat: 43.7477322, lng: -79.579668 },
-Marker { lat: 43.9111387, lng: -78.9153748 },
-Marker { lat: 43.9008955, lng: -79.255943 },
-Marker { lat: 43.774173, lng: -79.4202456 },
-Marker { lat: 43.7238478, lng: -79.4372562 },
-Marker { lat: 43.877967, lng: -79.233088 },
-Marker { lat: 43.6834366, lng: -79.3035764 },
-Marker { lat: 43.85195, lng: -79.431773 },
-Marker { lat: 43.796519, lng: -79.518865 },
-Marker { lat: 43.636943, lng: -79.4689321 },
-Marker { lat: 43.9700014, lng: -79.4787955 },
-Marker { lat: 43.6037837, lng: -79.7035156 },
-Marker { lat: 43.8935993, lng: -79.3736075 },
-Marker { lat: 43.6024134, lng: -79.5050072 },
-Marker { lat: 43.651605, lng: -79.410692 },
-Marker { lat: 43.6460155, lng: -79.3940644 },
-Marker { lat: 43.6403228, lng: -79.3917567 },
-Marker { lat: 43.767938, lng: -79.17758 },
-Marker { lat: 43.62526, lng: -79.477903 },
-Marker { lat: 43.4902762, lng: -79.8718435 },
-Marker { lat: 43.7666151, lng: -79.2854001 },
-Marker { lat: 43.6740324, lng: -79.3904727 },
-Marker { lat: 43.8440632, lng: -79.433706 },
-Marker { lat: 43.856131, lng: -79.045503 },
-Marker { lat: 43.7866554, lng: -79.3896426 },
-Marker { lat: 43.665786, lng: -79.3855809 },
-Marker { lat: 43.94631, lng: -79.482145 },
-Marker { lat: 43.6355252, lng: -79.5675095 },
-Marker { lat: 43.891209, lng: -78.9048902 },
-Marker { lat: 43.975839, lng: -79.664508 },
-Marker { lat: 43.476709, lng: -79.765059 },
-Marker { lat: 43.833663, lng: -79.661922 },
-Marker { lat: 43.871332, lng: -79.867277 },
-Marker { lat: 43.5508057, lng: -79.8613284 },
-Marker { lat: 43.866906, lng: -79.341517 },
-Marker { lat: 43.6366949, lng: -79.4324875 },
-Marker { lat: 43.453553, lng: -79.679132 },
-Marker { lat: 43.843006, lng: -79.712148 },
-Marker { lat: 43.8333819, lng: -79.4361256 },
-Marker { lat: 43.8728615, lng: -79.4363728 },
-Marker { lat: 43.4501767, lng: -79.9224315 },
-Marker { lat: 43.8746247, lng: -79.0622748 },
-Marker { lat: 43.791887, lng: -79.3535054 },
-Marker { lat: 43.697717, lng: -79.51213 },
-Marker { lat: 43.6976288, lng: -79.5120986 },
-Marker { lat: 43.697717, lng: -79.51213 },
-Marker { lat: 43.697717, lng: -79.51213 },
-Marker { lat: 43.6976288, lng: -79.5120986 },
-Marker { lat: 43.697717, lng: -79.51213 },
-Marker { lat: 43.901675, lng: -79.6512957 },
-Marker { lat: 43.7692297, lng: -79.411935 },
-Marker { lat: 43.84974, lng: -79.067092 },
-Marker { lat: 43.84903, lng: -79.067578 },
-Marker { lat: 43.84937, lng: -79.067699 },
-Marker { lat: 43.849451, lng: -79.066935 },
-Marker { lat: 43.848535, lng: -79.067476 },
-Marker { lat: 43.825456, lng: -79.073652 },
-Marker { lat: 43.957096, lng: -79.448611 },
-Marker { lat: 43.6274692, lng: -79.4771277 },
-Marker { lat: 43.452597, lng: -79.6708593 },
-Marker { lat: 43.849604, lng: -79.067803 },
-Marker { lat: 43.443748, lng: -79.684727 },
-Marker { lat: 43.773162, lng: -79.403535 },
-Marker { lat: 43.8810727, lng: -79.3689088 },
-Marker { lat: 43.944767, lng: -79.482384 },
-Marker { lat: 43.637724, lng: -79.39508 },
-Marker { lat: 43.5610026, lng: -79.5751777 },
-Marker { lat: 43.7686926, lng: -79.4275037 },
-Marker { lat: 43.623513, lng: -79.479562 },
-Marker { lat: 43.7197126, lng: -79.5155828 },
-Marker { lat: 43.6241341, lng: -79.4858146 },
-Marker { lat: 43.849149, lng: -79.065278 },
-Marker { lat: 43.643237, lng: -79.558759 },
-Marker { lat: 43.7549929, lng: -79.4238332 },
-Marker { lat: 43.4545792, lng: -79.6600479 },
-Marker { lat: 43.8188239, lng: -79.7170417 },
-Marker { lat: 43.7994407, lng: -79.2927461 },
-Marker { lat: 43.7267158, lng: -79.4263704 },
-Marker { lat: 43.8305579, lng: -79.4654061 },
-Marker { lat: 43.7486339, lng: -79.5522568 },
-Marker { lat: 43.9636846, lng: -79.2288086 },
-Marker { lat: 43.711825, lng: -79.2633446 },
-Marker { lat: 43.7150075, lng: -79.3660971 },
-Marker { lat: 43.3863858, lng: -79.8154969 },
-Marker { lat: 43.7451865, lng: -79.3084749 },
-Marker { lat: 43.3587761, lng: -79.7510522 },
-Marker { lat: 43.7228324, lng: -79.3093719 },
-Marker { lat: 43.948309, lng: -78.925777 },
-Marker { lat: 43.9236888, lng: -79.5306585 },
-Marker { lat: 43.8081345, lng: -79.4386576 },
-Marker { lat: 43.9264873, lng: -79.5166768 },
-Marker { lat: 43.802145, lng: -79.4206681 },
-Marker { lat: 43.7892655, lng: -79.4364369 },
-Marker { lat: 43.8887892, lng: -79.3408074 },
-Marker { lat: 43.920812, lng: -78.915962 },
-Marker { lat: 43.685714, lng: -79.404702 },
-Marker { lat: 43.697048, lng: -79.443203 },
-Marker { lat: 43.8834017, lng: -79.3062972 },
-Marker { lat: 43.952108, lng: -79.438072 },
-Marker { lat: 43.7887139, lng: -79.4583778 },
-Marker { lat: 43.8646859, lng: -79.3135411 },
-Marker { lat: 43.8311637, lng: -79.4046219 },
-Marker { lat: 43.823243, lng: -79.57372 },
-Marker { lat: 43.6386323, lng: -79.399676 },
-Marker { lat: 43.478741, lng: -79.759713 },
-Marker { lat: 43.6384898, lng: -79.7126322 },
-Marker { lat: 43.6344845, lng: -79.5656952 },
-Marker { lat: 43.7415941, lng: -79.5434627 },
-Marker { lat: 43.8613752, lng: -79.2944553 },
-Marker { lat: 43.7464177, lng: -79.5099064 },
-Marker { lat: 43.653408, lng: -79.4988186 },
-Marker { lat: 43.639567, lng: -79.564168 },
-Marker { lat: 43.4793057, lng: -79.7216264 },
-Marker { lat: 43.7878792, lng: -79.7183569 },
-Marker { lat: 43.892428, lng: -79.897488 },
-Marker { lat: 43.971496, lng: -78.992649 },
-Marker { lat: 43.7822086, lng: -79.3890393 },
-Marker { lat: 43.953809, lng: -79.324663 },
-Marker { lat: 43.8597456, lng: -79.3265612 },
-Marker { lat: 43.68078, lng: -79.425021 },
-Marker { lat: 43.4615833, lng: -79.7292864 },
-Marker { lat: 43.646099, lng: -79.365136 },
-Marker { lat: 43.802257, lng: -79.392852 },
-Marker { lat: 43.9300867, lng: -79.535333 },
-Marker { lat: 43.5373178, lng: -79.6205807 },
-Marker { lat: 43.7338186, lng: -79.346789 },
-Marker { lat: 43.7959991, lng: -79.4442188 },
-Marker { lat: 43.9768938, lng: -79.4572807 },
-Marker { lat: 43.7656363, lng: -79.4324473 },
-Marker { lat: 43.4501618, lng: -79.7329067 },
-Marker { lat: 43.5702845, lng: -79.631994 },
-Marker { lat: 43.8915887, lng: -79.4281468 },
-Marker { lat: 43.8294326, lng: -79.5662765 },
-Marker { lat: 43.942485, lng: -79.4781898 },
-Marker { lat: 43.775424, lng: -79.337482 },
-Marker { lat: 43.9415906, lng: -79.0289262 },
-Marker { lat: 43.7890853, lng: -79.4372567 },
-Marker { lat: 43.610842, lng: -79.6501712 },
-Marker { lat: 43.813647, lng: -79.4550817 },
-Marker { lat: 43.7087641, lng: -79.2537594 },
-Marker { lat: 43.5984106, lng: -79.6347881 },
-Marker { lat: 43.640955, lng: -79.393844 },
-Marker { lat: 43.6958406, lng: -79.3710147 },
-Marker { lat: 43.8061476, lng: -79.2555995 },
-Marker { lat: 43.7370862, lng: -79.4644372 },
-Marker { lat: 43.64182, lng: -79.381734 },
-Marker { lat: 43.812554, lng: -79.4544922 },
-Marker { lat: 43.665388, lng: -79.3880211 },
-Marker { lat: 43.586289, lng: -79.647364 },
-Marker { lat: 43.8628157, lng: -79.4416326 },
-Marker { lat: 43.5730593, lng: -79.7091852 },
-Marker { lat: 43.8664336, lng: -79.4355835 },
-Marker { lat: 43.6400837, lng: -79.3790949 },
-Marker { lat: 43.6009698, lng: -79.5452604 },
-Marker { lat: 43.834228, lng: -79.126098 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.776323, lng: -79.335927 },
-Marker { lat: 43.6214, lng: -79.911047 },
-Marker { lat: 43.7933998, lng: -79.6712452 },
-Marker { lat: 43.653934, lng: -79.810225 },
-Marker { lat: 43.8067482, lng: -79.3740919 },
-Marker { lat: 43.8553593, lng: -79.3434198 },
-Marker { lat: 43.7989698, lng: -79.4148234 },
-Marker { lat: 43.4559437, lng: -79.6611719 },
-Marker { lat: 43.8036515, lng: -79.4505507 },
-Marker { lat: 43.940646, lng: -79.475356 },
-Marker { lat: 43.7961517, lng: -79.4390752 },
-Marker { lat: 43.646274, lng: -79.3614175 },
-Marker { lat: 43.9702672, lng: -79.2637063 },
-Marker { lat: 43.447473, lng: -79.68731 },
-Marker { lat: 43.7443219, lng: -79.4189832 },
-Marker { lat: 43.7671729, lng: -79.40028 },
-Marker { lat: 43.6398049, lng: -79.3979687 },
-Marker { lat: 43.6386152, lng: -79.4127418 },
-Marker { lat: 43.410359, lng: -79.791237 },
-Marker { lat: 43.8128057, lng: -79.4519464 },
-Marker { lat: 43.7755305, lng: -79.4152516 },
-Marker { lat: 43.62933, lng: -79.476348 },
-Marker { lat: 43.9019183, lng: -79.2523583 },
-Marker { lat: 43.939768, lng: -79.466367 },
-Marker { lat: 43.6743501, lng: -79.4565812 },
-Marker { lat: 43.8469253, lng: -79.5171372 },
-Marker { lat: 43.532508, lng: -79.930354 },
-Marker { lat: 43.5738069, lng: -79.587996 },
-Marker { lat: 43.5714037, lng: -79.7825066 },
-Marker { lat: 43.7802681, lng: -79.4360638 },
-Marker { lat: 43.9470963, lng: -78.9367144 },
-Marker { lat: 43.7777736, lng: -79.3068188 },
-Marker { lat: 43.747544, lng: -79.28592 },
-Marker { lat: 43.843866, lng: -79.431036 },
-Marker { lat: 43.9718527, lng: -79.1299089 },
-Marker { lat: 43.8633183, lng: -79.3199135 },
-Marker { lat: 43.5161295, lng: -79.6025546 },
-Marker { lat: 43.8895424, lng: -79.3364377 },
-Marker { lat: 43.867882, lng: -79.866703 },
-Marker { lat: 43.5181921, lng: -79.6340019 },
-Marker { lat: 43.4152972, lng: -79.7014963 },
-Marker { lat: 43.9042917, lng: -79.2776743 },
-Marker { lat: 43.672194, lng: -79.387865 },
-Marker { lat: 43.7415135, lng: -79.3922842 },
-Marker { lat: 43.7020783, lng: -79.4191322 },
-Marker { lat: 43.869645, lng: -78.946695 },
-Marker { lat: 43.862011, lng: -79.481891 },
-Marker { lat: 43.882782, lng: -79.298042 },
-Marker { lat: 43.821012, lng: -79.4469339 },
-Marker { lat: 43.8200556, lng: -79.3768289 },
-Marker { lat: 43.700221, lng: -79.427761 },
-Marker { lat: 43.538031, lng: -79.6205573 },
-Marker { lat: 43.4484831, lng: -79.6642619 },
-Marker { lat: 43.4384731, lng: -79.680557 },
-Marker { lat: 43.7695626, lng: -79.4134466 },
-Marker { lat: 43.9702672, lng: -79.2637063 },
-Marker { lat: 43.650405, lng: -79.392168 },
-Marker { lat: 43.894338, lng: -79.2209233 },
-Marker { lat: 43.9539343, lng: -79.4812993 },
-Marker { lat: 43.7130728, lng: -79.3905173 },
-Marker { lat: 43.8585276, lng: -79.451226 },
-Marker { lat: 43.805462, lng: -79.43575 },
-Marker { lat: 43.681787, lng: -79.470323 },
-Marker { lat: 43.746149, lng: -79.3613486 },
-Marker { lat: 43.8555059, lng: -79.5014747 },
-Marker { lat: 43.9438131, lng: -79.4621117 },
-Marker { lat: 43.7458437, lng: -79.3908185 },
-Marker { lat: 43.5258101, lng: -79.6690748 },
-Marker { lat: 43.4434425, lng: -79.6699854 },
-Marker { lat: 43.795299, lng: -79.3014786 },
-Marker { lat: 43.4401402, lng: -79.6765163 },
-Marker { lat: 43.4939343, lng: -79.7044515 },
-Marker { lat: 43.8941579, lng: -79.3110231 },
-Marker { lat: 43.8711463, lng: -79.4392892 },
-Marker { lat: 43.6633091, lng: -79.3662602 },
-Marker { lat: 43.7422402, lng: -79.3892217 },
-Marker { lat: 43.6609916, lng: -79.8044496 },
-Marker { lat: 43.5517786, lng: -79.7217634 },
-Marker { lat: 43.4582458, lng: -79.6586392 },
-Marker { lat: 43.4582973, lng: -79.6525786 },
-Marker { lat: 43.5529847, lng: -79.7324364 },
-Marker { lat: 43.641596, lng: -79.423443 },
-Marker { lat: 43.4569527, lng: -79.6597724 },
-Marker { lat: 43.8899637, lng: -79.2135022 },
-Marker { lat: 43.350223, lng: -79.873215 },
-Marker { lat: 43.5205716, lng: -79.8809432 },
-Marker { lat: 43.64534, lng: -79.402185 },
-Marker { lat: 43.64534, lng: -79.402185 },
-Marker { lat: 43.694633, lng: -79.847553 },
-Marker { lat: 43.664701, lng: -79.77974 },
-Marker { lat: 43.656165, lng: -79.383893 },
-Marker { lat: 43.7764488, lng: -79.317786 },
-Marker { lat: 43.849132, lng: -79.432632 },
-Marker { lat: 43.865734, lng: -79.065205 },
-Marker { lat: 43.661363, lng: -79.386445 },
-Marker { lat: 43.3954966, lng: -79.8290204 },
-Marker { lat: 43.4974207, lng: -79.7424764 },
-Marker { lat: 43.67272, lng: -79.928141 },
-Marker { lat: 43.376057, lng: -79.7260197 },
-Marker { lat: 43.641605, lng: -79.528263 },
-Marker { lat: 43.908448, lng: -79.372054 },
-Marker { lat: 43.650554, lng: -79.357382 },
-Marker { lat: 43.842168, lng: -79.433249 },
-Marker { lat: 43.912082, lng: -79.053057 },
-Marker { lat: 43.438246, lng: -79.685952 },
-Marker { lat: 43.430908, lng: -79.725622 },
-Marker { lat: 43.808182, lng: -79.378377 },
-Marker { lat: 43.4449245, lng: -79.6827876 },
-Marker { lat: 43.6326794, lng: -79.5019916 },
-Marker { lat: 43.4326741, lng: -79.6835303 },
-Marker { lat: 43.648317, lng: -79.393065 },
-Marker { lat: 43.4813689, lng: -79.8752693 },
-Marker { lat: 43.621064, lng: -79.48332 },
-Marker { lat: 43.4756347, lng: -79.6940017 },
-Marker { lat: 43.659948, lng: -79.451586 },
-Marker { lat: 43.411811, lng: -79.801472 },
-Marker { lat: 43.345889, lng: -79.881669 },
-Marker { lat: 43.479585, lng: -79.725131 },
-Marker { lat: 43.659824, lng: -79.362727 },
-Marker { lat: 43.760108, lng: -79.408225 },
-Marker { lat: 43.793854, lng: -79.443389 },
-Marker { lat: 43.932223, lng: -79.520834 },
-Marker { lat: 43.6584681, lng: -79.3665242 },
-Marker { lat: 43.889038, lng: -79.280416 },
-Marker { lat: 43.961678, lng: -79.4372405 },
-Marker { lat: 43.622114, lng: -79.52269 },
-Marker { lat: 43.641596, lng: -79.423443 },
-Marker { lat: 43.6978398, lng: -79.3871947 },
-Marker { lat: 43.451045, lng: -79.6809362 },
-Marker { lat: 43.874528, lng: -79.121418 },
-Marker { lat: 43.69976, lng: -79.518492 },
-Marker { lat: 43.650615, lng: -79.384663 },
-Marker { lat: 43.655305, lng: -79.3537502 },
-Marker { lat: 43.652039, lng: -79.382188 },
-Marker { lat: 43.346577, lng: -79.888241 },
-Marker { lat: 43.5126327, lng: -79.6309339 },
-Marker { lat: 43.718556, lng: -79.32629 },
-Marker { lat: 43.668718, lng: -79.383996 },
-Marker { lat: 43.6425128, lng: -79.3813182 },
-Marker { lat: 43.8535994, lng: -79.464877 },
-Marker { lat: 43.8534942, lng: -79.4653801 },
-Marker { lat: 43.4063347, lng: -79.7152932 },
-Marker { lat: 43.636021, lng: -79.9470453 },
-Marker { lat: 43.777401, lng: -79.412802 },
-Marker { lat: 43.4372866, lng: -79.7250528 },
-Marker { lat: 43.4251737, lng: -79.784818 },
-Marker { lat: 43.669301, lng: -79.384171 },
-Marker { lat: 43.765314, lng: -79.412362 },
-Marker { lat: 43.8280559, lng: -79.090088 },
-Marker { lat: 43.4845535, lng: -79.7314613 },
-Marker { lat: 43.861658, lng: -78.935551 },
-Marker { lat: 43.4656943, lng: -79.6674942 },
-Marker { lat: 43.4660391, lng: -79.6466473 },
-Marker { lat: 43.515143, lng: -79.883451 },
-Marker { lat: 43.5024947, lng: -79.6644273 },
-Marker { lat: 43.3947011, lng: -79.8277361 },
-Marker { lat: 43.714808, lng: -79.479883 },
-Marker { lat: 43.656333, lng: -79.383964 },
-Marker { lat: 43.7885944, lng: -79.2579252 },
-Marker { lat: 43.645395, lng: -79.448947 },
-Marker { lat: 43.664057, lng: -79.383809 },
-Marker { lat: 43.668359, lng: -79.412681 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.5604778, lng: -79.5852356 },
-Marker { lat: 43.663536, lng: -79.383736 },
-Marker { lat: 43.769638, lng: -79.389692 },
-Marker { lat: 43.7376729, lng: -79.2190043 },
-Marker { lat: 43.8343267, lng: -79.5759204 },
-Marker { lat: 43.513318, lng: -79.8327561 },
-Marker { lat: 43.751476, lng: -79.457932 },
-Marker { lat: 43.7260989, lng: -79.3960241 },
-Marker { lat: 43.707824, lng: -79.392725 },
-Marker { lat: 43.5332837, lng: -79.7061214 },
-Marker { lat: 43.8574656, lng: -79.533649 },
-Marker { lat: 43.631009, lng: -79.8185911 },
-Marker { lat: 43.7665999, lng: -79.3831676 },
-Marker { lat: 43.9640566, lng: -79.246437 },
-Marker { lat: 43.6302596, lng: -79.5779556 },
-Marker { lat: 43.8476466, lng: -79.2522795 },
-Marker { lat: 43.4221953, lng: -79.941171 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.9778457, lng: -79.2386859 },
-Marker { lat: 43.5007987, lng: -79.7033617 },
-Marker { lat: 43.4697135, lng: -79.7313171 },
-Marker { lat: 43.63821, lng: -79.39136 },
-Marker { lat: 43.7836411, lng: -79.6708427 },
-Marker { lat: 43.3495506, lng: -79.8222063 },
-Marker { lat: 43.8524456, lng: -79.3280054 },
-Marker { lat: 43.581187, lng: -79.647617 },
-Marker { lat: 43.774764, lng: -79.336918 },
-Marker { lat: 43.926537, lng: -78.882781 },
-Marker { lat: 43.843571, lng: -79.129515 },
-Marker { lat: 43.9677688, lng: -78.9552989 },
-Marker { lat: 43.7815814, lng: -79.3922116 },
-Marker { lat: 43.6708347, lng: -79.4918039 },
-Marker { lat: 43.7783428, lng: -79.4159581 },
-Marker { lat: 43.6965294, lng: -79.4320342 },
-Marker { lat: 43.636759, lng: -79.39785 },
-Marker { lat: 43.6657347, lng: -79.447861 },
-Marker { lat: 43.3700573, lng: -79.7410001 },
-Marker { lat: 43.773021, lng: -79.3898521 },
-Marker { lat: 43.77288, lng: -79.38983 },
-Marker { lat: 43.7843658, lng: -79.4151643 },
-Marker { lat: 43.717294, lng: -79.4418502 },
-Marker { lat: 43.5523348, lng: -79.7346634 },
-Marker { lat: 43.7653441, lng: -79.4126151 },
-Marker { lat: 43.6556642, lng: -79.8007224 },
-Marker { lat: 43.704604, lng: -79.38901 },
-Marker { lat: 43.6824332, lng: -79.3902828 },
-Marker { lat: 43.646809, lng: -79.390182 },
-Marker { lat: 43.792291, lng: -79.205226 },
-Marker { lat: 43.8217204, lng: -79.3829932 },
-Marker { lat: 43.962794, lng: -79.448501 },
-Marker { lat: 43.8714097, lng: -79.3273437 },
-Marker { lat: 43.7941787, lng: -79.4397985 },
-Marker { lat: 43.6592713, lng: -79.3849163 },
-Marker { lat: 43.774325, lng: -79.346091 },
-Marker { lat: 43.7183968, lng: -79.3033583 },
-Marker { lat: 43.6348247, lng: -79.40156 },
-Marker { lat: 43.9681297, lng: -79.2223317 },
-Marker { lat: 43.7078684, lng: -79.2557625 },
-Marker { lat: 43.6094162, lng: -79.55872 },
-Marker { lat: 43.5553349, lng: -79.7565573 },
-Marker { lat: 43.9497928, lng: -78.8913365 },
-Marker { lat: 43.6412251, lng: -79.3809582 },
-Marker { lat: 43.8798896, lng: -79.3690595 },
-Marker { lat: 43.429299, lng: -79.727932 },
-Marker { lat: 43.772466, lng: -79.355047 },
-Marker { lat: 43.777026, lng: -79.413773 },
-Marker { lat: 43.869978, lng: -79.758273 },
-Marker { lat: 43.7570297, lng: -79.8216312 },
-Marker { lat: 43.8034472, lng: -79.2042361 },
-Marker { lat: 43.9022712, lng: -79.2388663 },
-Marker { lat: 43.7970588, lng: -79.3773701 },
-Marker { lat: 43.7103893, lng: -79.3021145 },
-Marker { lat: 43.9001883, lng: -79.2330816 },
-Marker { lat: 43.7067331, lng: -79.2937299 },
-Marker { lat: 43.512833, lng: -79.8409545 },
-Marker { lat: 43.5635299, lng: -79.7447234 },
-Marker { lat: 43.4285013, lng: -79.763019 },
-Marker { lat: 43.9680766, lng: -79.276052 },
-Marker { lat: 43.3428054, lng: -79.7697828 },
-Marker { lat: 43.874954, lng: -79.720096 },
-Marker { lat: 43.8964655, lng: -79.2468856 },
-Marker { lat: 43.5547239, lng: -79.5804579 },
-Marker { lat: 43.7621072, lng: -79.4493755 },
-Marker { lat: 43.884976, lng: -78.926292 },
-Marker { lat: 43.940552, lng: -79.646676 },
-Marker { lat: 43.8602791, lng: -79.4001188 },
-Marker { lat: 43.6476241, lng: -79.3991681 },
-Marker { lat: 43.7639534, lng: -79.4069857 },
-Marker { lat: 43.58504, lng: -79.646032 },
-Marker { lat: 43.7210657, lng: -79.4764953 },
-Marker { lat: 43.760315, lng: -79.411703 },
-Marker { lat: 43.6455963, lng: -79.5807862 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.641087, lng: -79.381405 },
-Marker { lat: 43.682132, lng: -79.809297 },
-Marker { lat: 43.7604118, lng: -79.422942 },
-Marker { lat: 43.6721786, lng: -79.404449 },
-Marker { lat: 43.760304, lng: -79.41162 },
-Marker { lat: 43.798839, lng: -79.919445 },
-Marker { lat: 43.7745195, lng: -79.3297599 },
-Marker { lat: 43.965954, lng: -79.377099 },
-Marker { lat: 43.687539, lng: -79.3978735 },
-Marker { lat: 43.707669, lng: -79.392307 },
-Marker { lat: 43.7717364, lng: -79.2613815 },
-Marker { lat: 43.641087, lng: -79.381405 },
-Marker { lat: 43.7482103, lng: -79.3616976 },
-Marker { lat: 43.7577213, lng: -79.3975128 },
-Marker { lat: 43.8311, lng: -79.114 },
-Marker { lat: 43.722521, lng: -79.2331879 },
-Marker { lat: 43.791233, lng: -79.4542851 },
-Marker { lat: 43.807566, lng: -79.227389 },
-Marker { lat: 43.8683287, lng: -79.2221955 },
-Marker { lat: 43.447443, lng: -79.686948 },
-Marker { lat: 43.9369963, lng: -79.5194783 },
-Marker { lat: 43.7537115, lng: -79.7475134 },
-Marker { lat: 43.7396, lng: -79.7378 },
-Marker { lat: 43.7177475, lng: -79.4494829 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.8691868, lng: -79.3157664 },
-Marker { lat: 43.6361775, lng: -79.8689457 },
-Marker { lat: 43.6717527, lng: -79.7967302 },
-Marker { lat: 43.7669919, lng: -79.5302489 },
-Marker { lat: 43.6211459, lng: -79.5215694 },
-Marker { lat: 43.447443, lng: -79.686948 },
-Marker { lat: 43.692768, lng: -79.508482 },
-Marker { lat: 43.7514475, lng: -79.7563639 },
-Marker { lat: 43.642168, lng: -79.528334 },
-Marker { lat: 43.8416724, lng: -79.4772333 },
-Marker { lat: 43.7538111, lng: -79.7366086 },
-Marker { lat: 43.5187, lng: -79.8761 },
-Marker { lat: 43.5972379, lng: -79.561405 },
-Marker { lat: 43.6978509, lng: -79.5057089 },
-Marker { lat: 43.6302017, lng: -79.7788949 },
-Marker { lat: 43.862011, lng: -79.481891 },
-Marker { lat: 43.982591, lng: -79.720816 },
-Marker { lat: 43.6702996, lng: -79.4001501 },
-Marker { lat: 43.7220867, lng: -79.4553008 },
-Marker { lat: 43.555075, lng: -79.583315 },
-Marker { lat: 43.6676702, lng: -79.3864205 },
-Marker { lat: 43.7532386, lng: -79.4909284 },
-Marker { lat: 43.7225962, lng: -79.7671899 },
-Marker { lat: 43.651055, lng: -79.359061 },
-Marker { lat: 43.6490534, lng: -79.3861546 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.7703, lng: -79.413645 },
-Marker { lat: 43.6753313, lng: -79.3971281 },
-Marker { lat: 43.673983, lng: -79.390142 },
-Marker { lat: 43.8529766, lng: -79.3368466 },
-Marker { lat: 43.8012143, lng: -79.424509 },
-Marker { lat: 43.689404, lng: -79.755104 },
-Marker { lat: 43.4956025, lng: -79.6999487 },
-Marker { lat: 43.6412251, lng: -79.3809582 },
-Marker { lat: 43.7764694, lng: -79.6974564 },
-Marker { lat: 43.853123, lng: -79.548564 },
-Marker { lat: 43.791887, lng: -79.3535054 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.402978, lng: -79.7818075 },
-Marker { lat: 43.5718999, lng: -79.7063225 },
-Marker { lat: 43.8677106, lng: -79.0424533 },
-Marker { lat: 43.969586, lng: -79.140692 },
-Marker { lat: 43.6426375, lng: -79.5588279 },
-Marker { lat: 43.8607997, lng: -79.3166672 },
-Marker { lat: 43.7169457, lng: -79.8310233 },
-Marker { lat: 43.9175279, lng: -79.4490679 },
-Marker { lat: 43.6399564, lng: -79.4259833 },
-Marker { lat: 43.887529, lng: -79.3130024 },
-Marker { lat: 43.7030548, lng: -79.8354249 },
-Marker { lat: 43.4777677, lng: -79.7156292 },
-Marker { lat: 43.5351838, lng: -79.6628669 },
-Marker { lat: 43.896526, lng: -79.2350117 },
-Marker { lat: 43.4149754, lng: -79.6999309 },
-Marker { lat: 43.6977523, lng: -79.277443 },
-Marker { lat: 43.6131283, lng: -79.4981267 },
-Marker { lat: 43.6475103, lng: -79.3927254 },
-Marker { lat: 43.4966454, lng: -79.8850013 },
-Marker { lat: 43.669593, lng: -79.38058 },
-Marker { lat: 43.640277, lng: -79.424126 },
-Marker { lat: 43.7255991, lng: -79.5102828 },
-Marker { lat: 43.879086, lng: -78.904358 },
-Marker { lat: 43.6600823, lng: -79.3659556 },
-Marker { lat: 43.5012703, lng: -79.8826343 },
-Marker { lat: 43.7957221, lng: -79.1363168 },
-Marker { lat: 43.413464, lng: -79.800437 },
-Marker { lat: 43.644254, lng: -79.766995 },
-Marker { lat: 43.8909803, lng: -79.4375734 },
-Marker { lat: 43.811983, lng: -79.456717 },
-Marker { lat: 43.4392429, lng: -79.737204 },
-Marker { lat: 43.7086904, lng: -79.7621837 },
-Marker { lat: 43.7756168, lng: -79.3764783 },
-Marker { lat: 43.63641, lng: -79.402003 },
-Marker { lat: 43.56487, lng: -79.701083 },
-Marker { lat: 43.683327, lng: -79.8187498 },
-Marker { lat: 43.6361261, lng: -79.5507148 },
-Marker { lat: 43.4463693, lng: -79.7240175 },
-Marker { lat: 43.760673, lng: -79.691768 },
-Marker { lat: 43.7810628, lng: -79.2485568 },
-Marker { lat: 43.7299064, lng: -79.5227726 },
-Marker { lat: 43.7270881, lng: -79.5046644 },
-Marker { lat: 43.7105797, lng: -79.6458085 },
-Marker { lat: 43.7590053, lng: -79.5885886 },
-Marker { lat: 43.6700845, lng: -79.3864005 },
-Marker { lat: 43.623426, lng: -79.4789788 },
-Marker { lat: 43.8276215, lng: -79.0267335 },
-Marker { lat: 43.7066454, lng: -79.4592963 },
-Marker { lat: 43.8866767, lng: -79.0060984 },
-Marker { lat: 43.9727011, lng: -79.4731266 },
-Marker { lat: 43.6991745, lng: -79.5321337 },
-Marker { lat: 43.4089326, lng: -79.7155054 },
-Marker { lat: 43.3819062, lng: -79.7358635 },
-Marker { lat: 43.59731, lng: -79.63104 },
-Marker { lat: 43.5423618, lng: -79.6781112 },
-Marker { lat: 43.6883007, lng: -79.3970953 },
-Marker { lat: 43.7324535, lng: -79.7197848 },
-Marker { lat: 43.7293994, lng: -79.8238981 },
-Marker { lat: 43.5733665, lng: -79.749233 },
-Marker { lat: 43.6460836, lng: -79.3650117 },
-Marker { lat: 43.5863525, lng: -79.636715 },
-Marker { lat: 43.644672, lng: -79.5294079 },
-Marker { lat: 43.7098418, lng: -79.396432 },
-Marker { lat: 43.7070855, lng: -79.3977452 },
-Marker { lat: 43.7681219, lng: -79.4111307 },
-Marker { lat: 43.8233165, lng: -79.3887041 },
-Marker { lat: 43.6323725, lng: -79.5206641 },
-Marker { lat: 43.462492, lng: -79.647492 },
-Marker { lat: 43.7752161, lng: -79.4133461 },
-Marker { lat: 43.7403056, lng: -79.3387775 },
-Marker { lat: 43.621616, lng: -79.482921 },
-Marker { lat: 43.5811552, lng: -79.6456268 },
-Marker { lat: 43.81238, lng: -79.424169 },
-Marker { lat: 43.6746538, lng: -79.3890069 },
-Marker { lat: 43.8654856, lng: -79.2198167 },
-Marker { lat: 43.7330798, lng: -79.7904981 },
-Marker { lat: 43.885644, lng: -79.025241 },
-Marker { lat: 43.6491412, lng: -79.5713724 },
-Marker { lat: 43.5883573, lng: -79.6395201 },
-Marker { lat: 43.7900246, lng: -79.25466 },
-Marker { lat: 43.89096, lng: -78.915065 },
-Marker { lat: 43.7045616, lng: -79.4256344 },
-Marker { lat: 43.668718, lng: -79.383996 },
-Marker { lat: 43.6890135, lng: -79.8192548 },
-Marker { lat: 43.7158103, lng: -79.2817834 },
-Marker { lat: 43.849828, lng: -79.490764 },
-Marker { lat: 43.9020421, lng: -79.2498065 },
-Marker { lat: 43.647627, lng: -79.390408 },
-Marker { lat: 43.6006042, lng: -79.6388674 },
-Marker { lat: 43.4916591, lng: -79.7191213 },
-Marker { lat: 43.6953616, lng: -79.3447377 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.7434519, lng: -79.4268995 },
-Marker { lat: 43.6808516, lng: -79.7618409 },
-Marker { lat: 43.7256544, lng: -79.8062037 },
-Marker { lat: 43.539538, lng: -79.674852 },
-Marker { lat: 43.8200414, lng: -79.3777265 },
-Marker { lat: 43.5450931, lng: -79.719666 },
-Marker { lat: 43.8941692, lng: -79.0330026 },
-Marker { lat: 43.7587861, lng: -79.7531244 },
-Marker { lat: 43.3938431, lng: -79.7117345 },
-Marker { lat: 43.7382972, lng: -79.4057368 },
-Marker { lat: 43.4426282, lng: -79.6723596 },
-Marker { lat: 43.640529, lng: -79.424411 },
-Marker { lat: 43.8518853, lng: -79.336294 },
-Marker { lat: 43.58813, lng: -79.640126 },
-Marker { lat: 43.763879, lng: -79.284027 },
-Marker { lat: 43.707083, lng: -79.397771 },
-Marker { lat: 43.773748, lng: -79.415509 },
-Marker { lat: 43.7817207, lng: -79.2473686 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.7734697, lng: -79.2536323 },
-Marker { lat: 43.6745155, lng: -79.7255361 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.7002792, lng: -79.4278103 },
-Marker { lat: 43.6594036, lng: -79.3611785 },
-Marker { lat: 43.8208482, lng: -79.4244323 },
-Marker { lat: 43.773688, lng: -79.255422 },
-Marker { lat: 43.8529766, lng: -79.3368466 },
-Marker { lat: 43.6515293, lng: -79.375757 },
-Marker { lat: 43.7627512, lng: -79.5199694 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.7118809, lng: -79.3059059 },
-Marker { lat: 43.668718, lng: -79.383996 },
-Marker { lat: 43.7681903, lng: -79.1830824 },
-Marker { lat: 43.8076806, lng: -79.2306884 },
-Marker { lat: 43.7267484, lng: -79.7619281 },
-Marker { lat: 43.809341, lng: -79.3607118 },
-Marker { lat: 43.6921289, lng: -79.5542629 },
-Marker { lat: 43.650554, lng: -79.357382 },
-Marker { lat: 43.5026866, lng: -79.8909051 },
-Marker { lat: 43.8621607, lng: -79.4113994 },
-Marker { lat: 43.7550216, lng: -79.3982838 },
-Marker { lat: 43.9073317, lng: -79.4386805 },
-Marker { lat: 43.632191, lng: -79.88495 },
-Marker { lat: 43.73867, lng: -79.441043 },
-Marker { lat: 43.7719571, lng: -79.3191226 },
-Marker { lat: 43.4435583, lng: -79.7481121 },
-Marker { lat: 43.3959385, lng: -79.7210313 },
-Marker { lat: 43.4030419, lng: -79.8161062 },
-Marker { lat: 43.8944222, lng: -79.0270803 },
-Marker { lat: 43.5810458, lng: -79.7656913 },
-Marker { lat: 43.5910499, lng: -79.7614546 },
-Marker { lat: 43.6163828, lng: -79.6505139 },
-Marker { lat: 43.6035302, lng: -79.5471206 },
-Marker { lat: 43.9215141, lng: -78.9208517 },
-Marker { lat: 43.721114, lng: -79.43085 },
-Marker { lat: 43.786063, lng: -79.367994 },
-Marker { lat: 43.5768308, lng: -79.7530217 },
-Marker { lat: 43.7107764, lng: -79.8114772 },
-Marker { lat: 43.7755949, lng: -79.7270111 },
-Marker { lat: 43.7470465, lng: -79.765495 },
-Marker { lat: 43.8818962, lng: -79.2599329 },
-Marker { lat: 43.6849765, lng: -79.8463223 },
-Marker { lat: 43.7946319, lng: -79.4806296 },
-Marker { lat: 43.7776579, lng: -79.135522 },
-Marker { lat: 43.9593664, lng: -78.946792 },
-Marker { lat: 43.8167807, lng: -79.3313569 },
-Marker { lat: 43.9561751, lng: -79.4839297 },
-Marker { lat: 43.5100528, lng: -79.857073 },
-Marker { lat: 43.8931802, lng: -79.2692925 },
-Marker { lat: 43.6418093, lng: -79.5585051 },
-Marker { lat: 43.6352534, lng: -79.8696693 },
-Marker { lat: 43.9387422, lng: -79.4494151 },
-Marker { lat: 43.517069, lng: -79.837974 },
-Marker { lat: 43.4589758, lng: -79.7280933 },
-Marker { lat: 43.7442819, lng: -79.3904292 },
-Marker { lat: 43.8875493, lng: -79.2681744 },
-Marker { lat: 43.8660321, lng: -79.6184309 },
-Marker { lat: 43.8948622, lng: -79.2144331 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.545551, lng: -79.721956 },
-Marker { lat: 43.9857563, lng: -79.4398224 },
-Marker { lat: 43.646944, lng: -79.392397 },
-Marker { lat: 43.648317, lng: -79.393065 },
-Marker { lat: 43.784886, lng: -79.184751 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.413464, lng: -79.800437 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.6493345, lng: -79.3747254 },
-Marker { lat: 43.6501593, lng: -79.3926005 },
-Marker { lat: 43.698775, lng: -79.384968 },
-Marker { lat: 43.8532315, lng: -79.1810708 },
-Marker { lat: 43.7456995, lng: -79.3100961 },
-Marker { lat: 43.6575992, lng: -79.3498961 },
-Marker { lat: 43.478579, lng: -79.70921 },
-Marker { lat: 43.832864, lng: -79.648597 },
-Marker { lat: 43.9663069, lng: -79.8380723 },
-Marker { lat: 43.8632208, lng: -79.4417948 },
-Marker { lat: 43.9158239, lng: -78.948886 },
-Marker { lat: 43.926094, lng: -79.510813 },
-Marker { lat: 43.647445, lng: -79.507983 },
-Marker { lat: 43.874635, lng: -79.757938 },
-Marker { lat: 43.8713224, lng: -79.0706868 },
-Marker { lat: 43.431721, lng: -79.730107 },
-Marker { lat: 43.910163, lng: -79.445996 },
-Marker { lat: 43.939725, lng: -79.5381221 },
-Marker { lat: 43.8893014, lng: -79.4472589 },
-Marker { lat: 43.674264, lng: -79.367471 },
-Marker { lat: 43.6729857, lng: -79.3870289 },
-Marker { lat: 43.7625969, lng: -79.4025561 },
-Marker { lat: 43.7329605, lng: -79.8087274 },
-Marker { lat: 43.9150364, lng: -79.4406501 },
-Marker { lat: 43.7744208, lng: -79.3863756 },
-Marker { lat: 43.7738844, lng: -79.7350078 },
-Marker { lat: 43.3530454, lng: -79.8170842 },
-Marker { lat: 43.8453919, lng: -79.4474294 },
-Marker { lat: 43.645447, lng: -79.394021 },
-Marker { lat: 43.5493205, lng: -79.5958932 },
-Marker { lat: 43.508964, lng: -79.86661 },
-Marker { lat: 43.6038153, lng: -79.6354148 },
-Marker { lat: 43.686815, lng: -79.464381 },
-Marker { lat: 43.6933573, lng: -79.440817 },
-Marker { lat: 43.6821116, lng: -79.3233927 },
-Marker { lat: 43.9093757, lng: -79.8221402 },
-Marker { lat: 43.6895145, lng: -79.3356072 },
-Marker { lat: 43.9546123, lng: -78.8793426 },
-Marker { lat: 43.6568586, lng: -79.3836642 },
-Marker { lat: 43.69024, lng: -79.268418 },
-Marker { lat: 43.8995528, lng: -79.3055226 },
-Marker { lat: 43.6534041, lng: -79.3680104 },
-Marker { lat: 43.578848, lng: -79.6433585 },
-Marker { lat: 43.8635074, lng: -79.4300156 },
-Marker { lat: 43.964411, lng: -79.451684 },
-Marker { lat: 43.65438, lng: -79.364389 },
-Marker { lat: 43.86819, lng: -79.430454 },
-Marker { lat: 43.8514464, lng: -79.6345138 },
-Marker { lat: 43.9119536, lng: -78.9162303 },
-Marker { lat: 43.8779252, lng: -79.2859268 },
-Marker { lat: 43.6404251, lng: -79.4246524 },
-Marker { lat: 43.6789769, lng: -79.5695503 },
-Marker { lat: 43.411804, lng: -79.802099 },
-Marker { lat: 43.7752161, lng: -79.4133461 },
-Marker { lat: 43.715027, lng: -79.358347 },
-Marker { lat: 43.793654, lng: -79.333327 },
-Marker { lat: 43.8798975, lng: -79.3441663 },
-Marker { lat: 43.646559, lng: -79.409978 },
-Marker { lat: 43.6489, lng: -79.392205 },
-Marker { lat: 43.8787023, lng: -79.3941674 },
-Marker { lat: 43.459797, lng: -79.7303324 },
-Marker { lat: 43.78884, lng: -79.3621103 },
-Marker { lat: 43.7165398, lng: -79.2845142 },
-Marker { lat: 43.851419, lng: -79.5566414 },
-Marker { lat: 43.7834476, lng: -79.283569 },
-Marker { lat: 43.7334172, lng: -79.4281016 },
-Marker { lat: 43.6539796, lng: -79.3885197 },
-Marker { lat: 43.864574, lng: -79.536831 },
-Marker { lat: 43.8123062, lng: -79.4558639 },
-Marker { lat: 43.6774063, lng: -79.4826329 },
-Marker { lat: 43.725775, lng: -79.6980808 },
-Marker { lat: 43.856463, lng: -79.125646 },
-Marker { lat: 43.854082, lng: -79.1217197 },
-Marker { lat: 43.8355585, lng: -79.461991 },
-Marker { lat: 43.4764081, lng: -79.6524421 },
-Marker { lat: 43.834896, lng: -79.280812 },
-Marker { lat: 43.876717, lng: -79.260768 },
-Marker { lat: 43.7788556, lng: -79.4437033 },
-Marker { lat: 43.4116993, lng: -79.7181466 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.669931, lng: -79.375463 },
-Marker { lat: 43.6839, lng: -79.4824 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.8100647, lng: -79.4487117 },
-Marker { lat: 43.9609, lng: -78.9009 },
-Marker { lat: 43.976236, lng: -79.739595 },
-Marker { lat: 43.646243, lng: -79.881366 },
-Marker { lat: 43.8391557, lng: -79.2876227 },
-Marker { lat: 43.9230065, lng: -79.4693751 },
-Marker { lat: 43.7181, lng: -79.702 },
-Marker { lat: 43.6778865, lng: -79.8016995 },
-Marker { lat: 43.679743, lng: -79.4891849 },
-Marker { lat: 43.6592572, lng: -79.412457 },
-Marker { lat: 43.7699696, lng: -79.3925376 },
-Marker { lat: 43.3947249, lng: -79.8288042 },
-Marker { lat: 43.4752188, lng: -79.7360742 },
-Marker { lat: 43.910163, lng: -79.445996 },
-Marker { lat: 43.668099, lng: -79.7748386 },
-Marker { lat: 43.9659793, lng: -79.4377373 },
-Marker { lat: 43.7846186, lng: -79.2853195 },
-Marker { lat: 43.8922746, lng: -79.435316 },
-Marker { lat: 43.5334113, lng: -79.6682894 },
-Marker { lat: 43.9006451, lng: -78.9447157 },
-Marker { lat: 43.6343426, lng: -79.4357252 },
-Marker { lat: 43.722366, lng: -79.711322 },
-Marker { lat: 43.776109, lng: -79.414288 },
-Marker { lat: 43.787299, lng: -79.670924 },
-Marker { lat: 43.6912297, lng: -79.3662334 },
-Marker { lat: 43.8508897, lng: -79.4637282 },
-Marker { lat: 43.727693, lng: -79.3077669 },
-Marker { lat: 43.433521, lng: -79.770299 },
-Marker { lat: 43.374964, lng: -79.8034109 },
-Marker { lat: 43.6721842, lng: -79.5176849 },
-Marker { lat: 43.8392922, lng: -79.3945697 },
-Marker { lat: 43.4285, lng: -79.7445 },
-Marker { lat: 43.735224, lng: -79.302882 },
-Marker { lat: 43.888688, lng: -79.073078 },
-Marker { lat: 43.852333, lng: -79.3391785 },
-Marker { lat: 43.9455533, lng: -79.2739547 },
-Marker { lat: 43.9569092, lng: -79.4444367 },
-Marker { lat: 43.4351591, lng: -79.6881601 },
-Marker { lat: 43.660605, lng: -79.378635 },
-Marker { lat: 43.802299, lng: -79.936187 },
-Marker { lat: 43.8501071, lng: -79.4185334 },
-Marker { lat: 43.7343472, lng: -79.7361069 },
-Marker { lat: 43.4187116, lng: -79.6968239 },
-Marker { lat: 43.7720572, lng: -79.6751779 },
-Marker { lat: 43.8648494, lng: -79.3894491 },
-Marker { lat: 43.7684181, lng: -79.7617117 },
-Marker { lat: 43.4285, lng: -79.7445 },
-Marker { lat: 43.6113025, lng: -79.6540743 },
-Marker { lat: 43.9060892, lng: -79.4471032 },
-Marker { lat: 43.5590498, lng: -79.7369812 },
-Marker { lat: 43.744077, lng: -79.581703 },
-Marker { lat: 43.805951, lng: -79.5971 },
-Marker { lat: 43.6699326, lng: -79.4591642 },
-Marker { lat: 43.727332, lng: -79.4890159 },
-Marker { lat: 43.8461798, lng: -79.5658687 },
-Marker { lat: 43.606272, lng: -79.655872 },
-Marker { lat: 43.8543178, lng: -79.7003624 },
-Marker { lat: 43.4910398, lng: -79.8637404 },
-Marker { lat: 43.4190633, lng: -79.7072915 },
-Marker { lat: 43.3968783, lng: -79.8171758 },
-Marker { lat: 43.686985, lng: -79.4491122 },
-Marker { lat: 43.7106435, lng: -79.5166525 },
-Marker { lat: 43.8225164, lng: -79.4466201 },
-Marker { lat: 43.6561334, lng: -79.5712675 },
-Marker { lat: 43.773748, lng: -79.415509 },
-Marker { lat: 43.8886088, lng: -79.2706612 },
-Marker { lat: 43.6949193, lng: -79.306904 },
-Marker { lat: 43.6491763, lng: -79.3770958 },
-Marker { lat: 43.6446596, lng: -79.420572 },
-Marker { lat: 43.8823726, lng: -79.2447689 },
-Marker { lat: 43.7221376, lng: -79.5825004 },
-Marker { lat: 43.5844612, lng: -79.7236909 },
-Marker { lat: 43.48417, lng: -79.915863 },
-Marker { lat: 43.6880417, lng: -79.4046306 },
-Marker { lat: 43.7927318, lng: -79.365604 },
-Marker { lat: 43.5898312, lng: -79.7809621 },
-Marker { lat: 43.711637, lng: -79.6447227 },
-Marker { lat: 43.8821986, lng: -78.9253949 },
-Marker { lat: 43.5541899, lng: -79.6132097 },
-Marker { lat: 43.9173276, lng: -79.5154805 },
-Marker { lat: 43.8834014, lng: -79.2444644 },
-Marker { lat: 43.6263857, lng: -79.5103212 },
-Marker { lat: 43.724592, lng: -79.81139 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.8014552, lng: -79.3973984 },
-Marker { lat: 43.689093, lng: -79.392561 },
-Marker { lat: 43.7354946, lng: -79.4251649 },
-Marker { lat: 43.646944, lng: -79.392397 },
-Marker { lat: 43.6796764, lng: -79.4144085 },
-Marker { lat: 43.7731083, lng: -79.4578215 },
-Marker { lat: 43.810167, lng: -79.221126 },
-Marker { lat: 43.7466592, lng: -79.5061406 },
-Marker { lat: 43.663587, lng: -79.45906 },
-Marker { lat: 43.7512065, lng: -79.5828607 },
-Marker { lat: 43.643269, lng: -79.427537 },
-Marker { lat: 43.6951203, lng: -79.3051588 },
-Marker { lat: 43.8948466, lng: -79.2145432 },
-Marker { lat: 43.8121289, lng: -79.4550839 },
-Marker { lat: 43.7677262, lng: -79.3706232 },
-Marker { lat: 43.8225179, lng: -79.3985646 },
-Marker { lat: 43.8947369, lng: -79.3642738 },
-Marker { lat: 43.942284, lng: -78.888547 },
-Marker { lat: 43.7485222, lng: -79.2472764 },
-Marker { lat: 43.9723265, lng: -79.2516677 },
-Marker { lat: 43.8716982, lng: -79.302801 },
-Marker { lat: 43.7922359, lng: -79.4165081 },
-Marker { lat: 43.9074972, lng: -78.8860251 },
-Marker { lat: 43.8615096, lng: -79.0164978 },
-Marker { lat: 43.90698, lng: -78.970693 },
-Marker { lat: 43.832056, lng: -79.098329 },
-Marker { lat: 43.6695417, lng: -79.3837883 },
-Marker { lat: 43.8719335, lng: -79.4528499 },
-Marker { lat: 43.9062993, lng: -79.4365912 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.760379, lng: -79.4113683 },
-Marker { lat: 43.6729857, lng: -79.3870289 },
-Marker { lat: 43.665753, lng: -79.385579 },
-Marker { lat: 43.802145, lng: -79.4206681 },
-Marker { lat: 43.6398049, lng: -79.3979687 },
-Marker { lat: 43.7682314, lng: -79.3513966 },
-Marker { lat: 43.7143591, lng: -79.3531865 },
-Marker { lat: 43.7808226, lng: -79.2476023 },
-Marker { lat: 43.7498888, lng: -79.3798738 },
-Marker { lat: 43.9587602, lng: -79.4400372 },
-Marker { lat: 43.873852, lng: -79.062286 },
-Marker { lat: 43.770655, lng: -79.413522 },
-Marker { lat: 43.7202729, lng: -79.7142161 },
-Marker { lat: 43.8719429, lng: -79.4660754 },
-Marker { lat: 43.756968, lng: -79.4107063 },
-Marker { lat: 43.773748, lng: -79.415509 },
-Marker { lat: 43.766764, lng: -79.385993 },
-Marker { lat: 43.7954673, lng: -79.3526778 },
-Marker { lat: 43.6848481, lng: -79.368773 },
-Marker { lat: 43.7298311, lng: -79.3335874 },
-Marker { lat: 43.66955, lng: -79.383776 },
-Marker { lat: 43.6622639, lng: -79.7968222 },
-Marker { lat: 43.4958971, lng: -79.7021706 },
-Marker { lat: 43.6784711, lng: -79.7571647 },
-Marker { lat: 43.760532, lng: -79.83237 },
-Marker { lat: 43.971078, lng: -79.793077 },
-Marker { lat: 43.671731, lng: -79.389691 },
-Marker { lat: 43.6400003, lng: -79.3788904 },
-Marker { lat: 43.89354, lng: -78.952122 },
-Marker { lat: 43.923808, lng: -78.93247 },
-Marker { lat: 43.6839995, lng: -79.4119636 },
-Marker { lat: 43.622888, lng: -79.727524 },
-Marker { lat: 43.372258, lng: -79.7262846 },
-Marker { lat: 43.6796667, lng: -79.5680513 },
-Marker { lat: 43.8768357, lng: -79.4458695 },
-Marker { lat: 43.7951402, lng: -79.5841771 },
-Marker { lat: 43.638378, lng: -79.584038 },
-Marker { lat: 43.8987311, lng: -79.25 },
-Marker { lat: 43.7563401, lng: -79.4056932 },
-Marker { lat: 43.8763015, lng: -79.0964844 },
-Marker { lat: 43.821014, lng: -79.1049082 },
-Marker { lat: 43.5930235, lng: -79.6593313 },
-Marker { lat: 43.8804216, lng: -79.4648648 },
-Marker { lat: 43.894524, lng: -79.032767 },
-Marker { lat: 43.543577, lng: -79.723239 },
-Marker { lat: 43.8086576, lng: -79.1718837 },
-Marker { lat: 43.4953392, lng: -79.8920864 },
-Marker { lat: 43.8824296, lng: -79.4058811 },
-Marker { lat: 43.606959, lng: -79.6614229 },
-Marker { lat: 43.4279285, lng: -79.6913796 },
-Marker { lat: 43.734452, lng: -79.775404 },
-Marker { lat: 43.702744, lng: -79.451167 },
-Marker { lat: 43.5728891, lng: -79.5870575 },
-Marker { lat: 43.963821, lng: -79.4485963 },
-Marker { lat: 43.9340291, lng: -79.4770094 },
-Marker { lat: 43.9046887, lng: -79.4469046 },
-Marker { lat: 43.9391717, lng: -79.4556725 },
-Marker { lat: 43.7900246, lng: -79.25466 },
-Marker { lat: 43.670941, lng: -79.7631509 },
-Marker { lat: 43.6871794, lng: -79.5048436 },
-Marker { lat: 43.858136, lng: -79.324393 },
-Marker { lat: 43.9840081, lng: -79.4887679 },
-Marker { lat: 43.7982782, lng: -79.2353209 },
-Marker { lat: 43.7946882, lng: -79.2374282 },
-Marker { lat: 43.7629653, lng: -79.3395363 },
-Marker { lat: 43.6805915, lng: -79.7922654 },
-Marker { lat: 43.7229446, lng: -79.3865745 },
-Marker { lat: 43.7569815, lng: -79.4044001 },
-Marker { lat: 43.713572, lng: -79.394524 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.71358, lng: -79.364763 },
-Marker { lat: 43.877556, lng: -79.041583 },
-Marker { lat: 43.6512436, lng: -79.7310134 },
-Marker { lat: 43.5137475, lng: -79.8481218 },
-Marker { lat: 43.6806159, lng: -79.8138286 },
-Marker { lat: 43.8259, lng: -79.094906 },
-Marker { lat: 43.7732071, lng: -79.3457949 },
-Marker { lat: 43.7581731, lng: -79.399496 },
-Marker { lat: 43.782123, lng: -79.361626 },
-Marker { lat: 43.659783, lng: -79.362653 },
-Marker { lat: 43.669593, lng: -79.38058 },
-Marker { lat: 43.7839146, lng: -79.593465 },
-Marker { lat: 43.8916003, lng: -79.258958 },
-Marker { lat: 43.6987908, lng: -79.2766007 },
-Marker { lat: 43.778017, lng: -79.416628 },
-Marker { lat: 43.832805, lng: -78.992519 },
-Marker { lat: 43.6962745, lng: -79.2927586 },
-Marker { lat: 43.7538587, lng: -79.3092364 },
-Marker { lat: 43.8088032, lng: -79.2091705 },
-Marker { lat: 43.8174378, lng: -79.463263 },
-Marker { lat: 43.6006654, lng: -79.5080873 },
-Marker { lat: 43.5602704, lng: -79.735462 },
-Marker { lat: 43.6655294, lng: -79.5297813 },
-Marker { lat: 43.7139423, lng: -79.4514055 },
-Marker { lat: 43.6094162, lng: -79.55872 },
-Marker { lat: 43.6698346, lng: -79.4225386 },
-Marker { lat: 43.6628196, lng: -79.4705273 },
-Marker { lat: 43.7344213, lng: -79.7719841 },
-Marker { lat: 43.6889824, lng: -79.8140733 },
-Marker { lat: 43.6360393, lng: -79.430819 },
-Marker { lat: 43.6740958, lng: -79.7395291 },
-Marker { lat: 43.6399393, lng: -79.5106166 },
-Marker { lat: 43.561803, lng: -79.7126716 },
-Marker { lat: 43.4723946, lng: -79.6424145 },
-Marker { lat: 43.7946305, lng: -79.5621036 },
-Marker { lat: 43.7349334, lng: -79.8114351 },
-Marker { lat: 43.7128657, lng: -79.3978466 },
-Marker { lat: 43.7677262, lng: -79.3706232 },
-Marker { lat: 43.9073512, lng: -78.9791956 },
-Marker { lat: 43.7656686, lng: -79.4541121 },
-Marker { lat: 43.7578781, lng: -79.3919283 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.6416369, lng: -79.4218439 },
-Marker { lat: 43.6791457, lng: -79.4330807 },
-Marker { lat: 43.719556, lng: -79.325035 },
-Marker { lat: 43.492368, lng: -79.720492 },
-Marker { lat: 43.81302, lng: -79.449356 },
-Marker { lat: 43.6908663, lng: -79.313226 },
-Marker { lat: 43.8237802, lng: -79.2739795 },
-Marker { lat: 43.765836, lng: -79.4528339 },
-Marker { lat: 43.47314, lng: -79.744806 },
-Marker { lat: 43.6955773, lng: -79.5077266 },
-Marker { lat: 43.9544625, lng: -78.8916365 },
-Marker { lat: 43.6396649, lng: -79.4146468 },
-Marker { lat: 43.4303452, lng: -79.7468787 },
-Marker { lat: 43.938239, lng: -78.902324 },
-Marker { lat: 43.7031511, lng: -79.3613153 },
-Marker { lat: 43.8870865, lng: -79.2812014 },
-Marker { lat: 43.843516, lng: -79.426394 },
-Marker { lat: 43.7221435, lng: -79.7118946 },
-Marker { lat: 43.7234845, lng: -79.3272775 },
-Marker { lat: 43.6616166, lng: -79.3846797 },
-Marker { lat: 43.710933, lng: -79.724816 },
-Marker { lat: 43.3586187, lng: -79.814426 },
-Marker { lat: 43.6783178, lng: -79.2838177 },
-Marker { lat: 43.708496, lng: -79.397652 },
-Marker { lat: 43.9391477, lng: -79.4337393 },
-Marker { lat: 43.6454752, lng: -79.3923183 },
-Marker { lat: 43.7338129, lng: -79.4894302 },
-Marker { lat: 43.7588298, lng: -79.1866921 },
-Marker { lat: 43.68795, lng: -79.522015 },
-Marker { lat: 43.5399296, lng: -79.6014262 },
-Marker { lat: 43.7179609, lng: -79.492099 },
-Marker { lat: 43.4871421, lng: -79.8419504 },
-Marker { lat: 43.89835, lng: -79.3088101 },
-Marker { lat: 43.851839, lng: -79.238309 },
-Marker { lat: 43.446237, lng: -79.707062 },
-Marker { lat: 43.9441043, lng: -79.4631476 },
-Marker { lat: 43.760906, lng: -79.4090028 },
-Marker { lat: 43.3696606, lng: -79.8396965 },
-Marker { lat: 43.779869, lng: -79.417504 },
-Marker { lat: 43.7659552, lng: -79.4520233 },
-Marker { lat: 43.8617045, lng: -79.2850377 },
-Marker { lat: 43.6740324, lng: -79.3904727 },
-Marker { lat: 43.7797628, lng: -79.3785799 },
-Marker { lat: 43.9458672, lng: -79.8429777 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.7642862, lng: -79.3441063 },
-Marker { lat: 43.9210718, lng: -78.8822166 },
-Marker { lat: 43.8560558, lng: -79.4825661 },
-Marker { lat: 43.4754784, lng: -79.7363569 },
-Marker { lat: 43.706209, lng: -79.5184792 },
-Marker { lat: 43.647233, lng: -79.4259973 },
-Marker { lat: 43.785895, lng: -79.29186 },
-Marker { lat: 43.840865, lng: -79.00503 },
-Marker { lat: 43.7904691, lng: -79.4520964 },
-Marker { lat: 43.8570604, lng: -79.4381827 },
-Marker { lat: 43.6488273, lng: -79.3780964 },
-Marker { lat: 43.9115002, lng: -79.547262 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.6114984, lng: -79.543595 },
-Marker { lat: 43.6694723, lng: -79.3874877 },
-Marker { lat: 43.7297244, lng: -79.4419558 },
-Marker { lat: 43.6422746, lng: -79.3727039 },
-Marker { lat: 43.661781, lng: -79.384218 },
-Marker { lat: 43.7094955, lng: -79.4313478 },
-Marker { lat: 43.8426726, lng: -79.564084 },
-Marker { lat: 43.8168498, lng: -79.4667481 },
-Marker { lat: 43.6781049, lng: -79.7387521 },
-Marker { lat: 43.6600002, lng: -79.4517988 },
-Marker { lat: 43.9629448, lng: -79.4516889 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.8523481, lng: -79.4716549 },
-Marker { lat: 43.7095895, lng: -79.4157983 },
-Marker { lat: 43.680106, lng: -79.842009 },
-Marker { lat: 43.6838126, lng: -79.3563764 },
-Marker { lat: 43.5945878, lng: -79.6347113 },
-Marker { lat: 43.7301714, lng: -79.6346387 },
-Marker { lat: 43.5512031, lng: -79.6315185 },
-Marker { lat: 43.808635, lng: -79.224419 },
-Marker { lat: 43.5201515, lng: -79.8440783 },
-Marker { lat: 43.705085, lng: -79.521596 },
-Marker { lat: 43.485236, lng: -79.854 },
-Marker { lat: 43.8989098, lng: -79.2525422 },
-Marker { lat: 43.6389416, lng: -79.5369791 },
-Marker { lat: 43.5449532, lng: -79.7233935 },
-Marker { lat: 43.8910467, lng: -79.4091586 },
-Marker { lat: 43.8910467, lng: -79.4091586 },
-Marker { lat: 43.786298, lng: -79.660137 },
-Marker { lat: 43.611472, lng: -79.625317 },
-Marker { lat: 43.5915378, lng: -79.6278223 },
-Marker { lat: 43.7255667, lng: -79.7836398 },
-Marker { lat: 43.4104879, lng: -79.6950112 },
-Marker { lat: 43.8922836, lng: -78.9250066 },
-Marker { lat: 43.6548441, lng: -79.3846797 },
-Marker { lat: 43.830091, lng: -79.1247375 },
-Marker { lat: 43.744642, lng: -79.212272 },
-Marker { lat: 43.6940389, lng: -79.4351548 },
-Marker { lat: 43.7737399, lng: -79.2555526 },
-Marker { lat: 43.707083, lng: -79.397771 },
-Marker { lat: 43.7933162, lng: -79.132904 },
-Marker { lat: 43.7499263, lng: -79.2686309 },
-Marker { lat: 43.9380073, lng: -79.4336295 },
-Marker { lat: 43.9242531, lng: -79.5331078 },
-Marker { lat: 43.8880258, lng: -79.4589 },
-Marker { lat: 43.646393, lng: -79.7570932 },
-Marker { lat: 43.6861558, lng: -79.3136823 },
-Marker { lat: 43.915091, lng: -79.4346049 },
-Marker { lat: 43.9683533, lng: -79.2491431 },
-Marker { lat: 43.8297502, lng: -79.395146 },
-Marker { lat: 43.5629705, lng: -79.68867 },
-Marker { lat: 43.8221148, lng: -79.2029282 },
-Marker { lat: 43.943448, lng: -78.890674 },
-Marker { lat: 43.502189, lng: -79.885311 },
-Marker { lat: 43.652382, lng: -79.5175744 },
-Marker { lat: 43.586289, lng: -79.647364 },
-Marker { lat: 43.594996, lng: -79.535349 },
-Marker { lat: 43.7410507, lng: -79.5923462 },
-Marker { lat: 43.606531, lng: -79.7274832 },
-Marker { lat: 43.475132, lng: -79.74083 },
-Marker { lat: 43.62372, lng: -79.483703 },
-Marker { lat: 43.7182734, lng: -79.6861973 },
-Marker { lat: 43.62497, lng: -79.478079 },
-Marker { lat: 43.6161559, lng: -79.5966193 },
-Marker { lat: 43.519422, lng: -79.835884 },
-Marker { lat: 43.3974952, lng: -79.7066576 },
-Marker { lat: 43.6383649, lng: -79.4145613 },
-Marker { lat: 43.6489, lng: -79.392205 },
-Marker { lat: 43.6760539, lng: -79.3995695 },
-Marker { lat: 43.7777587, lng: -79.2989658 },
-Marker { lat: 43.853305, lng: -79.030887 },
-Marker { lat: 43.6747371, lng: -79.3720025 },
-Marker { lat: 43.708965, lng: -79.392985 },
-Marker { lat: 43.6566436, lng: -79.3547917 },
-Marker { lat: 43.7889457, lng: -79.4293327 },
-Marker { lat: 43.716146, lng: -79.2344158 },
-Marker { lat: 43.9067193, lng: -79.2709537 },
-Marker { lat: 43.842016, lng: -79.477483 },
-Marker { lat: 43.8961484, lng: -79.2873852 },
-Marker { lat: 43.881403, lng: -79.219835 },
-Marker { lat: 43.882618, lng: -79.223097 },
-Marker { lat: 43.566812, lng: -79.7606 },
-Marker { lat: 43.6911885, lng: -79.8321107 },
-Marker { lat: 43.5970213, lng: -79.5346155 },
-Marker { lat: 43.4278415, lng: -79.7338826 },
-Marker { lat: 43.7328545, lng: -79.7322456 },
-Marker { lat: 43.6898587, lng: -79.8382839 },
-Marker { lat: 43.7002459, lng: -79.5477366 },
-Marker { lat: 43.8165308, lng: -79.6237981 },
-Marker { lat: 43.6765095, lng: -79.3081056 },
-Marker { lat: 43.761404, lng: -79.565788 },
-Marker { lat: 43.852415, lng: -79.032168 },
-Marker { lat: 43.711587, lng: -79.395391 },
-Marker { lat: 43.7959687, lng: -79.2858693 },
-Marker { lat: 43.7782404, lng: -79.4177748 },
-Marker { lat: 43.768151, lng: -79.390791 },
-Marker { lat: 43.860334, lng: -79.230731 },
-Marker { lat: 43.8512666, lng: -79.4054375 },
-Marker { lat: 43.7621676, lng: -79.8298523 },
-Marker { lat: 43.854881, lng: -79.2237856 },
-Marker { lat: 43.64787, lng: -79.391138 },
-Marker { lat: 43.4178717, lng: -79.6940162 },
-Marker { lat: 43.6970211, lng: -79.7935527 },
-Marker { lat: 43.5473433, lng: -79.5918669 },
-Marker { lat: 43.882039, lng: -79.747343 },
-Marker { lat: 43.648783, lng: -79.388042 },
-Marker { lat: 43.8215789, lng: -79.3621924 },
-Marker { lat: 43.808273, lng: -79.1724617 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.761635, lng: -79.4231011 },
-Marker { lat: 43.672167, lng: -79.379876 },
-Marker { lat: 43.7263649, lng: -79.3279802 },
-Marker { lat: 43.6701828, lng: -79.5009914 },
-Marker { lat: 43.7078442, lng: -79.2506657 },
-Marker { lat: 43.7799579, lng: -79.3446599 },
-Marker { lat: 43.9260998, lng: -79.5099032 },
-Marker { lat: 43.7210094, lng: -79.760753 },
-Marker { lat: 43.8729304, lng: -79.4782008 },
-Marker { lat: 43.6676474, lng: -79.3120252 },
-Marker { lat: 43.959709, lng: -79.481286 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.7755305, lng: -79.4152516 },
-Marker { lat: 43.7818804, lng: -79.246391 },
-Marker { lat: 43.809312, lng: -79.6128018 },
-Marker { lat: 43.6495461, lng: -79.4444488 },
-Marker { lat: 43.773748, lng: -79.415509 },
-Marker { lat: 43.6211459, lng: -79.5215694 },
-Marker { lat: 43.9723967, lng: -79.2630592 },
-Marker { lat: 43.62526, lng: -79.477903 },
-Marker { lat: 43.853874, lng: -79.02991 },
-Marker { lat: 43.4048134, lng: -79.8179446 },
-Marker { lat: 43.4551319, lng: -79.6979344 },
-Marker { lat: 43.8714839, lng: -79.3946876 },
-Marker { lat: 43.6460991, lng: -79.3651362 },
-Marker { lat: 43.72709, lng: -79.772438 },
-Marker { lat: 43.6695584, lng: -79.3846569 },
-Marker { lat: 43.780853, lng: -79.4144717 },
-Marker { lat: 43.775424, lng: -79.337482 },
-Marker { lat: 43.62739, lng: -79.4784173 },
-Marker { lat: 43.665412, lng: -79.3871187 },
-Marker { lat: 43.6391434, lng: -79.4123994 },
-Marker { lat: 43.6306421, lng: -79.4921997 },
-Marker { lat: 43.6400003, lng: -79.3788904 },
-Marker { lat: 43.8661599, lng: -79.4102225 },
-Marker { lat: 43.9082825, lng: -78.9213946 },
-Marker { lat: 43.6547114, lng: -79.3792073 },
-Marker { lat: 43.944341, lng: -79.476321 },
-Marker { lat: 43.9389975, lng: -78.8861605 },
-Marker { lat: 43.855423, lng: -79.018045 },
-Marker { lat: 43.5712501, lng: -79.5807684 },
-Marker { lat: 43.7563012, lng: -79.4083836 },
-Marker { lat: 43.889638, lng: -79.420645 },
-Marker { lat: 43.6921033, lng: -79.2832796 },
-Marker { lat: 43.958893, lng: -78.907369 },
-Marker { lat: 43.6667472, lng: -79.3733325 },
-Marker { lat: 43.6502225, lng: -79.357641 },
-Marker { lat: 43.958288, lng: -78.896484 },
-Marker { lat: 43.8726785, lng: -79.4334238 },
-Marker { lat: 43.8748381, lng: -79.4512557 },
-Marker { lat: 43.8049979, lng: -79.4053482 },
-Marker { lat: 43.7201221, lng: -79.2366987 },
-Marker { lat: 43.7213327, lng: -79.2345678 },
-Marker { lat: 43.7095303, lng: -79.3982269 },
-Marker { lat: 43.3885446, lng: -79.715935 },
-Marker { lat: 43.8612759, lng: -79.3614984 },
-Marker { lat: 43.6457578, lng: -79.3946255 },
-Marker { lat: 43.7490152, lng: -79.406243 },
-Marker { lat: 43.8402851, lng: -79.3945718 },
-Marker { lat: 43.716527, lng: -79.284452 },
-Marker { lat: 43.851885, lng: -79.336294 },
-Marker { lat: 43.648799, lng: -79.392756 },
-Marker { lat: 43.3498134, lng: -79.8830594 },
-Marker { lat: 43.4519634, lng: -79.6644954 },
-Marker { lat: 43.5934007, lng: -79.5246576 },
-Marker { lat: 43.6391101, lng: -79.4725886 },
-Marker { lat: 43.9577283, lng: -78.9068226 },
-Marker { lat: 43.4965308, lng: -79.8794142 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.5883573, lng: -79.6395201 },
-Marker { lat: 43.7863744, lng: -79.6183232 },
-Marker { lat: 43.6402683, lng: -79.3960141 },
-Marker { lat: 43.6607839, lng: -79.5475928 },
-Marker { lat: 43.69037, lng: -79.2628896 },
-Marker { lat: 43.5870065, lng: -79.6405748 },
-Marker { lat: 43.8547091, lng: -79.2410847 },
-Marker { lat: 43.551698, lng: -79.745589 },
-Marker { lat: 43.866093, lng: -79.439199 },
-Marker { lat: 43.6430971, lng: -79.3916393 },
-Marker { lat: 43.7694183, lng: -79.3686931 },
-Marker { lat: 43.689685, lng: -79.385788 },
-Marker { lat: 43.7778723, lng: -79.2807506 },
-Marker { lat: 43.779819, lng: -79.282864 },
-Marker { lat: 43.665388, lng: -79.3880211 },
-Marker { lat: 43.7948843, lng: -79.3520093 },
-Marker { lat: 43.758071, lng: -79.403165 },
-Marker { lat: 43.6714964, lng: -79.398267 },
-Marker { lat: 43.4271718, lng: -79.6978166 },
-Marker { lat: 43.6405065, lng: -79.3969011 },
-Marker { lat: 43.708965, lng: -79.392985 },
-Marker { lat: 43.8958503, lng: -79.3008372 },
-Marker { lat: 43.656092, lng: -79.519717 },
-Marker { lat: 43.8983475, lng: -79.2483376 },
-Marker { lat: 43.7879166, lng: -79.3665002 },
-Marker { lat: 43.4558798, lng: -79.6580495 },
-Marker { lat: 43.7764673, lng: -79.3860878 },
-Marker { lat: 43.7694275, lng: -79.7143381 },
-Marker { lat: 43.948882, lng: -78.9224271 },
-Marker { lat: 43.6031312, lng: -79.6512623 },
-Marker { lat: 43.625807, lng: -79.477924 },
-Marker { lat: 43.7383272, lng: -79.3611139 },
-Marker { lat: 43.7934558, lng: -79.5994975 },
-Marker { lat: 43.8582831, lng: -79.5366678 },
-Marker { lat: 43.849006, lng: -79.065148 },
-Marker { lat: 43.8587649, lng: -79.5039619 },
-Marker { lat: 43.4034476, lng: -79.784471 },
-Marker { lat: 43.9177225, lng: -79.5366086 },
-Marker { lat: 43.4609721, lng: -79.6522671 },
-Marker { lat: 43.8954974, lng: -79.0260234 },
-Marker { lat: 43.647089, lng: -79.4073793 },
-Marker { lat: 43.725896, lng: -79.794904 },
-Marker { lat: 43.5841974, lng: -79.6485124 },
-Marker { lat: 43.758968, lng: -79.575423 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.7440078, lng: -79.7758282 },
-Marker { lat: 43.687188, lng: -79.421474 },
-Marker { lat: 43.4898745, lng: -79.8521897 },
-Marker { lat: 43.7613741, lng: -79.8223259 },
-Marker { lat: 43.8166371, lng: -79.4331464 },
-Marker { lat: 43.8593745, lng: -79.468045 },
-Marker { lat: 43.7040839, lng: -79.8276331 },
-Marker { lat: 43.7130154, lng: -79.7035255 },
-Marker { lat: 43.4192811, lng: -79.7056896 },
-Marker { lat: 43.779725, lng: -79.414445 },
-Marker { lat: 43.8760364, lng: -79.4212939 },
-Marker { lat: 43.9724669, lng: -78.9494952 },
-Marker { lat: 43.7756387, lng: -79.2253082 },
-Marker { lat: 43.8707844, lng: -79.0456068 },
-Marker { lat: 43.7774165, lng: -79.2812115 },
-Marker { lat: 43.7774165, lng: -79.2812115 },
-Marker { lat: 43.648799, lng: -79.392756 },
-Marker { lat: 43.648431, lng: -79.5055243 },
-Marker { lat: 43.62372, lng: -79.483703 },
-Marker { lat: 43.8604386, lng: -79.4079874 },
-Marker { lat: 43.4458342, lng: -79.6830585 },
-Marker { lat: 43.7362224, lng: -79.3530965 },
-Marker { lat: 43.6909576, lng: -79.2672062 },
-Marker { lat: 43.8540396, lng: -79.1061409 },
-Marker { lat: 43.5432078, lng: -79.6696197 },
-Marker { lat: 43.6095757, lng: -79.5573689 },
-Marker { lat: 43.8961192, lng: -79.4756311 },
-Marker { lat: 43.7353537, lng: -79.4018414 },
-Marker { lat: 43.5596224, lng: -79.572358 },
-Marker { lat: 43.6592713, lng: -79.3849163 },
-Marker { lat: 43.5012109, lng: -79.6949478 },
-Marker { lat: 43.6055166, lng: -79.7245656 },
-Marker { lat: 43.819888, lng: -79.607724 },
-Marker { lat: 43.7985555, lng: -79.15389 },
-Marker { lat: 43.5743148, lng: -79.586985 },
-Marker { lat: 43.6714433, lng: -79.3394721 },
-Marker { lat: 43.828581, lng: -79.088266 },
-Marker { lat: 43.7685605, lng: -79.1917362 },
-Marker { lat: 43.6490534, lng: -79.3861546 },
-Marker { lat: 43.7738036, lng: -79.4155869 },
-Marker { lat: 43.7292339, lng: -79.3353471 },
-Marker { lat: 43.850576, lng: -79.067479 },
-Marker { lat: 43.7553234, lng: -79.385214 },
-Marker { lat: 43.6962745, lng: -79.2927586 },
-Marker { lat: 43.7614092, lng: -79.4098055 },
-Marker { lat: 43.858207, lng: -79.5550962 },
-Marker { lat: 43.778615, lng: -79.452044 },
-Marker { lat: 43.9458705, lng: -79.465713 },
-Marker { lat: 43.6767474, lng: -79.7335639 },
-Marker { lat: 43.967532, lng: -78.943233 },
-Marker { lat: 43.946958, lng: -79.847105 },
-Marker { lat: 43.8042371, lng: -79.4497063 },
-Marker { lat: 43.803377, lng: -79.8255742 },
-Marker { lat: 43.5686417, lng: -79.577915 },
-Marker { lat: 43.799864, lng: -79.626063 },
-Marker { lat: 43.484699, lng: -79.8477654 },
-Marker { lat: 43.7762719, lng: -79.3865816 },
-Marker { lat: 43.69626, lng: -79.507042 },
-Marker { lat: 43.9557024, lng: -78.9120583 },
-Marker { lat: 43.7725122, lng: -79.4203562 },
-Marker { lat: 43.643188, lng: -79.422494 },
-Marker { lat: 43.9551348, lng: -79.4778914 },
-Marker { lat: 43.945329, lng: -79.4476192 },
-Marker { lat: 43.8488041, lng: -79.4399474 },
-Marker { lat: 43.8850044, lng: -79.3550465 },
-Marker { lat: 43.8007653, lng: -79.4001525 },
-Marker { lat: 43.5907831, lng: -79.5613018 },
-Marker { lat: 43.716003, lng: -79.7695462 },
-Marker { lat: 43.9114484, lng: -78.915559 },
-Marker { lat: 43.8410876, lng: -79.405166 },
-Marker { lat: 43.4022375, lng: -79.8145448 },
-Marker { lat: 43.6948266, lng: -79.401871 },
-Marker { lat: 43.944448, lng: -79.481679 },
-Marker { lat: 43.6398049, lng: -79.3979687 },
-Marker { lat: 43.811433, lng: -79.2781663 },
-Marker { lat: 43.883273, lng: -79.006549 },
-Marker { lat: 43.73377, lng: -79.534489 },
-Marker { lat: 43.678272, lng: -79.843341 },
-Marker { lat: 43.668564, lng: -79.395458 },
-Marker { lat: 43.4401378, lng: -79.7453005 },
-Marker { lat: 43.6868495, lng: -79.4598939 },
-Marker { lat: 43.6704858, lng: -79.5212189 },
-Marker { lat: 43.779165, lng: -79.3993718 },
-Marker { lat: 43.4815957, lng: -79.7375738 },
-Marker { lat: 43.4725795, lng: -79.7338914 },
-Marker { lat: 43.7023707, lng: -79.8526091 },
-Marker { lat: 43.426156, lng: -79.7757807 },
-Marker { lat: 43.6865262, lng: -79.7430347 },
-Marker { lat: 43.8496133, lng: -79.4401078 },
-Marker { lat: 43.526144, lng: -79.849478 },
-Marker { lat: 43.665753, lng: -79.385579 },
-Marker { lat: 43.8327152, lng: -79.5634647 },
-Marker { lat: 43.6591011, lng: -79.7822509 },
-Marker { lat: 43.8902513, lng: -79.3326019 },
-Marker { lat: 43.8866606, lng: -79.3558004 },
-Marker { lat: 43.8943157, lng: -79.4077518 },
-Marker { lat: 43.647825, lng: -79.385742 },
-Marker { lat: 43.9281964, lng: -79.5161267 },
-Marker { lat: 43.6385725, lng: -79.7918624 },
-Marker { lat: 43.6967506, lng: -79.3409178 },
-Marker { lat: 43.447443, lng: -79.686948 },
-Marker { lat: 43.8833107, lng: -78.942445 },
-Marker { lat: 43.8661927, lng: -79.8736221 },
-Marker { lat: 43.9047326, lng: -79.6507906 },
-Marker { lat: 43.65318, lng: -79.379908 },
-Marker { lat: 43.861226, lng: -79.4627258 },
-Marker { lat: 43.9697312, lng: -79.2394969 },
-Marker { lat: 43.7023882, lng: -79.8189282 },
-Marker { lat: 43.7365216, lng: -79.2065556 },
-Marker { lat: 43.575018, lng: -79.571004 },
-Marker { lat: 43.384591, lng: -79.7288737 },
-Marker { lat: 43.6034303, lng: -79.7035915 },
-Marker { lat: 43.6517827, lng: -79.7654179 },
-Marker { lat: 43.836911, lng: -79.439786 },
-Marker { lat: 43.8817649, lng: -79.247829 },
-Marker { lat: 43.4924057, lng: -79.7203229 },
-Marker { lat: 43.705222, lng: -79.758983 },
-Marker { lat: 43.5583794, lng: -79.6952787 },
-Marker { lat: 43.9784802, lng: -79.4457547 },
-Marker { lat: 43.6670963, lng: -79.7712001 },
-Marker { lat: 43.786218, lng: -79.6067422 },
-Marker { lat: 43.8953173, lng: -79.2532904 },
-Marker { lat: 43.9795222, lng: -79.2269775 },
-Marker { lat: 43.7135797, lng: -79.303446 },
-Marker { lat: 43.8119891, lng: -79.4362761 },
-Marker { lat: 43.7286088, lng: -79.3261749 },
-Marker { lat: 43.7044863, lng: -79.4024379 },
-Marker { lat: 43.691194, lng: -79.293857 },
-Marker { lat: 43.7589383, lng: -79.1799599 },
-Marker { lat: 43.8334179, lng: -79.5798292 },
-Marker { lat: 43.7467074, lng: -79.3056876 },
-Marker { lat: 43.6706915, lng: -79.3148122 },
-Marker { lat: 43.795696, lng: -79.520318 },
-Marker { lat: 43.7569815, lng: -79.4044001 },
-Marker { lat: 43.7495294, lng: -79.5807822 },
-Marker { lat: 43.667162, lng: -79.3735007 },
-Marker { lat: 43.6422746, lng: -79.3727039 },
-Marker { lat: 43.6406816, lng: -79.376999 },
-Marker { lat: 43.765056, lng: -79.195785 },
-Marker { lat: 43.832529, lng: -79.647586 },
-Marker { lat: 43.8125907, lng: -79.3227587 },
-Marker { lat: 43.8631295, lng: -79.2400461 },
-Marker { lat: 43.7634454, lng: -79.4493141 },
-Marker { lat: 43.8524965, lng: -79.4239253 },
-Marker { lat: 43.774325, lng: -79.346091 },
-Marker { lat: 43.687702, lng: -79.396783 },
-Marker { lat: 43.8739359, lng: -79.4460969 },
-Marker { lat: 43.7063711, lng: -79.2428116 },
-Marker { lat: 43.8849383, lng: -79.4211929 },
-Marker { lat: 43.7742413, lng: -79.3461881 },
-Marker { lat: 43.937995, lng: -79.4454147 },
-Marker { lat: 43.8846267, lng: -79.2586349 },
-Marker { lat: 43.7807555, lng: -79.4184006 },
-Marker { lat: 43.644128, lng: -79.389406 },
-Marker { lat: 43.8979874, lng: -79.4621808 },
-Marker { lat: 43.4716369, lng: -79.6838096 },
-Marker { lat: 43.7031923, lng: -79.4309344 },
-Marker { lat: 43.8069459, lng: -79.1015633 },
-Marker { lat: 43.7100327, lng: -79.249325 },
-Marker { lat: 43.7477956, lng: -79.3867198 },
-Marker { lat: 43.3461435, lng: -79.7613687 },
-Marker { lat: 43.7628186, lng: -79.4980299 },
-Marker { lat: 43.698853, lng: -79.2604175 },
-Marker { lat: 43.7765064, lng: -79.733551 },
-Marker { lat: 43.782933, lng: -79.170682 },
-Marker { lat: 43.688375, lng: -79.3204011 },
-Marker { lat: 43.85778, lng: -78.945192 },
-Marker { lat: 43.7333596, lng: -79.2296163 },
-Marker { lat: 43.8617175, lng: -79.4355742 },
-Marker { lat: 43.8731977, lng: -79.2469285 },
-Marker { lat: 43.8125538, lng: -79.4529616 },
-Marker { lat: 43.943448, lng: -78.890674 },
-Marker { lat: 43.8100647, lng: -79.4487117 },
-Marker { lat: 43.8503461, lng: -79.4300813 },
-Marker { lat: 43.5638574, lng: -79.7394791 },
-Marker { lat: 43.8681988, lng: -79.2227556 },
-Marker { lat: 43.6998655, lng: -79.8553533 },
-Marker { lat: 43.6031312, lng: -79.6512623 },
-Marker { lat: 43.7767311, lng: -79.4212659 },
-Marker { lat: 43.6388334, lng: -79.3988388 },
-Marker { lat: 43.883566, lng: -78.93583 },
-Marker { lat: 43.665388, lng: -79.3880211 },
-Marker { lat: 43.7254565, lng: -79.4071448 },
-Marker { lat: 43.9810833, lng: -79.233886 },
-Marker { lat: 43.6884978, lng: -79.4146412 },
-Marker { lat: 43.6700844, lng: -79.3805554 },
-Marker { lat: 43.7598991, lng: -79.3102207 },
-Marker { lat: 43.5950234, lng: -79.6706839 },
-Marker { lat: 43.9156342, lng: -79.4730653 },
-Marker { lat: 43.8464647, lng: -79.1101887 },
-Marker { lat: 43.7499321, lng: -79.2050033 },
-Marker { lat: 43.8677496, lng: -79.4393146 },
-Marker { lat: 43.680803, lng: -79.842706 },
-Marker { lat: 43.5552299, lng: -79.6248383 },
-Marker { lat: 43.6700845, lng: -79.3864005 },
-Marker { lat: 43.8253374, lng: -79.4289198 },
-Marker { lat: 43.6307453, lng: -79.7965763 },
-Marker { lat: 43.866991, lng: -79.43314 },
-Marker { lat: 43.6952604, lng: -79.2765134 },
-Marker { lat: 43.798312, lng: -79.117012 },
-Marker { lat: 43.6912472, lng: -79.4360406 },
-Marker { lat: 43.6687309, lng: -79.389488 },
-Marker { lat: 43.6601105, lng: -79.3605898 },
-Marker { lat: 43.9175762, lng: -79.4305317 },
-Marker { lat: 43.8858906, lng: -79.4980818 },
-Marker { lat: 43.8414667, lng: -79.4504609 },
-Marker { lat: 43.5816498, lng: -79.6480951 },
-Marker { lat: 43.7022865, lng: -79.4420585 },
-Marker { lat: 43.5765598, lng: -79.5885163 },
-Marker { lat: 43.5795671, lng: -79.6577022 },
-Marker { lat: 43.4010318, lng: -79.7003312 },
-Marker { lat: 43.5802294, lng: -79.7882491 },
-Marker { lat: 43.650405, lng: -79.3921684 },
-Marker { lat: 43.9256623, lng: -79.5097699 },
-Marker { lat: 43.877593, lng: -79.233618 },
-Marker { lat: 43.6620369, lng: -79.3865221 },
-Marker { lat: 43.6440617, lng: -79.3894386 },
-Marker { lat: 43.6975455, lng: -79.5297098 },
-Marker { lat: 43.6448836, lng: -79.3920974 },
-Marker { lat: 43.6881075, lng: -79.3979987 },
-Marker { lat: 43.902443, lng: -79.2516395 },
-Marker { lat: 43.889023, lng: -79.732447 },
-Marker { lat: 43.6729857, lng: -79.3870289 },
-Marker { lat: 43.481539, lng: -79.876132 },
-Marker { lat: 43.9146218, lng: -78.9484569 },
-Marker { lat: 43.646722, lng: -79.854505 },
-Marker { lat: 43.8228197, lng: -79.4278429 },
-Marker { lat: 43.715789, lng: -79.285703 },
-Marker { lat: 43.8770702, lng: -79.3404076 },
-Marker { lat: 43.7893004, lng: -79.3893504 },
-Marker { lat: 43.7224402, lng: -79.4255975 },
-Marker { lat: 43.7672146, lng: -79.3817072 },
-Marker { lat: 43.791956, lng: -79.392834 },
-Marker { lat: 43.8106398, lng: -79.4662628 },
-Marker { lat: 43.828236, lng: -79.4301893 },
-Marker { lat: 43.9153755, lng: -79.4466158 },
-Marker { lat: 43.8536872, lng: -79.4077619 },
-Marker { lat: 43.9268314, lng: -79.4544533 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.603047, lng: -79.6940698 },
-Marker { lat: 43.7188195, lng: -79.7994711 },
-Marker { lat: 43.5894512, lng: -79.7185292 },
-Marker { lat: 43.56562, lng: -79.7186931 },
-Marker { lat: 43.7166276, lng: -79.7977073 },
-Marker { lat: 43.817544, lng: -79.080003 },
-Marker { lat: 43.9533979, lng: -78.9696736 },
-Marker { lat: 43.8174537, lng: -79.4133754 },
-Marker { lat: 43.7748866, lng: -79.3354382 },
-Marker { lat: 43.5545758, lng: -79.6446842 },
-Marker { lat: 43.7238562, lng: -79.4275872 },
-Marker { lat: 43.8581003, lng: -79.2367871 },
-Marker { lat: 43.7128295, lng: -79.7638633 },
-Marker { lat: 43.7425322, lng: -79.8182655 },
-Marker { lat: 43.4426762, lng: -79.8946101 },
-Marker { lat: 43.922937, lng: -79.5226282 },
-Marker { lat: 43.726578, lng: -79.398866 },
-Marker { lat: 43.6383599, lng: -79.398657 },
-Marker { lat: 43.848756, lng: -79.012986 },
-Marker { lat: 43.8990735, lng: -79.4519067 },
-Marker { lat: 43.6585543, lng: -79.3526851 },
-Marker { lat: 43.7554201, lng: -79.426668 },
-Marker { lat: 43.659235, lng: -79.363475 },
-Marker { lat: 43.7263649, lng: -79.3279802 },
-Marker { lat: 43.660292, lng: -79.360577 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.7782404, lng: -79.4177748 },
-Marker { lat: 43.7665436, lng: -79.3897946 },
-Marker { lat: 43.9573033, lng: -78.9064849 },
-Marker { lat: 43.6568586, lng: -79.3836642 },
-Marker { lat: 43.6746538, lng: -79.3890069 },
-Marker { lat: 43.6712941, lng: -79.384925 },
-Marker { lat: 43.6458128, lng: -79.7308456 },
-Marker { lat: 43.7098809, lng: -79.784904 },
-Marker { lat: 43.4296769, lng: -79.7557333 },
-Marker { lat: 43.6689196, lng: -79.3905139 },
-Marker { lat: 43.7714841, lng: -79.6899748 },
-Marker { lat: 43.4818465, lng: -79.6772506 },
-Marker { lat: 43.7177352, lng: -79.7124202 },
-Marker { lat: 43.766028, lng: -79.1979422 },
-Marker { lat: 43.4778553, lng: -79.8716321 },
-Marker { lat: 43.9686512, lng: -79.2734911 },
-Marker { lat: 43.4035156, lng: -79.8159985 },
-Marker { lat: 43.7474184, lng: -79.7659525 },
-Marker { lat: 43.8105317, lng: -79.4050189 },
-Marker { lat: 43.7494904, lng: -79.2509099 },
-Marker { lat: 43.668578, lng: -79.384479 },
-Marker { lat: 43.9240394, lng: -79.5519944 },
-Marker { lat: 43.9406739, lng: -79.4623116 },
-Marker { lat: 43.8474026, lng: -79.1006083 },
-Marker { lat: 43.6611024, lng: -79.3819277 },
-Marker { lat: 43.67522, lng: -79.430649 },
-Marker { lat: 43.6491763, lng: -79.3770958 },
-Marker { lat: 43.6422746, lng: -79.3727039 },
-Marker { lat: 43.7641848, lng: -79.4105428 },
-Marker { lat: 43.647282, lng: -79.3974191 },
-Marker { lat: 43.8152962, lng: -79.6099409 },
-Marker { lat: 43.8205905, lng: -79.4237985 },
-Marker { lat: 43.646099, lng: -79.365136 },
-Marker { lat: 43.7763249, lng: -79.4140326 },
-Marker { lat: 43.646099, lng: -79.365136 },
-Marker { lat: 43.8664336, lng: -79.4355835 },
-Marker { lat: 43.4793057, lng: -79.7216264 },
-Marker { lat: 43.661781, lng: -79.384218 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.649381, lng: -79.374532 },
-Marker { lat: 43.778008, lng: -79.4527173 },
-Marker { lat: 43.555075, lng: -79.583315 },
-Marker { lat: 43.595271, lng: -79.682353 },
-Marker { lat: 43.807673, lng: -79.442576 },
-Marker { lat: 43.4878826, lng: -79.8606551 },
-Marker { lat: 43.480198, lng: -79.720663 },
-Marker { lat: 43.6485138, lng: -79.7687746 },
-Marker { lat: 43.842785, lng: -79.425917 },
-Marker { lat: 43.646099, lng: -79.365136 },
-Marker { lat: 43.8038883, lng: -79.3832161 },
-Marker { lat: 43.613136, lng: -79.6607762 },
-Marker { lat: 43.7758348, lng: -79.4132441 },
-Marker { lat: 43.7674139, lng: -79.4112489 },
-Marker { lat: 43.6601105, lng: -79.3605898 },
-Marker { lat: 43.377748, lng: -79.802927 },
-Marker { lat: 43.6695417, lng: -79.3837883 },
-Marker { lat: 43.5947036, lng: -79.6355027 },
-Marker { lat: 43.7740759, lng: -79.2502014 },
-Marker { lat: 43.62497, lng: -79.478079 },
-Marker { lat: 43.8420482, lng: -79.0172914 },
-Marker { lat: 43.7758348, lng: -79.4132441 },
-Marker { lat: 43.570413, lng: -79.613315 },
-Marker { lat: 43.7681761, lng: -79.4117756 },
-Marker { lat: 43.7205516, lng: -79.4209382 },
-Marker { lat: 43.7921855, lng: -79.4119602 },
-Marker { lat: 43.711278, lng: -79.322834 },
-Marker { lat: 43.762714, lng: -79.346479 },
-Marker { lat: 43.785644, lng: -79.591696 },
-Marker { lat: 43.8312253, lng: -79.1250199 },
-Marker { lat: 43.8584331, lng: -79.436549 },
-Marker { lat: 43.657856, lng: -79.460183 },
-Marker { lat: 43.4834219, lng: -79.7032644 },
-Marker { lat: 43.6382499, lng: -79.5362492 },
-Marker { lat: 43.602134, lng: -79.70231 },
-Marker { lat: 43.3811307, lng: -79.7306522 },
-Marker { lat: 43.8712393, lng: -79.3270278 },
-Marker { lat: 43.5816498, lng: -79.6480951 },
-Marker { lat: 43.6190815, lng: -79.5957831 },
-Marker { lat: 43.866321, lng: -79.435621 },
-Marker { lat: 43.8031867, lng: -79.4131592 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.775123, lng: -79.345456 },
-Marker { lat: 43.7569815, lng: -79.4044001 },
-Marker { lat: 43.9213277, lng: -79.417672 },
-Marker { lat: 43.8737359, lng: -79.4155305 },
-Marker { lat: 43.5881354, lng: -79.5502257 },
-Marker { lat: 43.7762379, lng: -79.6773919 },
-Marker { lat: 43.7591503, lng: -79.8316549 },
-Marker { lat: 43.791931, lng: -79.2468595 },
-Marker { lat: 43.6620369, lng: -79.3865221 },
-Marker { lat: 43.915544, lng: -79.2693 },
-Marker { lat: 43.7072301, lng: -79.2923194 },
-Marker { lat: 43.649428, lng: -79.388411 },
-Marker { lat: 43.7292605, lng: -79.3281088 },
-Marker { lat: 43.7676872, lng: -79.1761135 },
-Marker { lat: 43.7899404, lng: -79.4437575 },
-Marker { lat: 43.785172, lng: -79.184825 },
-Marker { lat: 43.8225351, lng: -79.6235144 },
-Marker { lat: 43.659454, lng: -79.382587 },
-Marker { lat: 43.8227398, lng: -79.0606248 },
-Marker { lat: 43.944203, lng: -79.4620543 },
-Marker { lat: 43.7171145, lng: -79.7109485 },
-Marker { lat: 43.6782226, lng: -79.7834844 },
-Marker { lat: 43.6753252, lng: -79.3781816 },
-Marker { lat: 43.7669362, lng: -79.7439437 },
-Marker { lat: 43.7358712, lng: -79.4655811 },
-Marker { lat: 43.7222396, lng: -79.2579941 },
-Marker { lat: 43.9605986, lng: -79.4418182 },
-Marker { lat: 43.8055517, lng: -79.7271429 },
-Marker { lat: 43.7542018, lng: -79.7403827 },
-Marker { lat: 43.7268296, lng: -79.4330171 },
-Marker { lat: 43.6440617, lng: -79.3894386 },
-Marker { lat: 43.9438548, lng: -79.4308717 },
-Marker { lat: 43.5044388, lng: -79.8471823 },
-Marker { lat: 43.9331306, lng: -79.5265822 },
-Marker { lat: 43.4013954, lng: -79.7122033 },
-Marker { lat: 43.7751229, lng: -79.4138309 },
-Marker { lat: 43.858252, lng: -79.433621 },
-Marker { lat: 43.8230999, lng: -79.4197745 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.733011, lng: -79.4028757 },
-Marker { lat: 43.7056504, lng: -79.3973799 },
-Marker { lat: 43.6501401, lng: -79.3940135 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.9287897, lng: -79.5808964 },
-Marker { lat: 43.8425041, lng: -79.6540219 },
-Marker { lat: 43.8750538, lng: -79.2305364 },
-Marker { lat: 43.572777, lng: -79.843559 },
-Marker { lat: 43.573017, lng: -79.6854564 },
-Marker { lat: 43.838005, lng: -79.923217 },
-Marker { lat: 43.520327, lng: -79.881249 },
-Marker { lat: 43.7906301, lng: -79.399883 },
-Marker { lat: 43.779705, lng: -79.3738975 },
-Marker { lat: 43.8151123, lng: -79.2888675 },
-Marker { lat: 43.7877861, lng: -79.2624278 },
-Marker { lat: 43.6959079, lng: -79.8445469 },
-Marker { lat: 43.768151, lng: -79.390791 },
-Marker { lat: 43.3894114, lng: -79.8176828 },
-Marker { lat: 43.7553562, lng: -79.3952357 },
-Marker { lat: 43.703773, lng: -79.296443 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.684863, lng: -79.853331 },
-Marker { lat: 43.814801, lng: -79.4227938 },
-Marker { lat: 43.8304275, lng: -79.6263739 },
-Marker { lat: 43.5686664, lng: -79.6112569 },
-Marker { lat: 43.3485885, lng: -79.7643157 },
-Marker { lat: 43.6211556, lng: -79.5215447 },
-Marker { lat: 43.447607, lng: -79.752934 },
-Marker { lat: 43.7703, lng: -79.413645 },
-Marker { lat: 43.833016, lng: -79.143466 },
-Marker { lat: 43.64787, lng: -79.391138 },
-Marker { lat: 43.840373, lng: -79.550086 },
-Marker { lat: 43.85195, lng: -79.431773 },
-Marker { lat: 43.7822326, lng: -79.4002037 },
-Marker { lat: 43.7818804, lng: -79.246391 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.790317, lng: -79.357381 },
-Marker { lat: 43.7256183, lng: -79.2265603 },
-Marker { lat: 43.6624074, lng: -79.3858983 },
-Marker { lat: 43.7891253, lng: -79.2904069 },
-Marker { lat: 43.7998466, lng: -79.2029062 },
-Marker { lat: 43.8654223, lng: -79.4832873 },
-Marker { lat: 43.8633063, lng: -79.3384406 },
-Marker { lat: 43.6676702, lng: -79.3864205 },
-Marker { lat: 43.7914091, lng: -79.2366785 },
-Marker { lat: 43.7663179, lng: -79.333577 },
-Marker { lat: 43.940598, lng: -79.297171 },
-Marker { lat: 43.5171414, lng: -79.6399208 },
-Marker { lat: 43.713934, lng: -79.7863775 },
-Marker { lat: 43.7714653, lng: -79.7079148 },
-Marker { lat: 43.8420436, lng: -79.4242694 },
-Marker { lat: 43.8559417, lng: -79.700697 },
-Marker { lat: 43.622729, lng: -79.48319 },
-Marker { lat: 43.8225179, lng: -79.3985646 },
-Marker { lat: 43.5003628, lng: -79.8629605 },
-Marker { lat: 43.7107418, lng: -79.3337304 },
-Marker { lat: 43.8992987, lng: -79.4415962 },
-Marker { lat: 43.6079209, lng: -79.7114006 },
-Marker { lat: 43.77807, lng: -79.4158254 },
-Marker { lat: 43.895037, lng: -79.2998845 },
-Marker { lat: 43.724082, lng: -79.824816 },
-Marker { lat: 43.7612263, lng: -79.2332281 },
-Marker { lat: 43.8144837, lng: -79.325326 },
-Marker { lat: 43.7602436, lng: -79.4083424 },
-Marker { lat: 43.714021, lng: -79.521728 },
-Marker { lat: 43.7653413, lng: -79.3825659 },
-Marker { lat: 43.7338186, lng: -79.346789 },
-Marker { lat: 43.67032, lng: -79.394433 },
-Marker { lat: 43.66955, lng: -79.383776 },
-Marker { lat: 43.7168353, lng: -79.2379713 },
-Marker { lat: 43.7252353, lng: -79.6011482 },
-Marker { lat: 43.6854499, lng: -79.2791052 },
-Marker { lat: 43.7778723, lng: -79.2807506 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.6694723, lng: -79.3874877 },
-Marker { lat: 43.6383872, lng: -79.4010639 },
-Marker { lat: 43.6400086, lng: -79.3820669 },
-Marker { lat: 43.761461, lng: -79.409802 },
-Marker { lat: 43.6402781, lng: -79.5597448 },
-Marker { lat: 43.6959554, lng: -79.3957179 },
-Marker { lat: 43.678003, lng: -79.812423 },
-Marker { lat: 43.8932192, lng: -79.3988748 },
-Marker { lat: 43.3855962, lng: -79.7237481 },
-Marker { lat: 43.6546512, lng: -79.4145399 },
-Marker { lat: 43.7815006, lng: -79.4130543 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.654449, lng: -79.4680817 },
-Marker { lat: 43.7239017, lng: -79.4445614 },
-Marker { lat: 43.888592, lng: -79.370934 },
-Marker { lat: 43.5103289, lng: -79.8784555 },
-Marker { lat: 43.4740972, lng: -79.7043777 },
-Marker { lat: 43.838013, lng: -79.2939926 },
-Marker { lat: 43.8891139, lng: -79.2444514 },
-Marker { lat: 43.7592761, lng: -79.4296378 },
-Marker { lat: 43.5079699, lng: -79.8818944 },
-Marker { lat: 43.6744722, lng: -79.2906004 },
-Marker { lat: 43.6440617, lng: -79.3894386 },
-Marker { lat: 43.7581405, lng: -79.4156592 },
-Marker { lat: 43.6489, lng: -79.392205 },
-Marker { lat: 43.7832044, lng: -79.5795291 },
-Marker { lat: 43.9779694, lng: -79.233321 },
-Marker { lat: 43.9235433, lng: -79.5368216 },
-Marker { lat: 43.8383472, lng: -79.460143 },
-Marker { lat: 43.7127711, lng: -79.293179 },
-Marker { lat: 43.7169065, lng: -79.4205894 },
-Marker { lat: 43.7439122, lng: -79.3881707 },
-Marker { lat: 43.8148107, lng: -79.4033069 },
-Marker { lat: 43.5835526, lng: -79.6427658 },
-Marker { lat: 43.4118949, lng: -79.8012194 },
-Marker { lat: 43.8664336, lng: -79.4355835 },
-Marker { lat: 43.5876825, lng: -79.6283005 },
-Marker { lat: 43.7756343, lng: -79.3797485 },
-Marker { lat: 43.5140979, lng: -79.9237031 },
-Marker { lat: 43.7002667, lng: -79.4111944 },
-Marker { lat: 43.4195862, lng: -79.7048897 },
-Marker { lat: 43.7752003, lng: -79.412361 },
-Marker { lat: 43.6891499, lng: -79.504069 },
-Marker { lat: 43.7710844, lng: -79.4315478 },
-Marker { lat: 43.8516642, lng: -79.6302598 },
-Marker { lat: 43.8303899, lng: -79.7426184 },
-Marker { lat: 43.8726, lng: -79.4159142 },
-Marker { lat: 43.9662688, lng: -79.1363057 },
-Marker { lat: 43.7891876, lng: -79.4369176 },
-Marker { lat: 43.6013164, lng: -79.6101786 },
-Marker { lat: 43.7859764, lng: -79.1975731 },
-Marker { lat: 43.6729857, lng: -79.3870289 },
-Marker { lat: 43.643051, lng: -79.391643 },
-Marker { lat: 43.8182881, lng: -79.3616469 },
-Marker { lat: 43.7215339, lng: -79.3907323 },
-Marker { lat: 43.92585, lng: -79.5109644 },
-Marker { lat: 43.9006918, lng: -79.4242651 },
-Marker { lat: 43.350917, lng: -79.816293 },
-Marker { lat: 43.851159, lng: -79.272708 },
-Marker { lat: 43.6127481, lng: -79.7136626 },
-Marker { lat: 43.9716099, lng: -79.2647942 },
-Marker { lat: 43.6394336, lng: -79.4467459 },
-Marker { lat: 43.8665065, lng: -79.3164128 },
-Marker { lat: 43.766849, lng: -79.711613 },
-Marker { lat: 43.970267, lng: -79.263706 },
-Marker { lat: 43.679602, lng: -79.44097 },
-Marker { lat: 43.714141, lng: -79.450545 },
-Marker { lat: 43.930591, lng: -79.5315375 },
-Marker { lat: 43.4315725, lng: -79.6897186 },
-Marker { lat: 43.970267, lng: -79.263706 },
-Marker { lat: 43.7255458, lng: -79.2517641 },
-Marker { lat: 43.6274692, lng: -79.4771277 },
-Marker { lat: 43.968412, lng: -79.379601 },
-Marker { lat: 43.648858, lng: -79.378145 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.6969479, lng: -79.4430962 },
-Marker { lat: 43.743751, lng: -79.213172 },
-Marker { lat: 43.930412, lng: -79.5215418 },
-Marker { lat: 43.968721, lng: -78.884111 },
-Marker { lat: 43.4024206, lng: -79.7824283 },
-Marker { lat: 43.8523245, lng: -79.4984189 },
-Marker { lat: 43.6399005, lng: -79.3905103 },
-Marker { lat: 43.7762128, lng: -79.3059668 },
-Marker { lat: 43.778017, lng: -79.416628 },
-Marker { lat: 43.6878988, lng: -79.3890855 },
-Marker { lat: 43.6548441, lng: -79.3846797 },
-Marker { lat: 43.6425743, lng: -79.4964428 },
-Marker { lat: 43.7655684, lng: -79.5035845 },
-Marker { lat: 43.622729, lng: -79.48319 },
-Marker { lat: 43.6500154, lng: -79.3873578 },
-Marker { lat: 43.760304, lng: -79.41162 },
-Marker { lat: 43.7681003, lng: -79.4406234 },
-Marker { lat: 43.8544291, lng: -79.4663132 },
-Marker { lat: 43.4474464, lng: -79.6816398 },
-Marker { lat: 43.7525066, lng: -79.3766601 },
-Marker { lat: 43.6500154, lng: -79.3873578 },
-Marker { lat: 43.766616, lng: -79.382896 },
-Marker { lat: 43.6404408, lng: -79.3971889 },
-Marker { lat: 43.6428617, lng: -79.3769141 },
-Marker { lat: 43.9725627, lng: -78.9471389 },
-Marker { lat: 43.532913, lng: -79.712033 },
-Marker { lat: 43.7432633, lng: -79.7484377 },
-Marker { lat: 43.6975467, lng: -79.794611 },
-Marker { lat: 43.706188, lng: -79.4473279 },
-Marker { lat: 43.785023, lng: -79.436435 },
-Marker { lat: 43.6806534, lng: -79.4698655 },
-Marker { lat: 43.4716447, lng: -79.7420266 },
-Marker { lat: 43.8567081, lng: -79.8498549 },
-Marker { lat: 43.853478, lng: -79.336884 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.7144036, lng: -79.8329654 },
-Marker { lat: 43.6671576, lng: -79.7711328 },
-Marker { lat: 43.730531, lng: -79.5219 },
-Marker { lat: 43.4473961, lng: -79.686806 },
-Marker { lat: 43.713654, lng: -79.411549 },
-Marker { lat: 43.7228912, lng: -79.245434 },
-Marker { lat: 43.6693009, lng: -79.3841709 },
-Marker { lat: 43.4449206, lng: -79.7171516 },
-Marker { lat: 43.6762844, lng: -79.8266674 },
-Marker { lat: 43.5651748, lng: -79.6936266 },
-Marker { lat: 43.669301, lng: -79.384171 },
-Marker { lat: 43.6768454, lng: -79.3984501 },
-Marker { lat: 43.7558985, lng: -79.8170979 },
-Marker { lat: 43.8771773, lng: -79.41811 },
-Marker { lat: 43.6401441, lng: -79.4239168 },
-Marker { lat: 43.7502599, lng: -79.4059172 },
-Marker { lat: 43.6393941, lng: -79.3997862 },
-Marker { lat: 43.687702, lng: -79.396783 },
-Marker { lat: 43.7359826, lng: -79.4288125 },
-Marker { lat: 43.758071, lng: -79.403165 },
-Marker { lat: 43.764029, lng: -79.407042 },
-Marker { lat: 43.652136, lng: -79.371483 },
-Marker { lat: 43.667211, lng: -79.389153 },
-Marker { lat: 43.839437, lng: -79.393276 },
-Marker { lat: 43.6687952, lng: -79.3839693 },
-Marker { lat: 43.8612283, lng: -79.4057008 },
-Marker { lat: 43.8178228, lng: -79.2173875 },
-Marker { lat: 43.667222, lng: -79.3839697 },
-Marker { lat: 43.6419687, lng: -79.4129266 },
-Marker { lat: 43.8807657, lng: -79.3100651 },
-Marker { lat: 43.87629, lng: -79.2384109 },
-Marker { lat: 43.5874595, lng: -79.6072369 },
-Marker { lat: 43.9008447, lng: -79.2561593 },
-Marker { lat: 43.7897579, lng: -79.4698761 },
-Marker { lat: 43.7098418, lng: -79.396432 },
-Marker { lat: 43.7055628, lng: -79.3696034 },
-Marker { lat: 43.8645952, lng: -79.3984326 },
-Marker { lat: 43.6631984, lng: -79.5178465 },
-Marker { lat: 43.4976933, lng: -79.7200237 },
-Marker { lat: 43.5336622, lng: -79.6791416 },
-Marker { lat: 43.3949227, lng: -79.8213628 },
-Marker { lat: 43.5928683, lng: -79.6725099 },
-Marker { lat: 43.5967122, lng: -79.6842542 },
-Marker { lat: 43.646793, lng: -79.888187 },
-Marker { lat: 43.794967, lng: -79.353066 },
-Marker { lat: 43.7180212, lng: -79.3768816 },
-Marker { lat: 43.799471, lng: -79.356126 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.9051099, lng: -79.4572463 },
-Marker { lat: 43.8268388, lng: -79.2786357 },
-Marker { lat: 43.7724766, lng: -79.1623277 },
-Marker { lat: 43.943448, lng: -78.890674 },
-Marker { lat: 43.7801947, lng: -79.4046738 },
-Marker { lat: 43.8229556, lng: -79.291245 },
-Marker { lat: 43.85102, lng: -79.427588 },
-Marker { lat: 43.8981671, lng: -79.2683667 },
-Marker { lat: 43.8664512, lng: -79.214936 },
-Marker { lat: 43.7168184, lng: -79.7634558 },
-Marker { lat: 43.8129727, lng: -79.4551834 },
-Marker { lat: 43.8885315, lng: -79.2248881 },
-Marker { lat: 43.5198033, lng: -79.866139 },
-Marker { lat: 43.7909568, lng: -79.755841 },
-Marker { lat: 43.4983392, lng: -79.8802732 },
-Marker { lat: 43.7066822, lng: -79.4549232 },
-Marker { lat: 43.6840483, lng: -79.4487812 },
-Marker { lat: 43.8541173, lng: -79.3409476 },
-Marker { lat: 43.5629331, lng: -79.59104 },
-Marker { lat: 43.680164, lng: -79.493144 },
-Marker { lat: 43.5584691, lng: -79.7263706 },
-Marker { lat: 43.971766, lng: -79.805052 },
-Marker { lat: 43.5872308, lng: -79.639211 },
-Marker { lat: 43.5709108, lng: -79.7206605 },
-Marker { lat: 43.5152259, lng: -79.8834926 },
-Marker { lat: 43.693954, lng: -79.7934152 },
-Marker { lat: 43.8659, lng: -79.4342 },
-Marker { lat: 43.6514, lng: -79.9219 },
-Marker { lat: 43.7625, lng: -79.1981 },
-Marker { lat: 43.928, lng: -79.2581 },
-Marker { lat: 43.5893, lng: -79.6102 },
-Marker { lat: 43.6501, lng: -79.9175 },
-Marker { lat: 43.6501, lng: -79.9175 },
-Marker { lat: 43.8227779, lng: -79.3925602 },
-Marker { lat: 43.6804, lng: -79.7693 },
-Marker { lat: 43.7809647, lng: -79.249788 },
-Marker { lat: 43.6514, lng: -79.3826 },
-Marker { lat: 43.8825065, lng: -79.4653856 },
-Marker { lat: 43.5795444, lng: -79.6054876 },
-Marker { lat: 43.6585, lng: -79.4334 },
-Marker { lat: 43.8663974, lng: -79.6212485 },
-Marker { lat: 43.9601105, lng: -78.9092253 },
-Marker { lat: 43.7904047, lng: -79.6014363 },
-Marker { lat: 43.9072174, lng: -79.3949387 },
-Marker { lat: 43.9558868, lng: -78.905255 },
-Marker { lat: 43.8539543, lng: -79.7000065 },
-Marker { lat: 43.8164, lng: -79.3682 },
-Marker { lat: 43.7386, lng: -79.8437 },
-Marker { lat: 43.9049212, lng: -79.3883531 },
-Marker { lat: 43.9136545, lng: -78.9756982 },
-Marker { lat: 43.4285, lng: -79.7445 },
-Marker { lat: 43.6791, lng: -79.2932 },
-Marker { lat: 43.4066073, lng: -79.6944626 },
-Marker { lat: 43.732244, lng: -79.774479 },
-Marker { lat: 43.967819, lng: -79.2736409 },
-Marker { lat: 43.7812996, lng: -79.3813081 },
-Marker { lat: 43.577389, lng: -79.716675 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.9437005, lng: -79.4630177 },
-Marker { lat: 43.861078, lng: -79.067189 },
-Marker { lat: 43.8833844, lng: -78.8849623 },
-Marker { lat: 43.8463133, lng: -79.443207 },
-Marker { lat: 43.8373061, lng: -79.2672144 },
-Marker { lat: 43.7671267, lng: -79.5016271 },
-Marker { lat: 43.757354, lng: -79.409358 },
-Marker { lat: 43.8822936, lng: -79.2250367 },
-Marker { lat: 43.6401441, lng: -79.4239168 },
-Marker { lat: 43.5833367, lng: -79.6054253 },
-Marker { lat: 43.7951922, lng: -79.3243815 },
-Marker { lat: 43.7504859, lng: -79.3776325 },
-Marker { lat: 43.422211, lng: -79.769667 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.6420815, lng: -79.3828097 },
-Marker { lat: 43.8091745, lng: -79.4467726 },
-Marker { lat: 43.964452, lng: -79.4463189 },
-Marker { lat: 43.702046, lng: -79.2884338 },
-Marker { lat: 43.8790775, lng: -79.4393859 },
-Marker { lat: 43.523009, lng: -79.859479 },
-Marker { lat: 43.7127481, lng: -79.5013109 },
-Marker { lat: 43.8974694, lng: -79.468861 },
-Marker { lat: 43.569806, lng: -79.58588 },
-Marker { lat: 43.7551609, lng: -79.3992225 },
-Marker { lat: 43.8509449, lng: -79.4316332 },
-Marker { lat: 43.774325, lng: -79.346091 },
-Marker { lat: 43.6812125, lng: -79.3022548 },
-Marker { lat: 43.7970188, lng: -79.3721457 },
-Marker { lat: 43.642923, lng: -79.526725 },
-Marker { lat: 43.7980539, lng: -79.1191315 },
-Marker { lat: 43.8327258, lng: -79.5772837 },
-Marker { lat: 43.8117, lng: -79.6306 },
-Marker { lat: 43.882495, lng: -79.4658028 },
-Marker { lat: 43.8938061, lng: -79.6562835 },
-Marker { lat: 43.9647347, lng: -78.8913165 },
-Marker { lat: 43.4736095, lng: -79.7433517 },
-Marker { lat: 43.8659, lng: -79.4342 },
-Marker { lat: 43.6241341, lng: -79.4858146 },
-Marker { lat: 43.7529797, lng: -79.8414843 },
-Marker { lat: 43.8707933, lng: -79.4131122 },
-Marker { lat: 43.8798018, lng: -79.8623473 },
-Marker { lat: 43.7907121, lng: -79.600707 },
-Marker { lat: 43.8602061, lng: -79.3244441 },
-Marker { lat: 43.6503, lng: -79.9216 },
-Marker { lat: 43.8949586, lng: -79.6532038 },
-Marker { lat: 43.5029802, lng: -79.6568196 },
-Marker { lat: 43.8793208, lng: -79.0024538 },
-Marker { lat: 43.6519615, lng: -79.8148426 },
-Marker { lat: 43.7418, lng: -79.4709 },
-Marker { lat: 43.8368038, lng: -79.6607636 },
-Marker { lat: 43.7358463, lng: -79.772516 },
-Marker { lat: 43.8714467, lng: -79.2188914 },
-Marker { lat: 43.4854, lng: -79.6988 },
-Marker { lat: 43.8518, lng: -79.3887 },
-Marker { lat: 43.3478, lng: -79.7806 },
-Marker { lat: 43.9570875, lng: -78.9023788 },
-Marker { lat: 43.3635368, lng: -79.775045 },
-Marker { lat: 43.7886, lng: -79.3461 },
-Marker { lat: 43.9562204, lng: -78.901339 },
-Marker { lat: 43.6085, lng: -79.5066 },
-Marker { lat: 43.4837652, lng: -79.7316463 },
-Marker { lat: 43.8580177, lng: -79.702749 },
-Marker { lat: 43.7491456, lng: -79.3656056 },
-Marker { lat: 43.6539796, lng: -79.3885197 },
-Marker { lat: 43.645505, lng: -79.392457 },
-Marker { lat: 43.7753439, lng: -79.4315887 },
-Marker { lat: 43.6468455, lng: -79.4028294 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.6569646, lng: -79.4465224 },
-Marker { lat: 43.6729857, lng: -79.3870289 },
-Marker { lat: 43.6765411, lng: -79.4056079 },
-Marker { lat: 43.9567061, lng: -78.8933103 },
-Marker { lat: 43.7237984, lng: -79.2700999 },
-Marker { lat: 43.8202435, lng: -79.2856927 },
-Marker { lat: 43.7693821, lng: -79.235599 },
-Marker { lat: 43.657018, lng: -79.381197 },
-Marker { lat: 43.7805353, lng: -79.2510063 },
-Marker { lat: 43.6367266, lng: -79.54671 },
-Marker { lat: 43.609209, lng: -79.5583318 },
-Marker { lat: 43.5300299, lng: -79.6364823 },
-Marker { lat: 43.394839, lng: -79.7910592 },
-Marker { lat: 43.6083463, lng: -79.6466053 },
-Marker { lat: 43.6333917, lng: -79.4977992 },
-Marker { lat: 43.6296617, lng: -79.7983349 },
-Marker { lat: 43.682004, lng: -79.843868 },
-Marker { lat: 43.7004891, lng: -79.2759524 },
-Marker { lat: 43.738367, lng: -79.816368 },
-Marker { lat: 43.826005, lng: -79.012398 },
-Marker { lat: 43.8101834, lng: -79.1302485 },
-Marker { lat: 43.8559242, lng: -79.2926949 },
-Marker { lat: 43.6542625, lng: -79.7741758 },
-Marker { lat: 43.7008176, lng: -79.528138 },
-Marker { lat: 43.7705121, lng: -79.7523799 },
-Marker { lat: 43.6996878, lng: -79.4441283 },
-Marker { lat: 43.88644, lng: -79.451436 },
-Marker { lat: 43.7686951, lng: -79.3891863 },
-Marker { lat: 43.7901767, lng: -79.4495948 },
-Marker { lat: 43.7652017, lng: -79.3832771 },
-Marker { lat: 43.7097312, lng: -79.428317 },
-Marker { lat: 43.873512, lng: -78.9316885 },
-Marker { lat: 43.6459582, lng: -79.3898731 },
-Marker { lat: 43.9613539, lng: -78.9584243 },
-Marker { lat: 43.790419, lng: -79.6008927 },
-Marker { lat: 43.5614572, lng: -79.636797 },
-Marker { lat: 43.786522, lng: -79.2938619 },
-Marker { lat: 43.5974245, lng: -79.6298902 },
-Marker { lat: 43.7235438, lng: -79.4201971 },
-Marker { lat: 43.843801, lng: -79.566206 },
-Marker { lat: 43.6966073, lng: -79.2942117 },
-Marker { lat: 43.7327325, lng: -79.7337124 },
-Marker { lat: 43.8547752, lng: -79.4213714 },
-Marker { lat: 43.7512065, lng: -79.5828607 },
-Marker { lat: 43.6239417, lng: -79.4884467 },
-Marker { lat: 43.561597, lng: -79.712093 },
-Marker { lat: 43.7247033, lng: -79.307678 },
-Marker { lat: 43.9156614, lng: -78.9769092 },
-Marker { lat: 43.897883, lng: -79.46613 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.783388, lng: -79.265134 },
-Marker { lat: 43.8968119, lng: -79.3969637 },
-Marker { lat: 43.7262822, lng: -79.2279083 },
-Marker { lat: 43.5950726, lng: -79.6198339 },
-Marker { lat: 43.642862, lng: -79.527224 },
-Marker { lat: 43.7618184, lng: -79.4047473 },
-Marker { lat: 43.5835526, lng: -79.6427658 },
-Marker { lat: 43.849459, lng: -79.499082 },
-Marker { lat: 43.3597715, lng: -79.8483762 },
-Marker { lat: 43.8991266, lng: -79.432712 },
-Marker { lat: 43.6415894, lng: -79.4157619 },
-Marker { lat: 43.7958912, lng: -79.4271615 },
-Marker { lat: 43.7444315, lng: -79.7557979 },
-Marker { lat: 43.6561334, lng: -79.5712675 },
-Marker { lat: 43.628143, lng: -79.941092 },
-Marker { lat: 43.6813756, lng: -79.4484652 },
-Marker { lat: 43.6374676, lng: -79.4184499 },
-Marker { lat: 43.6568586, lng: -79.3836642 },
-Marker { lat: 43.8932707, lng: -79.2463205 },
-Marker { lat: 43.649256, lng: -79.375765 },
-Marker { lat: 43.8240908, lng: -79.4079722 },
-Marker { lat: 43.6520694, lng: -79.4719924 },
-Marker { lat: 43.4378413, lng: -79.765495 },
-Marker { lat: 43.7376671, lng: -79.2695674 },
-Marker { lat: 43.8793, lng: -79.497 },
-Marker { lat: 43.8714635, lng: -79.2219475 },
-Marker { lat: 43.3598653, lng: -79.8113489 },
-Marker { lat: 43.8463945, lng: -78.9980247 },
-Marker { lat: 43.6627991, lng: -79.4706377 },
-Marker { lat: 43.8906698, lng: -79.4570063 },
-Marker { lat: 43.3830851, lng: -79.8106693 },
-Marker { lat: 43.7809498, lng: -79.2606465 },
-Marker { lat: 43.79291, lng: -79.413572 },
-Marker { lat: 43.8827846, lng: -78.9176518 },
-Marker { lat: 43.4670299, lng: -79.6922162 },
-Marker { lat: 43.624075, lng: -79.484917 },
-Marker { lat: 43.630517, lng: -79.490656 },
-Marker { lat: 43.7527274, lng: -79.2558541 },
-Marker { lat: 43.7690784, lng: -79.3694836 },
-Marker { lat: 43.645964, lng: -79.399395 },
-Marker { lat: 43.8849551, lng: -79.3033126 },
-Marker { lat: 43.7311454, lng: -79.4300107 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.7690784, lng: -79.3694836 },
-Marker { lat: 43.889186, lng: -78.941875 },
-Marker { lat: 43.7274086, lng: -79.5218604 },
-Marker { lat: 43.7619854, lng: -79.7717269 },
-Marker { lat: 43.5880851, lng: -79.7579255 },
-Marker { lat: 43.7541529, lng: -79.4035081 },
-Marker { lat: 43.7368618, lng: -79.4760342 },
-Marker { lat: 43.5946806, lng: -79.7831711 },
-Marker { lat: 43.937316, lng: -79.4436585 },
-Marker { lat: 43.6676171, lng: -79.8054392 },
-Marker { lat: 43.692343, lng: -79.8468098 },
-Marker { lat: 43.8274988, lng: -79.3718821 },
-Marker { lat: 43.5825096, lng: -79.6830087 },
-Marker { lat: 43.7804397, lng: -79.6674895 },
-Marker { lat: 43.5396658, lng: -79.7340621 },
-Marker { lat: 43.3460466, lng: -79.7876236 },
-Marker { lat: 43.7242174, lng: -79.7162623 },
-Marker { lat: 43.8837922, lng: -79.4001349 },
-Marker { lat: 43.6989963, lng: -79.3815402 },
-Marker { lat: 43.7211998, lng: -79.4585418 },
-Marker { lat: 43.6697352, lng: -79.3176193 },
-Marker { lat: 43.7262971, lng: -79.7803852 },
-Marker { lat: 43.628256, lng: -79.894292 },
-Marker { lat: 43.8135459, lng: -79.322156 },
-Marker { lat: 43.869115, lng: -78.962299 },
-Marker { lat: 43.7277051, lng: -79.4016809 },
-Marker { lat: 43.7343386, lng: -79.355188 },
-Marker { lat: 43.780039, lng: -79.344324 },
-Marker { lat: 43.7481415, lng: -79.7474122 },
-Marker { lat: 43.7341094, lng: -79.7715959 },
-Marker { lat: 43.7853277, lng: -79.2885086 },
-Marker { lat: 43.843482, lng: -79.4427371 },
-Marker { lat: 43.7543083, lng: -79.5229676 },
-Marker { lat: 43.586308, lng: -79.7882665 },
-Marker { lat: 43.8525049, lng: -79.5637904 },
-Marker { lat: 43.7653441, lng: -79.4126151 },
-Marker { lat: 43.8638038, lng: -79.4308561 },
-Marker { lat: 43.7011344, lng: -79.8566694 },
-Marker { lat: 43.6679848, lng: -79.3755762 },
-Marker { lat: 43.778847, lng: -79.597021 },
-Marker { lat: 43.7859764, lng: -79.1975731 },
-Marker { lat: 43.849203, lng: -79.285062 },
-Marker { lat: 43.8046762, lng: -79.4230377 },
-Marker { lat: 43.7765413, lng: -79.4326862 },
-Marker { lat: 43.6599507, lng: -79.3849463 },
-Marker { lat: 43.7028171, lng: -79.4158597 },
-Marker { lat: 43.614903, lng: -79.488798 },
-Marker { lat: 43.7694392, lng: -79.4160861 },
-Marker { lat: 43.7745195, lng: -79.3297599 },
-Marker { lat: 43.7400418, lng: -79.4109884 },
-Marker { lat: 43.672274, lng: -79.3804573 },
-Marker { lat: 43.849897, lng: -79.438838 },
-Marker { lat: 43.717805, lng: -79.414806 },
-Marker { lat: 43.7070826, lng: -79.3977712 },
-Marker { lat: 43.6509155, lng: -79.4362398 },
-Marker { lat: 43.7501397, lng: -79.5673869 },
-Marker { lat: 43.660246, lng: -79.466657 },
-Marker { lat: 43.7671995, lng: -79.2688481 },
-Marker { lat: 43.954557, lng: -79.472827 },
-Marker { lat: 43.9698562, lng: -79.2216107 },
-Marker { lat: 43.779207, lng: -79.281519 },
-Marker { lat: 43.6364747, lng: -79.4020594 },
-Marker { lat: 43.8532036, lng: -79.499272 },
-Marker { lat: 43.7585452, lng: -79.4195607 },
-Marker { lat: 43.638987, lng: -79.531391 },
-Marker { lat: 43.8221939, lng: -79.4164431 },
-Marker { lat: 43.6846135, lng: -79.3569988 },
-Marker { lat: 43.779718, lng: -79.342657 },
-Marker { lat: 43.645281, lng: -79.432878 },
-Marker { lat: 43.485697, lng: -79.72297 },
-Marker { lat: 43.780493, lng: -79.3635433 },
-Marker { lat: 43.9793664, lng: -79.5995219 },
-Marker { lat: 43.6252026, lng: -79.5157833 },
-Marker { lat: 43.7271577, lng: -79.9369537 },
-Marker { lat: 43.7690784, lng: -79.3694836 },
-Marker { lat: 43.8311452, lng: -79.4670708 },
-Marker { lat: 43.754389, lng: -79.3778978 },
-Marker { lat: 43.5746071, lng: -79.5874583 },
-Marker { lat: 43.8041695, lng: -79.6086206 },
-Marker { lat: 43.83127, lng: -79.4785198 },
-Marker { lat: 43.687509, lng: -79.39595 },
-Marker { lat: 43.8621905, lng: -79.3391012 },
-Marker { lat: 43.9676226, lng: -78.9532315 },
-Marker { lat: 43.6746538, lng: -79.3890069 },
-Marker { lat: 43.7787489, lng: -79.4171422 },
-Marker { lat: 43.9850942, lng: -78.9566002 },
-Marker { lat: 43.714484, lng: -79.2828228 },
-Marker { lat: 43.7872283, lng: -79.1946802 },
-Marker { lat: 43.8263784, lng: -79.2750135 },
-Marker { lat: 43.9533203, lng: -79.007867 },
-Marker { lat: 43.833122, lng: -79.531325 },
-Marker { lat: 43.7682116, lng: -79.1828222 },
-Marker { lat: 43.9830769, lng: -79.4740904 },
-Marker { lat: 43.9432446, lng: -79.2822707 },
-Marker { lat: 43.8284551, lng: -79.574502 },
-Marker { lat: 43.73377, lng: -79.534489 },
-Marker { lat: 43.6875168, lng: -79.4489297 },
-Marker { lat: 43.8558994, lng: -79.4439996 },
-Marker { lat: 43.3997124, lng: -79.7909949 },
-Marker { lat: 43.9642973, lng: -79.2443429 },
-Marker { lat: 43.955457, lng: -79.252758 },
-Marker { lat: 43.780252, lng: -79.448149 },
-Marker { lat: 43.7722925, lng: -79.4122663 },
-Marker { lat: 43.659454, lng: -79.382587 },
-Marker { lat: 43.395228, lng: -79.817089 },
-Marker { lat: 43.8979201, lng: -79.02049 },
-Marker { lat: 43.911496, lng: -78.9334473 },
-Marker { lat: 43.724363, lng: -79.251666 },
-Marker { lat: 43.8586207, lng: -79.2978153 },
-Marker { lat: 43.8703233, lng: -79.2643744 },
-Marker { lat: 43.6400653, lng: -79.3784948 },
-Marker { lat: 43.6611357, lng: -79.3856052 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.7918325, lng: -79.1699236 },
-Marker { lat: 43.7690784, lng: -79.3694836 },
-Marker { lat: 43.549181, lng: -79.716731 },
-Marker { lat: 43.7365801, lng: -79.2578377 },
-Marker { lat: 43.8809693, lng: -79.4691862 },
-Marker { lat: 43.7424112, lng: -79.3431188 },
-Marker { lat: 43.9082913, lng: -79.4684995 },
-Marker { lat: 43.646331, lng: -79.41983 },
-Marker { lat: 43.7729216, lng: -79.8314509 },
-Marker { lat: 43.8025897, lng: -79.4198004 },
-Marker { lat: 43.6958772, lng: -79.8388981 },
-Marker { lat: 43.5126284, lng: -79.8454482 },
-Marker { lat: 43.6968967, lng: -79.7836114 },
-Marker { lat: 43.7088414, lng: -79.8223265 },
-Marker { lat: 43.8416179, lng: -79.5235865 },
-Marker { lat: 43.7845248, lng: -79.3138382 },
-Marker { lat: 43.681284, lng: -79.4102352 },
-Marker { lat: 43.939112, lng: -78.8863219 },
-Marker { lat: 43.623534, lng: -79.4801 },
-Marker { lat: 43.4703293, lng: -79.7127162 },
-Marker { lat: 43.7045407, lng: -79.7639288 },
-Marker { lat: 43.6939553, lng: -79.7918072 },
-Marker { lat: 43.6747703, lng: -79.3976829 },
-Marker { lat: 43.6593521, lng: -79.3856503 },
-Marker { lat: 43.647627, lng: -79.390408 },
-Marker { lat: 43.7163917, lng: -79.3757041 },
-Marker { lat: 43.7831665, lng: -79.4292269 },
-Marker { lat: 43.7850714, lng: -79.3973383 },
-Marker { lat: 43.663211, lng: -79.377803 },
-Marker { lat: 43.8876294, lng: -79.408964 },
-Marker { lat: 43.9831849, lng: -79.4660731 },
-Marker { lat: 43.6810466, lng: -79.4475371 },
-Marker { lat: 43.706419, lng: -79.832634 },
-Marker { lat: 43.67572, lng: -79.558851 },
-Marker { lat: 43.7038668, lng: -79.8456071 },
-Marker { lat: 43.5151895, lng: -79.8601993 },
-Marker { lat: 43.68387, lng: -79.8001621 },
-Marker { lat: 43.690254, lng: -79.760604 },
-Marker { lat: 43.8506913, lng: -79.4300025 },
-Marker { lat: 43.723452, lng: -79.8245372 },
-Marker { lat: 43.362431, lng: -79.8344751 },
-Marker { lat: 43.67032, lng: -79.394433 },
-Marker { lat: 43.74565, lng: -79.33818 },
-Marker { lat: 43.6425128, lng: -79.3813182 },
-Marker { lat: 43.7602436, lng: -79.4083424 },
-Marker { lat: 43.479218, lng: -79.6290854 },
-Marker { lat: 43.8374782, lng: -79.2847325 },
-Marker { lat: 43.7455539, lng: -79.563202 },
-Marker { lat: 43.8947709, lng: -79.2928522 },
-Marker { lat: 43.601038, lng: -79.709741 },
-Marker { lat: 43.6179737, lng: -79.588694 },
-Marker { lat: 43.680803, lng: -79.842706 },
-Marker { lat: 43.630665, lng: -79.7796712 },
-Marker { lat: 43.7373696, lng: -79.7471973 },
-Marker { lat: 43.3940387, lng: -79.8278629 },
-Marker { lat: 43.4394815, lng: -79.7511436 },
-Marker { lat: 43.5281277, lng: -79.6561727 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.7176283, lng: -79.4224757 },
-Marker { lat: 43.7873301, lng: -79.4553555 },
-Marker { lat: 43.6700844, lng: -79.3805554 },
-Marker { lat: 43.6750288, lng: -79.7608946 },
-Marker { lat: 43.393665, lng: -79.8299758 },
-Marker { lat: 43.8434543, lng: -79.0996706 },
-Marker { lat: 43.7032374, lng: -79.2938492 },
-Marker { lat: 43.8276274, lng: -79.0913745 },
-Marker { lat: 43.8010154, lng: -79.5892983 },
-Marker { lat: 43.9457948, lng: -79.5341949 },
-Marker { lat: 43.778294, lng: -79.575555 },
-Marker { lat: 43.968469, lng: -79.3313993 },
-Marker { lat: 43.8540874, lng: -79.4203962 },
-Marker { lat: 43.6940301, lng: -79.3132295 },
-Marker { lat: 43.5754061, lng: -79.7288301 },
-Marker { lat: 43.411804, lng: -79.802099 },
-Marker { lat: 43.6723825, lng: -79.7813585 },
-Marker { lat: 43.9559523, lng: -79.2634091 },
-Marker { lat: 43.6873738, lng: -79.8206227 },
-Marker { lat: 43.6835879, lng: -79.3566739 },
-Marker { lat: 43.6968373, lng: -79.293353 },
-Marker { lat: 43.97696, lng: -79.6040162 },
-Marker { lat: 43.7758101, lng: -79.3728376 },
-Marker { lat: 43.9680766, lng: -79.276052 },
-Marker { lat: 43.73283, lng: -79.272138 },
-Marker { lat: 43.8825991, lng: -79.3400665 },
-Marker { lat: 43.75366, lng: -79.448626 },
-Marker { lat: 43.779718, lng: -79.342657 },
-Marker { lat: 43.7675491, lng: -79.4962812 },
-Marker { lat: 43.756947, lng: -79.4064994 },
-Marker { lat: 43.884681, lng: -79.309914 },
-Marker { lat: 43.86695, lng: -79.446545 },
-Marker { lat: 43.4708233, lng: -79.6860852 },
-Marker { lat: 43.493922, lng: -79.705752 },
-Marker { lat: 43.6405034, lng: -79.4268581 },
-Marker { lat: 43.9444359, lng: -79.4780989 },
-Marker { lat: 43.9326769, lng: -79.477561 },
-Marker { lat: 43.4071558, lng: -79.7229256 },
-Marker { lat: 43.7252301, lng: -79.497878 },
-Marker { lat: 43.859613, lng: -79.86768 },
-Marker { lat: 43.6901474, lng: -79.450039 },
-Marker { lat: 43.7783428, lng: -79.4159581 },
-Marker { lat: 43.8784854, lng: -79.4043771 },
-Marker { lat: 43.8325877, lng: -79.5758284 },
-Marker { lat: 43.760906, lng: -79.4090028 },
-Marker { lat: 43.8883356, lng: -79.0136495 },
-Marker { lat: 43.959073, lng: -78.890261 },
-Marker { lat: 43.7879555, lng: -79.3868284 },
-Marker { lat: 43.9482735, lng: -79.4462354 },
-Marker { lat: 43.7358093, lng: -79.4783101 },
-Marker { lat: 43.7665801, lng: -79.605588 },
-Marker { lat: 43.631268, lng: -79.428567 },
-Marker { lat: 43.6513375, lng: -79.7695661 },
-Marker { lat: 43.6889375, lng: -79.7636694 },
-Marker { lat: 43.7123245, lng: -79.7174931 },
-Marker { lat: 43.5819207, lng: -79.7811414 },
-Marker { lat: 43.678272, lng: -79.843341 },
-Marker { lat: 43.6999458, lng: -79.8233358 },
-Marker { lat: 43.6947082, lng: -79.4441125 },
-Marker { lat: 43.8689705, lng: -79.4142155 },
-Marker { lat: 43.7252546, lng: -79.697615 },
-Marker { lat: 43.6704872, lng: -79.3845738 },
-Marker { lat: 43.773486, lng: -79.4330038 },
-Marker { lat: 43.6959554, lng: -79.3957179 },
-Marker { lat: 43.8719804, lng: -79.4209522 },
-Marker { lat: 43.87024, lng: -79.052365 },
-Marker { lat: 43.6429141, lng: -79.3806469 },
-Marker { lat: 43.3791554, lng: -79.7360275 },
-Marker { lat: 43.8449115, lng: -79.396375 },
-Marker { lat: 43.9602917, lng: -78.8988522 },
-Marker { lat: 43.669593, lng: -79.38058 },
-Marker { lat: 43.7663749, lng: -79.3905728 },
-Marker { lat: 43.7803294, lng: -79.3234667 },
-Marker { lat: 43.66955, lng: -79.383776 },
-Marker { lat: 43.7091888, lng: -79.823042 },
-Marker { lat: 43.564695, lng: -79.721629 },
-Marker { lat: 43.4179508, lng: -79.7064211 },
-Marker { lat: 43.7824325, lng: -79.6080814 },
-Marker { lat: 43.8952573, lng: -79.4019598 },
-Marker { lat: 43.642791, lng: -79.407195 },
-Marker { lat: 43.8998056, lng: -78.9668494 },
-Marker { lat: 43.9029701, lng: -79.3718302 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.7349349, lng: -79.2655749 },
-Marker { lat: 43.7070826, lng: -79.3977712 },
-Marker { lat: 43.6454752, lng: -79.3923183 },
-Marker { lat: 43.77807, lng: -79.4158254 },
-Marker { lat: 43.8956927, lng: -79.2445643 },
-Marker { lat: 43.8462111, lng: -79.4185051 },
-Marker { lat: 43.7580166, lng: -79.4642609 },
-Marker { lat: 43.7028201, lng: -79.4159264 },
-Marker { lat: 43.686709, lng: -79.399258 },
-Marker { lat: 43.7658385, lng: -79.4383835 },
-Marker { lat: 43.66955, lng: -79.383776 },
-Marker { lat: 43.8147022, lng: -79.3264457 },
-Marker { lat: 43.668578, lng: -79.384479 },
-Marker { lat: 43.700445, lng: -79.397789 },
-Marker { lat: 43.9066525, lng: -79.4722524 },
-Marker { lat: 43.537179, lng: -79.664968 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.7316086, lng: -79.5323051 },
-Marker { lat: 43.5443675, lng: -79.7334146 },
-Marker { lat: 43.7593366, lng: -79.3975501 },
-Marker { lat: 43.6620369, lng: -79.3865221 },
-Marker { lat: 43.9585629, lng: -78.9001504 },
-Marker { lat: 43.659381, lng: -79.385626 },
-Marker { lat: 43.8482277, lng: -79.5285576 },
-Marker { lat: 43.7293504, lng: -79.4784052 },
-Marker { lat: 43.8417165, lng: -79.4700041 },
-Marker { lat: 43.871669, lng: -79.050886 },
-Marker { lat: 43.9546252, lng: -78.9112584 },
-Marker { lat: 43.8649249, lng: -79.3126365 },
-Marker { lat: 43.8240436, lng: -79.4224264 },
-Marker { lat: 43.4793159, lng: -79.7257402 },
-Marker { lat: 43.6526759, lng: -79.5692126 },
-Marker { lat: 43.779746, lng: -79.4181433 },
-Marker { lat: 43.7148711, lng: -79.356621 },
-Marker { lat: 43.7422755, lng: -79.2395952 },
-Marker { lat: 43.8521806, lng: -79.3373192 },
-Marker { lat: 43.758023, lng: -79.420132 },
-Marker { lat: 43.6553047, lng: -79.375328 },
-Marker { lat: 43.6396262, lng: -79.4157955 },
-Marker { lat: 43.640149, lng: -79.3924625 },
-Marker { lat: 43.7759744, lng: -79.4225106 },
-Marker { lat: 43.7143591, lng: -79.3531865 },
-Marker { lat: 43.6630187, lng: -79.3863345 },
-Marker { lat: 43.7694392, lng: -79.4160861 },
-Marker { lat: 43.8869447, lng: -78.9652911 },
-Marker { lat: 43.773249, lng: -79.255273 },
-Marker { lat: 43.6614221, lng: -79.5803607 },
-Marker { lat: 43.553457, lng: -79.7492508 },
-Marker { lat: 43.5847029, lng: -79.6415724 },
-Marker { lat: 43.5872308, lng: -79.639211 },
-Marker { lat: 43.57932, lng: -79.626292 },
-Marker { lat: 43.7948843, lng: -79.3520093 },
-Marker { lat: 43.7673834, lng: -79.4033785 },
-Marker { lat: 43.787247, lng: -79.461929 },
-Marker { lat: 43.5527595, lng: -79.7250155 },
-Marker { lat: 43.8292472, lng: -79.5678278 },
-Marker { lat: 43.8576326, lng: -79.2230188 },
-Marker { lat: 43.5791873, lng: -79.5931746 },
-Marker { lat: 43.8441343, lng: -79.5724819 },
-Marker { lat: 43.8599697, lng: -79.4900572 },
-Marker { lat: 43.781091, lng: -79.410538 },
-Marker { lat: 43.6994678, lng: -79.5453106 },
-Marker { lat: 43.3718156, lng: -79.7639519 },
-Marker { lat: 43.4863803, lng: -79.8633229 },
-Marker { lat: 43.5053919, lng: -79.8419063 },
-Marker { lat: 43.7801104, lng: -79.7503467 },
-Marker { lat: 43.739889, lng: -79.511179 },
-Marker { lat: 43.643104, lng: -79.939443 },
-Marker { lat: 43.8340048, lng: -79.6576094 },
-Marker { lat: 43.7047605, lng: -79.3268787 },
-Marker { lat: 43.6488273, lng: -79.3780964 },
-Marker { lat: 43.8624882, lng: -79.0068788 },
-Marker { lat: 43.5291727, lng: -79.8585377 },
-Marker { lat: 43.782705, lng: -79.581291 },
-Marker { lat: 43.6596471, lng: -79.5498271 },
-Marker { lat: 43.6505177, lng: -79.4981161 },
-Marker { lat: 43.7321743, lng: -79.6002482 },
-Marker { lat: 43.870349, lng: -79.744298 },
-Marker { lat: 43.4852192, lng: -79.7241884 },
-Marker { lat: 43.6405538, lng: -79.74498 },
-Marker { lat: 43.7714257, lng: -79.373117 },
-Marker { lat: 43.3937117, lng: -79.7875381 },
-Marker { lat: 43.636057, lng: -79.470781 },
-Marker { lat: 43.6962745, lng: -79.2927586 },
-Marker { lat: 43.868922, lng: -79.052533 },
-Marker { lat: 43.850094, lng: -79.323495 },
-Marker { lat: 43.8359964, lng: -79.4639194 },
-Marker { lat: 43.766764, lng: -79.385993 },
-Marker { lat: 43.705293, lng: -79.3972641 },
-Marker { lat: 43.832915, lng: -79.437761 },
-Marker { lat: 43.8011945, lng: -79.4592035 },
-Marker { lat: 43.8309725, lng: -79.4743172 },
-Marker { lat: 43.640594, lng: -79.559733 },
-Marker { lat: 43.6398765, lng: -79.4167372 },
-Marker { lat: 43.581626, lng: -79.648167 },
-Marker { lat: 43.584219, lng: -79.7938512 },
-Marker { lat: 43.96666, lng: -79.2346173 },
-Marker { lat: 43.8950999, lng: -79.2983645 },
-Marker { lat: 43.6402683, lng: -79.3960141 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.8266225, lng: -79.4660207 },
-Marker { lat: 43.877595, lng: -79.2770486 },
-Marker { lat: 43.7083196, lng: -79.3926249 },
-Marker { lat: 43.7686168, lng: -79.3880662 },
-Marker { lat: 43.6219125, lng: -79.7276527 },
-Marker { lat: 43.4640305, lng: -79.6898528 },
-Marker { lat: 43.6382499, lng: -79.5362492 },
-Marker { lat: 43.581187, lng: -79.647617 },
-Marker { lat: 43.878074, lng: -79.031762 },
-Marker { lat: 43.5972157, lng: -79.5244404 },
-Marker { lat: 43.742602, lng: -79.7442982 },
-Marker { lat: 43.9151111, lng: -79.4365652 },
-Marker { lat: 43.972842, lng: -79.2392885 },
-Marker { lat: 43.629505, lng: -79.726234 },
-Marker { lat: 43.764029, lng: -79.407042 },
-Marker { lat: 43.6964558, lng: -79.3933434 },
-Marker { lat: 43.6477029, lng: -79.405334 },
-Marker { lat: 43.5779781, lng: -79.6364035 },
-Marker { lat: 43.640783, lng: -79.40121 },
-Marker { lat: 43.6959032, lng: -79.7563091 },
-Marker { lat: 43.8664336, lng: -79.4355835 },
-Marker { lat: 43.7316353, lng: -79.3978768 },
-Marker { lat: 43.6580162, lng: -79.3583966 },
-Marker { lat: 43.782209, lng: -79.3911018 },
-Marker { lat: 43.771566, lng: -79.412099 },
-Marker { lat: 43.734202, lng: -79.8094946 },
-Marker { lat: 43.6694723, lng: -79.3874877 },
-Marker { lat: 43.639918, lng: -79.919837 },
-Marker { lat: 43.6852453, lng: -79.7618555 },
-Marker { lat: 43.8392453, lng: -79.4686811 },
-Marker { lat: 43.8966549, lng: -79.4591716 },
-Marker { lat: 43.4032789, lng: -79.8195142 },
-Marker { lat: 43.4885327, lng: -79.8764272 },
-Marker { lat: 43.970267, lng: -79.263706 },
-Marker { lat: 43.700012, lng: -79.8585512 },
-Marker { lat: 43.546487, lng: -79.685416 },
-Marker { lat: 43.375577, lng: -79.759334 },
-Marker { lat: 43.7069544, lng: -79.395016 },
-Marker { lat: 43.8890654, lng: -79.3722457 },
-Marker { lat: 43.513758, lng: -79.832957 },
-Marker { lat: 43.5705345, lng: -79.6722149 },
-Marker { lat: 43.660137, lng: -79.385869 },
-Marker { lat: 43.856011, lng: -79.044626 },
-Marker { lat: 43.7261365, lng: -79.2643908 },
-Marker { lat: 43.8113804, lng: -79.2260421 },
-Marker { lat: 43.5838361, lng: -79.7246217 },
-Marker { lat: 43.7975676, lng: -79.3198497 },
-Marker { lat: 43.4829155, lng: -79.6873235 },
-Marker { lat: 43.5683342, lng: -79.6889292 },
-Marker { lat: 43.6747527, lng: -79.8275434 },
-Marker { lat: 43.7563401, lng: -79.4056932 },
-Marker { lat: 43.9722644, lng: -79.1262443 },
-Marker { lat: 43.9012996, lng: -79.2476513 },
-Marker { lat: 43.6476028, lng: -79.39076 },
-Marker { lat: 43.8330564, lng: -79.5324085 },
-Marker { lat: 43.5651865, lng: -79.7328736 },
-Marker { lat: 43.642311, lng: -79.38123 },
-Marker { lat: 43.7598013, lng: -79.1757221 },
-Marker { lat: 43.7038342, lng: -79.8172528 },
-Marker { lat: 43.7021103, lng: -79.2620519 },
-Marker { lat: 43.642626, lng: -79.415251 },
-Marker { lat: 43.6575162, lng: -79.3767709 },
-Marker { lat: 43.8575037, lng: -79.4323048 },
-Marker { lat: 43.6968373, lng: -79.293353 },
-Marker { lat: 43.8854929, lng: -79.4011961 },
-Marker { lat: 43.8826269, lng: -79.4654121 },
-Marker { lat: 43.9711, lng: -79.2461 },
-Marker { lat: 43.707592, lng: -79.8381496 },
-Marker { lat: 43.603235, lng: -79.801835 },
-Marker { lat: 43.6490838, lng: -79.392082 },
-Marker { lat: 43.624595, lng: -79.480119 },
-Marker { lat: 43.78, lng: -79.3808 },
-Marker { lat: 43.700445, lng: -79.397789 },
-Marker { lat: 43.4794569, lng: -79.7443958 },
-Marker { lat: 43.4805615, lng: -79.7385115 },
-Marker { lat: 43.8215005, lng: -79.5742024 },
-Marker { lat: 43.6938613, lng: -79.6934439 },
-Marker { lat: 43.8903975, lng: -79.3087531 },
-Marker { lat: 43.8340223, lng: -79.469114 },
-Marker { lat: 43.956234, lng: -78.9012802 },
-Marker { lat: 43.8592708, lng: -79.4568502 },
-Marker { lat: 43.6966073, lng: -79.2942117 },
-Marker { lat: 43.859995, lng: -79.4631718 },
-Marker { lat: 43.502473, lng: -79.875754 },
-Marker { lat: 43.7323762, lng: -79.5369779 },
-Marker { lat: 43.4204897, lng: -79.7112625 },
-Marker { lat: 43.6296116, lng: -79.4980148 },
-Marker { lat: 43.8434822, lng: -79.2521717 },
-Marker { lat: 43.7924394, lng: -79.3357717 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.708008, lng: -79.400785 },
-Marker { lat: 43.8078001, lng: -79.2653953 },
-Marker { lat: 43.7023302, lng: -79.5313567 },
-Marker { lat: 43.9043792, lng: -79.2718966 },
-Marker { lat: 43.6747343, lng: -79.8133482 },
-Marker { lat: 43.8884369, lng: -79.3012059 },
-Marker { lat: 43.6468108, lng: -79.3853366 },
-Marker { lat: 43.6870783, lng: -79.847612 },
-Marker { lat: 43.6514, lng: -79.3826 },
-Marker { lat: 43.6397347, lng: -79.3920867 },
-Marker { lat: 43.7708, lng: -79.4065 },
-Marker { lat: 43.8824691, lng: -79.4231581 },
-Marker { lat: 43.4683158, lng: -79.7233901 },
-Marker { lat: 43.7755305, lng: -79.4152516 },
-Marker { lat: 43.594494, lng: -79.635187 },
-Marker { lat: 43.7684033, lng: -79.1820615 },
-Marker { lat: 43.641596, lng: -79.423443 },
-Marker { lat: 43.655305, lng: -79.3537502 },
-Marker { lat: 43.6893201, lng: -79.5049734 },
-Marker { lat: 43.7951, lng: -79.2664 },
-Marker { lat: 43.5393637, lng: -79.6023338 },
-Marker { lat: 43.4094721, lng: -79.7114656 },
-Marker { lat: 43.8451061, lng: -79.2721591 },
-Marker { lat: 43.764178, lng: -79.404851 },
-Marker { lat: 43.7073389, lng: -79.394278 },
-Marker { lat: 43.5555758, lng: -79.577613 },
-Marker { lat: 43.5737139, lng: -79.6008183 },
-Marker { lat: 43.7553394, lng: -79.8179552 },
-Marker { lat: 43.87572, lng: -79.8605912 },
-Marker { lat: 43.662306, lng: -79.7288833 },
-Marker { lat: 43.889014, lng: -79.420832 },
-Marker { lat: 43.7406122, lng: -79.5763196 },
-Marker { lat: 43.6591336, lng: -79.5787458 },
-Marker { lat: 43.8423682, lng: -79.468242 },
-Marker { lat: 43.8468363, lng: -79.4551523 },
-Marker { lat: 43.8551515, lng: -79.344036 },
-Marker { lat: 43.899161, lng: -79.736216 },
-Marker { lat: 43.7220508, lng: -79.7154215 },
-Marker { lat: 43.766699, lng: -79.5132295 },
-Marker { lat: 43.4682014, lng: -79.7483163 },
-Marker { lat: 43.7421853, lng: -79.7541566 },
-Marker { lat: 43.4040571, lng: -79.8226002 },
-Marker { lat: 43.6504617, lng: -79.5686024 },
-Marker { lat: 43.4999654, lng: -79.7055013 },
-Marker { lat: 43.6079464, lng: -79.5672021 },
-Marker { lat: 43.6361579, lng: -79.4706161 },
-Marker { lat: 43.4334059, lng: -79.7289973 },
-Marker { lat: 43.7678164, lng: -79.7566688 },
-Marker { lat: 43.7509718, lng: -79.5628661 },
-Marker { lat: 43.8778935, lng: -79.4227004 },
-Marker { lat: 43.7999286, lng: -79.7344044 },
-Marker { lat: 43.9758657, lng: -79.2382465 },
-Marker { lat: 43.9529359, lng: -78.9469645 },
-Marker { lat: 43.8416228, lng: -79.5081923 },
-Marker { lat: 43.8799103, lng: -79.4051925 },
-Marker { lat: 43.854454, lng: -79.517075 },
-Marker { lat: 43.8738455, lng: -79.4279365 },
-Marker { lat: 43.636293, lng: -79.470463 },
-Marker { lat: 43.8641623, lng: -79.3215475 },
-Marker { lat: 43.8633159, lng: -79.2410409 },
-Marker { lat: 43.6016906, lng: -79.6473268 },
-Marker { lat: 43.6708764, lng: -79.4490892 },
-Marker { lat: 43.5648737, lng: -79.5816165 },
-Marker { lat: 43.6921805, lng: -79.8202193 },
-Marker { lat: 43.677978, lng: -79.802663 },
-Marker { lat: 43.5188509, lng: -79.8310344 },
-Marker { lat: 43.7006162, lng: -79.5268916 },
-Marker { lat: 43.6732356, lng: -79.815278 },
-Marker { lat: 43.6222561, lng: -79.4825565 },
-Marker { lat: 43.7373436, lng: -79.4920474 },
-Marker { lat: 43.4304479, lng: -79.7490979 },
-Marker { lat: 43.6399816, lng: -79.5845843 },
-Marker { lat: 43.7082794, lng: -79.8309586 },
-Marker { lat: 43.649737, lng: -79.930994 },
-Marker { lat: 43.6765748, lng: -79.7735215 },
-Marker { lat: 43.3584533, lng: -79.8136703 },
-Marker { lat: 43.7471825, lng: -79.3224755 },
-Marker { lat: 43.7880623, lng: -79.368497 },
-Marker { lat: 43.3640022, lng: -79.7488219 },
-Marker { lat: 43.7713438, lng: -79.418104 },
-Marker { lat: 43.646942, lng: -79.875748 },
-Marker { lat: 43.6788696, lng: -79.8082253 },
-Marker { lat: 43.6512214, lng: -79.3671167 },
-Marker { lat: 43.7307234, lng: -79.3276511 },
-Marker { lat: 43.371634, lng: -79.8362876 },
-Marker { lat: 43.7084796, lng: -79.3975213 },
-Marker { lat: 43.7123035, lng: -79.252199 },
-Marker { lat: 43.8813664, lng: -79.0285353 },
-Marker { lat: 43.7300903, lng: -79.3322682 },
-Marker { lat: 43.641767, lng: -79.381458 },
-Marker { lat: 43.841836, lng: -79.116954 },
-Marker { lat: 43.6490534, lng: -79.3861546 },
-Marker { lat: 43.6423906, lng: -79.4247385 },
-Marker { lat: 43.6478115, lng: -79.4385888 },
-Marker { lat: 43.7549431, lng: -79.4216053 },
-Marker { lat: 43.7665037, lng: -79.4110937 },
-Marker { lat: 43.647524, lng: -79.387335 },
-Marker { lat: 43.7340774, lng: -79.3547664 },
-Marker { lat: 43.6568586, lng: -79.3836642 },
-Marker { lat: 43.7320329, lng: -79.4391341 },
-Marker { lat: 43.7755305, lng: -79.4152516 },
-Marker { lat: 43.9264127, lng: -79.064344 },
-Marker { lat: 43.6596665, lng: -79.3859262 },
-Marker { lat: 43.6363712, lng: -79.4027602 },
-Marker { lat: 43.7937977, lng: -79.2350364 },
-Marker { lat: 43.7155526, lng: -79.399959 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.649696, lng: -79.398202 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.8847799, lng: -79.0231628 },
-Marker { lat: 43.775424, lng: -79.337482 },
-Marker { lat: 43.6403228, lng: -79.3917567 },
-Marker { lat: 43.8248015, lng: -79.1424542 },
-Marker { lat: 43.7792734, lng: -79.4493486 },
-Marker { lat: 43.9538326, lng: -79.4800817 },
-Marker { lat: 43.8747499, lng: -79.4739385 },
-Marker { lat: 43.8352463, lng: -79.5432834 },
-Marker { lat: 43.6411375, lng: -79.4007447 },
-Marker { lat: 43.660905, lng: -79.378806 },
-Marker { lat: 43.8433794, lng: -79.2568775 },
-Marker { lat: 43.555075, lng: -79.583315 },
-Marker { lat: 43.6661731, lng: -79.4405253 },
-Marker { lat: 43.8914749, lng: -79.2750268 },
-Marker { lat: 43.636306, lng: -79.466089 },
-Marker { lat: 43.8899503, lng: -79.4568958 },
-Marker { lat: 43.7511738, lng: -79.7523956 },
-Marker { lat: 43.589705, lng: -79.633017 },
-Marker { lat: 43.5887481, lng: -79.6517696 },
-Marker { lat: 43.7705026, lng: -79.6631209 },
-Marker { lat: 43.5464255, lng: -79.6851862 },
-Marker { lat: 43.6654182, lng: -79.5546415 },
-Marker { lat: 43.5962386, lng: -79.6089604 },
-Marker { lat: 43.5653466, lng: -79.6518856 },
-Marker { lat: 43.570843, lng: -79.630158 },
-Marker { lat: 43.5210803, lng: -79.8538453 },
-Marker { lat: 43.7793201, lng: -79.2814437 },
-Marker { lat: 43.60596, lng: -79.655123 },
-Marker { lat: 43.6609604, lng: -79.4954562 },
-Marker { lat: 43.5943985, lng: -79.6344007 },
-Marker { lat: 43.6402683, lng: -79.3960141 },
-Marker { lat: 43.7003517, lng: -79.8600383 },
-Marker { lat: 43.9239663, lng: -79.4258359 },
-Marker { lat: 43.9086469, lng: -79.4564547 },
-Marker { lat: 43.8719421, lng: -79.4268026 },
-Marker { lat: 43.7023688, lng: -79.4158524 },
-Marker { lat: 43.6525613, lng: -79.8055957 },
-Marker { lat: 43.827088, lng: -79.09158 },
-Marker { lat: 43.6298722, lng: -79.874685 },
-Marker { lat: 43.69555, lng: -79.3387329 },
-Marker { lat: 43.7590846, lng: -79.7490519 },
-Marker { lat: 43.623426, lng: -79.4789788 },
-Marker { lat: 43.5948353, lng: -79.6349645 },
-Marker { lat: 43.832463, lng: -78.973512 },
-Marker { lat: 43.5741106, lng: -79.5637888 },
-Marker { lat: 43.645621, lng: -79.389497 },
-Marker { lat: 43.846554, lng: -79.427873 },
-Marker { lat: 43.760402, lng: -79.40901 },
-Marker { lat: 43.8297978, lng: -79.5486644 },
-Marker { lat: 43.653558, lng: -79.36882 },
-Marker { lat: 43.743, lng: -79.3577 },
-Marker { lat: 43.5049792, lng: -79.8882163 },
-Marker { lat: 43.8429399, lng: -79.5495591 },
-Marker { lat: 43.4285, lng: -79.7445 },
-Marker { lat: 43.9112263, lng: -79.465549 },
-Marker { lat: 43.6850824, lng: -79.8484728 },
-Marker { lat: 43.4822893, lng: -79.851732 },
-Marker { lat: 43.9830701, lng: -79.4510583 },
-Marker { lat: 43.6331309, lng: -79.797321 },
-Marker { lat: 43.4262063, lng: -79.7856865 },
-Marker { lat: 43.3575904, lng: -79.8133894 },
-Marker { lat: 43.8722353, lng: -79.2756847 },
-Marker { lat: 43.6617328, lng: -79.4938161 },
-Marker { lat: 43.6590147, lng: -79.7774123 },
-Marker { lat: 43.5382123, lng: -79.7315532 },
-Marker { lat: 43.904739, lng: -78.9513901 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.9574808, lng: -78.9129228 },
-Marker { lat: 43.7054055, lng: -79.8302837 },
-Marker { lat: 43.8990421, lng: -79.2869781 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.7612374, lng: -79.408937 },
-Marker { lat: 43.6426507, lng: -79.3773286 },
-Marker { lat: 43.622729, lng: -79.48319 },
-Marker { lat: 43.433421, lng: -79.745617 },
-Marker { lat: 43.7080568, lng: -79.400489 },
-Marker { lat: 43.861428, lng: -79.436473 },
-Marker { lat: 43.6020769, lng: -79.6046599 },
-Marker { lat: 43.7458891, lng: -79.5158122 },
-Marker { lat: 43.908919, lng: -78.9124677 },
-Marker { lat: 43.5171436, lng: -79.8355327 },
-Marker { lat: 43.4656139, lng: -79.6708171 },
-Marker { lat: 43.770974, lng: -79.2030444 },
-Marker { lat: 43.7577362, lng: -79.7420811 },
-Marker { lat: 43.486708, lng: -79.78659 },
-Marker { lat: 43.653198, lng: -79.7893436 },
-Marker { lat: 43.5236115, lng: -79.8413139 },
-Marker { lat: 43.7497698, lng: -79.5693407 },
-Marker { lat: 43.8096514, lng: -79.385693 },
-Marker { lat: 43.3947311, lng: -79.8143593 },
-Marker { lat: 43.785909, lng: -79.2898574 },
-Marker { lat: 43.7535401, lng: -79.3690849 },
-Marker { lat: 43.8449515, lng: -79.4887184 },
-Marker { lat: 43.7900917, lng: -79.4415641 },
-Marker { lat: 43.7657785, lng: -79.4100647 },
-Marker { lat: 43.7900246, lng: -79.25466 },
-Marker { lat: 43.9317277, lng: -79.5329277 },
-Marker { lat: 43.472414, lng: -79.723402 },
-Marker { lat: 43.6804, lng: -79.7693 },
-Marker { lat: 43.8025897, lng: -79.4198004 },
-Marker { lat: 43.586289, lng: -79.647364 },
-Marker { lat: 43.6723158, lng: -79.3888155 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.757362, lng: -79.178918 },
-Marker { lat: 43.7597337, lng: -79.3396502 },
-Marker { lat: 43.6839, lng: -79.4824 },
-Marker { lat: 43.963799, lng: -78.977484 },
-Marker { lat: 43.7986429, lng: -79.3996222 },
-Marker { lat: 43.6516984, lng: -79.4174282 },
-Marker { lat: 43.606496, lng: -79.655651 },
-Marker { lat: 43.5930469, lng: -79.6708884 },
-Marker { lat: 43.3447257, lng: -79.8856937 },
-Marker { lat: 43.8129727, lng: -79.4551834 },
-Marker { lat: 43.5284027, lng: -79.6483933 },
-Marker { lat: 43.6729857, lng: -79.3870289 },
-Marker { lat: 43.773688, lng: -79.255422 },
-Marker { lat: 43.8914094, lng: -79.4289696 },
-Marker { lat: 43.666981, lng: -79.388889 },
-Marker { lat: 43.597134, lng: -79.524327 },
-Marker { lat: 43.8137639, lng: -79.455991 },
-Marker { lat: 43.683596, lng: -79.3952069 },
-Marker { lat: 43.640845, lng: -79.414319 },
-Marker { lat: 43.756136, lng: -79.412072 },
-Marker { lat: 43.9016071, lng: -79.2539065 },
-Marker { lat: 43.6400653, lng: -79.3784948 },
-Marker { lat: 43.8582866, lng: -79.4381617 },
-Marker { lat: 43.4252891, lng: -79.8706763 },
-Marker { lat: 43.62526, lng: -79.477903 },
-Marker { lat: 43.8614279, lng: -79.4364729 },
-Marker { lat: 43.806228, lng: -79.423727 },
-Marker { lat: 43.7795793, lng: -79.4458889 },
-Marker { lat: 43.7772881, lng: -79.170717 },
-Marker { lat: 43.5045077, lng: -79.845356 },
-Marker { lat: 43.878988, lng: -79.4761401 },
-Marker { lat: 43.8775362, lng: -79.4683409 },
-Marker { lat: 43.873644, lng: -79.732893 },
-Marker { lat: 43.7729357, lng: -79.4185008 },
-Marker { lat: 43.7680669, lng: -79.3767938 },
-Marker { lat: 43.6610449, lng: -79.4071207 },
-Marker { lat: 43.3551846, lng: -79.8448913 },
-Marker { lat: 43.6484374, lng: -79.4865948 },
-Marker { lat: 43.750384, lng: -79.4584405 },
-Marker { lat: 43.8038622, lng: -79.4342263 },
-Marker { lat: 43.8680528, lng: -79.5318225 },
-Marker { lat: 43.8943504, lng: -79.0436308 },
-Marker { lat: 43.9309764, lng: -78.8890455 },
-Marker { lat: 43.39785, lng: -79.815879 },
-Marker { lat: 43.5844394, lng: -79.7783752 },
-Marker { lat: 43.757173, lng: -79.40651 },
-Marker { lat: 43.9107468, lng: -78.8893547 },
-Marker { lat: 43.4836979, lng: -79.8387098 },
-Marker { lat: 43.645641, lng: -79.392953 },
-Marker { lat: 43.8065185, lng: -79.1072225 },
-Marker { lat: 43.6398513, lng: -79.5595134 },
-Marker { lat: 43.8687536, lng: -79.2647674 },
-Marker { lat: 43.7465536, lng: -79.4298792 },
-Marker { lat: 43.9664092, lng: -79.2470682 },
-Marker { lat: 43.7263754, lng: -79.5219621 },
-Marker { lat: 43.66593, lng: -79.4548133 },
-Marker { lat: 43.598229, lng: -79.5082098 },
-Marker { lat: 43.7648208, lng: -79.410443 },
-Marker { lat: 43.6871794, lng: -79.5048436 },
-Marker { lat: 43.8579344, lng: -79.4859955 },
-Marker { lat: 43.608816, lng: -79.89559 },
-Marker { lat: 43.431759, lng: -79.7743914 },
-Marker { lat: 43.6466079, lng: -79.4185965 },
-Marker { lat: 43.667768, lng: -79.79891 },
-Marker { lat: 43.9387351, lng: -79.4319861 },
-Marker { lat: 43.610353, lng: -79.551401 },
-Marker { lat: 43.7386, lng: -79.8437 },
-Marker { lat: 43.641523, lng: -79.4240871 },
-Marker { lat: 43.6636287, lng: -79.3864511 },
-Marker { lat: 43.701994, lng: -79.27654 },
-Marker { lat: 43.6809653, lng: -79.3522826 },
-Marker { lat: 43.85195, lng: -79.431773 },
-Marker { lat: 43.5859549, lng: -79.6405988 },
-Marker { lat: 43.8140985, lng: -79.4514793 },
-Marker { lat: 43.945007, lng: -79.4341746 },
-Marker { lat: 43.9204285, lng: -79.4746802 },
-Marker { lat: 43.591508, lng: -79.621852 },
-Marker { lat: 43.6345509, lng: -79.7253625 },
-Marker { lat: 43.76699, lng: -79.383151 },
-Marker { lat: 43.7925765, lng: -79.4154575 },
-Marker { lat: 43.7386, lng: -79.8437 },
-Marker { lat: 43.6946328, lng: -79.8071176 },
-Marker { lat: 43.670587, lng: -79.380609 },
-Marker { lat: 43.6489, lng: -79.392205 },
-Marker { lat: 43.626138, lng: -79.477339 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.7569734, lng: -79.3185723 },
-Marker { lat: 43.4823297, lng: -79.6494776 },
-Marker { lat: 43.5212415, lng: -79.857076 },
-Marker { lat: 43.7717916, lng: -79.6997013 },
-Marker { lat: 43.784334, lng: -79.3874597 },
-Marker { lat: 43.8286737, lng: -79.5614108 },
-Marker { lat: 43.7425966, lng: -79.4290446 },
-Marker { lat: 43.448364, lng: -79.6795964 },
-Marker { lat: 43.546985, lng: -79.607906 },
-Marker { lat: 43.9108969, lng: -78.9193967 },
-Marker { lat: 43.6400202, lng: -79.5714159 },
-Marker { lat: 43.682348, lng: -79.4354161 },
-Marker { lat: 43.595876, lng: -79.534967 },
-Marker { lat: 43.66125, lng: -79.347569 },
-Marker { lat: 43.3530343, lng: -79.7653445 },
-Marker { lat: 43.7945751, lng: -79.556816 },
-Marker { lat: 43.669301, lng: -79.384171 },
-Marker { lat: 43.6100597, lng: -79.533375 },
-Marker { lat: 43.9479087, lng: -79.4785599 },
-Marker { lat: 43.7937025, lng: -79.4163306 },
-Marker { lat: 43.643485, lng: -79.392304 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.7703, lng: -79.413645 },
-Marker { lat: 43.619564, lng: -79.5103162 },
-Marker { lat: 43.6710104, lng: -79.4496401 },
-Marker { lat: 43.7083196, lng: -79.3926249 },
-Marker { lat: 43.8764956, lng: -79.2429927 },
-Marker { lat: 43.646808, lng: -79.402798 },
-Marker { lat: 43.8761061, lng: -79.2652321 },
-Marker { lat: 43.7331798, lng: -79.3347545 },
-Marker { lat: 43.798082, lng: -79.583662 },
-Marker { lat: 43.7699824, lng: -79.4117447 },
-Marker { lat: 43.853996, lng: -79.103147 },
-Marker { lat: 43.8156714, lng: -79.6191709 },
-Marker { lat: 43.880745, lng: -79.312495 },
-Marker { lat: 43.653751, lng: -79.417073 },
-Marker { lat: 43.8713786, lng: -79.2548425 },
-Marker { lat: 43.8584881, lng: -79.4030652 },
-Marker { lat: 43.8818045, lng: -79.467208 },
-Marker { lat: 43.880813, lng: -79.31252 },
-Marker { lat: 43.880947, lng: -79.312573 },
-Marker { lat: 43.708287, lng: -79.391964 },
-Marker { lat: 43.6400003, lng: -79.3788904 },
-Marker { lat: 43.6523555, lng: -79.4089958 },
-Marker { lat: 43.6548441, lng: -79.3846797 },
-Marker { lat: 43.9661737, lng: -78.9735475 },
-Marker { lat: 43.8513311, lng: -79.3257986 },
-Marker { lat: 43.8457818, lng: -79.2613603 },
-Marker { lat: 43.5887481, lng: -79.6517696 },
-Marker { lat: 43.520078, lng: -79.620473 },
-Marker { lat: 43.9353407, lng: -79.555718 },
-Marker { lat: 43.6427017, lng: -79.3933634 },
-Marker { lat: 43.6495, lng: -79.4824 },
-Marker { lat: 43.926, lng: -79.2114 },
-Marker { lat: 43.641785, lng: -79.379006 },
-Marker { lat: 43.699017, lng: -79.490145 },
-Marker { lat: 43.9067621, lng: -78.9349896 },
-Marker { lat: 43.849752, lng: -79.324666 },
-Marker { lat: 43.5689554, lng: -79.6183989 },
-Marker { lat: 43.3516624, lng: -79.7654602 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.913128, lng: -78.907275 },
-Marker { lat: 43.7758348, lng: -79.4132441 },
-Marker { lat: 43.8071917, lng: -79.1039631 },
-Marker { lat: 43.7738219, lng: -79.258949 },
-Marker { lat: 43.7639679, lng: -79.2032065 },
-Marker { lat: 43.6641161, lng: -79.3332082 },
-Marker { lat: 43.6311305, lng: -79.7783346 },
-Marker { lat: 43.647226, lng: -79.3911 },
-Marker { lat: 43.858282, lng: -79.026458 },
-Marker { lat: 43.6463687, lng: -79.5149183 },
-Marker { lat: 43.590496, lng: -79.523664 },
-Marker { lat: 43.840964, lng: -79.118641 },
-Marker { lat: 43.4029139, lng: -79.8249211 },
-Marker { lat: 43.7325735, lng: -79.425911 },
-Marker { lat: 43.9223564, lng: -78.9171252 },
-Marker { lat: 43.5703809, lng: -79.6732952 },
-Marker { lat: 43.631849, lng: -79.886098 },
-Marker { lat: 43.5340357, lng: -79.7005271 },
-Marker { lat: 43.6695584, lng: -79.3846569 },
-Marker { lat: 43.6523306, lng: -79.3688408 },
-Marker { lat: 43.668718, lng: -79.383996 },
-Marker { lat: 43.667978, lng: -79.388548 },
-Marker { lat: 43.773922, lng: -79.345726 },
-Marker { lat: 43.655305, lng: -79.3537502 },
-Marker { lat: 43.6700844, lng: -79.3805554 },
-Marker { lat: 43.698156, lng: -79.445534 },
-Marker { lat: 43.7340901, lng: -79.5351774 },
-Marker { lat: 43.5525988, lng: -79.7124356 },
-Marker { lat: 43.864646, lng: -79.048124 },
-Marker { lat: 43.3947514, lng: -79.8268211 },
-Marker { lat: 43.6443936, lng: -79.363966 },
-Marker { lat: 43.756899, lng: -79.404584 },
-Marker { lat: 43.8534253, lng: -79.564263 },
-Marker { lat: 43.7186134, lng: -79.260883 },
-Marker { lat: 43.875909, lng: -79.4211276 },
-Marker { lat: 43.6425128, lng: -79.3813182 },
-Marker { lat: 43.953099, lng: -78.9661865 },
-Marker { lat: 43.7863082, lng: -79.661911 },
-Marker { lat: 43.665748, lng: -79.332803 },
-Marker { lat: 43.3466698, lng: -79.7793331 },
-Marker { lat: 43.665388, lng: -79.3880211 },
-Marker { lat: 43.722891, lng: -79.457092 },
-Marker { lat: 43.7083406, lng: -79.373056 },
-Marker { lat: 43.708965, lng: -79.392985 },
-Marker { lat: 43.7907822, lng: -79.3561322 },
-Marker { lat: 43.8069104, lng: -79.2666264 },
-Marker { lat: 43.6964903, lng: -79.4439129 },
-Marker { lat: 43.7119582, lng: -79.52918 },
-Marker { lat: 43.6394317, lng: -79.7424706 },
-Marker { lat: 43.401038, lng: -79.79498 },
-Marker { lat: 43.500916, lng: -79.877836 },
-Marker { lat: 43.8605315, lng: -79.4017491 },
-Marker { lat: 43.704753, lng: -79.300544 },
-Marker { lat: 43.6235172, lng: -79.4792877 },
-Marker { lat: 43.724184, lng: -79.402359 },
-Marker { lat: 43.7748575, lng: -79.1400356 },
-Marker { lat: 43.858385, lng: -79.4018108 },
-Marker { lat: 43.7268375, lng: -79.5786529 },
-Marker { lat: 43.6401213, lng: -79.3812266 },
-Marker { lat: 43.581694, lng: -79.7466745 },
-Marker { lat: 43.6866509, lng: -79.3995111 },
-Marker { lat: 43.670547, lng: -79.380581 },
-Marker { lat: 43.6494976, lng: -79.37295 },
-Marker { lat: 43.5478534, lng: -79.7202339 },
-Marker { lat: 43.661725, lng: -79.384128 },
-Marker { lat: 43.6504765, lng: -79.7456266 },
-Marker { lat: 43.6759015, lng: -79.3765325 },
-Marker { lat: 43.649696, lng: -79.398202 },
-Marker { lat: 43.6396436, lng: -79.4151851 },
-Marker { lat: 43.7539686, lng: -79.3835119 },
-Marker { lat: 43.8720493, lng: -79.4797372 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.7080568, lng: -79.400489 },
-Marker { lat: 43.7485837, lng: -79.2043835 },
-Marker { lat: 43.8790142, lng: -79.0286911 },
-Marker { lat: 43.896603, lng: -79.26584 },
-Marker { lat: 43.503783, lng: -79.850138 },
-Marker { lat: 43.7070855, lng: -79.3977452 },
-Marker { lat: 43.740158, lng: -79.827997 },
-Marker { lat: 43.6775805, lng: -79.4059522 },
-Marker { lat: 43.4014683, lng: -79.8211476 },
-Marker { lat: 43.625911, lng: -79.876704 },
-Marker { lat: 43.6754581, lng: -79.7340475 },
-Marker { lat: 43.6840347, lng: -79.3713052 },
-Marker { lat: 43.647627, lng: -79.390408 },
-Marker { lat: 43.7941787, lng: -79.4397985 },
-Marker { lat: 43.84167, lng: -79.395797 },
-Marker { lat: 43.6208228, lng: -79.8845648 },
-Marker { lat: 43.448084, lng: -79.85451 },
-Marker { lat: 43.4716187, lng: -79.735323 },
-Marker { lat: 43.8664747, lng: -79.4706616 },
-Marker { lat: 43.8441949, lng: -79.5492472 },
-Marker { lat: 43.9155625, lng: -78.9284369 },
-Marker { lat: 43.6624074, lng: -79.3858983 },
-Marker { lat: 43.7929561, lng: -79.400697 },
-Marker { lat: 43.7763249, lng: -79.4140326 },
-Marker { lat: 43.7542909, lng: -79.3366742 },
-Marker { lat: 43.591972, lng: -79.672462 },
-Marker { lat: 43.7313686, lng: -79.431222 },
-Marker { lat: 43.8562844, lng: -79.6184732 },
-Marker { lat: 43.6214406, lng: -79.7385888 },
-Marker { lat: 43.655111, lng: -79.375643 },
-Marker { lat: 43.4914247, lng: -79.8546468 },
-Marker { lat: 43.680666, lng: -79.444434 },
-Marker { lat: 43.751467, lng: -79.382273 },
-Marker { lat: 43.5614909, lng: -79.7506756 },
-Marker { lat: 43.382192, lng: -79.880599 },
-Marker { lat: 43.9224803, lng: -79.4681753 },
-Marker { lat: 43.7879703, lng: -79.4522084 },
-Marker { lat: 43.868242, lng: -79.028482 },
-Marker { lat: 43.9781988, lng: -79.4640722 },
-Marker { lat: 43.776323, lng: -79.335927 },
-Marker { lat: 43.973377, lng: -79.056732 },
-Marker { lat: 43.5658724, lng: -79.589856 },
-Marker { lat: 43.8926975, lng: -79.4051076 },
-Marker { lat: 43.663031, lng: -79.751751 },
-Marker { lat: 43.4594016, lng: -79.699177 },
-Marker { lat: 43.6409947, lng: -79.3945703 },
-Marker { lat: 43.7809828, lng: -79.4135506 },
-Marker { lat: 43.6655014, lng: -79.3833327 },
-Marker { lat: 43.6430625, lng: -79.4281337 },
-Marker { lat: 43.4818791, lng: -79.652781 },
-Marker { lat: 43.8583978, lng: -79.4492914 },
-Marker { lat: 43.6214031, lng: -79.4837838 },
-Marker { lat: 43.3556987, lng: -79.8975922 },
-Marker { lat: 43.9025614, lng: -79.4245286 },
-Marker { lat: 43.3540681, lng: -79.755222 },
-Marker { lat: 43.7470818, lng: -79.3660701 },
-Marker { lat: 43.8355998, lng: -79.4396367 },
-Marker { lat: 43.8735598, lng: -79.4614409 },
-Marker { lat: 43.7831107, lng: -79.4151692 },
-Marker { lat: 43.840464, lng: -79.549669 },
-Marker { lat: 43.6704559, lng: -79.5445228 },
-Marker { lat: 43.7284214, lng: -79.5223137 },
-Marker { lat: 43.55036, lng: -79.813638 },
-Marker { lat: 43.7847851, lng: -79.4504942 },
-Marker { lat: 43.623821, lng: -79.478728 },
-Marker { lat: 43.9542984, lng: -78.9481727 },
-Marker { lat: 43.8613389, lng: -79.3549151 },
-Marker { lat: 43.680803, lng: -79.842706 },
-Marker { lat: 43.592325, lng: -79.5393013 },
-Marker { lat: 43.6667116, lng: -79.3176486 },
-Marker { lat: 43.7859726, lng: -79.3202878 },
-Marker { lat: 43.843937, lng: -79.076081 },
-Marker { lat: 43.4353172, lng: -79.7581064 },
-Marker { lat: 43.6689591, lng: -79.4490174 },
-Marker { lat: 43.9435826, lng: -79.4527818 },
-Marker { lat: 43.7867927, lng: -79.4347244 },
-Marker { lat: 43.65352, lng: -79.37377 },
-Marker { lat: 43.6697112, lng: -79.3458364 },
-Marker { lat: 43.741049, lng: -79.415473 },
-Marker { lat: 43.8491073, lng: -79.4189953 },
-Marker { lat: 43.7937018, lng: -79.7464386 },
-Marker { lat: 43.6763643, lng: -79.4580721 },
-Marker { lat: 43.637438, lng: -79.5456688 },
-Marker { lat: 43.7215295, lng: -79.7784049 },
-Marker { lat: 43.859483, lng: -79.034961 },
-Marker { lat: 43.6619999, lng: -79.3439322 },
-Marker { lat: 43.7592531, lng: -79.3959164 },
-Marker { lat: 43.9609, lng: -78.9009 },
-Marker { lat: 43.8584382, lng: -79.5608707 },
-Marker { lat: 43.688745, lng: -79.478495 },
-Marker { lat: 43.9005814, lng: -79.0155178 },
-Marker { lat: 43.6426507, lng: -79.3773286 },
-Marker { lat: 43.6807304, lng: -79.3796001 },
-Marker { lat: 43.6920002, lng: -79.2713458 },
-Marker { lat: 43.8669734, lng: -79.5241901 },
-Marker { lat: 43.7854051, lng: -79.450821 },
-Marker { lat: 43.8508663, lng: -79.4283197 },
-Marker { lat: 43.6805107, lng: -79.4770538 },
-Marker { lat: 43.6800027, lng: -79.3094961 },
-Marker { lat: 43.5985213, lng: -79.5986441 },
-Marker { lat: 43.960834, lng: -78.956486 },
-Marker { lat: 43.598506, lng: -79.527238 },
-Marker { lat: 43.7486709, lng: -79.7320729 },
-Marker { lat: 43.666751, lng: -79.376235 },
-Marker { lat: 43.4748985, lng: -79.7359326 },
-Marker { lat: 43.8070505, lng: -79.4551611 },
-Marker { lat: 43.5584857, lng: -79.7172813 },
-Marker { lat: 43.5923371, lng: -79.6053276 },
-Marker { lat: 43.762714, lng: -79.346424 },
-Marker { lat: 43.858571, lng: -79.029553 },
-Marker { lat: 43.701876, lng: -79.507778 },
-Marker { lat: 43.764178, lng: -79.404851 },
-Marker { lat: 43.7336787, lng: -79.8045485 },
-Marker { lat: 43.902411, lng: -79.2323236 },
-Marker { lat: 43.5892563, lng: -79.5387348 },
-Marker { lat: 43.8847204, lng: -79.4212349 },
-Marker { lat: 43.3672, lng: -79.9339 },
-Marker { lat: 43.780228, lng: -79.418123 },
-Marker { lat: 43.8653622, lng: -79.5308837 },
-Marker { lat: 43.725022, lng: -79.4416507 },
-Marker { lat: 43.646274, lng: -79.3614175 },
-Marker { lat: 43.5811552, lng: -79.6456268 },
-Marker { lat: 43.5816498, lng: -79.6480951 },
-Marker { lat: 43.7422755, lng: -79.6006348 },
-Marker { lat: 43.6266427, lng: -79.5135657 },
-Marker { lat: 43.6952107, lng: -79.5508744 },
-Marker { lat: 43.9006334, lng: -78.9745291 },
-Marker { lat: 43.8775302, lng: -79.4429863 },
-Marker { lat: 43.4438176, lng: -79.7664156 },
-Marker { lat: 43.7817857, lng: -79.4051211 },
-Marker { lat: 43.813894, lng: -79.451429 },
-Marker { lat: 43.442246, lng: -79.725878 },
-Marker { lat: 43.9126837, lng: -78.9510089 },
-Marker { lat: 43.9422374, lng: -79.481775 },
-Marker { lat: 43.8017026, lng: -79.4259662 },
-Marker { lat: 43.8592866, lng: -79.4590204 },
-Marker { lat: 43.819036, lng: -79.6043051 },
-Marker { lat: 43.69523, lng: -79.322187 },
-Marker { lat: 43.714348, lng: -79.353145 },
-Marker { lat: 43.644128, lng: -79.389406 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.7643203, lng: -79.4137303 },
-Marker { lat: 43.6666912, lng: -79.3191628 },
-Marker { lat: 43.6894476, lng: -79.3423994 },
-Marker { lat: 43.8106158, lng: -79.4617992 },
-Marker { lat: 43.899648, lng: -79.735759 },
-Marker { lat: 43.9287372, lng: -79.5322048 },
-Marker { lat: 43.903917, lng: -79.4556081 },
-Marker { lat: 43.7715214, lng: -79.3028597 },
-Marker { lat: 43.808044, lng: -79.5952401 },
-Marker { lat: 43.823986, lng: -79.149938 },
-Marker { lat: 43.7218058, lng: -79.6950724 },
-Marker { lat: 43.8205905, lng: -79.4237985 },
-Marker { lat: 43.8932691, lng: -79.4382532 },
-Marker { lat: 43.6526701, lng: -79.4772091 },
-Marker { lat: 43.7558371, lng: -79.8389427 },
-Marker { lat: 43.652433, lng: -79.38886 },
-Marker { lat: 43.760223, lng: -79.3668163 },
-Marker { lat: 43.7431268, lng: -79.3574578 },
-Marker { lat: 43.654147, lng: -79.390535 },
-Marker { lat: 43.6821093, lng: -79.7367032 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.834009, lng: -79.150049 },
-Marker { lat: 43.668438, lng: -79.317623 },
-Marker { lat: 43.859464, lng: -79.4322987 },
-Marker { lat: 43.678, lng: -79.4875 },
-Marker { lat: 43.6940447, lng: -79.4396089 },
-Marker { lat: 43.5169, lng: -79.8826 },
-Marker { lat: 43.7258151, lng: -79.8164661 },
-Marker { lat: 43.707957, lng: -79.480399 },
-Marker { lat: 43.876441, lng: -79.861587 },
-Marker { lat: 43.495289, lng: -79.8774563 },
-Marker { lat: 43.5621011, lng: -79.7270249 },
-Marker { lat: 43.5874623, lng: -79.6414072 },
-Marker { lat: 43.5809236, lng: -79.6238027 },
-Marker { lat: 43.535449, lng: -79.858287 },
-Marker { lat: 43.7620963, lng: -79.5745593 },
-Marker { lat: 43.7322654, lng: -79.7540586 },
-Marker { lat: 43.4803424, lng: -79.6383155 },
-Marker { lat: 43.6131373, lng: -79.7024812 },
-Marker { lat: 43.6823311, lng: -79.8435177 },
-Marker { lat: 43.9585184, lng: -78.968362 },
-Marker { lat: 43.6504803, lng: -79.7857063 },
-Marker { lat: 43.7071, lng: -79.394138 },
-Marker { lat: 43.8227069, lng: -79.5609044 },
-Marker { lat: 43.5955489, lng: -79.6337354 },
-Marker { lat: 43.7479819, lng: -79.3747461 },
-Marker { lat: 43.4303813, lng: -79.6909989 },
-Marker { lat: 43.8134367, lng: -79.4266247 },
-Marker { lat: 43.669616, lng: -79.362936 },
-Marker { lat: 43.734051, lng: -79.448063 },
-Marker { lat: 43.7899083, lng: -79.4293647 },
-Marker { lat: 43.76972, lng: -79.366921 },
-Marker { lat: 43.633233, lng: -79.5663372 },
-Marker { lat: 43.727875, lng: -79.273962 },
-Marker { lat: 43.9588484, lng: -79.2481 },
-Marker { lat: 43.6503521, lng: -79.3609928 },
-Marker { lat: 43.9651009, lng: -79.8370037 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.668578, lng: -79.384479 },
-Marker { lat: 43.838867, lng: -79.013384 },
-Marker { lat: 43.6728011, lng: -79.2863448 },
-Marker { lat: 43.6374812, lng: -79.4400511 },
-Marker { lat: 43.640566, lng: -79.562739 },
-Marker { lat: 43.623782, lng: -79.488316 },
-Marker { lat: 43.9643628, lng: -78.9404214 },
-Marker { lat: 43.9645731, lng: -78.9698028 },
-Marker { lat: 43.8372371, lng: -79.4750463 },
-Marker { lat: 43.970586, lng: -79.244284 },
-Marker { lat: 43.9609, lng: -78.9009 },
-Marker { lat: 43.858252, lng: -79.433621 },
-Marker { lat: 43.8817118, lng: -79.2563632 },
-Marker { lat: 43.7111087, lng: -79.5199758 },
-Marker { lat: 43.660846, lng: -79.378756 },
-Marker { lat: 43.643543, lng: -79.376026 },
-Marker { lat: 43.7237418, lng: -79.5909711 },
-Marker { lat: 43.854538, lng: -79.085639 },
-Marker { lat: 43.7021095, lng: -79.3613924 },
-Marker { lat: 43.666981, lng: -79.388889 },
-Marker { lat: 43.8243568, lng: -79.425407 },
-Marker { lat: 43.660852, lng: -79.378702 },
-Marker { lat: 43.6047906, lng: -79.6539646 },
-Marker { lat: 43.8822225, lng: -79.4467291 },
-Marker { lat: 43.669616, lng: -79.362936 },
-Marker { lat: 43.795887, lng: -79.427048 },
-Marker { lat: 43.4026909, lng: -79.7886819 },
-Marker { lat: 43.665786, lng: -79.3855809 },
-Marker { lat: 43.4936455, lng: -79.8509726 },
-Marker { lat: 43.5530533, lng: -79.7119838 },
-Marker { lat: 43.642311, lng: -79.38123 },
-Marker { lat: 43.6962004, lng: -79.4564973 },
-Marker { lat: 43.8638834, lng: -79.4876821 },
-Marker { lat: 43.6282636, lng: -79.7185074 },
-Marker { lat: 43.7002992, lng: -79.4057381 },
-Marker { lat: 43.643646, lng: -79.41366 },
-Marker { lat: 43.7752161, lng: -79.4133461 },
-Marker { lat: 43.827088, lng: -79.09158 },
-Marker { lat: 43.9640419, lng: -78.8910352 },
-Marker { lat: 43.887882, lng: -79.457709 },
-Marker { lat: 43.707669, lng: -79.392307 },
-Marker { lat: 43.7509481, lng: -79.3963191 },
-Marker { lat: 43.8224469, lng: -79.3794106 },
-Marker { lat: 43.8660761, lng: -79.4183555 },
-Marker { lat: 43.7513487, lng: -79.7324727 },
-Marker { lat: 43.6887702, lng: -79.3480164 },
-Marker { lat: 43.639182, lng: -79.3863917 },
-Marker { lat: 43.6556663, lng: -79.4682434 },
-Marker { lat: 43.7691576, lng: -79.3688621 },
-Marker { lat: 43.7941787, lng: -79.4397985 },
-Marker { lat: 43.4592051, lng: -79.7043602 },
-Marker { lat: 43.6428617, lng: -79.3769141 },
-Marker { lat: 43.6887191, lng: -79.3362983 },
-Marker { lat: 43.7401115, lng: -79.4350833 },
-Marker { lat: 43.6630843, lng: -79.7861039 },
-Marker { lat: 43.8052004, lng: -79.1184585 },
-Marker { lat: 43.667982, lng: -79.774253 },
-Marker { lat: 43.6991375, lng: -79.4139477 },
-Marker { lat: 43.6589508, lng: -79.4031219 },
-Marker { lat: 43.86422, lng: -79.241216 },
-Marker { lat: 43.8602621, lng: -79.0889991 },
-Marker { lat: 43.775424, lng: -79.337482 },
-Marker { lat: 43.849312, lng: -79.4981895 },
-Marker { lat: 43.7634384, lng: -79.7670567 },
-Marker { lat: 43.680803, lng: -79.842706 },
-Marker { lat: 43.7150009, lng: -79.7030503 },
-Marker { lat: 43.5818543, lng: -79.7687821 },
-Marker { lat: 43.7347428, lng: -79.7855892 },
-Marker { lat: 43.5095764, lng: -79.6259364 },
-Marker { lat: 43.6522603, lng: -79.3688137 },
-Marker { lat: 43.6832031, lng: -79.4442184 },
-Marker { lat: 43.641061, lng: -79.415743 },
-Marker { lat: 43.6835201, lng: -79.8263357 },
-Marker { lat: 43.6194134, lng: -79.5855458 },
-Marker { lat: 43.7136434, lng: -79.4019823 },
-Marker { lat: 43.714348, lng: -79.353145 },
-Marker { lat: 43.8968172, lng: -79.2729394 },
-Marker { lat: 43.5365652, lng: -79.7334103 },
-Marker { lat: 43.49418, lng: -79.715921 },
-Marker { lat: 43.8908271, lng: -79.2316854 },
-Marker { lat: 43.632676, lng: -79.91857 },
-Marker { lat: 43.3485431, lng: -79.8872274 },
-Marker { lat: 43.748274, lng: -79.830798 },
-Marker { lat: 43.4281357, lng: -79.7645243 },
-Marker { lat: 43.7227157, lng: -79.3798235 },
-Marker { lat: 43.439235, lng: -79.749341 },
-Marker { lat: 43.7969279, lng: -79.4782679 },
-Marker { lat: 43.4199431, lng: -79.7078458 },
-Marker { lat: 43.7568224, lng: -79.8309587 },
-Marker { lat: 43.5697857, lng: -79.5950768 },
-Marker { lat: 43.9126645, lng: -78.8880464 },
-Marker { lat: 43.8488187, lng: -79.473713 },
-Marker { lat: 43.8765906, lng: -79.0955138 },
-Marker { lat: 43.9399361, lng: -79.457438 },
-Marker { lat: 43.8098535, lng: -79.6014561 },
-Marker { lat: 43.5086788, lng: -79.8361171 },
-Marker { lat: 43.5216835, lng: -79.8506581 },
-Marker { lat: 43.677266, lng: -79.406148 },
-Marker { lat: 43.6400585, lng: -79.5813557 },
-Marker { lat: 43.4961809, lng: -79.6993532 },
-Marker { lat: 43.4856798, lng: -79.8649357 },
-Marker { lat: 43.642832, lng: -79.376093 },
-Marker { lat: 43.3472456, lng: -79.8340848 },
-Marker { lat: 43.5675468, lng: -79.7643027 },
-Marker { lat: 43.6763153, lng: -79.4142677 },
-Marker { lat: 43.6411375, lng: -79.4007447 },
-Marker { lat: 43.6514007, lng: -79.5670712 },
-Marker { lat: 43.757229, lng: -79.927657 },
-Marker { lat: 43.6454146, lng: -79.387361 },
-Marker { lat: 43.7538531, lng: -79.3678581 },
-Marker { lat: 43.64787, lng: -79.391138 },
-Marker { lat: 43.643119, lng: -79.375786 },
-Marker { lat: 43.6489, lng: -79.392205 },
-Marker { lat: 43.6432587, lng: -79.3764035 },
-Marker { lat: 43.6843064, lng: -79.4112596 },
-Marker { lat: 43.641596, lng: -79.423443 },
-Marker { lat: 43.6391628, lng: -79.4191891 },
-Marker { lat: 43.8659, lng: -79.4342 },
-Marker { lat: 43.877861, lng: -79.233529 },
-Marker { lat: 43.5901692, lng: -79.6487568 },
-Marker { lat: 43.7449753, lng: -79.5873402 },
-Marker { lat: 43.6763281, lng: -79.5557986 },
-Marker { lat: 43.7135519, lng: -79.7901328 },
-Marker { lat: 43.623821, lng: -79.478728 },
-Marker { lat: 43.728742, lng: -79.482576 },
-Marker { lat: 43.5498299, lng: -79.7205978 },
-Marker { lat: 43.5870065, lng: -79.6405748 },
-Marker { lat: 43.586757, lng: -79.642403 },
-Marker { lat: 43.624995, lng: -79.479045 },
-Marker { lat: 43.647226, lng: -79.3911 },
-Marker { lat: 43.6411375, lng: -79.4007447 },
-Marker { lat: 43.7451437, lng: -79.8050688 },
-Marker { lat: 43.837642, lng: -79.234013 },
-Marker { lat: 43.8560115, lng: -79.3454051 },
-Marker { lat: 43.7142438, lng: -79.3716621 },
-Marker { lat: 43.6596665, lng: -79.3859262 },
-Marker { lat: 43.8963674, lng: -79.2656414 },
-Marker { lat: 43.7818804, lng: -79.246391 },
-Marker { lat: 43.6411375, lng: -79.4007447 },
-Marker { lat: 43.6718462, lng: -79.3726355 },
-Marker { lat: 43.6881075, lng: -79.3979987 },
-Marker { lat: 43.685894, lng: -79.414559 },
-Marker { lat: 43.9292202, lng: -79.5197774 },
-Marker { lat: 43.880007, lng: -78.9470541 },
-Marker { lat: 43.753799, lng: -79.42058 },
-Marker { lat: 43.4487233, lng: -79.7091882 },
-Marker { lat: 43.8610137, lng: -79.5416193 },
-Marker { lat: 43.6659594, lng: -79.5042526 },
-Marker { lat: 43.7947112, lng: -79.3948478 },
-Marker { lat: 43.8297595, lng: -79.4228743 },
-Marker { lat: 43.8498825, lng: -79.4456304 },
-Marker { lat: 43.4285, lng: -79.7445 },
-Marker { lat: 43.4285, lng: -79.7445 },
-Marker { lat: 43.6426375, lng: -79.5588279 },
-Marker { lat: 43.8550138, lng: -79.0756376 },
-Marker { lat: 43.7513009, lng: -79.4599333 },
-Marker { lat: 43.6400003, lng: -79.3788904 },
-Marker { lat: 43.641785, lng: -79.379006 },
-Marker { lat: 43.7540741, lng: -79.1854951 },
-Marker { lat: 43.638395, lng: -79.445621 },
-Marker { lat: 43.537293, lng: -79.861023 },
-Marker { lat: 43.9005502, lng: -79.4385402 },
-Marker { lat: 43.636608, lng: -79.4048673 },
-Marker { lat: 43.844821, lng: -79.47783 },
-Marker { lat: 43.7254632, lng: -79.4178342 },
-Marker { lat: 43.7024743, lng: -79.2880175 },
-Marker { lat: 43.9393314, lng: -79.4388096 },
-Marker { lat: 43.6411464, lng: -79.3830668 },
-Marker { lat: 43.69064, lng: -79.468887 },
-Marker { lat: 43.8689674, lng: -78.9390784 },
-Marker { lat: 43.671294, lng: -79.4660847 },
-Marker { lat: 43.6857104, lng: -79.4574687 },
-Marker { lat: 43.4624613, lng: -79.7321981 },
-Marker { lat: 43.555075, lng: -79.583315 },
-Marker { lat: 43.7504576, lng: -79.3703719 },
-Marker { lat: 43.7053515, lng: -79.2566693 },
-Marker { lat: 43.7026585, lng: -79.4125914 },
-Marker { lat: 43.766764, lng: -79.385993 },
-Marker { lat: 43.9168167, lng: -79.0227272 },
-Marker { lat: 43.6347636, lng: -79.7842752 },
-Marker { lat: 43.7077584, lng: -79.2985845 },
-Marker { lat: 43.6847858, lng: -79.8034797 },
-Marker { lat: 43.7652774, lng: -79.4414567 },
-Marker { lat: 43.9507308, lng: -79.4753453 },
-Marker { lat: 43.641874, lng: -79.428807 },
-Marker { lat: 43.776323, lng: -79.335927 },
-Marker { lat: 43.760712, lng: -79.308462 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.578326, lng: -79.568799 },
-Marker { lat: 43.8578063, lng: -79.4050167 },
-Marker { lat: 43.4315123, lng: -79.7356158 },
-Marker { lat: 43.6569143, lng: -79.4696057 },
-Marker { lat: 43.8218854, lng: -79.4243456 },
-Marker { lat: 43.8218854, lng: -79.4243456 },
-Marker { lat: 43.641087, lng: -79.381405 },
-Marker { lat: 43.6241314, lng: -79.4880676 },
-Marker { lat: 43.5626229, lng: -79.660245 },
-Marker { lat: 43.6217109, lng: -79.5901086 },
-Marker { lat: 43.4787449, lng: -79.7280526 },
-Marker { lat: 43.389039, lng: -79.918501 },
-Marker { lat: 43.618754, lng: -79.485361 },
-Marker { lat: 43.7859764, lng: -79.1975731 },
-Marker { lat: 43.7695626, lng: -79.4134466 },
-Marker { lat: 43.7020483, lng: -79.777884 },
-Marker { lat: 43.7685129, lng: -79.3980547 },
-Marker { lat: 43.864281, lng: -79.5248876 },
-Marker { lat: 43.7344681, lng: -79.4463751 },
-Marker { lat: 43.49533, lng: -79.70968 },
-Marker { lat: 43.667647, lng: -79.320093 },
-Marker { lat: 43.685112, lng: -79.292251 },
-Marker { lat: 43.970586, lng: -79.244284 },
-Marker { lat: 43.738443, lng: -79.554863 },
-Marker { lat: 43.9818014, lng: -79.4448434 },
-Marker { lat: 43.873542, lng: -79.752497 },
-Marker { lat: 43.4946113, lng: -79.8722506 },
-Marker { lat: 43.8329566, lng: -79.4613353 },
-Marker { lat: 43.8097257, lng: -79.400257 },
-Marker { lat: 43.7004809, lng: -79.4135574 },
-Marker { lat: 43.7128328, lng: -79.3840418 },
-Marker { lat: 43.899991, lng: -79.4676656 },
-Marker { lat: 43.7299934, lng: -79.3379531 },
-Marker { lat: 43.8983475, lng: -79.2483376 },
-Marker { lat: 43.8002298, lng: -79.4069948 },
-Marker { lat: 43.5130706, lng: -79.6255887 },
-Marker { lat: 43.4792503, lng: -79.6341654 },
-Marker { lat: 43.6966073, lng: -79.2942117 },
-Marker { lat: 43.867248, lng: -79.034061 },
-Marker { lat: 43.4883051, lng: -79.7294514 },
-Marker { lat: 43.706203, lng: -79.391923 },
-Marker { lat: 43.9688411, lng: -79.1341957 },
-Marker { lat: 43.4796022, lng: -79.7190726 },
-Marker { lat: 43.4955221, lng: -79.8776156 },
-Marker { lat: 43.798975, lng: -79.4912457 },
-Marker { lat: 43.8573, lng: -79.0125 },
-Marker { lat: 43.954029, lng: -79.43508 },
-Marker { lat: 43.5990787, lng: -79.6357516 },
-Marker { lat: 43.727437, lng: -79.6244644 },
-Marker { lat: 43.8318151, lng: -79.6560267 },
-Marker { lat: 43.596018, lng: -79.7770721 },
-Marker { lat: 43.6882528, lng: -79.8138968 },
-Marker { lat: 43.9553355, lng: -78.8812312 },
-Marker { lat: 43.6871437, lng: -79.5064656 },
-Marker { lat: 43.7253062, lng: -79.2202052 },
-Marker { lat: 43.6854103, lng: -79.4776903 },
-Marker { lat: 43.4930074, lng: -79.8711913 },
-Marker { lat: 43.6939285, lng: -79.3715662 },
-Marker { lat: 43.8206522, lng: -79.4599303 },
-Marker { lat: 43.6758727, lng: -79.5068983 },
-Marker { lat: 43.6625376, lng: -79.4178414 },
-Marker { lat: 43.7380669, lng: -79.5468058 },
-Marker { lat: 43.6693046, lng: -79.3821176 },
-Marker { lat: 43.6516507, lng: -79.7293393 },
-Marker { lat: 43.9508243, lng: -78.896716 },
-Marker { lat: 43.838645, lng: -79.008155 },
-Marker { lat: 43.6917265, lng: -79.270644 },
-Marker { lat: 43.4882947, lng: -79.9345586 },
-Marker { lat: 43.9025405, lng: -79.3756777 },
-Marker { lat: 43.8885825, lng: -79.2311096 },
-Marker { lat: 43.6484128, lng: -79.3764665 },
-Marker { lat: 43.669949, lng: -79.387053 },
-Marker { lat: 43.6426507, lng: -79.3773286 },
-Marker { lat: 43.6429141, lng: -79.3806469 },
-Marker { lat: 43.7563401, lng: -79.4056932 },
-Marker { lat: 43.6403108, lng: -79.3908593 },
-Marker { lat: 43.7667335, lng: -79.3714173 },
-Marker { lat: 43.790566, lng: -79.356238 },
-Marker { lat: 43.661896, lng: -79.385748 },
-Marker { lat: 43.6784907, lng: -79.2988979 },
-Marker { lat: 43.7571089, lng: -79.4242865 },
-Marker { lat: 43.7800398, lng: -79.4126795 },
-Marker { lat: 43.839126, lng: -79.38984 },
-Marker { lat: 43.8614279, lng: -79.4364729 },
-Marker { lat: 43.5967244, lng: -79.7061441 },
-Marker { lat: 43.5621506, lng: -79.572398 },
-Marker { lat: 43.62372, lng: -79.483703 },
-Marker { lat: 43.862021, lng: -79.505245 },
-Marker { lat: 43.4860233, lng: -79.7088979 },
-Marker { lat: 43.5859122, lng: -79.6472023 },
-Marker { lat: 43.902498, lng: -79.408493 },
-Marker { lat: 43.5393331, lng: -79.6714769 },
-Marker { lat: 43.849716, lng: -79.329883 },
-Marker { lat: 43.668564, lng: -79.395458 },
-Marker { lat: 43.7840829, lng: -79.4326294 },
-Marker { lat: 43.749969, lng: -79.406135 },
-Marker { lat: 43.7107994, lng: -79.4387081 },
-Marker { lat: 43.6562206, lng: -79.397564 },
-Marker { lat: 43.7506494, lng: -79.464186 },
-Marker { lat: 43.5364306, lng: -79.6333995 },
-Marker { lat: 43.630022, lng: -79.5715834 },
-Marker { lat: 43.670587, lng: -79.380609 },
-Marker { lat: 43.5119, lng: -79.6299 },
-Marker { lat: 43.884977, lng: -78.926993 },
-Marker { lat: 43.8436675, lng: -79.0026174 },
-Marker { lat: 43.6440617, lng: -79.3894386 },
-Marker { lat: 43.4694437, lng: -79.7092667 },
-Marker { lat: 43.374053, lng: -79.721288 },
-Marker { lat: 43.753813, lng: -79.445853 },
-Marker { lat: 43.652757, lng: -79.366511 },
-Marker { lat: 43.8485336, lng: -79.4327552 },
-Marker { lat: 43.665928, lng: -79.383465 },
-Marker { lat: 43.6166043, lng: -79.6460477 },
-Marker { lat: 43.8798261, lng: -79.2379761 },
-Marker { lat: 43.6728056, lng: -79.383906 },
-Marker { lat: 43.861153, lng: -79.230921 },
-Marker { lat: 43.648858, lng: -79.378145 },
-Marker { lat: 43.7029302, lng: -79.4510511 },
-Marker { lat: 43.9470092, lng: -79.4828757 },
-Marker { lat: 43.6601379, lng: -79.34308 },
-Marker { lat: 43.477811, lng: -79.722557 },
-Marker { lat: 43.491265, lng: -79.706617 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.4739547, lng: -79.6549593 },
-Marker { lat: 43.8031152, lng: -79.420837 },
-Marker { lat: 43.761564, lng: -79.1970846 },
-Marker { lat: 43.7559127, lng: -79.4155951 },
-Marker { lat: 43.810029, lng: -79.130792 },
-Marker { lat: 43.6460991, lng: -79.3651362 },
-Marker { lat: 43.8942413, lng: -79.2571072 },
-Marker { lat: 43.3779582, lng: -79.7304443 },
-Marker { lat: 43.6692764, lng: -79.4839308 },
-Marker { lat: 43.4460732, lng: -79.7637167 },
-Marker { lat: 43.7670761, lng: -79.3840091 },
-Marker { lat: 43.6400003, lng: -79.3788904 },
-Marker { lat: 43.858232, lng: -78.931245 },
-Marker { lat: 43.7780601, lng: -79.4166733 },
-Marker { lat: 43.7244955, lng: -79.2517684 },
-Marker { lat: 43.7479634, lng: -79.5052909 },
-Marker { lat: 43.7978348, lng: -79.6722328 },
-Marker { lat: 43.6732968, lng: -79.5276373 },
-Marker { lat: 43.5582954, lng: -79.5913411 },
-Marker { lat: 43.7070855, lng: -79.3645091 },
-Marker { lat: 43.666815, lng: -79.3875312 },
-Marker { lat: 43.649381, lng: -79.374532 },
-Marker { lat: 43.6920523, lng: -79.4386282 },
-Marker { lat: 43.8793, lng: -79.497 },
-Marker { lat: 43.799944, lng: -79.458072 },
-Marker { lat: 43.63641, lng: -79.402003 },
-Marker { lat: 43.6400887, lng: -79.3939574 },
-Marker { lat: 43.760906, lng: -79.4090028 },
-Marker { lat: 43.8943765, lng: -79.012863 },
-Marker { lat: 43.7499299, lng: -79.2581923 },
-Marker { lat: 43.7817207, lng: -79.2473686 },
-Marker { lat: 43.8074249, lng: -79.2243419 },
-Marker { lat: 43.724239, lng: -79.40255 },
-Marker { lat: 43.910763, lng: -79.604381 },
-Marker { lat: 43.8498016, lng: -78.9900742 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.6457578, lng: -79.3946255 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.641966, lng: -79.38174 },
-Marker { lat: 43.6405065, lng: -79.3969011 },
-Marker { lat: 43.6696, lng: -79.317574 },
-Marker { lat: 43.761994, lng: -79.419996 },
-Marker { lat: 43.6593726, lng: -79.3825439 },
-Marker { lat: 43.6460991, lng: -79.3651362 },
-Marker { lat: 43.6530828, lng: -79.3900382 },
-Marker { lat: 43.7590161, lng: -79.455548 },
-Marker { lat: 43.7029302, lng: -79.4510511 },
-Marker { lat: 43.811106, lng: -79.102584 },
-Marker { lat: 43.6866509, lng: -79.3995111 },
-Marker { lat: 43.661132, lng: -79.385711 },
-Marker { lat: 43.7028015, lng: -79.4513882 },
-Marker { lat: 43.7830199, lng: -79.4169093 },
-Marker { lat: 43.5897545, lng: -79.6480938 },
-Marker { lat: 43.7070855, lng: -79.3977452 },
-Marker { lat: 43.6774, lng: -79.511229 },
-Marker { lat: 43.8894707, lng: -79.2208396 },
-Marker { lat: 43.656537, lng: -79.354497 },
-Marker { lat: 43.447167, lng: -79.687053 },
-Marker { lat: 43.8828078, lng: -79.8523809 },
-Marker { lat: 43.7454933, lng: -79.7497385 },
-Marker { lat: 43.6877888, lng: -79.8505818 },
-Marker { lat: 43.684681, lng: -79.853092 },
-Marker { lat: 43.9819302, lng: -79.4839831 },
-Marker { lat: 43.7020147, lng: -79.7680873 },
-Marker { lat: 43.435663, lng: -79.775606 },
-Marker { lat: 43.665753, lng: -79.385579 },
-Marker { lat: 43.707669, lng: -79.392307 },
-Marker { lat: 43.665753, lng: -79.385579 },
-Marker { lat: 43.7331301, lng: -79.4074003 },
-Marker { lat: 43.637218, lng: -79.396666 },
-Marker { lat: 43.7760789, lng: -79.4027233 },
-Marker { lat: 43.6917934, lng: -79.436255 },
-Marker { lat: 43.6461536, lng: -79.3752086 },
-Marker { lat: 43.8736223, lng: -79.3036829 },
-Marker { lat: 43.894897, lng: -79.052424 },
-Marker { lat: 43.831402, lng: -79.032261 },
-Marker { lat: 43.84972, lng: -79.43554 },
-Marker { lat: 43.623821, lng: -79.478728 },
-Marker { lat: 43.783315, lng: -79.578869 },
-Marker { lat: 43.894484, lng: -79.2560691 },
-Marker { lat: 43.7058899, lng: -79.2575238 },
-Marker { lat: 43.520078, lng: -79.620473 },
-Marker { lat: 43.401812, lng: -79.7041148 },
-Marker { lat: 43.5020011, lng: -79.9100355 },
-Marker { lat: 43.4703666, lng: -79.7059208 },
-Marker { lat: 43.8459683, lng: -79.4577706 },
-Marker { lat: 43.7571856, lng: -79.3865123 },
-Marker { lat: 43.7574997, lng: -79.3728957 },
-Marker { lat: 43.7919893, lng: -79.7456921 },
-Marker { lat: 43.6668981, lng: -79.3890419 },
-Marker { lat: 43.4024206, lng: -79.7824283 },
-Marker { lat: 43.3640429, lng: -79.7612498 },
-Marker { lat: 43.4132172, lng: -79.7004303 },
-Marker { lat: 43.7610884, lng: -79.302581 },
-Marker { lat: 43.759376, lng: -79.78495 },
-Marker { lat: 43.7437408, lng: -79.3623011 },
-Marker { lat: 43.8138858, lng: -79.4629291 },
-Marker { lat: 43.9411688, lng: -79.0287261 },
-Marker { lat: 43.876717, lng: -79.260768 },
-Marker { lat: 43.5335436, lng: -79.6243111 },
-Marker { lat: 43.8381939, lng: -79.646073 },
-Marker { lat: 43.8391029, lng: -79.2490908 },
-Marker { lat: 43.3992143, lng: -79.7077282 },
-Marker { lat: 43.639185, lng: -79.399974 },
-Marker { lat: 43.7090836, lng: -79.645422 },
-Marker { lat: 43.8497821, lng: -79.3288246 },
-Marker { lat: 43.7772997, lng: -79.336725 },
-Marker { lat: 43.7841279, lng: -79.5877542 },
-Marker { lat: 43.7904691, lng: -79.4520964 },
-Marker { lat: 43.821686, lng: -79.099565 },
-Marker { lat: 43.7617207, lng: -79.782513 },
-Marker { lat: 43.7251286, lng: -79.4411101 },
-Marker { lat: 43.858252, lng: -79.433621 },
-Marker { lat: 43.9257163, lng: -78.9631783 },
-Marker { lat: 43.7767049, lng: -79.4110639 },
-Marker { lat: 43.7402172, lng: -79.391444 },
-Marker { lat: 43.641621, lng: -79.371728 },
-Marker { lat: 43.399397, lng: -79.7083418 },
-Marker { lat: 43.7673848, lng: -79.4247689 },
-Marker { lat: 43.4375965, lng: -79.685548 },
-Marker { lat: 43.4502742, lng: -79.6578324 },
-Marker { lat: 43.7086965, lng: -79.3967069 },
-Marker { lat: 43.5856554, lng: -79.7814221 },
-Marker { lat: 43.7955816, lng: -79.5202249 },
-Marker { lat: 43.737867, lng: -79.476491 },
-Marker { lat: 43.8117, lng: -79.6306 },
-Marker { lat: 43.636346, lng: -79.404535 },
-Marker { lat: 43.4378858, lng: -79.7642479 },
-Marker { lat: 43.5668055, lng: -79.5776466 },
-Marker { lat: 43.89416, lng: -79.40404 },
-Marker { lat: 43.6912708, lng: -79.3558969 },
-Marker { lat: 43.8604001, lng: -79.3219361 },
-Marker { lat: 43.8583306, lng: -79.4636638 },
-Marker { lat: 43.6595018, lng: -79.3847849 },
-Marker { lat: 43.4608543, lng: -79.7125647 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.7113275, lng: -79.4820895 },
-Marker { lat: 43.669593, lng: -79.38058 },
-Marker { lat: 43.707083, lng: -79.397771 },
-Marker { lat: 43.7178261, lng: -79.768715 },
-Marker { lat: 43.838536, lng: -79.549747 },
-Marker { lat: 43.625545, lng: -79.478746 },
-Marker { lat: 43.4402987, lng: -79.7324483 },
-Marker { lat: 43.885545, lng: -79.457283 },
-Marker { lat: 43.5881063, lng: -79.6481333 },
-Marker { lat: 43.6594036, lng: -79.3611785 },
-Marker { lat: 43.7713233, lng: -79.6886739 },
-Marker { lat: 43.7383538, lng: -79.5858853 },
-Marker { lat: 43.652778, lng: -79.367763 },
-Marker { lat: 43.647513, lng: -79.392702 },
-Marker { lat: 43.954328, lng: -79.4834859 },
-Marker { lat: 43.7907822, lng: -79.3561322 },
-Marker { lat: 43.8697591, lng: -79.4340578 },
-Marker { lat: 43.9375008, lng: -79.4755271 },
-Marker { lat: 43.7563401, lng: -79.4056932 },
-Marker { lat: 43.5411532, lng: -79.5999709 },
-Marker { lat: 43.8821513, lng: -79.4661792 },
-Marker { lat: 43.7384183, lng: -79.2439895 },
-Marker { lat: 43.6400086, lng: -79.3820669 },
-Marker { lat: 43.8929507, lng: -79.2339316 },
-Marker { lat: 43.9554909, lng: -78.9491944 },
-Marker { lat: 43.9685831, lng: -79.4668152 },
-Marker { lat: 43.666815, lng: -79.3875312 },
-Marker { lat: 43.7653441, lng: -79.4126151 },
-Marker { lat: 43.9036033, lng: -79.4533404 },
-Marker { lat: 43.878211, lng: -79.434702 },
-Marker { lat: 43.4401816, lng: -79.7663147 },
-Marker { lat: 43.6406816, lng: -79.376999 },
-Marker { lat: 43.7206115, lng: -79.7612123 },
-Marker { lat: 43.7070855, lng: -79.3977452 },
-Marker { lat: 43.8769359, lng: -79.0845182 },
-Marker { lat: 43.8790112, lng: -79.2754371 },
-Marker { lat: 43.469232, lng: -79.719796 },
-Marker { lat: 43.783532, lng: -79.7079935 },
-Marker { lat: 43.7520632, lng: -79.4642668 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.621064, lng: -79.48332 },
-Marker { lat: 43.7734808, lng: -79.2544987 },
-Marker { lat: 43.447167, lng: -79.687053 },
-Marker { lat: 43.7259478, lng: -79.4054076 },
-Marker { lat: 43.53269, lng: -79.86191 },
-Marker { lat: 43.9599579, lng: -79.5635666 },
-Marker { lat: 43.412659, lng: -79.80078 },
-Marker { lat: 43.648858, lng: -79.378145 },
-Marker { lat: 43.7264112, lng: -79.5815861 },
-Marker { lat: 43.959403, lng: -78.898438 },
-Marker { lat: 43.5344013, lng: -79.6935019 },
-Marker { lat: 43.6780622, lng: -79.5503111 },
-Marker { lat: 43.8299, lng: -79.0846 },
-Marker { lat: 43.7290218, lng: -79.5227173 },
-Marker { lat: 43.5653099, lng: -79.7488403 },
-Marker { lat: 43.788704, lng: -79.30225 },
-Marker { lat: 43.6429981, lng: -79.5125854 },
-Marker { lat: 43.885529, lng: -79.265213 },
-Marker { lat: 43.8827863, lng: -79.245893 },
-Marker { lat: 43.732027, lng: -79.635423 },
-Marker { lat: 43.6501, lng: -79.9175 },
-Marker { lat: 43.9099781, lng: -78.8836004 },
-Marker { lat: 43.6755754, lng: -79.2885285 },
-Marker { lat: 43.637411, lng: -79.418479 },
-Marker { lat: 43.7665037, lng: -79.4110937 },
-Marker { lat: 43.7397751, lng: -79.4245618 },
-Marker { lat: 43.9714943, lng: -78.9406597 },
-Marker { lat: 43.6422746, lng: -79.3727039 },
-Marker { lat: 43.7630277, lng: -79.3208032 },
-Marker { lat: 43.6926208, lng: -79.2968814 },
-Marker { lat: 43.7813866, lng: -79.3131748 },
-Marker { lat: 43.7837208, lng: -79.3959666 },
-Marker { lat: 43.7424837, lng: -79.1979595 },
-Marker { lat: 43.6851041, lng: -79.3133756 },
-Marker { lat: 43.784452, lng: -79.209007 },
-Marker { lat: 43.7199811, lng: -79.2614574 },
-Marker { lat: 43.8090107, lng: -79.2597155 },
-Marker { lat: 43.8907881, lng: -79.4275446 },
-Marker { lat: 43.9155296, lng: -79.4735598 },
-Marker { lat: 43.9306343, lng: -79.4736 },
-Marker { lat: 43.851671, lng: -79.4921889 },
-Marker { lat: 43.7785895, lng: -79.2833814 },
-Marker { lat: 43.8100647, lng: -79.4487117 },
-Marker { lat: 43.8066212, lng: -79.4171274 },
-Marker { lat: 43.9781975, lng: -79.4793106 },
-Marker { lat: 43.829214, lng: -79.012976 },
-Marker { lat: 43.7894812, lng: -79.4832979 },
-Marker { lat: 43.672986, lng: -79.387029 },
-Marker { lat: 43.868562, lng: -79.2129947 },
-Marker { lat: 43.8995273, lng: -78.8803312 },
-Marker { lat: 43.686315, lng: -79.514191 },
-Marker { lat: 43.8584331, lng: -79.436549 },
-Marker { lat: 43.7330081, lng: -79.4322322 },
-Marker { lat: 43.6180595, lng: -79.5906044 },
-Marker { lat: 43.4954368, lng: -79.8819613 },
-Marker { lat: 43.541405, lng: -79.6971703 },
-Marker { lat: 43.6249491, lng: -79.4782438 },
-Marker { lat: 43.6984664, lng: -79.8568167 },
-Marker { lat: 43.6552131, lng: -79.5264164 },
-Marker { lat: 43.4837347, lng: -79.6915673 },
-Marker { lat: 43.6416067, lng: -79.580345 },
-Marker { lat: 43.6405502, lng: -79.4291015 },
-Marker { lat: 43.6638633, lng: -79.4726844 },
-Marker { lat: 43.5235452, lng: -79.6879939 },
-Marker { lat: 43.748687, lng: -79.784893 },
-Marker { lat: 43.6948112, lng: -79.775919 },
-Marker { lat: 43.7365674, lng: -79.7244332 },
-Marker { lat: 43.8951754, lng: -79.7413417 },
-Marker { lat: 43.776244, lng: -79.744432 },
-Marker { lat: 43.8741642, lng: -79.3062241 },
-Marker { lat: 43.7538031, lng: -79.8172648 },
-Marker { lat: 43.5949957, lng: -79.7779361 },
-Marker { lat: 43.7078997, lng: -79.4192464 },
-Marker { lat: 43.7487547, lng: -79.2701126 },
-Marker { lat: 43.9382031, lng: -79.4612908 },
-Marker { lat: 43.8708839, lng: -79.5612133 },
-Marker { lat: 43.6722959, lng: -79.3060326 },
-Marker { lat: 43.68065, lng: -79.2871653 },
-Marker { lat: 43.9499976, lng: -78.8954392 },
-Marker { lat: 43.666981, lng: -79.388889 },
-Marker { lat: 43.661299, lng: -79.346677 },
-Marker { lat: 43.9010319, lng: -79.2693471 },
-Marker { lat: 43.771574, lng: -79.2105057 },
-Marker { lat: 43.9101693, lng: -79.44595 },
-Marker { lat: 43.8278609, lng: -79.3210084 },
-Marker { lat: 43.8714691, lng: -79.5189553 },
-Marker { lat: 43.3579805, lng: -79.8408894 },
-Marker { lat: 43.5311052, lng: -79.6338725 },
-Marker { lat: 43.8937683, lng: -79.4083695 },
-Marker { lat: 43.7022079, lng: -79.8367995 },
-Marker { lat: 43.835408, lng: -79.01368 },
-Marker { lat: 43.693629, lng: -79.280023 },
-Marker { lat: 43.7364705, lng: -79.4652097 },
-Marker { lat: 43.8751938, lng: -79.8619279 },
-Marker { lat: 43.636057, lng: -79.470781 },
-Marker { lat: 43.7604326, lng: -79.6902569 },
-Marker { lat: 43.6241314, lng: -79.4880676 },
-Marker { lat: 43.697382, lng: -79.527707 },
-Marker { lat: 43.7341066, lng: -79.7991446 },
-Marker { lat: 43.7863536, lng: -79.8138239 },
-Marker { lat: 43.9098495, lng: -79.4698839 },
-Marker { lat: 43.6702658, lng: -79.3943539 },
-Marker { lat: 43.672274, lng: -79.3804573 },
-Marker { lat: 43.8976031, lng: -79.2573859 },
-Marker { lat: 43.6836509, lng: -79.8507267 },
-Marker { lat: 43.9574824, lng: -78.9023192 },
-Marker { lat: 43.690914, lng: -79.5016358 },
-Marker { lat: 43.8603434, lng: -79.4324218 },
-Marker { lat: 43.866886, lng: -79.04877 },
-Marker { lat: 43.9023506, lng: -79.2305343 },
-Marker { lat: 43.7859764, lng: -79.1975731 },
-Marker { lat: 43.960265, lng: -78.9479255 },
-Marker { lat: 43.6799337, lng: -79.3881653 },
-Marker { lat: 43.646357, lng: -79.937671 },
-Marker { lat: 43.861726, lng: -79.435841 },
-Marker { lat: 43.3734067, lng: -79.8432718 },
-Marker { lat: 43.3427537, lng: -79.8439704 },
-Marker { lat: 43.638323, lng: -79.878839 },
-Marker { lat: 43.377116, lng: -79.7240949 },
-Marker { lat: 43.638941, lng: -79.918858 },
-Marker { lat: 43.9454408, lng: -78.8896149 },
-Marker { lat: 43.3949364, lng: -79.8125528 },
-Marker { lat: 43.651643, lng: -79.919946 },
-Marker { lat: 43.4285013, lng: -79.763019 },
-Marker { lat: 43.4045145, lng: -79.708475 },
-Marker { lat: 43.5270801, lng: -79.8475522 },
-Marker { lat: 43.5055628, lng: -79.8834785 },
-Marker { lat: 43.6667966, lng: -79.3886683 },
-Marker { lat: 43.6548441, lng: -79.3846797 },
-Marker { lat: 43.7722925, lng: -79.4122663 },
-Marker { lat: 43.7658523, lng: -79.3827681 },
-Marker { lat: 43.68901, lng: -79.400005 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.668718, lng: -79.383996 },
-Marker { lat: 43.9718642, lng: -78.9484889 },
-Marker { lat: 43.9556477, lng: -79.253057 },
-Marker { lat: 43.648317, lng: -79.393065 },
-Marker { lat: 43.5608565, lng: -79.7237495 },
-Marker { lat: 43.592274, lng: -79.634311 },
-Marker { lat: 43.790879, lng: -79.678408 },
-Marker { lat: 43.4010629, lng: -79.8281382 },
-Marker { lat: 43.9720159, lng: -79.5492901 },
-Marker { lat: 43.8863787, lng: -79.4486714 },
-Marker { lat: 43.88251, lng: -79.225774 },
-Marker { lat: 43.8561605, lng: -79.4491674 },
-Marker { lat: 43.881727, lng: -79.493037 },
-Marker { lat: 43.970816, lng: -79.249128 },
-Marker { lat: 43.970816, lng: -79.249128 },
-Marker { lat: 43.8578603, lng: -79.4584595 },
-Marker { lat: 43.494461, lng: -79.715995 },
-Marker { lat: 43.9702577, lng: -79.2674595 },
-Marker { lat: 43.6855327, lng: -79.7692415 },
-Marker { lat: 43.4426282, lng: -79.6723596 },
-Marker { lat: 43.643485, lng: -79.392304 },
-Marker { lat: 43.645505, lng: -79.392457 },
-Marker { lat: 43.5478948, lng: -79.6230785 },
-Marker { lat: 43.762465, lng: -79.363837 },
-Marker { lat: 43.8376015, lng: -79.391158 },
-Marker { lat: 43.7364656, lng: -79.3558221 },
-Marker { lat: 43.668136, lng: -79.300964 },
-Marker { lat: 43.8924585, lng: -79.0232355 },
-Marker { lat: 43.848894, lng: -79.4886946 },
-Marker { lat: 43.4195805, lng: -79.7126929 },
-Marker { lat: 43.8102603, lng: -79.4659951 },
-Marker { lat: 43.7180212, lng: -79.3768816 },
-Marker { lat: 43.7196684, lng: -79.5289182 },
-Marker { lat: 43.9322029, lng: -79.4732445 },
-Marker { lat: 43.5925251, lng: -79.6712504 },
-Marker { lat: 43.4337146, lng: -79.7773103 },
-Marker { lat: 43.8928419, lng: -78.9069009 },
-Marker { lat: 43.7149614, lng: -79.4412442 },
-Marker { lat: 43.9817268, lng: -79.4439623 },
-Marker { lat: 43.697049, lng: -79.394156 },
-Marker { lat: 43.9483385, lng: -79.4694808 },
-Marker { lat: 43.982267, lng: -79.375247 },
-Marker { lat: 43.5847029, lng: -79.6415724 },
-Marker { lat: 43.7773884, lng: -79.3598905 },
-Marker { lat: 43.7114613, lng: -79.4627272 },
-Marker { lat: 43.665352, lng: -79.340268 },
-Marker { lat: 43.7471525, lng: -79.3994228 },
-Marker { lat: 43.902485, lng: -79.253581 },
-Marker { lat: 43.7502921, lng: -79.3953702 },
-Marker { lat: 43.6423457, lng: -79.6005261 },
-Marker { lat: 43.7752913, lng: -79.3907695 },
-Marker { lat: 43.8091676, lng: -79.455206 },
-Marker { lat: 43.5894255, lng: -79.6733391 },
-Marker { lat: 43.68752, lng: -79.849596 },
-Marker { lat: 43.4813689, lng: -79.8752693 },
-Marker { lat: 43.7767049, lng: -79.4110639 },
-Marker { lat: 43.59115, lng: -79.635605 },
-Marker { lat: 43.875256, lng: -79.2315162 },
-Marker { lat: 43.7413588, lng: -79.446903 },
-Marker { lat: 43.717144, lng: -79.393375 },
-Marker { lat: 43.6699365, lng: -79.4880524 },
-Marker { lat: 43.9727906, lng: -78.9515541 },
-Marker { lat: 43.7455389, lng: -79.4200569 },
-Marker { lat: 43.345915, lng: -79.881456 },
-Marker { lat: 43.641785, lng: -79.379006 },
-Marker { lat: 43.4966739, lng: -79.7073167 },
-Marker { lat: 43.8920038, lng: -78.914248 },
-Marker { lat: 43.9530349, lng: -79.4818745 },
-Marker { lat: 43.7653413, lng: -79.3825659 },
-Marker { lat: 43.88104, lng: -79.2535668 },
-Marker { lat: 43.9721976, lng: -78.9435309 },
-Marker { lat: 43.8097996, lng: -79.1317796 },
-Marker { lat: 43.6296866, lng: -79.5044362 },
-Marker { lat: 43.883946, lng: -79.748912 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.8339392, lng: -79.4867058 },
-Marker { lat: 43.7425676, lng: -79.3720558 },
-Marker { lat: 43.7949339, lng: -79.4088358 },
-Marker { lat: 43.6570037, lng: -79.3552254 },
-Marker { lat: 43.795371, lng: -79.406699 },
-Marker { lat: 43.694252, lng: -79.8600068 },
-Marker { lat: 43.8094847, lng: -79.4414529 },
-Marker { lat: 43.7522495, lng: -79.8409628 },
-Marker { lat: 43.858607, lng: -79.411205 },
-Marker { lat: 43.8842833, lng: -78.9276157 },
-Marker { lat: 43.815689, lng: -79.361582 },
-Marker { lat: 43.9233503, lng: -78.9445542 },
-Marker { lat: 43.8990721, lng: -78.9704535 },
-Marker { lat: 43.9577084, lng: -78.8845995 },
-Marker { lat: 43.714131, lng: -79.454068 },
-Marker { lat: 43.5568579, lng: -79.703596 },
-Marker { lat: 43.71507, lng: -79.453301 },
-Marker { lat: 43.763578, lng: -79.5676023 },
-Marker { lat: 43.847084, lng: -79.3001003 },
-Marker { lat: 43.8204383, lng: -79.3959561 },
-Marker { lat: 43.7783281, lng: -79.6694345 },
-Marker { lat: 43.723197, lng: -79.410398 },
-Marker { lat: 43.7019362, lng: -79.3027284 },
-Marker { lat: 43.955549, lng: -78.8791075 },
-Marker { lat: 43.7590555, lng: -79.7632992 },
-Marker { lat: 43.67032, lng: -79.394433 },
-Marker { lat: 43.6910577, lng: -79.388667 },
-Marker { lat: 43.7195087, lng: -79.3377222 },
-Marker { lat: 43.731643, lng: -79.421088 },
-Marker { lat: 43.6916838, lng: -79.2999451 },
-Marker { lat: 43.6446881, lng: -79.412696 },
-Marker { lat: 43.594882, lng: -79.634317 },
-Marker { lat: 43.8466574, lng: -79.2623974 },
-Marker { lat: 43.6568586, lng: -79.3836642 },
-Marker { lat: 43.8896612, lng: -79.0284716 },
-Marker { lat: 43.7924932, lng: -79.3700577 },
-Marker { lat: 43.6653712, lng: -79.3403815 },
-Marker { lat: 43.7395147, lng: -79.4349468 },
-Marker { lat: 43.358339, lng: -79.813697 },
-Marker { lat: 43.634183, lng: -79.51831 },
-Marker { lat: 43.8136714, lng: -79.4590754 },
-Marker { lat: 43.6694507, lng: -79.3111551 },
-Marker { lat: 43.4491708, lng: -79.7461798 },
-Marker { lat: 43.8788055, lng: -79.393017 },
-Marker { lat: 43.954117, lng: -79.461306 },
-Marker { lat: 43.65056, lng: -79.382072 },
-Marker { lat: 43.682004, lng: -79.843868 },
-Marker { lat: 43.893368, lng: -79.4101722 },
-Marker { lat: 43.5910365, lng: -79.532819 },
-Marker { lat: 43.613379, lng: -79.498167 },
-Marker { lat: 43.646819, lng: -79.7744583 },
-Marker { lat: 43.4346014, lng: -79.6846366 },
-Marker { lat: 43.7220508, lng: -79.7154215 },
-Marker { lat: 43.518299, lng: -79.877404 },
-Marker { lat: 43.764193, lng: -79.412758 },
-Marker { lat: 43.7375378, lng: -79.3550858 },
-Marker { lat: 43.480917, lng: -79.716089 },
-Marker { lat: 43.833236, lng: -79.530936 },
-Marker { lat: 43.7237215, lng: -79.3253732 },
-Marker { lat: 43.7367513, lng: -79.4767442 },
-Marker { lat: 43.7143156, lng: -79.2444201 },
-Marker { lat: 43.6461536, lng: -79.3752086 },
-Marker { lat: 43.768844, lng: -79.4414432 },
-Marker { lat: 43.643909, lng: -79.407624 },
-Marker { lat: 43.7672943, lng: -79.2530972 },
-Marker { lat: 43.652331, lng: -79.356014 },
-Marker { lat: 43.7031445, lng: -79.2993449 },
-Marker { lat: 43.7109739, lng: -79.6498789 },
-Marker { lat: 43.835992, lng: -79.554132 },
-Marker { lat: 43.6161559, lng: -79.5966193 },
-Marker { lat: 43.675972, lng: -79.826307 },
-Marker { lat: 43.874616, lng: -79.759178 },
-Marker { lat: 43.6954498, lng: -79.5166627 },
-Marker { lat: 43.5393625, lng: -79.6973565 },
-Marker { lat: 43.6048372, lng: -79.651399 },
-Marker { lat: 43.9803147, lng: -79.4872586 },
-Marker { lat: 43.8994457, lng: -78.9082468 },
-Marker { lat: 43.780841, lng: -79.4143832 },
-Marker { lat: 43.6528622, lng: -79.5276233 },
-Marker { lat: 43.482, lng: -79.873427 },
-Marker { lat: 43.6647658, lng: -79.3840887 },
-Marker { lat: 43.3707846, lng: -79.7650455 },
-Marker { lat: 43.9086157, lng: -79.4726382 },
-Marker { lat: 43.7123485, lng: -79.8103681 },
-Marker { lat: 43.5237772, lng: -79.6365871 },
-Marker { lat: 43.8633835, lng: -79.4332961 },
-Marker { lat: 43.8847414, lng: -79.457227 },
-Marker { lat: 43.8397762, lng: -79.0826967 },
-Marker { lat: 43.5841052, lng: -79.5780509 },
-Marker { lat: 43.828489, lng: -79.474585 },
-Marker { lat: 43.791836, lng: -79.237404 },
-Marker { lat: 43.560454, lng: -79.737688 },
-Marker { lat: 43.88897, lng: -79.445262 },
-Marker { lat: 43.8896663, lng: -79.5154732 },
-Marker { lat: 43.902498, lng: -79.408493 },
-Marker { lat: 43.8767977, lng: -79.4460509 },
-Marker { lat: 43.7675029, lng: -79.4102384 },
-Marker { lat: 43.7583518, lng: -79.3379271 },
-Marker { lat: 43.6767474, lng: -79.3272623 },
-Marker { lat: 43.7733999, lng: -79.2558617 },
-Marker { lat: 43.714455, lng: -79.454411 },
-Marker { lat: 43.6040446, lng: -79.6523817 },
-Marker { lat: 43.7300903, lng: -79.3322682 },
-Marker { lat: 43.774071, lng: -79.329324 },
-Marker { lat: 43.757354, lng: -79.409358 },
-Marker { lat: 43.916593, lng: -79.028641 },
-Marker { lat: 43.6384589, lng: -79.4140635 },
-Marker { lat: 43.6383599, lng: -79.398657 },
-Marker { lat: 43.6373148, lng: -79.4029448 },
-Marker { lat: 43.7695665, lng: -79.4299601 },
-Marker { lat: 43.8428417, lng: -79.4262012 },
-Marker { lat: 43.6853242, lng: -79.3300402 },
-Marker { lat: 43.8372873, lng: -79.4328447 },
-Marker { lat: 43.9167327, lng: -79.4714942 },
-Marker { lat: 43.5731495, lng: -79.7807806 },
-Marker { lat: 43.862641, lng: -79.4494707 },
-Marker { lat: 43.8030041, lng: -79.3023529 },
-Marker { lat: 43.7311638, lng: -79.4616848 },
-Marker { lat: 43.6255085, lng: -79.5237781 },
-Marker { lat: 43.668799, lng: -79.448242 },
-Marker { lat: 43.4224924, lng: -79.7700047 },
-Marker { lat: 43.36088, lng: -79.7535725 },
-Marker { lat: 43.412093, lng: -79.801236 },
-Marker { lat: 43.3616832, lng: -79.7632755 },
-Marker { lat: 43.3945132, lng: -79.7880299 },
-Marker { lat: 43.6420853, lng: -79.5089923 },
-Marker { lat: 43.8437361, lng: -79.5330338 },
-Marker { lat: 43.757855, lng: -79.416974 },
-Marker { lat: 43.8317976, lng: -79.0130653 },
-Marker { lat: 43.631384, lng: -79.882047 },
-Marker { lat: 43.79615, lng: -79.3539499 },
-Marker { lat: 43.661781, lng: -79.384218 },
-Marker { lat: 43.7363075, lng: -79.4310648 },
-Marker { lat: 43.6691837, lng: -79.3873558 },
-Marker { lat: 43.6667966, lng: -79.3886683 },
-Marker { lat: 43.7354841, lng: -79.4123003 },
-Marker { lat: 43.646098, lng: -79.376055 },
-Marker { lat: 43.701127, lng: -79.396602 },
-Marker { lat: 43.7692665, lng: -79.4113412 },
-Marker { lat: 43.7747358, lng: -79.3886501 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.6424403, lng: -79.3721255 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.7382111, lng: -79.4264502 },
-Marker { lat: 43.7639679, lng: -79.2032065 },
-Marker { lat: 43.7778723, lng: -79.2807506 },
-Marker { lat: 43.690647, lng: -79.3055448 },
-Marker { lat: 43.6518519, lng: -79.3690555 },
-Marker { lat: 43.7125007, lng: -79.3835202 },
-Marker { lat: 43.8049638, lng: -79.2281848 },
-Marker { lat: 43.9621913, lng: -78.9419574 },
-Marker { lat: 43.9207229, lng: -78.9254753 },
-Marker { lat: 43.8872876, lng: -78.9401018 },
-Marker { lat: 43.6952602, lng: -79.3064481 },
-Marker { lat: 43.822918, lng: -79.018807 },
-Marker { lat: 43.8050219, lng: -79.2047035 },
-Marker { lat: 43.516021, lng: -79.883669 },
-Marker { lat: 43.8527461, lng: -79.3386798 },
-Marker { lat: 43.890146, lng: -79.5165327 },
-Marker { lat: 43.851993, lng: -79.43178 },
-Marker { lat: 43.818515, lng: -79.6281933 },
-Marker { lat: 43.9538658, lng: -79.4364712 },
-Marker { lat: 43.7270473, lng: -79.7190642 },
-Marker { lat: 43.5962963, lng: -79.5351088 },
-Marker { lat: 43.9703332, lng: -79.2539406 },
-Marker { lat: 43.869442, lng: -79.467557 },
-Marker { lat: 43.5164289, lng: -79.8433177 },
-Marker { lat: 43.5662907, lng: -79.5790302 },
-Marker { lat: 43.594882, lng: -79.634317 },
-Marker { lat: 43.3427096, lng: -79.8360708 },
-Marker { lat: 43.5844788, lng: -79.5925967 },
-Marker { lat: 43.4202302, lng: -79.7069652 },
-Marker { lat: 43.7283118, lng: -79.7997846 },
-Marker { lat: 43.6952107, lng: -79.5508744 },
-Marker { lat: 43.5247855, lng: -79.8346688 },
-Marker { lat: 43.5223216, lng: -79.8459005 },
-Marker { lat: 43.6801416, lng: -79.7602669 },
-Marker { lat: 43.708017, lng: -79.838369 },
-Marker { lat: 43.5883573, lng: -79.6395201 },
-Marker { lat: 43.4333305, lng: -79.7680473 },
-Marker { lat: 43.690746, lng: -79.836342 },
-Marker { lat: 43.7549938, lng: -79.7515108 },
-Marker { lat: 43.6978167, lng: -79.4318968 },
-Marker { lat: 43.652572, lng: -79.363722 },
-Marker { lat: 43.6726027, lng: -79.3953805 },
-Marker { lat: 43.643188, lng: -79.422494 },
-Marker { lat: 43.652778, lng: -79.367763 },
-Marker { lat: 43.679437, lng: -79.382305 },
-Marker { lat: 43.7884853, lng: -79.3656891 },
-Marker { lat: 43.6668231, lng: -79.3354103 },
-Marker { lat: 43.6647658, lng: -79.3840887 },
-Marker { lat: 43.7639679, lng: -79.2032065 },
-Marker { lat: 43.6458819, lng: -79.3973406 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.678249, lng: -79.350679 },
-Marker { lat: 43.8140635, lng: -79.2729459 },
-Marker { lat: 43.9027017, lng: -78.9462413 },
-Marker { lat: 43.7828222, lng: -79.3240163 },
-Marker { lat: 43.9457549, lng: -79.4578658 },
-Marker { lat: 43.8323441, lng: -79.5078179 },
-Marker { lat: 43.9602045, lng: -78.9685078 },
-Marker { lat: 43.8519231, lng: -79.4206137 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.621064, lng: -79.48332 },
-Marker { lat: 43.6615344, lng: -79.5696699 },
-Marker { lat: 43.3417679, lng: -79.7685068 },
-Marker { lat: 43.7260965, lng: -79.4796815 },
-Marker { lat: 43.62372, lng: -79.483703 },
-Marker { lat: 43.6867701, lng: -79.8112274 },
-Marker { lat: 43.644402, lng: -79.5286903 },
-Marker { lat: 43.5751848, lng: -79.5636178 },
-Marker { lat: 43.4923625, lng: -79.8438002 },
-Marker { lat: 43.7568248, lng: -79.8175389 },
-Marker { lat: 43.623782, lng: -79.488316 },
-Marker { lat: 43.5870065, lng: -79.6405748 },
-Marker { lat: 43.447443, lng: -79.686948 },
-Marker { lat: 43.6701539, lng: -79.4993079 },
-Marker { lat: 43.5168921, lng: -79.8472089 },
-Marker { lat: 43.4910274, lng: -79.8624972 },
-Marker { lat: 43.5996695, lng: -79.7199904 },
-Marker { lat: 43.6958036, lng: -79.3347898 },
-Marker { lat: 43.757992, lng: -79.4438034 },
-Marker { lat: 43.9818663, lng: -79.4530233 },
-Marker { lat: 43.4369661, lng: -79.7660594 },
-Marker { lat: 43.972717, lng: -79.827587 },
-Marker { lat: 43.6689844, lng: -79.3055637 },
-Marker { lat: 43.8212472, lng: -79.5631421 },
-Marker { lat: 43.8480897, lng: -79.4698251 },
-Marker { lat: 43.6797019, lng: -79.3131682 },
-Marker { lat: 43.789348, lng: -79.172379 },
-Marker { lat: 43.839792, lng: -79.093703 },
-Marker { lat: 43.665106, lng: -79.462516 },
-Marker { lat: 43.8999027, lng: -79.2486534 },
-Marker { lat: 43.7091099, lng: -79.242444 },
-Marker { lat: 43.8104895, lng: -79.325254 },
-Marker { lat: 43.764598, lng: -79.3882301 },
-Marker { lat: 43.8155586, lng: -79.934547 },
-Marker { lat: 43.9012655, lng: -79.300659 },
-Marker { lat: 43.842605, lng: -79.4368631 },
-Marker { lat: 43.7182375, lng: -79.8259504 },
-Marker { lat: 43.7265442, lng: -79.3818775 },
-Marker { lat: 43.982267, lng: -79.375247 },
-Marker { lat: 43.894755, lng: -79.660619 },
-Marker { lat: 43.646098, lng: -79.376055 },
-Marker { lat: 43.6642271, lng: -79.788926 },
-Marker { lat: 43.4922466, lng: -79.7213623 },
-Marker { lat: 43.8777152, lng: -79.8646006 },
-Marker { lat: 43.4769, lng: -79.6517 },
-Marker { lat: 43.7148225, lng: -79.2379386 },
-Marker { lat: 43.6544986, lng: -79.3631573 },
-Marker { lat: 43.4279059, lng: -79.7641856 },
-Marker { lat: 43.8451068, lng: -79.4074817 },
-Marker { lat: 43.6954772, lng: -79.4736871 },
-Marker { lat: 43.8494694, lng: -79.2545783 },
-Marker { lat: 43.7221446, lng: -79.2270277 },
-Marker { lat: 43.477745, lng: -79.7184002 },
-Marker { lat: 43.4758573, lng: -79.6445719 },
-Marker { lat: 43.7198983, lng: -79.6259583 },
-Marker { lat: 43.626138, lng: -79.477339 },
-Marker { lat: 43.6333519, lng: -79.7799487 },
-Marker { lat: 43.7101924, lng: -79.2768435 },
-Marker { lat: 43.646098, lng: -79.376055 },
-Marker { lat: 43.773876, lng: -79.2525897 },
-Marker { lat: 43.8531634, lng: -79.1085135 },
-Marker { lat: 43.67032, lng: -79.394433 },
-Marker { lat: 43.9607952, lng: -78.9389387 },
-Marker { lat: 43.810167, lng: -79.221126 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.6398182, lng: -79.3836155 },
-Marker { lat: 43.6290551, lng: -79.7700167 },
-Marker { lat: 43.79131, lng: -79.2169322 },
-Marker { lat: 43.7740006, lng: -79.1786995 },
-Marker { lat: 43.89611, lng: -79.2227211 },
-Marker { lat: 43.95819, lng: -79.470279 },
-Marker { lat: 43.778008, lng: -79.4527173 },
-Marker { lat: 43.4820031, lng: -79.7067336 },
-Marker { lat: 43.6883218, lng: -79.2785613 },
-Marker { lat: 43.9225231, lng: -79.533183 },
-Marker { lat: 43.7734661, lng: -79.2050947 },
-Marker { lat: 43.435599, lng: -79.7726 },
-Marker { lat: 43.8961439, lng: -79.4236917 },
-Marker { lat: 43.5587749, lng: -79.6063047 },
-Marker { lat: 43.8934612, lng: -79.2339902 },
-Marker { lat: 43.5643788, lng: -79.5999606 },
-Marker { lat: 43.823404, lng: -79.086648 },
-Marker { lat: 43.3791169, lng: -79.7550947 },
-Marker { lat: 43.7012116, lng: -79.7683787 },
-Marker { lat: 43.6147163, lng: -79.5072187 },
-Marker { lat: 43.4918877, lng: -79.6851841 },
-Marker { lat: 43.8931825, lng: -79.0191131 },
-Marker { lat: 43.6842723, lng: -79.339358 },
-Marker { lat: 43.9449698, lng: -79.4480479 },
-Marker { lat: 43.833606, lng: -79.647154 },
-Marker { lat: 43.8467258, lng: -79.490679 },
-Marker { lat: 43.669593, lng: -79.38058 },
-Marker { lat: 43.8298358, lng: -79.3731894 },
-Marker { lat: 43.57814, lng: -79.5942517 },
-Marker { lat: 43.946999, lng: -79.827848 },
-Marker { lat: 43.648909, lng: -79.386075 },
-Marker { lat: 43.6947301, lng: -79.7975971 },
-Marker { lat: 43.7222609, lng: -79.5838342 },
-Marker { lat: 43.7070403, lng: -79.3209742 },
-Marker { lat: 43.8569705, lng: -79.4801523 },
-Marker { lat: 43.819558, lng: -79.022058 },
-Marker { lat: 43.9151082, lng: -78.9760958 },
-Marker { lat: 43.78995, lng: -79.546154 },
-Marker { lat: 43.7825286, lng: -79.3418461 },
-Marker { lat: 43.7562359, lng: -79.7735897 },
-Marker { lat: 43.7126526, lng: -79.7558009 },
-Marker { lat: 43.6668104, lng: -79.4199661 },
-Marker { lat: 43.9554937, lng: -79.4832927 },
-Marker { lat: 43.6616536, lng: -79.4244404 },
-Marker { lat: 43.9068943, lng: -79.4406035 },
-Marker { lat: 43.672989, lng: -79.4587839 },
-Marker { lat: 43.7987669, lng: -79.6792904 },
-Marker { lat: 43.6458247, lng: -79.5333982 },
-Marker { lat: 43.4765364, lng: -79.6867947 },
-Marker { lat: 43.9668921, lng: -79.4363811 },
-Marker { lat: 43.8988371, lng: -79.4525188 },
-Marker { lat: 43.645402, lng: -79.431818 },
-Marker { lat: 43.646499, lng: -79.893766 },
-Marker { lat: 43.6626765, lng: -79.4502034 },
-Marker { lat: 43.6501177, lng: -79.3579349 },
-Marker { lat: 43.7901063, lng: -79.4102927 },
-Marker { lat: 43.6677676, lng: -79.3808901 },
-Marker { lat: 43.8782772, lng: -79.0929854 },
-Marker { lat: 43.950415, lng: -78.8934721 },
-Marker { lat: 43.69976, lng: -79.518492 },
-Marker { lat: 43.8888025, lng: -79.3119028 },
-Marker { lat: 43.8783144, lng: -79.865126 },
-Marker { lat: 43.5669962, lng: -79.6048521 },
-Marker { lat: 43.6891129, lng: -79.4966567 },
-Marker { lat: 43.6378846, lng: -79.756336 },
-Marker { lat: 43.744754, lng: -79.220024 },
-Marker { lat: 43.6505055, lng: -79.7335471 },
-Marker { lat: 43.733212, lng: -79.5357687 },
-Marker { lat: 43.8125713, lng: -79.2088054 },
-Marker { lat: 43.659421, lng: -79.361025 },
-Marker { lat: 43.539441, lng: -79.6276076 },
-Marker { lat: 43.526368, lng: -79.846905 },
-Marker { lat: 43.7677262, lng: -79.3706232 },
-Marker { lat: 43.6442354, lng: -79.5496549 },
-Marker { lat: 43.590821, lng: -79.632008 },
-Marker { lat: 43.7934384, lng: -79.1580284 },
-Marker { lat: 43.5589237, lng: -79.5931412 },
-Marker { lat: 43.6740536, lng: -79.7884432 },
-Marker { lat: 43.881674, lng: -79.225092 },
-Marker { lat: 43.6399005, lng: -79.3905103 },
-Marker { lat: 43.4831781, lng: -79.6463187 },
-Marker { lat: 43.5097612, lng: -79.8570569 },
-Marker { lat: 43.869083, lng: -79.062189 },
-Marker { lat: 43.676739, lng: -79.840932 },
-Marker { lat: 43.6298722, lng: -79.874685 },
-Marker { lat: 43.646744, lng: -79.7765 },
-Marker { lat: 43.8793, lng: -79.497 },
-Marker { lat: 43.7048104, lng: -79.3754804 },
-Marker { lat: 43.8610201, lng: -79.5048112 },
-Marker { lat: 43.5283457, lng: -79.8722088 },
-Marker { lat: 43.796161, lng: -79.605003 },
-Marker { lat: 43.6357531, lng: -79.5583607 },
-Marker { lat: 43.668718, lng: -79.383996 },
-Marker { lat: 43.6370159, lng: -79.4055772 },
-Marker { lat: 43.8406266, lng: -79.4761388 },
-Marker { lat: 43.5850975, lng: -79.6461541 },
-Marker { lat: 43.8881614, lng: -79.2517614 },
-Marker { lat: 43.5736606, lng: -79.7421471 },
-Marker { lat: 43.5578908, lng: -79.5968329 },
-Marker { lat: 43.4916329, lng: -79.7126199 },
-Marker { lat: 43.9018569, lng: -79.2619175 },
-Marker { lat: 43.7510166, lng: -79.4585669 },
-Marker { lat: 43.7312268, lng: -79.2128747 },
-Marker { lat: 43.8455445, lng: -79.566648 },
-Marker { lat: 43.7281765, lng: -79.4978013 },
-Marker { lat: 43.6051968, lng: -79.4916684 },
-Marker { lat: 43.8397762, lng: -79.0826967 },
-Marker { lat: 43.529165, lng: -79.6020218 },
-Marker { lat: 43.9720517, lng: -78.9459018 },
-Marker { lat: 43.8607977, lng: -79.4611385 },
-Marker { lat: 43.835555, lng: -79.143787 },
-Marker { lat: 43.9383711, lng: -78.8772558 },
-Marker { lat: 43.4720976, lng: -79.7380337 },
-Marker { lat: 43.8299, lng: -79.0846 },
-Marker { lat: 43.9205389, lng: -79.4517833 },
-Marker { lat: 43.614551, lng: -79.625756 },
-Marker { lat: 43.7875625, lng: -79.2817031 },
-Marker { lat: 43.547418, lng: -79.606736 },
-Marker { lat: 43.7576116, lng: -79.3993409 },
-Marker { lat: 43.8065647, lng: -79.5567363 },
-Marker { lat: 43.7676486, lng: -79.3821279 },
-Marker { lat: 43.3456036, lng: -79.7878776 },
-Marker { lat: 43.6729857, lng: -79.3870289 },
-Marker { lat: 43.62187, lng: -79.521539 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.7107075, lng: -79.4014504 },
-Marker { lat: 43.743891, lng: -79.837694 },
-Marker { lat: 43.773344, lng: -79.256149 },
-Marker { lat: 43.640566, lng: -79.562739 },
-Marker { lat: 43.4839109, lng: -79.7121523 },
-Marker { lat: 43.8504455, lng: -79.4202134 },
-Marker { lat: 43.9689155, lng: -78.9440049 },
-Marker { lat: 43.7078271, lng: -79.4583231 },
-Marker { lat: 43.5552474, lng: -79.5785842 },
-Marker { lat: 43.508158, lng: -79.698695 },
-Marker { lat: 43.7832853, lng: -79.5785927 },
-Marker { lat: 43.8525295, lng: -79.4925632 },
-Marker { lat: 43.7822014, lng: -79.4381119 },
-Marker { lat: 43.77934, lng: -79.3965307 },
-Marker { lat: 43.48762, lng: -79.733907 },
-Marker { lat: 43.6698726, lng: -79.3136327 },
-Marker { lat: 43.8617383, lng: -79.3245463 },
-Marker { lat: 43.4780583, lng: -79.7460748 },
-Marker { lat: 43.6824808, lng: -79.8438196 },
-Marker { lat: 43.6238205, lng: -79.480348 },
-Marker { lat: 43.7967631, lng: -79.2219676 },
-Marker { lat: 43.8267588, lng: -79.4048856 },
-Marker { lat: 43.6456952, lng: -79.3761433 },
-Marker { lat: 43.4275305, lng: -79.7685313 },
-Marker { lat: 43.5661077, lng: -79.6477661 },
-Marker { lat: 43.6518091, lng: -79.7507065 },
-Marker { lat: 43.8823131, lng: -79.4193025 },
-Marker { lat: 43.834659, lng: -78.971726 },
-Marker { lat: 43.8322481, lng: -79.4734877 },
-Marker { lat: 43.5137676, lng: -79.8528153 },
-Marker { lat: 43.641184, lng: -79.421498 },
-Marker { lat: 43.7862519, lng: -79.4269358 },
-Marker { lat: 43.3454689, lng: -79.7849431 },
-Marker { lat: 43.7690784, lng: -79.3694836 },
-Marker { lat: 43.811983, lng: -79.456717 },
-Marker { lat: 43.8695472, lng: -79.0727582 },
-Marker { lat: 43.8372476, lng: -79.626355 },
-Marker { lat: 43.8734769, lng: -78.9575468 },
-Marker { lat: 43.6904251, lng: -79.7692591 },
-Marker { lat: 43.6515293, lng: -79.375757 },
-Marker { lat: 43.7775639, lng: -79.4003595 },
-Marker { lat: 43.7742659, lng: -79.3881742 },
-Marker { lat: 43.624107, lng: -79.498431 },
-Marker { lat: 43.6768374, lng: -79.3120964 },
-Marker { lat: 43.8161716, lng: -79.7303707 },
-Marker { lat: 43.7485912, lng: -79.2158155 },
-Marker { lat: 43.7149233, lng: -79.3904293 },
-Marker { lat: 43.6836783, lng: -79.3903045 },
-Marker { lat: 43.8565448, lng: -79.3244227 },
-Marker { lat: 43.546337, lng: -79.718319 },
-Marker { lat: 43.6873367, lng: -79.2906907 },
-Marker { lat: 43.7803104, lng: -79.2747984 },
-Marker { lat: 43.9428624, lng: -78.8921881 },
-Marker { lat: 43.6732539, lng: -79.2976813 },
-Marker { lat: 43.6502312, lng: -79.5740158 },
-Marker { lat: 43.8083165, lng: -79.4241598 },
-Marker { lat: 43.35434, lng: -79.774555 },
-Marker { lat: 43.8230446, lng: -79.3708367 },
-Marker { lat: 43.94557, lng: -79.535056 },
-Marker { lat: 43.7432076, lng: -79.3477351 },
-Marker { lat: 43.7075677, lng: -79.2464381 },
-Marker { lat: 43.9791725, lng: -79.477182 },
-Marker { lat: 43.437725, lng: -79.7576257 },
-Marker { lat: 43.6747485, lng: -79.3254361 },
-Marker { lat: 43.898278, lng: -79.312644 },
-Marker { lat: 43.6632004, lng: -79.3777681 },
-Marker { lat: 43.8753201, lng: -79.3155889 },
-Marker { lat: 43.624447, lng: -79.4899623 },
-Marker { lat: 43.7986199, lng: -79.3776391 },
-Marker { lat: 43.9680727, lng: -78.9496315 },
-Marker { lat: 43.6813665, lng: -79.8479471 },
-Marker { lat: 43.7183312, lng: -79.5494188 },
-Marker { lat: 43.5168921, lng: -79.8472089 },
-Marker { lat: 43.623426, lng: -79.4789788 },
-Marker { lat: 43.8914831, lng: -78.9299544 },
-Marker { lat: 43.8095352, lng: -79.2618122 },
-Marker { lat: 43.72555, lng: -79.2329632 },
-Marker { lat: 43.8226089, lng: -79.4247113 },
-Marker { lat: 43.8760985, lng: -79.4424802 },
-Marker { lat: 43.9620836, lng: -78.9460546 },
-Marker { lat: 43.9169634, lng: -79.4484388 },
-Marker { lat: 43.5278082, lng: -79.6387021 },
-Marker { lat: 43.7251399, lng: -79.4149855 },
-Marker { lat: 43.9259108, lng: -78.9239283 },
-Marker { lat: 43.7853952, lng: -79.4055754 },
-Marker { lat: 43.70754, lng: -79.25681 },
-Marker { lat: 43.902498, lng: -79.408493 },
-Marker { lat: 43.4367303, lng: -79.6799384 },
-Marker { lat: 43.624099, lng: -79.500743 },
-Marker { lat: 43.6647797, lng: -79.3409446 },
-Marker { lat: 43.774318, lng: -79.1377695 },
-Marker { lat: 43.4951771, lng: -79.6591403 },
-Marker { lat: 43.8917754, lng: -79.0204153 },
-Marker { lat: 43.8138176, lng: -79.4492202 },
-Marker { lat: 43.851993, lng: -79.43178 },
-Marker { lat: 43.7703, lng: -79.413645 },
-Marker { lat: 43.7181989, lng: -79.8163711 },
-Marker { lat: 43.6688211, lng: -79.4142981 },
-Marker { lat: 43.6096951, lng: -79.4954164 },
-Marker { lat: 43.774836, lng: -79.3820774 },
-Marker { lat: 43.77807, lng: -79.4158254 },
-Marker { lat: 43.7904691, lng: -79.4520964 },
-Marker { lat: 43.6397347, lng: -79.3920867 },
-Marker { lat: 43.8832924, lng: -79.3052671 },
-Marker { lat: 43.8429224, lng: -79.5721246 },
-Marker { lat: 43.773403, lng: -79.344636 },
-Marker { lat: 43.8371365, lng: -79.4489832 },
-Marker { lat: 43.8458638, lng: -79.4264298 },
-Marker { lat: 43.641087, lng: -79.381405 },
-Marker { lat: 43.6095757, lng: -79.5573689 },
-Marker { lat: 43.715384, lng: -79.454548 },
-Marker { lat: 43.774071, lng: -79.329324 },
-Marker { lat: 43.646434, lng: -79.397806 },
-Marker { lat: 43.7563401, lng: -79.4056932 },
-Marker { lat: 43.657547, lng: -79.401464 },
-Marker { lat: 43.769664, lng: -79.413783 },
-Marker { lat: 43.641806, lng: -79.378981 },
-Marker { lat: 43.6611024, lng: -79.3819277 },
-Marker { lat: 43.7651693, lng: -79.3849692 },
-Marker { lat: 43.3689655, lng: -79.8271665 },
-Marker { lat: 43.6870787, lng: -79.3117348 },
-Marker { lat: 43.668578, lng: -79.384479 },
-Marker { lat: 43.641071, lng: -79.400252 },
-Marker { lat: 43.950907, lng: -78.957545 },
-Marker { lat: 43.6613016, lng: -79.3783274 },
-Marker { lat: 43.4392479, lng: -79.6775626 },
-Marker { lat: 43.374053, lng: -79.721288 },
-Marker { lat: 43.646099, lng: -79.365136 },
-Marker { lat: 43.7725548, lng: -79.1737217 },
-Marker { lat: 43.861726, lng: -79.435841 },
-Marker { lat: 43.4438914, lng: -79.728737 },
-Marker { lat: 43.905601, lng: -79.3747098 },
-Marker { lat: 43.780046, lng: -79.165129 },
-Marker { lat: 43.74953, lng: -79.407192 },
-Marker { lat: 43.663633, lng: -79.3408258 },
-Marker { lat: 43.7582408, lng: -79.409106 },
-Marker { lat: 43.6243612, lng: -79.5154297 },
-Marker { lat: 43.8974806, lng: -79.2174292 },
-Marker { lat: 43.677266, lng: -79.406148 },
-Marker { lat: 43.3589506, lng: -79.7792242 },
-Marker { lat: 43.58838, lng: -79.639953 },
-Marker { lat: 43.5212296, lng: -79.6777909 },
-Marker { lat: 43.7801798, lng: -79.3875639 },
-Marker { lat: 43.7876995, lng: -79.4169893 },
-Marker { lat: 43.652227, lng: -79.398262 },
-Marker { lat: 43.801019, lng: -79.275308 },
-Marker { lat: 43.8521474, lng: -78.9541848 },
-Marker { lat: 43.839914, lng: -79.009394 },
-Marker { lat: 43.752415, lng: -79.265816 },
-Marker { lat: 43.5800439, lng: -79.6250026 },
-Marker { lat: 43.7629803, lng: -79.7123395 },
-Marker { lat: 43.755609, lng: -79.580115 },
-Marker { lat: 43.7103469, lng: -79.811198 },
-Marker { lat: 43.4670299, lng: -79.6922162 },
-Marker { lat: 43.675773, lng: -79.8164 },
-Marker { lat: 43.7033263, lng: -79.7523173 },
-Marker { lat: 43.930221, lng: -79.945537 },
-Marker { lat: 43.6059394, lng: -79.7197953 },
-Marker { lat: 43.7205173, lng: -79.258877 },
-Marker { lat: 43.8749965, lng: -79.0929031 },
-Marker { lat: 43.8593677, lng: -78.9319517 },
-Marker { lat: 43.516021, lng: -79.883669 },
-Marker { lat: 43.4895951, lng: -79.8575519 },
-Marker { lat: 43.7422702, lng: -79.2144328 },
-Marker { lat: 43.9068881, lng: -79.4525615 },
-Marker { lat: 43.8546835, lng: -79.5647692 },
-Marker { lat: 43.9401497, lng: -79.44315 },
-Marker { lat: 43.748882, lng: -79.778978 },
-Marker { lat: 43.5699925, lng: -79.7186185 },
-Marker { lat: 43.599252, lng: -79.726065 },
-Marker { lat: 43.8053306, lng: -79.471356 },
-Marker { lat: 43.7278613, lng: -79.7999345 },
-Marker { lat: 43.7307332, lng: -79.7150731 },
-Marker { lat: 43.8280706, lng: -79.6550618 },
-Marker { lat: 43.783076, lng: -79.664058 },
-Marker { lat: 43.8999739, lng: -79.2708322 },
-Marker { lat: 43.3470034, lng: -79.8434911 },
-Marker { lat: 43.876971, lng: -79.046927 },
-Marker { lat: 43.886835, lng: -79.01002 },
-Marker { lat: 43.8772397, lng: -78.9520659 },
-Marker { lat: 43.730701, lng: -79.465717 },
-Marker { lat: 43.9328078, lng: -79.5315807 },
-Marker { lat: 43.8533097, lng: -79.4897184 },
-Marker { lat: 43.8941, lng: -79.223255 },
-Marker { lat: 43.8496444, lng: -79.427085 },
-Marker { lat: 43.950363, lng: -79.422041 },
-Marker { lat: 43.6790517, lng: -79.8391641 },
-Marker { lat: 43.8867735, lng: -79.3091005 },
-Marker { lat: 43.5333861, lng: -79.6874112 },
-Marker { lat: 43.641755, lng: -79.3796991 },
-Marker { lat: 43.7050382, lng: -79.2558003 },
-Marker { lat: 43.5410791, lng: -79.6974539 },
-Marker { lat: 43.4523098, lng: -79.7550578 },
-Marker { lat: 43.5571607, lng: -79.7071213 },
-Marker { lat: 43.655787, lng: -79.571363 },
-Marker { lat: 43.5487148, lng: -79.6084415 },
-Marker { lat: 43.7456661, lng: -79.5043085 },
-Marker { lat: 43.7042112, lng: -79.2628597 },
-Marker { lat: 43.7537831, lng: -79.4604084 },
-Marker { lat: 43.768501, lng: -79.381285 },
-Marker { lat: 43.6059202, lng: -79.5059646 },
-Marker { lat: 43.4966101, lng: -79.6587456 },
-Marker { lat: 43.6590117, lng: -79.4083564 },
-Marker { lat: 43.6886608, lng: -79.4402012 },
-Marker { lat: 43.9586472, lng: -79.4620626 },
-Marker { lat: 43.8498302, lng: -79.4462554 },
-Marker { lat: 43.9685708, lng: -79.2532014 },
-Marker { lat: 43.8877383, lng: -79.253831 },
-Marker { lat: 43.8793, lng: -79.497 },
-Marker { lat: 43.7853599, lng: -79.4347692 },
-Marker { lat: 43.6012751, lng: -79.6043883 },
-Marker { lat: 43.9324161, lng: -79.0301361 },
-Marker { lat: 43.977099, lng: -79.47279 },
-Marker { lat: 43.7934043, lng: -79.2921908 },
-Marker { lat: 43.563756, lng: -79.6581419 },
-Marker { lat: 43.7441938, lng: -79.5917954 },
-Marker { lat: 43.5128657, lng: -79.881369 },
-Marker { lat: 43.771579, lng: -79.407679 },
-Marker { lat: 43.7860942, lng: -79.6074802 },
-Marker { lat: 43.7210459, lng: -79.6172923 },
-Marker { lat: 43.3743271, lng: -79.8315139 },
-Marker { lat: 43.7735386, lng: -79.4229947 },
-Marker { lat: 43.7766433, lng: -79.1709156 },
-Marker { lat: 43.7141509, lng: -79.5437061 },
-Marker { lat: 43.8687263, lng: -79.327166 },
-Marker { lat: 43.372258, lng: -79.7262846 },
-Marker { lat: 43.8479666, lng: -79.4509023 },
-Marker { lat: 43.8790744, lng: -79.4627713 },
-Marker { lat: 43.669963, lng: -79.386158 },
-Marker { lat: 43.8818797, lng: -79.4140734 },
-Marker { lat: 43.7604152, lng: -79.1987529 },
-Marker { lat: 43.9249401, lng: -79.5353078 },
-Marker { lat: 43.3812924, lng: -79.815799 },
-Marker { lat: 43.960115, lng: -78.9069897 },
-Marker { lat: 43.647627, lng: -79.390408 },
-Marker { lat: 43.7510289, lng: -79.230392 },
-Marker { lat: 43.9578487, lng: -78.8981354 },
-Marker { lat: 43.9604863, lng: -78.8990264 },
-Marker { lat: 43.6871554, lng: -79.8001963 },
-Marker { lat: 43.7520037, lng: -79.3971086 },
-Marker { lat: 43.900013, lng: -79.879856 },
-Marker { lat: 43.8997415, lng: -79.4583838 },
-Marker { lat: 43.3645551, lng: -79.82865 },
-Marker { lat: 43.8956727, lng: -79.4562795 },
-Marker { lat: 43.532417, lng: -79.847735 },
-Marker { lat: 43.8062229, lng: -79.3232223 },
-Marker { lat: 43.6520334, lng: -79.4117239 },
-Marker { lat: 43.6839, lng: -79.4824 },
-Marker { lat: 43.875625, lng: -79.068573 },
-Marker { lat: 43.623821, lng: -79.478728 },
-Marker { lat: 43.6404408, lng: -79.3971889 },
-Marker { lat: 43.642791, lng: -79.407195 },
-Marker { lat: 43.778264, lng: -79.415454 },
-Marker { lat: 43.7668827, lng: -79.4636396 },
-Marker { lat: 43.5348232, lng: -79.8602292 },
-Marker { lat: 43.7696984, lng: -79.4206676 },
-Marker { lat: 43.9019066, lng: -79.2483409 },
-Marker { lat: 43.5956797, lng: -79.6347654 },
-Marker { lat: 43.812797, lng: -79.227691 },
-Marker { lat: 43.5754399, lng: -79.7461808 },
-Marker { lat: 43.8534923, lng: -79.4031472 },
-Marker { lat: 43.5970586, lng: -79.6522565 },
-Marker { lat: 43.8845453, lng: -78.9753266 },
-Marker { lat: 43.4169515, lng: -79.7111417 },
-Marker { lat: 43.6811127, lng: -79.3413528 },
-Marker { lat: 43.691369, lng: -79.5064889 },
-Marker { lat: 43.6939362, lng: -79.3805935 },
-Marker { lat: 43.9225131, lng: -78.9171924 },
-Marker { lat: 43.6702175, lng: -79.3951083 },
-Marker { lat: 43.6761846, lng: -79.343525 },
-Marker { lat: 43.623689, lng: -79.513324 },
-Marker { lat: 43.649568, lng: -79.5265828 },
-Marker { lat: 43.7595855, lng: -79.4150269 },
-Marker { lat: 43.8685365, lng: -79.4347893 },
-Marker { lat: 43.9583844, lng: -79.4657172 },
-Marker { lat: 43.4245317, lng: -79.7021289 },
-Marker { lat: 43.7797258, lng: -79.3897198 },
-Marker { lat: 43.8442866, lng: -79.6600134 },
-Marker { lat: 43.6343544, lng: -79.5662371 },
-Marker { lat: 43.851507, lng: -79.488741 },
-Marker { lat: 43.5280324, lng: -79.6710379 },
-Marker { lat: 43.5777625, lng: -79.7779694 },
-Marker { lat: 43.4508518, lng: -79.7052387 },
-Marker { lat: 43.662771, lng: -79.7702282 },
-Marker { lat: 43.7714408, lng: -79.4042794 },
-Marker { lat: 43.960774, lng: -79.442791 },
-Marker { lat: 43.7443856, lng: -79.3858861 },
-Marker { lat: 43.6717727, lng: -79.3947803 },
-Marker { lat: 43.5178359, lng: -79.8481703 },
-Marker { lat: 43.7013448, lng: -79.3985783 },
-Marker { lat: 43.541743, lng: -79.631656 },
-Marker { lat: 43.7343824, lng: -79.4133764 },
-Marker { lat: 43.4286025, lng: -79.7811407 },
-Marker { lat: 43.6978659, lng: -79.4194288 },
-Marker { lat: 43.568429, lng: -79.5627667 },
-Marker { lat: 43.6563373, lng: -79.3601266 },
-Marker { lat: 43.5756512, lng: -79.5751698 },
-Marker { lat: 43.806124, lng: -79.4475591 },
-Marker { lat: 43.4686756, lng: -79.662597 },
-Marker { lat: 43.481729, lng: -79.716193 },
-Marker { lat: 43.843616, lng: -79.466321 },
-Marker { lat: 43.8877763, lng: -79.4135376 },
-Marker { lat: 43.7115169, lng: -79.3859306 },
-Marker { lat: 43.798226, lng: -79.4895872 },
-Marker { lat: 43.7354447, lng: -79.3598577 },
-Marker { lat: 43.8885665, lng: -79.4656243 },
-Marker { lat: 43.879004, lng: -79.2593779 },
-Marker { lat: 43.3428625, lng: -79.8847813 },
-Marker { lat: 43.8334075, lng: -79.2951873 },
-Marker { lat: 43.3568458, lng: -79.7504501 },
-Marker { lat: 43.7629235, lng: -79.7477789 },
-Marker { lat: 43.8811694, lng: -79.3010955 },
-Marker { lat: 43.8892638, lng: -79.3035985 },
-Marker { lat: 43.8427494, lng: -79.4713251 },
-Marker { lat: 43.3888917, lng: -79.8209863 },
-Marker { lat: 43.3849287, lng: -79.8251997 },
-Marker { lat: 43.6844626, lng: -79.2781424 },
-Marker { lat: 43.7438905, lng: -79.4473636 },
-Marker { lat: 43.544324, lng: -79.596933 },
-Marker { lat: 43.8668446, lng: -79.4866613 },
-Marker { lat: 43.394172, lng: -79.8057302 },
-Marker { lat: 43.8668219, lng: -79.4706817 },
-Marker { lat: 43.8566023, lng: -79.4258009 },
-Marker { lat: 43.6084573, lng: -79.4958318 },
-Marker { lat: 43.7986831, lng: -79.3682244 },
-Marker { lat: 43.8889797, lng: -79.4986652 },
-Marker { lat: 43.891995, lng: -79.4176745 },
-Marker { lat: 43.6514, lng: -79.9219 },
-Marker { lat: 43.4285025, lng: -79.7797741 },
-Marker { lat: 43.4863416, lng: -79.7347333 },
-Marker { lat: 43.4584021, lng: -79.654951 },
-Marker { lat: 43.5883905, lng: -79.7781668 },
-Marker { lat: 43.868954, lng: -79.028324 },
-Marker { lat: 43.7946201, lng: -79.3368258 },
-Marker { lat: 43.7722796, lng: -79.4044473 },
-Marker { lat: 43.7877841, lng: -79.5881691 },
-Marker { lat: 43.8751623, lng: -78.904747 },
-Marker { lat: 43.7073343, lng: -79.3774564 },
-Marker { lat: 43.7070855, lng: -79.3977452 },
-Marker { lat: 43.774172, lng: -79.417642 },
-Marker { lat: 43.665388, lng: -79.3880211 },
-Marker { lat: 43.6627217, lng: -79.3794411 },
-Marker { lat: 43.550574, lng: -79.6752647 },
-Marker { lat: 43.6798859, lng: -79.4955979 },
-Marker { lat: 43.6429592, lng: -79.4306846 },
-Marker { lat: 43.4845793, lng: -79.8642981 },
-Marker { lat: 43.6593823, lng: -79.5261695 },
-Marker { lat: 43.6402719, lng: -79.4267695 },
-Marker { lat: 43.9805499, lng: -79.2546783 },
-Marker { lat: 43.5764556, lng: -79.7800494 },
-Marker { lat: 43.916173, lng: -79.446721 },
-Marker { lat: 43.7243375, lng: -79.7915804 },
-Marker { lat: 43.656511, lng: -79.919248 },
-Marker { lat: 43.8699797, lng: -79.4685984 },
-Marker { lat: 43.9601179, lng: -78.9044029 },
-Marker { lat: 43.4944277, lng: -79.6577959 },
-Marker { lat: 43.7083238, lng: -79.7675156 },
-Marker { lat: 43.8491445, lng: -79.4282183 },
-Marker { lat: 43.706847, lng: -79.831502 },
-Marker { lat: 43.8550823, lng: -79.4496876 },
-Marker { lat: 43.573522, lng: -79.700576 },
-Marker { lat: 43.824465, lng: -79.034618 },
-Marker { lat: 43.9175254, lng: -78.9085546 },
-Marker { lat: 43.66854, lng: -79.728519 },
-Marker { lat: 43.6984367, lng: -79.7694257 },
-Marker { lat: 43.7386, lng: -79.8437 },
-Marker { lat: 43.8496423, lng: -78.9469498 },
-Marker { lat: 43.7948651, lng: -79.3340779 },
-Marker { lat: 43.3903412, lng: -79.8040622 },
-Marker { lat: 43.833747, lng: -79.010533 },
-Marker { lat: 43.796702, lng: -79.414832 },
-Marker { lat: 43.7066441, lng: -79.4271318 },
-Marker { lat: 43.6400653, lng: -79.3784948 },
-Marker { lat: 43.7408676, lng: -79.2388813 },
-Marker { lat: 43.8825419, lng: -78.9575601 },
-Marker { lat: 43.4025352, lng: -79.8176559 },
-Marker { lat: 43.5194261, lng: -79.8420069 },
-Marker { lat: 43.9852307, lng: -79.4338935 },
-Marker { lat: 43.7261019, lng: -79.4291345 },
-Marker { lat: 43.3477301, lng: -79.8485723 },
-Marker { lat: 43.6712941, lng: -79.384925 },
-Marker { lat: 43.591682, lng: -79.628015 },
-Marker { lat: 43.7811337, lng: -79.3940885 },
-Marker { lat: 43.5370577, lng: -79.6900371 },
-Marker { lat: 43.5129542, lng: -79.6564577 },
-Marker { lat: 43.7914578, lng: -79.3475704 },
-Marker { lat: 43.6461536, lng: -79.3752086 },
-Marker { lat: 43.3682931, lng: -79.8411427 },
-Marker { lat: 43.8670605, lng: -79.4325556 },
-Marker { lat: 43.435599, lng: -79.7726 },
-Marker { lat: 43.7697784, lng: -79.3575716 },
-Marker { lat: 43.6653207, lng: -79.7298217 },
-Marker { lat: 43.7528565, lng: -79.4417461 },
-Marker { lat: 43.7234947, lng: -79.391112 },
-Marker { lat: 43.8363787, lng: -79.2753966 },
-Marker { lat: 43.862199, lng: -79.031909 },
-Marker { lat: 43.7133145, lng: -79.7897426 },
-Marker { lat: 43.6431916, lng: -79.7593568 },
-Marker { lat: 43.67758, lng: -79.5469374 },
-Marker { lat: 43.7728259, lng: -79.7312914 },
-Marker { lat: 43.691884, lng: -79.3211607 },
-Marker { lat: 43.5033402, lng: -79.6479152 },
-Marker { lat: 43.7694183, lng: -79.3686931 },
-Marker { lat: 43.6735187, lng: -79.8099245 },
-Marker { lat: 43.731194, lng: -79.3345701 },
-Marker { lat: 43.5749676, lng: -79.7702258 },
-Marker { lat: 43.7042912, lng: -79.762722 },
-Marker { lat: 43.808657, lng: -79.414176 },
-Marker { lat: 43.6057987, lng: -79.6546773 },
-Marker { lat: 43.5311829, lng: -79.639121 },
-Marker { lat: 43.626067, lng: -79.4906182 },
-Marker { lat: 43.6306135, lng: -79.5160791 },
-Marker { lat: 43.774071, lng: -79.329324 },
-Marker { lat: 43.8563501, lng: -79.5464122 },
-Marker { lat: 43.852179, lng: -79.5543795 },
-Marker { lat: 43.649067, lng: -79.37693 },
-Marker { lat: 43.7437412, lng: -79.8057046 },
-Marker { lat: 43.7012876, lng: -79.7676478 },
-Marker { lat: 43.7720245, lng: -79.6649655 },
-Marker { lat: 43.8080559, lng: -79.4553335 },
-Marker { lat: 43.7742702, lng: -79.3933826 },
-Marker { lat: 43.820784, lng: -79.213414 },
-Marker { lat: 43.894645, lng: -79.0099666 },
-Marker { lat: 43.6335031, lng: -79.3999789 },
-Marker { lat: 43.5927146, lng: -79.5231259 },
-Marker { lat: 43.7412897, lng: -79.3950178 },
-Marker { lat: 43.642422, lng: -79.3755829 },
-Marker { lat: 43.947142, lng: -79.837321 },
-Marker { lat: 43.9148656, lng: -78.9519058 },
-Marker { lat: 43.857251, lng: -79.124959 },
-Marker { lat: 43.656574, lng: -79.3683276 },
-Marker { lat: 43.88937, lng: -79.046047 },
-Marker { lat: 43.781616, lng: -79.572502 },
-Marker { lat: 43.933882, lng: -79.4747558 },
-Marker { lat: 43.8466555, lng: -79.5335555 },
-Marker { lat: 43.5579065, lng: -79.7336717 },
-Marker { lat: 43.656935, lng: -79.749237 },
-Marker { lat: 43.4402456, lng: -79.7333922 },
-Marker { lat: 43.9018597, lng: -78.9468593 },
-Marker { lat: 43.7747846, lng: -79.2965258 },
-Marker { lat: 43.7259975, lng: -79.2331698 },
-Marker { lat: 43.796519, lng: -79.518865 },
-Marker { lat: 43.876966, lng: -79.029316 },
-Marker { lat: 43.5876825, lng: -79.6283005 },
-Marker { lat: 43.760402, lng: -79.40901 },
-Marker { lat: 43.778211, lng: -79.704172 },
-Marker { lat: 43.6817346, lng: -79.7361996 },
-Marker { lat: 43.5755751, lng: -79.7826072 },
-Marker { lat: 43.7181463, lng: -79.72599 },
-Marker { lat: 43.438753, lng: -79.681103 },
-Marker { lat: 43.532669, lng: -79.8615997 },
-Marker { lat: 43.486401, lng: -79.645967 },
-Marker { lat: 43.5100528, lng: -79.857073 },
-Marker { lat: 43.9665735, lng: -78.9574739 },
-Marker { lat: 43.7280051, lng: -79.7730317 },
-Marker { lat: 43.5886947, lng: -79.6081405 },
-Marker { lat: 43.66187, lng: -79.4101633 },
-Marker { lat: 43.523905, lng: -79.61541 },
-Marker { lat: 43.4678721, lng: -79.6669108 },
-Marker { lat: 43.8857367, lng: -78.9417389 },
-Marker { lat: 43.4935347, lng: -79.8743128 },
-Marker { lat: 43.9086355, lng: -78.941162 },
-Marker { lat: 43.527182, lng: -79.833284 },
-Marker { lat: 43.6488273, lng: -79.3780964 },
-Marker { lat: 43.880161, lng: -79.041147 },
-Marker { lat: 43.887893, lng: -79.011566 },
-Marker { lat: 43.6574768, lng: -79.445671 },
-Marker { lat: 43.6837653, lng: -79.4189514 },
-Marker { lat: 43.6627217, lng: -79.3794411 },
-Marker { lat: 43.659603, lng: -79.384784 },
-Marker { lat: 43.7452139, lng: -79.2079532 },
-Marker { lat: 43.685367, lng: -79.294191 },
-Marker { lat: 43.833383, lng: -79.003937 },
-Marker { lat: 43.6391628, lng: -79.4191891 },
-Marker { lat: 43.7939829, lng: -79.4434454 },
-Marker { lat: 43.6563503, lng: -79.4140066 },
-Marker { lat: 43.8136153, lng: -79.3234102 },
-Marker { lat: 43.8535057, lng: -79.5040574 },
-Marker { lat: 43.9551848, lng: -78.8944654 },
-Marker { lat: 43.8813616, lng: -78.9673293 },
-Marker { lat: 43.8412959, lng: -79.3938341 },
-Marker { lat: 43.9767236, lng: -79.4496714 },
-Marker { lat: 43.9071666, lng: -79.2710629 },
-Marker { lat: 43.8505249, lng: -79.3257399 },
-Marker { lat: 43.8129727, lng: -79.4551834 },
-Marker { lat: 43.9375809, lng: -79.4704335 },
-Marker { lat: 43.8836465, lng: -79.4952627 },
-Marker { lat: 43.9006045, lng: -79.4532939 },
-Marker { lat: 43.970816, lng: -79.249128 },
-Marker { lat: 43.720384, lng: -79.603272 },
-Marker { lat: 43.778531, lng: -79.413214 },
-Marker { lat: 43.5168921, lng: -79.8472089 },
-Marker { lat: 43.8667088, lng: -79.438789 },
-Marker { lat: 43.556527, lng: -79.65459 },
-Marker { lat: 43.721733, lng: -79.732107 },
-Marker { lat: 43.6038308, lng: -79.6076207 },
-Marker { lat: 43.7552018, lng: -79.565216 },
-Marker { lat: 43.7786839, lng: -79.6011466 },
-Marker { lat: 43.647915, lng: -79.373848 },
-Marker { lat: 43.6959554, lng: -79.3957179 },
-Marker { lat: 43.6737227, lng: -79.3011219 },
-Marker { lat: 43.7133186, lng: -79.7932594 },
-Marker { lat: 43.8561611, lng: -79.4412115 },
-Marker { lat: 43.6493456, lng: -79.3758022 },
-Marker { lat: 43.7805353, lng: -79.2510063 },
-Marker { lat: 43.6367062, lng: -79.4000807 },
-Marker { lat: 43.5519771, lng: -79.7286804 },
-Marker { lat: 43.696309, lng: -79.7962365 },
-Marker { lat: 43.8363264, lng: -79.2646563 },
-Marker { lat: 43.8383596, lng: -79.512209 },
-Marker { lat: 43.950513, lng: -79.423624 },
-Marker { lat: 43.531039, lng: -79.8812748 },
-Marker { lat: 43.586289, lng: -79.647364 },
-Marker { lat: 43.4930531, lng: -79.7187781 },
-Marker { lat: 43.62372, lng: -79.483703 },
-Marker { lat: 43.895976, lng: -79.014458 },
-Marker { lat: 43.3665775, lng: -79.7566687 },
-Marker { lat: 43.3931614, lng: -79.7884102 },
-Marker { lat: 43.665666, lng: -79.382607 },
-Marker { lat: 43.7907581, lng: -79.4508033 },
-Marker { lat: 43.9392124, lng: -78.8897893 },
-Marker { lat: 43.68381, lng: -79.41879 },
-Marker { lat: 43.6620882, lng: -79.3856802 },
-Marker { lat: 43.963094, lng: -78.905184 },
-Marker { lat: 43.703768, lng: -79.4110697 },
-Marker { lat: 43.7951922, lng: -79.3243815 },
-Marker { lat: 43.8939693, lng: -79.0144907 },
-Marker { lat: 43.8333415, lng: -79.1511721 },
-Marker { lat: 43.8761434, lng: -79.4580667 },
-Marker { lat: 43.8619105, lng: -79.2851671 },
-Marker { lat: 43.8398565, lng: -79.5060058 },
-Marker { lat: 43.9005864, lng: -79.2810194 },
-Marker { lat: 43.625159, lng: -79.47872 },
-Marker { lat: 43.826744, lng: -79.091001 },
-Marker { lat: 43.9642554, lng: -79.2499841 },
-Marker { lat: 43.8416778, lng: -79.5277041 },
-Marker { lat: 43.581136, lng: -79.88297 },
-Marker { lat: 43.8527788, lng: -79.5427656 },
-Marker { lat: 43.854024, lng: -79.465291 },
-Marker { lat: 43.4693383, lng: -79.7093826 },
-Marker { lat: 43.3492611, lng: -79.7699107 },
-Marker { lat: 43.5069139, lng: -79.6432897 },
-Marker { lat: 43.6440838, lng: -79.5286733 },
-Marker { lat: 43.6124062, lng: -79.5406485 },
-Marker { lat: 43.488891, lng: -79.8504324 },
-Marker { lat: 43.5619689, lng: -79.7374941 },
-Marker { lat: 43.721122, lng: -79.501826 },
-Marker { lat: 43.5781155, lng: -79.7251141 },
-Marker { lat: 43.7671412, lng: -79.7490095 },
-Marker { lat: 43.771574, lng: -79.2105057 },
-Marker { lat: 43.9677451, lng: -79.2225009 },
-Marker { lat: 43.7357352, lng: -79.8293255 },
-Marker { lat: 43.870014, lng: -79.4684526 },
-Marker { lat: 43.87886, lng: -79.741036 },
-Marker { lat: 43.6298722, lng: -79.874685 },
-Marker { lat: 43.6527825, lng: -79.3939324 },
-Marker { lat: 43.4296895, lng: -79.7571709 },
-Marker { lat: 43.644683, lng: -79.364087 },
-Marker { lat: 43.3943735, lng: -79.8187557 },
-Marker { lat: 43.7231476, lng: -79.4482263 },
-Marker { lat: 43.5570125, lng: -79.7402594 },
-Marker { lat: 43.4021906, lng: -79.7869305 },
-Marker { lat: 43.606015, lng: -79.654859 },
-Marker { lat: 43.8743, lng: -79.7311 },
-Marker { lat: 43.9000078, lng: -79.4035474 },
-Marker { lat: 43.6592713, lng: -79.3849163 },
-Marker { lat: 43.8884843, lng: -78.9389348 },
-Marker { lat: 43.780324, lng: -79.418647 },
-Marker { lat: 43.7069544, lng: -79.395016 },
-Marker { lat: 43.8668405, lng: -79.3169327 },
-Marker { lat: 43.724239, lng: -79.40255 },
-Marker { lat: 43.588195, lng: -79.78837 },
-Marker { lat: 43.434491, lng: -79.775259 },
-Marker { lat: 43.7423511, lng: -79.3453014 },
-Marker { lat: 43.7234718, lng: -79.4047717 },
-Marker { lat: 43.646084, lng: -79.365012 },
-Marker { lat: 43.7125911, lng: -79.3776798 },
-Marker { lat: 43.7075225, lng: -79.392402 },
-Marker { lat: 43.6982968, lng: -79.4388447 },
-Marker { lat: 43.6383649, lng: -79.4145613 },
-Marker { lat: 43.8991171, lng: -78.9717692 },
-Marker { lat: 43.7128157, lng: -79.8117941 },
-Marker { lat: 43.5888511, lng: -79.7855735 },
-Marker { lat: 43.6871437, lng: -79.5064656 }
+pub static MARKERS: &[(f64, f64)] = &[
+(43.6358644, -79.4673894),
+(43.893691, -78.9528484),
+(43.7003735, -79.82465),
+(43.8090152, -79.5977762),
+(43.5464983, -79.7196848),
+(43.6479257, -79.4843808),
+(43.6479257, -79.4843808),
+(43.58174, -79.7629768),
+(43.6381724, -79.489059),
+(43.7398872, -79.4860122),
+(43.9662864, -79.2731931),
+(43.6383588, -79.558157),
+(43.6787095, -79.7298062),
+(43.5357441, -79.620029),
+(43.6411375, -79.4007447),
+(43.5671142, -79.5720556),
+(43.6362766, -79.7110688),
+(43.5966175, -79.508407),
+(43.7642731, -79.289817),
+(43.6737835, -79.3289639),
+(43.5104756, -79.6378863),
+(43.6824739, -79.7923111),
+(43.7249117, -79.5587716),
+(43.7038398, -79.3972256),
+(43.8515565, -79.4326387),
+(43.7857976, -79.4202892),
+(43.5588904, -79.5748638),
+(43.7894687, -79.6225507),
+(43.8716243, -79.4138112),
+(43.7400255, -79.7567148),
+(43.858393, -79.046034),
+(43.70462, -79.397498),
+(43.7034304, -79.5257417),
+(43.7034304, -79.5257417),
+(43.7329696, -79.3698925),
+(43.76784, -79.647201),
+(43.4379865, -79.7793937),
+(43.7258079, -79.4806067),
+(43.5085523, -79.8447597),
+(43.8028771, -79.6067427),
+(43.7761934, -79.7060079),
+(43.8484721, -79.2516394),
+(43.708728, -79.393175),
+(43.593633, -79.534104),
+(43.5451746, -79.7392907),
+(43.5850975, -79.6461541),
+(43.8611036, -79.3058536),
+(43.6700845, -79.3864005),
+(43.6717424, -79.3878028),
+(43.7893747, -79.5306859),
+(43.7889194, -79.1710875),
+(43.7398872, -79.4860122),
+(43.661967, -79.383964),
+(43.5891322, -79.5665102),
+(43.5397534, -79.6752927),
+(43.4529826, -79.6835772),
+(43.9126435, -79.5224295),
+(43.5875112, -79.5396698),
+(43.817627, -79.526387),
+(43.661641, -79.551529),
+(43.781194, -79.699799),
+(43.8827846, -78.9176518),
+(43.4866305, -79.7203828),
+(43.7307386, -79.4025863),
+(43.770218, -79.752934),
+(43.7404261, -79.767597),
+(43.6467185, -79.5254493),
+(43.7376226, -79.5526474),
+(43.4426282, -79.6723596),
+(43.7780028, -79.5927857),
+(43.4034532, -79.6962786),
+(43.4769, -79.6517),
+(43.450706, -79.6637369),
+(43.6708124, -79.5684146),
+(43.7484654, -79.4288477),
+(43.7424282, -79.3895303),
+(43.9296357, -79.4080208),
+(43.4456204, -79.6707202),
+(43.4456204, -79.6707202),
+(43.7677191, -79.5025131),
+(43.7247312, -79.3794753),
+(43.7488304, -79.3801307),
+(43.644864, -79.39906),
+(43.7466012, -79.3873379),
+(43.603957, -79.6496967),
+(43.3798154, -79.8135987),
+(43.8602656, -79.3345049),
+(43.778779, -79.234277),
+(43.7035233, -79.4964812),
+(43.566313, -79.701798),
+(43.7657237, -79.3834602),
+(43.9291685, -79.5206991),
+(43.6466477, -79.5222686),
+(43.5800439, -79.6250026),
+(43.8902602, -79.4175139),
+(43.724328, -79.457733),
+(43.724328, -79.457733),
+(43.5800439, -79.6250026),
+(43.7853217, -79.481963),
+(43.8823821, -79.3582469),
+(43.4295918, -79.6901089),
+(43.8376, -79.3405),
+(43.8673932, -79.4292426),
+(43.4922466, -79.7213623),
+(43.866389, -79.419764),
+(43.693909, -79.50581),
+(43.6406527, -79.3957762),
+(43.79922, -79.119018),
+(43.712554, -79.406076),
+(43.964052, -79.451905),
+(43.750501, -79.463972),
+(43.7954103, -79.5852631),
+(43.759517, -79.4473645),
+(43.876246, -79.084793),
+(43.979162, -79.605173),
+(43.943104, -79.473689),
+(43.789, -79.5743),
+(43.7100841, -79.7518485),
+(43.9547713, -78.9605599),
+(43.7364608, -79.3795479),
+(43.7031905, -79.4515166),
+(43.3685055, -79.8213968),
+(43.4590762, -79.6629057),
+(43.6928268, -79.3041993),
+(43.7153856, -79.7978277),
+(43.648953, -79.385768),
+(43.965084, -79.469436),
+(43.3462945, -79.879245),
+(43.434491, -79.775259),
+(43.9237996, -79.5189553),
+(43.71814, -79.2352263),
+(43.5128184, -79.8783264),
+(43.8146255, -79.4670506),
+(43.6043786, -79.4938077),
+(43.7550283, -79.3996323),
+(43.8793, -79.497),
+(43.9799805, -79.4441398),
+(43.628177, -79.478717),
+(43.8668718, -79.2754123),
+(43.6083711, -79.5321219),
+(43.892331, -79.041405),
+(43.9226845, -79.4694341),
+(43.606496, -79.655651),
+(43.632067, -79.796738),
+(43.6301998, -79.7788924),
+(43.7392709, -79.3914954),
+(43.637822, -79.392997),
+(43.6484128, -79.3764665),
+(43.588944, -79.6619422),
+(43.5217755, -79.6451295),
+(43.6418261, -79.7263528),
+(43.7600373, -79.5245304),
+(43.761461, -79.409802),
+(43.88, -79.43),
+(43.9241031, -79.5338182),
+(43.474768, -79.746258),
+(43.645505, -79.392457),
+(43.8573143, -79.5642557),
+(43.7366961, -79.8296295),
+(43.5221261, -79.6077384),
+(43.7191625, -79.4923959),
+(43.7439664, -79.49275),
+(43.7810966, -79.4128321),
+(43.850509, -79.0746),
+(43.8442794, -79.5551795),
+(43.712554, -79.406076),
+(43.6404162, -79.5856861),
+(43.7230949, -79.3897365),
+(43.43, -79.7225),
+(43.6385781, -79.3993661),
+(43.665202, -79.55153),
+(43.8696148, -79.2197984),
+(43.857807, -79.081645),
+(43.817274, -79.124216),
+(43.868881, -79.504132),
+(43.675308, -79.280562),
+(43.7990565, -79.2382248),
+(43.834474, -79.581217),
+(43.6438226, -79.5589299),
+(43.6840931, -79.5167347),
+(43.9066239, -79.6447435),
+(43.8220748, -79.1453528),
+(43.6527787, -79.8078842),
+(43.661725, -79.384128),
+(43.7369211, -79.4419226),
+(43.848855, -79.572754),
+(43.7977757, -79.4064701),
+(43.7762122, -79.6061479),
+(43.760304, -79.41162),
+(43.6849299, -79.48394),
+(43.8200813, -79.5661949),
+(43.863327, -79.904064),
+(43.874256, -79.000566),
+(43.9551619, -78.96217),
+(43.7028172, -79.4101737),
+(43.941575, -78.886407),
+(43.7954103, -79.5852631),
+(43.8901316, -79.2713581),
+(43.887557, -78.9132341),
+(43.65429, -79.463097),
+(43.6452883, -79.7318251),
+(43.7530801, -79.5111433),
+(43.6484128, -79.3764665),
+(43.768151, -79.390791),
+(43.6982465, -79.5144527),
+(43.7449277, -79.401526),
+(43.882892, -79.012662),
+(43.83, -79.2096),
+(43.8562518, -79.5438141),
+(43.7657237, -79.3834602),
+(43.7717364, -79.2613815),
+(43.8221254, -79.558882),
+(43.7112057, -79.2879966),
+(43.7080205, -79.4786935),
+(43.8932507, -79.256921),
+(43.5857073, -79.6464202),
+(43.7541274, -79.5013529),
+(43.708965, -79.392985),
+(43.5442626, -79.6680551),
+(43.647699, -79.566622),
+(43.6593521, -79.3856503),
+(43.6616166, -79.3846797),
+(43.6595018, -79.3847849),
+(43.87765, -79.439331),
+(43.4273464, -79.801659),
+(43.6768964, -79.5165707),
+(43.788215, -79.392636),
+(43.567403, -79.590972),
+(43.3911173, -79.896943),
+(43.6976288, -79.5120986),
+(43.697717, -79.51213),
+(43.623821, -79.478728),
+(43.452521, -79.657899),
+(43.6446591, -79.4847665),
+(43.850021, -79.067152),
+(43.84902, -79.066748),
+(43.849856, -79.067957),
+(43.850079, -79.068071),
+(43.849211, -79.066866),
+(43.850277, -79.067298),
+(43.883973, -79.339361),
+(43.837757, -79.689359),
+(43.833614, -79.644529),
+(43.956064, -79.460794),
+(43.7942627, -79.4421239),
+(43.7907823, -79.5571765),
+(43.7325595, -79.8279358),
+(43.9082719, -78.9366993),
+(43.6659675, -79.3871076),
+(43.8772637, -79.0537471),
+(43.7233558, -79.3797211),
+(43.4318359, -79.9302112),
+(43.4712989, -79.6423452),
+(43.5779331, -79.5884547),
+(43.7191965, -79.9329562),
+(43.8762759, -79.2697201),
+(43.4210884, -79.7095968),
+(43.944026, -79.778394),
+(43.6338194, -79.7737747),
+(43.5497304, -79.6706397),
+(43.7515172, -79.8259083),
+(43.8829341, -79.3975317),
+(43.5015311, -79.6941039),
+(43.858247, -79.438127),
+(43.7938753, -79.4157965),
+(43.910163, -79.445996),
+(43.8205905, -79.4237985),
+(43.80208, -79.714026),
+(43.6554446, -79.4260804),
+(43.7953138, -79.3545517),
+(43.640345, -79.563119),
+(43.7931515, -79.6813391),
+(43.925739, -79.5164663),
+(43.662775, -79.411813),
+(43.645107, -79.391052),
+(43.800219, -79.4089761),
+(43.65341, -79.923246),
+(43.874695, -79.440523),
+(43.632425, -79.870069),
+(43.621199, -79.910656),
+(43.7258482, -79.3643509),
+(43.833554, -79.487112),
+(43.6383599, -79.398657),
+(43.625807, -79.477924),
+(43.8446161, -79.5090854),
+(43.983376, -79.380147),
+(43.3512621, -79.8519404),
+(43.867274, -79.4319528),
+(43.7161359, -79.4341751),
+(43.7989466, -79.4149676),
+(43.9095919, -78.9507187),
+(43.816392, -79.103311),
+(43.8619851, -79.4374648),
+(43.8974112, -79.4575046),
+(43.420095, -79.687314),
+(43.8369445, -79.3081149),
+(43.7473316, -79.5539102),
+(43.717992, -79.415278),
+(43.731919, -79.577359),
+(43.43852, -79.753911),
+(43.8753838, -79.407612),
+(43.7560373, -79.5110753),
+(43.7002792, -79.4278103),
+(43.644883, -79.363868),
+(43.851993, -79.43178),
+(43.6307453, -79.7965763),
+(43.4560511, -79.6644791),
+(43.704519, -79.433254),
+(43.667953, -79.293598),
+(43.6506887, -79.5674133),
+(43.8890652, -79.3239364),
+(43.7941547, -79.5143493),
+(43.500402, -79.878087),
+(43.4617205, -79.6455308),
+(43.8228271, -79.3989259),
+(43.7727699, -79.3938121),
+(43.942295, -79.4504192),
+(43.7795678, -79.3542799),
+(43.6436191, -79.8003496),
+(43.4655941, -79.6471301),
+(43.975456, -78.99449),
+(43.8695348, -79.3172869),
+(43.9450774, -79.6560971),
+(43.9107362, -79.2552344),
+(43.6676849, -79.3890196),
+(43.7602923, -79.4154558),
+(43.8447451, -79.4432559),
+(43.8063683, -79.4182548),
+(43.352015, -79.944409),
+(43.95103, -79.4768495),
+(43.7286088, -79.3261749),
+(43.64078, -79.396295),
+(43.9285482, -79.417096),
+(43.6676849, -79.3890196),
+(43.6616609, -79.3536888),
+(43.7844008, -79.288555),
+(43.8246179, -79.4327452),
+(43.843866, -79.431036),
+(43.7740292, -79.3984688),
+(43.4549918, -79.6628853),
+(43.6054405, -79.5401977),
+(43.665786, -79.3855809),
+(43.6414946, -79.8052683),
+(43.819975, -79.380895),
+(43.939768, -79.466367),
+(43.9734, -79.802179),
+(43.7247033, -79.307678),
+(43.766028, -79.1979422),
+(43.8425836, -79.0913144),
+(43.6774655, -79.8339673),
+(43.4196107, -79.7061283),
+(43.8552424, -79.4116306),
+(43.672821, -79.2823433),
+(43.4366336, -79.6783318),
+(43.8627984, -79.4651037),
+(43.8140888, -79.4372378),
+(43.6549162, -79.379287),
+(43.8075577, -79.379943),
+(43.6490838, -79.392082),
+(43.696135, -79.403471),
+(43.7524996, -79.3325441),
+(43.8713481, -79.4377905),
+(43.7887037, -79.4370217),
+(43.62526, -79.477903),
+(43.789718, -79.274999),
+(43.778008, -79.4527173),
+(43.8159152, -79.3984355),
+(43.7475418, -79.4356373),
+(43.5901567, -79.5377688),
+(43.63499, -79.4937258),
+(43.8135782, -79.6214215),
+(43.8760975, -79.4796529),
+(43.8571799, -79.3261757),
+(43.6041158, -79.6183248),
+(43.7761811, -79.3621877),
+(43.8555461, -79.4626471),
+(43.7976995, -79.4004139),
+(43.6024451, -79.4953553),
+(43.6397347, -79.3920867),
+(43.5292779, -79.8657555),
+(43.652331, -79.356014),
+(43.7940884, -79.6683269),
+(43.6985318, -79.7919938),
+(43.7690784, -79.3694836),
+(43.487834, -79.7316009),
+(43.651872, -79.883),
+(43.4486419, -79.6674981),
+(43.448688, -79.667467),
+(43.885839, -79.3145671),
+(43.7814069, -79.5763573),
+(43.908177, -79.375176),
+(43.5584535, -79.6126917),
+(43.939828, -79.466651),
+(43.9702083, -79.4898684),
+(43.632905, -79.928671),
+(43.6926243, -79.4563179),
+(43.7769633, -79.3711861),
+(43.7897445, -79.151341),
+(43.8742771, -79.0385196),
+(43.868772, -79.393233),
+(43.8000938, -79.474316),
+(43.8512896, -79.5119295),
+(43.853766, -79.5633173),
+(43.7287555, -79.2492934),
+(43.5638496, -79.576553),
+(43.9707739, -79.4412916),
+(43.809992, -79.561354),
+(43.835263, -79.6256733),
+(43.7202729, -79.7142161),
+(43.7951102, -79.2391742),
+(43.892394, -79.213701),
+(43.843866, -79.431036),
+(43.7850657, -79.7206633),
+(43.8068655, -79.7143324),
+(43.8946325, -79.377817),
+(43.7763249, -79.4140326),
+(43.8083698, -79.4116091),
+(43.4410466, -79.7259838),
+(43.9218516, -79.5248497),
+(43.903741, -78.956544),
+(43.589184, -79.6543144),
+(43.7949531, -79.393571),
+(43.9622131, -79.4416564),
+(43.983629, -79.468687),
+(43.801904, -79.3009306),
+(43.6026495, -79.5925375),
+(43.606173, -79.655629),
+(43.755457, -79.454193),
+(43.583364, -79.71222),
+(43.7937989, -79.280506),
+(43.9528351, -79.3857072),
+(43.7368681, -79.3491592),
+(43.8104341, -79.3808711),
+(43.665724, -79.447415),
+(43.881403, -79.219835),
+(43.6807098, -79.3894335),
+(43.8694674, -79.3272055),
+(43.8743171, -79.3303704),
+(43.6968333, -79.3939934),
+(43.7031907, -79.5136749),
+(43.659661, -79.780012),
+(43.5990981, -79.6743268),
+(43.6707526, -79.4615102),
+(43.863707, -79.514399),
+(43.4889496, -79.8503825),
+(43.64182, -79.381734),
+(43.434214, -79.776184),
+(43.862518, -79.016156),
+(43.9010978, -78.9756096),
+(43.84354, -79.466315),
+(43.4381875, -79.9412275),
+(43.7137, -79.7913883),
+(43.7787489, -79.4171422),
+(43.780228, -79.418123),
+(43.718287, -79.830654),
+(43.4792273, -79.6545708),
+(43.7843486, -79.3971592),
+(43.4271323, -79.6964378),
+(43.496354, -79.719763),
+(43.569919, -79.5937118),
+(43.4833156, -79.7298005),
+(43.765581, -79.412587),
+(43.3563593, -79.7638927),
+(43.4787969, -79.75987),
+(43.9711, -79.2461),
+(43.8565997, -79.4836714),
+(43.656165, -79.383893),
+(43.7400831, -79.344693),
+(43.7171038, -79.2800195),
+(43.960441, -78.959294),
+(43.412108, -79.801877),
+(43.903929, -79.401161),
+(43.4418243, -79.7607598),
+(43.641596, -79.423443),
+(43.641966, -79.38174),
+(43.7575473, -79.4067503),
+(43.731548, -79.762418),
+(43.944339, -79.455992),
+(43.852115, -79.10122),
+(43.961678, -79.4372405),
+(43.961678, -79.4372405),
+(43.649067, -79.37693),
+(43.5304905, -79.8695173),
+(43.660137, -79.385869),
+(43.6200221, -79.5967888),
+(43.638608, -79.413352),
+(43.523063, -79.835957),
+(43.3830024, -79.8027324),
+(43.4678256, -79.6471677),
+(43.4790773, -79.653967),
+(43.6853077, -79.552673),
+(43.594365, -79.6682848),
+(43.8729042, -79.4275302),
+(43.668718, -79.383996),
+(43.663592, -79.38683),
+(43.8534942, -79.4653801),
+(43.3726009, -79.7529051),
+(43.6664221, -79.5560937),
+(43.377245, -79.8359689),
+(43.873693, -79.062149),
+(43.3939474, -79.8279672),
+(43.760559, -79.4080879),
+(43.692288, -79.860371),
+(43.64534, -79.402185),
+(43.518299, -79.877404),
+(43.758585, -79.406534),
+(43.4293, -79.782108),
+(43.8865437, -79.4589935),
+(43.659964, -79.451591),
+(43.4552599, -79.6680814),
+(43.588525, -79.67628),
+(43.4014816, -79.7051778),
+(43.447443, -79.686948),
+(43.6374676, -79.4184499),
+(43.381104, -79.731903),
+(43.624595, -79.480119),
+(43.4366336, -79.6783318),
+(43.624595, -79.480119),
+(43.641087, -79.381405),
+(43.664042, -79.387166),
+(43.7058847, -79.2744219),
+(43.647651, -79.401518),
+(43.7774165, -79.2812115),
+(43.655305, -79.3537502),
+(43.6652675, -79.3410336),
+(43.6503521, -79.3609928),
+(43.6294481, -79.7978252),
+(43.8518098, -79.4302547),
+(43.4973072, -79.691856),
+(43.862336, -79.435736),
+(43.8294967, -79.2362866),
+(43.7758867, -79.599052),
+(43.6018706, -79.6571194),
+(43.4033178, -79.6945961),
+(43.8541173, -79.3409476),
+(43.977482, -79.232725),
+(43.780228, -79.418123),
+(43.7300903, -79.3322682),
+(43.7307812, -79.3353886),
+(43.66295, -79.3863069),
+(43.6480159, -79.3968889),
+(43.8035, -79.3543771),
+(43.7578524, -79.4130365),
+(43.7734697, -79.2536323),
+(43.8564417, -79.3251868),
+(43.6784907, -79.2988979),
+(43.6559939, -79.4669626),
+(43.783315, -79.578869),
+(43.7094565, -79.4335822),
+(43.6593726, -79.3825439),
+(43.7242482, -79.6162362),
+(43.678607, -79.8011632),
+(43.8529504, -79.0561635),
+(43.792606, -79.415067),
+(43.8874316, -79.3074869),
+(43.792314, -79.449052),
+(43.6853301, -79.4349605),
+(43.491504, -79.7202492),
+(43.3581324, -79.8350093),
+(43.5805107, -79.6244991),
+(43.7739436, -79.3452463),
+(43.7069544, -79.395016),
+(43.6459385, -79.7340171),
+(43.590285, -79.652689),
+(43.6369656, -79.7550544),
+(43.622729, -79.48319),
+(43.7180254, -79.4013513),
+(43.7698862, -79.4135354),
+(43.7793201, -79.2814437),
+(43.6930009, -79.8032856),
+(43.549821, -79.614806),
+(43.4837182, -79.7328219),
+(43.6185387, -79.4850993),
+(43.8029581, -79.2718367),
+(43.9538565, -79.4613649),
+(43.809115, -79.423348),
+(43.863769, -79.4308691),
+(43.9193502, -79.5246262),
+(43.637727, -79.582466),
+(43.721178, -79.741324),
+(43.6881587, -79.7858857),
+(43.639593, -79.563672),
+(43.5516727, -79.7135208),
+(43.4256549, -79.774697),
+(43.4230246, -79.7598708),
+(43.506948, -79.813279),
+(43.90919, -79.636193),
+(43.8346732, -79.4391956),
+(43.5921211, -79.6841365),
+(43.888228, -79.465994),
+(43.7400855, -79.3848074),
+(43.871856, -79.113531),
+(43.957065, -78.971797),
+(43.3718156, -79.7639519),
+(43.5963161, -79.7667906),
+(43.7024449, -79.8563285),
+(43.5286325, -79.6569117),
+(43.7311487, -79.7872672),
+(43.9387658, -79.4337945),
+(43.7803254, -79.2897587),
+(43.78323, -79.2988415),
+(43.8335678, -79.5750104),
+(43.858601, -79.504814),
+(43.8588994, -79.2863437),
+(43.979692, -79.281561),
+(43.9693647, -79.2671987),
+(43.7308993, -79.4180256),
+(43.4958948, -79.6634856),
+(43.6312825, -79.8179249),
+(43.96976, -79.5130729),
+(43.7676486, -79.3821279),
+(43.8609613, -79.4683922),
+(43.664773, -79.386987),
+(43.6397205, -79.384172),
+(43.5311699, -79.6812284),
+(43.644672, -79.5294079),
+(43.8738949, -79.4096392),
+(43.663008, -79.376777),
+(43.8911679, -79.915979),
+(43.861812, -79.438149),
+(43.639803, -79.414753),
+(43.7536761, -79.1908242),
+(43.762714, -79.346424),
+(43.9241418, -79.5175135),
+(43.7393249, -79.3948333),
+(43.647513, -79.392702),
+(43.688974, -79.388742),
+(43.9613393, -78.9622963),
+(43.9252792, -78.881418),
+(43.8608398, -79.4602586),
+(43.8518581, -79.5018269),
+(43.9067919, -79.6514425),
+(43.6770656, -79.4412766),
+(43.759234, -79.5851521),
+(43.883078, -79.814237),
+(43.625383, -79.4969316),
+(43.8958312, -79.2160162),
+(43.7610136, -79.7184557),
+(43.6227432, -79.4819344),
+(43.5646723, -79.7366116),
+(43.5620702, -79.7202245),
+(43.6320192, -79.8732153),
+(43.728742, -79.482576),
+(43.6805, -79.4625767),
+(43.586289, -79.647364),
+(43.75429, -79.492249),
+(43.4513865, -79.7368857),
+(43.6883032, -79.4768786),
+(43.701781, -79.418053),
+(43.910163, -79.445996),
+(43.6998957, -79.8501093),
+(43.6830187, -79.4180049),
+(43.5559395, -79.6495358),
+(43.7026519, -79.351603),
+(43.535943, -79.858296),
+(43.852333, -79.3391785),
+(43.4791688, -79.7261692),
+(43.6533142, -79.3683904),
+(43.7713931, -79.4126675),
+(43.7786129, -79.4518042),
+(43.64787, -79.391138),
+(43.7789023, -79.2834915),
+(43.8180112, -79.3297792),
+(43.704646, -79.251748),
+(43.601008, -79.503545),
+(43.3589266, -79.8427286),
+(43.376575, -79.7491803),
+(43.643012, -79.406201),
+(43.6948889, -79.3182261),
+(43.718009, -79.325996),
+(43.6800705, -79.7319362),
+(43.649783, -79.38044),
+(43.88453, -78.880411),
+(43.887132, -79.420343),
+(43.6460836, -79.3650117),
+(43.8301675, -79.5708923),
+(43.826744, -79.091001),
+(43.813422, -79.10561),
+(43.8444911, -79.4258074),
+(43.890795, -78.915014),
+(43.737966, -79.762589),
+(43.718033, -79.7838365),
+(43.5110649, -79.851121),
+(43.4095538, -79.7045407),
+(43.80821, -79.357177),
+(43.838982, -79.523495),
+(43.6871413, -79.3327398),
+(43.6911065, -79.7917003),
+(43.7282818, -79.4332806),
+(43.659421, -79.361025),
+(43.3567142, -79.8363353),
+(43.7989173, -79.2097151),
+(43.769664, -79.413783),
+(43.6679848, -79.3755762),
+(43.7534418, -79.3046931),
+(43.8635634, -79.4650042),
+(43.6243335, -79.485323),
+(43.7267828, -79.8000023),
+(43.7204811, -79.7132316),
+(43.6434207, -79.7251993),
+(43.833122, -79.531325),
+(43.4517224, -79.735387),
+(43.660142, -79.4404141),
+(43.631119, -79.6006862),
+(43.9579666, -79.2433791),
+(43.656981, -79.919535),
+(43.412112, -79.800498),
+(43.6368129, -79.3975163),
+(43.7517603, -79.3975819),
+(43.85593, -79.3433541),
+(43.6654565, -79.7730581),
+(43.74509, -79.776653),
+(43.4709615, -79.6921393),
+(43.6642255, -79.7804607),
+(43.590124, -79.632916),
+(43.6211459, -79.5215694),
+(43.8527753, -79.3560834),
+(43.773403, -79.344636),
+(43.7964306, -79.294996),
+(43.8263784, -79.2750135),
+(43.7751647, -79.429325),
+(43.863141, -79.326994),
+(43.7786606, -79.4179499),
+(43.7242249, -79.6165728),
+(43.669963, -79.386158),
+(43.6271301, -79.7420208),
+(43.8535994, -79.464877),
+(43.875926, -79.400308),
+(43.6778138, -79.5233338),
+(43.4531835, -79.7479672),
+(43.4650951, -79.6487322),
+(43.3589266, -79.8427286),
+(43.5814507, -79.6036067),
+(43.9617408, -79.2473084),
+(43.793542, -79.664573),
+(43.7859191, -79.3189248),
+(43.5416825, -79.6917468),
+(43.572029, -79.757581),
+(43.7070855, -79.3977452),
+(43.6700845, -79.3864005),
+(43.4821135, -79.8491058),
+(43.7070855, -79.3977452),
+(43.7258079, -79.4806067),
+(43.77807, -79.4158254),
+(43.9131528, -78.9082985),
+(43.58504, -79.646032),
+(43.8633646, -79.4300888),
+(43.7525703, -79.7378973),
+(43.646811, -79.56668),
+(43.6484128, -79.3764665),
+(43.9677686, -79.2325097),
+(43.6869213, -79.7452191),
+(43.464726, -79.723928),
+(43.479721, -79.727978),
+(43.648817, -79.388006),
+(43.676052, -79.810895),
+(43.5049101, -79.8453117),
+(43.7177605, -79.2357029),
+(43.66719, -79.4130511),
+(43.816504, -79.287461),
+(43.4889538, -79.8699167),
+(43.5943476, -79.664237),
+(43.6670409, -79.3866462),
+(43.779746, -79.4181433),
+(43.7066441, -79.4271318),
+(43.660905, -79.378806),
+(43.856447, -79.3282132),
+(43.641596, -79.423443),
+(43.6784555, -79.4628991),
+(43.646084, -79.365012),
+(43.807228, -79.608513),
+(43.7694183, -79.3686931),
+(43.665388, -79.3880211),
+(43.6648039, -79.3869669),
+(43.6648039, -79.3869669),
+(43.6695417, -79.3837883),
+(43.8085914, -79.7335603),
+(43.7027883, -79.8415704),
+(43.6772701, -79.5068667),
+(43.8969807, -79.4643117),
+(43.649261, -79.375726),
+(43.6963597, -79.7951628),
+(43.8154357, -79.0817429),
+(43.7844008, -79.288555),
+(43.6739425, -79.7896573),
+(43.803687, -79.730903),
+(43.7801939, -79.6020845),
+(43.585748, -79.648916),
+(43.624447, -79.4899623),
+(43.539427, -79.671352),
+(43.5864149, -79.6479746),
+(43.6384158, -79.582077),
+(43.8036515, -79.4505507),
+(43.961709, -78.910855),
+(43.8835053, -79.3721782),
+(43.6334372, -79.7815062),
+(43.8853343, -79.0228945),
+(43.6851958, -79.406631),
+(43.5889647, -79.5346886),
+(43.8664986, -79.3138921),
+(43.938691, -79.4714774),
+(43.85334, -79.468411),
+(43.3828159, -79.7275546),
+(43.403482, -79.798526),
+(43.733567, -79.416081),
+(43.615549, -79.645627),
+(43.539184, -79.674289),
+(43.8807305, -79.8586862),
+(43.899808, -79.010902),
+(43.8163328, -79.2223067),
+(43.8989026, -79.2407699),
+(43.8959512, -79.3014164),
+(43.963183, -78.945472),
+(43.527228, -79.854865),
+(43.9411203, -79.4387293),
+(43.802027, -79.4475386),
+(43.867291, -79.04633),
+(43.4469446, -79.7627738),
+(43.3492884, -79.766177),
+(43.881051, -79.0280918),
+(43.6022361, -79.6498521),
+(43.5442093, -79.7218194),
+(43.7810628, -79.2485568),
+(43.4910572, -79.8720089),
+(43.816463, -79.3283933),
+(43.8306057, -79.4789238),
+(43.5850975, -79.6461541),
+(43.6611022, -79.4361113),
+(43.5598955, -79.7417334),
+(43.7420013, -79.3615893),
+(43.8121752, -79.3173672),
+(43.7539761, -79.4448412),
+(43.6433727, -79.4041107),
+(43.730701, -79.465717),
+(43.640416, -79.5630875),
+(43.622466, -79.4824018),
+(43.879058, -79.3936241),
+(43.708965, -79.392985),
+(43.5569564, -79.7620473),
+(43.7985883, -79.6734913),
+(43.4919501, -79.8485289),
+(43.5689451, -79.5647828),
+(43.790843, -79.5627052),
+(43.7273082, -79.3635963),
+(43.684239, -79.468408),
+(43.7427021, -79.7626853),
+(43.899088, -79.266191),
+(43.7157936, -79.8406367),
+(43.7484647, -79.3773326),
+(43.689247, -79.843054),
+(43.8983826, -79.2385614),
+(43.759198, -79.28341),
+(43.8883417, -79.4613768),
+(43.9516826, -79.4396413),
+(43.4905363, -79.7137149),
+(43.9711, -79.2461),
+(43.9711, -79.2461),
+(43.9711, -79.2461),
+(43.9711, -79.2461),
+(43.8376, -79.3405),
+(43.9711, -79.2461),
+(43.9711, -79.2461),
+(43.5748, -79.7214),
+(43.6040446, -79.6523817),
+(43.7927944, -79.5999348),
+(43.7574, -79.8126),
+(43.6826194, -79.391523),
+(43.6628495, -79.3762687),
+(43.655028, -79.418075),
+(43.7411752, -79.3517979),
+(43.7925345, -79.6002808),
+(43.463402, -79.71856),
+(43.9280415, -79.5206777),
+(43.8497349, -79.5598207),
+(43.6400887, -79.3939574),
+(43.6839995, -79.4119636),
+(43.859698, -79.0351219),
+(43.6302332, -79.4762201),
+(43.581455, -79.648078),
+(43.7121041, -79.4604911),
+(43.6720296, -79.3798098),
+(43.7410742, -79.5373929),
+(43.92302, -78.9513617),
+(43.71418, -79.7416356),
+(43.5718108, -79.7354552),
+(43.6989956, -79.4472598),
+(43.8810864, -79.052594),
+(43.7795461, -79.2715982),
+(43.899075, -78.935432),
+(43.8218614, -79.0132992),
+(43.842348, -79.5657199),
+(43.8936052, -79.4563559),
+(43.84485, -79.509822),
+(43.9711, -79.2461),
+(43.912438, -79.462263),
+(43.873693, -79.062149),
+(43.5837216, -79.5751113),
+(43.4854, -79.6988),
+(43.3606606, -79.7532716),
+(43.873623, -79.4521193),
+(43.7448413, -79.2146236),
+(43.8901743, -79.2711734),
+(43.8846415, -79.2795246),
+(43.6317833, -79.4946972),
+(43.66572, -79.386054),
+(43.8547742, -79.3405972),
+(43.802145, -79.4206681),
+(43.8152085, -79.4470479),
+(43.8509173, -79.5720767),
+(43.879944, -79.4681185),
+(43.6740453, -79.7614893),
+(43.7594123, -79.6893057),
+(43.6932811, -79.3273475),
+(43.7608609, -79.6957299),
+(43.488937, -79.864588),
+(43.717564, -79.7264808),
+(43.649381, -79.374532),
+(43.756947, -79.4064994),
+(43.7460379, -79.3991282),
+(43.6396262, -79.4157955),
+(43.64787, -79.391138),
+(43.67032, -79.394433),
+(43.7506401, -79.3837449),
+(43.6740324, -79.3904727),
+(43.8499194, -79.5649858),
+(43.640315, -79.562295),
+(43.7396482, -79.7733183),
+(43.7024135, -79.8133843),
+(43.6508493, -79.8021868),
+(43.680462, -79.7593612),
+(43.7391234, -79.7243367),
+(43.5379379, -79.6468683),
+(43.756555, -79.837396),
+(43.7292971, -79.4905773),
+(43.9457853, -79.4668869),
+(43.8907716, -79.4428898),
+(43.8719194, -79.3066319),
+(43.8393905, -79.4577525),
+(43.8548328, -79.4840717),
+(43.7175965, -79.4494736),
+(43.9633683, -79.2404162),
+(43.7951402, -79.5841771),
+(43.5751766, -79.5790979),
+(43.4802665, -79.7261506),
+(43.6514205, -79.8114896),
+(43.3757944, -79.798879),
+(43.4254103, -79.7355246),
+(43.8522079, -79.427938),
+(43.7684033, -79.1820615),
+(43.7839969, -79.4160404),
+(43.8329287, -79.2884565),
+(43.6006042, -79.6388674),
+(43.7681761, -79.4117756),
+(43.7485556, -79.7525642),
+(43.8771346, -79.3645673),
+(43.7949078, -79.3139676),
+(43.665412, -79.3871187),
+(43.7029299, -79.2503152),
+(43.6986141, -79.2731601),
+(43.714343, -79.411659),
+(43.665388, -79.3880211),
+(43.664188, -79.380543),
+(43.6429141, -79.3806469),
+(43.646944, -79.392397),
+(43.7518004, -79.1931398),
+(43.8275331, -79.4254001),
+(43.8953052, -79.4535677),
+(43.95062, -79.455785),
+(43.8412959, -79.3938341),
+(43.8523801, -79.4147703),
+(43.802582, -79.420565),
+(43.8794858, -78.9035611),
+(43.76988, -79.419991),
+(43.769846, -79.420097),
+(43.6536011, -79.3688254),
+(43.7092776, -79.8217697),
+(43.7306147, -79.4060587),
+(43.869188, -79.053185),
+(43.7266293, -79.8218776),
+(43.8270588, -79.656495),
+(43.72866, -79.523063),
+(43.624376, -79.48554),
+(43.6852244, -79.4601451),
+(43.7419021, -79.2723504),
+(43.576824, -79.7024934),
+(43.8494035, -79.4337956),
+(43.788764, -79.673036),
+(43.6923725, -79.8205623),
+(43.5312137, -79.7113219),
+(43.6612322, -79.5555583),
+(43.7466256, -79.7627146),
+(43.5421846, -79.6269109),
+(43.7566544, -79.4204548),
+(43.7947528, -79.3748898),
+(43.7255458, -79.2517641),
+(43.6891263, -79.2745072),
+(43.7677262, -79.3706232),
+(43.624083, -79.4917823),
+(43.391031, -79.783862),
+(43.3822161, -79.740034),
+(43.7195729, -79.2955413),
+(43.4990914, -79.7066482),
+(43.6954626, -79.5651811),
+(43.5728324, -79.5654932),
+(43.8911637, -79.2740915),
+(43.7839969, -79.4160404),
+(43.7913353, -79.3866546),
+(43.7519341, -79.4389849),
+(43.7305312, -79.251337),
+(43.7621296, -79.3993738),
+(43.8395254, -79.3920429),
+(43.9715083, -79.4775213),
+(43.8502471, -79.417431),
+(43.6239195, -79.7257475),
+(43.7276063, -79.5221164),
+(43.7654478, -79.4141909),
+(43.6404932, -79.3949157),
+(43.8042371, -79.4497063),
+(43.8436901, -79.4514242),
+(43.9585028, -79.433902),
+(43.6484128, -79.3764665),
+(43.893692, -79.322627),
+(43.9403548, -78.8882409),
+(43.75514, -79.833201),
+(43.8492988, -79.396558),
+(43.5400177, -79.6006715),
+(43.7957434, -79.7520742),
+(43.79014, -79.423905),
+(43.8556947, -79.300041),
+(43.983375, -78.908867),
+(43.9074397, -78.8826113),
+(43.7307782, -79.4902255),
+(43.7163324, -79.4418676),
+(43.4751482, -79.7129872),
+(43.977555, -79.462377),
+(43.5019894, -79.8526964),
+(43.9175913, -79.4695632),
+(43.5850975, -79.6461541),
+(43.721887, -79.334639),
+(43.3834033, -79.7299149),
+(43.6474301, -79.7567794),
+(43.6867713, -79.4530624),
+(43.7794018, -79.2205213),
+(43.767464, -79.370763),
+(43.642313, -79.424366),
+(43.795489, -79.933931),
+(43.8940108, -79.2848021),
+(43.666981, -79.388889),
+(43.773403, -79.344636),
+(43.9607742, -78.9136596),
+(43.6537223, -79.7943158),
+(43.5344205, -79.8600686),
+(43.7084377, -79.2641399),
+(43.675944, -79.459984),
+(43.6671242, -79.5162796),
+(43.6642374, -79.3755383),
+(43.9505981, -79.4230827),
+(43.8963738, -79.0362081),
+(43.65517, -79.915208),
+(43.641806, -79.378981),
+(43.5832384, -79.7274283),
+(43.647524, -79.387335),
+(43.473702, -79.742289),
+(43.65056, -79.382072),
+(43.7676405, -79.2828561),
+(43.7029246, -79.7437753),
+(43.6593726, -79.3825439),
+(43.8403126, -79.3915204),
+(43.6460836, -79.3650117),
+(43.7686168, -79.3880662),
+(43.8823929, -79.2232651),
+(43.770449, -79.2115916),
+(43.5363749, -79.8598455),
+(43.837415, -79.391117),
+(43.3938878, -79.8280365),
+(43.6398757, -79.5525158),
+(43.7680556, -79.4995224),
+(43.5905171, -79.628811),
+(43.4671888, -79.7125127),
+(43.625807, -79.477924),
+(43.749978, -79.840983),
+(43.8044039, -79.5921063),
+(43.7960194, -79.2911588),
+(43.6440617, -79.3894386),
+(43.6952107, -79.5508744),
+(43.778071, -79.7140865),
+(43.7931914, -79.3500044),
+(43.862017, -79.4014194),
+(43.8124718, -79.7337541),
+(43.6102912, -79.7046361),
+(43.862662, -79.644374),
+(43.7763249, -79.4140326),
+(43.7217287, -79.2560904),
+(43.675203, -79.399574),
+(43.7316454, -79.430181),
+(43.7117457, -79.3892836),
+(43.812585, -79.453084),
+(43.6720081, -79.3879319),
+(43.887489, -79.4987307),
+(43.6424403, -79.3721255),
+(43.9034699, -78.9612489),
+(43.7909895, -79.4499094),
+(43.73757, -79.4254754),
+(43.811936, -79.455842),
+(43.4475761, -79.7434318),
+(43.5920325, -79.7070699),
+(43.9158745, -79.4730575),
+(43.7073201, -79.8239393),
+(43.6600471, -79.3754477),
+(43.7124424, -79.4265613),
+(43.6865805, -79.3975526),
+(43.877593, -79.233618),
+(43.8209675, -79.3221572),
+(43.7236817, -79.3854368),
+(43.8241469, -79.4214265),
+(43.7485573, -79.7844606),
+(43.686627, -79.841684),
+(43.756556, -79.408625),
+(43.621078, -79.521523),
+(43.4326767, -79.6927803),
+(43.5883573, -79.6395201),
+(43.697149, -79.4482492),
+(43.8935129, -79.3761163),
+(43.581151, -79.645693),
+(43.6702658, -79.3943539),
+(43.8113804, -79.2260421),
+(43.665753, -79.385579),
+(43.6982743, -79.8076261),
+(43.7653441, -79.4126151),
+(43.6319202, -79.4913421),
+(43.5081843, -79.8553512),
+(43.7575885, -79.7504381),
+(43.7858307, -79.6663713),
+(43.7578384, -79.581581),
+(43.4369552, -79.6901138),
+(43.7781623, -79.7194514),
+(43.7734808, -79.2544987),
+(43.5949331, -79.6351503),
+(43.8636105, -79.4388462),
+(43.9641069, -78.97017),
+(43.7136212, -79.4079573),
+(43.669963, -79.386158),
+(43.8592966, -79.3554939),
+(43.850653, -79.049782),
+(43.8902513, -79.3326019),
+(43.7606271, -79.7145895),
+(43.8573138, -79.3296381),
+(43.8091676, -79.455206),
+(43.685286, -79.761492),
+(43.7544241, -79.5101932),
+(43.8505281, -79.0538837),
+(43.652757, -79.366511),
+(43.6668981, -79.3890419),
+(43.7652017, -79.3832771),
+(43.638513, -79.400897),
+(43.6406527, -79.3957762),
+(43.829999, -79.109403),
+(43.6700845, -79.3864005),
+(43.6479143, -79.4305512),
+(43.6238862, -79.4792234),
+(43.7676405, -79.2828561),
+(43.765639, -79.4127414),
+(43.833122, -79.531325),
+(43.5887481, -79.6517696),
+(43.8582227, -79.4336464),
+(43.656092, -79.519717),
+(43.7680083, -79.206352),
+(43.8287829, -79.1228298),
+(43.8830681, -79.2723628),
+(43.496674, -79.707317),
+(43.7575874, -79.326688),
+(43.8505281, -79.0538837),
+(43.8318469, -79.532176),
+(43.6647482, -79.3324607),
+(43.7424087, -79.3012429),
+(43.9034357, -79.4643012),
+(43.4091464, -79.7110254),
+(43.67032, -79.394433),
+(43.6484128, -79.3764665),
+(43.4544123, -79.6550123),
+(43.7110634, -79.2889948),
+(43.3969643, -79.8114381),
+(43.7947424, -79.6201098),
+(43.5558201, -79.5780703),
+(43.7490152, -79.406243),
+(43.7639534, -79.4069857),
+(43.8567338, -79.5038745),
+(43.7036269, -79.2923982),
+(43.9313023, -79.5300336),
+(43.8833711, -79.461697),
+(43.7785806, -79.2811718),
+(43.8829799, -79.2779997),
+(43.784842, -79.5938836),
+(43.7368051, -79.4308942),
+(43.8574527, -79.8506497),
+(43.86231, -79.084741),
+(43.595443, -79.7128167),
+(43.67862, -79.390345),
+(43.9704741, -79.5643181),
+(43.8959272, -79.2371549),
+(43.6756198, -79.806306),
+(43.711559, -79.8224172),
+(43.8574348, -79.3248166),
+(43.8234203, -79.6107975),
+(43.7101719, -79.4560217),
+(43.521972, -79.838921),
+(43.8417326, -79.0908077),
+(43.9747394, -79.2355651),
+(43.5967736, -79.7241716),
+(43.692773, -79.4201127),
+(43.669301, -79.384171),
+(43.661781, -79.384218),
+(43.6383599, -79.398657),
+(43.778476, -79.450359),
+(43.6624074, -79.3858983),
+(43.6695417, -79.3837883),
+(43.90763, -78.973962),
+(43.797121, -79.14246),
+(43.7428085, -79.3495661),
+(43.768501, -79.381285),
+(43.853813, -79.1084381),
+(43.6637914, -79.3830261),
+(43.5342555, -79.6291675),
+(43.689939, -79.790151),
+(43.9605301, -79.2518691),
+(43.6547114, -79.3792073),
+(43.9707943, -79.2722247),
+(43.7040059, -79.3998499),
+(43.6958987, -79.2785755),
+(43.62372, -79.483703),
+(43.5787037, -79.5705129),
+(43.8976755, -78.8803451),
+(43.8923949, -79.4963122),
+(43.6616166, -79.3846797),
+(43.8517028, -79.5654388),
+(43.7344419, -79.4764623),
+(43.8890019, -79.5142354),
+(43.8358024, -79.4887048),
+(43.8366415, -79.4765307),
+(43.848113, -79.478862),
+(43.7692453, -79.9216405),
+(43.8567204, -79.5433182),
+(43.8109022, -79.3161035),
+(43.8516438, -79.5482603),
+(43.8118964, -79.456238),
+(43.8305857, -79.4790669),
+(43.8042371, -79.4497063),
+(43.963094, -78.905184),
+(43.656092, -79.519717),
+(43.9265987, -79.5078287),
+(43.760762, -79.821738),
+(43.669963, -79.386158),
+(43.793032, -79.599426),
+(43.943448, -78.890674),
+(43.8081274, -79.3149766),
+(43.7603442, -79.8220617),
+(43.8216745, -79.3788001),
+(43.6899454, -79.4033746),
+(43.9404802, -79.4282711),
+(43.8907373, -79.4591732),
+(43.792045, -79.4640508),
+(43.7664283, -79.4289788),
+(43.628152, -79.7611206),
+(43.6667012, -79.4120351),
+(43.7653441, -79.4126151),
+(43.872007, -79.736076),
+(43.6417386, -79.4233157),
+(43.6298595, -79.7108006),
+(43.9022349, -79.4558445),
+(43.8086392, -79.4125703),
+(43.7422943, -79.4438507),
+(43.8922591, -79.0241404),
+(43.7075225, -79.392402),
+(43.9004866, -79.288139),
+(43.641806, -79.378981),
+(43.665753, -79.385579),
+(43.7042621, -79.8534208),
+(43.7786878, -79.430321),
+(43.6547114, -79.3792073),
+(43.673817, -79.7575811),
+(43.65056, -79.382072),
+(43.5849599, -79.6749579),
+(43.7181463, -79.72599),
+(43.601736, -79.6094174),
+(43.5990147, -79.6255636),
+(43.7691576, -79.3688621),
+(43.7265437, -79.4706079),
+(43.8633835, -79.4332961),
+(43.8636931, -79.6179175),
+(43.478579, -79.70921),
+(43.9609, -78.9009),
+(43.949801, -79.462762),
+(43.7932277, -79.4127048),
+(43.9533804, -78.9929012),
+(43.6940509, -79.8347245),
+(43.6839, -79.4824),
+(43.9240652, -79.4153371),
+(43.3945503, -79.8296494),
+(43.3933686, -79.8281313),
+(43.3499142, -79.8168743),
+(43.8651044, -79.6212948),
+(43.9422165, -79.4789287),
+(43.9269989, -78.8804339),
+(43.648909, -79.386075),
+(43.5274646, -79.6708009),
+(43.6902386, -79.822147),
+(43.4803368, -79.7199784),
+(43.8428587, -79.4411012),
+(43.883097, -79.466912),
+(43.586139, -79.6238631),
+(43.7506494, -79.464186),
+(43.980412, -79.446872),
+(43.4739326, -79.6834478),
+(43.8524695, -79.0787038),
+(43.7035497, -79.2912606),
+(43.716387, -79.3641303),
+(43.785532, -79.212796),
+(43.670587, -79.380609),
+(43.749112, -79.2710394),
+(43.8081639, -79.5553916),
+(43.8643379, -79.5195378),
+(43.7750632, -79.3457849),
+(43.9649979, -79.3705946),
+(43.664579, -79.410692),
+(43.434214, -79.776184),
+(43.7330964, -79.2264877),
+(43.7118324, -79.2914046),
+(43.6850847, -79.3341608),
+(43.6397347, -79.3920867),
+(43.5925004, -79.5267079),
+(43.7676173, -79.5010688),
+(43.6363712, -79.4027602),
+(43.7612374, -79.408937),
+(43.669963, -79.386158),
+(43.6400907, -79.3786023),
+(43.6926459, -79.4462154),
+(43.6489903, -79.3870081),
+(43.4392296, -79.755996),
+(43.8615497, -79.4611572),
+(43.544741, -79.927523),
+(43.7306775, -79.4657154),
+(43.9711, -79.2461),
+(43.6667966, -79.3886683),
+(43.9351943, -79.5504595),
+(43.7669808, -79.4309076),
+(43.8886582, -79.1592889),
+(43.6675198, -79.4011128),
+(43.9641241, -79.2305602),
+(43.8534631, -79.5717405),
+(43.607292, -79.895302),
+(43.661294, -79.358138),
+(43.786191, -79.21174),
+(43.8745191, -79.4212141),
+(43.7923021, -79.4490826),
+(43.7103699, -79.7537018),
+(43.6922159, -79.7578757),
+(43.8883291, -79.2367508),
+(43.9439803, -79.4620682),
+(43.968714, -79.280113),
+(43.4974207, -79.7424764),
+(43.8179183, -79.319368),
+(43.8573, -79.0125),
+(43.7566627, -79.4547997),
+(43.8457702, -79.3994987),
+(43.8606926, -79.3965395),
+(43.8270616, -79.3953945),
+(43.6720476, -79.2999357),
+(43.9531958, -79.4633433),
+(43.4873911, -79.7059627),
+(43.8727776, -79.2752252),
+(43.960306, -78.9362303),
+(43.8985203, -78.8847343),
+(43.887092, -79.293886),
+(43.760341, -79.325519),
+(43.8007128, -79.3170366),
+(43.8719951, -79.5267141),
+(43.8221323, -79.6093906),
+(43.8559126, -79.5414046),
+(43.5679451, -79.5646593),
+(43.4835307, -79.844804),
+(43.7784327, -79.413539),
+(43.8764498, -79.422309),
+(43.8046762, -79.4230377),
+(43.8336461, -79.3042692),
+(43.6423906, -79.4247385),
+(43.768151, -79.390791),
+(43.5816498, -79.6480951),
+(43.85062, -79.427416),
+(43.8874029, -79.3346351),
+(43.6485613, -79.4241096),
+(43.7778811, -79.7036415),
+(43.6527316, -79.4305034),
+(43.62372, -79.483703),
+(43.791298, -79.407038),
+(43.648678, -79.397235),
+(43.6445412, -79.3764773),
+(43.661725, -79.384128),
+(43.763643, -79.395882),
+(43.661214, -79.382681),
+(43.6884863, -79.3136348),
+(43.814514, -79.125992),
+(43.8610302, -79.5085464),
+(43.8318469, -79.532176),
+(43.766764, -79.385993),
+(43.818577, -79.08624),
+(43.8099381, -79.358997),
+(43.9255085, -78.9267848),
+(43.7939829, -79.4434454),
+(43.858252, -79.433621),
+(43.884765, -79.4210353),
+(43.5613699, -79.7131936),
+(43.6539727, -79.7791162),
+(43.8869239, -79.242555),
+(43.4387945, -79.6732763),
+(43.4993373, -79.8831371),
+(43.686315, -79.514191),
+(43.624305, -79.488423),
+(43.6753924, -79.4693025),
+(43.6774473, -79.8136478),
+(43.6098317, -79.659562),
+(43.5733634, -79.6907616),
+(43.8630267, -79.4323167),
+(43.7498015, -79.3924442),
+(43.6893403, -79.8489851),
+(43.7679629, -79.3964689),
+(43.7817373, -79.4143115),
+(43.768597, -79.3896),
+(43.6649335, -79.4174207),
+(43.6629934, -79.3404389),
+(43.7751229, -79.4138309),
+(43.7125376, -79.3734249),
+(43.7563401, -79.4056932),
+(43.9244074, -79.467687),
+(43.688905, -79.8508543),
+(43.780039, -79.344324),
+(43.593602, -79.6312311),
+(43.8603434, -79.4324218),
+(43.8503461, -79.4300813),
+(43.662961, -79.349123),
+(43.7645339, -79.3703253),
+(43.6429451, -79.3805034),
+(43.8108853, -79.221617),
+(43.8496836, -79.3765109),
+(43.6914665, -79.4005801),
+(43.897399, -79.3123844),
+(43.6659675, -79.3871076),
+(43.749969, -79.406135),
+(43.7737399, -79.2555526),
+(43.775123, -79.345456),
+(43.625807, -79.477924),
+(43.9002054, -79.4403178),
+(43.773688, -79.255422),
+(43.7512277, -79.4641134),
+(43.7226665, -79.2709227),
+(43.8617175, -79.4355742),
+(43.8574348, -79.3248166),
+(43.6460155, -79.3940644),
+(43.5927159, -79.6041477),
+(43.6351152, -79.717727),
+(43.6417386, -79.4233157),
+(43.960787, -79.442412),
+(43.4189435, -79.7742067),
+(43.7709677, -79.3730181),
+(43.7754066, -79.4295265),
+(43.8075508, -79.1235335),
+(43.878289, -79.2583967),
+(43.850094, -79.323495),
+(43.621968, -79.52092),
+(43.613229, -79.659943),
+(43.779164, -79.414515),
+(43.520078, -79.620473),
+(43.7504178, -79.5817375),
+(43.4030283, -79.7170106),
+(43.5384146, -79.6249765),
+(43.730455, -79.729121),
+(43.584967, -79.587183),
+(43.8966534, -79.2703268),
+(43.675835, -79.827952),
+(43.725403, -79.698982),
+(43.7885851, -79.4265789),
+(43.6527869, -79.4688202),
+(43.891758, -79.5191663),
+(43.862523, -79.292638),
+(43.6424403, -79.3721255),
+(43.618717, -79.4855402),
+(43.480595, -79.731421),
+(43.5319544, -79.6269204),
+(43.640349, -79.914759),
+(43.814732, -79.4605717),
+(43.8218854, -79.4243456),
+(43.7611969, -79.383773),
+(43.8759276, -78.9580626),
+(43.9568234, -78.8969736),
+(43.6746538, -79.3890069),
+(43.5743956, -79.6876426),
+(43.8911125, -79.2656609),
+(43.7686, -79.412075),
+(43.8611973, -79.3969425),
+(43.7086423, -79.7582664),
+(43.7902978, -79.4276874),
+(43.486386, -79.733027),
+(43.8481941, -79.2746771),
+(43.5587686, -79.5820891),
+(43.851756, -79.432591),
+(43.5211882, -79.6201163),
+(43.8753846, -79.3600939),
+(43.6980638, -79.7876559),
+(43.8154729, -79.4580946),
+(43.473702, -79.742289),
+(43.7412272, -79.5374166),
+(43.7087705, -79.2924247),
+(43.7208042, -79.7839006),
+(43.8528736, -79.0571995),
+(43.958331, -78.953064),
+(43.7572108, -79.4221169),
+(43.5712691, -79.5694847),
+(43.773403, -79.344636),
+(43.6480403, -79.4186404),
+(43.638987, -79.531391),
+(43.7551247, -79.8301219),
+(43.623406, -79.481472),
+(43.5291142, -79.6636794),
+(43.6665874, -79.4460776),
+(43.7752161, -79.4133461),
+(43.7307234, -79.3276511),
+(43.6101691, -79.4986065),
+(43.8100647, -79.4487117),
+(43.9009639, -79.461736),
+(43.625159, -79.47872),
+(43.8415411, -79.3928268),
+(43.7496021, -79.2668943),
+(43.794242, -79.524434),
+(43.694531, -79.74986),
+(43.7842089, -79.3337756),
+(43.886548, -79.037424),
+(43.5395893, -79.6297652),
+(43.937246, -79.5202033),
+(43.698496, -79.548124),
+(43.782451, -79.175908),
+(43.8909873, -79.2258973),
+(43.6398182, -79.3836155),
+(43.7532386, -79.4909284),
+(43.7624387, -79.5670189),
+(43.6784181, -79.7757596),
+(43.8436904, -79.4428336),
+(43.9075604, -78.9792908),
+(43.9114073, -78.9225918),
+(43.7030009, -79.2926536),
+(43.8747929, -79.4133547),
+(43.8705017, -79.324673),
+(43.73372, -79.446151),
+(43.9645732, -79.5875673),
+(43.753813, -79.445853),
+(43.7812179, -79.1735087),
+(43.8887296, -79.4282765),
+(43.768151, -79.390791),
+(43.8376638, -79.3903703),
+(43.64787, -79.391138),
+(43.7524468, -79.7438979),
+(43.647627, -79.390408),
+(43.6892811, -79.4508791),
+(43.648909, -79.386075),
+(43.779869, -79.417504),
+(43.8517581, -79.337025),
+(43.645404, -79.401414),
+(43.766836, -79.198163),
+(43.7751229, -79.4138309),
+(43.791887, -79.3535054),
+(43.6460155, -79.3940644),
+(43.8027272, -79.4110347),
+(43.852413, -79.336585),
+(43.3682997, -79.8592575),
+(43.9595382, -78.8915145),
+(43.8064685, -79.3738234),
+(43.8738137, -79.4026916),
+(43.5976032, -79.6210436),
+(43.8234886, -79.2760294),
+(43.8978767, -79.4426111),
+(43.6597031, -79.4039817),
+(43.8486492, -79.0688134),
+(43.6363831, -79.7352478),
+(43.7728109, -79.398128),
+(43.7820048, -79.3220891),
+(43.7227214, -79.7135484),
+(43.9349353, -79.3797962),
+(43.6637546, -79.5012643),
+(43.5627815, -79.7060868),
+(43.6905799, -79.5297734),
+(43.5097176, -79.884918),
+(43.494968, -79.701773),
+(43.7949916, -79.1553338),
+(43.7496773, -79.5661533),
+(43.419861, -79.762377),
+(43.6733936, -79.7267631),
+(43.6401441, -79.4239168),
+(43.6620882, -79.3856802),
+(43.906049, -79.031418),
+(43.9736865, -79.2354151),
+(43.9702672, -79.2637063),
+(43.979601, -79.360172),
+(43.4693018, -79.7508604),
+(43.8474824, -79.4304978),
+(43.8240601, -79.2769069),
+(43.511045, -79.8427812),
+(43.4706311, -79.6476176),
+(43.8699539, -78.9410646),
+(43.4506024, -79.7338018),
+(43.6011244, -79.5949481),
+(43.576244, -79.877987),
+(43.8889393, -79.4252324),
+(43.6050137, -79.6708941),
+(43.433141, -79.770423),
+(43.7158593, -79.7617054),
+(43.8228271, -79.3989259),
+(43.923974, -78.9211729),
+(43.787379, -79.4614381),
+(43.643103, -79.403831),
+(43.638788, -79.557133),
+(43.967435, -78.9464482),
+(43.669616, -79.362936),
+(43.7188144, -79.758636),
+(43.5803772, -79.5819982),
+(43.943115, -79.425708),
+(43.9704216, -79.2237158),
+(43.8647331, -79.592525),
+(43.6720468, -79.3900486),
+(43.773403, -79.344636),
+(43.9759711, -79.2587575),
+(43.6699665, -79.3053023),
+(43.6468455, -79.4028294),
+(43.761198, -79.408952),
+(43.8376638, -79.3903703),
+(43.3720526, -79.7550548),
+(43.762714, -79.346479),
+(43.558267, -79.6122732),
+(43.554909, -79.5807682),
+(43.7831107, -79.4151692),
+(43.641806, -79.378981),
+(43.7167448, -79.7271985),
+(43.9014414, -79.6551231),
+(43.760304, -79.41162),
+(43.7388732, -79.3443723),
+(43.778486, -79.41333),
+(43.59554, -79.633839),
+(43.6808474, -79.3285478),
+(43.6556821, -79.5168468),
+(43.6956176, -79.5538552),
+(43.378349, -79.796061),
+(43.8851913, -79.2858471),
+(43.894628, -78.895206),
+(43.7750632, -79.3457849),
+(43.7897579, -79.4698761),
+(43.3949227, -79.8213628),
+(43.7733926, -79.4130418),
+(43.66703, -79.376386),
+(43.7395482, -79.3011461),
+(43.7439728, -79.559961),
+(43.707396, -79.368413),
+(43.6834057, -79.4051256),
+(43.669963, -79.386158),
+(43.6469441, -79.3923974),
+(43.7138387, -79.2636181),
+(43.5944469, -79.6351125),
+(43.791887, -79.3535054),
+(43.4253516, -79.7843774),
+(43.753507, -79.421812),
+(43.7490751, -79.4023737),
+(43.6787181, -79.4142359),
+(43.8533239, -79.4127103),
+(43.642311, -79.38123),
+(43.6377596, -79.3951472),
+(43.7933386, -79.3262139),
+(43.657893, -79.405207),
+(43.6342318, -79.540934),
+(43.6712188, -79.354131),
+(43.5595722, -79.5747312),
+(43.5645699, -79.574195),
+(43.7239329, -79.5609709),
+(43.660137, -79.385869),
+(43.6406292, -79.4484244),
+(43.8728022, -78.9414628),
+(43.8664336, -79.4355835),
+(43.8519036, -79.4956062),
+(43.8500482, -79.4189922),
+(43.763024, -79.420463),
+(43.6460991, -79.3651362),
+(43.8664336, -79.4355835),
+(43.7065639, -79.7193434),
+(43.7788623, -79.7363767),
+(43.858252, -79.433621),
+(43.6250411, -79.7618933),
+(43.5994595, -79.6376254),
+(43.837415, -79.391117),
+(43.851965, -79.438503),
+(43.7587651, -79.4084528),
+(43.9082416, -78.9215725),
+(43.7711875, -79.2871116),
+(43.6454146, -79.387361),
+(43.6765411, -79.4056079),
+(43.6401441, -79.4239168),
+(43.6754371, -79.400335),
+(43.7253596, -79.7301871),
+(43.945014, -78.917078),
+(43.6113238, -79.6487546),
+(43.6402683, -79.3960141),
+(43.622515, -79.482441),
+(43.4138418, -79.6965336),
+(43.7277024, -79.5675984),
+(43.7663216, -79.5033594),
+(43.665786, -79.3855809),
+(43.7444002, -79.4000937),
+(43.7731661, -79.4154428),
+(43.492401, -79.70929),
+(43.4832052, -79.7317394),
+(43.9409238, -78.8886319),
+(43.5230051, -79.8905313),
+(43.8499537, -79.5760725),
+(43.4168331, -79.7081456),
+(43.8815358, -79.2435834),
+(43.6227388, -79.7140587),
+(43.5696562, -79.6306328),
+(43.4085337, -79.8198733),
+(43.82918, -79.653006),
+(43.9585636, -78.8988281),
+(43.820001, -79.087098),
+(43.707083, -79.397771),
+(43.6720081, -79.3879319),
+(43.764029, -79.407042),
+(43.672907, -79.411122),
+(43.7199816, -79.6277139),
+(43.7461933, -79.7266562),
+(43.748215, -79.781536),
+(43.6650324, -79.447587),
+(43.747335, -79.552426),
+(43.7181463, -79.72599),
+(43.674976, -79.5137411),
+(43.7446264, -79.5733191),
+(43.7248102, -79.4209473),
+(43.6478816, -79.4224246),
+(43.772832, -79.412978),
+(43.761894, -79.571274),
+(43.751463, -79.740283),
+(43.7123572, -79.6939768),
+(43.3584533, -79.8136703),
+(43.643291, -79.404726),
+(43.606496, -79.655651),
+(43.8031442, -79.4133405),
+(43.6542637, -79.4078083),
+(43.7917035, -79.4093977),
+(43.7870959, -79.4314198),
+(43.5650248, -79.5815754),
+(43.812554, -79.4544922),
+(43.8649249, -79.3126365),
+(43.671731, -79.389691),
+(43.6874164, -79.3739842),
+(43.7569815, -79.4044001),
+(43.6640993, -79.3745788),
+(43.869972, -79.3233936),
+(43.8344493, -79.4726017),
+(43.9011218, -79.2518848),
+(43.7909007, -79.4131942),
+(43.7636916, -79.5154799),
+(43.7868866, -79.7488714),
+(43.6791456, -79.4733623),
+(43.6595449, -79.7370602),
+(43.6695417, -79.3837883),
+(43.6406527, -79.3957762),
+(43.631577, -79.897849),
+(43.661725, -79.384128),
+(43.6427513, -79.3769186),
+(43.7727963, -79.3453943),
+(43.674612, -79.36692),
+(43.70378, -79.409702),
+(43.7686951, -79.3891863),
+(43.6942883, -79.3170607),
+(43.7070826, -79.3977712),
+(43.497263, -79.613658),
+(43.5409851, -79.6171006),
+(43.515867, -79.754293),
+(43.798491, -79.117095),
+(43.491852, -79.718708),
+(43.4244716, -79.6908325),
+(43.8675652, -79.3196797),
+(43.6470829, -79.5109309),
+(43.6517188, -79.7824615),
+(43.7195087, -79.3377222),
+(43.8869248, -79.3573508),
+(43.7532386, -79.4909284),
+(43.871436, -79.017332),
+(43.642524, -79.797178),
+(43.954921, -79.4344552),
+(43.8497821, -79.3288246),
+(43.935288, -78.877872),
+(43.865158, -79.220024),
+(43.6302332, -79.4762201),
+(43.7124535, -79.3785713),
+(43.654525, -79.915981),
+(43.397218, -79.8228728),
+(43.8042371, -79.4497063),
+(43.862336, -79.435736),
+(43.549497, -79.716276),
+(43.877256, -79.450193),
+(43.8204485, -79.3764056),
+(43.8930603, -79.4483962),
+(43.650945, -79.398185),
+(43.6725539, -79.389571),
+(43.6717424, -79.3878028),
+(43.647226, -79.3911),
+(43.555075, -79.583315),
+(43.7854735, -79.2058909),
+(43.736234, -79.539159),
+(43.5529343, -79.6487737),
+(43.8884824, -79.3103491),
+(43.680889, -79.328525),
+(43.6888757, -79.7635882),
+(43.680633, -79.3022343),
+(43.6807098, -79.3894335),
+(43.5696324, -79.5786668),
+(43.6761117, -79.8245371),
+(43.8527461, -79.3386798),
+(43.6573448, -79.3599297),
+(43.77807, -79.4158254),
+(43.707949, -79.400632),
+(43.8850547, -79.2802087),
+(43.656008, -79.40872),
+(43.874974, -79.733004),
+(43.6806972, -79.5689852),
+(43.789653, -79.253554),
+(43.67386, -79.394042),
+(43.8800433, -78.8958752),
+(43.9507499, -78.8960947),
+(43.8992665, -79.441747),
+(43.8889441, -79.4432869),
+(43.4290194, -79.6971669),
+(43.746564, -79.404908),
+(43.4804055, -79.6350468),
+(43.9582084, -79.4648115),
+(43.7920378, -79.4689109),
+(43.7667499, -79.3928005),
+(43.4700365, -79.6927295),
+(43.6104241, -79.6604224),
+(43.6313736, -79.4933266),
+(43.8948774, -79.2140377),
+(43.7073389, -79.394278),
+(43.434491, -79.775259),
+(43.7606972, -79.494205),
+(43.8517791, -79.4368481),
+(43.9680514, -79.2570698),
+(43.635423, -79.871509),
+(43.6642383, -79.7963174),
+(43.825265, -79.4323354),
+(43.9503713, -78.9468456),
+(43.5658494, -79.5660168),
+(43.7446316, -79.3446506),
+(43.708008, -79.400785),
+(43.6686799, -79.297004),
+(43.965793, -79.327849),
+(43.7787489, -79.4171422),
+(43.665588, -79.383028),
+(43.7359262, -79.5454763),
+(43.6593726, -79.3825439),
+(43.5850905, -79.6168483),
+(43.7585319, -79.4122556),
+(43.971199, -79.2698608),
+(43.7049603, -79.2726095),
+(43.891514, -79.027517),
+(43.6699083, -79.3815245),
+(43.6712941, -79.384925),
+(43.6668981, -79.3890419),
+(43.6460836, -79.3650117),
+(43.5680367, -79.7425758),
+(43.7510438, -79.2058019),
+(43.8658769, -79.4875481),
+(43.8497541, -79.4466197),
+(43.9169063, -79.4711918),
+(43.8385031, -79.2879329),
+(43.7698862, -79.4135354),
+(43.9134672, -79.4530437),
+(43.6676702, -79.3864205),
+(43.7050736, -79.3189432),
+(43.4598083, -79.6727511),
+(43.6812513, -79.4647683),
+(43.8921312, -79.4528938),
+(43.8496775, -79.6103766),
+(43.7531706, -79.5109026),
+(43.8557947, -79.4575266),
+(43.622625, -79.5910922),
+(43.6377807, -79.4379497),
+(43.8278556, -79.3948726),
+(43.8234688, -79.4504487),
+(43.6679848, -79.3755762),
+(43.4107238, -79.6953428),
+(43.7070578, -79.3250801),
+(43.739248, -79.7674484),
+(43.5278373, -79.669754),
+(43.8611858, -79.4408931),
+(43.8444917, -79.4405206),
+(43.7952076, -79.4020077),
+(43.8113804, -79.2260421),
+(43.834401, -79.003871),
+(43.9120609, -79.2513548),
+(43.7364399, -79.4266762),
+(43.7434662, -79.4022897),
+(43.6387756, -79.4163147),
+(43.6387756, -79.4163147),
+(43.7581379, -79.4095627),
+(43.7073389, -79.394278),
+(43.957, -78.9682239),
+(43.6451676, -79.753333),
+(43.8202234, -79.3235999),
+(43.952675, -79.432564),
+(43.898864, -79.2258017),
+(43.6068994, -79.820796),
+(43.9055193, -79.6491683),
+(43.7682673, -79.2077877),
+(43.8754331, -79.2447458),
+(43.7289081, -79.4024371),
+(43.64589, -79.73255),
+(43.7088581, -79.7159762),
+(43.6063679, -79.6554019),
+(43.6371667, -79.404733),
+(43.7075225, -79.392402),
+(43.6368129, -79.3975163),
+(43.7534621, -79.416317),
+(43.3823386, -79.7239662),
+(43.685286, -79.761492),
+(43.687956, -79.848052),
+(43.6140853, -79.5749679),
+(43.4896526, -79.7110609),
+(43.6818067, -79.2825843),
+(43.5897092, -79.625799),
+(43.6823901, -79.2796015),
+(43.447443, -79.686948),
+(43.8542665, -79.2372676),
+(43.8834989, -79.4523119),
+(43.8834312, -79.4212689),
+(43.797244, -79.3652338),
+(43.6342318, -79.540934),
+(43.9435059, -79.4629398),
+(43.640623, -79.4378519),
+(43.6342271, -79.6013926),
+(43.6152776, -79.7370708),
+(43.623426, -79.4789788),
+(43.4122719, -79.7132397),
+(43.9300438, -78.9285222),
+(43.63092, -79.884987),
+(43.7691713, -79.3433872),
+(43.7255161, -79.3865601),
+(43.6388732, -79.3986617),
+(43.65056, -79.382072),
+(43.6412251, -79.3809582),
+(43.848575, -79.43254),
+(43.7604383, -79.6912717),
+(43.6980142, -79.5136834),
+(43.4992679, -79.8659486),
+(43.7576978, -79.7645338),
+(43.870621, -79.757606),
+(43.7814276, -79.7460724),
+(43.6842483, -79.8091713),
+(43.9591047, -79.440706),
+(43.9474715, -79.4778024),
+(43.7986752, -79.4048859),
+(43.8822922, -79.087325),
+(43.6107139, -79.6591896),
+(43.6852491, -79.4247549),
+(43.6404932, -79.3949157),
+(43.6852146, -79.7618393),
+(43.646564, -79.943122),
+(43.8885601, -79.3265574),
+(43.4898858, -79.8738655),
+(43.7739867, -79.3595046),
+(43.6967419, -79.4422624),
+(43.8026996, -79.4084069),
+(43.6593726, -79.3825439),
+(43.5991017, -79.6820735),
+(43.6045411, -79.666338),
+(43.8870397, -79.4222753),
+(43.7686168, -79.3880662),
+(43.3455205, -79.8898836),
+(43.7339816, -79.5623587),
+(43.811745, -79.5993135),
+(43.9651459, -78.8877804),
+(43.842195, -79.394728),
+(43.665909, -79.385172),
+(43.785895, -79.29186),
+(43.6854748, -79.8132336),
+(43.7657785, -79.4100647),
+(43.8466723, -79.4293837),
+(43.5410066, -79.7277574),
+(43.8750281, -79.4481817),
+(43.7919231, -79.2388853),
+(43.7700944, -79.7191734),
+(43.5571607, -79.7071213),
+(43.783315, -79.578869),
+(43.6713468, -79.7305986),
+(43.711111, -79.8136307),
+(43.9763642, -79.4872393),
+(43.5436603, -79.6462686),
+(43.5866025, -79.6422661),
+(43.716298, -79.832908),
+(43.6054342, -79.5312891),
+(43.9175, -79.799265),
+(43.8565168, -79.541477),
+(43.958219, -78.8955236),
+(43.5175, -79.6423),
+(43.5796362, -79.6053443),
+(43.9711, -79.2461),
+(43.9219045, -79.5349221),
+(43.9435223, -79.5426558),
+(43.624101, -79.783075),
+(43.3672, -79.9339),
+(43.7386, -79.8437),
+(43.6692, -79.53),
+(43.893036, -79.6618456),
+(43.8117, -79.6306),
+(43.865886, -79.6190214),
+(43.6360952, -79.9043551),
+(43.9246166, -79.5472112),
+(43.5885932, -79.6229642),
+(43.8376, -79.3405),
+(43.661967, -79.383964),
+(43.898658, -79.2251235),
+(43.3943872, -79.8292067),
+(43.814876, -79.4223816),
+(43.9470849, -79.4629504),
+(43.7087517, -79.2462679),
+(43.7283791, -79.2490187),
+(43.509869, -79.857259),
+(43.780275, -79.3145667),
+(43.6302332, -79.4762201),
+(43.7566931, -79.5713408),
+(43.562506, -79.576562),
+(43.7886777, -79.2217572),
+(43.708965, -79.392985),
+(43.7165398, -79.2845142),
+(43.795356, -79.181504),
+(43.6426507, -79.3773286),
+(43.9159535, -78.9757182),
+(43.3939206, -79.8296628),
+(43.6647, -79.4352),
+(43.9748537, -79.2721053),
+(43.8581, -78.9318),
+(43.8927225, -79.6611964),
+(43.4720976, -79.7380337),
+(43.8117, -79.6306),
+(43.658239, -79.404774),
+(43.9609, -78.9009),
+(43.8954, -79.3222),
+(43.8573, -79.0125),
+(43.9590676, -78.903133),
+(43.7294, -79.3373),
+(43.8117, -79.6306),
+(43.3633984, -79.7752536),
+(43.7740321, -79.2580098),
+(43.3912476, -79.8278123),
+(43.5748, -79.7214),
+(43.6428502, -79.3917462),
+(43.9000932, -79.8832675),
+(43.6704872, -79.3845738),
+(43.771429, -79.365577),
+(43.7799579, -79.3446599),
+(43.6637914, -79.3830261),
+(43.6446545, -79.4156518),
+(43.708965, -79.392985),
+(43.6429141, -79.3806469),
+(43.769664, -79.413783),
+(43.8340372, -79.4620169),
+(43.8844281, -79.4442288),
+(43.8616497, -79.5087576),
+(43.8700132, -79.4758881),
+(43.7376729, -79.2190043),
+(43.831892, -79.644562),
+(43.62902, -79.477811),
+(43.3788314, -79.8094539),
+(43.9201416, -79.4750348),
+(43.774532, -79.688773),
+(43.77341, -79.747018),
+(43.8829682, -79.4674762),
+(43.3530986, -79.7856423),
+(43.8284068, -79.4075625),
+(43.9005837, -79.2469012),
+(43.6393941, -79.3997862),
+(43.7659803, -79.4014463),
+(43.7689071, -79.345767),
+(43.6594036, -79.3611785),
+(43.5942303, -79.6859991),
+(43.6547114, -79.3792073),
+(43.7255458, -79.2517641),
+(43.656806, -79.375092),
+(43.6462325, -79.3835846),
+(43.8051356, -79.257493),
+(43.6702658, -79.3943539),
+(43.863259, -79.3161433),
+(43.7867552, -79.217231),
+(43.867771, -79.054617),
+(43.6704388, -79.4220686),
+(43.6083247, -79.6600429),
+(43.6630622, -79.7811829),
+(43.7399096, -79.7213105),
+(43.92663, -79.5096404),
+(43.897049, -79.3030439),
+(43.940519, -78.884837),
+(43.7074786, -79.8163889),
+(43.6830051, -79.525957),
+(43.9112476, -79.6526676),
+(43.656359, -79.357277),
+(43.846104, -79.013541),
+(43.816197, -79.145249),
+(43.8875222, -79.4567741),
+(43.6566436, -79.3547917),
+(43.7312599, -79.3731208),
+(43.8051997, -79.3970691),
+(43.6469073, -79.3709274),
+(43.842075, -79.424278),
+(43.6417034, -79.592157),
+(43.7270016, -79.8218279),
+(43.6249874, -79.6073628),
+(43.8401337, -79.468557),
+(43.685286, -79.761492),
+(43.9763923, -79.2577411),
+(43.8508663, -79.4283197),
+(43.8977687, -79.2566536),
+(43.6095153, -79.6102789),
+(43.4903974, -79.6872711),
+(43.637279, -79.402978),
+(43.9003576, -79.2572755),
+(43.3756181, -79.7377875),
+(43.8955768, -79.4536817),
+(43.9559477, -79.4708513),
+(43.645939, -79.902034),
+(43.8889579, -79.3102321),
+(43.779746, -79.4181433),
+(43.5229548, -79.6530311),
+(43.943448, -78.890674),
+(43.8153218, -79.2630549),
+(43.881761, -79.730803),
+(43.6036032, -79.5331303),
+(43.694531, -79.74986),
+(43.8688, -79.0336),
+(43.5896218, -79.6302402),
+(43.8784439, -79.3468439),
+(43.877888, -79.2362167),
+(43.761461, -79.409802),
+(43.7698862, -79.4135354),
+(43.7489421, -79.600491),
+(43.7637774, -79.8307099),
+(43.8272251, -79.6415633),
+(43.8986691, -79.4249799),
+(43.77416, -79.189538),
+(43.713225, -79.4383228),
+(43.686709, -79.399258),
+(43.9323017, -79.4791329),
+(43.795094, -79.681998),
+(43.774818, -79.3356934),
+(43.7211631, -79.8001308),
+(43.7914648, -79.4247652),
+(43.7395147, -79.4349468),
+(43.8860927, -79.4476214),
+(43.8470596, -79.457073),
+(43.7956305, -79.4392177),
+(43.9446789, -79.4743824),
+(43.7872283, -79.1946802),
+(43.4047181, -79.8242696),
+(43.6201698, -79.4833611),
+(43.7703396, -79.703017),
+(43.684467, -79.7679405),
+(43.8146689, -79.5734157),
+(43.5062199, -79.6427766),
+(43.5350934, -79.7055681),
+(43.9760691, -79.2589597),
+(43.5967029, -79.5344793),
+(43.891482, -79.457138),
+(43.6411298, -79.5284464),
+(43.7504482, -79.770012),
+(43.9645387, -79.2488277),
+(43.628159, -79.898497),
+(43.7844981, -79.2964658),
+(43.7036226, -79.7685535),
+(43.8206416, -79.5581339),
+(43.8997958, -79.4419535),
+(43.7718089, -79.373956),
+(43.7191005, -79.3022627),
+(43.683431, -79.348767),
+(43.7043, -79.9029),
+(43.7318575, -79.7740451),
+(43.65221, -79.370799),
+(43.6421222, -79.4001609),
+(43.7681094, -79.4405388),
+(43.782532, -79.455287),
+(43.679292, -79.4461077),
+(43.8121488, -79.4569092),
+(43.7487288, -79.4329663),
+(43.6695417, -79.3837883),
+(43.8732842, -79.4530934),
+(43.647226, -79.3911),
+(43.921988, -79.519422),
+(43.7672502, -79.4384451),
+(43.559866, -79.7009666),
+(43.478579, -79.70921),
+(43.7448893, -79.3980004),
+(43.7937977, -79.2350364),
+(43.9647212, -79.4451956),
+(43.802582, -79.420565),
+(43.659603, -79.384784),
+(43.6993951, -79.7548379),
+(43.909998, -79.4331786),
+(43.8667026, -79.2203482),
+(43.6653017, -79.7963055),
+(43.7014439, -79.289252),
+(43.908555, -78.979307),
+(43.7747204, -79.4343092),
+(43.3435608, -79.7918215),
+(43.875089, -79.718707),
+(43.7750632, -79.3457849),
+(43.753829, -79.7364243),
+(43.8817107, -79.4161718),
+(43.6390951, -79.5351457),
+(43.640285, -79.559817),
+(43.8396291, -79.3910537),
+(43.647075, -79.50782),
+(43.536776, -79.601921),
+(43.5442651, -79.5994485),
+(43.846555, -79.5250477),
+(43.511767, -79.844183),
+(43.6676702, -79.3864205),
+(43.6191999, -79.4830575),
+(43.6796195, -79.7802695),
+(43.758071, -79.403165),
+(43.8263134, -79.6470566),
+(43.7302883, -79.6035826),
+(43.8431887, -79.0869897),
+(43.6611627, -79.4529506),
+(43.5077126, -79.8804733),
+(43.80564, -79.712861),
+(43.668542, -79.397174),
+(43.659069, -79.367188),
+(43.8151101, -79.1037059),
+(43.5960512, -79.5343077),
+(43.6673161, -79.3154417),
+(43.5805008, -79.6548227),
+(43.546571, -79.7186691),
+(43.49771, -79.887476),
+(43.6222485, -79.592394),
+(43.6223725, -79.6539939),
+(43.6498372, -79.4445731),
+(43.950761, -79.874882),
+(43.6456519, -79.5139868),
+(43.834078, -79.144601),
+(43.4003059, -79.8206956),
+(43.6374676, -79.4184499),
+(43.652588, -79.3676913),
+(43.7856596, -79.4202513),
+(43.6525884, -79.3637912),
+(43.6490923, -79.3885042),
+(43.62678, -79.650311),
+(43.6710574, -79.8112399),
+(43.656574, -79.3683276),
+(43.6632069, -79.5841018),
+(43.5709343, -79.75942),
+(43.4277485, -79.7647268),
+(43.8067321, -79.7267111),
+(43.6120826, -79.5418484),
+(43.8007128, -79.3170366),
+(43.9188358, -78.9223224),
+(43.8984529, -79.0223244),
+(43.7146015, -79.354391),
+(43.729322, -79.2529134),
+(43.9090624, -78.9131181),
+(43.447167, -79.687053),
+(43.6765756, -79.8095196),
+(43.3923239, -79.8297819),
+(43.8659, -79.4342),
+(43.6501, -79.9175),
+(43.9711, -79.2461),
+(43.7901984, -79.6009603),
+(43.8117, -79.6306),
+(43.88, -79.43),
+(43.9318, -79.411),
+(43.3942593, -79.8296393),
+(43.8575, -79.0534),
+(43.6334086, -79.7970504),
+(43.8793, -79.497),
+(43.8141951, -79.7242259),
+(43.6514942, -79.7696292),
+(43.676273, -79.338363),
+(43.6989613, -79.4244115),
+(43.4022325, -79.788762),
+(43.5563609, -79.700108),
+(43.8559576, -79.0818026),
+(43.6839, -79.4824),
+(43.714455, -79.454411),
+(43.6789739, -79.729878),
+(43.571114, -79.566798),
+(43.4937942, -79.700813),
+(43.5627912, -79.7256475),
+(43.5246987, -79.6987852),
+(43.9694108, -79.4875074),
+(43.8518786, -79.3273662),
+(43.6497827, -79.3804402),
+(43.7994134, -79.5522788),
+(43.8645025, -79.5679757),
+(43.7776863, -79.4270995),
+(43.8205795, -79.287934),
+(43.779725, -79.414445),
+(43.4767612, -79.6338277),
+(43.782553, -79.6033834),
+(43.7533079, -79.7389241),
+(43.7739867, -79.3595046),
+(43.8176, -79.4921),
+(43.6451081, -79.757374),
+(43.6238516, -79.878298),
+(43.7610496, -79.8319554),
+(43.8355352, -79.6526618),
+(43.8812, -79.1253),
+(43.9139788, -78.9759081),
+(43.4922064, -79.8464476),
+(43.665588, -79.383028),
+(43.8117, -79.6306),
+(43.3918628, -79.8298439),
+(43.836224, -79.2782681),
+(43.6241341, -79.4858146),
+(43.7474, -79.5903),
+(43.7622868, -79.7840826),
+(43.6904837, -79.8342389),
+(43.8482725, -79.4498615),
+(43.803238, -79.4153963),
+(43.7064611, -79.7536934),
+(43.8589797, -79.5040618),
+(43.5816498, -79.6480951),
+(43.3995859, -79.7038426),
+(43.7676128, -79.7546178),
+(43.5336328, -79.6256087),
+(43.6016906, -79.6473268),
+(43.599726, -79.638381),
+(43.7604326, -79.6902569),
+(43.8896735, -79.4630795),
+(43.7675016, -79.2877702),
+(43.5656934, -79.7505529),
+(43.8521806, -79.3373192),
+(43.7881889, -79.7486126),
+(43.6484128, -79.3764665),
+(43.9089031, -79.3786047),
+(43.9318, -79.411),
+(43.4333511, -79.6995725),
+(43.4555564, -79.6772657),
+(43.7083045, -79.4525829),
+(43.606015, -79.654859),
+(43.5592261, -79.753395),
+(43.3563341, -79.7648958),
+(43.5583988, -79.6001052),
+(43.691772, -79.5270004),
+(43.8915156, -79.8985097),
+(43.678247, -79.729876),
+(43.6035205, -79.7229745),
+(43.6600002, -79.4517988),
+(43.811668, -79.726536),
+(43.6719565, -79.7873183),
+(43.7070826, -79.3977712),
+(43.780234, -79.419673),
+(43.6468455, -79.4028294),
+(43.594797, -79.534494),
+(43.643012, -79.406201),
+(43.6496742, -79.3981713),
+(43.7323343, -79.420748),
+(43.6620369, -79.3865221),
+(43.866964, -79.063429),
+(43.8187589, -79.1300632),
+(43.8997402, -79.2931977),
+(43.6637514, -79.7802816),
+(43.8007128, -79.3170366),
+(43.650779, -79.383519),
+(43.7444159, -79.3910667),
+(43.6795665, -79.3995161),
+(43.3776739, -79.8042364),
+(43.8617175, -79.4355742),
+(43.6318518, -79.6036581),
+(43.6598992, -79.4817372),
+(43.5700533, -79.6309451),
+(43.9387168, -79.4680269),
+(43.3938431, -79.7117345),
+(43.79622, -79.142447),
+(43.5896117, -79.6523764),
+(43.650405, -79.392168),
+(43.5821801, -79.7711336),
+(43.8819503, -79.0495909),
+(43.7564719, -79.7347764),
+(43.695303, -79.447018),
+(43.9339901, -79.4715628),
+(43.7782517, -79.4347568),
+(43.8898713, -79.406379),
+(43.7694392, -79.4160861),
+(43.658493, -79.384854),
+(43.7922949, -79.7504551),
+(43.895128, -79.4050381),
+(43.9533809, -78.9444964),
+(43.9532411, -79.4438399),
+(43.7239547, -79.7148731),
+(43.908969, -79.4390763),
+(43.7024834, -79.8183845),
+(43.6752989, -79.8276732),
+(43.6107497, -79.6569604),
+(43.9124557, -78.8967887),
+(43.829145, -78.990392),
+(43.665666, -79.382607),
+(43.7190541, -79.2383063),
+(43.5896117, -79.6523764),
+(43.7975582, -79.414986),
+(43.6595018, -79.3847849),
+(43.376648, -79.80065),
+(43.7582885, -79.586393),
+(43.428238, -79.7492063),
+(43.6936538, -79.8403649),
+(43.970267, -79.263706),
+(43.8117632, -79.4388514),
+(43.7827154, -79.429505),
+(43.9022238, -79.2520534),
+(43.6031028, -79.5919361),
+(43.7297244, -79.4419558),
+(43.5689488, -79.5654176),
+(43.7804885, -79.4470239),
+(43.6497455, -79.4105005),
+(43.7593645, -79.4058308),
+(43.6800965, -79.3921327),
+(43.7301549, -79.7941791),
+(43.740625, -79.3990231),
+(43.8724755, -79.4418904),
+(43.7654037, -79.2879246),
+(43.70693, -79.499761),
+(43.7823554, -79.4311434),
+(43.6571106, -79.7148876),
+(43.75371, -79.8763784),
+(43.8935595, -79.375744),
+(43.859254, -79.119665),
+(43.6667966, -79.3886683),
+(43.8504522, -79.4696633),
+(43.6546284, -79.4208488),
+(43.7465433, -79.43541),
+(43.7862635, -79.3695798),
+(43.768597, -79.3896),
+(43.7875307, -79.417827),
+(43.6620882, -79.3856802),
+(43.758761, -79.576257),
+(43.9186243, -78.9108494),
+(43.6548441, -79.3846797),
+(43.648321, -79.3928867),
+(43.637453, -79.418387),
+(43.7624163, -79.4128224),
+(43.7982779, -79.3430673),
+(43.8005838, -79.4053655),
+(43.8208244, -79.0848127),
+(43.7945882, -79.4380612),
+(43.792665, -79.237219),
+(43.7793669, -79.4064017),
+(43.8348422, -79.4909889),
+(43.8338415, -79.4903767),
+(43.9680766, -79.276052),
+(43.9000365, -79.2537898),
+(43.843778, -79.2709682),
+(43.56222, -79.575467),
+(43.6070809, -79.5433997),
+(43.7939829, -79.4434454),
+(43.79615, -79.3539499),
+(43.6539796, -79.3885197),
+(43.703816, -79.399822),
+(43.795742, -79.4173293),
+(43.9229615, -79.454053),
+(43.6545521, -79.5215743),
+(43.6184563, -79.7434628),
+(43.6547114, -79.3792073),
+(43.8934211, -79.2196975),
+(43.8037782, -79.3503596),
+(43.6683716, -79.5426154),
+(43.8139532, -79.6049461),
+(43.6337605, -79.4899705),
+(43.6832877, -79.3024054),
+(43.6406816, -79.376999),
+(43.6758498, -79.7336825),
+(43.6488273, -79.3780964),
+(43.8026456, -79.3228686),
+(43.869143, -79.473773),
+(43.9449423, -79.4322093),
+(43.766836, -79.198163),
+(43.3465148, -79.8463698),
+(43.648909, -79.386075),
+(43.8057263, -79.4241206),
+(43.6363058, -79.7143093),
+(43.5359841, -79.6495258),
+(43.773248, -79.345838),
+(43.8226089, -79.4247113),
+(43.3783814, -79.7988286),
+(43.7162449, -79.423762),
+(43.76972, -79.366921),
+(43.704848, -79.2907636),
+(43.7560372, -79.1828855),
+(43.7651793, -79.1819983),
+(43.7006393, -79.2760185),
+(43.8170814, -79.4165541),
+(43.8112083, -79.4616662),
+(43.7041341, -79.853586),
+(43.5285219, -79.8444923),
+(43.9419648, -78.8933791),
+(43.6708286, -79.4562831),
+(43.7378891, -79.7230589),
+(43.6209169, -79.5068931),
+(43.7209346, -79.411508),
+(43.661123, -79.4058778),
+(43.7505689, -79.2425288),
+(43.5623402, -79.6186935),
+(43.522968, -79.836003),
+(43.9687097, -79.2515382),
+(43.5875756, -79.6750138),
+(43.7405091, -79.7283684),
+(43.8524091, -79.5112967),
+(43.3513459, -79.8160013),
+(43.646434, -79.397806),
+(43.7424033, -79.4021006),
+(43.6962745, -79.2927586),
+(43.672166, -79.377049),
+(43.6698863, -79.3909824),
+(43.6679848, -79.3755762),
+(43.8242246, -79.4747088),
+(43.8399987, -79.0994387),
+(43.9221659, -79.4591391),
+(43.9658148, -78.9397802),
+(43.7214936, -79.3956384),
+(43.534297, -79.600505),
+(43.7859764, -79.1975731),
+(43.7483422, -79.5495289),
+(43.621078, -79.521523),
+(43.9106144, -78.9038774),
+(43.793183, -79.453798),
+(43.6731833, -79.4611267),
+(43.6957356, -79.4540034),
+(43.666647, -79.448206),
+(43.564132, -79.860855),
+(43.6818873, -79.4110167),
+(43.665666, -79.382607),
+(43.756947, -79.4064994),
+(43.7787489, -79.4171422),
+(43.786346, -79.419292),
+(43.649074, -79.404884),
+(43.7066889, -79.3804467),
+(43.7639218, -79.3668643),
+(43.708287, -79.391964),
+(43.8107918, -79.3590482),
+(43.9732247, -79.2396598),
+(43.8571799, -79.3261757),
+(43.665666, -79.382607),
+(43.8318668, -79.2337799),
+(43.811118, -79.559299),
+(43.7496021, -79.2668943),
+(43.7802816, -79.4455163),
+(43.5944866, -79.632353),
+(43.5847029, -79.6415724),
+(43.3539883, -79.8387967),
+(43.609209, -79.5583318),
+(43.9744979, -79.4473933),
+(43.8939062, -79.290452),
+(43.8205905, -79.4237985),
+(43.594797, -79.534494),
+(43.773403, -79.344636),
+(43.448163, -79.740535),
+(43.6012373, -79.5249438),
+(43.349837, -79.8851514),
+(43.851994, -78.9413657),
+(43.654085, -79.354172),
+(43.643132, -79.376486),
+(43.866321, -79.435621),
+(43.977234, -79.261816),
+(43.6283161, -79.7547458),
+(43.7320681, -79.4303849),
+(43.8225024, -79.607995),
+(43.9648544, -79.24389),
+(43.7724923, -79.1837248),
+(43.793896, -79.417397),
+(43.7922564, -79.3153309),
+(43.9021112, -78.943731),
+(43.9028585, -78.9768567),
+(43.7226789, -79.3801588),
+(43.8175253, -79.3181227),
+(43.680806, -79.3389588),
+(43.8590201, -79.5088869),
+(43.7810628, -79.2485568),
+(43.8511, -79.105366),
+(43.7318577, -79.576954),
+(43.8083789, -79.4461995),
+(43.66955, -79.383776),
+(43.506779, -79.8776673),
+(43.6399438, -79.4735875),
+(43.5615679, -79.7311595),
+(43.6888631, -79.7940534),
+(43.5698797, -79.576748),
+(43.7942854, -79.2873764),
+(43.700445, -79.397789),
+(43.7252229, -79.6287903),
+(43.6870922, -79.4355427),
+(43.6952107, -79.5508744),
+(43.707083, -79.397771),
+(43.7334078, -79.4356985),
+(43.6968333, -79.3939934),
+(43.766764, -79.385993),
+(43.7726667, -79.7417898),
+(43.9069438, -79.3786245),
+(43.8012387, -79.3985109),
+(43.8488033, -79.4295915),
+(43.7614554, -79.7365311),
+(43.9649367, -79.2361445),
+(43.8020778, -79.3315811),
+(43.5852249, -79.7428385),
+(43.7507925, -79.7675869),
+(43.7834295, -79.5949341),
+(43.8247242, -79.4704958),
+(43.590497, -79.63578),
+(43.7431246, -79.8103573),
+(43.60596, -79.655123),
+(43.682424, -79.279432),
+(43.7295846, -79.7296348),
+(43.94966, -78.918945),
+(43.6568586, -79.3836642),
+(43.669593, -79.38058),
+(43.6706377, -79.3901448),
+(43.8551602, -79.4207989),
+(43.7066497, -79.2982284),
+(43.5857073, -79.6464202),
+(43.66983, -79.381818),
+(43.529907, -79.846203),
+(43.7985239, -79.3153793),
+(43.6154368, -79.6252354),
+(43.6390951, -79.5351457),
+(43.643939, -79.401477),
+(43.7158923, -79.2377266),
+(43.6016906, -79.6473268),
+(43.9082505, -79.4322974),
+(43.8956651, -78.9568582),
+(43.4956301, -79.7173717),
+(43.8711747, -79.465073),
+(43.7164916, -79.8294463),
+(43.8870591, -79.2625378),
+(43.8710914, -79.082758),
+(43.8677, -78.9743),
+(43.8581, -78.9318),
+(43.8793, -79.497),
+(43.872229, -79.468727),
+(43.4803396, -79.7360838),
+(43.8915239, -78.9178202),
+(43.650996, -79.393888),
+(43.764933, -79.410561),
+(43.8007402, -79.3834263),
+(43.8738212, -79.3523399),
+(43.6991784, -79.4483194),
+(43.6565113, -79.4453793),
+(43.6585306, -79.346833),
+(43.6686162, -79.3843472),
+(43.868958, -79.026466),
+(43.7918858, -79.4571177),
+(43.758194, -79.5885159),
+(43.689739, -79.4480973),
+(43.561639, -79.712551),
+(43.8830002, -79.8520869),
+(43.8410112, -79.4651145),
+(43.6184125, -79.6387417),
+(43.734331, -79.534458),
+(43.8864737, -79.2692408),
+(43.8445495, -79.089615),
+(43.70529, -79.397827),
+(43.7617153, -79.3817303),
+(43.646084, -79.365012),
+(43.6593726, -79.3825439),
+(43.622729, -79.48319),
+(43.5850975, -79.6461541),
+(43.6717727, -79.3947803),
+(43.6904137, -79.8160384),
+(43.7047318, -79.8139049),
+(43.874469, -79.432662),
+(43.883934, -79.047635),
+(43.779746, -79.4181433),
+(43.6201607, -79.508481),
+(43.650718, -79.49575),
+(43.7138263, -79.8102237),
+(43.7260607, -79.4968256),
+(43.8077831, -79.3917533),
+(43.6897126, -79.2989718),
+(43.9211371, -79.470702),
+(43.41821, -79.7051989),
+(43.624595, -79.480119),
+(43.7021752, -79.763478),
+(43.4443931, -79.7655091),
+(43.9555627, -78.9112313),
+(43.8152932, -79.7261025),
+(43.9381379, -79.4459982),
+(43.8693746, -79.317758),
+(43.8054856, -79.4495125),
+(43.8426931, -79.5169233),
+(43.8332745, -79.4854625),
+(43.9407421, -79.4443385),
+(43.9788372, -79.4919871),
+(43.6226636, -79.5872963),
+(43.4785433, -79.7386257),
+(43.4043282, -79.821991),
+(43.6004413, -79.5697589),
+(43.683985, -79.564277),
+(43.5971374, -79.6652055),
+(43.6710573, -79.8040616),
+(43.5890579, -79.6309412),
+(43.6650324, -79.447587),
+(43.593015, -79.708597),
+(43.6454885, -79.5303865),
+(43.6306779, -79.4758539),
+(43.862636, -79.041391),
+(43.813653, -79.32323),
+(43.8485336, -79.4327552),
+(43.701585, -79.771813),
+(43.7458891, -79.5158122),
+(43.7939829, -79.4434454),
+(43.9182238, -79.4662114),
+(43.8440441, -79.4878336),
+(43.8005417, -79.4191588),
+(43.8211037, -79.4399479),
+(43.9093328, -79.3777612),
+(43.3824119, -79.7402414),
+(43.7001923, -79.756787),
+(43.6803099, -79.8084479),
+(43.717448, -79.831553),
+(43.497059, -79.6998944),
+(43.5960512, -79.5343077),
+(43.4875328, -79.7270423),
+(43.5103475, -79.6271995),
+(43.7416363, -79.7414763),
+(43.6871794, -79.5048436),
+(43.6243335, -79.485323),
+(43.759027, -79.757243),
+(43.6172, -79.492424),
+(43.6828878, -79.5273754),
+(43.6822721, -79.7422132),
+(43.5735519, -79.6204246),
+(43.94391, -79.775605),
+(43.5998052, -79.5466281),
+(43.721347, -79.733575),
+(43.6709686, -79.7433219),
+(43.7914635, -79.9283422),
+(43.7348274, -79.7220433),
+(43.7446909, -79.3498725),
+(43.661933, -79.3782001),
+(43.6520368, -79.3733358),
+(43.7181463, -79.72599),
+(43.7213367, -79.7081443),
+(43.7325022, -79.2481332),
+(43.7033, -79.828427),
+(43.7369733, -79.4299885),
+(43.7073389, -79.394278),
+(43.652508, -79.3885848),
+(43.6853614, -79.3950269),
+(43.6423906, -79.4247385),
+(43.8970671, -78.8890692),
+(43.7759878, -79.3987954),
+(43.6648469, -79.3861096),
+(43.6733388, -79.3968295),
+(43.8505548, -78.9465347),
+(43.6667966, -79.3886683),
+(43.700767, -79.407987),
+(43.8737578, -79.1225325),
+(43.665666, -79.382607),
+(43.6593521, -79.3856503),
+(43.7767049, -79.4110639),
+(43.6404948, -79.3808155),
+(43.7312911, -79.3934479),
+(43.6500154, -79.3873578),
+(43.641521, -79.415204),
+(43.6768701, -79.4335111),
+(43.920333, -78.9174978),
+(43.7961895, -79.3119872),
+(43.6239417, -79.4884467),
+(43.6882357, -79.3100233),
+(43.7560349, -79.4108793),
+(43.6387756, -79.4163147),
+(43.8136201, -79.3298006),
+(43.7803426, -79.388221),
+(43.6673247, -79.3727384),
+(43.866309, -79.029406),
+(43.849678, -78.9469136),
+(43.873693, -79.062149),
+(43.940519, -78.884837),
+(43.887633, -79.046162),
+(43.854714, -79.021384),
+(43.8766856, -79.4339105),
+(43.8240409, -79.392354),
+(43.7752161, -79.4133461),
+(43.8565448, -79.3244227),
+(43.656537, -79.354497),
+(43.8611036, -79.3058536),
+(43.9140954, -79.4353744),
+(43.7879818, -79.3554579),
+(43.5842175, -79.6164494),
+(43.8375018, -79.6190981),
+(43.589678, -79.630472),
+(43.5334514, -79.8420464),
+(43.6871794, -79.5048436),
+(43.721268, -79.732148),
+(43.564446, -79.7271362),
+(43.72643, -79.7143814),
+(43.4925287, -79.7174344),
+(43.7333727, -79.5906723),
+(43.621078, -79.521523),
+(43.63932, -79.385723),
+(43.8551057, -79.3453338),
+(43.3703773, -79.7288226),
+(43.760402, -79.40901),
+(43.3486176, -79.8067668),
+(43.8091676, -79.455206),
+(43.4392429, -79.737204),
+(43.7902389, -79.600766),
+(43.6638679, -79.7766695),
+(43.8147713, -79.4432081),
+(43.6222561, -79.4825565),
+(43.6022361, -79.6498521),
+(43.6448465, -79.4022849),
+(43.4973119, -79.7042877),
+(43.645621, -79.389497),
+(43.6327973, -79.7686575),
+(43.9390813, -79.4747451),
+(43.6149072, -79.7444803),
+(43.6647797, -79.3409446),
+(43.5262629, -79.65372),
+(43.7736002, -79.2069556),
+(43.8100647, -79.4487117),
+(43.5330392, -79.8541709),
+(43.7294, -79.3373),
+(43.7963988, -79.1671812),
+(43.8429639, -79.5485961),
+(43.7506, -79.4435),
+(43.9318, -79.411),
+(43.854404, -79.7004542),
+(43.5952639, -79.7083251),
+(43.8117, -79.6306),
+(43.8560345, -79.3280239),
+(43.8066203, -79.7285117),
+(43.4785311, -79.7459985),
+(43.7617716, -79.8258369),
+(43.8659, -79.4342),
+(43.7694183, -79.3686931),
+(43.886862, -79.048412),
+(43.5661014, -79.7466393),
+(43.6432587, -79.3764035),
+(43.5816498, -79.6480951),
+(43.915045, -79.645189),
+(43.851427, -78.940724),
+(43.7795711, -79.6983228),
+(43.6375103, -79.4005804),
+(43.6891866, -79.8574258),
+(43.6372054, -79.4035754),
+(43.9391999, -79.4691406),
+(43.6676702, -79.3864205),
+(43.3798023, -79.7432543),
+(43.3733294, -79.8365383),
+(43.526368, -79.846905),
+(43.5551832, -79.7390383),
+(43.781465, -79.726097),
+(43.3867476, -79.7221636),
+(43.4358343, -79.6946972),
+(43.3978356, -79.8159123),
+(43.391031, -79.783862),
+(43.812554, -79.4544922),
+(43.6936358, -79.8023319),
+(43.7122855, -79.6985085),
+(43.8493964, -79.2736596),
+(43.6692155, -79.4530051),
+(43.8684802, -79.5180359),
+(43.9713671, -78.9444532),
+(43.5096367, -79.8521782),
+(43.837511, -78.984112),
+(43.6860744, -79.8305874),
+(43.488954, -79.869917),
+(43.3477753, -79.8062143),
+(43.493466, -79.840259),
+(43.820405, -79.6258802),
+(43.5656802, -79.6120757),
+(43.799707, -79.4181582),
+(43.8356224, -79.4394752),
+(43.7615186, -79.4640085),
+(43.6108333, -79.6496434),
+(43.7647371, -79.4935259),
+(43.869017, -79.026254),
+(43.548701, -79.7248802),
+(43.6459582, -79.3898731),
+(43.446344, -79.725153),
+(43.6393446, -79.3929716),
+(43.4476595, -79.7083698),
+(43.6164915, -79.7458242),
+(43.8268733, -79.3251829),
+(43.7366089, -79.7480521),
+(43.818925, -79.021441),
+(43.3467657, -79.850725),
+(43.6357104, -79.3988424),
+(43.8050452, -79.7287954),
+(43.854705, -79.031997),
+(43.638563, -79.412811),
+(43.982395, -79.633002),
+(43.8501943, -79.4064839),
+(43.4016533, -79.9292351),
+(43.79615, -79.3539499),
+(43.8443265, -79.4021773),
+(43.685137, -79.2809765),
+(43.732864, -79.7510983),
+(43.7927851, -79.1583503),
+(43.6748037, -79.2875099),
+(43.3922129, -79.7826151),
+(43.642311, -79.38123),
+(43.6400907, -79.3786023),
+(43.83998, -79.4881873),
+(43.7492059, -79.3608746),
+(43.6823901, -79.2796015),
+(43.6598587, -79.4884017),
+(43.711535, -79.4353189),
+(43.5883573, -79.6395201),
+(43.609209, -79.5583318),
+(43.58813, -79.640126),
+(43.7595056, -79.8234441),
+(43.7796544, -79.2829265),
+(43.622729, -79.48319),
+(43.8190204, -79.6092996),
+(43.8772017, -79.422479),
+(43.7778723, -79.2807506),
+(43.6605208, -79.3785421),
+(43.7657237, -79.3834602),
+(43.653687, -79.4187412),
+(43.809346, -79.41344),
+(43.754143, -79.4486376),
+(43.6371667, -79.404733),
+(43.512309, -79.65286),
+(43.7489254, -79.5917971),
+(43.9040143, -79.4005292),
+(43.7348494, -79.7685711),
+(43.5410701, -79.6018536),
+(43.7935688, -79.4213652),
+(43.9225233, -78.9534705),
+(43.8741377, -79.2448158),
+(43.9534235, -78.9711935),
+(43.9037673, -79.6574998),
+(43.648317, -79.393065),
+(43.769664, -79.413783),
+(43.6363712, -79.4027602),
+(43.641087, -79.381405),
+(43.769718, -79.41383),
+(43.7672196, -79.3908157),
+(43.78291, -79.417394),
+(43.6595018, -79.3847849),
+(43.6423906, -79.4247385),
+(43.7599764, -79.2586042),
+(43.6667966, -79.3886683),
+(43.6810916, -79.2802931),
+(43.8159627, -79.3310626),
+(43.905756, -78.969102),
+(43.781795, -79.246575),
+(43.8742465, -79.3357115),
+(43.93946, -79.466757),
+(43.6238862, -79.4792234),
+(43.9623693, -79.4369718),
+(43.631322, -79.592586),
+(43.6657347, -79.447861),
+(43.661781, -79.384218),
+(43.7681782, -79.3906547),
+(43.372041, -79.8041742),
+(43.7657785, -79.4100647),
+(43.665666, -79.382607),
+(43.665666, -79.382607),
+(43.665753, -79.385579),
+(43.7872283, -79.1946802),
+(43.802705, -79.4192904),
+(43.7577566, -79.3814139),
+(43.939683, -79.655925),
+(43.7028015, -79.4513882),
+(43.3506558, -79.8128351),
+(43.5800439, -79.6250026),
+(43.747413, -79.224651),
+(43.6601105, -79.3605898),
+(43.914941, -79.4412465),
+(43.8014785, -79.1539126),
+(43.6651112, -79.4886038),
+(43.6401213, -79.3812266),
+(43.7047403, -79.7673576),
+(43.6398049, -79.3979687),
+(43.7102879, -79.303001),
+(43.779735, -79.366223),
+(43.7844276, -79.4161322),
+(43.737385, -79.2907484),
+(43.7405594, -79.4245956),
+(43.569813, -79.6304482),
+(43.8737636, -79.086527),
+(43.8805211, -79.2872262),
+(43.7703, -79.413645),
+(43.619564, -79.5103162),
+(43.6449599, -79.3643305),
+(43.7692297, -79.411935),
+(43.897327, -79.4473026),
+(43.7430899, -79.211373),
+(43.6539796, -79.3885197),
+(43.91521, -79.642209),
+(43.5550514, -79.5833327),
+(43.5130706, -79.6255887),
+(43.6830187, -79.4180049),
+(43.4016688, -79.7882484),
+(43.7255754, -79.5230345),
+(43.7010661, -79.8254968),
+(43.8396633, -79.5679172),
+(43.9157575, -79.538157),
+(43.7858274, -79.2915268),
+(43.6489, -79.392205),
+(43.7752003, -79.412361),
+(43.641579, -79.415902),
+(43.7831752, -79.3202693),
+(43.437345, -79.716022),
+(43.5874623, -79.6414072),
+(43.6676702, -79.3864205),
+(43.975297, -79.246042),
+(43.9040841, -79.2563332),
+(43.649696, -79.398202),
+(43.855302, -79.040422),
+(43.645375, -79.877219),
+(43.7940191, -79.1242582),
+(43.7440854, -79.4010926),
+(43.6726511, -79.388732),
+(43.6866509, -79.3995111),
+(43.651936, -79.394743),
+(43.6490534, -79.3861546),
+(43.9318, -79.411),
+(43.8227273, -79.3877869),
+(43.8298031, -79.379595),
+(43.9562448, -79.4535255),
+(43.899422, -79.2232002),
+(43.8228271, -79.3989259),
+(43.6758169, -79.5475719),
+(43.7065673, -79.5124902),
+(43.7012318, -79.4486233),
+(43.512022, -79.840375),
+(43.5589545, -79.6438869),
+(43.5392365, -79.6001881),
+(43.5980183, -79.6213526),
+(43.840289, -79.392027),
+(43.8999, -78.914515),
+(43.6998074, -79.4189457),
+(43.630306, -79.93046),
+(43.6477053, -79.5860438),
+(43.7847637, -79.6135763),
+(43.8719421, -79.4268026),
+(43.650161, -79.930178),
+(43.6909738, -79.3505854),
+(43.9580123, -79.4394975),
+(43.4864684, -79.8762441),
+(43.654085, -79.354172),
+(43.9390957, -79.4766175),
+(43.6694723, -79.3874877),
+(43.640783, -79.40121),
+(43.57592, -79.7740978),
+(43.9167218, -78.9186822),
+(43.646099, -79.365136),
+(43.8890923, -79.2664697),
+(43.7484859, -79.4952603),
+(43.8236775, -79.4491149),
+(43.5524026, -79.7503458),
+(43.8140985, -79.4514793),
+(43.6454468, -79.7334741),
+(43.3937868, -79.711106),
+(43.7549969, -79.5722286),
+(43.8332895, -79.4951178),
+(43.8631591, -79.3380648),
+(43.6591662, -79.9088837),
+(43.642422, -79.3755829),
+(43.9617028, -78.969589),
+(43.7218509, -79.4262468),
+(43.725109, -79.7002685),
+(43.8428417, -79.4262012),
+(43.7075225, -79.392402),
+(43.908679, -79.2469741),
+(43.9034828, -79.4403346),
+(43.6977219, -79.440005),
+(43.725655, -79.398456),
+(43.3708943, -79.8343871),
+(43.3706106, -79.8343855),
+(43.6557184, -79.9313693),
+(43.5187137, -79.8507639),
+(43.362748, -79.7481797),
+(43.605548, -79.675623),
+(43.476725, -79.688622),
+(43.626013, -79.894091),
+(43.7547, -79.372485),
+(43.5967029, -79.5344793),
+(43.9716303, -79.2219425),
+(43.7683764, -79.2124157),
+(43.8937987, -79.2546305),
+(43.6943623, -79.3093245),
+(43.661725, -79.384128),
+(43.7703, -79.413645),
+(43.7185483, -79.8208202),
+(43.6429451, -79.3805034),
+(43.78291, -79.417394),
+(43.7379409, -79.4255601),
+(43.718243, -79.37642),
+(43.8073965, -79.2671295),
+(43.6951374, -79.3208556),
+(43.8591058, -79.4372759),
+(43.6384158, -79.582077),
+(43.4219945, -79.7723611),
+(43.804606, -79.212387),
+(43.8378043, -79.2764902),
+(43.3434654, -79.7904648),
+(43.3413947, -79.7956072),
+(43.717218, -79.9358897),
+(43.878334, -79.352188),
+(43.5269974, -79.6541593),
+(43.434214, -79.776184),
+(43.6791701, -79.5180055),
+(43.4149174, -79.7102973),
+(43.717865, -79.828857),
+(43.639783, -79.397947),
+(43.641087, -79.381405),
+(43.851993, -79.43178),
+(43.7700855, -79.4402409),
+(43.843083, -79.425925),
+(43.7437176, -79.2456657),
+(43.9675306, -78.9497941),
+(43.6468329, -79.3752164),
+(43.9774085, -79.4747086),
+(43.846632, -79.43836),
+(43.7752161, -79.4133461),
+(43.769664, -79.413783),
+(43.494925, -79.8717301),
+(43.637225, -79.4037),
+(43.7409219, -79.2911107),
+(43.6620882, -79.3856802),
+(43.7639657, -79.8304003),
+(43.6460155, -79.3940644),
+(43.7810628, -79.2485568),
+(43.770256, -79.2871781),
+(43.7831209, -79.3987281),
+(43.685364, -79.4366604),
+(43.624595, -79.480119),
+(43.7740321, -79.2580098),
+(43.6899231, -79.2976766),
+(43.6403228, -79.3917567),
+(43.9825441, -79.4827891),
+(43.6916185, -79.4627873),
+(43.8452981, -79.4589418),
+(43.8637063, -79.440589),
+(43.5554368, -79.7482852),
+(43.4698162, -79.7471018),
+(43.872531, -79.022982),
+(43.904543, -79.4355601),
+(43.384717, -79.817382),
+(43.6656028, -79.4137405),
+(43.6436277, -79.5043039),
+(43.8410854, -79.2855675),
+(43.9391561, -79.4455705),
+(43.6440617, -79.3894386),
+(43.717364, -79.829605),
+(43.4300041, -79.7672994),
+(43.7459265, -79.7788376),
+(43.712675, -79.840813),
+(43.638299, -79.582237),
+(43.9266911, -79.5122681),
+(43.71166, -79.324539),
+(43.5445398, -79.7009559),
+(43.7005632, -79.3105504),
+(43.6568058, -79.3750924),
+(43.785204, -79.409031),
+(43.665666, -79.382607),
+(43.6399005, -79.3905103),
+(43.8779688, -79.4492459),
+(43.960864, -78.9135827),
+(43.840667, -79.393288),
+(43.6898448, -79.3127857),
+(43.584381, -79.6519398),
+(43.4273448, -79.7313502),
+(43.647524, -79.387335),
+(43.8401874, -79.2867746),
+(43.7225005, -79.8079905),
+(43.6684578, -79.772701),
+(43.7377481, -79.5113152),
+(43.7855326, -79.3682668),
+(43.823302, -79.129185),
+(43.878251, -79.051014),
+(43.4039079, -79.7100979),
+(43.6842512, -79.8079003),
+(43.5646574, -79.6657993),
+(43.8080689, -79.6109004),
+(43.8036515, -79.4505507),
+(43.685286, -79.761492),
+(43.445879, -79.7082266),
+(43.6806683, -79.7918873),
+(43.6559105, -79.5159025),
+(43.827088, -79.09158),
+(43.7925486, -79.3037522),
+(43.641785, -79.379006),
+(43.835247, -79.141997),
+(43.7268093, -79.4020116),
+(43.6632625, -79.34844),
+(43.9131067, -78.96192),
+(43.6411777, -79.3937202),
+(43.9121058, -78.8878215),
+(43.847647, -79.25228),
+(43.676041, -79.558165),
+(43.8629479, -79.2411178),
+(43.7724448, -79.393289),
+(43.860066, -78.938789),
+(43.739924, -79.434866),
+(43.6822551, -79.5481405),
+(43.6371018, -79.5008655),
+(43.7813679, -79.5957636),
+(43.8465981, -79.471566),
+(43.6611781, -79.4409001),
+(43.7173297, -79.5326653),
+(43.6438543, -79.5291731),
+(43.657455, -79.5859143),
+(43.677065, -79.322465),
+(43.7739867, -79.3595046),
+(43.656806, -79.375092),
+(43.766764, -79.385993),
+(43.6896134, -79.4325777),
+(43.6482422, -79.3949808),
+(43.6533584, -79.390087),
+(43.6374676, -79.4184499),
+(43.7187973, -79.7385051),
+(43.6480233, -79.7343203),
+(43.5521282, -79.7554441),
+(43.6429592, -79.4306846),
+(43.7590528, -79.7374842),
+(43.553766, -79.581959),
+(43.553858, -79.711239),
+(43.718367, -79.830287),
+(43.6457578, -79.3946255),
+(43.5094415, -79.8445691),
+(43.5512965, -79.5943358),
+(43.8425055, -79.2721067),
+(43.6771165, -79.3275484),
+(43.670547, -79.380581),
+(43.3450041, -79.8897972),
+(43.4074609, -79.8150468),
+(43.7667084, -79.3098093),
+(43.5253141, -79.8806397),
+(43.6668981, -79.3890419),
+(43.8966101, -79.2669407),
+(43.8947344, -78.9546165),
+(43.8910403, -79.4552748),
+(43.8036515, -79.4505507),
+(43.6697789, -79.4478683),
+(43.447443, -79.686948),
+(43.66199, -79.434017),
+(43.403434, -79.804417),
+(43.6390951, -79.5351457),
+(43.8757916, -79.0920436),
+(43.7251734, -79.4276214),
+(43.7439705, -79.2635931),
+(43.7728732, -79.4554129),
+(43.774928, -79.33551),
+(43.774482, -79.3592026),
+(43.820418, -79.130745),
+(43.8496064, -79.5648463),
+(43.9001512, -79.2660727),
+(43.7524543, -79.2658188),
+(43.6366903, -79.5262624),
+(43.846483, -79.566664),
+(43.8729042, -79.4275302),
+(43.8070505, -79.4551611),
+(43.615229, -79.489016),
+(43.7640884, -79.3753644),
+(43.6882845, -79.4086639),
+(43.4252974, -79.7844288),
+(43.7213754, -79.5659705),
+(43.5592325, -79.5818658),
+(43.5665838, -79.6949328),
+(43.6313954, -79.5492978),
+(43.6596291, -79.7152671),
+(43.613136, -79.6607762),
+(43.6333489, -79.39983),
+(43.6126343, -79.6539773),
+(43.7265925, -79.6233842),
+(43.759752, -79.461853),
+(43.7114796, -79.709072),
+(43.8503808, -79.0099622),
+(43.55642, -79.740125),
+(43.7568656, -79.7346371),
+(43.7202729, -79.7142161),
+(43.5455436, -79.6853328),
+(43.5798519, -79.6285491),
+(43.757354, -79.409358),
+(43.560127, -79.737142),
+(43.6825697, -79.8473303),
+(43.5277101, -79.6539767),
+(43.4285, -79.7445),
+(43.5409056, -79.7278654),
+(43.6046367, -79.5534502),
+(43.5959983, -79.5477901),
+(43.6724665, -79.2862241),
+(43.672578, -79.28627),
+(43.623762, -79.604951),
+(43.8794877, -79.4225585),
+(43.6211556, -79.5215447),
+(43.7400561, -79.2728098),
+(43.9739552, -78.9418519),
+(43.6959683, -79.3957747),
+(43.7075225, -79.392402),
+(43.7707505, -79.6637375),
+(43.652433, -79.38886),
+(43.6363712, -79.4027602),
+(43.7449, -79.828559),
+(43.7645058, -79.288476),
+(43.7474115, -79.5599092),
+(43.6823354, -79.4856319),
+(43.789371, -79.664708),
+(43.7936532, -79.5499233),
+(43.6828608, -79.5300597),
+(43.7474, -79.5903),
+(43.5062118, -79.6443859),
+(43.3415378, -79.7989424),
+(43.3565262, -79.8162623),
+(43.4011826, -79.7139237),
+(43.7993897, -79.4490838),
+(43.5816498, -79.6480951),
+(43.71694, -79.260884),
+(43.8219046, -79.3821684),
+(43.9218512, -79.5358425),
+(43.6740094, -79.4124294),
+(43.665432, -79.40831),
+(43.802033, -79.275585),
+(43.691162, -79.840879),
+(43.7882208, -79.4494936),
+(43.6364331, -79.5575207),
+(43.8608861, -79.506176),
+(43.8886163, -79.3130133),
+(43.8288549, -79.4111336),
+(43.4836123, -79.8534907),
+(43.654776, -79.425277),
+(43.838892, -79.084382),
+(43.8006531, -79.3051929),
+(43.7582903, -79.4070459),
+(43.5592774, -79.6394023),
+(43.8575094, -79.3186998),
+(43.762598, -79.3938),
+(43.8243568, -79.425407),
+(43.7247945, -79.2353277),
+(43.848113, -79.478862),
+(43.9520637, -78.9472426),
+(43.7778723, -79.2807506),
+(43.7350429, -79.4208381),
+(43.7800627, -79.3930053),
+(43.6982888, -79.5480021),
+(43.8391533, -79.3859029),
+(43.68596, -79.853966),
+(43.4848211, -79.7302319),
+(43.6986033, -79.2715347),
+(43.7438205, -79.6017924),
+(43.8565979, -79.4960695),
+(43.7783322, -79.2825717),
+(43.752406, -79.454611),
+(43.5944489, -79.6346157),
+(43.7377965, -79.3055942),
+(43.7476968, -79.2138714),
+(43.4537817, -79.7523708),
+(43.7803283, -79.4481677),
+(43.5706038, -79.7428689),
+(43.6882394, -79.4383422),
+(43.5856516, -79.7876062),
+(43.6295841, -79.4908855),
+(43.7149634, -79.5430499),
+(43.682004, -79.843868),
+(43.801543, -79.73444),
+(43.5213571, -79.8416424),
+(43.555983, -79.582205),
+(43.9784137, -79.2365),
+(43.4089957, -79.699805),
+(43.530807, -79.845634),
+(43.6629571, -79.4610823),
+(43.641806, -79.403004),
+(43.6848879, -79.7911692),
+(43.586832, -79.791553),
+(43.779746, -79.4181433),
+(43.624995, -79.479045),
+(43.8406893, -79.4670326),
+(43.6466606, -79.438819),
+(43.7710571, -79.7541126),
+(43.858252, -79.433621),
+(43.7648322, -79.4339059),
+(43.6951904, -79.4095862),
+(43.594882, -79.634317),
+(43.843516, -79.426394),
+(43.7985645, -79.5835534),
+(43.3575904, -79.8133894),
+(43.6710104, -79.4496401),
+(43.6240514, -79.5277116),
+(43.9070205, -79.6561078),
+(43.5890786, -79.7516318),
+(43.876851, -79.032551),
+(43.8532467, -79.5252063),
+(43.9687697, -79.2665561),
+(43.8701389, -79.3014085),
+(43.9457675, -79.4825387),
+(43.5605185, -79.7243702),
+(43.833032, -79.565129),
+(43.8247986, -79.5789158),
+(43.5814259, -79.7228),
+(43.4080561, -79.7975075),
+(43.6884856, -79.2668986),
+(43.885547, -79.012727),
+(43.8043953, -79.405691),
+(43.4093686, -79.8170909),
+(43.641579, -79.415903),
+(43.3776672, -79.7932763),
+(43.6037091, -79.7316222),
+(43.648687, -79.9159),
+(43.669301, -79.384171),
+(43.6445412, -79.3764773),
+(43.7751229, -79.4138309),
+(43.7627348, -79.3464967),
+(43.7300903, -79.3322682),
+(43.6846135, -79.3569988),
+(43.6866108, -79.2872095),
+(43.6599507, -79.3849463),
+(43.6440617, -79.3894386),
+(43.643012, -79.406201),
+(43.648783, -79.388042),
+(43.6522442, -79.3776386),
+(43.752094, -79.297182),
+(43.852094, -79.432423),
+(43.581187, -79.647617),
+(43.5491973, -79.7200968),
+(43.479968, -79.712859),
+(43.7506974, -79.4650506),
+(43.8666211, -79.2943904),
+(43.6548441, -79.3846797),
+(43.660927, -79.5345097),
+(43.81121, -79.102616),
+(43.8128057, -79.4519464),
+(43.6394695, -79.3850744),
+(43.814434, -79.104712),
+(43.6539796, -79.3885197),
+(43.7070855, -79.3977452),
+(43.3832987, -79.8039491),
+(43.8529663, -79.5721764),
+(43.649256, -79.375765),
+(43.772018, -79.4208548),
+(43.6514779, -79.7817386),
+(43.73493, -79.463639),
+(43.685494, -79.414432),
+(43.7075225, -79.392402),
+(43.9196978, -78.9142853),
+(43.7245989, -79.5518205),
+(43.79326, -79.137192),
+(43.7454584, -79.3670288),
+(43.8985155, -79.4516208),
+(43.762714, -79.346424),
+(43.3961572, -79.8043383),
+(43.4399016, -79.739501),
+(43.7588282, -79.4464761),
+(43.6180336, -79.6049525),
+(43.70155, -79.858744),
+(43.9533, -79.4518135),
+(43.749148, -79.2263315),
+(43.774865, -79.313528),
+(43.700394, -79.845346),
+(43.6976794, -79.3097607),
+(43.8002725, -79.3737175),
+(43.804698, -79.2997868),
+(43.881959, -79.030372),
+(43.9573388, -79.4677698),
+(43.8564417, -79.3251868),
+(43.665666, -79.382607),
+(43.766751, -79.410332),
+(43.7949078, -79.3139676),
+(43.6881075, -79.3979987),
+(43.7211935, -79.2480273),
+(43.70465, -79.7064213),
+(43.6393446, -79.3929716),
+(43.792559, -79.449828),
+(43.7817207, -79.2473686),
+(43.7502504, -79.7529767),
+(43.929264, -78.887778),
+(43.6833566, -79.4186353),
+(43.4857301, -79.7097265),
+(43.7244264, -79.6259024),
+(43.7931132, -79.3485898),
+(43.9114898, -79.6629989),
+(43.7916822, -79.3847338),
+(43.8983359, -79.4470063),
+(43.640078, -79.928558),
+(43.479958, -79.726695),
+(43.8422158, -79.7812865),
+(43.7052154, -79.5268021),
+(43.3939455, -79.7132383),
+(43.6896326, -79.2683731),
+(43.7753462, -79.4018025),
+(43.5626228, -79.7348677),
+(43.8930246, -79.4338708),
+(43.6695135, -79.4224707),
+(43.7033227, -79.3998916),
+(43.6896304, -79.3706227),
+(43.646434, -79.397806),
+(43.645077, -79.364342),
+(43.6575162, -79.3767709),
+(43.4717578, -79.6896302),
+(43.8586114, -79.3240221),
+(43.746555, -79.5629067),
+(43.8231751, -79.3717502),
+(43.9823032, -79.2368413),
+(43.6332403, -79.5982495),
+(43.9628951, -78.9098074),
+(43.887104, -78.920092),
+(43.4116145, -79.7114053),
+(43.6062627, -79.6806568),
+(43.6702252, -79.3817578),
+(43.7872283, -79.1946802),
+(43.5488926, -79.7455016),
+(43.8554741, -79.5645114),
+(43.7905089, -79.7821717),
+(43.8965, -78.8965),
+(43.5947307, -79.6352183),
+(43.6628495, -79.3762687),
+(43.6654978, -79.3672247),
+(43.8004023, -79.3219642),
+(43.9085206, -78.9072092),
+(43.970586, -79.244284),
+(43.7219643, -79.8254379),
+(43.8498245, -79.4259626),
+(43.654587, -79.386656),
+(43.7842304, -79.1713827),
+(43.7134604, -79.4199252),
+(43.6736696, -79.8166034),
+(43.9081825, -79.4316069),
+(43.8476604, -79.5121181),
+(43.9219727, -78.9244036),
+(43.9017429, -79.2312897),
+(43.6902468, -79.788643),
+(43.7667355, -79.4234375),
+(43.7035255, -79.2940227),
+(43.4571396, -79.6576673),
+(43.7373096, -79.3066342),
+(43.6801314, -79.7590658),
+(43.8930769, -79.3757918),
+(43.5682369, -79.6213603),
+(43.7443073, -79.4334042),
+(43.3523156, -79.7831415),
+(43.8416693, -79.4767782),
+(43.8859854, -79.2284435),
+(43.9405808, -79.4267268),
+(43.8465879, -79.0958771),
+(43.516763, -79.865347),
+(43.648369, -79.376601),
+(43.5763205, -79.676517),
+(43.5126327, -79.6309339),
+(43.4334059, -79.7289973),
+(43.8028037, -79.4016187),
+(43.8669352, -79.2159245),
+(43.4074138, -79.8227631),
+(43.6134376, -79.6391991),
+(43.67025, -79.385924),
+(43.76115, -79.788257),
+(43.841845, -79.032281),
+(43.779588, -79.748509),
+(43.4189833, -79.7053),
+(43.367985, -79.8198147),
+(43.3589266, -79.8427286),
+(43.661401, -79.38091),
+(43.775801, -79.182197),
+(43.4711211, -79.7485849),
+(43.6429141, -79.3806469),
+(43.666751, -79.376235),
+(43.5550963, -79.7317985),
+(43.7413445, -79.4158105),
+(43.7589, -79.688582),
+(43.652008, -79.4495376),
+(43.9392041, -79.4567374),
+(43.8410876, -79.405166),
+(43.863295, -79.447799),
+(43.9488503, -79.461149),
+(43.4381983, -79.7469338),
+(43.374053, -79.721288),
+(43.7028015, -79.4513882),
+(43.9164543, -78.9093675),
+(43.6440617, -79.3894386),
+(43.5956797, -79.6347654),
+(43.474318, -79.64438),
+(43.4969291, -79.704724),
+(43.4949853, -79.6886521),
+(43.403161, -79.820086),
+(43.727577, -79.751225),
+(43.6243335, -79.485323),
+(43.633803, -79.87001),
+(43.6451235, -79.402811),
+(43.7467948, -79.3762964),
+(43.696892, -79.4243228),
+(43.8280667, -79.4362287),
+(43.8635065, -79.2515481),
+(43.6279274, -79.4781622),
+(43.3992143, -79.7077282),
+(43.8954886, -79.3731473),
+(43.4410008, -79.6787715),
+(43.8968262, -79.4246916),
+(43.7341056, -79.6378743),
+(43.9451324, -79.4648839),
+(43.5897545, -79.6480938),
+(43.7013763, -79.2648),
+(43.7031829, -79.4509291),
+(43.6986823, -79.5537027),
+(43.6592713, -79.3849163),
+(43.8729042, -79.4275302),
+(43.7642862, -79.3441063),
+(43.6560406, -79.3631568),
+(43.7767974, -79.1530293),
+(43.8081133, -79.2317736),
+(43.863524, -79.029654),
+(43.5800439, -79.6250026),
+(43.8007128, -79.3170366),
+(43.9111604, -79.4388427),
+(43.8406631, -79.4667506),
+(43.6460836, -79.3650117),
+(43.7260965, -79.4796815),
+(43.6028557, -79.517091),
+(43.644864, -79.39906),
+(43.78189, -79.595788),
+(43.4460732, -79.7637167),
+(43.6398031, -79.4139432),
+(43.826744, -79.091001),
+(43.6475103, -79.3927254),
+(43.7357289, -79.7552608),
+(43.5843282, -79.5969674),
+(43.8652838, -79.0424179),
+(43.7403616, -79.238065),
+(43.6085, -79.5066),
+(43.8776809, -79.2339602),
+(43.736284, -79.8215483),
+(43.779321, -79.413712),
+(43.6399004, -79.7417617),
+(43.866093, -79.439199),
+(43.4190769, -79.6858089),
+(43.6352427, -79.7117053),
+(43.6400887, -79.3939574),
+(43.778765, -79.417903),
+(43.7692297, -79.411935),
+(43.669461, -79.295065),
+(43.638513, -79.400897),
+(43.75225, -79.45571),
+(43.594913, -79.635736),
+(43.532856, -79.6215529),
+(43.5874623, -79.6414072),
+(43.9571256, -79.4819402),
+(43.8375818, -79.1295289),
+(43.767123, -79.383185),
+(43.622729, -79.48319),
+(43.9061381, -79.4497531),
+(43.65056, -79.382072),
+(43.4062308, -79.7113983),
+(43.7173319, -79.3595986),
+(43.7076183, -79.811398),
+(43.730849, -79.714086),
+(43.79493, -79.313943),
+(43.683176, -79.4861647),
+(43.7389387, -79.5827134),
+(43.8739101, -79.4490594),
+(43.3881226, -79.8894165),
+(43.6387203, -79.5115546),
+(43.9042886, -79.6418098),
+(43.708008, -79.400785),
+(43.7893778, -79.4275055),
+(43.835544, -79.108044),
+(43.7723462, -79.4063005),
+(43.4512793, -79.7362915),
+(43.8467022, -79.5155581),
+(43.8870243, -79.3372246),
+(43.3931614, -79.7884102),
+(43.977105, -79.2450586),
+(43.8860043, -78.9699265),
+(43.8476757, -79.3802375),
+(43.9332452, -79.5521789),
+(43.782769, -79.431291),
+(43.794242, -79.524434),
+(43.8089068, -79.2284012),
+(43.7657266, -79.4415531),
+(43.524102, -79.841486),
+(43.9090089, -78.9140607),
+(43.7940724, -79.4407676),
+(43.8710654, -79.0695096),
+(43.7396054, -79.7526261),
+(43.706291, -79.392573),
+(43.4747702, -79.6606415),
+(43.4740407, -79.737348),
+(43.399397, -79.7083418),
+(43.9609619, -79.258437),
+(43.8593994, -79.2938863),
+(43.7247863, -79.2556789),
+(43.649759, -79.928177),
+(43.636057, -79.470781),
+(43.5952453, -79.5342625),
+(43.7698441, -79.409455),
+(43.4426282, -79.6723596),
+(43.931493, -79.5313864),
+(43.6400548, -79.4938009),
+(43.758071, -79.403165),
+(43.5188156, -79.9291684),
+(43.833059, -79.532424),
+(43.6042625, -79.5360879),
+(43.460707, -79.704644),
+(43.7754722, -79.2195397),
+(43.5060521, -79.642427),
+(43.652508, -79.3885848),
+(43.8954886, -79.3731473),
+(43.5225199, -79.6040191),
+(43.877212, -79.05649),
+(43.5678026, -79.7235325),
+(43.7739867, -79.3595046),
+(43.5470587, -79.5986904),
+(43.5948677, -79.6049147),
+(43.656806, -79.375092),
+(43.7016605, -79.4852617),
+(43.8583822, -78.9470359),
+(43.7813959, -79.4135743),
+(43.8894617, -79.3944839),
+(43.6656459, -79.3859491),
+(43.8745759, -79.4474349),
+(43.6029586, -79.6911568),
+(43.668718, -79.383996),
+(43.7800398, -79.4126795),
+(43.801665, -79.674593),
+(43.6966073, -79.2942117),
+(43.669879, -79.385009),
+(43.757501, -79.409225),
+(43.669301, -79.384171),
+(43.6400003, -79.3788904),
+(43.970267, -79.263706),
+(43.5861922, -79.6429407),
+(43.6595987, -79.7640774),
+(43.6289027, -79.7896781),
+(43.6385781, -79.3993661),
+(43.5748, -79.7214),
+(43.5800439, -79.6250026),
+(43.6251221, -79.478823),
+(43.9768744, -79.258244),
+(43.7272339, -79.8009218),
+(43.950513, -79.423624),
+(43.9239455, -78.9296541),
+(43.90256, -78.947763),
+(43.768151, -79.390791),
+(43.626884, -79.7045448),
+(43.4867886, -79.8670315),
+(43.5945672, -79.6887741),
+(43.6404948, -79.3808155),
+(43.7504348, -79.3736576),
+(43.6625248, -79.3400404),
+(43.8563585, -78.946274),
+(43.828794, -79.002218),
+(43.7081002, -79.8180854),
+(43.5345352, -79.8599404),
+(43.586211, -79.64293),
+(43.422984, -79.938387),
+(43.865893, -79.235518),
+(43.5941, -79.7437),
+(43.8091676, -79.455206),
+(43.8155617, -79.0825462),
+(43.8650534, -79.2216923),
+(43.802845, -79.585896),
+(43.8999734, -78.8940108),
+(43.7341315, -79.268091),
+(43.8800746, -79.2859721),
+(43.9299495, -79.4781005),
+(43.854717, -79.539701),
+(43.7429376, -79.8193144),
+(43.5907831, -79.5613018),
+(43.561803, -79.7126716),
+(43.4337966, -79.6921229),
+(43.6078198, -79.4912655),
+(43.6941679, -79.4691066),
+(43.6867268, -79.7454334),
+(43.6956176, -79.5538552),
+(43.640285, -79.559817),
+(43.6493542, -79.4795714),
+(43.6815369, -79.4899844),
+(43.483614, -79.839783),
+(43.751781, -79.843774),
+(43.6402022, -79.5593652),
+(43.7015228, -79.8375858),
+(43.5079692, -79.6215126),
+(43.6322677, -79.7678705),
+(43.6819191, -79.7966068),
+(43.623289, -79.483695),
+(43.6103928, -79.6614215),
+(43.5876077, -79.7873495),
+(43.7179076, -79.6255816),
+(43.7428168, -79.7639405),
+(43.8995658, -79.219362),
+(43.6971531, -79.8393511),
+(43.4899618, -79.8525978),
+(43.6771024, -79.3766691),
+(43.6782715, -79.3443713),
+(43.8458404, -78.9989636),
+(43.680733, -79.28772),
+(43.590391, -79.628548),
+(43.66703, -79.376386),
+(43.7044614, -79.3699101),
+(43.6834734, -79.4633101),
+(43.875512, -79.120966),
+(43.8541241, -79.4268381),
+(43.7536416, -79.7538928),
+(43.6241314, -79.4880676),
+(43.6752013, -79.7812877),
+(43.450892, -79.705464),
+(43.7238651, -79.7819717),
+(43.7896333, -79.7380051),
+(43.3730238, -79.8388131),
+(43.3819062, -79.7358635),
+(43.7485427, -79.7723143),
+(43.8469842, -79.5666433),
+(43.674717, -79.430682),
+(43.6410594, -79.4461529),
+(43.4279897, -79.7499778),
+(43.6383872, -79.4010639),
+(43.427839, -79.752518),
+(43.580457, -79.747192),
+(43.4837779, -79.8398583),
+(43.6517024, -79.3747876),
+(43.774071, -79.329324),
+(43.684626, -79.398302),
+(43.777401, -79.412802),
+(43.6859084, -79.3941337),
+(43.903929, -79.401161),
+(43.7783428, -79.4159581),
+(43.5890411, -79.6495976),
+(43.5166262, -79.8628156),
+(43.468073, -79.7450595),
+(43.58838, -79.639953),
+(43.561339, -79.6027745),
+(43.83071, -79.4194337),
+(43.7070826, -79.3977712),
+(43.8659617, -79.4490198),
+(43.626966, -79.478488),
+(43.8665769, -79.3372021),
+(43.650405, -79.392168),
+(43.6924039, -79.3132605),
+(43.970816, -79.249128),
+(43.643939, -79.401477),
+(43.7295847, -79.4244916),
+(43.707098, -79.39508),
+(43.827363, -79.6505997),
+(43.6388334, -79.3988388),
+(43.848616, -79.048902),
+(43.773403, -79.344636),
+(43.9694907, -79.4772885),
+(43.4206713, -79.7049524),
+(43.5905171, -79.628811),
+(43.661725, -79.384128),
+(43.9769805, -79.4073853),
+(43.667162, -79.3735007),
+(43.5187, -79.8761),
+(43.525403, -79.860402),
+(43.637524, -79.400877),
+(43.6591293, -79.4098165),
+(43.488196, -79.876802),
+(43.6197166, -79.8841008),
+(43.7707253, -79.4332638),
+(43.9038519, -78.9706114),
+(43.4051468, -79.7260997),
+(43.6570646, -79.7583659),
+(43.6964648, -79.8604467),
+(43.7065117, -79.4132715),
+(43.754427, -79.444778),
+(43.7311713, -79.5685464),
+(43.4968504, -79.6929681),
+(43.7790064, -79.4336058),
+(43.6720296, -79.3798098),
+(43.896918, -78.886429),
+(43.9259744, -79.5278258),
+(43.8701828, -79.4645518),
+(43.8800614, -78.9692371),
+(43.9072933, -78.9279286),
+(43.8031782, -79.3541414),
+(43.6740094, -79.4124294),
+(43.6871115, -79.4241354),
+(43.7832366, -79.3702475),
+(43.6367062, -79.4000807),
+(43.641087, -79.381405),
+(43.9060379, -78.9037684),
+(43.7780331, -79.1588032),
+(43.7642862, -79.3441063),
+(43.7117927, -79.2562122),
+(43.7158967, -79.2791716),
+(43.83938, -79.088197),
+(43.8941806, -78.9184509),
+(43.911477, -78.9184026),
+(43.6575906, -79.3389784),
+(43.7805353, -79.2510063),
+(43.8127933, -79.4240875),
+(43.9713613, -79.259949),
+(43.6575992, -79.3498961),
+(43.665299, -79.341231),
+(43.848844, -79.4743494),
+(43.3682117, -79.8464461),
+(43.646006, -79.389908),
+(43.760304, -79.41162),
+(43.730304, -79.4411185),
+(43.6425128, -79.3813182),
+(43.648879, -79.3880443),
+(43.7844008, -79.288555),
+(43.7254686, -79.3917967),
+(43.4194334, -79.701612),
+(43.433141, -79.770423),
+(43.758421, -79.422073),
+(43.938036, -79.4321152),
+(43.681516, -79.7455618),
+(43.9687748, -79.4818464),
+(43.649292, -79.93197),
+(43.8131214, -79.4545306),
+(43.623124, -79.886156),
+(43.6960795, -79.7782895),
+(43.447189, -79.669002),
+(43.646885, -79.925432),
+(43.5255217, -79.8604228),
+(43.3931614, -79.7884102),
+(43.4211812, -79.7617046),
+(43.5105079, -79.9057925),
+(43.7018936, -79.8383179),
+(43.4635594, -79.6475377),
+(43.6600002, -79.4517988),
+(43.4927789, -79.6587051),
+(43.406445, -79.8214555),
+(43.6383693, -79.3986981),
+(43.6707607, -79.3773018),
+(43.5495979, -79.7196551),
+(43.8704713, -79.4134306),
+(43.6620882, -79.3856802),
+(43.4358151, -79.7184366),
+(43.781809, -79.3820298),
+(43.5480257, -79.6225518),
+(43.705218, -79.361653),
+(43.852094, -79.432423),
+(43.7338186, -79.346789),
+(43.610767, -79.65335),
+(43.7972588, -79.4010257),
+(43.9620694, -79.4422967),
+(43.8407403, -79.6486134),
+(43.5606547, -79.6059811),
+(43.4793503, -79.6385056),
+(43.70529, -79.397827),
+(43.413464, -79.800437),
+(43.6239412, -79.5243608),
+(43.6659594, -79.7717436),
+(43.5053997, -79.8547261),
+(43.846748, -79.476694),
+(43.7958074, -79.3251799),
+(43.779718, -79.342657),
+(43.623289, -79.483695),
+(43.63952, -79.890359),
+(43.653068, -79.760674),
+(43.9536999, -78.8970862),
+(43.763873, -79.405766),
+(43.623289, -79.483695),
+(43.851506, -79.067217),
+(43.810531, -79.3706978),
+(43.7805216, -79.4007203),
+(43.8939431, -79.5006582),
+(43.8992778, -79.2850316),
+(43.4799969, -79.7266097),
+(43.7490152, -79.406243),
+(43.585748, -79.648916),
+(43.8972899, -78.9257416),
+(43.6882719, -79.4278246),
+(43.8768815, -79.0840065),
+(43.799608, -79.2733021),
+(43.7569815, -79.4044001),
+(43.635851, -79.780203),
+(43.6677808, -79.3259031),
+(43.6926302, -79.508629),
+(43.6606139, -79.3782378),
+(43.7447369, -79.2027201),
+(43.3905964, -79.7796355),
+(43.669054, -79.365898),
+(43.8451296, -79.4924157),
+(43.6426507, -79.3773286),
+(43.78085, -79.7304292),
+(43.4978683, -79.6917625),
+(43.625834, -79.7473606),
+(43.8167584, -79.4656411),
+(43.8462814, -79.4246254),
+(43.4890068, -79.8659412),
+(43.4927104, -79.8573787),
+(43.6915273, -79.5354938),
+(43.6851041, -79.3133756),
+(43.7795648, -79.4024739),
+(43.9189501, -79.4694683),
+(43.8080466, -79.4173394),
+(43.486269, -79.865843),
+(43.773249, -79.255273),
+(43.715865, -79.84172),
+(43.6823082, -79.3579525),
+(43.63694, -79.402947),
+(43.8760774, -79.3588037),
+(43.67408, -79.374474),
+(43.751745, -79.266388),
+(43.6403228, -79.3917567),
+(43.8814559, -79.4687383),
+(43.8148654, -79.466816),
+(43.75116, -79.7789955),
+(43.95453, -79.501623),
+(43.6679848, -79.3755762),
+(43.7765738, -79.3087084),
+(43.6915364, -79.3424811),
+(43.7140871, -79.6447392),
+(43.5759635, -79.5700505),
+(43.7261365, -79.2643908),
+(43.776533, -79.670361),
+(43.78638, -79.291535),
+(43.6882901, -79.4383244),
+(43.8273217, -79.5700868),
+(43.7510466, -79.1883601),
+(43.6807387, -79.2883524),
+(43.875512, -79.120966),
+(43.6768065, -79.3741415),
+(43.6892416, -79.3952485),
+(43.7063718, -79.2571002),
+(43.632572, -79.6018504),
+(43.6617603, -79.5722083),
+(43.52916, -79.843133),
+(43.8647346, -79.4301639),
+(43.7732692, -79.3450544),
+(43.6907955, -79.2931823),
+(43.3927652, -79.7824767),
+(43.675347, -79.43592),
+(43.7053255, -79.4342263),
+(43.6820728, -79.4497509),
+(43.6529119, -79.3703524),
+(43.7545016, -79.3938097),
+(43.6922058, -79.3087827),
+(43.7344068, -79.4109092),
+(43.6691837, -79.3873558),
+(43.795696, -79.520318),
+(43.8680274, -79.4279956),
+(43.812916, -79.4599396),
+(43.7037028, -79.4335729),
+(43.661725, -79.384128),
+(43.6429892, -79.5039369),
+(43.8787944, -79.0954067),
+(43.9341146, -79.4776855),
+(43.881674, -79.225092),
+(43.5820394, -79.7641533),
+(43.6174639, -79.6650514),
+(43.847647, -79.25228),
+(43.6922018, -79.3252941),
+(43.7914091, -79.2366785),
+(43.6438059, -79.3916717),
+(43.7079594, -79.4105444),
+(43.7639534, -79.4069857),
+(43.856886, -79.03765),
+(43.806869, -79.2653042),
+(43.8565448, -79.3244227),
+(43.8097923, -79.2556847),
+(43.882437, -79.002606),
+(43.8719421, -79.4268026),
+(43.7298398, -79.2195215),
+(43.3715952, -79.8440052),
+(43.53262, -79.846869),
+(43.58765, -79.790914),
+(43.8993513, -79.0232277),
+(43.3525655, -79.7652892),
+(43.7180212, -79.3768816),
+(43.6782788, -79.3956553),
+(43.7786129, -79.4518042),
+(43.6577566, -79.3678178),
+(43.649516, -79.387954),
+(43.6610187, -79.3635907),
+(43.6594036, -79.3611785),
+(43.7946144, -79.410105),
+(43.8734982, -78.9592202),
+(43.951241, -78.950943),
+(43.6744052, -79.2806529),
+(43.830255, -79.0067),
+(43.858771, -79.029209),
+(43.8805979, -78.9688178),
+(43.6685941, -79.3076191),
+(43.814792, -79.3938029),
+(43.858252, -79.433621),
+(43.8294833, -79.2867074),
+(43.851911, -79.4313933),
+(43.8596195, -79.2214946),
+(43.8766566, -79.2612263),
+(43.8128057, -79.4519464),
+(43.756902, -79.51127),
+(43.6418186, -79.440346),
+(43.6804583, -79.7880439),
+(43.5197129, -79.6724485),
+(43.7594734, -79.7557956),
+(43.666647, -79.448206),
+(43.7536264, -79.5604059),
+(43.6243991, -79.4785079),
+(43.518299, -79.877404),
+(43.5410615, -79.6004473),
+(43.5998135, -79.5255865),
+(43.636608, -79.4048673),
+(43.760402, -79.40901),
+(43.6377596, -79.3951472),
+(43.84754, -78.992652),
+(43.7577327, -79.403149),
+(43.7486095, -79.3004445),
+(43.7905781, -79.2158875),
+(43.6364747, -79.4020594),
+(43.8130739, -79.2173426),
+(43.828551, -79.10462),
+(43.8270741, -79.3884452),
+(43.7639679, -79.2032065),
+(43.6960564, -79.3085967),
+(43.686315, -79.514191),
+(43.6254697, -79.7589431),
+(43.693185, -79.842792),
+(43.580921, -79.6258565),
+(43.5330697, -79.8559583),
+(43.7423514, -79.5386744),
+(43.6373709, -79.7722032),
+(43.7241914, -79.2310809),
+(43.819849, -79.026476),
+(43.8753112, -79.4127466),
+(43.8139504, -79.4401187),
+(43.7615883, -79.2889197),
+(43.886044, -79.228971),
+(43.6719848, -79.4402226),
+(43.9040284, -79.23145),
+(43.9608784, -79.2501138),
+(43.7779246, -79.3761996),
+(43.6375103, -79.4005804),
+(43.7302432, -79.4697143),
+(43.8779873, -79.2753025),
+(43.6548441, -79.3846797),
+(43.7114372, -79.2560505),
+(43.7806579, -79.3763973),
+(43.82621, -79.117198),
+(43.720895, -79.4745273),
+(43.7469491, -79.4348698),
+(43.6735793, -79.3361402),
+(43.6971244, -79.28271),
+(43.858084, -79.028059),
+(43.875122, -79.313541),
+(43.8860665, -79.2471054),
+(43.685843, -79.274132),
+(43.430246, -79.755139),
+(43.7337826, -79.5644371),
+(43.6971856, -79.2827337),
+(43.7339219, -79.2099336),
+(43.8319048, -79.4776071),
+(43.9550411, -78.9483969),
+(43.6695417, -79.3837883),
+(43.6707892, -79.8023597),
+(43.8528309, -79.4446191),
+(43.706123, -79.442929),
+(43.801291, -79.149438),
+(43.668535, -79.321173),
+(43.8234526, -79.4760661),
+(43.8848814, -78.9080943),
+(43.6735244, -79.3080522),
+(43.8501286, -79.565712),
+(43.9639375, -78.9748211),
+(43.5589348, -79.5823834),
+(43.6211657, -79.8836245),
+(43.641523, -79.4240871),
+(43.7782466, -79.5975781),
+(43.48461, -79.846911),
+(43.640285, -79.559817),
+(43.7968255, -79.2998342),
+(43.7167448, -79.7271985),
+(43.550227, -79.669046),
+(43.7189326, -79.6234501),
+(43.6970302, -79.2826787),
+(43.6206069, -79.4865296),
+(43.909609, -78.905197),
+(43.674231, -79.307142),
+(43.645261, -79.554412),
+(43.838279, -79.092443),
+(43.9785703, -79.4462784),
+(43.6553702, -79.4561244),
+(43.698221, -79.445264),
+(43.964227, -79.559455),
+(43.4193928, -79.7039195),
+(43.8649408, -79.410603),
+(43.623821, -79.478728),
+(43.6464971, -79.4211364),
+(43.623821, -79.478728),
+(43.6959554, -79.3957179),
+(43.944729, -79.085851),
+(43.639955, -79.382025),
+(43.6847598, -79.3417523),
+(43.7762122, -79.6061479),
+(43.838262, -79.488991),
+(43.8659, -79.4342),
+(43.6076, -79.6719),
+(43.8562513, -79.6186978),
+(43.7830984, -79.3255486),
+(43.6398765, -79.4167372),
+(43.9435276, -79.4498888),
+(43.769094, -79.368687),
+(43.395758, -79.791088),
+(43.624595, -79.480119),
+(43.7785351, -79.3898979),
+(43.774764, -79.336918),
+(43.778008, -79.4527173),
+(43.8752668, -78.934097),
+(43.5460424, -79.7415352),
+(43.750599, -79.465027),
+(43.9594812, -78.906286),
+(43.819402, -79.145208),
+(43.8977124, -79.4343074),
+(43.662393, -79.467027),
+(43.8794466, -79.4700178),
+(43.8979737, -78.9258371),
+(43.62372, -79.483703),
+(43.7779113, -79.3299942),
+(43.8562959, -79.5130013),
+(43.8164461, -79.6186428),
+(43.6042027, -79.7012312),
+(43.6654142, -79.7464395),
+(43.75667, -79.408623),
+(43.7947234, -79.4035924),
+(43.8797936, -79.4373026),
+(43.6017336, -79.5251608),
+(43.7320107, -79.4261705),
+(43.6897569, -79.8518111),
+(43.661725, -79.384128),
+(43.8683356, -79.3084298),
+(43.684897, -79.4704611),
+(43.7918439, -79.4061522),
+(43.6413253, -79.4365534),
+(43.8395422, -79.2483829),
+(43.6787938, -79.2866696),
+(43.5654152, -79.7358586),
+(43.8225321, -79.2243412),
+(43.6822081, -79.5182218),
+(43.7096197, -79.8252008),
+(43.661126, -79.3532776),
+(43.6396649, -79.4146468),
+(43.6871766, -79.3465648),
+(43.9629602, -78.9391588),
+(43.916996, -79.441757),
+(43.7780952, -79.3385344),
+(43.6342488, -79.5144012),
+(43.749969, -79.406135),
+(43.65056, -79.382072),
+(43.660405, -79.4070029),
+(43.790292, -79.411578),
+(43.520078, -79.620473),
+(43.9703547, -79.2335199),
+(43.645077, -79.364342),
+(43.6591852, -79.434787),
+(43.8671615, -79.5249901),
+(43.7333204, -79.2223925),
+(43.7362117, -79.2654009),
+(43.7033917, -79.7652463),
+(43.6620243, -79.3865463),
+(43.6712941, -79.384925),
+(43.665368, -79.387318),
+(43.7708581, -79.4120073),
+(43.7600504, -79.4074672),
+(43.4854315, -79.8546992),
+(43.5846327, -79.7820268),
+(43.7538386, -79.8420693),
+(43.6217109, -79.5901086),
+(43.475321, -79.7361903),
+(43.5890411, -79.6495976),
+(43.6690845, -79.3213525),
+(43.853925, -79.025874),
+(43.7905493, -79.1838591),
+(43.8077984, -79.2028867),
+(43.7805353, -79.2510063),
+(43.8774726, -78.8994916),
+(43.7991402, -79.1232996),
+(43.777026, -79.413773),
+(43.8376638, -79.3903703),
+(43.6966383, -79.7731308),
+(43.8516093, -79.3278228),
+(43.8584949, -79.2542774),
+(43.512024, -79.844703),
+(43.6685967, -79.4526653),
+(43.7646482, -79.7650034),
+(43.5758424, -79.7563621),
+(43.5244604, -79.8863931),
+(43.6297235, -79.8803554),
+(43.8214575, -79.727793),
+(43.5816834, -79.7286125),
+(43.651394, -79.8017765),
+(43.5420788, -79.6912269),
+(43.3504578, -79.7773855),
+(43.4268892, -79.7842184),
+(43.3689398, -79.8064052),
+(43.916141, -78.907386),
+(43.638764, -79.416242),
+(43.7853727, -79.3424792),
+(43.7399512, -79.4141134),
+(43.658009, -79.362484),
+(43.859112, -79.032895),
+(43.7802816, -79.4455163),
+(43.8139326, -79.451459),
+(43.7998802, -79.4455936),
+(43.8583448, -79.5225776),
+(43.6969342, -79.5629805),
+(43.7434783, -79.7562183),
+(43.6701574, -79.4498051),
+(43.5631586, -79.732453),
+(43.4932777, -79.8710321),
+(43.6708111, -79.4978575),
+(43.6859944, -79.8036553),
+(43.8251872, -79.7335427),
+(43.6448263, -79.7227034),
+(43.6363836, -79.4857012),
+(43.622658, -79.6533335),
+(43.6427532, -79.4274654),
+(43.7703, -79.413645),
+(43.8240601, -79.2769069),
+(43.769906, -79.411747),
+(43.724239, -79.40255),
+(43.7932131, -79.4230093),
+(43.6384324, -79.4135407),
+(43.6745572, -79.7287736),
+(43.4831913, -79.8701418),
+(43.6993368, -79.7494078),
+(43.8139375, -79.3571315),
+(43.491259, -79.7041605),
+(43.7030998, -79.8151052),
+(43.8374915, -79.4397024),
+(43.9762458, -79.2277496),
+(43.7630078, -79.7668224),
+(43.811911, -79.2317174),
+(43.807136, -79.101569),
+(43.839495, -79.448749),
+(43.6842723, -79.339358),
+(43.9530798, -79.2647477),
+(43.7041839, -79.7432245),
+(43.6488706, -79.50833),
+(43.737143, -79.800691),
+(43.7266141, -79.8297065),
+(43.6724473, -79.7404743),
+(43.607785, -79.665932),
+(43.490987, -79.845326),
+(43.4984046, -79.8794327),
+(43.641633, -79.382053),
+(43.8796811, -79.3688456),
+(43.749878, -79.531076),
+(43.818816, -79.077231),
+(43.7066318, -79.3656577),
+(43.6349494, -79.5556028),
+(43.7006188, -79.2613348),
+(43.7987509, -79.3799786),
+(43.7063986, -79.4137966),
+(43.680512, -79.3144592),
+(43.7635981, -79.2368484),
+(43.9071093, -78.8993617),
+(43.724239, -79.40255),
+(43.8769594, -79.3363685),
+(43.6609733, -79.5423059),
+(43.6628915, -79.3677193),
+(43.6746538, -79.3890069),
+(43.6408931, -79.5107879),
+(43.640345, -79.563119),
+(43.5874559, -79.5522414),
+(43.630221, -79.51004),
+(43.5814591, -79.7649635),
+(43.77414, -79.178771),
+(43.6687948, -79.5222739),
+(43.6779435, -79.5268329),
+(43.7958397, -79.7439736),
+(43.6645238, -79.3083842),
+(43.665642, -79.777419),
+(43.7391782, -79.3456388),
+(43.7927261, -79.6136447),
+(43.7547018, -79.2363154),
+(43.7657287, -79.6767878),
+(43.8776809, -79.2339602),
+(43.9198152, -79.473357),
+(43.9674318, -79.4841077),
+(43.427728, -79.733357),
+(43.7160694, -79.2760357),
+(43.6713415, -79.3012185),
+(43.7008874, -79.3830882),
+(43.7205104, -79.5676787),
+(43.5532522, -79.6729278),
+(43.7542372, -79.8182109),
+(43.7415284, -79.5421902),
+(43.7612064, -79.8262915),
+(43.9019382, -79.4610424),
+(43.7156837, -79.8320623),
+(43.3784963, -79.8088371),
+(43.6856494, -79.3178527),
+(43.3576496, -79.8453949),
+(43.641071, -79.400252),
+(43.664424, -79.4833853),
+(43.598537, -79.6822215),
+(43.4385703, -79.6740542),
+(43.4259146, -79.7054593),
+(43.7851358, -79.5951728),
+(43.890966, -79.02842),
+(43.6644192, -79.3528382),
+(43.5413513, -79.6055772),
+(43.5603226, -79.6022085),
+(43.6369794, -79.4060787),
+(43.6342318, -79.540934),
+(43.411811, -79.801472),
+(43.8080559, -79.4553335),
+(43.7945494, -79.166009),
+(43.9091686, -78.9457338),
+(43.6570971, -79.3690995),
+(43.7871878, -79.4310826),
+(43.5809236, -79.6238027),
+(43.62825, -79.759417),
+(43.6541577, -79.7747873),
+(43.862228, -79.5401151),
+(43.8265229, -79.5805217),
+(43.649844, -79.444627),
+(43.881674, -79.225092),
+(43.8633835, -79.4332961),
+(43.6429451, -79.3805034),
+(43.6499, -79.7339),
+(43.9218906, -79.4678832),
+(43.635457, -79.870386),
+(43.4854, -79.6988),
+(43.4566156, -79.6902655),
+(43.641291, -79.5049174),
+(43.9116381, -78.9309734),
+(43.58504, -79.646032),
+(43.8296128, -79.6522442),
+(43.8842351, -79.3513438),
+(43.6811548, -79.4647502),
+(43.745448, -79.392705),
+(43.7531706, -79.5109026),
+(43.707083, -79.397771),
+(43.822714, -79.398741),
+(43.6898782, -79.4013358),
+(43.7149433, -79.2641055),
+(43.6358951, -79.5775681),
+(43.651639, -79.410785),
+(43.9059046, -78.9700541),
+(43.9090949, -78.9300448),
+(43.740237, -79.747101),
+(43.5191078, -79.8246582),
+(43.7081088, -79.7494836),
+(43.665388, -79.3880211),
+(43.8006531, -79.3051929),
+(43.405875, -79.725807),
+(43.89928, -79.735429),
+(43.983445, -79.238875),
+(43.5256777, -79.6884089),
+(43.7303276, -79.2309128),
+(43.8806588, -78.9098709),
+(43.5844461, -79.7183504),
+(43.544666, -79.594681),
+(43.6790581, -79.3093626),
+(43.834747, -79.007799),
+(43.6918342, -79.3261898),
+(43.9538324, -78.9708882),
+(43.7704344, -79.1635782),
+(43.5317195, -79.8789523),
+(43.66273, -79.379025),
+(43.644629, -79.392933),
+(43.621285, -79.4968961),
+(43.572976, -79.680012),
+(43.715384, -79.454548),
+(43.8745836, -79.3487094),
+(43.87686, -79.2714764),
+(43.8949733, -79.2948504),
+(43.8917222, -79.3061116),
+(43.8196803, -79.3146699),
+(43.7817373, -79.4143115),
+(43.7880545, -79.1523278),
+(43.96478, -79.371892),
+(43.6491763, -79.3770958),
+(43.667222, -79.3839697),
+(43.779718, -79.342657),
+(43.638784, -79.398863),
+(43.6214031, -79.4837838),
+(43.775055, -79.6647377),
+(43.8457525, -79.4719348),
+(43.7695626, -79.4134466),
+(43.6968373, -79.293353),
+(43.683047, -79.822826),
+(43.672822, -79.414098),
+(43.826744, -79.091001),
+(43.7914344, -79.3333916),
+(43.8614279, -79.4364729),
+(43.798005, -79.2154367),
+(43.8381722, -79.1307769),
+(43.7816473, -79.2449273),
+(43.4854, -79.6988),
+(43.5483701, -79.7147011),
+(43.595841, -79.681723),
+(43.660905, -79.378806),
+(43.8403126, -79.3915204),
+(43.9643519, -79.2418543),
+(43.8450756, -79.0887944),
+(43.6590857, -79.3756802),
+(43.659137, -79.3578443),
+(43.7550359, -79.3610977),
+(43.7463137, -79.2086907),
+(43.7994443, -79.5659722),
+(43.7566685, -79.8172817),
+(43.7109922, -79.5292263),
+(43.6341013, -79.5134468),
+(43.6259053, -79.5936465),
+(43.6598918, -79.4482483),
+(43.6966498, -79.814265),
+(43.8642237, -79.2160149),
+(43.5614759, -79.743117),
+(43.8281418, -79.2323095),
+(43.7472669, -79.507223),
+(43.7023034, -79.6504321),
+(43.5967884, -79.7158424),
+(43.5316414, -79.6632147),
+(43.381034, -79.798334),
+(43.4675312, -79.7462961),
+(43.6548176, -79.8003252),
+(43.5383733, -79.6997667),
+(43.6614316, -79.7671763),
+(43.8752956, -79.3227974),
+(43.6700784, -79.7338812),
+(43.8200179, -79.396951),
+(43.6476394, -79.7426066),
+(43.7762122, -79.6061479),
+(43.843516, -79.426394),
+(43.5397866, -79.6752457),
+(43.8419509, -79.5346191),
+(43.6411433, -79.5209149),
+(43.76228, -79.7286),
+(43.377128, -79.8002583),
+(43.586871, -79.572192),
+(43.9693694, -79.1339026),
+(43.7874191, -79.3669901),
+(43.824578, -79.015081),
+(43.8522694, -79.431868),
+(43.8999421, -79.4373678),
+(43.9501155, -78.8791714),
+(43.7388898, -79.2728878),
+(43.9173966, -78.9182827),
+(43.7879505, -79.2142114),
+(43.7433627, -79.3431633),
+(43.720889, -79.716783),
+(43.6216546, -79.483188),
+(43.848575, -79.43254),
+(43.985927, -79.530876),
+(43.8501686, -79.3264608),
+(43.7938805, -79.4818504),
+(43.9058657, -79.4297581),
+(43.700445, -79.397789),
+(43.6818471, -79.3249838),
+(43.8384935, -79.1117271),
+(43.8228173, -79.475183),
+(43.7615378, -79.2482897),
+(43.5209202, -79.8471596),
+(43.5530533, -79.7119838),
+(43.6764633, -79.5647825),
+(43.5595182, -79.7415424),
+(43.6802687, -79.4602327),
+(43.8694955, -78.9400803),
+(43.7031717, -79.3835804),
+(43.6978398, -79.3871947),
+(43.9714626, -79.4788217),
+(43.8826112, -79.4658394),
+(43.8824717, -79.2576784),
+(43.9489878, -79.4732376),
+(43.930668, -79.029476),
+(43.791361, -79.3314669),
+(43.859563, -79.4603847),
+(43.6505967, -79.8035915),
+(43.8411271, -79.2619593),
+(43.4255418, -79.7796351),
+(43.7341144, -79.3090117),
+(43.8384839, -79.3905913),
+(43.4566156, -79.6902655),
+(43.9791419, -79.4708658),
+(43.9330962, -79.410058),
+(43.908459, -78.915276),
+(43.7373063, -79.4175152),
+(43.8710718, -79.2580406),
+(43.4398427, -79.6819776),
+(43.929346, -79.5200806),
+(43.730772, -79.643457),
+(43.7704511, -79.4318068),
+(43.8842389, -79.4572355),
+(43.700843, -79.76937),
+(43.6909516, -79.8608188),
+(43.4849245, -79.7327975),
+(43.7570235, -79.4587707),
+(43.5944866, -79.632353),
+(43.697, -79.437094),
+(43.6916733, -79.4440573),
+(43.6451278, -79.4265917),
+(43.6779678, -79.4488036),
+(43.8746097, -79.064976),
+(43.393691, -79.8214354),
+(43.8373498, -79.1274666),
+(43.8081004, -79.4488949),
+(43.4657601, -79.6700891),
+(43.9440791, -78.997466),
+(43.623335, -79.5222847),
+(43.7322447, -79.2116127),
+(43.5892132, -79.5483734),
+(43.7154127, -79.5012784),
+(43.777401, -79.412802),
+(43.5122856, -79.8583754),
+(43.644768, -79.4026),
+(43.6277473, -79.5108889),
+(43.4708785, -79.702139),
+(43.7017573, -79.7617035),
+(43.6871437, -79.5064656),
+(43.7652017, -79.3832771),
+(43.3487291, -79.885335),
+(43.4932777, -79.8710321),
+(43.7310859, -79.7283975),
+(43.899716, -79.2527241),
+(43.3926025, -79.7228336),
+(43.626917, -79.478378),
+(43.622515, -79.482441),
+(43.6908649, -79.8413983),
+(43.657547, -79.401464),
+(43.7149056, -79.8133143),
+(43.7917673, -79.8785604),
+(43.8833921, -79.2469364),
+(43.5753621, -79.6175603),
+(43.6599471, -79.4165785),
+(43.880717, -79.2956574),
+(43.8841749, -79.2820172),
+(43.3634928, -79.7405651),
+(43.7055814, -79.7776636),
+(43.6660249, -79.320463),
+(43.6895541, -79.3158738),
+(43.5597966, -79.5977394),
+(43.7804522, -79.4275805),
+(43.3538863, -79.8161092),
+(43.7769288, -79.1500251),
+(43.4325856, -79.6983128),
+(43.4803135, -79.8678431),
+(43.8607037, -79.4533321),
+(43.7677952, -79.4377575),
+(43.6813854, -79.4928427),
+(43.909627, -79.44314),
+(43.7820784, -79.3921358),
+(43.8558603, -79.4836722),
+(43.7755525, -79.3876432),
+(43.9697256, -79.4451098),
+(43.8167459, -79.5617563),
+(43.6747765, -79.2861823),
+(43.403215, -79.700388),
+(43.728777, -79.522653),
+(43.5863025, -79.602461),
+(43.5630943, -79.601452),
+(43.7244955, -79.2517684),
+(43.454157, -79.670543),
+(43.545153, -79.733174),
+(43.53699, -79.7035537),
+(43.4276412, -79.6991059),
+(43.4311554, -79.6842184),
+(43.7872812, -79.4282906),
+(43.9669001, -79.13366),
+(43.9425584, -79.4762835),
+(43.4770024, -79.6565169),
+(43.9762223, -79.4880841),
+(43.9446796, -79.4652727),
+(43.8894085, -79.2488304),
+(43.8440376, -79.5033458),
+(43.8728915, -79.2463381),
+(43.7735355, -79.3798505),
+(43.683599, -79.9466291),
+(43.5883235, -79.5865722),
+(43.663293, -79.323712),
+(43.7409711, -79.4426921),
+(43.7835817, -79.4014933),
+(43.8800967, -78.9083916),
+(43.8844103, -79.4029686),
+(43.7959305, -79.3991497),
+(43.8546577, -79.4931617),
+(43.6252037, -79.8793658),
+(43.4056825, -79.7017371),
+(43.6446545, -79.4156518),
+(43.9762649, -79.4879015),
+(43.4204154, -79.6871974),
+(43.640634, -79.552153),
+(43.6842595, -79.8524018),
+(43.744298, -79.209542),
+(43.6854398, -79.2828686),
+(43.7736783, -79.3770054),
+(43.6088415, -79.5386035),
+(43.9300305, -78.9301457),
+(43.8762988, -79.2422027),
+(43.808893, -79.366376),
+(43.4536802, -79.6711111),
+(43.526331, -79.846265),
+(43.443316, -79.670015),
+(43.507186, -79.863059),
+(43.8904837, -78.9230386),
+(43.823668, -79.147326),
+(43.751745, -79.1890611),
+(43.6411375, -79.4007447),
+(43.7782404, -79.4177748),
+(43.493007, -79.8656167),
+(43.5857073, -79.6464202),
+(43.5745729, -79.7337269),
+(43.759252, -79.2575702),
+(43.8560491, -79.5436316),
+(43.881963, -78.9548268),
+(43.6620243, -79.3865463),
+(43.6871794, -79.5048436),
+(43.7563401, -79.4056932),
+(43.6650085, -79.5631207),
+(43.7625, -79.1981),
+(43.9024294, -79.6589596),
+(43.5218696, -79.6761877),
+(43.5975715, -79.510258),
+(43.833889, -78.986646),
+(43.8251084, -79.4682552),
+(43.9648649, -78.9592638),
+(43.639214, -79.5903991),
+(43.7892336, -79.4138558),
+(43.7548425, -79.3372804),
+(43.7544434, -79.3745661),
+(43.6893339, -79.7464586),
+(43.6576836, -79.54376),
+(43.7049147, -79.2608658),
+(43.647624, -79.396861),
+(43.7353274, -79.7626674),
+(43.4831913, -79.8701418),
+(43.6107139, -79.6591896),
+(43.8991794, -79.0112008),
+(43.8892899, -78.9659786),
+(43.9370665, -78.8923068),
+(43.9064116, -78.9787368),
+(43.9128215, -78.922252),
+(43.7827681, -79.3657391),
+(43.6401213, -79.3812266),
+(43.829591, -79.066753),
+(43.7517802, -79.8215371),
+(43.8363536, -79.5539074),
+(43.640845, -79.414319),
+(43.873962, -79.718549),
+(43.7106444, -79.6445193),
+(43.643323, -79.559239),
+(43.6191999, -79.4830575),
+(43.588409, -79.789988),
+(43.5904264, -79.5707812),
+(43.5548552, -79.5800457),
+(43.6693707, -79.544687),
+(43.3478, -79.7806),
+(43.8861136, -78.945867),
+(43.857262, -79.017354),
+(43.5927159, -79.6041477),
+(43.7022417, -79.8428082),
+(43.5867765, -79.7719822),
+(43.8887209, -79.4129958),
+(43.5630488, -79.5759477),
+(43.7073194, -79.4198986),
+(43.7751229, -79.4138309),
+(43.6230869, -79.8838443),
+(43.778926, -79.417112),
+(43.8368067, -79.6226433),
+(43.657102, -79.374093),
+(43.6983542, -79.3647852),
+(43.7156819, -79.7677409),
+(43.7748082, -79.4045609),
+(43.537821, -79.85965),
+(43.745043, -79.765243),
+(43.843628, -79.128603),
+(43.5474659, -79.7431876),
+(43.7089383, -79.7823182),
+(43.669301, -79.384171),
+(43.5805107, -79.6244991),
+(43.664723, -79.52401),
+(43.6572744, -79.537638),
+(43.6706377, -79.3901448),
+(43.739727, -79.5746516),
+(43.87989, -79.735228),
+(43.8410876, -79.405166),
+(43.5917166, -79.7771307),
+(43.6667359, -79.4506731),
+(43.708353, -79.264185),
+(43.652331, -79.356014),
+(43.9781869, -79.4643373),
+(43.8371904, -79.0784106),
+(43.471603, -79.7504152),
+(43.756206, -79.602491),
+(43.5571607, -79.7071213),
+(43.859275, -79.028471),
+(43.6489903, -79.3870081),
+(43.810086, -79.36409),
+(43.644679, -79.900498),
+(43.3962395, -79.7127951),
+(43.640359, -79.884764),
+(43.7075746, -79.3101847),
+(43.74543, -79.3555308),
+(43.7402509, -79.2609564),
+(43.5927336, -79.706565),
+(43.4904103, -79.8774736),
+(43.4425968, -79.712181),
+(43.96066, -78.946192),
+(43.6782856, -79.8280876),
+(43.9111894, -78.9206406),
+(43.852115, -79.10122),
+(43.7531571, -79.5136899),
+(43.866009, -79.861997),
+(43.7733017, -79.2553721),
+(43.834701, -79.070892),
+(43.8996692, -79.2931826),
+(43.8429834, -79.2754337),
+(43.861574, -79.084851),
+(43.8121488, -79.4569092),
+(43.6765829, -79.4139144),
+(43.7255635, -79.8197747),
+(43.9214991, -78.9167473),
+(43.6725851, -79.3172907),
+(43.8107918, -79.3590482),
+(43.7083764, -79.7725137),
+(43.4314539, -79.7791373),
+(43.5178359, -79.8481703),
+(43.8790625, -79.0851659),
+(43.872169, -79.4667374),
+(43.7164916, -79.8294463),
+(43.3550653, -79.7496457),
+(43.9119329, -79.2555124),
+(43.8873812, -79.0290219),
+(43.6957708, -79.7845929),
+(43.8888522, -78.8886065),
+(43.668332, -79.8230233),
+(43.900546, -78.976088),
+(43.842433, -79.122962),
+(43.7563794, -79.7334914),
+(43.707669, -79.392307),
+(43.6865523, -79.4421867),
+(43.6430829, -79.4018327),
+(43.6427017, -79.3933634),
+(43.7658523, -79.3827681),
+(43.671156, -79.394936),
+(43.7731661, -79.4154428),
+(43.6613867, -79.3804382),
+(43.8706367, -78.9512665),
+(43.8855176, -79.4069896),
+(43.915026, -78.8971915),
+(43.3525786, -79.8494377),
+(43.5955489, -79.6337354),
+(43.877466, -79.231754),
+(43.4935347, -79.8743128),
+(43.84704, -79.249586),
+(43.6441339, -79.5889237),
+(43.878775, -79.715406),
+(43.8046762, -79.4230377),
+(43.669593, -79.38058),
+(43.6888299, -79.3531033),
+(43.6947332, -79.2733524),
+(43.637305, -79.735244),
+(43.3772464, -79.7948168),
+(43.6600719, -79.5164138),
+(43.4282526, -79.7546148),
+(43.7494133, -79.7730888),
+(43.6457103, -79.5145589),
+(43.601038, -79.709741),
+(43.802145, -79.4206681),
+(43.6036254, -79.6939638),
+(43.6876996, -79.4674183),
+(43.766314, -79.74784),
+(43.717564, -79.7264808),
+(43.8090153, -79.7322489),
+(43.7107688, -79.7238786),
+(43.5350747, -79.8605302),
+(43.428668, -79.7031432),
+(43.411811, -79.801472),
+(43.5619689, -79.7374941),
+(43.6947043, -79.5576801),
+(43.3434469, -79.7963879),
+(43.8961675, -79.4329359),
+(43.9036446, -79.6505264),
+(43.7242852, -79.4233642),
+(43.7695626, -79.4134466),
+(43.780853, -79.4144717),
+(43.780853, -79.412934),
+(43.7141279, -79.3920441),
+(43.773403, -79.344636),
+(43.6679848, -79.3755762),
+(43.7002792, -79.4278103),
+(43.6695584, -79.3846569),
+(43.772832, -79.412978),
+(43.651327, -79.3583833),
+(43.9057871, -78.9743136),
+(43.9568259, -78.9634517),
+(43.8667088, -79.438789),
+(43.796519, -79.518865),
+(43.5737365, -79.5788452),
+(43.8735933, -79.3295128),
+(43.7494363, -79.2092391),
+(43.4735747, -79.6365465),
+(43.8495444, -79.0654087),
+(43.558921, -79.643939),
+(43.4449377, -79.6823965),
+(43.6875507, -79.800025),
+(43.705845, -79.466962),
+(43.8745824, -79.2726648),
+(43.7271344, -79.5654462),
+(43.7654079, -79.7243942),
+(43.5845766, -79.723839),
+(43.9771081, -79.4781684),
+(43.642643, -79.527247),
+(43.4273331, -79.7455358),
+(43.7057634, -79.466791),
+(43.77535, -79.760604),
+(43.8612785, -79.3488571),
+(43.7447117, -79.2228141),
+(43.8426315, -79.4669955),
+(43.5863243, -79.6413876),
+(43.8497821, -79.3288246),
+(43.5653261, -79.7404961),
+(43.6664277, -79.8143448),
+(43.7024886, -79.8431824),
+(43.4498303, -79.7271343),
+(43.5964444, -79.5443432),
+(43.8983, -79.4064),
+(43.7625782, -79.3659614),
+(43.6414554, -79.3912936),
+(43.7690784, -79.3694836),
+(43.955584, -79.572696),
+(43.594913, -79.635736),
+(43.7084468, -79.3976005),
+(43.6429141, -79.3806469),
+(43.641806, -79.378981),
+(43.7056504, -79.3973799),
+(43.5800439, -79.6250026),
+(43.6427513, -79.3769186),
+(43.5800439, -79.6250026),
+(43.5809236, -79.6238027),
+(43.7785806, -79.2811718),
+(43.7075225, -79.392402),
+(43.6426507, -79.3773286),
+(43.6462722, -79.3945349),
+(43.672986, -79.387029),
+(43.664773, -79.386987),
+(43.7602436, -79.4083424),
+(43.630681, -79.882218),
+(43.690637, -79.341822),
+(43.802145, -79.4206681),
+(43.664844, -79.38593),
+(43.7648208, -79.410443),
+(43.8146069, -79.2334758),
+(43.981979, -79.4832516),
+(43.623821, -79.478728),
+(43.641785, -79.379006),
+(43.7642352, -79.4127406),
+(43.731744, -79.577292),
+(43.447443, -79.686948),
+(43.4087632, -79.819142),
+(43.765261, -79.4143597),
+(43.970816, -79.249128),
+(43.642548, -79.425899),
+(43.818981, -79.079008),
+(43.495476, -79.718644),
+(43.8825679, -79.493175),
+(43.4400475, -79.7378899),
+(43.953941, -78.895383),
+(43.4008212, -79.8241167),
+(43.6534384, -79.8101954),
+(43.5546111, -79.7106761),
+(43.919627, -79.799857),
+(43.827056, -79.113665),
+(43.7877417, -79.7494199),
+(43.8509016, -79.4306409),
+(43.7084948, -79.7495266),
+(43.6316681, -79.548399),
+(43.641806, -79.378981),
+(43.6466902, -79.4218755),
+(43.9133161, -78.9550351),
+(43.6381724, -79.489059),
+(43.7398872, -79.4860122),
+(43.7398872, -79.4860122),
+(43.6358644, -79.4673894),
+(43.9627558, -78.9055234),
+(43.7727963, -79.3453943),
+(43.7843151, -79.3717668),
+(43.9785703, -79.4462784),
+(43.4818934, -79.7267202),
+(43.6467185, -79.5254493),
+(43.6706912, -79.4783546),
+(43.6673325, -79.8030392),
+(43.5671142, -79.5720556),
+(43.5671142, -79.5720556),
+(43.6626979, -79.3794096),
+(43.7842158, -79.3967163),
+(43.6510293, -79.7515727),
+(43.6513368, -79.7510423),
+(43.6997614, -79.7595255),
+(43.829103, -79.10092),
+(43.6807221, -79.7832496),
+(43.6464451, -79.5257461),
+(43.7930527, -79.5812928),
+(43.913658, -79.6485229),
+(43.7034304, -79.5257417),
+(43.5671142, -79.5720556),
+(43.7406387, -79.7429786),
+(43.671238, -79.823197),
+(43.6411407, -79.4036466),
+(43.7907553, -79.758992),
+(43.6617616, -79.3836302),
+(43.5935564, -79.5336308),
+(43.5841003, -79.6446072),
+(43.5935564, -79.5336308),
+(43.8316837, -79.1328101),
+(43.8940298, -79.0418276),
+(43.871239, -79.710657),
+(43.661967, -79.383964),
+(43.8873431, -79.4073095),
+(43.6617616, -79.3836302),
+(43.4529826, -79.6835772),
+(43.4529826, -79.6835772),
+(43.819654, -79.022235),
+(43.7650996, -79.7270478),
+(43.7034304, -79.5257417),
+(43.8419241, -79.0725646),
+(43.67369, -79.3417634),
+(43.45176, -79.65631),
+(43.7827, -79.4673),
+(43.8430677, -79.4265211),
+(43.4930027, -79.8706212),
+(43.6862934, -79.3074313),
+(43.865177, -79.027217),
+(43.7704603, -79.4322739),
+(43.4479112, -79.9295999),
+(43.6238205, -79.480348),
+(43.7630506, -79.2828027),
+(43.7893747, -79.5306859),
+(43.671819, -79.3724032),
+(43.8003304, -79.4787099),
+(43.9553569, -79.2640615),
+(43.5355336, -79.6607718),
+(43.4529826, -79.6835772),
+(43.4555421, -79.6679018),
+(43.850146, -79.6096926),
+(43.8606975, -78.9383595),
+(43.6368129, -79.3975163),
+(43.5671142, -79.5720556),
+(43.7590053, -79.5885886),
+(43.6718462, -79.3726355),
+(43.6785321, -79.8279415),
+(43.5656739, -79.7024956),
+(43.6520694, -79.8044671),
+(43.721861, -79.448516),
+(43.778141, -79.406668),
+(43.6387257, -79.5356352),
+(43.4121113, -79.6986941),
+(43.8132447, -79.412254),
+(43.3414059, -79.7928312),
+(43.7430414, -79.3659229),
+(43.6388334, -79.3988388),
+(43.7585111, -79.3765865),
+(43.983192, -79.771893),
+(43.7990117, -79.5910862),
+(43.3775777, -79.8066593),
+(43.9461791, -79.7787746),
+(43.433141, -79.770423),
+(43.486598, -79.720422),
+(43.6427756, -79.4984381),
+(43.6359244, -79.7131849),
+(43.3621489, -79.7391312),
+(43.7246556, -79.5300975),
+(43.6605241, -79.4492841),
+(43.3590482, -79.9410353),
+(43.564334, -79.570084),
+(43.75618, -79.398791),
+(43.398877, -79.7152548),
+(43.3954773, -79.8180629),
+(43.899892, -79.010573),
+(43.6397347, -79.3920867),
+(43.831699, -79.06477),
+(43.8399429, -79.4700121),
+(43.358787, -79.8471878),
+(43.3940052, -79.7087494),
+(43.7286405, -79.4636886),
+(43.5413969, -79.5958942),
+(43.4539938, -79.6683522),
+(43.7434438, -79.3986633),
+(43.5805107, -79.6244991),
+(43.9204073, -79.5224306),
+(43.6263259, -79.477234),
+(43.7957722, -79.2305512),
+(43.3554902, -79.7502084),
+(43.7366766, -79.7212827),
+(43.8543625, -79.6377077),
+(43.731351, -79.48585),
+(43.780324, -79.418647),
+(43.4285, -79.7445),
+(43.8326, -79.6325),
+(43.9398619, -79.4735329),
+(43.5800439, -79.6250026),
+(43.880364, -79.00241),
+(43.4769, -79.6517),
+(43.8125538, -79.4529616),
+(43.543937, -79.892214),
+(43.8793, -79.497),
+(43.7842507, -79.5962857),
+(43.603957, -79.6496967),
+(43.795759, -79.520472),
+(43.871448, -79.774777),
+(43.7472676, -79.3999829),
+(43.5524496, -79.6109675),
+(43.7070826, -79.3977712),
+(43.7953769, -79.7359535),
+(43.8988459, -79.4257251),
+(43.6704, -79.7528),
+(43.707083, -79.397771),
+(43.753877, -79.385386),
+(43.6417992, -79.5445367),
+(43.7688896, -79.4180457),
+(43.7438137, -79.3999152),
+(43.625807, -79.477924),
+(43.6056375, -79.4984726),
+(43.6395122, -79.5828363),
+(43.4360764, -79.7763802),
+(43.8882009, -79.2350784),
+(43.8705234, -78.932931),
+(43.60371, -79.545679),
+(43.8681264, -79.2130916),
+(43.749821, -79.384514),
+(43.4650271, -79.6584269),
+(43.8629236, -79.2234014),
+(43.7853926, -79.5994358),
+(43.88701, -79.3936526),
+(43.863707, -79.514399),
+(43.8427373, -79.9223509),
+(43.6584381, -79.4705413),
+(43.659454, -79.382587),
+(43.7487107, -79.4326312),
+(43.7884904, -79.452403),
+(43.7023628, -79.4479735),
+(43.3767332, -79.7203957),
+(43.7386, -79.8437),
+(43.650405, -79.392168),
+(43.4816061, -79.8741102),
+(43.7491906, -79.4040226),
+(43.658924, -79.3840439),
+(43.8533449, -79.5532775),
+(43.7855946, -79.3645242),
+(43.8441286, -79.5528643),
+(43.652572, -79.363722),
+(43.7703, -79.413645),
+(43.7544787, -79.3941315),
+(43.9394042, -79.0295658),
+(43.4670676, -79.657395),
+(43.9865649, -79.4814569),
+(43.485136, -79.849159),
+(43.8044089, -79.7452375),
+(43.456768, -79.706792),
+(43.618993, -79.483137),
+(43.7418, -79.4709),
+(43.9010081, -79.4292539),
+(43.7691232, -79.3699769),
+(43.7938043, -79.1566145),
+(43.7800398, -79.4126795),
+(43.961019, -78.892052),
+(43.827954, -79.122653),
+(43.8480882, -79.5714003),
+(43.8198174, -79.6182177),
+(43.8169954, -79.7232336),
+(43.680643, -79.8395583),
+(43.768673, -79.177074),
+(43.778613, -79.4510561),
+(43.8821374, -78.9333207),
+(43.6915081, -79.7754452),
+(43.7262279, -79.7337354),
+(43.693802, -79.326994),
+(43.6688114, -79.3068879),
+(43.8669461, -79.4405641),
+(43.5917708, -79.6157969),
+(43.8970108, -79.4687358),
+(43.9040847, -79.2324901),
+(43.882782, -79.34572),
+(43.6740872, -79.2904412),
+(43.694369, -79.278434),
+(43.8140985, -79.4514793),
+(43.6489, -79.392205),
+(43.7477322, -79.579668),
+(43.9111387, -78.9153748),
+(43.9008955, -79.255943),
+(43.774173, -79.4202456),
+(43.7238478, -79.4372562),
+(43.877967, -79.233088),
+(43.6834366, -79.3035764),
+(43.85195, -79.431773),
+(43.796519, -79.518865),
+(43.636943, -79.4689321),
+(43.9700014, -79.4787955),
+(43.6037837, -79.7035156),
+(43.8935993, -79.3736075),
+(43.6024134, -79.5050072),
+(43.651605, -79.410692),
+(43.6460155, -79.3940644),
+(43.6403228, -79.3917567),
+(43.767938, -79.17758),
+(43.62526, -79.477903),
+(43.4902762, -79.8718435),
+(43.7666151, -79.2854001),
+(43.6740324, -79.3904727),
+(43.8440632, -79.433706),
+(43.856131, -79.045503),
+(43.7866554, -79.3896426),
+(43.665786, -79.3855809),
+(43.94631, -79.482145),
+(43.6355252, -79.5675095),
+(43.891209, -78.9048902),
+(43.975839, -79.664508),
+(43.476709, -79.765059),
+(43.833663, -79.661922),
+(43.871332, -79.867277),
+(43.5508057, -79.8613284),
+(43.866906, -79.341517),
+(43.6366949, -79.4324875),
+(43.453553, -79.679132),
+(43.843006, -79.712148),
+(43.8333819, -79.4361256),
+(43.8728615, -79.4363728),
+(43.4501767, -79.9224315),
+(43.8746247, -79.0622748),
+(43.791887, -79.3535054),
+(43.697717, -79.51213),
+(43.6976288, -79.5120986),
+(43.697717, -79.51213),
+(43.697717, -79.51213),
+(43.6976288, -79.5120986),
+(43.697717, -79.51213),
+(43.901675, -79.6512957),
+(43.7692297, -79.411935),
+(43.84974, -79.067092),
+(43.84903, -79.067578),
+(43.84937, -79.067699),
+(43.849451, -79.066935),
+(43.848535, -79.067476),
+(43.825456, -79.073652),
+(43.957096, -79.448611),
+(43.6274692, -79.4771277),
+(43.452597, -79.6708593),
+(43.849604, -79.067803),
+(43.443748, -79.684727),
+(43.773162, -79.403535),
+(43.8810727, -79.3689088),
+(43.944767, -79.482384),
+(43.637724, -79.39508),
+(43.5610026, -79.5751777),
+(43.7686926, -79.4275037),
+(43.623513, -79.479562),
+(43.7197126, -79.5155828),
+(43.6241341, -79.4858146),
+(43.849149, -79.065278),
+(43.643237, -79.558759),
+(43.7549929, -79.4238332),
+(43.4545792, -79.6600479),
+(43.8188239, -79.7170417),
+(43.7994407, -79.2927461),
+(43.7267158, -79.4263704),
+(43.8305579, -79.4654061),
+(43.7486339, -79.5522568),
+(43.9636846, -79.2288086),
+(43.711825, -79.2633446),
+(43.7150075, -79.3660971),
+(43.3863858, -79.8154969),
+(43.7451865, -79.3084749),
+(43.3587761, -79.7510522),
+(43.7228324, -79.3093719),
+(43.948309, -78.925777),
+(43.9236888, -79.5306585),
+(43.8081345, -79.4386576),
+(43.9264873, -79.5166768),
+(43.802145, -79.4206681),
+(43.7892655, -79.4364369),
+(43.8887892, -79.3408074),
+(43.920812, -78.915962),
+(43.685714, -79.404702),
+(43.697048, -79.443203),
+(43.8834017, -79.3062972),
+(43.952108, -79.438072),
+(43.7887139, -79.4583778),
+(43.8646859, -79.3135411),
+(43.8311637, -79.4046219),
+(43.823243, -79.57372),
+(43.6386323, -79.399676),
+(43.478741, -79.759713),
+(43.6384898, -79.7126322),
+(43.6344845, -79.5656952),
+(43.7415941, -79.5434627),
+(43.8613752, -79.2944553),
+(43.7464177, -79.5099064),
+(43.653408, -79.4988186),
+(43.639567, -79.564168),
+(43.4793057, -79.7216264),
+(43.7878792, -79.7183569),
+(43.892428, -79.897488),
+(43.971496, -78.992649),
+(43.7822086, -79.3890393),
+(43.953809, -79.324663),
+(43.8597456, -79.3265612),
+(43.68078, -79.425021),
+(43.4615833, -79.7292864),
+(43.646099, -79.365136),
+(43.802257, -79.392852),
+(43.9300867, -79.535333),
+(43.5373178, -79.6205807),
+(43.7338186, -79.346789),
+(43.7959991, -79.4442188),
+(43.9768938, -79.4572807),
+(43.7656363, -79.4324473),
+(43.4501618, -79.7329067),
+(43.5702845, -79.631994),
+(43.8915887, -79.4281468),
+(43.8294326, -79.5662765),
+(43.942485, -79.4781898),
+(43.775424, -79.337482),
+(43.9415906, -79.0289262),
+(43.7890853, -79.4372567),
+(43.610842, -79.6501712),
+(43.813647, -79.4550817),
+(43.7087641, -79.2537594),
+(43.5984106, -79.6347881),
+(43.640955, -79.393844),
+(43.6958406, -79.3710147),
+(43.8061476, -79.2555995),
+(43.7370862, -79.4644372),
+(43.64182, -79.381734),
+(43.812554, -79.4544922),
+(43.665388, -79.3880211),
+(43.586289, -79.647364),
+(43.8628157, -79.4416326),
+(43.5730593, -79.7091852),
+(43.8664336, -79.4355835),
+(43.6400837, -79.3790949),
+(43.6009698, -79.5452604),
+(43.834228, -79.126098),
+(43.6238205, -79.480348),
+(43.776323, -79.335927),
+(43.6214, -79.911047),
+(43.7933998, -79.6712452),
+(43.653934, -79.810225),
+(43.8067482, -79.3740919),
+(43.8553593, -79.3434198),
+(43.7989698, -79.4148234),
+(43.4559437, -79.6611719),
+(43.8036515, -79.4505507),
+(43.940646, -79.475356),
+(43.7961517, -79.4390752),
+(43.646274, -79.3614175),
+(43.9702672, -79.2637063),
+(43.447473, -79.68731),
+(43.7443219, -79.4189832),
+(43.7671729, -79.40028),
+(43.6398049, -79.3979687),
+(43.6386152, -79.4127418),
+(43.410359, -79.791237),
+(43.8128057, -79.4519464),
+(43.7755305, -79.4152516),
+(43.62933, -79.476348),
+(43.9019183, -79.2523583),
+(43.939768, -79.466367),
+(43.6743501, -79.4565812),
+(43.8469253, -79.5171372),
+(43.532508, -79.930354),
+(43.5738069, -79.587996),
+(43.5714037, -79.7825066),
+(43.7802681, -79.4360638),
+(43.9470963, -78.9367144),
+(43.7777736, -79.3068188),
+(43.747544, -79.28592),
+(43.843866, -79.431036),
+(43.9718527, -79.1299089),
+(43.8633183, -79.3199135),
+(43.5161295, -79.6025546),
+(43.8895424, -79.3364377),
+(43.867882, -79.866703),
+(43.5181921, -79.6340019),
+(43.4152972, -79.7014963),
+(43.9042917, -79.2776743),
+(43.672194, -79.387865),
+(43.7415135, -79.3922842),
+(43.7020783, -79.4191322),
+(43.869645, -78.946695),
+(43.862011, -79.481891),
+(43.882782, -79.298042),
+(43.821012, -79.4469339),
+(43.8200556, -79.3768289),
+(43.700221, -79.427761),
+(43.538031, -79.6205573),
+(43.4484831, -79.6642619),
+(43.4384731, -79.680557),
+(43.7695626, -79.4134466),
+(43.9702672, -79.2637063),
+(43.650405, -79.392168),
+(43.894338, -79.2209233),
+(43.9539343, -79.4812993),
+(43.7130728, -79.3905173),
+(43.8585276, -79.451226),
+(43.805462, -79.43575),
+(43.681787, -79.470323),
+(43.746149, -79.3613486),
+(43.8555059, -79.5014747),
+(43.9438131, -79.4621117),
+(43.7458437, -79.3908185),
+(43.5258101, -79.6690748),
+(43.4434425, -79.6699854),
+(43.795299, -79.3014786),
+(43.4401402, -79.6765163),
+(43.4939343, -79.7044515),
+(43.8941579, -79.3110231),
+(43.8711463, -79.4392892),
+(43.6633091, -79.3662602),
+(43.7422402, -79.3892217),
+(43.6609916, -79.8044496),
+(43.5517786, -79.7217634),
+(43.4582458, -79.6586392),
+(43.4582973, -79.6525786),
+(43.5529847, -79.7324364),
+(43.641596, -79.423443),
+(43.4569527, -79.6597724),
+(43.8899637, -79.2135022),
+(43.350223, -79.873215),
+(43.5205716, -79.8809432),
+(43.64534, -79.402185),
+(43.64534, -79.402185),
+(43.694633, -79.847553),
+(43.664701, -79.77974),
+(43.656165, -79.383893),
+(43.7764488, -79.317786),
+(43.849132, -79.432632),
+(43.865734, -79.065205),
+(43.661363, -79.386445),
+(43.3954966, -79.8290204),
+(43.4974207, -79.7424764),
+(43.67272, -79.928141),
+(43.376057, -79.7260197),
+(43.641605, -79.528263),
+(43.908448, -79.372054),
+(43.650554, -79.357382),
+(43.842168, -79.433249),
+(43.912082, -79.053057),
+(43.438246, -79.685952),
+(43.430908, -79.725622),
+(43.808182, -79.378377),
+(43.4449245, -79.6827876),
+(43.6326794, -79.5019916),
+(43.4326741, -79.6835303),
+(43.648317, -79.393065),
+(43.4813689, -79.8752693),
+(43.621064, -79.48332),
+(43.4756347, -79.6940017),
+(43.659948, -79.451586),
+(43.411811, -79.801472),
+(43.345889, -79.881669),
+(43.479585, -79.725131),
+(43.659824, -79.362727),
+(43.760108, -79.408225),
+(43.793854, -79.443389),
+(43.932223, -79.520834),
+(43.6584681, -79.3665242),
+(43.889038, -79.280416),
+(43.961678, -79.4372405),
+(43.622114, -79.52269),
+(43.641596, -79.423443),
+(43.6978398, -79.3871947),
+(43.451045, -79.6809362),
+(43.874528, -79.121418),
+(43.69976, -79.518492),
+(43.650615, -79.384663),
+(43.655305, -79.3537502),
+(43.652039, -79.382188),
+(43.346577, -79.888241),
+(43.5126327, -79.6309339),
+(43.718556, -79.32629),
+(43.668718, -79.383996),
+(43.6425128, -79.3813182),
+(43.8535994, -79.464877),
+(43.8534942, -79.4653801),
+(43.4063347, -79.7152932),
+(43.636021, -79.9470453),
+(43.777401, -79.412802),
+(43.4372866, -79.7250528),
+(43.4251737, -79.784818),
+(43.669301, -79.384171),
+(43.765314, -79.412362),
+(43.8280559, -79.090088),
+(43.4845535, -79.7314613),
+(43.861658, -78.935551),
+(43.4656943, -79.6674942),
+(43.4660391, -79.6466473),
+(43.515143, -79.883451),
+(43.5024947, -79.6644273),
+(43.3947011, -79.8277361),
+(43.714808, -79.479883),
+(43.656333, -79.383964),
+(43.7885944, -79.2579252),
+(43.645395, -79.448947),
+(43.664057, -79.383809),
+(43.668359, -79.412681),
+(43.9711, -79.2461),
+(43.5604778, -79.5852356),
+(43.663536, -79.383736),
+(43.769638, -79.389692),
+(43.7376729, -79.2190043),
+(43.8343267, -79.5759204),
+(43.513318, -79.8327561),
+(43.751476, -79.457932),
+(43.7260989, -79.3960241),
+(43.707824, -79.392725),
+(43.5332837, -79.7061214),
+(43.8574656, -79.533649),
+(43.631009, -79.8185911),
+(43.7665999, -79.3831676),
+(43.9640566, -79.246437),
+(43.6302596, -79.5779556),
+(43.8476466, -79.2522795),
+(43.4221953, -79.941171),
+(43.6668981, -79.3890419),
+(43.9778457, -79.2386859),
+(43.5007987, -79.7033617),
+(43.4697135, -79.7313171),
+(43.63821, -79.39136),
+(43.7836411, -79.6708427),
+(43.3495506, -79.8222063),
+(43.8524456, -79.3280054),
+(43.581187, -79.647617),
+(43.774764, -79.336918),
+(43.926537, -78.882781),
+(43.843571, -79.129515),
+(43.9677688, -78.9552989),
+(43.7815814, -79.3922116),
+(43.6708347, -79.4918039),
+(43.7783428, -79.4159581),
+(43.6965294, -79.4320342),
+(43.636759, -79.39785),
+(43.6657347, -79.447861),
+(43.3700573, -79.7410001),
+(43.773021, -79.3898521),
+(43.77288, -79.38983),
+(43.7843658, -79.4151643),
+(43.717294, -79.4418502),
+(43.5523348, -79.7346634),
+(43.7653441, -79.4126151),
+(43.6556642, -79.8007224),
+(43.704604, -79.38901),
+(43.6824332, -79.3902828),
+(43.646809, -79.390182),
+(43.792291, -79.205226),
+(43.8217204, -79.3829932),
+(43.962794, -79.448501),
+(43.8714097, -79.3273437),
+(43.7941787, -79.4397985),
+(43.6592713, -79.3849163),
+(43.774325, -79.346091),
+(43.7183968, -79.3033583),
+(43.6348247, -79.40156),
+(43.9681297, -79.2223317),
+(43.7078684, -79.2557625),
+(43.6094162, -79.55872),
+(43.5553349, -79.7565573),
+(43.9497928, -78.8913365),
+(43.6412251, -79.3809582),
+(43.8798896, -79.3690595),
+(43.429299, -79.727932),
+(43.772466, -79.355047),
+(43.777026, -79.413773),
+(43.869978, -79.758273),
+(43.7570297, -79.8216312),
+(43.8034472, -79.2042361),
+(43.9022712, -79.2388663),
+(43.7970588, -79.3773701),
+(43.7103893, -79.3021145),
+(43.9001883, -79.2330816),
+(43.7067331, -79.2937299),
+(43.512833, -79.8409545),
+(43.5635299, -79.7447234),
+(43.4285013, -79.763019),
+(43.9680766, -79.276052),
+(43.3428054, -79.7697828),
+(43.874954, -79.720096),
+(43.8964655, -79.2468856),
+(43.5547239, -79.5804579),
+(43.7621072, -79.4493755),
+(43.884976, -78.926292),
+(43.940552, -79.646676),
+(43.8602791, -79.4001188),
+(43.6476241, -79.3991681),
+(43.7639534, -79.4069857),
+(43.58504, -79.646032),
+(43.7210657, -79.4764953),
+(43.760315, -79.411703),
+(43.6455963, -79.5807862),
+(43.646084, -79.365012),
+(43.641087, -79.381405),
+(43.682132, -79.809297),
+(43.7604118, -79.422942),
+(43.6721786, -79.404449),
+(43.760304, -79.41162),
+(43.798839, -79.919445),
+(43.7745195, -79.3297599),
+(43.965954, -79.377099),
+(43.687539, -79.3978735),
+(43.707669, -79.392307),
+(43.7717364, -79.2613815),
+(43.641087, -79.381405),
+(43.7482103, -79.3616976),
+(43.7577213, -79.3975128),
+(43.8311, -79.114),
+(43.722521, -79.2331879),
+(43.791233, -79.4542851),
+(43.807566, -79.227389),
+(43.8683287, -79.2221955),
+(43.447443, -79.686948),
+(43.9369963, -79.5194783),
+(43.7537115, -79.7475134),
+(43.7396, -79.7378),
+(43.7177475, -79.4494829),
+(43.881674, -79.225092),
+(43.8691868, -79.3157664),
+(43.6361775, -79.8689457),
+(43.6717527, -79.7967302),
+(43.7669919, -79.5302489),
+(43.6211459, -79.5215694),
+(43.447443, -79.686948),
+(43.692768, -79.508482),
+(43.7514475, -79.7563639),
+(43.642168, -79.528334),
+(43.8416724, -79.4772333),
+(43.7538111, -79.7366086),
+(43.5187, -79.8761),
+(43.5972379, -79.561405),
+(43.6978509, -79.5057089),
+(43.6302017, -79.7788949),
+(43.862011, -79.481891),
+(43.982591, -79.720816),
+(43.6702996, -79.4001501),
+(43.7220867, -79.4553008),
+(43.555075, -79.583315),
+(43.6676702, -79.3864205),
+(43.7532386, -79.4909284),
+(43.7225962, -79.7671899),
+(43.651055, -79.359061),
+(43.6490534, -79.3861546),
+(43.646084, -79.365012),
+(43.7703, -79.413645),
+(43.6753313, -79.3971281),
+(43.673983, -79.390142),
+(43.8529766, -79.3368466),
+(43.8012143, -79.424509),
+(43.689404, -79.755104),
+(43.4956025, -79.6999487),
+(43.6412251, -79.3809582),
+(43.7764694, -79.6974564),
+(43.853123, -79.548564),
+(43.791887, -79.3535054),
+(43.665666, -79.382607),
+(43.402978, -79.7818075),
+(43.5718999, -79.7063225),
+(43.8677106, -79.0424533),
+(43.969586, -79.140692),
+(43.6426375, -79.5588279),
+(43.8607997, -79.3166672),
+(43.7169457, -79.8310233),
+(43.9175279, -79.4490679),
+(43.6399564, -79.4259833),
+(43.887529, -79.3130024),
+(43.7030548, -79.8354249),
+(43.4777677, -79.7156292),
+(43.5351838, -79.6628669),
+(43.896526, -79.2350117),
+(43.4149754, -79.6999309),
+(43.6977523, -79.277443),
+(43.6131283, -79.4981267),
+(43.6475103, -79.3927254),
+(43.4966454, -79.8850013),
+(43.669593, -79.38058),
+(43.640277, -79.424126),
+(43.7255991, -79.5102828),
+(43.879086, -78.904358),
+(43.6600823, -79.3659556),
+(43.5012703, -79.8826343),
+(43.7957221, -79.1363168),
+(43.413464, -79.800437),
+(43.644254, -79.766995),
+(43.8909803, -79.4375734),
+(43.811983, -79.456717),
+(43.4392429, -79.737204),
+(43.7086904, -79.7621837),
+(43.7756168, -79.3764783),
+(43.63641, -79.402003),
+(43.56487, -79.701083),
+(43.683327, -79.8187498),
+(43.6361261, -79.5507148),
+(43.4463693, -79.7240175),
+(43.760673, -79.691768),
+(43.7810628, -79.2485568),
+(43.7299064, -79.5227726),
+(43.7270881, -79.5046644),
+(43.7105797, -79.6458085),
+(43.7590053, -79.5885886),
+(43.6700845, -79.3864005),
+(43.623426, -79.4789788),
+(43.8276215, -79.0267335),
+(43.7066454, -79.4592963),
+(43.8866767, -79.0060984),
+(43.9727011, -79.4731266),
+(43.6991745, -79.5321337),
+(43.4089326, -79.7155054),
+(43.3819062, -79.7358635),
+(43.59731, -79.63104),
+(43.5423618, -79.6781112),
+(43.6883007, -79.3970953),
+(43.7324535, -79.7197848),
+(43.7293994, -79.8238981),
+(43.5733665, -79.749233),
+(43.6460836, -79.3650117),
+(43.5863525, -79.636715),
+(43.644672, -79.5294079),
+(43.7098418, -79.396432),
+(43.7070855, -79.3977452),
+(43.7681219, -79.4111307),
+(43.8233165, -79.3887041),
+(43.6323725, -79.5206641),
+(43.462492, -79.647492),
+(43.7752161, -79.4133461),
+(43.7403056, -79.3387775),
+(43.621616, -79.482921),
+(43.5811552, -79.6456268),
+(43.81238, -79.424169),
+(43.6746538, -79.3890069),
+(43.8654856, -79.2198167),
+(43.7330798, -79.7904981),
+(43.885644, -79.025241),
+(43.6491412, -79.5713724),
+(43.5883573, -79.6395201),
+(43.7900246, -79.25466),
+(43.89096, -78.915065),
+(43.7045616, -79.4256344),
+(43.668718, -79.383996),
+(43.6890135, -79.8192548),
+(43.7158103, -79.2817834),
+(43.849828, -79.490764),
+(43.9020421, -79.2498065),
+(43.647627, -79.390408),
+(43.6006042, -79.6388674),
+(43.4916591, -79.7191213),
+(43.6953616, -79.3447377),
+(43.881674, -79.225092),
+(43.7434519, -79.4268995),
+(43.6808516, -79.7618409),
+(43.7256544, -79.8062037),
+(43.539538, -79.674852),
+(43.8200414, -79.3777265),
+(43.5450931, -79.719666),
+(43.8941692, -79.0330026),
+(43.7587861, -79.7531244),
+(43.3938431, -79.7117345),
+(43.7382972, -79.4057368),
+(43.4426282, -79.6723596),
+(43.640529, -79.424411),
+(43.8518853, -79.336294),
+(43.58813, -79.640126),
+(43.763879, -79.284027),
+(43.707083, -79.397771),
+(43.773748, -79.415509),
+(43.7817207, -79.2473686),
+(43.665666, -79.382607),
+(43.7734697, -79.2536323),
+(43.6745155, -79.7255361),
+(43.769664, -79.413783),
+(43.7002792, -79.4278103),
+(43.6594036, -79.3611785),
+(43.8208482, -79.4244323),
+(43.773688, -79.255422),
+(43.8529766, -79.3368466),
+(43.6515293, -79.375757),
+(43.7627512, -79.5199694),
+(43.669963, -79.386158),
+(43.7118809, -79.3059059),
+(43.668718, -79.383996),
+(43.7681903, -79.1830824),
+(43.8076806, -79.2306884),
+(43.7267484, -79.7619281),
+(43.809341, -79.3607118),
+(43.6921289, -79.5542629),
+(43.650554, -79.357382),
+(43.5026866, -79.8909051),
+(43.8621607, -79.4113994),
+(43.7550216, -79.3982838),
+(43.9073317, -79.4386805),
+(43.632191, -79.88495),
+(43.73867, -79.441043),
+(43.7719571, -79.3191226),
+(43.4435583, -79.7481121),
+(43.3959385, -79.7210313),
+(43.4030419, -79.8161062),
+(43.8944222, -79.0270803),
+(43.5810458, -79.7656913),
+(43.5910499, -79.7614546),
+(43.6163828, -79.6505139),
+(43.6035302, -79.5471206),
+(43.9215141, -78.9208517),
+(43.721114, -79.43085),
+(43.786063, -79.367994),
+(43.5768308, -79.7530217),
+(43.7107764, -79.8114772),
+(43.7755949, -79.7270111),
+(43.7470465, -79.765495),
+(43.8818962, -79.2599329),
+(43.6849765, -79.8463223),
+(43.7946319, -79.4806296),
+(43.7776579, -79.135522),
+(43.9593664, -78.946792),
+(43.8167807, -79.3313569),
+(43.9561751, -79.4839297),
+(43.5100528, -79.857073),
+(43.8931802, -79.2692925),
+(43.6418093, -79.5585051),
+(43.6352534, -79.8696693),
+(43.9387422, -79.4494151),
+(43.517069, -79.837974),
+(43.4589758, -79.7280933),
+(43.7442819, -79.3904292),
+(43.8875493, -79.2681744),
+(43.8660321, -79.6184309),
+(43.8948622, -79.2144331),
+(43.9711, -79.2461),
+(43.545551, -79.721956),
+(43.9857563, -79.4398224),
+(43.646944, -79.392397),
+(43.648317, -79.393065),
+(43.784886, -79.184751),
+(43.9711, -79.2461),
+(43.9711, -79.2461),
+(43.9711, -79.2461),
+(43.9711, -79.2461),
+(43.9711, -79.2461),
+(43.413464, -79.800437),
+(43.9711, -79.2461),
+(43.6493345, -79.3747254),
+(43.6501593, -79.3926005),
+(43.698775, -79.384968),
+(43.8532315, -79.1810708),
+(43.7456995, -79.3100961),
+(43.6575992, -79.3498961),
+(43.478579, -79.70921),
+(43.832864, -79.648597),
+(43.9663069, -79.8380723),
+(43.8632208, -79.4417948),
+(43.9158239, -78.948886),
+(43.926094, -79.510813),
+(43.647445, -79.507983),
+(43.874635, -79.757938),
+(43.8713224, -79.0706868),
+(43.431721, -79.730107),
+(43.910163, -79.445996),
+(43.939725, -79.5381221),
+(43.8893014, -79.4472589),
+(43.674264, -79.367471),
+(43.6729857, -79.3870289),
+(43.7625969, -79.4025561),
+(43.7329605, -79.8087274),
+(43.9150364, -79.4406501),
+(43.7744208, -79.3863756),
+(43.7738844, -79.7350078),
+(43.3530454, -79.8170842),
+(43.8453919, -79.4474294),
+(43.645447, -79.394021),
+(43.5493205, -79.5958932),
+(43.508964, -79.86661),
+(43.6038153, -79.6354148),
+(43.686815, -79.464381),
+(43.6933573, -79.440817),
+(43.6821116, -79.3233927),
+(43.9093757, -79.8221402),
+(43.6895145, -79.3356072),
+(43.9546123, -78.8793426),
+(43.6568586, -79.3836642),
+(43.69024, -79.268418),
+(43.8995528, -79.3055226),
+(43.6534041, -79.3680104),
+(43.578848, -79.6433585),
+(43.8635074, -79.4300156),
+(43.964411, -79.451684),
+(43.65438, -79.364389),
+(43.86819, -79.430454),
+(43.8514464, -79.6345138),
+(43.9119536, -78.9162303),
+(43.8779252, -79.2859268),
+(43.6404251, -79.4246524),
+(43.6789769, -79.5695503),
+(43.411804, -79.802099),
+(43.7752161, -79.4133461),
+(43.715027, -79.358347),
+(43.793654, -79.333327),
+(43.8798975, -79.3441663),
+(43.646559, -79.409978),
+(43.6489, -79.392205),
+(43.8787023, -79.3941674),
+(43.459797, -79.7303324),
+(43.78884, -79.3621103),
+(43.7165398, -79.2845142),
+(43.851419, -79.5566414),
+(43.7834476, -79.283569),
+(43.7334172, -79.4281016),
+(43.6539796, -79.3885197),
+(43.864574, -79.536831),
+(43.8123062, -79.4558639),
+(43.6774063, -79.4826329),
+(43.725775, -79.6980808),
+(43.856463, -79.125646),
+(43.854082, -79.1217197),
+(43.8355585, -79.461991),
+(43.4764081, -79.6524421),
+(43.834896, -79.280812),
+(43.876717, -79.260768),
+(43.7788556, -79.4437033),
+(43.4116993, -79.7181466),
+(43.9711, -79.2461),
+(43.669931, -79.375463),
+(43.6839, -79.4824),
+(43.6238205, -79.480348),
+(43.8100647, -79.4487117),
+(43.9609, -78.9009),
+(43.976236, -79.739595),
+(43.646243, -79.881366),
+(43.8391557, -79.2876227),
+(43.9230065, -79.4693751),
+(43.7181, -79.702),
+(43.6778865, -79.8016995),
+(43.679743, -79.4891849),
+(43.6592572, -79.412457),
+(43.7699696, -79.3925376),
+(43.3947249, -79.8288042),
+(43.4752188, -79.7360742),
+(43.910163, -79.445996),
+(43.668099, -79.7748386),
+(43.9659793, -79.4377373),
+(43.7846186, -79.2853195),
+(43.8922746, -79.435316),
+(43.5334113, -79.6682894),
+(43.9006451, -78.9447157),
+(43.6343426, -79.4357252),
+(43.722366, -79.711322),
+(43.776109, -79.414288),
+(43.787299, -79.670924),
+(43.6912297, -79.3662334),
+(43.8508897, -79.4637282),
+(43.727693, -79.3077669),
+(43.433521, -79.770299),
+(43.374964, -79.8034109),
+(43.6721842, -79.5176849),
+(43.8392922, -79.3945697),
+(43.4285, -79.7445),
+(43.735224, -79.302882),
+(43.888688, -79.073078),
+(43.852333, -79.3391785),
+(43.9455533, -79.2739547),
+(43.9569092, -79.4444367),
+(43.4351591, -79.6881601),
+(43.660605, -79.378635),
+(43.802299, -79.936187),
+(43.8501071, -79.4185334),
+(43.7343472, -79.7361069),
+(43.4187116, -79.6968239),
+(43.7720572, -79.6751779),
+(43.8648494, -79.3894491),
+(43.7684181, -79.7617117),
+(43.4285, -79.7445),
+(43.6113025, -79.6540743),
+(43.9060892, -79.4471032),
+(43.5590498, -79.7369812),
+(43.744077, -79.581703),
+(43.805951, -79.5971),
+(43.6699326, -79.4591642),
+(43.727332, -79.4890159),
+(43.8461798, -79.5658687),
+(43.606272, -79.655872),
+(43.8543178, -79.7003624),
+(43.4910398, -79.8637404),
+(43.4190633, -79.7072915),
+(43.3968783, -79.8171758),
+(43.686985, -79.4491122),
+(43.7106435, -79.5166525),
+(43.8225164, -79.4466201),
+(43.6561334, -79.5712675),
+(43.773748, -79.415509),
+(43.8886088, -79.2706612),
+(43.6949193, -79.306904),
+(43.6491763, -79.3770958),
+(43.6446596, -79.420572),
+(43.8823726, -79.2447689),
+(43.7221376, -79.5825004),
+(43.5844612, -79.7236909),
+(43.48417, -79.915863),
+(43.6880417, -79.4046306),
+(43.7927318, -79.365604),
+(43.5898312, -79.7809621),
+(43.711637, -79.6447227),
+(43.8821986, -78.9253949),
+(43.5541899, -79.6132097),
+(43.9173276, -79.5154805),
+(43.8834014, -79.2444644),
+(43.6263857, -79.5103212),
+(43.724592, -79.81139),
+(43.769664, -79.413783),
+(43.8014552, -79.3973984),
+(43.689093, -79.392561),
+(43.7354946, -79.4251649),
+(43.646944, -79.392397),
+(43.6796764, -79.4144085),
+(43.7731083, -79.4578215),
+(43.810167, -79.221126),
+(43.7466592, -79.5061406),
+(43.663587, -79.45906),
+(43.7512065, -79.5828607),
+(43.643269, -79.427537),
+(43.6951203, -79.3051588),
+(43.8948466, -79.2145432),
+(43.8121289, -79.4550839),
+(43.7677262, -79.3706232),
+(43.8225179, -79.3985646),
+(43.8947369, -79.3642738),
+(43.942284, -78.888547),
+(43.7485222, -79.2472764),
+(43.9723265, -79.2516677),
+(43.8716982, -79.302801),
+(43.7922359, -79.4165081),
+(43.9074972, -78.8860251),
+(43.8615096, -79.0164978),
+(43.90698, -78.970693),
+(43.832056, -79.098329),
+(43.6695417, -79.3837883),
+(43.8719335, -79.4528499),
+(43.9062993, -79.4365912),
+(43.669963, -79.386158),
+(43.760379, -79.4113683),
+(43.6729857, -79.3870289),
+(43.665753, -79.385579),
+(43.802145, -79.4206681),
+(43.6398049, -79.3979687),
+(43.7682314, -79.3513966),
+(43.7143591, -79.3531865),
+(43.7808226, -79.2476023),
+(43.7498888, -79.3798738),
+(43.9587602, -79.4400372),
+(43.873852, -79.062286),
+(43.770655, -79.413522),
+(43.7202729, -79.7142161),
+(43.8719429, -79.4660754),
+(43.756968, -79.4107063),
+(43.773748, -79.415509),
+(43.766764, -79.385993),
+(43.7954673, -79.3526778),
+(43.6848481, -79.368773),
+(43.7298311, -79.3335874),
+(43.66955, -79.383776),
+(43.6622639, -79.7968222),
+(43.4958971, -79.7021706),
+(43.6784711, -79.7571647),
+(43.760532, -79.83237),
+(43.971078, -79.793077),
+(43.671731, -79.389691),
+(43.6400003, -79.3788904),
+(43.89354, -78.952122),
+(43.923808, -78.93247),
+(43.6839995, -79.4119636),
+(43.622888, -79.727524),
+(43.372258, -79.7262846),
+(43.6796667, -79.5680513),
+(43.8768357, -79.4458695),
+(43.7951402, -79.5841771),
+(43.638378, -79.584038),
+(43.8987311, -79.25),
+(43.7563401, -79.4056932),
+(43.8763015, -79.0964844),
+(43.821014, -79.1049082),
+(43.5930235, -79.6593313),
+(43.8804216, -79.4648648),
+(43.894524, -79.032767),
+(43.543577, -79.723239),
+(43.8086576, -79.1718837),
+(43.4953392, -79.8920864),
+(43.8824296, -79.4058811),
+(43.606959, -79.6614229),
+(43.4279285, -79.6913796),
+(43.734452, -79.775404),
+(43.702744, -79.451167),
+(43.5728891, -79.5870575),
+(43.963821, -79.4485963),
+(43.9340291, -79.4770094),
+(43.9046887, -79.4469046),
+(43.9391717, -79.4556725),
+(43.7900246, -79.25466),
+(43.670941, -79.7631509),
+(43.6871794, -79.5048436),
+(43.858136, -79.324393),
+(43.9840081, -79.4887679),
+(43.7982782, -79.2353209),
+(43.7946882, -79.2374282),
+(43.7629653, -79.3395363),
+(43.6805915, -79.7922654),
+(43.7229446, -79.3865745),
+(43.7569815, -79.4044001),
+(43.713572, -79.394524),
+(43.665666, -79.382607),
+(43.71358, -79.364763),
+(43.877556, -79.041583),
+(43.6512436, -79.7310134),
+(43.5137475, -79.8481218),
+(43.6806159, -79.8138286),
+(43.8259, -79.094906),
+(43.7732071, -79.3457949),
+(43.7581731, -79.399496),
+(43.782123, -79.361626),
+(43.659783, -79.362653),
+(43.669593, -79.38058),
+(43.7839146, -79.593465),
+(43.8916003, -79.258958),
+(43.6987908, -79.2766007),
+(43.778017, -79.416628),
+(43.832805, -78.992519),
+(43.6962745, -79.2927586),
+(43.7538587, -79.3092364),
+(43.8088032, -79.2091705),
+(43.8174378, -79.463263),
+(43.6006654, -79.5080873),
+(43.5602704, -79.735462),
+(43.6655294, -79.5297813),
+(43.7139423, -79.4514055),
+(43.6094162, -79.55872),
+(43.6698346, -79.4225386),
+(43.6628196, -79.4705273),
+(43.7344213, -79.7719841),
+(43.6889824, -79.8140733),
+(43.6360393, -79.430819),
+(43.6740958, -79.7395291),
+(43.6399393, -79.5106166),
+(43.561803, -79.7126716),
+(43.4723946, -79.6424145),
+(43.7946305, -79.5621036),
+(43.7349334, -79.8114351),
+(43.7128657, -79.3978466),
+(43.7677262, -79.3706232),
+(43.9073512, -78.9791956),
+(43.7656686, -79.4541121),
+(43.7578781, -79.3919283),
+(43.773403, -79.344636),
+(43.6416369, -79.4218439),
+(43.6791457, -79.4330807),
+(43.719556, -79.325035),
+(43.492368, -79.720492),
+(43.81302, -79.449356),
+(43.6908663, -79.313226),
+(43.8237802, -79.2739795),
+(43.765836, -79.4528339),
+(43.47314, -79.744806),
+(43.6955773, -79.5077266),
+(43.9544625, -78.8916365),
+(43.6396649, -79.4146468),
+(43.4303452, -79.7468787),
+(43.938239, -78.902324),
+(43.7031511, -79.3613153),
+(43.8870865, -79.2812014),
+(43.843516, -79.426394),
+(43.7221435, -79.7118946),
+(43.7234845, -79.3272775),
+(43.6616166, -79.3846797),
+(43.710933, -79.724816),
+(43.3586187, -79.814426),
+(43.6783178, -79.2838177),
+(43.708496, -79.397652),
+(43.9391477, -79.4337393),
+(43.6454752, -79.3923183),
+(43.7338129, -79.4894302),
+(43.7588298, -79.1866921),
+(43.68795, -79.522015),
+(43.5399296, -79.6014262),
+(43.7179609, -79.492099),
+(43.4871421, -79.8419504),
+(43.89835, -79.3088101),
+(43.851839, -79.238309),
+(43.446237, -79.707062),
+(43.9441043, -79.4631476),
+(43.760906, -79.4090028),
+(43.3696606, -79.8396965),
+(43.779869, -79.417504),
+(43.7659552, -79.4520233),
+(43.8617045, -79.2850377),
+(43.6740324, -79.3904727),
+(43.7797628, -79.3785799),
+(43.9458672, -79.8429777),
+(43.669963, -79.386158),
+(43.7642862, -79.3441063),
+(43.9210718, -78.8822166),
+(43.8560558, -79.4825661),
+(43.4754784, -79.7363569),
+(43.706209, -79.5184792),
+(43.647233, -79.4259973),
+(43.785895, -79.29186),
+(43.840865, -79.00503),
+(43.7904691, -79.4520964),
+(43.8570604, -79.4381827),
+(43.6488273, -79.3780964),
+(43.9115002, -79.547262),
+(43.648909, -79.386075),
+(43.6114984, -79.543595),
+(43.6694723, -79.3874877),
+(43.7297244, -79.4419558),
+(43.6422746, -79.3727039),
+(43.661781, -79.384218),
+(43.7094955, -79.4313478),
+(43.8426726, -79.564084),
+(43.8168498, -79.4667481),
+(43.6781049, -79.7387521),
+(43.6600002, -79.4517988),
+(43.9629448, -79.4516889),
+(43.65056, -79.382072),
+(43.8523481, -79.4716549),
+(43.7095895, -79.4157983),
+(43.680106, -79.842009),
+(43.6838126, -79.3563764),
+(43.5945878, -79.6347113),
+(43.7301714, -79.6346387),
+(43.5512031, -79.6315185),
+(43.808635, -79.224419),
+(43.5201515, -79.8440783),
+(43.705085, -79.521596),
+(43.485236, -79.854),
+(43.8989098, -79.2525422),
+(43.6389416, -79.5369791),
+(43.5449532, -79.7233935),
+(43.8910467, -79.4091586),
+(43.8910467, -79.4091586),
+(43.786298, -79.660137),
+(43.611472, -79.625317),
+(43.5915378, -79.6278223),
+(43.7255667, -79.7836398),
+(43.4104879, -79.6950112),
+(43.8922836, -78.9250066),
+(43.6548441, -79.3846797),
+(43.830091, -79.1247375),
+(43.744642, -79.212272),
+(43.6940389, -79.4351548),
+(43.7737399, -79.2555526),
+(43.707083, -79.397771),
+(43.7933162, -79.132904),
+(43.7499263, -79.2686309),
+(43.9380073, -79.4336295),
+(43.9242531, -79.5331078),
+(43.8880258, -79.4589),
+(43.646393, -79.7570932),
+(43.6861558, -79.3136823),
+(43.915091, -79.4346049),
+(43.9683533, -79.2491431),
+(43.8297502, -79.395146),
+(43.5629705, -79.68867),
+(43.8221148, -79.2029282),
+(43.943448, -78.890674),
+(43.502189, -79.885311),
+(43.652382, -79.5175744),
+(43.586289, -79.647364),
+(43.594996, -79.535349),
+(43.7410507, -79.5923462),
+(43.606531, -79.7274832),
+(43.475132, -79.74083),
+(43.62372, -79.483703),
+(43.7182734, -79.6861973),
+(43.62497, -79.478079),
+(43.6161559, -79.5966193),
+(43.519422, -79.835884),
+(43.3974952, -79.7066576),
+(43.6383649, -79.4145613),
+(43.6489, -79.392205),
+(43.6760539, -79.3995695),
+(43.7777587, -79.2989658),
+(43.853305, -79.030887),
+(43.6747371, -79.3720025),
+(43.708965, -79.392985),
+(43.6566436, -79.3547917),
+(43.7889457, -79.4293327),
+(43.716146, -79.2344158),
+(43.9067193, -79.2709537),
+(43.842016, -79.477483),
+(43.8961484, -79.2873852),
+(43.881403, -79.219835),
+(43.882618, -79.223097),
+(43.566812, -79.7606),
+(43.6911885, -79.8321107),
+(43.5970213, -79.5346155),
+(43.4278415, -79.7338826),
+(43.7328545, -79.7322456),
+(43.6898587, -79.8382839),
+(43.7002459, -79.5477366),
+(43.8165308, -79.6237981),
+(43.6765095, -79.3081056),
+(43.761404, -79.565788),
+(43.852415, -79.032168),
+(43.711587, -79.395391),
+(43.7959687, -79.2858693),
+(43.7782404, -79.4177748),
+(43.768151, -79.390791),
+(43.860334, -79.230731),
+(43.8512666, -79.4054375),
+(43.7621676, -79.8298523),
+(43.854881, -79.2237856),
+(43.64787, -79.391138),
+(43.4178717, -79.6940162),
+(43.6970211, -79.7935527),
+(43.5473433, -79.5918669),
+(43.882039, -79.747343),
+(43.648783, -79.388042),
+(43.8215789, -79.3621924),
+(43.808273, -79.1724617),
+(43.669963, -79.386158),
+(43.761635, -79.4231011),
+(43.672167, -79.379876),
+(43.7263649, -79.3279802),
+(43.6701828, -79.5009914),
+(43.7078442, -79.2506657),
+(43.7799579, -79.3446599),
+(43.9260998, -79.5099032),
+(43.7210094, -79.760753),
+(43.8729304, -79.4782008),
+(43.6676474, -79.3120252),
+(43.959709, -79.481286),
+(43.6238205, -79.480348),
+(43.7755305, -79.4152516),
+(43.7818804, -79.246391),
+(43.809312, -79.6128018),
+(43.6495461, -79.4444488),
+(43.773748, -79.415509),
+(43.6211459, -79.5215694),
+(43.9723967, -79.2630592),
+(43.62526, -79.477903),
+(43.853874, -79.02991),
+(43.4048134, -79.8179446),
+(43.4551319, -79.6979344),
+(43.8714839, -79.3946876),
+(43.6460991, -79.3651362),
+(43.72709, -79.772438),
+(43.6695584, -79.3846569),
+(43.780853, -79.4144717),
+(43.775424, -79.337482),
+(43.62739, -79.4784173),
+(43.665412, -79.3871187),
+(43.6391434, -79.4123994),
+(43.6306421, -79.4921997),
+(43.6400003, -79.3788904),
+(43.8661599, -79.4102225),
+(43.9082825, -78.9213946),
+(43.6547114, -79.3792073),
+(43.944341, -79.476321),
+(43.9389975, -78.8861605),
+(43.855423, -79.018045),
+(43.5712501, -79.5807684),
+(43.7563012, -79.4083836),
+(43.889638, -79.420645),
+(43.6921033, -79.2832796),
+(43.958893, -78.907369),
+(43.6667472, -79.3733325),
+(43.6502225, -79.357641),
+(43.958288, -78.896484),
+(43.8726785, -79.4334238),
+(43.8748381, -79.4512557),
+(43.8049979, -79.4053482),
+(43.7201221, -79.2366987),
+(43.7213327, -79.2345678),
+(43.7095303, -79.3982269),
+(43.3885446, -79.715935),
+(43.8612759, -79.3614984),
+(43.6457578, -79.3946255),
+(43.7490152, -79.406243),
+(43.8402851, -79.3945718),
+(43.716527, -79.284452),
+(43.851885, -79.336294),
+(43.648799, -79.392756),
+(43.3498134, -79.8830594),
+(43.4519634, -79.6644954),
+(43.5934007, -79.5246576),
+(43.6391101, -79.4725886),
+(43.9577283, -78.9068226),
+(43.4965308, -79.8794142),
+(43.6238205, -79.480348),
+(43.5883573, -79.6395201),
+(43.7863744, -79.6183232),
+(43.6402683, -79.3960141),
+(43.6607839, -79.5475928),
+(43.69037, -79.2628896),
+(43.5870065, -79.6405748),
+(43.8547091, -79.2410847),
+(43.551698, -79.745589),
+(43.866093, -79.439199),
+(43.6430971, -79.3916393),
+(43.7694183, -79.3686931),
+(43.689685, -79.385788),
+(43.7778723, -79.2807506),
+(43.779819, -79.282864),
+(43.665388, -79.3880211),
+(43.7948843, -79.3520093),
+(43.758071, -79.403165),
+(43.6714964, -79.398267),
+(43.4271718, -79.6978166),
+(43.6405065, -79.3969011),
+(43.708965, -79.392985),
+(43.8958503, -79.3008372),
+(43.656092, -79.519717),
+(43.8983475, -79.2483376),
+(43.7879166, -79.3665002),
+(43.4558798, -79.6580495),
+(43.7764673, -79.3860878),
+(43.7694275, -79.7143381),
+(43.948882, -78.9224271),
+(43.6031312, -79.6512623),
+(43.625807, -79.477924),
+(43.7383272, -79.3611139),
+(43.7934558, -79.5994975),
+(43.8582831, -79.5366678),
+(43.849006, -79.065148),
+(43.8587649, -79.5039619),
+(43.4034476, -79.784471),
+(43.9177225, -79.5366086),
+(43.4609721, -79.6522671),
+(43.8954974, -79.0260234),
+(43.647089, -79.4073793),
+(43.725896, -79.794904),
+(43.5841974, -79.6485124),
+(43.758968, -79.575423),
+(43.6668981, -79.3890419),
+(43.7440078, -79.7758282),
+(43.687188, -79.421474),
+(43.4898745, -79.8521897),
+(43.7613741, -79.8223259),
+(43.8166371, -79.4331464),
+(43.8593745, -79.468045),
+(43.7040839, -79.8276331),
+(43.7130154, -79.7035255),
+(43.4192811, -79.7056896),
+(43.779725, -79.414445),
+(43.8760364, -79.4212939),
+(43.9724669, -78.9494952),
+(43.7756387, -79.2253082),
+(43.8707844, -79.0456068),
+(43.7774165, -79.2812115),
+(43.7774165, -79.2812115),
+(43.648799, -79.392756),
+(43.648431, -79.5055243),
+(43.62372, -79.483703),
+(43.8604386, -79.4079874),
+(43.4458342, -79.6830585),
+(43.7362224, -79.3530965),
+(43.6909576, -79.2672062),
+(43.8540396, -79.1061409),
+(43.5432078, -79.6696197),
+(43.6095757, -79.5573689),
+(43.8961192, -79.4756311),
+(43.7353537, -79.4018414),
+(43.5596224, -79.572358),
+(43.6592713, -79.3849163),
+(43.5012109, -79.6949478),
+(43.6055166, -79.7245656),
+(43.819888, -79.607724),
+(43.7985555, -79.15389),
+(43.5743148, -79.586985),
+(43.6714433, -79.3394721),
+(43.828581, -79.088266),
+(43.7685605, -79.1917362),
+(43.6490534, -79.3861546),
+(43.7738036, -79.4155869),
+(43.7292339, -79.3353471),
+(43.850576, -79.067479),
+(43.7553234, -79.385214),
+(43.6962745, -79.2927586),
+(43.7614092, -79.4098055),
+(43.858207, -79.5550962),
+(43.778615, -79.452044),
+(43.9458705, -79.465713),
+(43.6767474, -79.7335639),
+(43.967532, -78.943233),
+(43.946958, -79.847105),
+(43.8042371, -79.4497063),
+(43.803377, -79.8255742),
+(43.5686417, -79.577915),
+(43.799864, -79.626063),
+(43.484699, -79.8477654),
+(43.7762719, -79.3865816),
+(43.69626, -79.507042),
+(43.9557024, -78.9120583),
+(43.7725122, -79.4203562),
+(43.643188, -79.422494),
+(43.9551348, -79.4778914),
+(43.945329, -79.4476192),
+(43.8488041, -79.4399474),
+(43.8850044, -79.3550465),
+(43.8007653, -79.4001525),
+(43.5907831, -79.5613018),
+(43.716003, -79.7695462),
+(43.9114484, -78.915559),
+(43.8410876, -79.405166),
+(43.4022375, -79.8145448),
+(43.6948266, -79.401871),
+(43.944448, -79.481679),
+(43.6398049, -79.3979687),
+(43.811433, -79.2781663),
+(43.883273, -79.006549),
+(43.73377, -79.534489),
+(43.678272, -79.843341),
+(43.668564, -79.395458),
+(43.4401378, -79.7453005),
+(43.6868495, -79.4598939),
+(43.6704858, -79.5212189),
+(43.779165, -79.3993718),
+(43.4815957, -79.7375738),
+(43.4725795, -79.7338914),
+(43.7023707, -79.8526091),
+(43.426156, -79.7757807),
+(43.6865262, -79.7430347),
+(43.8496133, -79.4401078),
+(43.526144, -79.849478),
+(43.665753, -79.385579),
+(43.8327152, -79.5634647),
+(43.6591011, -79.7822509),
+(43.8902513, -79.3326019),
+(43.8866606, -79.3558004),
+(43.8943157, -79.4077518),
+(43.647825, -79.385742),
+(43.9281964, -79.5161267),
+(43.6385725, -79.7918624),
+(43.6967506, -79.3409178),
+(43.447443, -79.686948),
+(43.8833107, -78.942445),
+(43.8661927, -79.8736221),
+(43.9047326, -79.6507906),
+(43.65318, -79.379908),
+(43.861226, -79.4627258),
+(43.9697312, -79.2394969),
+(43.7023882, -79.8189282),
+(43.7365216, -79.2065556),
+(43.575018, -79.571004),
+(43.384591, -79.7288737),
+(43.6034303, -79.7035915),
+(43.6517827, -79.7654179),
+(43.836911, -79.439786),
+(43.8817649, -79.247829),
+(43.4924057, -79.7203229),
+(43.705222, -79.758983),
+(43.5583794, -79.6952787),
+(43.9784802, -79.4457547),
+(43.6670963, -79.7712001),
+(43.786218, -79.6067422),
+(43.8953173, -79.2532904),
+(43.9795222, -79.2269775),
+(43.7135797, -79.303446),
+(43.8119891, -79.4362761),
+(43.7286088, -79.3261749),
+(43.7044863, -79.4024379),
+(43.691194, -79.293857),
+(43.7589383, -79.1799599),
+(43.8334179, -79.5798292),
+(43.7467074, -79.3056876),
+(43.6706915, -79.3148122),
+(43.795696, -79.520318),
+(43.7569815, -79.4044001),
+(43.7495294, -79.5807822),
+(43.667162, -79.3735007),
+(43.6422746, -79.3727039),
+(43.6406816, -79.376999),
+(43.765056, -79.195785),
+(43.832529, -79.647586),
+(43.8125907, -79.3227587),
+(43.8631295, -79.2400461),
+(43.7634454, -79.4493141),
+(43.8524965, -79.4239253),
+(43.774325, -79.346091),
+(43.687702, -79.396783),
+(43.8739359, -79.4460969),
+(43.7063711, -79.2428116),
+(43.8849383, -79.4211929),
+(43.7742413, -79.3461881),
+(43.937995, -79.4454147),
+(43.8846267, -79.2586349),
+(43.7807555, -79.4184006),
+(43.644128, -79.389406),
+(43.8979874, -79.4621808),
+(43.4716369, -79.6838096),
+(43.7031923, -79.4309344),
+(43.8069459, -79.1015633),
+(43.7100327, -79.249325),
+(43.7477956, -79.3867198),
+(43.3461435, -79.7613687),
+(43.7628186, -79.4980299),
+(43.698853, -79.2604175),
+(43.7765064, -79.733551),
+(43.782933, -79.170682),
+(43.688375, -79.3204011),
+(43.85778, -78.945192),
+(43.7333596, -79.2296163),
+(43.8617175, -79.4355742),
+(43.8731977, -79.2469285),
+(43.8125538, -79.4529616),
+(43.943448, -78.890674),
+(43.8100647, -79.4487117),
+(43.8503461, -79.4300813),
+(43.5638574, -79.7394791),
+(43.8681988, -79.2227556),
+(43.6998655, -79.8553533),
+(43.6031312, -79.6512623),
+(43.7767311, -79.4212659),
+(43.6388334, -79.3988388),
+(43.883566, -78.93583),
+(43.665388, -79.3880211),
+(43.7254565, -79.4071448),
+(43.9810833, -79.233886),
+(43.6884978, -79.4146412),
+(43.6700844, -79.3805554),
+(43.7598991, -79.3102207),
+(43.5950234, -79.6706839),
+(43.9156342, -79.4730653),
+(43.8464647, -79.1101887),
+(43.7499321, -79.2050033),
+(43.8677496, -79.4393146),
+(43.680803, -79.842706),
+(43.5552299, -79.6248383),
+(43.6700845, -79.3864005),
+(43.8253374, -79.4289198),
+(43.6307453, -79.7965763),
+(43.866991, -79.43314),
+(43.6952604, -79.2765134),
+(43.798312, -79.117012),
+(43.6912472, -79.4360406),
+(43.6687309, -79.389488),
+(43.6601105, -79.3605898),
+(43.9175762, -79.4305317),
+(43.8858906, -79.4980818),
+(43.8414667, -79.4504609),
+(43.5816498, -79.6480951),
+(43.7022865, -79.4420585),
+(43.5765598, -79.5885163),
+(43.5795671, -79.6577022),
+(43.4010318, -79.7003312),
+(43.5802294, -79.7882491),
+(43.650405, -79.3921684),
+(43.9256623, -79.5097699),
+(43.877593, -79.233618),
+(43.6620369, -79.3865221),
+(43.6440617, -79.3894386),
+(43.6975455, -79.5297098),
+(43.6448836, -79.3920974),
+(43.6881075, -79.3979987),
+(43.902443, -79.2516395),
+(43.889023, -79.732447),
+(43.6729857, -79.3870289),
+(43.481539, -79.876132),
+(43.9146218, -78.9484569),
+(43.646722, -79.854505),
+(43.8228197, -79.4278429),
+(43.715789, -79.285703),
+(43.8770702, -79.3404076),
+(43.7893004, -79.3893504),
+(43.7224402, -79.4255975),
+(43.7672146, -79.3817072),
+(43.791956, -79.392834),
+(43.8106398, -79.4662628),
+(43.828236, -79.4301893),
+(43.9153755, -79.4466158),
+(43.8536872, -79.4077619),
+(43.9268314, -79.4544533),
+(43.6238205, -79.480348),
+(43.603047, -79.6940698),
+(43.7188195, -79.7994711),
+(43.5894512, -79.7185292),
+(43.56562, -79.7186931),
+(43.7166276, -79.7977073),
+(43.817544, -79.080003),
+(43.9533979, -78.9696736),
+(43.8174537, -79.4133754),
+(43.7748866, -79.3354382),
+(43.5545758, -79.6446842),
+(43.7238562, -79.4275872),
+(43.8581003, -79.2367871),
+(43.7128295, -79.7638633),
+(43.7425322, -79.8182655),
+(43.4426762, -79.8946101),
+(43.922937, -79.5226282),
+(43.726578, -79.398866),
+(43.6383599, -79.398657),
+(43.848756, -79.012986),
+(43.8990735, -79.4519067),
+(43.6585543, -79.3526851),
+(43.7554201, -79.426668),
+(43.659235, -79.363475),
+(43.7263649, -79.3279802),
+(43.660292, -79.360577),
+(43.7075225, -79.392402),
+(43.7782404, -79.4177748),
+(43.7665436, -79.3897946),
+(43.9573033, -78.9064849),
+(43.6568586, -79.3836642),
+(43.6746538, -79.3890069),
+(43.6712941, -79.384925),
+(43.6458128, -79.7308456),
+(43.7098809, -79.784904),
+(43.4296769, -79.7557333),
+(43.6689196, -79.3905139),
+(43.7714841, -79.6899748),
+(43.4818465, -79.6772506),
+(43.7177352, -79.7124202),
+(43.766028, -79.1979422),
+(43.4778553, -79.8716321),
+(43.9686512, -79.2734911),
+(43.4035156, -79.8159985),
+(43.7474184, -79.7659525),
+(43.8105317, -79.4050189),
+(43.7494904, -79.2509099),
+(43.668578, -79.384479),
+(43.9240394, -79.5519944),
+(43.9406739, -79.4623116),
+(43.8474026, -79.1006083),
+(43.6611024, -79.3819277),
+(43.67522, -79.430649),
+(43.6491763, -79.3770958),
+(43.6422746, -79.3727039),
+(43.7641848, -79.4105428),
+(43.647282, -79.3974191),
+(43.8152962, -79.6099409),
+(43.8205905, -79.4237985),
+(43.646099, -79.365136),
+(43.7763249, -79.4140326),
+(43.646099, -79.365136),
+(43.8664336, -79.4355835),
+(43.4793057, -79.7216264),
+(43.661781, -79.384218),
+(43.6484128, -79.3764665),
+(43.649381, -79.374532),
+(43.778008, -79.4527173),
+(43.555075, -79.583315),
+(43.595271, -79.682353),
+(43.807673, -79.442576),
+(43.4878826, -79.8606551),
+(43.480198, -79.720663),
+(43.6485138, -79.7687746),
+(43.842785, -79.425917),
+(43.646099, -79.365136),
+(43.8038883, -79.3832161),
+(43.613136, -79.6607762),
+(43.7758348, -79.4132441),
+(43.7674139, -79.4112489),
+(43.6601105, -79.3605898),
+(43.377748, -79.802927),
+(43.6695417, -79.3837883),
+(43.5947036, -79.6355027),
+(43.7740759, -79.2502014),
+(43.62497, -79.478079),
+(43.8420482, -79.0172914),
+(43.7758348, -79.4132441),
+(43.570413, -79.613315),
+(43.7681761, -79.4117756),
+(43.7205516, -79.4209382),
+(43.7921855, -79.4119602),
+(43.711278, -79.322834),
+(43.762714, -79.346479),
+(43.785644, -79.591696),
+(43.8312253, -79.1250199),
+(43.8584331, -79.436549),
+(43.657856, -79.460183),
+(43.4834219, -79.7032644),
+(43.6382499, -79.5362492),
+(43.602134, -79.70231),
+(43.3811307, -79.7306522),
+(43.8712393, -79.3270278),
+(43.5816498, -79.6480951),
+(43.6190815, -79.5957831),
+(43.866321, -79.435621),
+(43.8031867, -79.4131592),
+(43.773403, -79.344636),
+(43.775123, -79.345456),
+(43.7569815, -79.4044001),
+(43.9213277, -79.417672),
+(43.8737359, -79.4155305),
+(43.5881354, -79.5502257),
+(43.7762379, -79.6773919),
+(43.7591503, -79.8316549),
+(43.791931, -79.2468595),
+(43.6620369, -79.3865221),
+(43.915544, -79.2693),
+(43.7072301, -79.2923194),
+(43.649428, -79.388411),
+(43.7292605, -79.3281088),
+(43.7676872, -79.1761135),
+(43.7899404, -79.4437575),
+(43.785172, -79.184825),
+(43.8225351, -79.6235144),
+(43.659454, -79.382587),
+(43.8227398, -79.0606248),
+(43.944203, -79.4620543),
+(43.7171145, -79.7109485),
+(43.6782226, -79.7834844),
+(43.6753252, -79.3781816),
+(43.7669362, -79.7439437),
+(43.7358712, -79.4655811),
+(43.7222396, -79.2579941),
+(43.9605986, -79.4418182),
+(43.8055517, -79.7271429),
+(43.7542018, -79.7403827),
+(43.7268296, -79.4330171),
+(43.6440617, -79.3894386),
+(43.9438548, -79.4308717),
+(43.5044388, -79.8471823),
+(43.9331306, -79.5265822),
+(43.4013954, -79.7122033),
+(43.7751229, -79.4138309),
+(43.858252, -79.433621),
+(43.8230999, -79.4197745),
+(43.648909, -79.386075),
+(43.733011, -79.4028757),
+(43.7056504, -79.3973799),
+(43.6501401, -79.3940135),
+(43.65056, -79.382072),
+(43.9287897, -79.5808964),
+(43.8425041, -79.6540219),
+(43.8750538, -79.2305364),
+(43.572777, -79.843559),
+(43.573017, -79.6854564),
+(43.838005, -79.923217),
+(43.520327, -79.881249),
+(43.7906301, -79.399883),
+(43.779705, -79.3738975),
+(43.8151123, -79.2888675),
+(43.7877861, -79.2624278),
+(43.6959079, -79.8445469),
+(43.768151, -79.390791),
+(43.3894114, -79.8176828),
+(43.7553562, -79.3952357),
+(43.703773, -79.296443),
+(43.6593726, -79.3825439),
+(43.684863, -79.853331),
+(43.814801, -79.4227938),
+(43.8304275, -79.6263739),
+(43.5686664, -79.6112569),
+(43.3485885, -79.7643157),
+(43.6211556, -79.5215447),
+(43.447607, -79.752934),
+(43.7703, -79.413645),
+(43.833016, -79.143466),
+(43.64787, -79.391138),
+(43.840373, -79.550086),
+(43.85195, -79.431773),
+(43.7822326, -79.4002037),
+(43.7818804, -79.246391),
+(43.773403, -79.344636),
+(43.790317, -79.357381),
+(43.7256183, -79.2265603),
+(43.6624074, -79.3858983),
+(43.7891253, -79.2904069),
+(43.7998466, -79.2029062),
+(43.8654223, -79.4832873),
+(43.8633063, -79.3384406),
+(43.6676702, -79.3864205),
+(43.7914091, -79.2366785),
+(43.7663179, -79.333577),
+(43.940598, -79.297171),
+(43.5171414, -79.6399208),
+(43.713934, -79.7863775),
+(43.7714653, -79.7079148),
+(43.8420436, -79.4242694),
+(43.8559417, -79.700697),
+(43.622729, -79.48319),
+(43.8225179, -79.3985646),
+(43.5003628, -79.8629605),
+(43.7107418, -79.3337304),
+(43.8992987, -79.4415962),
+(43.6079209, -79.7114006),
+(43.77807, -79.4158254),
+(43.895037, -79.2998845),
+(43.724082, -79.824816),
+(43.7612263, -79.2332281),
+(43.8144837, -79.325326),
+(43.7602436, -79.4083424),
+(43.714021, -79.521728),
+(43.7653413, -79.3825659),
+(43.7338186, -79.346789),
+(43.67032, -79.394433),
+(43.66955, -79.383776),
+(43.7168353, -79.2379713),
+(43.7252353, -79.6011482),
+(43.6854499, -79.2791052),
+(43.7778723, -79.2807506),
+(43.773403, -79.344636),
+(43.6694723, -79.3874877),
+(43.6383872, -79.4010639),
+(43.6400086, -79.3820669),
+(43.761461, -79.409802),
+(43.6402781, -79.5597448),
+(43.6959554, -79.3957179),
+(43.678003, -79.812423),
+(43.8932192, -79.3988748),
+(43.3855962, -79.7237481),
+(43.6546512, -79.4145399),
+(43.7815006, -79.4130543),
+(43.6238205, -79.480348),
+(43.654449, -79.4680817),
+(43.7239017, -79.4445614),
+(43.888592, -79.370934),
+(43.5103289, -79.8784555),
+(43.4740972, -79.7043777),
+(43.838013, -79.2939926),
+(43.8891139, -79.2444514),
+(43.7592761, -79.4296378),
+(43.5079699, -79.8818944),
+(43.6744722, -79.2906004),
+(43.6440617, -79.3894386),
+(43.7581405, -79.4156592),
+(43.6489, -79.392205),
+(43.7832044, -79.5795291),
+(43.9779694, -79.233321),
+(43.9235433, -79.5368216),
+(43.8383472, -79.460143),
+(43.7127711, -79.293179),
+(43.7169065, -79.4205894),
+(43.7439122, -79.3881707),
+(43.8148107, -79.4033069),
+(43.5835526, -79.6427658),
+(43.4118949, -79.8012194),
+(43.8664336, -79.4355835),
+(43.5876825, -79.6283005),
+(43.7756343, -79.3797485),
+(43.5140979, -79.9237031),
+(43.7002667, -79.4111944),
+(43.4195862, -79.7048897),
+(43.7752003, -79.412361),
+(43.6891499, -79.504069),
+(43.7710844, -79.4315478),
+(43.8516642, -79.6302598),
+(43.8303899, -79.7426184),
+(43.8726, -79.4159142),
+(43.9662688, -79.1363057),
+(43.7891876, -79.4369176),
+(43.6013164, -79.6101786),
+(43.7859764, -79.1975731),
+(43.6729857, -79.3870289),
+(43.643051, -79.391643),
+(43.8182881, -79.3616469),
+(43.7215339, -79.3907323),
+(43.92585, -79.5109644),
+(43.9006918, -79.4242651),
+(43.350917, -79.816293),
+(43.851159, -79.272708),
+(43.6127481, -79.7136626),
+(43.9716099, -79.2647942),
+(43.6394336, -79.4467459),
+(43.8665065, -79.3164128),
+(43.766849, -79.711613),
+(43.970267, -79.263706),
+(43.679602, -79.44097),
+(43.714141, -79.450545),
+(43.930591, -79.5315375),
+(43.4315725, -79.6897186),
+(43.970267, -79.263706),
+(43.7255458, -79.2517641),
+(43.6274692, -79.4771277),
+(43.968412, -79.379601),
+(43.648858, -79.378145),
+(43.7075225, -79.392402),
+(43.6969479, -79.4430962),
+(43.743751, -79.213172),
+(43.930412, -79.5215418),
+(43.968721, -78.884111),
+(43.4024206, -79.7824283),
+(43.8523245, -79.4984189),
+(43.6399005, -79.3905103),
+(43.7762128, -79.3059668),
+(43.778017, -79.416628),
+(43.6878988, -79.3890855),
+(43.6548441, -79.3846797),
+(43.6425743, -79.4964428),
+(43.7655684, -79.5035845),
+(43.622729, -79.48319),
+(43.6500154, -79.3873578),
+(43.760304, -79.41162),
+(43.7681003, -79.4406234),
+(43.8544291, -79.4663132),
+(43.4474464, -79.6816398),
+(43.7525066, -79.3766601),
+(43.6500154, -79.3873578),
+(43.766616, -79.382896),
+(43.6404408, -79.3971889),
+(43.6428617, -79.3769141),
+(43.9725627, -78.9471389),
+(43.532913, -79.712033),
+(43.7432633, -79.7484377),
+(43.6975467, -79.794611),
+(43.706188, -79.4473279),
+(43.785023, -79.436435),
+(43.6806534, -79.4698655),
+(43.4716447, -79.7420266),
+(43.8567081, -79.8498549),
+(43.853478, -79.336884),
+(43.648909, -79.386075),
+(43.7144036, -79.8329654),
+(43.6671576, -79.7711328),
+(43.730531, -79.5219),
+(43.4473961, -79.686806),
+(43.713654, -79.411549),
+(43.7228912, -79.245434),
+(43.6693009, -79.3841709),
+(43.4449206, -79.7171516),
+(43.6762844, -79.8266674),
+(43.5651748, -79.6936266),
+(43.669301, -79.384171),
+(43.6768454, -79.3984501),
+(43.7558985, -79.8170979),
+(43.8771773, -79.41811),
+(43.6401441, -79.4239168),
+(43.7502599, -79.4059172),
+(43.6393941, -79.3997862),
+(43.687702, -79.396783),
+(43.7359826, -79.4288125),
+(43.758071, -79.403165),
+(43.764029, -79.407042),
+(43.652136, -79.371483),
+(43.667211, -79.389153),
+(43.839437, -79.393276),
+(43.6687952, -79.3839693),
+(43.8612283, -79.4057008),
+(43.8178228, -79.2173875),
+(43.667222, -79.3839697),
+(43.6419687, -79.4129266),
+(43.8807657, -79.3100651),
+(43.87629, -79.2384109),
+(43.5874595, -79.6072369),
+(43.9008447, -79.2561593),
+(43.7897579, -79.4698761),
+(43.7098418, -79.396432),
+(43.7055628, -79.3696034),
+(43.8645952, -79.3984326),
+(43.6631984, -79.5178465),
+(43.4976933, -79.7200237),
+(43.5336622, -79.6791416),
+(43.3949227, -79.8213628),
+(43.5928683, -79.6725099),
+(43.5967122, -79.6842542),
+(43.646793, -79.888187),
+(43.794967, -79.353066),
+(43.7180212, -79.3768816),
+(43.799471, -79.356126),
+(43.665666, -79.382607),
+(43.769664, -79.413783),
+(43.9051099, -79.4572463),
+(43.8268388, -79.2786357),
+(43.7724766, -79.1623277),
+(43.943448, -78.890674),
+(43.7801947, -79.4046738),
+(43.8229556, -79.291245),
+(43.85102, -79.427588),
+(43.8981671, -79.2683667),
+(43.8664512, -79.214936),
+(43.7168184, -79.7634558),
+(43.8129727, -79.4551834),
+(43.8885315, -79.2248881),
+(43.5198033, -79.866139),
+(43.7909568, -79.755841),
+(43.4983392, -79.8802732),
+(43.7066822, -79.4549232),
+(43.6840483, -79.4487812),
+(43.8541173, -79.3409476),
+(43.5629331, -79.59104),
+(43.680164, -79.493144),
+(43.5584691, -79.7263706),
+(43.971766, -79.805052),
+(43.5872308, -79.639211),
+(43.5709108, -79.7206605),
+(43.5152259, -79.8834926),
+(43.693954, -79.7934152),
+(43.8659, -79.4342),
+(43.6514, -79.9219),
+(43.7625, -79.1981),
+(43.928, -79.2581),
+(43.5893, -79.6102),
+(43.6501, -79.9175),
+(43.6501, -79.9175),
+(43.8227779, -79.3925602),
+(43.6804, -79.7693),
+(43.7809647, -79.249788),
+(43.6514, -79.3826),
+(43.8825065, -79.4653856),
+(43.5795444, -79.6054876),
+(43.6585, -79.4334),
+(43.8663974, -79.6212485),
+(43.9601105, -78.9092253),
+(43.7904047, -79.6014363),
+(43.9072174, -79.3949387),
+(43.9558868, -78.905255),
+(43.8539543, -79.7000065),
+(43.8164, -79.3682),
+(43.7386, -79.8437),
+(43.9049212, -79.3883531),
+(43.9136545, -78.9756982),
+(43.4285, -79.7445),
+(43.6791, -79.2932),
+(43.4066073, -79.6944626),
+(43.732244, -79.774479),
+(43.967819, -79.2736409),
+(43.7812996, -79.3813081),
+(43.577389, -79.716675),
+(43.7075225, -79.392402),
+(43.9437005, -79.4630177),
+(43.861078, -79.067189),
+(43.8833844, -78.8849623),
+(43.8463133, -79.443207),
+(43.8373061, -79.2672144),
+(43.7671267, -79.5016271),
+(43.757354, -79.409358),
+(43.8822936, -79.2250367),
+(43.6401441, -79.4239168),
+(43.5833367, -79.6054253),
+(43.7951922, -79.3243815),
+(43.7504859, -79.3776325),
+(43.422211, -79.769667),
+(43.669963, -79.386158),
+(43.6420815, -79.3828097),
+(43.8091745, -79.4467726),
+(43.964452, -79.4463189),
+(43.702046, -79.2884338),
+(43.8790775, -79.4393859),
+(43.523009, -79.859479),
+(43.7127481, -79.5013109),
+(43.8974694, -79.468861),
+(43.569806, -79.58588),
+(43.7551609, -79.3992225),
+(43.8509449, -79.4316332),
+(43.774325, -79.346091),
+(43.6812125, -79.3022548),
+(43.7970188, -79.3721457),
+(43.642923, -79.526725),
+(43.7980539, -79.1191315),
+(43.8327258, -79.5772837),
+(43.8117, -79.6306),
+(43.882495, -79.4658028),
+(43.8938061, -79.6562835),
+(43.9647347, -78.8913165),
+(43.4736095, -79.7433517),
+(43.8659, -79.4342),
+(43.6241341, -79.4858146),
+(43.7529797, -79.8414843),
+(43.8707933, -79.4131122),
+(43.8798018, -79.8623473),
+(43.7907121, -79.600707),
+(43.8602061, -79.3244441),
+(43.6503, -79.9216),
+(43.8949586, -79.6532038),
+(43.5029802, -79.6568196),
+(43.8793208, -79.0024538),
+(43.6519615, -79.8148426),
+(43.7418, -79.4709),
+(43.8368038, -79.6607636),
+(43.7358463, -79.772516),
+(43.8714467, -79.2188914),
+(43.4854, -79.6988),
+(43.8518, -79.3887),
+(43.3478, -79.7806),
+(43.9570875, -78.9023788),
+(43.3635368, -79.775045),
+(43.7886, -79.3461),
+(43.9562204, -78.901339),
+(43.6085, -79.5066),
+(43.4837652, -79.7316463),
+(43.8580177, -79.702749),
+(43.7491456, -79.3656056),
+(43.6539796, -79.3885197),
+(43.645505, -79.392457),
+(43.7753439, -79.4315887),
+(43.6468455, -79.4028294),
+(43.669963, -79.386158),
+(43.6569646, -79.4465224),
+(43.6729857, -79.3870289),
+(43.6765411, -79.4056079),
+(43.9567061, -78.8933103),
+(43.7237984, -79.2700999),
+(43.8202435, -79.2856927),
+(43.7693821, -79.235599),
+(43.657018, -79.381197),
+(43.7805353, -79.2510063),
+(43.6367266, -79.54671),
+(43.609209, -79.5583318),
+(43.5300299, -79.6364823),
+(43.394839, -79.7910592),
+(43.6083463, -79.6466053),
+(43.6333917, -79.4977992),
+(43.6296617, -79.7983349),
+(43.682004, -79.843868),
+(43.7004891, -79.2759524),
+(43.738367, -79.816368),
+(43.826005, -79.012398),
+(43.8101834, -79.1302485),
+(43.8559242, -79.2926949),
+(43.6542625, -79.7741758),
+(43.7008176, -79.528138),
+(43.7705121, -79.7523799),
+(43.6996878, -79.4441283),
+(43.88644, -79.451436),
+(43.7686951, -79.3891863),
+(43.7901767, -79.4495948),
+(43.7652017, -79.3832771),
+(43.7097312, -79.428317),
+(43.873512, -78.9316885),
+(43.6459582, -79.3898731),
+(43.9613539, -78.9584243),
+(43.790419, -79.6008927),
+(43.5614572, -79.636797),
+(43.786522, -79.2938619),
+(43.5974245, -79.6298902),
+(43.7235438, -79.4201971),
+(43.843801, -79.566206),
+(43.6966073, -79.2942117),
+(43.7327325, -79.7337124),
+(43.8547752, -79.4213714),
+(43.7512065, -79.5828607),
+(43.6239417, -79.4884467),
+(43.561597, -79.712093),
+(43.7247033, -79.307678),
+(43.9156614, -78.9769092),
+(43.897883, -79.46613),
+(43.65056, -79.382072),
+(43.783388, -79.265134),
+(43.8968119, -79.3969637),
+(43.7262822, -79.2279083),
+(43.5950726, -79.6198339),
+(43.642862, -79.527224),
+(43.7618184, -79.4047473),
+(43.5835526, -79.6427658),
+(43.849459, -79.499082),
+(43.3597715, -79.8483762),
+(43.8991266, -79.432712),
+(43.6415894, -79.4157619),
+(43.7958912, -79.4271615),
+(43.7444315, -79.7557979),
+(43.6561334, -79.5712675),
+(43.628143, -79.941092),
+(43.6813756, -79.4484652),
+(43.6374676, -79.4184499),
+(43.6568586, -79.3836642),
+(43.8932707, -79.2463205),
+(43.649256, -79.375765),
+(43.8240908, -79.4079722),
+(43.6520694, -79.4719924),
+(43.4378413, -79.765495),
+(43.7376671, -79.2695674),
+(43.8793, -79.497),
+(43.8714635, -79.2219475),
+(43.3598653, -79.8113489),
+(43.8463945, -78.9980247),
+(43.6627991, -79.4706377),
+(43.8906698, -79.4570063),
+(43.3830851, -79.8106693),
+(43.7809498, -79.2606465),
+(43.79291, -79.413572),
+(43.8827846, -78.9176518),
+(43.4670299, -79.6922162),
+(43.624075, -79.484917),
+(43.630517, -79.490656),
+(43.7527274, -79.2558541),
+(43.7690784, -79.3694836),
+(43.645964, -79.399395),
+(43.8849551, -79.3033126),
+(43.7311454, -79.4300107),
+(43.769664, -79.413783),
+(43.7690784, -79.3694836),
+(43.889186, -78.941875),
+(43.7274086, -79.5218604),
+(43.7619854, -79.7717269),
+(43.5880851, -79.7579255),
+(43.7541529, -79.4035081),
+(43.7368618, -79.4760342),
+(43.5946806, -79.7831711),
+(43.937316, -79.4436585),
+(43.6676171, -79.8054392),
+(43.692343, -79.8468098),
+(43.8274988, -79.3718821),
+(43.5825096, -79.6830087),
+(43.7804397, -79.6674895),
+(43.5396658, -79.7340621),
+(43.3460466, -79.7876236),
+(43.7242174, -79.7162623),
+(43.8837922, -79.4001349),
+(43.6989963, -79.3815402),
+(43.7211998, -79.4585418),
+(43.6697352, -79.3176193),
+(43.7262971, -79.7803852),
+(43.628256, -79.894292),
+(43.8135459, -79.322156),
+(43.869115, -78.962299),
+(43.7277051, -79.4016809),
+(43.7343386, -79.355188),
+(43.780039, -79.344324),
+(43.7481415, -79.7474122),
+(43.7341094, -79.7715959),
+(43.7853277, -79.2885086),
+(43.843482, -79.4427371),
+(43.7543083, -79.5229676),
+(43.586308, -79.7882665),
+(43.8525049, -79.5637904),
+(43.7653441, -79.4126151),
+(43.8638038, -79.4308561),
+(43.7011344, -79.8566694),
+(43.6679848, -79.3755762),
+(43.778847, -79.597021),
+(43.7859764, -79.1975731),
+(43.849203, -79.285062),
+(43.8046762, -79.4230377),
+(43.7765413, -79.4326862),
+(43.6599507, -79.3849463),
+(43.7028171, -79.4158597),
+(43.614903, -79.488798),
+(43.7694392, -79.4160861),
+(43.7745195, -79.3297599),
+(43.7400418, -79.4109884),
+(43.672274, -79.3804573),
+(43.849897, -79.438838),
+(43.717805, -79.414806),
+(43.7070826, -79.3977712),
+(43.6509155, -79.4362398),
+(43.7501397, -79.5673869),
+(43.660246, -79.466657),
+(43.7671995, -79.2688481),
+(43.954557, -79.472827),
+(43.9698562, -79.2216107),
+(43.779207, -79.281519),
+(43.6364747, -79.4020594),
+(43.8532036, -79.499272),
+(43.7585452, -79.4195607),
+(43.638987, -79.531391),
+(43.8221939, -79.4164431),
+(43.6846135, -79.3569988),
+(43.779718, -79.342657),
+(43.645281, -79.432878),
+(43.485697, -79.72297),
+(43.780493, -79.3635433),
+(43.9793664, -79.5995219),
+(43.6252026, -79.5157833),
+(43.7271577, -79.9369537),
+(43.7690784, -79.3694836),
+(43.8311452, -79.4670708),
+(43.754389, -79.3778978),
+(43.5746071, -79.5874583),
+(43.8041695, -79.6086206),
+(43.83127, -79.4785198),
+(43.687509, -79.39595),
+(43.8621905, -79.3391012),
+(43.9676226, -78.9532315),
+(43.6746538, -79.3890069),
+(43.7787489, -79.4171422),
+(43.9850942, -78.9566002),
+(43.714484, -79.2828228),
+(43.7872283, -79.1946802),
+(43.8263784, -79.2750135),
+(43.9533203, -79.007867),
+(43.833122, -79.531325),
+(43.7682116, -79.1828222),
+(43.9830769, -79.4740904),
+(43.9432446, -79.2822707),
+(43.8284551, -79.574502),
+(43.73377, -79.534489),
+(43.6875168, -79.4489297),
+(43.8558994, -79.4439996),
+(43.3997124, -79.7909949),
+(43.9642973, -79.2443429),
+(43.955457, -79.252758),
+(43.780252, -79.448149),
+(43.7722925, -79.4122663),
+(43.659454, -79.382587),
+(43.395228, -79.817089),
+(43.8979201, -79.02049),
+(43.911496, -78.9334473),
+(43.724363, -79.251666),
+(43.8586207, -79.2978153),
+(43.8703233, -79.2643744),
+(43.6400653, -79.3784948),
+(43.6611357, -79.3856052),
+(43.6238205, -79.480348),
+(43.7918325, -79.1699236),
+(43.7690784, -79.3694836),
+(43.549181, -79.716731),
+(43.7365801, -79.2578377),
+(43.8809693, -79.4691862),
+(43.7424112, -79.3431188),
+(43.9082913, -79.4684995),
+(43.646331, -79.41983),
+(43.7729216, -79.8314509),
+(43.8025897, -79.4198004),
+(43.6958772, -79.8388981),
+(43.5126284, -79.8454482),
+(43.6968967, -79.7836114),
+(43.7088414, -79.8223265),
+(43.8416179, -79.5235865),
+(43.7845248, -79.3138382),
+(43.681284, -79.4102352),
+(43.939112, -78.8863219),
+(43.623534, -79.4801),
+(43.4703293, -79.7127162),
+(43.7045407, -79.7639288),
+(43.6939553, -79.7918072),
+(43.6747703, -79.3976829),
+(43.6593521, -79.3856503),
+(43.647627, -79.390408),
+(43.7163917, -79.3757041),
+(43.7831665, -79.4292269),
+(43.7850714, -79.3973383),
+(43.663211, -79.377803),
+(43.8876294, -79.408964),
+(43.9831849, -79.4660731),
+(43.6810466, -79.4475371),
+(43.706419, -79.832634),
+(43.67572, -79.558851),
+(43.7038668, -79.8456071),
+(43.5151895, -79.8601993),
+(43.68387, -79.8001621),
+(43.690254, -79.760604),
+(43.8506913, -79.4300025),
+(43.723452, -79.8245372),
+(43.362431, -79.8344751),
+(43.67032, -79.394433),
+(43.74565, -79.33818),
+(43.6425128, -79.3813182),
+(43.7602436, -79.4083424),
+(43.479218, -79.6290854),
+(43.8374782, -79.2847325),
+(43.7455539, -79.563202),
+(43.8947709, -79.2928522),
+(43.601038, -79.709741),
+(43.6179737, -79.588694),
+(43.680803, -79.842706),
+(43.630665, -79.7796712),
+(43.7373696, -79.7471973),
+(43.3940387, -79.8278629),
+(43.4394815, -79.7511436),
+(43.5281277, -79.6561727),
+(43.6593726, -79.3825439),
+(43.7176283, -79.4224757),
+(43.7873301, -79.4553555),
+(43.6700844, -79.3805554),
+(43.6750288, -79.7608946),
+(43.393665, -79.8299758),
+(43.8434543, -79.0996706),
+(43.7032374, -79.2938492),
+(43.8276274, -79.0913745),
+(43.8010154, -79.5892983),
+(43.9457948, -79.5341949),
+(43.778294, -79.575555),
+(43.968469, -79.3313993),
+(43.8540874, -79.4203962),
+(43.6940301, -79.3132295),
+(43.5754061, -79.7288301),
+(43.411804, -79.802099),
+(43.6723825, -79.7813585),
+(43.9559523, -79.2634091),
+(43.6873738, -79.8206227),
+(43.6835879, -79.3566739),
+(43.6968373, -79.293353),
+(43.97696, -79.6040162),
+(43.7758101, -79.3728376),
+(43.9680766, -79.276052),
+(43.73283, -79.272138),
+(43.8825991, -79.3400665),
+(43.75366, -79.448626),
+(43.779718, -79.342657),
+(43.7675491, -79.4962812),
+(43.756947, -79.4064994),
+(43.884681, -79.309914),
+(43.86695, -79.446545),
+(43.4708233, -79.6860852),
+(43.493922, -79.705752),
+(43.6405034, -79.4268581),
+(43.9444359, -79.4780989),
+(43.9326769, -79.477561),
+(43.4071558, -79.7229256),
+(43.7252301, -79.497878),
+(43.859613, -79.86768),
+(43.6901474, -79.450039),
+(43.7783428, -79.4159581),
+(43.8784854, -79.4043771),
+(43.8325877, -79.5758284),
+(43.760906, -79.4090028),
+(43.8883356, -79.0136495),
+(43.959073, -78.890261),
+(43.7879555, -79.3868284),
+(43.9482735, -79.4462354),
+(43.7358093, -79.4783101),
+(43.7665801, -79.605588),
+(43.631268, -79.428567),
+(43.6513375, -79.7695661),
+(43.6889375, -79.7636694),
+(43.7123245, -79.7174931),
+(43.5819207, -79.7811414),
+(43.678272, -79.843341),
+(43.6999458, -79.8233358),
+(43.6947082, -79.4441125),
+(43.8689705, -79.4142155),
+(43.7252546, -79.697615),
+(43.6704872, -79.3845738),
+(43.773486, -79.4330038),
+(43.6959554, -79.3957179),
+(43.8719804, -79.4209522),
+(43.87024, -79.052365),
+(43.6429141, -79.3806469),
+(43.3791554, -79.7360275),
+(43.8449115, -79.396375),
+(43.9602917, -78.8988522),
+(43.669593, -79.38058),
+(43.7663749, -79.3905728),
+(43.7803294, -79.3234667),
+(43.66955, -79.383776),
+(43.7091888, -79.823042),
+(43.564695, -79.721629),
+(43.4179508, -79.7064211),
+(43.7824325, -79.6080814),
+(43.8952573, -79.4019598),
+(43.642791, -79.407195),
+(43.8998056, -78.9668494),
+(43.9029701, -79.3718302),
+(43.648909, -79.386075),
+(43.7349349, -79.2655749),
+(43.7070826, -79.3977712),
+(43.6454752, -79.3923183),
+(43.77807, -79.4158254),
+(43.8956927, -79.2445643),
+(43.8462111, -79.4185051),
+(43.7580166, -79.4642609),
+(43.7028201, -79.4159264),
+(43.686709, -79.399258),
+(43.7658385, -79.4383835),
+(43.66955, -79.383776),
+(43.8147022, -79.3264457),
+(43.668578, -79.384479),
+(43.700445, -79.397789),
+(43.9066525, -79.4722524),
+(43.537179, -79.664968),
+(43.648909, -79.386075),
+(43.7316086, -79.5323051),
+(43.5443675, -79.7334146),
+(43.7593366, -79.3975501),
+(43.6620369, -79.3865221),
+(43.9585629, -78.9001504),
+(43.659381, -79.385626),
+(43.8482277, -79.5285576),
+(43.7293504, -79.4784052),
+(43.8417165, -79.4700041),
+(43.871669, -79.050886),
+(43.9546252, -78.9112584),
+(43.8649249, -79.3126365),
+(43.8240436, -79.4224264),
+(43.4793159, -79.7257402),
+(43.6526759, -79.5692126),
+(43.779746, -79.4181433),
+(43.7148711, -79.356621),
+(43.7422755, -79.2395952),
+(43.8521806, -79.3373192),
+(43.758023, -79.420132),
+(43.6553047, -79.375328),
+(43.6396262, -79.4157955),
+(43.640149, -79.3924625),
+(43.7759744, -79.4225106),
+(43.7143591, -79.3531865),
+(43.6630187, -79.3863345),
+(43.7694392, -79.4160861),
+(43.8869447, -78.9652911),
+(43.773249, -79.255273),
+(43.6614221, -79.5803607),
+(43.553457, -79.7492508),
+(43.5847029, -79.6415724),
+(43.5872308, -79.639211),
+(43.57932, -79.626292),
+(43.7948843, -79.3520093),
+(43.7673834, -79.4033785),
+(43.787247, -79.461929),
+(43.5527595, -79.7250155),
+(43.8292472, -79.5678278),
+(43.8576326, -79.2230188),
+(43.5791873, -79.5931746),
+(43.8441343, -79.5724819),
+(43.8599697, -79.4900572),
+(43.781091, -79.410538),
+(43.6994678, -79.5453106),
+(43.3718156, -79.7639519),
+(43.4863803, -79.8633229),
+(43.5053919, -79.8419063),
+(43.7801104, -79.7503467),
+(43.739889, -79.511179),
+(43.643104, -79.939443),
+(43.8340048, -79.6576094),
+(43.7047605, -79.3268787),
+(43.6488273, -79.3780964),
+(43.8624882, -79.0068788),
+(43.5291727, -79.8585377),
+(43.782705, -79.581291),
+(43.6596471, -79.5498271),
+(43.6505177, -79.4981161),
+(43.7321743, -79.6002482),
+(43.870349, -79.744298),
+(43.4852192, -79.7241884),
+(43.6405538, -79.74498),
+(43.7714257, -79.373117),
+(43.3937117, -79.7875381),
+(43.636057, -79.470781),
+(43.6962745, -79.2927586),
+(43.868922, -79.052533),
+(43.850094, -79.323495),
+(43.8359964, -79.4639194),
+(43.766764, -79.385993),
+(43.705293, -79.3972641),
+(43.832915, -79.437761),
+(43.8011945, -79.4592035),
+(43.8309725, -79.4743172),
+(43.640594, -79.559733),
+(43.6398765, -79.4167372),
+(43.581626, -79.648167),
+(43.584219, -79.7938512),
+(43.96666, -79.2346173),
+(43.8950999, -79.2983645),
+(43.6402683, -79.3960141),
+(43.669963, -79.386158),
+(43.6593726, -79.3825439),
+(43.8266225, -79.4660207),
+(43.877595, -79.2770486),
+(43.7083196, -79.3926249),
+(43.7686168, -79.3880662),
+(43.6219125, -79.7276527),
+(43.4640305, -79.6898528),
+(43.6382499, -79.5362492),
+(43.581187, -79.647617),
+(43.878074, -79.031762),
+(43.5972157, -79.5244404),
+(43.742602, -79.7442982),
+(43.9151111, -79.4365652),
+(43.972842, -79.2392885),
+(43.629505, -79.726234),
+(43.764029, -79.407042),
+(43.6964558, -79.3933434),
+(43.6477029, -79.405334),
+(43.5779781, -79.6364035),
+(43.640783, -79.40121),
+(43.6959032, -79.7563091),
+(43.8664336, -79.4355835),
+(43.7316353, -79.3978768),
+(43.6580162, -79.3583966),
+(43.782209, -79.3911018),
+(43.771566, -79.412099),
+(43.734202, -79.8094946),
+(43.6694723, -79.3874877),
+(43.639918, -79.919837),
+(43.6852453, -79.7618555),
+(43.8392453, -79.4686811),
+(43.8966549, -79.4591716),
+(43.4032789, -79.8195142),
+(43.4885327, -79.8764272),
+(43.970267, -79.263706),
+(43.700012, -79.8585512),
+(43.546487, -79.685416),
+(43.375577, -79.759334),
+(43.7069544, -79.395016),
+(43.8890654, -79.3722457),
+(43.513758, -79.832957),
+(43.5705345, -79.6722149),
+(43.660137, -79.385869),
+(43.856011, -79.044626),
+(43.7261365, -79.2643908),
+(43.8113804, -79.2260421),
+(43.5838361, -79.7246217),
+(43.7975676, -79.3198497),
+(43.4829155, -79.6873235),
+(43.5683342, -79.6889292),
+(43.6747527, -79.8275434),
+(43.7563401, -79.4056932),
+(43.9722644, -79.1262443),
+(43.9012996, -79.2476513),
+(43.6476028, -79.39076),
+(43.8330564, -79.5324085),
+(43.5651865, -79.7328736),
+(43.642311, -79.38123),
+(43.7598013, -79.1757221),
+(43.7038342, -79.8172528),
+(43.7021103, -79.2620519),
+(43.642626, -79.415251),
+(43.6575162, -79.3767709),
+(43.8575037, -79.4323048),
+(43.6968373, -79.293353),
+(43.8854929, -79.4011961),
+(43.8826269, -79.4654121),
+(43.9711, -79.2461),
+(43.707592, -79.8381496),
+(43.603235, -79.801835),
+(43.6490838, -79.392082),
+(43.624595, -79.480119),
+(43.78, -79.3808),
+(43.700445, -79.397789),
+(43.4794569, -79.7443958),
+(43.4805615, -79.7385115),
+(43.8215005, -79.5742024),
+(43.6938613, -79.6934439),
+(43.8903975, -79.3087531),
+(43.8340223, -79.469114),
+(43.956234, -78.9012802),
+(43.8592708, -79.4568502),
+(43.6966073, -79.2942117),
+(43.859995, -79.4631718),
+(43.502473, -79.875754),
+(43.7323762, -79.5369779),
+(43.4204897, -79.7112625),
+(43.6296116, -79.4980148),
+(43.8434822, -79.2521717),
+(43.7924394, -79.3357717),
+(43.6593726, -79.3825439),
+(43.708008, -79.400785),
+(43.8078001, -79.2653953),
+(43.7023302, -79.5313567),
+(43.9043792, -79.2718966),
+(43.6747343, -79.8133482),
+(43.8884369, -79.3012059),
+(43.6468108, -79.3853366),
+(43.6870783, -79.847612),
+(43.6514, -79.3826),
+(43.6397347, -79.3920867),
+(43.7708, -79.4065),
+(43.8824691, -79.4231581),
+(43.4683158, -79.7233901),
+(43.7755305, -79.4152516),
+(43.594494, -79.635187),
+(43.7684033, -79.1820615),
+(43.641596, -79.423443),
+(43.655305, -79.3537502),
+(43.6893201, -79.5049734),
+(43.7951, -79.2664),
+(43.5393637, -79.6023338),
+(43.4094721, -79.7114656),
+(43.8451061, -79.2721591),
+(43.764178, -79.404851),
+(43.7073389, -79.394278),
+(43.5555758, -79.577613),
+(43.5737139, -79.6008183),
+(43.7553394, -79.8179552),
+(43.87572, -79.8605912),
+(43.662306, -79.7288833),
+(43.889014, -79.420832),
+(43.7406122, -79.5763196),
+(43.6591336, -79.5787458),
+(43.8423682, -79.468242),
+(43.8468363, -79.4551523),
+(43.8551515, -79.344036),
+(43.899161, -79.736216),
+(43.7220508, -79.7154215),
+(43.766699, -79.5132295),
+(43.4682014, -79.7483163),
+(43.7421853, -79.7541566),
+(43.4040571, -79.8226002),
+(43.6504617, -79.5686024),
+(43.4999654, -79.7055013),
+(43.6079464, -79.5672021),
+(43.6361579, -79.4706161),
+(43.4334059, -79.7289973),
+(43.7678164, -79.7566688),
+(43.7509718, -79.5628661),
+(43.8778935, -79.4227004),
+(43.7999286, -79.7344044),
+(43.9758657, -79.2382465),
+(43.9529359, -78.9469645),
+(43.8416228, -79.5081923),
+(43.8799103, -79.4051925),
+(43.854454, -79.517075),
+(43.8738455, -79.4279365),
+(43.636293, -79.470463),
+(43.8641623, -79.3215475),
+(43.8633159, -79.2410409),
+(43.6016906, -79.6473268),
+(43.6708764, -79.4490892),
+(43.5648737, -79.5816165),
+(43.6921805, -79.8202193),
+(43.677978, -79.802663),
+(43.5188509, -79.8310344),
+(43.7006162, -79.5268916),
+(43.6732356, -79.815278),
+(43.6222561, -79.4825565),
+(43.7373436, -79.4920474),
+(43.4304479, -79.7490979),
+(43.6399816, -79.5845843),
+(43.7082794, -79.8309586),
+(43.649737, -79.930994),
+(43.6765748, -79.7735215),
+(43.3584533, -79.8136703),
+(43.7471825, -79.3224755),
+(43.7880623, -79.368497),
+(43.3640022, -79.7488219),
+(43.7713438, -79.418104),
+(43.646942, -79.875748),
+(43.6788696, -79.8082253),
+(43.6512214, -79.3671167),
+(43.7307234, -79.3276511),
+(43.371634, -79.8362876),
+(43.7084796, -79.3975213),
+(43.7123035, -79.252199),
+(43.8813664, -79.0285353),
+(43.7300903, -79.3322682),
+(43.641767, -79.381458),
+(43.841836, -79.116954),
+(43.6490534, -79.3861546),
+(43.6423906, -79.4247385),
+(43.6478115, -79.4385888),
+(43.7549431, -79.4216053),
+(43.7665037, -79.4110937),
+(43.647524, -79.387335),
+(43.7340774, -79.3547664),
+(43.6568586, -79.3836642),
+(43.7320329, -79.4391341),
+(43.7755305, -79.4152516),
+(43.9264127, -79.064344),
+(43.6596665, -79.3859262),
+(43.6363712, -79.4027602),
+(43.7937977, -79.2350364),
+(43.7155526, -79.399959),
+(43.646084, -79.365012),
+(43.649696, -79.398202),
+(43.665666, -79.382607),
+(43.8847799, -79.0231628),
+(43.775424, -79.337482),
+(43.6403228, -79.3917567),
+(43.8248015, -79.1424542),
+(43.7792734, -79.4493486),
+(43.9538326, -79.4800817),
+(43.8747499, -79.4739385),
+(43.8352463, -79.5432834),
+(43.6411375, -79.4007447),
+(43.660905, -79.378806),
+(43.8433794, -79.2568775),
+(43.555075, -79.583315),
+(43.6661731, -79.4405253),
+(43.8914749, -79.2750268),
+(43.636306, -79.466089),
+(43.8899503, -79.4568958),
+(43.7511738, -79.7523956),
+(43.589705, -79.633017),
+(43.5887481, -79.6517696),
+(43.7705026, -79.6631209),
+(43.5464255, -79.6851862),
+(43.6654182, -79.5546415),
+(43.5962386, -79.6089604),
+(43.5653466, -79.6518856),
+(43.570843, -79.630158),
+(43.5210803, -79.8538453),
+(43.7793201, -79.2814437),
+(43.60596, -79.655123),
+(43.6609604, -79.4954562),
+(43.5943985, -79.6344007),
+(43.6402683, -79.3960141),
+(43.7003517, -79.8600383),
+(43.9239663, -79.4258359),
+(43.9086469, -79.4564547),
+(43.8719421, -79.4268026),
+(43.7023688, -79.4158524),
+(43.6525613, -79.8055957),
+(43.827088, -79.09158),
+(43.6298722, -79.874685),
+(43.69555, -79.3387329),
+(43.7590846, -79.7490519),
+(43.623426, -79.4789788),
+(43.5948353, -79.6349645),
+(43.832463, -78.973512),
+(43.5741106, -79.5637888),
+(43.645621, -79.389497),
+(43.846554, -79.427873),
+(43.760402, -79.40901),
+(43.8297978, -79.5486644),
+(43.653558, -79.36882),
+(43.743, -79.3577),
+(43.5049792, -79.8882163),
+(43.8429399, -79.5495591),
+(43.4285, -79.7445),
+(43.9112263, -79.465549),
+(43.6850824, -79.8484728),
+(43.4822893, -79.851732),
+(43.9830701, -79.4510583),
+(43.6331309, -79.797321),
+(43.4262063, -79.7856865),
+(43.3575904, -79.8133894),
+(43.8722353, -79.2756847),
+(43.6617328, -79.4938161),
+(43.6590147, -79.7774123),
+(43.5382123, -79.7315532),
+(43.904739, -78.9513901),
+(43.65056, -79.382072),
+(43.9574808, -78.9129228),
+(43.7054055, -79.8302837),
+(43.8990421, -79.2869781),
+(43.6484128, -79.3764665),
+(43.7612374, -79.408937),
+(43.6426507, -79.3773286),
+(43.622729, -79.48319),
+(43.433421, -79.745617),
+(43.7080568, -79.400489),
+(43.861428, -79.436473),
+(43.6020769, -79.6046599),
+(43.7458891, -79.5158122),
+(43.908919, -78.9124677),
+(43.5171436, -79.8355327),
+(43.4656139, -79.6708171),
+(43.770974, -79.2030444),
+(43.7577362, -79.7420811),
+(43.486708, -79.78659),
+(43.653198, -79.7893436),
+(43.5236115, -79.8413139),
+(43.7497698, -79.5693407),
+(43.8096514, -79.385693),
+(43.3947311, -79.8143593),
+(43.785909, -79.2898574),
+(43.7535401, -79.3690849),
+(43.8449515, -79.4887184),
+(43.7900917, -79.4415641),
+(43.7657785, -79.4100647),
+(43.7900246, -79.25466),
+(43.9317277, -79.5329277),
+(43.472414, -79.723402),
+(43.6804, -79.7693),
+(43.8025897, -79.4198004),
+(43.586289, -79.647364),
+(43.6723158, -79.3888155),
+(43.6668981, -79.3890419),
+(43.757362, -79.178918),
+(43.7597337, -79.3396502),
+(43.6839, -79.4824),
+(43.963799, -78.977484),
+(43.7986429, -79.3996222),
+(43.6516984, -79.4174282),
+(43.606496, -79.655651),
+(43.5930469, -79.6708884),
+(43.3447257, -79.8856937),
+(43.8129727, -79.4551834),
+(43.5284027, -79.6483933),
+(43.6729857, -79.3870289),
+(43.773688, -79.255422),
+(43.8914094, -79.4289696),
+(43.666981, -79.388889),
+(43.597134, -79.524327),
+(43.8137639, -79.455991),
+(43.683596, -79.3952069),
+(43.640845, -79.414319),
+(43.756136, -79.412072),
+(43.9016071, -79.2539065),
+(43.6400653, -79.3784948),
+(43.8582866, -79.4381617),
+(43.4252891, -79.8706763),
+(43.62526, -79.477903),
+(43.8614279, -79.4364729),
+(43.806228, -79.423727),
+(43.7795793, -79.4458889),
+(43.7772881, -79.170717),
+(43.5045077, -79.845356),
+(43.878988, -79.4761401),
+(43.8775362, -79.4683409),
+(43.873644, -79.732893),
+(43.7729357, -79.4185008),
+(43.7680669, -79.3767938),
+(43.6610449, -79.4071207),
+(43.3551846, -79.8448913),
+(43.6484374, -79.4865948),
+(43.750384, -79.4584405),
+(43.8038622, -79.4342263),
+(43.8680528, -79.5318225),
+(43.8943504, -79.0436308),
+(43.9309764, -78.8890455),
+(43.39785, -79.815879),
+(43.5844394, -79.7783752),
+(43.757173, -79.40651),
+(43.9107468, -78.8893547),
+(43.4836979, -79.8387098),
+(43.645641, -79.392953),
+(43.8065185, -79.1072225),
+(43.6398513, -79.5595134),
+(43.8687536, -79.2647674),
+(43.7465536, -79.4298792),
+(43.9664092, -79.2470682),
+(43.7263754, -79.5219621),
+(43.66593, -79.4548133),
+(43.598229, -79.5082098),
+(43.7648208, -79.410443),
+(43.6871794, -79.5048436),
+(43.8579344, -79.4859955),
+(43.608816, -79.89559),
+(43.431759, -79.7743914),
+(43.6466079, -79.4185965),
+(43.667768, -79.79891),
+(43.9387351, -79.4319861),
+(43.610353, -79.551401),
+(43.7386, -79.8437),
+(43.641523, -79.4240871),
+(43.6636287, -79.3864511),
+(43.701994, -79.27654),
+(43.6809653, -79.3522826),
+(43.85195, -79.431773),
+(43.5859549, -79.6405988),
+(43.8140985, -79.4514793),
+(43.945007, -79.4341746),
+(43.9204285, -79.4746802),
+(43.591508, -79.621852),
+(43.6345509, -79.7253625),
+(43.76699, -79.383151),
+(43.7925765, -79.4154575),
+(43.7386, -79.8437),
+(43.6946328, -79.8071176),
+(43.670587, -79.380609),
+(43.6489, -79.392205),
+(43.626138, -79.477339),
+(43.65056, -79.382072),
+(43.7569734, -79.3185723),
+(43.4823297, -79.6494776),
+(43.5212415, -79.857076),
+(43.7717916, -79.6997013),
+(43.784334, -79.3874597),
+(43.8286737, -79.5614108),
+(43.7425966, -79.4290446),
+(43.448364, -79.6795964),
+(43.546985, -79.607906),
+(43.9108969, -78.9193967),
+(43.6400202, -79.5714159),
+(43.682348, -79.4354161),
+(43.595876, -79.534967),
+(43.66125, -79.347569),
+(43.3530343, -79.7653445),
+(43.7945751, -79.556816),
+(43.669301, -79.384171),
+(43.6100597, -79.533375),
+(43.9479087, -79.4785599),
+(43.7937025, -79.4163306),
+(43.643485, -79.392304),
+(43.646084, -79.365012),
+(43.7703, -79.413645),
+(43.619564, -79.5103162),
+(43.6710104, -79.4496401),
+(43.7083196, -79.3926249),
+(43.8764956, -79.2429927),
+(43.646808, -79.402798),
+(43.8761061, -79.2652321),
+(43.7331798, -79.3347545),
+(43.798082, -79.583662),
+(43.7699824, -79.4117447),
+(43.853996, -79.103147),
+(43.8156714, -79.6191709),
+(43.880745, -79.312495),
+(43.653751, -79.417073),
+(43.8713786, -79.2548425),
+(43.8584881, -79.4030652),
+(43.8818045, -79.467208),
+(43.880813, -79.31252),
+(43.880947, -79.312573),
+(43.708287, -79.391964),
+(43.6400003, -79.3788904),
+(43.6523555, -79.4089958),
+(43.6548441, -79.3846797),
+(43.9661737, -78.9735475),
+(43.8513311, -79.3257986),
+(43.8457818, -79.2613603),
+(43.5887481, -79.6517696),
+(43.520078, -79.620473),
+(43.9353407, -79.555718),
+(43.6427017, -79.3933634),
+(43.6495, -79.4824),
+(43.926, -79.2114),
+(43.641785, -79.379006),
+(43.699017, -79.490145),
+(43.9067621, -78.9349896),
+(43.849752, -79.324666),
+(43.5689554, -79.6183989),
+(43.3516624, -79.7654602),
+(43.646084, -79.365012),
+(43.913128, -78.907275),
+(43.7758348, -79.4132441),
+(43.8071917, -79.1039631),
+(43.7738219, -79.258949),
+(43.7639679, -79.2032065),
+(43.6641161, -79.3332082),
+(43.6311305, -79.7783346),
+(43.647226, -79.3911),
+(43.858282, -79.026458),
+(43.6463687, -79.5149183),
+(43.590496, -79.523664),
+(43.840964, -79.118641),
+(43.4029139, -79.8249211),
+(43.7325735, -79.425911),
+(43.9223564, -78.9171252),
+(43.5703809, -79.6732952),
+(43.631849, -79.886098),
+(43.5340357, -79.7005271),
+(43.6695584, -79.3846569),
+(43.6523306, -79.3688408),
+(43.668718, -79.383996),
+(43.667978, -79.388548),
+(43.773922, -79.345726),
+(43.655305, -79.3537502),
+(43.6700844, -79.3805554),
+(43.698156, -79.445534),
+(43.7340901, -79.5351774),
+(43.5525988, -79.7124356),
+(43.864646, -79.048124),
+(43.3947514, -79.8268211),
+(43.6443936, -79.363966),
+(43.756899, -79.404584),
+(43.8534253, -79.564263),
+(43.7186134, -79.260883),
+(43.875909, -79.4211276),
+(43.6425128, -79.3813182),
+(43.953099, -78.9661865),
+(43.7863082, -79.661911),
+(43.665748, -79.332803),
+(43.3466698, -79.7793331),
+(43.665388, -79.3880211),
+(43.722891, -79.457092),
+(43.7083406, -79.373056),
+(43.708965, -79.392985),
+(43.7907822, -79.3561322),
+(43.8069104, -79.2666264),
+(43.6964903, -79.4439129),
+(43.7119582, -79.52918),
+(43.6394317, -79.7424706),
+(43.401038, -79.79498),
+(43.500916, -79.877836),
+(43.8605315, -79.4017491),
+(43.704753, -79.300544),
+(43.6235172, -79.4792877),
+(43.724184, -79.402359),
+(43.7748575, -79.1400356),
+(43.858385, -79.4018108),
+(43.7268375, -79.5786529),
+(43.6401213, -79.3812266),
+(43.581694, -79.7466745),
+(43.6866509, -79.3995111),
+(43.670547, -79.380581),
+(43.6494976, -79.37295),
+(43.5478534, -79.7202339),
+(43.661725, -79.384128),
+(43.6504765, -79.7456266),
+(43.6759015, -79.3765325),
+(43.649696, -79.398202),
+(43.6396436, -79.4151851),
+(43.7539686, -79.3835119),
+(43.8720493, -79.4797372),
+(43.773403, -79.344636),
+(43.7080568, -79.400489),
+(43.7485837, -79.2043835),
+(43.8790142, -79.0286911),
+(43.896603, -79.26584),
+(43.503783, -79.850138),
+(43.7070855, -79.3977452),
+(43.740158, -79.827997),
+(43.6775805, -79.4059522),
+(43.4014683, -79.8211476),
+(43.625911, -79.876704),
+(43.6754581, -79.7340475),
+(43.6840347, -79.3713052),
+(43.647627, -79.390408),
+(43.7941787, -79.4397985),
+(43.84167, -79.395797),
+(43.6208228, -79.8845648),
+(43.448084, -79.85451),
+(43.4716187, -79.735323),
+(43.8664747, -79.4706616),
+(43.8441949, -79.5492472),
+(43.9155625, -78.9284369),
+(43.6624074, -79.3858983),
+(43.7929561, -79.400697),
+(43.7763249, -79.4140326),
+(43.7542909, -79.3366742),
+(43.591972, -79.672462),
+(43.7313686, -79.431222),
+(43.8562844, -79.6184732),
+(43.6214406, -79.7385888),
+(43.655111, -79.375643),
+(43.4914247, -79.8546468),
+(43.680666, -79.444434),
+(43.751467, -79.382273),
+(43.5614909, -79.7506756),
+(43.382192, -79.880599),
+(43.9224803, -79.4681753),
+(43.7879703, -79.4522084),
+(43.868242, -79.028482),
+(43.9781988, -79.4640722),
+(43.776323, -79.335927),
+(43.973377, -79.056732),
+(43.5658724, -79.589856),
+(43.8926975, -79.4051076),
+(43.663031, -79.751751),
+(43.4594016, -79.699177),
+(43.6409947, -79.3945703),
+(43.7809828, -79.4135506),
+(43.6655014, -79.3833327),
+(43.6430625, -79.4281337),
+(43.4818791, -79.652781),
+(43.8583978, -79.4492914),
+(43.6214031, -79.4837838),
+(43.3556987, -79.8975922),
+(43.9025614, -79.4245286),
+(43.3540681, -79.755222),
+(43.7470818, -79.3660701),
+(43.8355998, -79.4396367),
+(43.8735598, -79.4614409),
+(43.7831107, -79.4151692),
+(43.840464, -79.549669),
+(43.6704559, -79.5445228),
+(43.7284214, -79.5223137),
+(43.55036, -79.813638),
+(43.7847851, -79.4504942),
+(43.623821, -79.478728),
+(43.9542984, -78.9481727),
+(43.8613389, -79.3549151),
+(43.680803, -79.842706),
+(43.592325, -79.5393013),
+(43.6667116, -79.3176486),
+(43.7859726, -79.3202878),
+(43.843937, -79.076081),
+(43.4353172, -79.7581064),
+(43.6689591, -79.4490174),
+(43.9435826, -79.4527818),
+(43.7867927, -79.4347244),
+(43.65352, -79.37377),
+(43.6697112, -79.3458364),
+(43.741049, -79.415473),
+(43.8491073, -79.4189953),
+(43.7937018, -79.7464386),
+(43.6763643, -79.4580721),
+(43.637438, -79.5456688),
+(43.7215295, -79.7784049),
+(43.859483, -79.034961),
+(43.6619999, -79.3439322),
+(43.7592531, -79.3959164),
+(43.9609, -78.9009),
+(43.8584382, -79.5608707),
+(43.688745, -79.478495),
+(43.9005814, -79.0155178),
+(43.6426507, -79.3773286),
+(43.6807304, -79.3796001),
+(43.6920002, -79.2713458),
+(43.8669734, -79.5241901),
+(43.7854051, -79.450821),
+(43.8508663, -79.4283197),
+(43.6805107, -79.4770538),
+(43.6800027, -79.3094961),
+(43.5985213, -79.5986441),
+(43.960834, -78.956486),
+(43.598506, -79.527238),
+(43.7486709, -79.7320729),
+(43.666751, -79.376235),
+(43.4748985, -79.7359326),
+(43.8070505, -79.4551611),
+(43.5584857, -79.7172813),
+(43.5923371, -79.6053276),
+(43.762714, -79.346424),
+(43.858571, -79.029553),
+(43.701876, -79.507778),
+(43.764178, -79.404851),
+(43.7336787, -79.8045485),
+(43.902411, -79.2323236),
+(43.5892563, -79.5387348),
+(43.8847204, -79.4212349),
+(43.3672, -79.9339),
+(43.780228, -79.418123),
+(43.8653622, -79.5308837),
+(43.725022, -79.4416507),
+(43.646274, -79.3614175),
+(43.5811552, -79.6456268),
+(43.5816498, -79.6480951),
+(43.7422755, -79.6006348),
+(43.6266427, -79.5135657),
+(43.6952107, -79.5508744),
+(43.9006334, -78.9745291),
+(43.8775302, -79.4429863),
+(43.4438176, -79.7664156),
+(43.7817857, -79.4051211),
+(43.813894, -79.451429),
+(43.442246, -79.725878),
+(43.9126837, -78.9510089),
+(43.9422374, -79.481775),
+(43.8017026, -79.4259662),
+(43.8592866, -79.4590204),
+(43.819036, -79.6043051),
+(43.69523, -79.322187),
+(43.714348, -79.353145),
+(43.644128, -79.389406),
+(43.6668981, -79.3890419),
+(43.7643203, -79.4137303),
+(43.6666912, -79.3191628),
+(43.6894476, -79.3423994),
+(43.8106158, -79.4617992),
+(43.899648, -79.735759),
+(43.9287372, -79.5322048),
+(43.903917, -79.4556081),
+(43.7715214, -79.3028597),
+(43.808044, -79.5952401),
+(43.823986, -79.149938),
+(43.7218058, -79.6950724),
+(43.8205905, -79.4237985),
+(43.8932691, -79.4382532),
+(43.6526701, -79.4772091),
+(43.7558371, -79.8389427),
+(43.652433, -79.38886),
+(43.760223, -79.3668163),
+(43.7431268, -79.3574578),
+(43.654147, -79.390535),
+(43.6821093, -79.7367032),
+(43.881674, -79.225092),
+(43.881674, -79.225092),
+(43.834009, -79.150049),
+(43.668438, -79.317623),
+(43.859464, -79.4322987),
+(43.678, -79.4875),
+(43.6940447, -79.4396089),
+(43.5169, -79.8826),
+(43.7258151, -79.8164661),
+(43.707957, -79.480399),
+(43.876441, -79.861587),
+(43.495289, -79.8774563),
+(43.5621011, -79.7270249),
+(43.5874623, -79.6414072),
+(43.5809236, -79.6238027),
+(43.535449, -79.858287),
+(43.7620963, -79.5745593),
+(43.7322654, -79.7540586),
+(43.4803424, -79.6383155),
+(43.6131373, -79.7024812),
+(43.6823311, -79.8435177),
+(43.9585184, -78.968362),
+(43.6504803, -79.7857063),
+(43.7071, -79.394138),
+(43.8227069, -79.5609044),
+(43.5955489, -79.6337354),
+(43.7479819, -79.3747461),
+(43.4303813, -79.6909989),
+(43.8134367, -79.4266247),
+(43.669616, -79.362936),
+(43.734051, -79.448063),
+(43.7899083, -79.4293647),
+(43.76972, -79.366921),
+(43.633233, -79.5663372),
+(43.727875, -79.273962),
+(43.9588484, -79.2481),
+(43.6503521, -79.3609928),
+(43.9651009, -79.8370037),
+(43.6668981, -79.3890419),
+(43.668578, -79.384479),
+(43.838867, -79.013384),
+(43.6728011, -79.2863448),
+(43.6374812, -79.4400511),
+(43.640566, -79.562739),
+(43.623782, -79.488316),
+(43.9643628, -78.9404214),
+(43.9645731, -78.9698028),
+(43.8372371, -79.4750463),
+(43.970586, -79.244284),
+(43.9609, -78.9009),
+(43.858252, -79.433621),
+(43.8817118, -79.2563632),
+(43.7111087, -79.5199758),
+(43.660846, -79.378756),
+(43.643543, -79.376026),
+(43.7237418, -79.5909711),
+(43.854538, -79.085639),
+(43.7021095, -79.3613924),
+(43.666981, -79.388889),
+(43.8243568, -79.425407),
+(43.660852, -79.378702),
+(43.6047906, -79.6539646),
+(43.8822225, -79.4467291),
+(43.669616, -79.362936),
+(43.795887, -79.427048),
+(43.4026909, -79.7886819),
+(43.665786, -79.3855809),
+(43.4936455, -79.8509726),
+(43.5530533, -79.7119838),
+(43.642311, -79.38123),
+(43.6962004, -79.4564973),
+(43.8638834, -79.4876821),
+(43.6282636, -79.7185074),
+(43.7002992, -79.4057381),
+(43.643646, -79.41366),
+(43.7752161, -79.4133461),
+(43.827088, -79.09158),
+(43.9640419, -78.8910352),
+(43.887882, -79.457709),
+(43.707669, -79.392307),
+(43.7509481, -79.3963191),
+(43.8224469, -79.3794106),
+(43.8660761, -79.4183555),
+(43.7513487, -79.7324727),
+(43.6887702, -79.3480164),
+(43.639182, -79.3863917),
+(43.6556663, -79.4682434),
+(43.7691576, -79.3688621),
+(43.7941787, -79.4397985),
+(43.4592051, -79.7043602),
+(43.6428617, -79.3769141),
+(43.6887191, -79.3362983),
+(43.7401115, -79.4350833),
+(43.6630843, -79.7861039),
+(43.8052004, -79.1184585),
+(43.667982, -79.774253),
+(43.6991375, -79.4139477),
+(43.6589508, -79.4031219),
+(43.86422, -79.241216),
+(43.8602621, -79.0889991),
+(43.775424, -79.337482),
+(43.849312, -79.4981895),
+(43.7634384, -79.7670567),
+(43.680803, -79.842706),
+(43.7150009, -79.7030503),
+(43.5818543, -79.7687821),
+(43.7347428, -79.7855892),
+(43.5095764, -79.6259364),
+(43.6522603, -79.3688137),
+(43.6832031, -79.4442184),
+(43.641061, -79.415743),
+(43.6835201, -79.8263357),
+(43.6194134, -79.5855458),
+(43.7136434, -79.4019823),
+(43.714348, -79.353145),
+(43.8968172, -79.2729394),
+(43.5365652, -79.7334103),
+(43.49418, -79.715921),
+(43.8908271, -79.2316854),
+(43.632676, -79.91857),
+(43.3485431, -79.8872274),
+(43.748274, -79.830798),
+(43.4281357, -79.7645243),
+(43.7227157, -79.3798235),
+(43.439235, -79.749341),
+(43.7969279, -79.4782679),
+(43.4199431, -79.7078458),
+(43.7568224, -79.8309587),
+(43.5697857, -79.5950768),
+(43.9126645, -78.8880464),
+(43.8488187, -79.473713),
+(43.8765906, -79.0955138),
+(43.9399361, -79.457438),
+(43.8098535, -79.6014561),
+(43.5086788, -79.8361171),
+(43.5216835, -79.8506581),
+(43.677266, -79.406148),
+(43.6400585, -79.5813557),
+(43.4961809, -79.6993532),
+(43.4856798, -79.8649357),
+(43.642832, -79.376093),
+(43.3472456, -79.8340848),
+(43.5675468, -79.7643027),
+(43.6763153, -79.4142677),
+(43.6411375, -79.4007447),
+(43.6514007, -79.5670712),
+(43.757229, -79.927657),
+(43.6454146, -79.387361),
+(43.7538531, -79.3678581),
+(43.64787, -79.391138),
+(43.643119, -79.375786),
+(43.6489, -79.392205),
+(43.6432587, -79.3764035),
+(43.6843064, -79.4112596),
+(43.641596, -79.423443),
+(43.6391628, -79.4191891),
+(43.8659, -79.4342),
+(43.877861, -79.233529),
+(43.5901692, -79.6487568),
+(43.7449753, -79.5873402),
+(43.6763281, -79.5557986),
+(43.7135519, -79.7901328),
+(43.623821, -79.478728),
+(43.728742, -79.482576),
+(43.5498299, -79.7205978),
+(43.5870065, -79.6405748),
+(43.586757, -79.642403),
+(43.624995, -79.479045),
+(43.647226, -79.3911),
+(43.6411375, -79.4007447),
+(43.7451437, -79.8050688),
+(43.837642, -79.234013),
+(43.8560115, -79.3454051),
+(43.7142438, -79.3716621),
+(43.6596665, -79.3859262),
+(43.8963674, -79.2656414),
+(43.7818804, -79.246391),
+(43.6411375, -79.4007447),
+(43.6718462, -79.3726355),
+(43.6881075, -79.3979987),
+(43.685894, -79.414559),
+(43.9292202, -79.5197774),
+(43.880007, -78.9470541),
+(43.753799, -79.42058),
+(43.4487233, -79.7091882),
+(43.8610137, -79.5416193),
+(43.6659594, -79.5042526),
+(43.7947112, -79.3948478),
+(43.8297595, -79.4228743),
+(43.8498825, -79.4456304),
+(43.4285, -79.7445),
+(43.4285, -79.7445),
+(43.6426375, -79.5588279),
+(43.8550138, -79.0756376),
+(43.7513009, -79.4599333),
+(43.6400003, -79.3788904),
+(43.641785, -79.379006),
+(43.7540741, -79.1854951),
+(43.638395, -79.445621),
+(43.537293, -79.861023),
+(43.9005502, -79.4385402),
+(43.636608, -79.4048673),
+(43.844821, -79.47783),
+(43.7254632, -79.4178342),
+(43.7024743, -79.2880175),
+(43.9393314, -79.4388096),
+(43.6411464, -79.3830668),
+(43.69064, -79.468887),
+(43.8689674, -78.9390784),
+(43.671294, -79.4660847),
+(43.6857104, -79.4574687),
+(43.4624613, -79.7321981),
+(43.555075, -79.583315),
+(43.7504576, -79.3703719),
+(43.7053515, -79.2566693),
+(43.7026585, -79.4125914),
+(43.766764, -79.385993),
+(43.9168167, -79.0227272),
+(43.6347636, -79.7842752),
+(43.7077584, -79.2985845),
+(43.6847858, -79.8034797),
+(43.7652774, -79.4414567),
+(43.9507308, -79.4753453),
+(43.641874, -79.428807),
+(43.776323, -79.335927),
+(43.760712, -79.308462),
+(43.881674, -79.225092),
+(43.578326, -79.568799),
+(43.8578063, -79.4050167),
+(43.4315123, -79.7356158),
+(43.6569143, -79.4696057),
+(43.8218854, -79.4243456),
+(43.8218854, -79.4243456),
+(43.641087, -79.381405),
+(43.6241314, -79.4880676),
+(43.5626229, -79.660245),
+(43.6217109, -79.5901086),
+(43.4787449, -79.7280526),
+(43.389039, -79.918501),
+(43.618754, -79.485361),
+(43.7859764, -79.1975731),
+(43.7695626, -79.4134466),
+(43.7020483, -79.777884),
+(43.7685129, -79.3980547),
+(43.864281, -79.5248876),
+(43.7344681, -79.4463751),
+(43.49533, -79.70968),
+(43.667647, -79.320093),
+(43.685112, -79.292251),
+(43.970586, -79.244284),
+(43.738443, -79.554863),
+(43.9818014, -79.4448434),
+(43.873542, -79.752497),
+(43.4946113, -79.8722506),
+(43.8329566, -79.4613353),
+(43.8097257, -79.400257),
+(43.7004809, -79.4135574),
+(43.7128328, -79.3840418),
+(43.899991, -79.4676656),
+(43.7299934, -79.3379531),
+(43.8983475, -79.2483376),
+(43.8002298, -79.4069948),
+(43.5130706, -79.6255887),
+(43.4792503, -79.6341654),
+(43.6966073, -79.2942117),
+(43.867248, -79.034061),
+(43.4883051, -79.7294514),
+(43.706203, -79.391923),
+(43.9688411, -79.1341957),
+(43.4796022, -79.7190726),
+(43.4955221, -79.8776156),
+(43.798975, -79.4912457),
+(43.8573, -79.0125),
+(43.954029, -79.43508),
+(43.5990787, -79.6357516),
+(43.727437, -79.6244644),
+(43.8318151, -79.6560267),
+(43.596018, -79.7770721),
+(43.6882528, -79.8138968),
+(43.9553355, -78.8812312),
+(43.6871437, -79.5064656),
+(43.7253062, -79.2202052),
+(43.6854103, -79.4776903),
+(43.4930074, -79.8711913),
+(43.6939285, -79.3715662),
+(43.8206522, -79.4599303),
+(43.6758727, -79.5068983),
+(43.6625376, -79.4178414),
+(43.7380669, -79.5468058),
+(43.6693046, -79.3821176),
+(43.6516507, -79.7293393),
+(43.9508243, -78.896716),
+(43.838645, -79.008155),
+(43.6917265, -79.270644),
+(43.4882947, -79.9345586),
+(43.9025405, -79.3756777),
+(43.8885825, -79.2311096),
+(43.6484128, -79.3764665),
+(43.669949, -79.387053),
+(43.6426507, -79.3773286),
+(43.6429141, -79.3806469),
+(43.7563401, -79.4056932),
+(43.6403108, -79.3908593),
+(43.7667335, -79.3714173),
+(43.790566, -79.356238),
+(43.661896, -79.385748),
+(43.6784907, -79.2988979),
+(43.7571089, -79.4242865),
+(43.7800398, -79.4126795),
+(43.839126, -79.38984),
+(43.8614279, -79.4364729),
+(43.5967244, -79.7061441),
+(43.5621506, -79.572398),
+(43.62372, -79.483703),
+(43.862021, -79.505245),
+(43.4860233, -79.7088979),
+(43.5859122, -79.6472023),
+(43.902498, -79.408493),
+(43.5393331, -79.6714769),
+(43.849716, -79.329883),
+(43.668564, -79.395458),
+(43.7840829, -79.4326294),
+(43.749969, -79.406135),
+(43.7107994, -79.4387081),
+(43.6562206, -79.397564),
+(43.7506494, -79.464186),
+(43.5364306, -79.6333995),
+(43.630022, -79.5715834),
+(43.670587, -79.380609),
+(43.5119, -79.6299),
+(43.884977, -78.926993),
+(43.8436675, -79.0026174),
+(43.6440617, -79.3894386),
+(43.4694437, -79.7092667),
+(43.374053, -79.721288),
+(43.753813, -79.445853),
+(43.652757, -79.366511),
+(43.8485336, -79.4327552),
+(43.665928, -79.383465),
+(43.6166043, -79.6460477),
+(43.8798261, -79.2379761),
+(43.6728056, -79.383906),
+(43.861153, -79.230921),
+(43.648858, -79.378145),
+(43.7029302, -79.4510511),
+(43.9470092, -79.4828757),
+(43.6601379, -79.34308),
+(43.477811, -79.722557),
+(43.491265, -79.706617),
+(43.669963, -79.386158),
+(43.4739547, -79.6549593),
+(43.8031152, -79.420837),
+(43.761564, -79.1970846),
+(43.7559127, -79.4155951),
+(43.810029, -79.130792),
+(43.6460991, -79.3651362),
+(43.8942413, -79.2571072),
+(43.3779582, -79.7304443),
+(43.6692764, -79.4839308),
+(43.4460732, -79.7637167),
+(43.7670761, -79.3840091),
+(43.6400003, -79.3788904),
+(43.858232, -78.931245),
+(43.7780601, -79.4166733),
+(43.7244955, -79.2517684),
+(43.7479634, -79.5052909),
+(43.7978348, -79.6722328),
+(43.6732968, -79.5276373),
+(43.5582954, -79.5913411),
+(43.7070855, -79.3645091),
+(43.666815, -79.3875312),
+(43.649381, -79.374532),
+(43.6920523, -79.4386282),
+(43.8793, -79.497),
+(43.799944, -79.458072),
+(43.63641, -79.402003),
+(43.6400887, -79.3939574),
+(43.760906, -79.4090028),
+(43.8943765, -79.012863),
+(43.7499299, -79.2581923),
+(43.7817207, -79.2473686),
+(43.8074249, -79.2243419),
+(43.724239, -79.40255),
+(43.910763, -79.604381),
+(43.8498016, -78.9900742),
+(43.669963, -79.386158),
+(43.6457578, -79.3946255),
+(43.6668981, -79.3890419),
+(43.641966, -79.38174),
+(43.6405065, -79.3969011),
+(43.6696, -79.317574),
+(43.761994, -79.419996),
+(43.6593726, -79.3825439),
+(43.6460991, -79.3651362),
+(43.6530828, -79.3900382),
+(43.7590161, -79.455548),
+(43.7029302, -79.4510511),
+(43.811106, -79.102584),
+(43.6866509, -79.3995111),
+(43.661132, -79.385711),
+(43.7028015, -79.4513882),
+(43.7830199, -79.4169093),
+(43.5897545, -79.6480938),
+(43.7070855, -79.3977452),
+(43.6774, -79.511229),
+(43.8894707, -79.2208396),
+(43.656537, -79.354497),
+(43.447167, -79.687053),
+(43.8828078, -79.8523809),
+(43.7454933, -79.7497385),
+(43.6877888, -79.8505818),
+(43.684681, -79.853092),
+(43.9819302, -79.4839831),
+(43.7020147, -79.7680873),
+(43.435663, -79.775606),
+(43.665753, -79.385579),
+(43.707669, -79.392307),
+(43.665753, -79.385579),
+(43.7331301, -79.4074003),
+(43.637218, -79.396666),
+(43.7760789, -79.4027233),
+(43.6917934, -79.436255),
+(43.6461536, -79.3752086),
+(43.8736223, -79.3036829),
+(43.894897, -79.052424),
+(43.831402, -79.032261),
+(43.84972, -79.43554),
+(43.623821, -79.478728),
+(43.783315, -79.578869),
+(43.894484, -79.2560691),
+(43.7058899, -79.2575238),
+(43.520078, -79.620473),
+(43.401812, -79.7041148),
+(43.5020011, -79.9100355),
+(43.4703666, -79.7059208),
+(43.8459683, -79.4577706),
+(43.7571856, -79.3865123),
+(43.7574997, -79.3728957),
+(43.7919893, -79.7456921),
+(43.6668981, -79.3890419),
+(43.4024206, -79.7824283),
+(43.3640429, -79.7612498),
+(43.4132172, -79.7004303),
+(43.7610884, -79.302581),
+(43.759376, -79.78495),
+(43.7437408, -79.3623011),
+(43.8138858, -79.4629291),
+(43.9411688, -79.0287261),
+(43.876717, -79.260768),
+(43.5335436, -79.6243111),
+(43.8381939, -79.646073),
+(43.8391029, -79.2490908),
+(43.3992143, -79.7077282),
+(43.639185, -79.399974),
+(43.7090836, -79.645422),
+(43.8497821, -79.3288246),
+(43.7772997, -79.336725),
+(43.7841279, -79.5877542),
+(43.7904691, -79.4520964),
+(43.821686, -79.099565),
+(43.7617207, -79.782513),
+(43.7251286, -79.4411101),
+(43.858252, -79.433621),
+(43.9257163, -78.9631783),
+(43.7767049, -79.4110639),
+(43.7402172, -79.391444),
+(43.641621, -79.371728),
+(43.399397, -79.7083418),
+(43.7673848, -79.4247689),
+(43.4375965, -79.685548),
+(43.4502742, -79.6578324),
+(43.7086965, -79.3967069),
+(43.5856554, -79.7814221),
+(43.7955816, -79.5202249),
+(43.737867, -79.476491),
+(43.8117, -79.6306),
+(43.636346, -79.404535),
+(43.4378858, -79.7642479),
+(43.5668055, -79.5776466),
+(43.89416, -79.40404),
+(43.6912708, -79.3558969),
+(43.8604001, -79.3219361),
+(43.8583306, -79.4636638),
+(43.6595018, -79.3847849),
+(43.4608543, -79.7125647),
+(43.5800439, -79.6250026),
+(43.7113275, -79.4820895),
+(43.669593, -79.38058),
+(43.707083, -79.397771),
+(43.7178261, -79.768715),
+(43.838536, -79.549747),
+(43.625545, -79.478746),
+(43.4402987, -79.7324483),
+(43.885545, -79.457283),
+(43.5881063, -79.6481333),
+(43.6594036, -79.3611785),
+(43.7713233, -79.6886739),
+(43.7383538, -79.5858853),
+(43.652778, -79.367763),
+(43.647513, -79.392702),
+(43.954328, -79.4834859),
+(43.7907822, -79.3561322),
+(43.8697591, -79.4340578),
+(43.9375008, -79.4755271),
+(43.7563401, -79.4056932),
+(43.5411532, -79.5999709),
+(43.8821513, -79.4661792),
+(43.7384183, -79.2439895),
+(43.6400086, -79.3820669),
+(43.8929507, -79.2339316),
+(43.9554909, -78.9491944),
+(43.9685831, -79.4668152),
+(43.666815, -79.3875312),
+(43.7653441, -79.4126151),
+(43.9036033, -79.4533404),
+(43.878211, -79.434702),
+(43.4401816, -79.7663147),
+(43.6406816, -79.376999),
+(43.7206115, -79.7612123),
+(43.7070855, -79.3977452),
+(43.8769359, -79.0845182),
+(43.8790112, -79.2754371),
+(43.469232, -79.719796),
+(43.783532, -79.7079935),
+(43.7520632, -79.4642668),
+(43.5800439, -79.6250026),
+(43.621064, -79.48332),
+(43.7734808, -79.2544987),
+(43.447167, -79.687053),
+(43.7259478, -79.4054076),
+(43.53269, -79.86191),
+(43.9599579, -79.5635666),
+(43.412659, -79.80078),
+(43.648858, -79.378145),
+(43.7264112, -79.5815861),
+(43.959403, -78.898438),
+(43.5344013, -79.6935019),
+(43.6780622, -79.5503111),
+(43.8299, -79.0846),
+(43.7290218, -79.5227173),
+(43.5653099, -79.7488403),
+(43.788704, -79.30225),
+(43.6429981, -79.5125854),
+(43.885529, -79.265213),
+(43.8827863, -79.245893),
+(43.732027, -79.635423),
+(43.6501, -79.9175),
+(43.9099781, -78.8836004),
+(43.6755754, -79.2885285),
+(43.637411, -79.418479),
+(43.7665037, -79.4110937),
+(43.7397751, -79.4245618),
+(43.9714943, -78.9406597),
+(43.6422746, -79.3727039),
+(43.7630277, -79.3208032),
+(43.6926208, -79.2968814),
+(43.7813866, -79.3131748),
+(43.7837208, -79.3959666),
+(43.7424837, -79.1979595),
+(43.6851041, -79.3133756),
+(43.784452, -79.209007),
+(43.7199811, -79.2614574),
+(43.8090107, -79.2597155),
+(43.8907881, -79.4275446),
+(43.9155296, -79.4735598),
+(43.9306343, -79.4736),
+(43.851671, -79.4921889),
+(43.7785895, -79.2833814),
+(43.8100647, -79.4487117),
+(43.8066212, -79.4171274),
+(43.9781975, -79.4793106),
+(43.829214, -79.012976),
+(43.7894812, -79.4832979),
+(43.672986, -79.387029),
+(43.868562, -79.2129947),
+(43.8995273, -78.8803312),
+(43.686315, -79.514191),
+(43.8584331, -79.436549),
+(43.7330081, -79.4322322),
+(43.6180595, -79.5906044),
+(43.4954368, -79.8819613),
+(43.541405, -79.6971703),
+(43.6249491, -79.4782438),
+(43.6984664, -79.8568167),
+(43.6552131, -79.5264164),
+(43.4837347, -79.6915673),
+(43.6416067, -79.580345),
+(43.6405502, -79.4291015),
+(43.6638633, -79.4726844),
+(43.5235452, -79.6879939),
+(43.748687, -79.784893),
+(43.6948112, -79.775919),
+(43.7365674, -79.7244332),
+(43.8951754, -79.7413417),
+(43.776244, -79.744432),
+(43.8741642, -79.3062241),
+(43.7538031, -79.8172648),
+(43.5949957, -79.7779361),
+(43.7078997, -79.4192464),
+(43.7487547, -79.2701126),
+(43.9382031, -79.4612908),
+(43.8708839, -79.5612133),
+(43.6722959, -79.3060326),
+(43.68065, -79.2871653),
+(43.9499976, -78.8954392),
+(43.666981, -79.388889),
+(43.661299, -79.346677),
+(43.9010319, -79.2693471),
+(43.771574, -79.2105057),
+(43.9101693, -79.44595),
+(43.8278609, -79.3210084),
+(43.8714691, -79.5189553),
+(43.3579805, -79.8408894),
+(43.5311052, -79.6338725),
+(43.8937683, -79.4083695),
+(43.7022079, -79.8367995),
+(43.835408, -79.01368),
+(43.693629, -79.280023),
+(43.7364705, -79.4652097),
+(43.8751938, -79.8619279),
+(43.636057, -79.470781),
+(43.7604326, -79.6902569),
+(43.6241314, -79.4880676),
+(43.697382, -79.527707),
+(43.7341066, -79.7991446),
+(43.7863536, -79.8138239),
+(43.9098495, -79.4698839),
+(43.6702658, -79.3943539),
+(43.672274, -79.3804573),
+(43.8976031, -79.2573859),
+(43.6836509, -79.8507267),
+(43.9574824, -78.9023192),
+(43.690914, -79.5016358),
+(43.8603434, -79.4324218),
+(43.866886, -79.04877),
+(43.9023506, -79.2305343),
+(43.7859764, -79.1975731),
+(43.960265, -78.9479255),
+(43.6799337, -79.3881653),
+(43.646357, -79.937671),
+(43.861726, -79.435841),
+(43.3734067, -79.8432718),
+(43.3427537, -79.8439704),
+(43.638323, -79.878839),
+(43.377116, -79.7240949),
+(43.638941, -79.918858),
+(43.9454408, -78.8896149),
+(43.3949364, -79.8125528),
+(43.651643, -79.919946),
+(43.4285013, -79.763019),
+(43.4045145, -79.708475),
+(43.5270801, -79.8475522),
+(43.5055628, -79.8834785),
+(43.6667966, -79.3886683),
+(43.6548441, -79.3846797),
+(43.7722925, -79.4122663),
+(43.7658523, -79.3827681),
+(43.68901, -79.400005),
+(43.665666, -79.382607),
+(43.668718, -79.383996),
+(43.9718642, -78.9484889),
+(43.9556477, -79.253057),
+(43.648317, -79.393065),
+(43.5608565, -79.7237495),
+(43.592274, -79.634311),
+(43.790879, -79.678408),
+(43.4010629, -79.8281382),
+(43.9720159, -79.5492901),
+(43.8863787, -79.4486714),
+(43.88251, -79.225774),
+(43.8561605, -79.4491674),
+(43.881727, -79.493037),
+(43.970816, -79.249128),
+(43.970816, -79.249128),
+(43.8578603, -79.4584595),
+(43.494461, -79.715995),
+(43.9702577, -79.2674595),
+(43.6855327, -79.7692415),
+(43.4426282, -79.6723596),
+(43.643485, -79.392304),
+(43.645505, -79.392457),
+(43.5478948, -79.6230785),
+(43.762465, -79.363837),
+(43.8376015, -79.391158),
+(43.7364656, -79.3558221),
+(43.668136, -79.300964),
+(43.8924585, -79.0232355),
+(43.848894, -79.4886946),
+(43.4195805, -79.7126929),
+(43.8102603, -79.4659951),
+(43.7180212, -79.3768816),
+(43.7196684, -79.5289182),
+(43.9322029, -79.4732445),
+(43.5925251, -79.6712504),
+(43.4337146, -79.7773103),
+(43.8928419, -78.9069009),
+(43.7149614, -79.4412442),
+(43.9817268, -79.4439623),
+(43.697049, -79.394156),
+(43.9483385, -79.4694808),
+(43.982267, -79.375247),
+(43.5847029, -79.6415724),
+(43.7773884, -79.3598905),
+(43.7114613, -79.4627272),
+(43.665352, -79.340268),
+(43.7471525, -79.3994228),
+(43.902485, -79.253581),
+(43.7502921, -79.3953702),
+(43.6423457, -79.6005261),
+(43.7752913, -79.3907695),
+(43.8091676, -79.455206),
+(43.5894255, -79.6733391),
+(43.68752, -79.849596),
+(43.4813689, -79.8752693),
+(43.7767049, -79.4110639),
+(43.59115, -79.635605),
+(43.875256, -79.2315162),
+(43.7413588, -79.446903),
+(43.717144, -79.393375),
+(43.6699365, -79.4880524),
+(43.9727906, -78.9515541),
+(43.7455389, -79.4200569),
+(43.345915, -79.881456),
+(43.641785, -79.379006),
+(43.4966739, -79.7073167),
+(43.8920038, -78.914248),
+(43.9530349, -79.4818745),
+(43.7653413, -79.3825659),
+(43.88104, -79.2535668),
+(43.9721976, -78.9435309),
+(43.8097996, -79.1317796),
+(43.6296866, -79.5044362),
+(43.883946, -79.748912),
+(43.669963, -79.386158),
+(43.8339392, -79.4867058),
+(43.7425676, -79.3720558),
+(43.7949339, -79.4088358),
+(43.6570037, -79.3552254),
+(43.795371, -79.406699),
+(43.694252, -79.8600068),
+(43.8094847, -79.4414529),
+(43.7522495, -79.8409628),
+(43.858607, -79.411205),
+(43.8842833, -78.9276157),
+(43.815689, -79.361582),
+(43.9233503, -78.9445542),
+(43.8990721, -78.9704535),
+(43.9577084, -78.8845995),
+(43.714131, -79.454068),
+(43.5568579, -79.703596),
+(43.71507, -79.453301),
+(43.763578, -79.5676023),
+(43.847084, -79.3001003),
+(43.8204383, -79.3959561),
+(43.7783281, -79.6694345),
+(43.723197, -79.410398),
+(43.7019362, -79.3027284),
+(43.955549, -78.8791075),
+(43.7590555, -79.7632992),
+(43.67032, -79.394433),
+(43.6910577, -79.388667),
+(43.7195087, -79.3377222),
+(43.731643, -79.421088),
+(43.6916838, -79.2999451),
+(43.6446881, -79.412696),
+(43.594882, -79.634317),
+(43.8466574, -79.2623974),
+(43.6568586, -79.3836642),
+(43.8896612, -79.0284716),
+(43.7924932, -79.3700577),
+(43.6653712, -79.3403815),
+(43.7395147, -79.4349468),
+(43.358339, -79.813697),
+(43.634183, -79.51831),
+(43.8136714, -79.4590754),
+(43.6694507, -79.3111551),
+(43.4491708, -79.7461798),
+(43.8788055, -79.393017),
+(43.954117, -79.461306),
+(43.65056, -79.382072),
+(43.682004, -79.843868),
+(43.893368, -79.4101722),
+(43.5910365, -79.532819),
+(43.613379, -79.498167),
+(43.646819, -79.7744583),
+(43.4346014, -79.6846366),
+(43.7220508, -79.7154215),
+(43.518299, -79.877404),
+(43.764193, -79.412758),
+(43.7375378, -79.3550858),
+(43.480917, -79.716089),
+(43.833236, -79.530936),
+(43.7237215, -79.3253732),
+(43.7367513, -79.4767442),
+(43.7143156, -79.2444201),
+(43.6461536, -79.3752086),
+(43.768844, -79.4414432),
+(43.643909, -79.407624),
+(43.7672943, -79.2530972),
+(43.652331, -79.356014),
+(43.7031445, -79.2993449),
+(43.7109739, -79.6498789),
+(43.835992, -79.554132),
+(43.6161559, -79.5966193),
+(43.675972, -79.826307),
+(43.874616, -79.759178),
+(43.6954498, -79.5166627),
+(43.5393625, -79.6973565),
+(43.6048372, -79.651399),
+(43.9803147, -79.4872586),
+(43.8994457, -78.9082468),
+(43.780841, -79.4143832),
+(43.6528622, -79.5276233),
+(43.482, -79.873427),
+(43.6647658, -79.3840887),
+(43.3707846, -79.7650455),
+(43.9086157, -79.4726382),
+(43.7123485, -79.8103681),
+(43.5237772, -79.6365871),
+(43.8633835, -79.4332961),
+(43.8847414, -79.457227),
+(43.8397762, -79.0826967),
+(43.5841052, -79.5780509),
+(43.828489, -79.474585),
+(43.791836, -79.237404),
+(43.560454, -79.737688),
+(43.88897, -79.445262),
+(43.8896663, -79.5154732),
+(43.902498, -79.408493),
+(43.8767977, -79.4460509),
+(43.7675029, -79.4102384),
+(43.7583518, -79.3379271),
+(43.6767474, -79.3272623),
+(43.7733999, -79.2558617),
+(43.714455, -79.454411),
+(43.6040446, -79.6523817),
+(43.7300903, -79.3322682),
+(43.774071, -79.329324),
+(43.757354, -79.409358),
+(43.916593, -79.028641),
+(43.6384589, -79.4140635),
+(43.6383599, -79.398657),
+(43.6373148, -79.4029448),
+(43.7695665, -79.4299601),
+(43.8428417, -79.4262012),
+(43.6853242, -79.3300402),
+(43.8372873, -79.4328447),
+(43.9167327, -79.4714942),
+(43.5731495, -79.7807806),
+(43.862641, -79.4494707),
+(43.8030041, -79.3023529),
+(43.7311638, -79.4616848),
+(43.6255085, -79.5237781),
+(43.668799, -79.448242),
+(43.4224924, -79.7700047),
+(43.36088, -79.7535725),
+(43.412093, -79.801236),
+(43.3616832, -79.7632755),
+(43.3945132, -79.7880299),
+(43.6420853, -79.5089923),
+(43.8437361, -79.5330338),
+(43.757855, -79.416974),
+(43.8317976, -79.0130653),
+(43.631384, -79.882047),
+(43.79615, -79.3539499),
+(43.661781, -79.384218),
+(43.7363075, -79.4310648),
+(43.6691837, -79.3873558),
+(43.6667966, -79.3886683),
+(43.7354841, -79.4123003),
+(43.646098, -79.376055),
+(43.701127, -79.396602),
+(43.7692665, -79.4113412),
+(43.7747358, -79.3886501),
+(43.665666, -79.382607),
+(43.6424403, -79.3721255),
+(43.773403, -79.344636),
+(43.7382111, -79.4264502),
+(43.7639679, -79.2032065),
+(43.7778723, -79.2807506),
+(43.690647, -79.3055448),
+(43.6518519, -79.3690555),
+(43.7125007, -79.3835202),
+(43.8049638, -79.2281848),
+(43.9621913, -78.9419574),
+(43.9207229, -78.9254753),
+(43.8872876, -78.9401018),
+(43.6952602, -79.3064481),
+(43.822918, -79.018807),
+(43.8050219, -79.2047035),
+(43.516021, -79.883669),
+(43.8527461, -79.3386798),
+(43.890146, -79.5165327),
+(43.851993, -79.43178),
+(43.818515, -79.6281933),
+(43.9538658, -79.4364712),
+(43.7270473, -79.7190642),
+(43.5962963, -79.5351088),
+(43.9703332, -79.2539406),
+(43.869442, -79.467557),
+(43.5164289, -79.8433177),
+(43.5662907, -79.5790302),
+(43.594882, -79.634317),
+(43.3427096, -79.8360708),
+(43.5844788, -79.5925967),
+(43.4202302, -79.7069652),
+(43.7283118, -79.7997846),
+(43.6952107, -79.5508744),
+(43.5247855, -79.8346688),
+(43.5223216, -79.8459005),
+(43.6801416, -79.7602669),
+(43.708017, -79.838369),
+(43.5883573, -79.6395201),
+(43.4333305, -79.7680473),
+(43.690746, -79.836342),
+(43.7549938, -79.7515108),
+(43.6978167, -79.4318968),
+(43.652572, -79.363722),
+(43.6726027, -79.3953805),
+(43.643188, -79.422494),
+(43.652778, -79.367763),
+(43.679437, -79.382305),
+(43.7884853, -79.3656891),
+(43.6668231, -79.3354103),
+(43.6647658, -79.3840887),
+(43.7639679, -79.2032065),
+(43.6458819, -79.3973406),
+(43.773403, -79.344636),
+(43.678249, -79.350679),
+(43.8140635, -79.2729459),
+(43.9027017, -78.9462413),
+(43.7828222, -79.3240163),
+(43.9457549, -79.4578658),
+(43.8323441, -79.5078179),
+(43.9602045, -78.9685078),
+(43.8519231, -79.4206137),
+(43.881674, -79.225092),
+(43.621064, -79.48332),
+(43.6615344, -79.5696699),
+(43.3417679, -79.7685068),
+(43.7260965, -79.4796815),
+(43.62372, -79.483703),
+(43.6867701, -79.8112274),
+(43.644402, -79.5286903),
+(43.5751848, -79.5636178),
+(43.4923625, -79.8438002),
+(43.7568248, -79.8175389),
+(43.623782, -79.488316),
+(43.5870065, -79.6405748),
+(43.447443, -79.686948),
+(43.6701539, -79.4993079),
+(43.5168921, -79.8472089),
+(43.4910274, -79.8624972),
+(43.5996695, -79.7199904),
+(43.6958036, -79.3347898),
+(43.757992, -79.4438034),
+(43.9818663, -79.4530233),
+(43.4369661, -79.7660594),
+(43.972717, -79.827587),
+(43.6689844, -79.3055637),
+(43.8212472, -79.5631421),
+(43.8480897, -79.4698251),
+(43.6797019, -79.3131682),
+(43.789348, -79.172379),
+(43.839792, -79.093703),
+(43.665106, -79.462516),
+(43.8999027, -79.2486534),
+(43.7091099, -79.242444),
+(43.8104895, -79.325254),
+(43.764598, -79.3882301),
+(43.8155586, -79.934547),
+(43.9012655, -79.300659),
+(43.842605, -79.4368631),
+(43.7182375, -79.8259504),
+(43.7265442, -79.3818775),
+(43.982267, -79.375247),
+(43.894755, -79.660619),
+(43.646098, -79.376055),
+(43.6642271, -79.788926),
+(43.4922466, -79.7213623),
+(43.8777152, -79.8646006),
+(43.4769, -79.6517),
+(43.7148225, -79.2379386),
+(43.6544986, -79.3631573),
+(43.4279059, -79.7641856),
+(43.8451068, -79.4074817),
+(43.6954772, -79.4736871),
+(43.8494694, -79.2545783),
+(43.7221446, -79.2270277),
+(43.477745, -79.7184002),
+(43.4758573, -79.6445719),
+(43.7198983, -79.6259583),
+(43.626138, -79.477339),
+(43.6333519, -79.7799487),
+(43.7101924, -79.2768435),
+(43.646098, -79.376055),
+(43.773876, -79.2525897),
+(43.8531634, -79.1085135),
+(43.67032, -79.394433),
+(43.9607952, -78.9389387),
+(43.810167, -79.221126),
+(43.646084, -79.365012),
+(43.6398182, -79.3836155),
+(43.6290551, -79.7700167),
+(43.79131, -79.2169322),
+(43.7740006, -79.1786995),
+(43.89611, -79.2227211),
+(43.95819, -79.470279),
+(43.778008, -79.4527173),
+(43.4820031, -79.7067336),
+(43.6883218, -79.2785613),
+(43.9225231, -79.533183),
+(43.7734661, -79.2050947),
+(43.435599, -79.7726),
+(43.8961439, -79.4236917),
+(43.5587749, -79.6063047),
+(43.8934612, -79.2339902),
+(43.5643788, -79.5999606),
+(43.823404, -79.086648),
+(43.3791169, -79.7550947),
+(43.7012116, -79.7683787),
+(43.6147163, -79.5072187),
+(43.4918877, -79.6851841),
+(43.8931825, -79.0191131),
+(43.6842723, -79.339358),
+(43.9449698, -79.4480479),
+(43.833606, -79.647154),
+(43.8467258, -79.490679),
+(43.669593, -79.38058),
+(43.8298358, -79.3731894),
+(43.57814, -79.5942517),
+(43.946999, -79.827848),
+(43.648909, -79.386075),
+(43.6947301, -79.7975971),
+(43.7222609, -79.5838342),
+(43.7070403, -79.3209742),
+(43.8569705, -79.4801523),
+(43.819558, -79.022058),
+(43.9151082, -78.9760958),
+(43.78995, -79.546154),
+(43.7825286, -79.3418461),
+(43.7562359, -79.7735897),
+(43.7126526, -79.7558009),
+(43.6668104, -79.4199661),
+(43.9554937, -79.4832927),
+(43.6616536, -79.4244404),
+(43.9068943, -79.4406035),
+(43.672989, -79.4587839),
+(43.7987669, -79.6792904),
+(43.6458247, -79.5333982),
+(43.4765364, -79.6867947),
+(43.9668921, -79.4363811),
+(43.8988371, -79.4525188),
+(43.645402, -79.431818),
+(43.646499, -79.893766),
+(43.6626765, -79.4502034),
+(43.6501177, -79.3579349),
+(43.7901063, -79.4102927),
+(43.6677676, -79.3808901),
+(43.8782772, -79.0929854),
+(43.950415, -78.8934721),
+(43.69976, -79.518492),
+(43.8888025, -79.3119028),
+(43.8783144, -79.865126),
+(43.5669962, -79.6048521),
+(43.6891129, -79.4966567),
+(43.6378846, -79.756336),
+(43.744754, -79.220024),
+(43.6505055, -79.7335471),
+(43.733212, -79.5357687),
+(43.8125713, -79.2088054),
+(43.659421, -79.361025),
+(43.539441, -79.6276076),
+(43.526368, -79.846905),
+(43.7677262, -79.3706232),
+(43.6442354, -79.5496549),
+(43.590821, -79.632008),
+(43.7934384, -79.1580284),
+(43.5589237, -79.5931412),
+(43.6740536, -79.7884432),
+(43.881674, -79.225092),
+(43.6399005, -79.3905103),
+(43.4831781, -79.6463187),
+(43.5097612, -79.8570569),
+(43.869083, -79.062189),
+(43.676739, -79.840932),
+(43.6298722, -79.874685),
+(43.646744, -79.7765),
+(43.8793, -79.497),
+(43.7048104, -79.3754804),
+(43.8610201, -79.5048112),
+(43.5283457, -79.8722088),
+(43.796161, -79.605003),
+(43.6357531, -79.5583607),
+(43.668718, -79.383996),
+(43.6370159, -79.4055772),
+(43.8406266, -79.4761388),
+(43.5850975, -79.6461541),
+(43.8881614, -79.2517614),
+(43.5736606, -79.7421471),
+(43.5578908, -79.5968329),
+(43.4916329, -79.7126199),
+(43.9018569, -79.2619175),
+(43.7510166, -79.4585669),
+(43.7312268, -79.2128747),
+(43.8455445, -79.566648),
+(43.7281765, -79.4978013),
+(43.6051968, -79.4916684),
+(43.8397762, -79.0826967),
+(43.529165, -79.6020218),
+(43.9720517, -78.9459018),
+(43.8607977, -79.4611385),
+(43.835555, -79.143787),
+(43.9383711, -78.8772558),
+(43.4720976, -79.7380337),
+(43.8299, -79.0846),
+(43.9205389, -79.4517833),
+(43.614551, -79.625756),
+(43.7875625, -79.2817031),
+(43.547418, -79.606736),
+(43.7576116, -79.3993409),
+(43.8065647, -79.5567363),
+(43.7676486, -79.3821279),
+(43.3456036, -79.7878776),
+(43.6729857, -79.3870289),
+(43.62187, -79.521539),
+(43.773403, -79.344636),
+(43.7107075, -79.4014504),
+(43.743891, -79.837694),
+(43.773344, -79.256149),
+(43.640566, -79.562739),
+(43.4839109, -79.7121523),
+(43.8504455, -79.4202134),
+(43.9689155, -78.9440049),
+(43.7078271, -79.4583231),
+(43.5552474, -79.5785842),
+(43.508158, -79.698695),
+(43.7832853, -79.5785927),
+(43.8525295, -79.4925632),
+(43.7822014, -79.4381119),
+(43.77934, -79.3965307),
+(43.48762, -79.733907),
+(43.6698726, -79.3136327),
+(43.8617383, -79.3245463),
+(43.4780583, -79.7460748),
+(43.6824808, -79.8438196),
+(43.6238205, -79.480348),
+(43.7967631, -79.2219676),
+(43.8267588, -79.4048856),
+(43.6456952, -79.3761433),
+(43.4275305, -79.7685313),
+(43.5661077, -79.6477661),
+(43.6518091, -79.7507065),
+(43.8823131, -79.4193025),
+(43.834659, -78.971726),
+(43.8322481, -79.4734877),
+(43.5137676, -79.8528153),
+(43.641184, -79.421498),
+(43.7862519, -79.4269358),
+(43.3454689, -79.7849431),
+(43.7690784, -79.3694836),
+(43.811983, -79.456717),
+(43.8695472, -79.0727582),
+(43.8372476, -79.626355),
+(43.8734769, -78.9575468),
+(43.6904251, -79.7692591),
+(43.6515293, -79.375757),
+(43.7775639, -79.4003595),
+(43.7742659, -79.3881742),
+(43.624107, -79.498431),
+(43.6768374, -79.3120964),
+(43.8161716, -79.7303707),
+(43.7485912, -79.2158155),
+(43.7149233, -79.3904293),
+(43.6836783, -79.3903045),
+(43.8565448, -79.3244227),
+(43.546337, -79.718319),
+(43.6873367, -79.2906907),
+(43.7803104, -79.2747984),
+(43.9428624, -78.8921881),
+(43.6732539, -79.2976813),
+(43.6502312, -79.5740158),
+(43.8083165, -79.4241598),
+(43.35434, -79.774555),
+(43.8230446, -79.3708367),
+(43.94557, -79.535056),
+(43.7432076, -79.3477351),
+(43.7075677, -79.2464381),
+(43.9791725, -79.477182),
+(43.437725, -79.7576257),
+(43.6747485, -79.3254361),
+(43.898278, -79.312644),
+(43.6632004, -79.3777681),
+(43.8753201, -79.3155889),
+(43.624447, -79.4899623),
+(43.7986199, -79.3776391),
+(43.9680727, -78.9496315),
+(43.6813665, -79.8479471),
+(43.7183312, -79.5494188),
+(43.5168921, -79.8472089),
+(43.623426, -79.4789788),
+(43.8914831, -78.9299544),
+(43.8095352, -79.2618122),
+(43.72555, -79.2329632),
+(43.8226089, -79.4247113),
+(43.8760985, -79.4424802),
+(43.9620836, -78.9460546),
+(43.9169634, -79.4484388),
+(43.5278082, -79.6387021),
+(43.7251399, -79.4149855),
+(43.9259108, -78.9239283),
+(43.7853952, -79.4055754),
+(43.70754, -79.25681),
+(43.902498, -79.408493),
+(43.4367303, -79.6799384),
+(43.624099, -79.500743),
+(43.6647797, -79.3409446),
+(43.774318, -79.1377695),
+(43.4951771, -79.6591403),
+(43.8917754, -79.0204153),
+(43.8138176, -79.4492202),
+(43.851993, -79.43178),
+(43.7703, -79.413645),
+(43.7181989, -79.8163711),
+(43.6688211, -79.4142981),
+(43.6096951, -79.4954164),
+(43.774836, -79.3820774),
+(43.77807, -79.4158254),
+(43.7904691, -79.4520964),
+(43.6397347, -79.3920867),
+(43.8832924, -79.3052671),
+(43.8429224, -79.5721246),
+(43.773403, -79.344636),
+(43.8371365, -79.4489832),
+(43.8458638, -79.4264298),
+(43.641087, -79.381405),
+(43.6095757, -79.5573689),
+(43.715384, -79.454548),
+(43.774071, -79.329324),
+(43.646434, -79.397806),
+(43.7563401, -79.4056932),
+(43.657547, -79.401464),
+(43.769664, -79.413783),
+(43.641806, -79.378981),
+(43.6611024, -79.3819277),
+(43.7651693, -79.3849692),
+(43.3689655, -79.8271665),
+(43.6870787, -79.3117348),
+(43.668578, -79.384479),
+(43.641071, -79.400252),
+(43.950907, -78.957545),
+(43.6613016, -79.3783274),
+(43.4392479, -79.6775626),
+(43.374053, -79.721288),
+(43.646099, -79.365136),
+(43.7725548, -79.1737217),
+(43.861726, -79.435841),
+(43.4438914, -79.728737),
+(43.905601, -79.3747098),
+(43.780046, -79.165129),
+(43.74953, -79.407192),
+(43.663633, -79.3408258),
+(43.7582408, -79.409106),
+(43.6243612, -79.5154297),
+(43.8974806, -79.2174292),
+(43.677266, -79.406148),
+(43.3589506, -79.7792242),
+(43.58838, -79.639953),
+(43.5212296, -79.6777909),
+(43.7801798, -79.3875639),
+(43.7876995, -79.4169893),
+(43.652227, -79.398262),
+(43.801019, -79.275308),
+(43.8521474, -78.9541848),
+(43.839914, -79.009394),
+(43.752415, -79.265816),
+(43.5800439, -79.6250026),
+(43.7629803, -79.7123395),
+(43.755609, -79.580115),
+(43.7103469, -79.811198),
+(43.4670299, -79.6922162),
+(43.675773, -79.8164),
+(43.7033263, -79.7523173),
+(43.930221, -79.945537),
+(43.6059394, -79.7197953),
+(43.7205173, -79.258877),
+(43.8749965, -79.0929031),
+(43.8593677, -78.9319517),
+(43.516021, -79.883669),
+(43.4895951, -79.8575519),
+(43.7422702, -79.2144328),
+(43.9068881, -79.4525615),
+(43.8546835, -79.5647692),
+(43.9401497, -79.44315),
+(43.748882, -79.778978),
+(43.5699925, -79.7186185),
+(43.599252, -79.726065),
+(43.8053306, -79.471356),
+(43.7278613, -79.7999345),
+(43.7307332, -79.7150731),
+(43.8280706, -79.6550618),
+(43.783076, -79.664058),
+(43.8999739, -79.2708322),
+(43.3470034, -79.8434911),
+(43.876971, -79.046927),
+(43.886835, -79.01002),
+(43.8772397, -78.9520659),
+(43.730701, -79.465717),
+(43.9328078, -79.5315807),
+(43.8533097, -79.4897184),
+(43.8941, -79.223255),
+(43.8496444, -79.427085),
+(43.950363, -79.422041),
+(43.6790517, -79.8391641),
+(43.8867735, -79.3091005),
+(43.5333861, -79.6874112),
+(43.641755, -79.3796991),
+(43.7050382, -79.2558003),
+(43.5410791, -79.6974539),
+(43.4523098, -79.7550578),
+(43.5571607, -79.7071213),
+(43.655787, -79.571363),
+(43.5487148, -79.6084415),
+(43.7456661, -79.5043085),
+(43.7042112, -79.2628597),
+(43.7537831, -79.4604084),
+(43.768501, -79.381285),
+(43.6059202, -79.5059646),
+(43.4966101, -79.6587456),
+(43.6590117, -79.4083564),
+(43.6886608, -79.4402012),
+(43.9586472, -79.4620626),
+(43.8498302, -79.4462554),
+(43.9685708, -79.2532014),
+(43.8877383, -79.253831),
+(43.8793, -79.497),
+(43.7853599, -79.4347692),
+(43.6012751, -79.6043883),
+(43.9324161, -79.0301361),
+(43.977099, -79.47279),
+(43.7934043, -79.2921908),
+(43.563756, -79.6581419),
+(43.7441938, -79.5917954),
+(43.5128657, -79.881369),
+(43.771579, -79.407679),
+(43.7860942, -79.6074802),
+(43.7210459, -79.6172923),
+(43.3743271, -79.8315139),
+(43.7735386, -79.4229947),
+(43.7766433, -79.1709156),
+(43.7141509, -79.5437061),
+(43.8687263, -79.327166),
+(43.372258, -79.7262846),
+(43.8479666, -79.4509023),
+(43.8790744, -79.4627713),
+(43.669963, -79.386158),
+(43.8818797, -79.4140734),
+(43.7604152, -79.1987529),
+(43.9249401, -79.5353078),
+(43.3812924, -79.815799),
+(43.960115, -78.9069897),
+(43.647627, -79.390408),
+(43.7510289, -79.230392),
+(43.9578487, -78.8981354),
+(43.9604863, -78.8990264),
+(43.6871554, -79.8001963),
+(43.7520037, -79.3971086),
+(43.900013, -79.879856),
+(43.8997415, -79.4583838),
+(43.3645551, -79.82865),
+(43.8956727, -79.4562795),
+(43.532417, -79.847735),
+(43.8062229, -79.3232223),
+(43.6520334, -79.4117239),
+(43.6839, -79.4824),
+(43.875625, -79.068573),
+(43.623821, -79.478728),
+(43.6404408, -79.3971889),
+(43.642791, -79.407195),
+(43.778264, -79.415454),
+(43.7668827, -79.4636396),
+(43.5348232, -79.8602292),
+(43.7696984, -79.4206676),
+(43.9019066, -79.2483409),
+(43.5956797, -79.6347654),
+(43.812797, -79.227691),
+(43.5754399, -79.7461808),
+(43.8534923, -79.4031472),
+(43.5970586, -79.6522565),
+(43.8845453, -78.9753266),
+(43.4169515, -79.7111417),
+(43.6811127, -79.3413528),
+(43.691369, -79.5064889),
+(43.6939362, -79.3805935),
+(43.9225131, -78.9171924),
+(43.6702175, -79.3951083),
+(43.6761846, -79.343525),
+(43.623689, -79.513324),
+(43.649568, -79.5265828),
+(43.7595855, -79.4150269),
+(43.8685365, -79.4347893),
+(43.9583844, -79.4657172),
+(43.4245317, -79.7021289),
+(43.7797258, -79.3897198),
+(43.8442866, -79.6600134),
+(43.6343544, -79.5662371),
+(43.851507, -79.488741),
+(43.5280324, -79.6710379),
+(43.5777625, -79.7779694),
+(43.4508518, -79.7052387),
+(43.662771, -79.7702282),
+(43.7714408, -79.4042794),
+(43.960774, -79.442791),
+(43.7443856, -79.3858861),
+(43.6717727, -79.3947803),
+(43.5178359, -79.8481703),
+(43.7013448, -79.3985783),
+(43.541743, -79.631656),
+(43.7343824, -79.4133764),
+(43.4286025, -79.7811407),
+(43.6978659, -79.4194288),
+(43.568429, -79.5627667),
+(43.6563373, -79.3601266),
+(43.5756512, -79.5751698),
+(43.806124, -79.4475591),
+(43.4686756, -79.662597),
+(43.481729, -79.716193),
+(43.843616, -79.466321),
+(43.8877763, -79.4135376),
+(43.7115169, -79.3859306),
+(43.798226, -79.4895872),
+(43.7354447, -79.3598577),
+(43.8885665, -79.4656243),
+(43.879004, -79.2593779),
+(43.3428625, -79.8847813),
+(43.8334075, -79.2951873),
+(43.3568458, -79.7504501),
+(43.7629235, -79.7477789),
+(43.8811694, -79.3010955),
+(43.8892638, -79.3035985),
+(43.8427494, -79.4713251),
+(43.3888917, -79.8209863),
+(43.3849287, -79.8251997),
+(43.6844626, -79.2781424),
+(43.7438905, -79.4473636),
+(43.544324, -79.596933),
+(43.8668446, -79.4866613),
+(43.394172, -79.8057302),
+(43.8668219, -79.4706817),
+(43.8566023, -79.4258009),
+(43.6084573, -79.4958318),
+(43.7986831, -79.3682244),
+(43.8889797, -79.4986652),
+(43.891995, -79.4176745),
+(43.6514, -79.9219),
+(43.4285025, -79.7797741),
+(43.4863416, -79.7347333),
+(43.4584021, -79.654951),
+(43.5883905, -79.7781668),
+(43.868954, -79.028324),
+(43.7946201, -79.3368258),
+(43.7722796, -79.4044473),
+(43.7877841, -79.5881691),
+(43.8751623, -78.904747),
+(43.7073343, -79.3774564),
+(43.7070855, -79.3977452),
+(43.774172, -79.417642),
+(43.665388, -79.3880211),
+(43.6627217, -79.3794411),
+(43.550574, -79.6752647),
+(43.6798859, -79.4955979),
+(43.6429592, -79.4306846),
+(43.4845793, -79.8642981),
+(43.6593823, -79.5261695),
+(43.6402719, -79.4267695),
+(43.9805499, -79.2546783),
+(43.5764556, -79.7800494),
+(43.916173, -79.446721),
+(43.7243375, -79.7915804),
+(43.656511, -79.919248),
+(43.8699797, -79.4685984),
+(43.9601179, -78.9044029),
+(43.4944277, -79.6577959),
+(43.7083238, -79.7675156),
+(43.8491445, -79.4282183),
+(43.706847, -79.831502),
+(43.8550823, -79.4496876),
+(43.573522, -79.700576),
+(43.824465, -79.034618),
+(43.9175254, -78.9085546),
+(43.66854, -79.728519),
+(43.6984367, -79.7694257),
+(43.7386, -79.8437),
+(43.8496423, -78.9469498),
+(43.7948651, -79.3340779),
+(43.3903412, -79.8040622),
+(43.833747, -79.010533),
+(43.796702, -79.414832),
+(43.7066441, -79.4271318),
+(43.6400653, -79.3784948),
+(43.7408676, -79.2388813),
+(43.8825419, -78.9575601),
+(43.4025352, -79.8176559),
+(43.5194261, -79.8420069),
+(43.9852307, -79.4338935),
+(43.7261019, -79.4291345),
+(43.3477301, -79.8485723),
+(43.6712941, -79.384925),
+(43.591682, -79.628015),
+(43.7811337, -79.3940885),
+(43.5370577, -79.6900371),
+(43.5129542, -79.6564577),
+(43.7914578, -79.3475704),
+(43.6461536, -79.3752086),
+(43.3682931, -79.8411427),
+(43.8670605, -79.4325556),
+(43.435599, -79.7726),
+(43.7697784, -79.3575716),
+(43.6653207, -79.7298217),
+(43.7528565, -79.4417461),
+(43.7234947, -79.391112),
+(43.8363787, -79.2753966),
+(43.862199, -79.031909),
+(43.7133145, -79.7897426),
+(43.6431916, -79.7593568),
+(43.67758, -79.5469374),
+(43.7728259, -79.7312914),
+(43.691884, -79.3211607),
+(43.5033402, -79.6479152),
+(43.7694183, -79.3686931),
+(43.6735187, -79.8099245),
+(43.731194, -79.3345701),
+(43.5749676, -79.7702258),
+(43.7042912, -79.762722),
+(43.808657, -79.414176),
+(43.6057987, -79.6546773),
+(43.5311829, -79.639121),
+(43.626067, -79.4906182),
+(43.6306135, -79.5160791),
+(43.774071, -79.329324),
+(43.8563501, -79.5464122),
+(43.852179, -79.5543795),
+(43.649067, -79.37693),
+(43.7437412, -79.8057046),
+(43.7012876, -79.7676478),
+(43.7720245, -79.6649655),
+(43.8080559, -79.4553335),
+(43.7742702, -79.3933826),
+(43.820784, -79.213414),
+(43.894645, -79.0099666),
+(43.6335031, -79.3999789),
+(43.5927146, -79.5231259),
+(43.7412897, -79.3950178),
+(43.642422, -79.3755829),
+(43.947142, -79.837321),
+(43.9148656, -78.9519058),
+(43.857251, -79.124959),
+(43.656574, -79.3683276),
+(43.88937, -79.046047),
+(43.781616, -79.572502),
+(43.933882, -79.4747558),
+(43.8466555, -79.5335555),
+(43.5579065, -79.7336717),
+(43.656935, -79.749237),
+(43.4402456, -79.7333922),
+(43.9018597, -78.9468593),
+(43.7747846, -79.2965258),
+(43.7259975, -79.2331698),
+(43.796519, -79.518865),
+(43.876966, -79.029316),
+(43.5876825, -79.6283005),
+(43.760402, -79.40901),
+(43.778211, -79.704172),
+(43.6817346, -79.7361996),
+(43.5755751, -79.7826072),
+(43.7181463, -79.72599),
+(43.438753, -79.681103),
+(43.532669, -79.8615997),
+(43.486401, -79.645967),
+(43.5100528, -79.857073),
+(43.9665735, -78.9574739),
+(43.7280051, -79.7730317),
+(43.5886947, -79.6081405),
+(43.66187, -79.4101633),
+(43.523905, -79.61541),
+(43.4678721, -79.6669108),
+(43.8857367, -78.9417389),
+(43.4935347, -79.8743128),
+(43.9086355, -78.941162),
+(43.527182, -79.833284),
+(43.6488273, -79.3780964),
+(43.880161, -79.041147),
+(43.887893, -79.011566),
+(43.6574768, -79.445671),
+(43.6837653, -79.4189514),
+(43.6627217, -79.3794411),
+(43.659603, -79.384784),
+(43.7452139, -79.2079532),
+(43.685367, -79.294191),
+(43.833383, -79.003937),
+(43.6391628, -79.4191891),
+(43.7939829, -79.4434454),
+(43.6563503, -79.4140066),
+(43.8136153, -79.3234102),
+(43.8535057, -79.5040574),
+(43.9551848, -78.8944654),
+(43.8813616, -78.9673293),
+(43.8412959, -79.3938341),
+(43.9767236, -79.4496714),
+(43.9071666, -79.2710629),
+(43.8505249, -79.3257399),
+(43.8129727, -79.4551834),
+(43.9375809, -79.4704335),
+(43.8836465, -79.4952627),
+(43.9006045, -79.4532939),
+(43.970816, -79.249128),
+(43.720384, -79.603272),
+(43.778531, -79.413214),
+(43.5168921, -79.8472089),
+(43.8667088, -79.438789),
+(43.556527, -79.65459),
+(43.721733, -79.732107),
+(43.6038308, -79.6076207),
+(43.7552018, -79.565216),
+(43.7786839, -79.6011466),
+(43.647915, -79.373848),
+(43.6959554, -79.3957179),
+(43.6737227, -79.3011219),
+(43.7133186, -79.7932594),
+(43.8561611, -79.4412115),
+(43.6493456, -79.3758022),
+(43.7805353, -79.2510063),
+(43.6367062, -79.4000807),
+(43.5519771, -79.7286804),
+(43.696309, -79.7962365),
+(43.8363264, -79.2646563),
+(43.8383596, -79.512209),
+(43.950513, -79.423624),
+(43.531039, -79.8812748),
+(43.586289, -79.647364),
+(43.4930531, -79.7187781),
+(43.62372, -79.483703),
+(43.895976, -79.014458),
+(43.3665775, -79.7566687),
+(43.3931614, -79.7884102),
+(43.665666, -79.382607),
+(43.7907581, -79.4508033),
+(43.9392124, -78.8897893),
+(43.68381, -79.41879),
+(43.6620882, -79.3856802),
+(43.963094, -78.905184),
+(43.703768, -79.4110697),
+(43.7951922, -79.3243815),
+(43.8939693, -79.0144907),
+(43.8333415, -79.1511721),
+(43.8761434, -79.4580667),
+(43.8619105, -79.2851671),
+(43.8398565, -79.5060058),
+(43.9005864, -79.2810194),
+(43.625159, -79.47872),
+(43.826744, -79.091001),
+(43.9642554, -79.2499841),
+(43.8416778, -79.5277041),
+(43.581136, -79.88297),
+(43.8527788, -79.5427656),
+(43.854024, -79.465291),
+(43.4693383, -79.7093826),
+(43.3492611, -79.7699107),
+(43.5069139, -79.6432897),
+(43.6440838, -79.5286733),
+(43.6124062, -79.5406485),
+(43.488891, -79.8504324),
+(43.5619689, -79.7374941),
+(43.721122, -79.501826),
+(43.5781155, -79.7251141),
+(43.7671412, -79.7490095),
+(43.771574, -79.2105057),
+(43.9677451, -79.2225009),
+(43.7357352, -79.8293255),
+(43.870014, -79.4684526),
+(43.87886, -79.741036),
+(43.6298722, -79.874685),
+(43.6527825, -79.3939324),
+(43.4296895, -79.7571709),
+(43.644683, -79.364087),
+(43.3943735, -79.8187557),
+(43.7231476, -79.4482263),
+(43.5570125, -79.7402594),
+(43.4021906, -79.7869305),
+(43.606015, -79.654859),
+(43.8743, -79.7311),
+(43.9000078, -79.4035474),
+(43.6592713, -79.3849163),
+(43.8884843, -78.9389348),
+(43.780324, -79.418647),
+(43.7069544, -79.395016),
+(43.8668405, -79.3169327),
+(43.724239, -79.40255),
+(43.588195, -79.78837),
+(43.434491, -79.775259),
+(43.7423511, -79.3453014),
+(43.7234718, -79.4047717),
+(43.646084, -79.365012),
+(43.7125911, -79.3776798),
+(43.7075225, -79.392402),
+(43.6982968, -79.4388447),
+(43.6383649, -79.4145613),
+(43.8991171, -78.9717692),
+(43.7128157, -79.8117941),
+(43.5888511, -79.7855735),
+(43.6871437, -79.5064656)
 ];
\ No newline at end of file
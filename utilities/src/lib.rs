@@ -13,7 +13,9 @@ pub static DEFAULT_BOUNDS: Bounds = Bounds {
 };
 
 pub fn get_sample_markers() -> Vec<Marker> {
-  MARKERS.to_vec()
+  MARKERS.iter()
+    .map(|&(lat, lng)| Marker { lat, lng, id: None, properties: None })
+    .collect()
 }
 
 pub fn get_unique_sample_markers() -> Vec<UniqueMarker> {